@@ -0,0 +1,493 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Reads security advisories from a local checkout of the
+//! [RustSec advisory database](https://github.com/rustsec/advisory-db)
+//! and turns the ones affecting the dependencies into CycloneDX vulnerabilities.
+//!
+//! Every advisory is a Markdown file in `crates/<crate name>/` that starts with
+//! a fenced TOML block containing the metadata, followed by the title and description.
+
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use cargo_metadata::Package;
+use cyclonedx_bom::external_models::date_time::DateTime;
+use cyclonedx_bom::external_models::normalized_string::NormalizedString;
+use cyclonedx_bom::external_models::uri::Uri;
+use cyclonedx_bom::models::advisory::{Advisories, Advisory as CdxAdvisory};
+use cyclonedx_bom::models::vulnerability::{Vulnerabilities, Vulnerability};
+use cyclonedx_bom::models::vulnerability_rating::{
+    ScoreMethod, VulnerabilityRating, VulnerabilityRatings,
+};
+use cyclonedx_bom::models::vulnerability_reference::{
+    VulnerabilityReference, VulnerabilityReferences,
+};
+use cyclonedx_bom::models::vulnerability_source::VulnerabilitySource;
+use cyclonedx_bom::models::vulnerability_target::{
+    Version, Versions, VulnerabilityTarget, VulnerabilityTargets,
+};
+use semver::VersionReq;
+use serde::Deserialize;
+use thiserror::Error;
+
+/// The advisories of a RustSec advisory database, indexed by crate name
+#[derive(Debug, Default)]
+pub struct AdvisoryDatabase {
+    advisories: HashMap<String, Vec<Advisory>>,
+}
+
+impl AdvisoryDatabase {
+    /// Loads all crate advisories from the advisory database at `path`
+    pub fn open(path: &Path) -> Result<Self, AdvisoryError> {
+        let crates_dir = path.join("crates");
+        if !crates_dir.is_dir() {
+            return Err(AdvisoryError::NotAnAdvisoryDatabase(path.to_path_buf()));
+        }
+
+        let mut database = Self::default();
+        for crate_dir in read_dir(&crates_dir)? {
+            if !crate_dir.is_dir() {
+                continue;
+            }
+            for file in read_dir(&crate_dir)? {
+                if file.extension().map_or(true, |ext| ext != "md") {
+                    continue;
+                }
+                let content = fs::read_to_string(&file).map_err(|source| AdvisoryError::Io {
+                    path: file.clone(),
+                    source,
+                })?;
+                let advisory = Advisory::parse(&content).map_err(|error| {
+                    AdvisoryError::InvalidAdvisory(file.clone(), error.to_string())
+                })?;
+                database.add(advisory);
+            }
+        }
+
+        log::debug!(
+            "Loaded advisories for {} crates from {}",
+            database.advisories.len(),
+            path.display()
+        );
+        Ok(database)
+    }
+
+    pub fn add(&mut self, advisory: Advisory) {
+        self.advisories
+            .entry(advisory.package.clone())
+            .or_default()
+            .push(advisory);
+    }
+
+    /// Returns the advisories that affect the given package.
+    ///
+    /// Only packages from crates.io are considered, since that is what the advisories refer to.
+    pub fn advisories_for<'a>(
+        &'a self,
+        package: &'a Package,
+    ) -> impl Iterator<Item = &'a Advisory> {
+        let from_crates_io = package
+            .source
+            .as_ref()
+            .is_some_and(crate::generator::is_crates_io);
+        let advisories = match from_crates_io {
+            true => self.advisories.get(&package.name).map(Vec::as_slice),
+            false => None,
+        };
+
+        advisories
+            .unwrap_or_default()
+            .iter()
+            .filter(|advisory| advisory.affects(&package.version))
+    }
+
+    /// Creates a vulnerability for every advisory affecting at least one of the packages.
+    /// The affected packages are referenced by their `bom-ref`, i.e. their package ID.
+    pub fn vulnerabilities<'a>(
+        &self,
+        packages: impl IntoIterator<Item = &'a Package>,
+    ) -> Option<Vulnerabilities> {
+        let mut affected: Vec<(&Advisory, Vec<&Package>)> = Vec::new();
+        for package in packages {
+            for advisory in self.advisories_for(package) {
+                match affected.iter_mut().find(|(a, _)| a.id == advisory.id) {
+                    Some((_, packages)) => packages.push(package),
+                    None => affected.push((advisory, vec![package])),
+                }
+            }
+        }
+
+        if affected.is_empty() {
+            return None;
+        }
+
+        affected.sort_by(|(a, _), (b, _)| a.id.cmp(&b.id));
+        Some(Vulnerabilities(
+            affected
+                .into_iter()
+                .map(|(advisory, packages)| advisory.to_vulnerability(&packages))
+                .collect(),
+        ))
+    }
+}
+
+/// A single advisory of the RustSec advisory database
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Advisory {
+    pub id: String,
+    pub package: String,
+    /// The date the advisory was published, e.g. `2021-01-31`
+    pub date: String,
+    pub url: Option<String>,
+    /// IDs of the same vulnerability in other databases, e.g. CVE or GHSA IDs
+    pub aliases: Vec<String>,
+    /// A CVSS v3 vector
+    pub cvss: Option<String>,
+    pub title: String,
+    pub description: String,
+    pub patched: Vec<VersionReq>,
+    pub unaffected: Vec<VersionReq>,
+    /// Informational advisories, e.g. for unmaintained crates, are not vulnerabilities
+    pub informational: Option<String>,
+    pub withdrawn: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct AdvisoryFile {
+    advisory: AdvisoryMetadata,
+    #[serde(default)]
+    versions: AdvisoryVersions,
+}
+
+#[derive(Deserialize)]
+struct AdvisoryMetadata {
+    id: String,
+    package: String,
+    date: String,
+    url: Option<String>,
+    #[serde(default)]
+    aliases: Vec<String>,
+    cvss: Option<String>,
+    informational: Option<String>,
+    withdrawn: Option<String>,
+}
+
+#[derive(Default, Deserialize)]
+struct AdvisoryVersions {
+    #[serde(default)]
+    patched: Vec<VersionReq>,
+    #[serde(default)]
+    unaffected: Vec<VersionReq>,
+}
+
+impl Advisory {
+    /// Parses an advisory in the Markdown format of the advisory database
+    pub fn parse(content: &str) -> Result<Self, AdvisoryError> {
+        let front_matter = content
+            .trim_start()
+            .strip_prefix("```toml")
+            .ok_or(AdvisoryError::MissingFrontMatter)?;
+        let (toml, markdown) = front_matter
+            .split_once("\n```")
+            .ok_or(AdvisoryError::MissingFrontMatter)?;
+        let file: AdvisoryFile = toml::from_str(toml)?;
+
+        let markdown = markdown.trim();
+        let (title, description) = match markdown.strip_prefix("# ") {
+            Some(rest) => rest.split_once('\n').unwrap_or((rest, "")),
+            None => ("", markdown),
+        };
+
+        Ok(Self {
+            id: file.advisory.id,
+            package: file.advisory.package,
+            date: file.advisory.date,
+            url: file.advisory.url,
+            aliases: file.advisory.aliases,
+            cvss: file.advisory.cvss,
+            title: title.trim().to_string(),
+            description: description.trim().to_string(),
+            patched: file.versions.patched,
+            unaffected: file.versions.unaffected,
+            informational: file.advisory.informational,
+            withdrawn: file.advisory.withdrawn,
+        })
+    }
+
+    /// Returns `true` if the given version is neither patched nor unaffected
+    pub fn affects(&self, version: &semver::Version) -> bool {
+        self.withdrawn.is_none()
+            && self.informational.is_none()
+            && !self
+                .patched
+                .iter()
+                .chain(self.unaffected.iter())
+                .any(|req| req.matches(version))
+    }
+
+    fn to_vulnerability(&self, packages: &[&Package]) -> Vulnerability {
+        let mut vulnerability = Vulnerability::new(None);
+        vulnerability.id = Some(NormalizedString::new(&self.id));
+        vulnerability.vulnerability_source = Some(VulnerabilitySource::new(
+            Some("RustSec".to_string()),
+            uri(format!("https://rustsec.org/advisories/{}.html", self.id)),
+        ));
+        vulnerability.description = (!self.title.is_empty()).then(|| self.title.clone());
+        vulnerability.detail = (!self.description.is_empty()).then(|| self.description.clone());
+        vulnerability.published = DateTime::try_from(format!("{}T00:00:00Z", self.date)).ok();
+
+        if !self.patched.is_empty() {
+            let patched: Vec<String> = self.patched.iter().map(|req| req.to_string()).collect();
+            vulnerability.recommendation = Some(format!(
+                "Upgrade to a version matching {}",
+                patched.join(" or ")
+            ));
+        }
+
+        if !self.aliases.is_empty() {
+            vulnerability.vulnerability_references = Some(VulnerabilityReferences(
+                self.aliases
+                    .iter()
+                    .map(|alias| VulnerabilityReference::new(alias, alias_source(alias)))
+                    .collect(),
+            ));
+        }
+
+        if let Some(cvss) = &self.cvss {
+            let score_method = match cvss.starts_with("CVSS:3.1/") {
+                true => ScoreMethod::CVSSv31,
+                false => ScoreMethod::CVSSv3,
+            };
+            let mut rating = VulnerabilityRating::new(None, None, Some(score_method));
+            rating.vector = Some(NormalizedString::new(cvss));
+            rating.score = rating.cvss_base_score();
+            vulnerability.vulnerability_ratings = Some(VulnerabilityRatings(vec![rating]));
+        }
+
+        if let Some(url) = self.url.clone().and_then(uri) {
+            vulnerability.advisories = Some(Advisories(vec![CdxAdvisory::new(url)]));
+        }
+
+        vulnerability.vulnerability_targets = Some(VulnerabilityTargets(
+            packages
+                .iter()
+                .map(|package| {
+                    let mut target = VulnerabilityTarget::new(package.id.to_string());
+                    target.versions = Some(Versions(vec![Version::new(
+                        &package.version.to_string(),
+                        "affected",
+                    )]));
+                    target
+                })
+                .collect(),
+        ));
+
+        vulnerability
+    }
+}
+
+/// Guesses the database an alias of an advisory comes from
+fn alias_source(alias: &str) -> VulnerabilitySource {
+    if alias.starts_with("CVE-") {
+        VulnerabilitySource::new(
+            Some("NVD".to_string()),
+            uri(format!("https://nvd.nist.gov/vuln/detail/{alias}")),
+        )
+    } else if alias.starts_with("GHSA-") {
+        VulnerabilitySource::new(
+            Some("GitHub".to_string()),
+            uri(format!("https://github.com/advisories/{alias}")),
+        )
+    } else {
+        VulnerabilitySource::new(None, None)
+    }
+}
+
+fn uri(value: String) -> Option<Uri> {
+    match Uri::try_from(value) {
+        Ok(uri) => Some(uri),
+        Err(e) => {
+            log::warn!("Ignoring invalid URL in advisory: {}", e);
+            None
+        }
+    }
+}
+
+fn read_dir(path: &Path) -> Result<Vec<PathBuf>, AdvisoryError> {
+    let to_error = |source| AdvisoryError::Io {
+        path: path.to_path_buf(),
+        source,
+    };
+    let mut paths = fs::read_dir(path)
+        .map_err(to_error)?
+        .map(|entry| entry.map(|e| e.path()))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(to_error)?;
+    paths.sort();
+    Ok(paths)
+}
+
+#[derive(Debug, Error)]
+pub enum AdvisoryError {
+    #[error("{} is not a RustSec advisory database, it has no crates directory", .0.display())]
+    NotAnAdvisoryDatabase(PathBuf),
+
+    #[error("I/O error while reading {}: {source}", .path.display())]
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+
+    #[error("Invalid advisory {}: {}", .0.display(), .1)]
+    InvalidAdvisory(PathBuf, String),
+
+    #[error("The advisory does not start with a TOML block")]
+    MissingFrontMatter,
+
+    #[error("Invalid advisory metadata: {0}")]
+    InvalidMetadata(#[from] toml::de::Error),
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use cyclonedx_bom::models::vulnerability_rating::Score;
+    use cyclonedx_bom::models::vulnerability_target::VersionRange;
+
+    const ADVISORY: &str = r#"```toml
+[advisory]
+id = "RUSTSEC-2020-0071"
+package = "aho-corasick"
+date = "2020-11-18"
+url = "https://example.com/issues/293"
+categories = ["code-execution"]
+aliases = ["CVE-2020-26235", "GHSA-wcg3-cvx6-7396"]
+cvss = "CVSS:3.1/AV:L/AC:H/PR:N/UI:R/S:U/C:N/I:N/A:H"
+
+[versions]
+patched = [">= 1.1.3"]
+unaffected = ["< 1.0.0"]
+```
+
+# Potential segfault
+
+Unix-like operating systems may segfault.
+"#;
+
+    fn package() -> Package {
+        serde_json::from_str(include_str!("../tests/fixtures/crates_io_package.json")).unwrap()
+    }
+
+    #[test]
+    fn it_should_parse_an_advisory() {
+        let advisory = Advisory::parse(ADVISORY).unwrap();
+
+        assert_eq!(advisory.id, "RUSTSEC-2020-0071");
+        assert_eq!(advisory.package, "aho-corasick");
+        assert_eq!(advisory.title, "Potential segfault");
+        assert_eq!(
+            advisory.description,
+            "Unix-like operating systems may segfault."
+        );
+        assert_eq!(
+            advisory.patched,
+            vec![VersionReq::parse(">=1.1.3").unwrap()]
+        );
+    }
+
+    #[test]
+    fn it_should_reject_advisories_without_front_matter() {
+        assert!(matches!(
+            Advisory::parse("# Title"),
+            Err(AdvisoryError::MissingFrontMatter)
+        ));
+    }
+
+    #[test]
+    fn it_should_match_affected_versions() {
+        let mut advisory = Advisory::parse(ADVISORY).unwrap();
+
+        assert!(advisory.affects(&semver::Version::new(1, 1, 2)));
+        assert!(!advisory.affects(&semver::Version::new(1, 1, 3)));
+        assert!(!advisory.affects(&semver::Version::new(0, 9, 0)));
+
+        advisory.withdrawn = Some("2021-01-01".to_string());
+        assert!(!advisory.affects(&semver::Version::new(1, 1, 2)));
+    }
+
+    #[test]
+    fn it_should_create_vulnerabilities_for_affected_packages() {
+        let mut database = AdvisoryDatabase::default();
+        database.add(Advisory::parse(ADVISORY).unwrap());
+        let package = package();
+
+        let vulnerabilities = database.vulnerabilities([&package]).unwrap();
+
+        assert_eq!(vulnerabilities.0.len(), 1);
+        let vulnerability = &vulnerabilities.0[0];
+        assert_eq!(
+            vulnerability.id,
+            Some(NormalizedString::new("RUSTSEC-2020-0071"))
+        );
+        assert_eq!(
+            vulnerability.recommendation.as_deref(),
+            Some("Upgrade to a version matching >=1.1.3")
+        );
+        assert!(vulnerability.published.is_some());
+        assert_eq!(
+            vulnerability.vulnerability_references.as_ref().unwrap().0[0]
+                .vulnerability_source
+                .name,
+            Some(NormalizedString::new("NVD"))
+        );
+        let rating = &vulnerability.vulnerability_ratings.as_ref().unwrap().0[0];
+        assert_eq!(rating.score_method, Some(ScoreMethod::CVSSv31));
+        assert_eq!(rating.score, Score::from_f32(4.7));
+
+        let target = &vulnerability.vulnerability_targets.as_ref().unwrap().0[0];
+        assert_eq!(target.bom_ref, package.id.to_string());
+        assert_eq!(
+            target.versions.as_ref().unwrap().0[0].version_range,
+            VersionRange::Version(NormalizedString::new("1.1.2"))
+        );
+    }
+
+    #[test]
+    fn it_should_match_packages_from_the_sparse_crates_io_index() {
+        let mut database = AdvisoryDatabase::default();
+        database.add(Advisory::parse(ADVISORY).unwrap());
+        let mut package = package();
+        package.source =
+            Some(serde_json::from_str(r#""sparse+https://index.crates.io/""#).unwrap());
+
+        assert_eq!(database.vulnerabilities([&package]).unwrap().0.len(), 1);
+    }
+
+    #[test]
+    fn it_should_ignore_packages_not_from_crates_io() {
+        let mut database = AdvisoryDatabase::default();
+        database.add(Advisory::parse(ADVISORY).unwrap());
+        let mut package = package();
+        package.source = None;
+
+        assert!(database.vulnerabilities([&package]).is_none());
+    }
+}
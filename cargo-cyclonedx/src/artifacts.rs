@@ -0,0 +1,237 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Locates the binaries and libraries Cargo has built for the targets of a package,
+//! so that their hashes can link the SBOM to the exact artifacts it describes.
+//!
+//! Cargo places the artifacts in `target/<profile>/`, or in `target/<triple>/<profile>/`
+//! when building for an explicitly given target, and names them after the target and crate type.
+
+use std::fs::File;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use cargo_metadata::Target;
+use cyclonedx_bom::models::hash::{Hash, HashAlgorithm, HashValue, Hashes};
+use sha2::{Digest, Sha256};
+
+/// The directories that may hold the artifacts of one profile, in order of preference
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ArtifactDirectory {
+    candidates: Vec<PathBuf>,
+    triple: String,
+}
+
+impl ArtifactDirectory {
+    /// `profile` is the name of a Cargo profile, e.g. `release`
+    pub fn new(target_directory: &Path, profile: &str, triple: &str) -> Self {
+        // The built-in profiles don't use their own names for their directories
+        let profile_dir = match profile {
+            "dev" | "test" => "debug",
+            "bench" => "release",
+            other => other,
+        };
+
+        Self {
+            candidates: vec![
+                target_directory.join(triple).join(profile_dir),
+                target_directory.join(profile_dir),
+            ],
+            triple: triple.to_string(),
+        }
+    }
+
+    /// Finds the artifact of the target. For libraries with several crate types,
+    /// the first crate type that was built is used.
+    pub fn find(&self, target: &Target) -> Option<PathBuf> {
+        artifact_file_names(target, &self.triple)
+            .iter()
+            .flat_map(|name| self.candidates.iter().map(move |dir| dir.join(name)))
+            .find(|path| path.is_file())
+    }
+
+    /// Hashes the artifact of the target, if it has been built
+    pub fn hashes(&self, target: &Target) -> Option<Hashes> {
+        let path = match self.find(target) {
+            Some(path) => path,
+            None => {
+                log::warn!(
+                    "No artifact of the target {} found in {}. Has it been built with that profile?",
+                    target.name,
+                    self.candidates[0].display()
+                );
+                return None;
+            }
+        };
+
+        file_hashes(&path)
+    }
+}
+
+/// The SHA-256 digest of the running `cargo-cyclonedx` executable, to describe it as a tool
+pub fn executable_hashes() -> Option<Hashes> {
+    let path = match std::env::current_exe() {
+        Ok(path) => path,
+        Err(error) => {
+            log::warn!("Failed to locate the cargo-cyclonedx executable: {}", error);
+            return None;
+        }
+    };
+
+    file_hashes(&path)
+}
+
+/// The SHA-256 digest of the file, or `None` with a warning if it cannot be read
+pub fn file_hashes(path: &Path) -> Option<Hashes> {
+    match sha256_file(path) {
+        Ok(digest) => Some(Hashes(vec![Hash::new(
+            HashAlgorithm::SHA256,
+            HashValue(digest),
+        )])),
+        Err(error) => {
+            log::warn!("Failed to hash {}: {}", path.display(), error);
+            None
+        }
+    }
+}
+
+/// The file names Cargo uses for the artifacts of the target when building for `triple`,
+/// in the order of the target's crate types
+pub fn artifact_file_names(target: &Target, triple: &str) -> Vec<String> {
+    let windows = triple.contains("-windows");
+    let apple = triple.contains("-apple-");
+    let wasm = triple.starts_with("wasm");
+    // Library names use underscores, just like in `use` statements
+    let crate_name = target.name.replace('-', "_");
+
+    target
+        .crate_types
+        .iter()
+        .filter_map(|crate_type| match crate_type.as_str() {
+            "bin" if windows => Some(format!("{}.exe", target.name)),
+            "bin" if wasm => Some(format!("{}.wasm", target.name)),
+            "bin" => Some(target.name.clone()),
+            "lib" | "rlib" => Some(format!("lib{crate_name}.rlib")),
+            "dylib" | "cdylib" if windows => Some(format!("{crate_name}.dll")),
+            "dylib" | "cdylib" if apple => Some(format!("lib{crate_name}.dylib")),
+            "dylib" | "cdylib" if wasm => Some(format!("{crate_name}.wasm")),
+            "dylib" | "cdylib" => Some(format!("lib{crate_name}.so")),
+            "staticlib" if triple.ends_with("-windows-msvc") => Some(format!("{crate_name}.lib")),
+            "staticlib" => Some(format!("lib{crate_name}.a")),
+            // Procedural macros only run during the build and are not distributed
+            _ => None,
+        })
+        .collect()
+}
+
+/// Returns the SHA-256 digest of the file as lowercase hex
+fn sha256_file(path: &Path) -> io::Result<String> {
+    let mut hasher = Sha256::new();
+    io::copy(&mut File::open(path)?, &mut hasher)?;
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use assert_fs::prelude::*;
+
+    fn target(name: &str, crate_types: &[&str]) -> Target {
+        serde_json::from_value(serde_json::json!({
+            "name": name,
+            "kind": crate_types,
+            "crate_types": crate_types,
+            "src_path": "/src/lib.rs",
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn it_should_name_artifacts_like_cargo() {
+        let bin = target("my-tool", &["bin"]);
+        let lib = target("my-lib", &["cdylib", "rlib", "staticlib"]);
+
+        assert_eq!(
+            artifact_file_names(&bin, "x86_64-unknown-linux-gnu"),
+            vec!["my-tool"]
+        );
+        assert_eq!(
+            artifact_file_names(&bin, "x86_64-pc-windows-msvc"),
+            vec!["my-tool.exe"]
+        );
+        assert_eq!(
+            artifact_file_names(&lib, "x86_64-unknown-linux-gnu"),
+            vec!["libmy_lib.so", "libmy_lib.rlib", "libmy_lib.a"]
+        );
+        assert_eq!(
+            artifact_file_names(&lib, "aarch64-apple-darwin"),
+            vec!["libmy_lib.dylib", "libmy_lib.rlib", "libmy_lib.a"]
+        );
+        assert_eq!(
+            artifact_file_names(&lib, "x86_64-pc-windows-msvc"),
+            vec!["my_lib.dll", "libmy_lib.rlib", "my_lib.lib"]
+        );
+        assert!(artifact_file_names(
+            &target("derive", &["proc-macro"]),
+            "x86_64-unknown-linux-gnu"
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn it_should_hash_built_artifacts() {
+        let target_dir = assert_fs::TempDir::new().unwrap();
+        let triple = "x86_64-unknown-linux-gnu";
+        target_dir
+            .child("release/tool")
+            .write_str("binary")
+            .unwrap();
+        target_dir
+            .child(format!("{triple}/release/libmy_lib.rlib"))
+            .write_str("library")
+            .unwrap();
+
+        let artifacts = ArtifactDirectory::new(target_dir.path(), "release", triple);
+
+        assert_eq!(
+            artifacts.hashes(&target("tool", &["bin"])),
+            Some(Hashes(vec![Hash::new(
+                HashAlgorithm::SHA256,
+                // echo -n binary | sha256sum
+                HashValue(
+                    "9a3a45d01531a20e89ac6ae10b0b0beb0492acd7216a368aa062d1a5fecaf9cd".to_string()
+                ),
+            )]))
+        );
+        assert_eq!(
+            artifacts.find(&target("my-lib", &["cdylib", "rlib"])),
+            Some(
+                target_dir
+                    .path()
+                    .join(triple)
+                    .join("release/libmy_lib.rlib")
+            )
+        );
+        assert_eq!(artifacts.hashes(&target("other", &["bin"])), None);
+        assert_eq!(
+            ArtifactDirectory::new(target_dir.path(), "dev", triple)
+                .find(&target("tool", &["bin"])),
+            None
+        );
+    }
+}
@@ -0,0 +1,162 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Finds the binaries that Cargo only builds with features that are not enabled.
+//!
+//! `cargo build --bin <name>` enables the `required-features` of the binary, so its
+//! dependencies can only be described by resolving them again with those features.
+
+use std::collections::BTreeMap;
+
+use cargo_metadata::Metadata;
+
+use crate::generator::has_required_features;
+
+/// Binaries of a workspace member that need the same features which are not enabled
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FeatureGatedBinaries {
+    pub package: String,
+    /// Whether the package is the root package of the workspace, whose features
+    /// Cargo enables without naming the package
+    pub root_package: bool,
+    /// The `required-features` of the binaries that are not enabled
+    pub features: Vec<String>,
+    pub binaries: Vec<String>,
+}
+
+impl FeatureGatedBinaries {
+    /// The features to pass to Cargo, in addition to the configured ones, to build the binaries
+    pub fn cargo_features(&self) -> Vec<String> {
+        match self.root_package {
+            true => self.features.clone(),
+            false => self
+                .features
+                .iter()
+                .map(|feature| format!("{}/{}", self.package, feature))
+                .collect(),
+        }
+    }
+}
+
+/// Groups the binaries of the workspace members that are not built with the features
+/// of the resolve in `metadata` by the features they need
+pub fn feature_gated_binaries(metadata: &Metadata) -> Vec<FeatureGatedBinaries> {
+    let root_package = metadata.root_package().map(|package| &package.id);
+    let mut gated: BTreeMap<(String, bool, Vec<String>), Vec<String>> = BTreeMap::new();
+
+    for package in metadata.workspace_packages() {
+        let enabled_features = metadata
+            .resolve
+            .as_ref()
+            .and_then(|resolve| resolve.nodes.iter().find(|node| node.id == package.id))
+            .map_or(&[][..], |node| node.features.as_slice());
+
+        for target in &package.targets {
+            if !target.is_bin() || has_required_features(target, enabled_features) {
+                continue;
+            }
+            let mut features = target.required_features.clone();
+            features.sort();
+            gated
+                .entry((
+                    package.name.clone(),
+                    root_package == Some(&package.id),
+                    features,
+                ))
+                .or_default()
+                .push(target.name.clone());
+        }
+    }
+
+    gated
+        .into_iter()
+        .map(
+            |((package, root_package, features), binaries)| FeatureGatedBinaries {
+                package,
+                root_package,
+                features,
+                binaries,
+            },
+        )
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn metadata(enabled_features: &[&str]) -> Metadata {
+        let target = |name: &str, required_features: &[&str]| {
+            serde_json::json!({
+                "name": name,
+                "kind": ["bin"],
+                "crate_types": ["bin"],
+                "required-features": required_features,
+                "src_path": format!("/app/src/bin/{name}.rs"),
+            })
+        };
+        serde_json::from_value(serde_json::json!({
+            "packages": [{
+                "name": "app",
+                "version": "0.1.0",
+                "id": "path+file:///app#0.1.0",
+                "dependencies": [],
+                "targets": [
+                    target("app", &[]),
+                    target("cli", &["cli"]),
+                    target("admin", &["cli", "admin"]),
+                    target("server", &["admin", "cli"]),
+                ],
+                "features": {},
+                "manifest_path": "/app/Cargo.toml",
+            }],
+            "workspace_members": ["path+file:///app#0.1.0"],
+            "resolve": {
+                "nodes": [{
+                    "id": "path+file:///app#0.1.0",
+                    "dependencies": [],
+                    "deps": [],
+                    "features": enabled_features,
+                }],
+                "root": "path+file:///app#0.1.0",
+            },
+            "target_directory": "/app/target",
+            "version": 1,
+            "workspace_root": "/app",
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn it_should_group_binaries_by_their_required_features() {
+        assert_eq!(
+            feature_gated_binaries(&metadata(&["cli"])),
+            vec![FeatureGatedBinaries {
+                package: "app".to_string(),
+                root_package: true,
+                features: vec!["admin".to_string(), "cli".to_string()],
+                binaries: vec!["admin".to_string(), "server".to_string()],
+            }]
+        );
+        let mut gated = feature_gated_binaries(&metadata(&["cli"])).remove(0);
+        assert_eq!(gated.cargo_features(), vec!["admin", "cli"]);
+        gated.root_package = false;
+        assert_eq!(gated.cargo_features(), vec!["app/admin", "app/cli"]);
+        assert!(feature_gated_binaries(&metadata(&["admin", "cli"])).is_empty());
+    }
+}
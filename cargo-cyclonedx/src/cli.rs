@@ -1,12 +1,19 @@
 use cargo_cyclonedx::{
     config::{
-        CdxExtension, CustomPrefix, Features, IncludedDependencies, LicenseParserOptions,
-        OutputOptions, ParseMode, Pattern, PlatformSuffix, Prefix, PrefixError, SbomConfig, Target,
+        BuildTimeDependencies, CdxExtension, CustomPrefix, DenyCheck, Features,
+        IncludedDependencies, LicenseParserOptions, OutputOptions, PackageFilter, ParseMode,
+        Pattern, PlatformSuffix, Prefix, PrefixError, SbomConfig, Signing, SourceHashing, Target,
+        Validation, YankedCheck,
     },
-    format::Format,
+    dtrack::{DependencyTrack, Project},
+    format::{Compression, Format},
+    generator::SbomFile,
     platform::host_platform,
 };
 use clap::{ArgAction, ArgGroup, Parser};
+use cyclonedx_bom::models::bom::SpecVersion;
+use cyclonedx_bom::models::component::Classification;
+use cyclonedx_bom::models::signature::Algorithm;
 use std::collections::HashSet;
 use std::iter::FromIterator;
 use std::path;
@@ -29,10 +36,62 @@ pub struct Args {
     #[clap(long = "manifest-path", value_name = "PATH")]
     pub manifest_path: Option<path::PathBuf>,
 
+    /// Read the options from this file instead of the cyclonedx.toml found next to the manifest
+    /// or in its parent directories. Options given on the command line take precedence
+    #[clap(long = "config", value_name = "PATH")]
+    pub config: Option<path::PathBuf>,
+
+    /// Generate the SBOM from this Cargo.lock alone, without resolving the dependencies
+    /// with Cargo. The manifest is optional in this mode. The SBOM lists the dependencies
+    /// of all platforms, including dev-dependencies
+    #[clap(
+        long = "lockfile",
+        value_name = "PATH",
+        conflicts_with_all = ["target", "features", "all_features", "no_default_features"]
+    )]
+    pub lockfile: Option<path::PathBuf>,
+
+    /// Generate the SBOM of a binary that has already been built, from the dependencies
+    /// `cargo auditable` embeds in it or, for a binary installed with `cargo install`,
+    /// the package it was built from. The SBOMs are written to the current directory
+    #[clap(
+        long = "binary",
+        value_name = "PATH",
+        action = ArgAction::Append,
+        conflicts_with_all = ["lockfile", "target", "features", "all_features", "no_default_features"]
+    )]
+    pub binary: Vec<path::PathBuf>,
+
+    /// Generate the SBOMs of all binaries installed with `cargo install`, like --binary
+    #[clap(
+        long = "installed",
+        conflicts_with_all = ["lockfile", "target", "features", "all_features", "no_default_features"]
+    )]
+    pub installed: bool,
+
+    /// Run without accessing the network, e.g. with vendored sources
+    #[clap(long = "offline")]
+    pub offline: bool,
+
     /// Output BOM format: json, xml
     #[clap(long = "format", short = 'f', value_name = "FORMAT")]
     pub format: Option<Format>,
 
+    /// The CycloneDX spec versions to write a document for: 1.3, 1.4, 1.5.
+    /// Several versions imply --output-cdx and add the version to the filenames.
+    /// Defaults to the lowest version that can represent the whole SBOM
+    #[clap(
+        long = "spec-version",
+        value_name = "VERSION",
+        value_delimiter = ',',
+        action = ArgAction::Append
+    )]
+    pub spec_version: Vec<SpecVersion>,
+
+    /// Compress the BOM file: gzip, zstd
+    #[clap(long = "compress", value_name = "COMPRESSION")]
+    pub compress: Option<Compression>,
+
     /// Use verbose output (-vv very verbose/build.rs output)
     #[clap(long = "verbose", short = 'v', action = clap::ArgAction::Count)]
     pub verbose: u8,
@@ -76,6 +135,25 @@ Defaults to the host target, as printed by 'rustc -vV'"
     #[clap(name = "top-level", long = "top-level", conflicts_with = "all")]
     pub top_level: bool,
 
+    /// What to do with build dependencies, procedural macros and the crates only they depend on,
+    /// which run during the build but do not ship in the artifacts: mark, or exclude them
+    #[clap(long = "build-time-dependencies", value_name = "ACTION")]
+    pub build_time_dependencies: Option<BuildTimeDependencies>,
+
+    /// Include the dev-dependencies of the package, which are only used by its tests,
+    /// examples and benchmarks, with the excluded scope
+    #[clap(long = "dev-dependencies")]
+    pub dev_dependencies: bool,
+
+    /// Resolve the dependencies with Cargo's feature resolver, as a build does, leaving out
+    /// the crates and dependencies `cargo metadata` reports only for other targets or for
+    /// features enabled by dev-dependencies
+    #[clap(
+        long = "exact-features",
+        conflicts_with_all = ["lockfile", "binary", "installed"]
+    )]
+    pub exact_features: bool,
+
     /// Prepend file extension with .cdx
     #[clap(long = "output-cdx")]
     pub output_cdx: bool,
@@ -105,6 +183,110 @@ Defaults to the host target, as printed by 'rustc -vV'"
     /// Add license names which will not be warned about when parsing them as a SPDX expression fails
     #[clap(long = "license-accept-named", action=ArgAction::Append)]
     pub license_accept_named: Vec<String>,
+
+    /// Add vulnerabilities from a local checkout of the RustSec advisory database. Implies spec version 1.4
+    #[clap(long = "advisory-db", value_name = "PATH")]
+    pub advisory_db: Option<path::PathBuf>,
+
+    /// Look up the resolved versions of the dependencies in their registry index
+    /// and mark the yanked ones
+    #[clap(long = "check-yanked")]
+    pub check_yanked: bool,
+
+    /// Fail if a dependency has been yanked from its registry. Implies --check-yanked
+    #[clap(long = "fail-on-yanked")]
+    pub fail_on_yanked: bool,
+
+    /// Look up crates.io crates in this index instead of https://index.crates.io/:
+    /// the URL of a sparse index or a local directory with the same layout. Implies --check-yanked.
+    /// With --offline, the check needs a local directory
+    #[clap(long = "crates-io-index", value_name = "URL_OR_PATH")]
+    pub crates_io_index: Option<String>,
+
+    /// Check the licenses and sources of the dependencies against the policy of this cargo-deny
+    /// configuration and record the violations
+    #[clap(long = "deny-config", value_name = "PATH")]
+    pub deny_config: Option<path::PathBuf>,
+
+    /// Fail if a dependency violates the cargo-deny policy at the deny level
+    #[clap(long = "fail-on-deny", requires = "deny_config")]
+    pub fail_on_deny: bool,
+
+    /// Sign the SBOM with the key in this file: PEM encoded PKCS #8 for ES256 and Ed25519,
+    /// PKCS #8 or PKCS #1 for RSA, the raw secret for HMAC. JSON documents get a JSF signature,
+    /// XML documents an enveloped XML-DSig signature. Implies spec version 1.4
+    #[clap(long = "sign-key", value_name = "PATH")]
+    pub sign_key: Option<path::PathBuf>,
+
+    /// The algorithm to sign the SBOM with: ES256, Ed25519, RS256, RS384, RS512, PS256, PS384,
+    /// PS512, HS256, HS384, HS512
+    #[clap(
+        long = "sign-algorithm",
+        value_name = "ALGORITHM",
+        default_value = "ES256",
+        requires = "sign_key"
+    )]
+    pub sign_algorithm: Algorithm,
+
+    /// Attach the SHA-256 hashes of the binaries and libraries built with this Cargo profile,
+    /// e.g. 'release', to the components describing the targets of the package
+    #[clap(long = "hash-artifacts", value_name = "PROFILE")]
+    pub hash_artifacts: Option<String>,
+
+    /// Hash the source files `cargo package` would include and record the hashes on the component
+    /// the SBOM describes: a single hash of all files (combined), or that and the hash of every file (files)
+    #[clap(long = "hash-sources", value_name = "MODE")]
+    pub hash_sources: Option<SourceHashing>,
+
+    /// Describe the build with this Cargo profile, e.g. 'release', in the formulation section:
+    /// the equivalent Cargo command line, the enabled features and the relevant environment
+    /// variables. Implies spec version 1.5
+    #[clap(long = "describe-build", value_name = "PROFILE")]
+    pub describe_build: Option<String>,
+
+    /// The type of the component the SBOM describes, e.g. 'firmware', instead of 'application'
+    /// or 'library' as derived from the targets of the package
+    #[clap(long = "component-type", value_name = "TYPE")]
+    pub component_type: Option<Classification>,
+
+    /// What to do if the generated SBOM fails validation: fail, or warn and write it anyway
+    #[clap(long = "on-invalid", value_name = "ACTION")]
+    pub on_invalid: Option<Validation>,
+
+    /// Only list the crates whose names match one of these glob patterns, e.g. 'serde*'.
+    /// Their dependencies are attached to the crates depending on them
+    #[clap(long = "include", value_name = "GLOB", action=ArgAction::Append)]
+    pub include: Vec<glob::Pattern>,
+
+    /// Omit the crates whose names match one of these glob patterns, e.g. 'internal-*'.
+    /// Their dependencies are attached to the crates depending on them
+    #[clap(long = "exclude", value_name = "GLOB", action=ArgAction::Append)]
+    pub exclude: Vec<glob::Pattern>,
+
+    /// Upload the SBOM to the Dependency-Track server at this URL, e.g. 'https://dtrack.example.com'
+    #[clap(long = "upload", value_name = "URL")]
+    pub upload: Option<String>,
+
+    /// The API key to upload the SBOM to Dependency-Track with
+    #[clap(
+        long = "api-key",
+        value_name = "KEY",
+        env = "DTRACK_API_KEY",
+        hide_env_values = true
+    )]
+    pub api_key: Option<String>,
+
+    /// The name of the Dependency-Track project to upload to. Defaults to the name of the package
+    #[clap(long = "project-name", value_name = "NAME", requires = "upload")]
+    pub project_name: Option<String>,
+
+    /// The version of the Dependency-Track project to upload to. Defaults to the version of the package
+    #[clap(long = "project-version", value_name = "VERSION", requires = "upload")]
+    pub project_version: Option<String>,
+
+    /// Create the Dependency-Track project if it does not exist yet
+    #[clap(long = "auto-create", requires = "upload")]
+    pub auto_create: bool,
 }
 
 impl Args {
@@ -149,14 +331,22 @@ impl Args {
                 })
             };
 
-        let target_string = self.target.clone().unwrap_or_else(host_platform);
+        // A lockfile records the dependencies of all platforms, and a binary those of its own
+        let target_string = match self.lockfile.is_some() || self.inspects_binaries() {
+            true => "all".to_string(),
+            false => self.target.clone().unwrap_or_else(host_platform),
+        };
         let target = Some(if &target_string == "all" {
             Target::AllTargets
         } else {
             Target::SingleTarget(target_string)
         });
 
-        let mut cdx_extension = match self.output_cdx {
+        let mut spec_versions = self.spec_version.clone();
+        spec_versions.sort();
+        spec_versions.dedup();
+
+        let mut cdx_extension = match self.output_cdx || spec_versions.len() > 1 {
             true => Some(CdxExtension::Included),
             false => None,
         };
@@ -196,21 +386,107 @@ impl Args {
             accept_named: HashSet::from_iter(self.license_accept_named.clone()),
         });
 
+        let package_filter = Some(PackageFilter {
+            include: self.include.clone(),
+            exclude: self.exclude.clone(),
+        })
+        .filter(|filter| !filter.is_empty());
+
+        let signing = self.sign_key.as_ref().map(|key| Signing {
+            key: key.clone(),
+            algorithm: self.sign_algorithm,
+        });
+
+        // Signatures were only added in CycloneDX 1.4
+        if signing.is_some() && spec_versions.contains(&SpecVersion::V1_3) {
+            return Err(ArgsError::SigningRequiresSpecVersion);
+        }
+
+        let yanked_check = (self.check_yanked
+            || self.fail_on_yanked
+            || self.crates_io_index.is_some())
+        .then(|| YankedCheck {
+            crates_io_index: self.crates_io_index.clone(),
+            fail: self.fail_on_yanked,
+        });
+
         Ok(SbomConfig {
             format: self.format,
+            compression: self.compress,
+            spec_versions: Some(spec_versions).filter(|versions| !versions.is_empty()),
             included_dependencies,
             output_options,
             features,
             target,
             license_parser,
+            advisory_db: self.advisory_db.clone(),
+            signing,
+            artifact_profile: self.hash_artifacts.clone(),
+            build_profile: self.describe_build.clone(),
+            package_filter,
+            license_overrides: None,
+            supplier: None,
+            manufacture: None,
+            component_type: self.component_type.clone(),
+            validation: self.on_invalid,
+            lockfile: self.lockfile.clone(),
+            build_time_dependencies: self.build_time_dependencies,
+            dev_dependencies: Some(self.dev_dependencies).filter(|dev| *dev),
+            yanked_check,
+            source_hashing: self.hash_sources,
+            exact_features: Some(self.exact_features).filter(|exact| *exact),
+            deny_check: self.deny_config.clone().map(|path| DenyCheck {
+                path,
+                fail: self.fail_on_deny,
+            }),
+            offline: Some(self.offline).filter(|offline| *offline),
         })
     }
 }
 
+impl Args {
+    /// Whether the SBOMs describe binaries that have already been built
+    pub fn inspects_binaries(&self) -> bool {
+        !self.binary.is_empty() || self.installed
+    }
+
+    /// The Dependency-Track server to upload the SBOMs to, if any
+    pub fn dependency_track(&self) -> Result<Option<DependencyTrack>, ArgsError> {
+        match (&self.upload, &self.api_key) {
+            (Some(url), Some(api_key)) => Ok(Some(DependencyTrack::new(url, api_key))),
+            (Some(_), None) => Err(ArgsError::UploadRequiresApiKey),
+            (None, _) => Ok(None),
+        }
+    }
+
+    /// The Dependency-Track project to upload the SBOM written to `file` to
+    pub fn project(&self, file: &SbomFile) -> Project {
+        Project {
+            name: self
+                .project_name
+                .clone()
+                .unwrap_or_else(|| file.name.clone()),
+            version: self
+                .project_version
+                .clone()
+                .or_else(|| file.version.clone()),
+            auto_create: self.auto_create,
+        }
+    }
+}
+
 #[derive(Error, Debug, PartialEq, Eq)]
 pub enum ArgsError {
     #[error("Invalid prefix from CLI")]
     CustomPrefixError(#[from] PrefixError),
+
+    #[error("Signing is only supported for spec version 1.4 and later")]
+    SigningRequiresSpecVersion,
+
+    #[error(
+        "Uploading to Dependency-Track requires an API key, set with --api-key or DTRACK_API_KEY"
+    )]
+    UploadRequiresApiKey,
 }
 
 #[cfg(test)]
@@ -245,6 +521,176 @@ mod tests {
         assert!(!contains_feature(&config, ""));
     }
 
+    #[test]
+    fn parse_signing() {
+        let config = parse_to_config(&["cyclonedx"]);
+        assert!(config.signing.is_none());
+
+        let config = parse_to_config(&["cyclonedx", "--sign-key", "key.pem"]);
+        assert_eq!(
+            config.signing,
+            Some(Signing {
+                key: "key.pem".into(),
+                algorithm: Algorithm::ES256
+            })
+        );
+        assert_eq!(config.format, None);
+
+        let config = parse_to_config(&[
+            "cyclonedx",
+            "--sign-key",
+            "key.pem",
+            "--sign-algorithm",
+            "Ed25519",
+        ]);
+        assert_eq!(config.signing.unwrap().algorithm, Algorithm::Ed25519);
+
+        let config = parse_to_config(&["cyclonedx", "--sign-key", "key", "--format", "xml"]);
+        assert_eq!(config.format, Some(Format::Xml));
+
+        assert!(Args::try_parse_from(["cyclonedx", "--sign-algorithm", "HS256"]).is_err());
+    }
+
+    #[test]
+    fn parse_package_filter() {
+        let config = parse_to_config(&["cyclonedx"]);
+        assert!(config.package_filter.is_none());
+
+        let config = parse_to_config(&[
+            "cyclonedx",
+            "--exclude",
+            "internal-*",
+            "--exclude=*-fixtures",
+            "--include",
+            "*",
+        ]);
+        let filter = config.package_filter.unwrap();
+        assert_eq!(filter.include, vec![glob::Pattern::new("*").unwrap()]);
+        assert_eq!(filter.exclude.len(), 2);
+        assert!(!filter.allows("internal-macros"));
+
+        assert!(Args::try_parse_from(["cyclonedx", "--exclude", "[invalid"]).is_err());
+    }
+
+    #[test]
+    fn parse_offline() {
+        assert_eq!(parse_to_config(&["cyclonedx"]).offline, None);
+        assert!(parse_to_config(&["cyclonedx", "--offline"]).offline());
+    }
+
+    #[test]
+    fn parse_yanked_check() {
+        let config = parse_to_config(&["cyclonedx"]);
+        assert!(config.yanked_check.is_none());
+
+        let config = parse_to_config(&["cyclonedx", "--check-yanked"]);
+        assert_eq!(config.yanked_check, Some(YankedCheck::default()));
+
+        let config = parse_to_config(&[
+            "cyclonedx",
+            "--fail-on-yanked",
+            "--crates-io-index",
+            "https://mirror.example.com/index/",
+        ]);
+        assert_eq!(
+            config.yanked_check,
+            Some(YankedCheck {
+                crates_io_index: Some("https://mirror.example.com/index/".to_string()),
+                fail: true,
+            })
+        );
+    }
+
+    #[test]
+    fn parse_deny_check() {
+        let config = parse_to_config(&["cyclonedx"]);
+        assert!(config.deny_check.is_none());
+
+        let config =
+            parse_to_config(&["cyclonedx", "--deny-config", "deny.toml", "--fail-on-deny"]);
+        assert_eq!(
+            config.deny_check,
+            Some(DenyCheck {
+                path: path::PathBuf::from("deny.toml"),
+                fail: true,
+            })
+        );
+
+        assert!(Args::try_parse_from(["cyclonedx", "--fail-on-deny"]).is_err());
+    }
+
+    #[test]
+    fn parse_spec_versions() {
+        let config = parse_to_config(&["cyclonedx"]);
+        assert!(config.spec_versions.is_none());
+        assert!(config.output_options.is_none());
+
+        let config = parse_to_config(&["cyclonedx", "--spec-version", "1.4"]);
+        assert_eq!(config.spec_versions, Some(vec![SpecVersion::V1_4]));
+        assert!(config.output_options.is_none());
+
+        let config = parse_to_config(&[
+            "cyclonedx",
+            "--spec-version",
+            "1.5,1.3",
+            "--spec-version=1.5",
+        ]);
+        assert_eq!(
+            config.spec_versions,
+            Some(vec![SpecVersion::V1_3, SpecVersion::V1_5])
+        );
+        assert_eq!(
+            config.output_options.unwrap().cdx_extension,
+            CdxExtension::Included
+        );
+
+        let args = Args::parse_from(["cyclonedx", "--sign-key", "key", "--spec-version", "1.3"]);
+        assert_eq!(args.as_config(), Err(ArgsError::SigningRequiresSpecVersion));
+
+        assert!(Args::try_parse_from(["cyclonedx", "--spec-version", "1.2"]).is_err());
+    }
+
+    #[test]
+    fn parse_upload() {
+        let args = Args::parse_from(["cyclonedx"]);
+        assert!(args.dependency_track().unwrap().is_none());
+
+        let args = Args::parse_from(["cyclonedx", "--upload", "https://dtrack.example.com"]);
+        if std::env::var_os("DTRACK_API_KEY").is_none() {
+            assert_eq!(
+                args.dependency_track().unwrap_err(),
+                ArgsError::UploadRequiresApiKey
+            );
+        }
+
+        let args = Args::parse_from([
+            "cyclonedx",
+            "--upload",
+            "https://dtrack.example.com",
+            "--api-key",
+            "key",
+            "--project-version",
+            "main",
+            "--auto-create",
+        ]);
+        assert!(args.dependency_track().unwrap().is_some());
+        let file = SbomFile {
+            path: "bom.xml".into(),
+            name: "app".to_string(),
+            version: Some("0.1.0".to_string()),
+        };
+        assert_eq!(
+            args.project(&file),
+            Project {
+                name: "app".to_string(),
+                version: Some("main".to_string()),
+                auto_create: true,
+            }
+        );
+
+        assert!(Args::try_parse_from(["cyclonedx", "--auto-create"]).is_err());
+    }
+
     fn parse_to_config(args: &[&str]) -> SbomConfig {
         Args::parse_from(args.iter()).as_config().unwrap()
     }
@@ -1,5 +1,6 @@
 use serde::Deserialize;
 use std::collections::HashSet;
+use std::path::PathBuf;
 use std::str::FromStr;
 use thiserror::Error;
 
@@ -20,16 +21,61 @@ use thiserror::Error;
  *
  * SPDX-License-Identifier: Apache-2.0
  */
-use crate::format::Format;
+use crate::format::{Compression, Format};
+use cyclonedx_bom::external_models::email::Email;
+use cyclonedx_bom::external_models::normalized_string::NormalizedString;
+use cyclonedx_bom::external_models::spdx::SpdxExpression;
+use cyclonedx_bom::external_models::uri::Uri;
+use cyclonedx_bom::models::bom::SpecVersion;
+use cyclonedx_bom::models::component::Classification;
+use cyclonedx_bom::models::organization::{OrganizationalContact, OrganizationalEntity};
+use cyclonedx_bom::models::signature::Algorithm;
+use std::convert::TryFrom;
 
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct SbomConfig {
     pub format: Option<Format>,
+    pub compression: Option<Compression>,
+    /// The spec versions to write a document for, in ascending order
+    pub spec_versions: Option<Vec<SpecVersion>>,
     pub included_dependencies: Option<IncludedDependencies>,
     pub output_options: Option<OutputOptions>,
     pub features: Option<Features>,
     pub target: Option<Target>,
     pub license_parser: Option<LicenseParserOptions>,
+    /// Path to a local checkout of the RustSec advisory database
+    pub advisory_db: Option<PathBuf>,
+    pub signing: Option<Signing>,
+    /// The Cargo profile whose build artifacts get hashed
+    pub artifact_profile: Option<String>,
+    /// The Cargo profile of the build described in the formulation section
+    pub build_profile: Option<String>,
+    pub package_filter: Option<PackageFilter>,
+    pub license_overrides: Option<LicenseOverrides>,
+    /// The organization that supplies the crates of the workspace
+    pub supplier: Option<OrganizationalEntity>,
+    /// The organization that builds the crates of the workspace
+    pub manufacture: Option<OrganizationalEntity>,
+    /// The type of the component the SBOM describes, instead of the one derived from its targets
+    pub component_type: Option<Classification>,
+    /// What to do if a generated SBOM fails validation
+    pub validation: Option<Validation>,
+    /// The lockfile the SBOM is generated from, instead of the one next to the manifest
+    pub lockfile: Option<PathBuf>,
+    /// What to do with the crates that only run during the build
+    pub build_time_dependencies: Option<BuildTimeDependencies>,
+    /// Whether the dev-dependencies of the package are included
+    pub dev_dependencies: Option<bool>,
+    /// Whether and how the dependencies are checked for yanked versions
+    pub yanked_check: Option<YankedCheck>,
+    /// Whether the packaged source files of the package are hashed
+    pub source_hashing: Option<SourceHashing>,
+    /// Whether the dependencies are resolved with Cargo's feature resolver, as in a build
+    pub exact_features: Option<bool>,
+    /// The cargo-deny policy the licenses and sources of the dependencies are checked against
+    pub deny_check: Option<DenyCheck>,
+    /// Whether Cargo and the checks run without accessing the network
+    pub offline: Option<bool>,
 }
 
 impl SbomConfig {
@@ -40,6 +86,11 @@ impl SbomConfig {
     pub fn merge(&self, other: &SbomConfig) -> SbomConfig {
         SbomConfig {
             format: other.format.or(self.format),
+            compression: other.compression.or(self.compression),
+            spec_versions: other
+                .spec_versions
+                .clone()
+                .or_else(|| self.spec_versions.clone()),
             included_dependencies: other.included_dependencies.or(self.included_dependencies),
             output_options: other
                 .output_options
@@ -52,9 +103,99 @@ impl SbomConfig {
                 .clone()
                 .map(|other| self.license_parser.clone().unwrap_or_default().merge(other))
                 .or_else(|| self.license_parser.clone()),
+            advisory_db: other
+                .advisory_db
+                .clone()
+                .or_else(|| self.advisory_db.clone()),
+            signing: other.signing.clone().or_else(|| self.signing.clone()),
+            artifact_profile: other
+                .artifact_profile
+                .clone()
+                .or_else(|| self.artifact_profile.clone()),
+            build_profile: other
+                .build_profile
+                .clone()
+                .or_else(|| self.build_profile.clone()),
+            package_filter: other
+                .package_filter
+                .clone()
+                .map(|other| self.package_filter.clone().unwrap_or_default().merge(other))
+                .or_else(|| self.package_filter.clone()),
+            license_overrides: other
+                .license_overrides
+                .clone()
+                .map(|other| other.merge(self.license_overrides.clone().unwrap_or_default()))
+                .or_else(|| self.license_overrides.clone()),
+            supplier: other.supplier.clone().or_else(|| self.supplier.clone()),
+            manufacture: other
+                .manufacture
+                .clone()
+                .or_else(|| self.manufacture.clone()),
+            component_type: other
+                .component_type
+                .clone()
+                .or_else(|| self.component_type.clone()),
+            validation: other.validation.or(self.validation),
+            lockfile: other.lockfile.clone().or_else(|| self.lockfile.clone()),
+            build_time_dependencies: other
+                .build_time_dependencies
+                .or(self.build_time_dependencies),
+            dev_dependencies: other.dev_dependencies.or(self.dev_dependencies),
+            yanked_check: other
+                .yanked_check
+                .clone()
+                .or_else(|| self.yanked_check.clone()),
+            source_hashing: other.source_hashing.or(self.source_hashing),
+            exact_features: other.exact_features.or(self.exact_features),
+            deny_check: other.deny_check.clone().or_else(|| self.deny_check.clone()),
+            offline: other.offline.or(self.offline),
         }
     }
 
+    /// Reads the configuration from the `[*.metadata.cyclonedx]` table of a manifest, as output by `cargo metadata`
+    pub fn from_manifest_metadata(
+        metadata: &serde_json::Value,
+    ) -> Result<Self, ManifestConfigError> {
+        let table = match metadata.get("cyclonedx") {
+            Some(table) => table,
+            None => return Ok(Self::empty_config()),
+        };
+        let organization = |field: &'static str| {
+            table
+                .get(field)
+                .map(parse_organization)
+                .transpose()
+                .map_err(|reason| ManifestConfigError::InvalidOrganization { field, reason })
+        };
+        let patterns = |field: &'static str| {
+            table
+                .get(field)
+                .map(parse_patterns)
+                .transpose()
+                .map(Option::unwrap_or_default)
+                .map_err(|reason| ManifestConfigError::InvalidPatterns { field, reason })
+        };
+        let package_filter = PackageFilter {
+            include: patterns("include")?,
+            exclude: patterns("exclude")?,
+        };
+
+        Ok(Self {
+            license_overrides: table
+                .get("license-overrides")
+                .map(LicenseOverrides::from_table)
+                .transpose()?,
+            supplier: organization("supplier")?,
+            manufacture: organization("manufacture")?,
+            component_type: table
+                .get("component-type")
+                .map(parse_component_type)
+                .transpose()?,
+            package_filter: (!package_filter.is_empty()).then_some(package_filter),
+            ..Self::empty_config()
+        })
+    }
+
     pub fn format(&self) -> Format {
         self.format.unwrap_or_default()
     }
@@ -63,6 +204,22 @@ impl SbomConfig {
         self.included_dependencies.unwrap_or_default()
     }
 
+    pub fn build_time_dependencies(&self) -> BuildTimeDependencies {
+        self.build_time_dependencies.unwrap_or_default()
+    }
+
+    pub fn dev_dependencies(&self) -> bool {
+        self.dev_dependencies.unwrap_or_default()
+    }
+
+    pub fn exact_features(&self) -> bool {
+        self.exact_features.unwrap_or_default()
+    }
+
+    pub fn offline(&self) -> bool {
+        self.offline.unwrap_or_default()
+    }
+
     pub fn output_options(&self) -> OutputOptions {
         self.output_options.clone().unwrap_or_default()
     }
@@ -70,6 +227,137 @@ impl SbomConfig {
     pub fn license_parser(&self) -> LicenseParserOptions {
         self.license_parser.clone().unwrap_or_default()
     }
+
+    pub fn package_filter(&self) -> PackageFilter {
+        self.package_filter.clone().unwrap_or_default()
+    }
+
+    pub fn license_overrides(&self) -> LicenseOverrides {
+        self.license_overrides.clone().unwrap_or_default()
+    }
+
+    pub fn validation(&self) -> Validation {
+        self.validation.unwrap_or_default()
+    }
+}
+
+/// Selects the crates that are listed as components by matching their names against glob patterns
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PackageFilter {
+    /// If not empty, only crates matching one of these patterns are listed
+    pub include: Vec<glob::Pattern>,
+    /// Crates matching one of these patterns are never listed
+    pub exclude: Vec<glob::Pattern>,
+}
+
+impl PackageFilter {
+    pub fn is_empty(&self) -> bool {
+        self.include.is_empty() && self.exclude.is_empty()
+    }
+
+    /// Whether the crate with this name should be listed
+    pub fn allows(&self, name: &str) -> bool {
+        (self.include.is_empty() || self.include.iter().any(|p| p.matches(name)))
+            && !self.exclude.iter().any(|p| p.matches(name))
+    }
+
+    pub fn merge(mut self, other: Self) -> Self {
+        self.include.extend(other.include);
+        self.exclude.extend(other.exclude);
+        self
+    }
+}
+
+/// Signs the generated SBOMs with the key at `key`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Signing {
+    pub key: PathBuf,
+    pub algorithm: Algorithm,
+}
+
+/// What to do if a generated SBOM fails validation
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Validation {
+    /// Report the failures and write no SBOM
+    #[default]
+    Fail,
+    /// Report the failures and write the SBOM anyway
+    Warn,
+}
+
+impl FromStr for Validation {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "fail" => Ok(Self::Fail),
+            "warn" => Ok(Self::Warn),
+            _ => Err(format!("Expected fail or warn, got `{}`", s)),
+        }
+    }
+}
+
+/// What to do with build dependencies, procedural macros and their dependencies,
+/// which run during the build but are not part of the built artifacts
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum BuildTimeDependencies {
+    /// List them with the `excluded` scope
+    #[default]
+    Mark,
+    /// Leave them out of the SBOM
+    Exclude,
+}
+
+impl FromStr for BuildTimeDependencies {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "mark" => Ok(Self::Mark),
+            "exclude" => Ok(Self::Exclude),
+            _ => Err(format!("Expected mark or exclude, got `{}`", s)),
+        }
+    }
+}
+
+/// How the source files that `cargo package` would include are hashed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SourceHashing {
+    /// A single hash of all files
+    Combined,
+    /// The combined hash and the hash of every file
+    Files,
+}
+
+impl FromStr for SourceHashing {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "combined" => Ok(Self::Combined),
+            "files" => Ok(Self::Files),
+            _ => Err(format!("Expected combined or files, got `{}`", s)),
+        }
+    }
+}
+
+/// Looks up the resolved versions of the dependencies in their registry index to find the yanked ones
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct YankedCheck {
+    /// The crates.io index to use instead of <https://index.crates.io/>:
+    /// the URL of a sparse index, such as a mirror, or a local directory with the same layout
+    pub crates_io_index: Option<String>,
+    /// Fail the generation if a dependency is yanked, instead of only recording it
+    pub fail: bool,
+}
+
+/// Checks the licenses and sources of the dependencies against the policy of a cargo-deny configuration
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DenyCheck {
+    /// The `deny.toml` file
+    pub path: PathBuf,
+    /// Fail the generation if a policy is violated at the `deny` level, instead of only recording it
+    pub fail: bool,
 }
 
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
@@ -243,6 +531,228 @@ impl LicenseParserOptions {
     }
 }
 
+/// Corrected license expressions for crates with wrong or missing license metadata,
+/// configured in the `license-overrides` table of `[workspace.metadata.cyclonedx]`
+/// or `[package.metadata.cyclonedx]`:
+///
+/// ```toml
+/// [workspace.metadata.cyclonedx.license-overrides]
+/// "ring@0.16.20" = "MIT AND ISC AND OpenSSL"
+/// "pkg:cargo/internal-*" = "LicenseRef-Proprietary"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LicenseOverrides(pub Vec<LicenseOverride>);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LicenseOverride {
+    /// The key of the entry in the table, e.g. `ring@0.16.20`
+    pub rule: String,
+    pub selector: PackageSelector,
+    pub expression: SpdxExpression,
+}
+
+/// Matches crates by name, by name and version or by a glob pattern for their purl
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PackageSelector {
+    Name(String),
+    NameVersion(String, String),
+    Purl(glob::Pattern),
+}
+
+impl FromStr for PackageSelector {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.starts_with("pkg:") {
+            return glob::Pattern::new(s)
+                .map(Self::Purl)
+                .map_err(|e| format!("Invalid purl pattern `{}`: {}", s, e));
+        }
+        match s.split_once('@') {
+            Some((name, version)) if !name.is_empty() && !version.is_empty() => {
+                Ok(Self::NameVersion(name.to_string(), version.to_string()))
+            }
+            Some(_) => Err(format!("Expected name@version, got `{}`", s)),
+            None if !s.is_empty() => Ok(Self::Name(s.to_string())),
+            None => Err("Expected a crate name, got an empty string".to_string()),
+        }
+    }
+}
+
+impl PackageSelector {
+    pub fn matches(&self, name: &str, version: &str, purl: Option<&str>) -> bool {
+        match self {
+            Self::Name(n) => n == name,
+            Self::NameVersion(n, v) => n == name && v == version,
+            Self::Purl(pattern) => purl.is_some_and(|purl| pattern.matches(purl)),
+        }
+    }
+
+    /// More specific selectors take precedence
+    fn specificity(&self) -> u8 {
+        match self {
+            Self::NameVersion(..) => 2,
+            Self::Purl(_) => 1,
+            Self::Name(_) => 0,
+        }
+    }
+}
+
+impl LicenseOverrides {
+    /// Reads the overrides from the `license-overrides` table
+    pub fn from_table(table: &serde_json::Value) -> Result<Self, LicenseOverrideError> {
+        let table = table.as_object().ok_or(LicenseOverrideError::NotATable)?;
+
+        let overrides = table
+            .iter()
+            .map(|(rule, expression)| {
+                let invalid = |reason: String| LicenseOverrideError::InvalidOverride {
+                    rule: rule.clone(),
+                    reason,
+                };
+                let selector = rule.parse().map_err(invalid)?;
+                let expression = expression
+                    .as_str()
+                    .ok_or_else(|| invalid("the license is not a string".to_string()))?;
+                let expression = SpdxExpression::try_from(expression.to_string())
+                    .map_err(|e| invalid(e.to_string()))?;
+                Ok(LicenseOverride {
+                    rule: rule.clone(),
+                    selector,
+                    expression,
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self(overrides))
+    }
+
+    /// Finds the most specific override for a crate. Among equally specific ones, the first wins.
+    pub fn find(&self, name: &str, version: &str, purl: Option<&str>) -> Option<&LicenseOverride> {
+        self.0
+            .iter()
+            .filter(|o| o.selector.matches(name, version, purl))
+            .fold(
+                None,
+                |best: Option<&LicenseOverride>, candidate| match best {
+                    Some(best)
+                        if best.selector.specificity() >= candidate.selector.specificity() =>
+                    {
+                        Some(best)
+                    }
+                    _ => Some(candidate),
+                },
+            )
+    }
+
+    /// Combines both sets of overrides, preferring the ones in `self`
+    pub fn merge(mut self, other: Self) -> Self {
+        self.0.extend(other.0);
+        self
+    }
+}
+
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum LicenseOverrideError {
+    #[error("`license-overrides` must be a table")]
+    NotATable,
+
+    #[error("Invalid license override `{rule}`: {reason}")]
+    InvalidOverride { rule: String, reason: String },
+}
+
+/// An organization as configured in the `supplier` or `manufacture` table
+/// of `[workspace.metadata.cyclonedx]` or `[package.metadata.cyclonedx]`:
+///
+/// ```toml
+/// [workspace.metadata.cyclonedx.supplier]
+/// name = "Example Inc."
+/// url = ["https://example.com"]
+/// contact = [{ name = "Jane Doe", email = "jane@example.com" }]
+/// ```
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct OrganizationConfig {
+    name: Option<String>,
+    #[serde(default)]
+    url: Vec<String>,
+    #[serde(default)]
+    contact: Vec<ContactConfig>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ContactConfig {
+    name: Option<String>,
+    email: Option<String>,
+    phone: Option<String>,
+}
+
+fn parse_organization(value: &serde_json::Value) -> Result<OrganizationalEntity, String> {
+    let config = OrganizationConfig::deserialize(value).map_err(|e| e.to_string())?;
+
+    let url = config
+        .url
+        .into_iter()
+        .map(|url| Uri::try_from(url.clone()).map_err(|e| format!("Invalid URL `{}`: {}", url, e)))
+        .collect::<Result<Vec<_>, _>>()?;
+    let contact = config
+        .contact
+        .into_iter()
+        .map(|contact| {
+            let email = contact
+                .email
+                .as_deref()
+                .map(|email| Email::new(email).map_err(|_| format!("Invalid email `{}`", email)))
+                .transpose()?;
+            let mut organizational_contact = OrganizationalContact::default();
+            organizational_contact.name = contact.name.as_deref().map(NormalizedString::new);
+            organizational_contact.email = email;
+            organizational_contact.phone = contact.phone.as_deref().map(NormalizedString::new);
+            Ok(organizational_contact)
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+
+    let mut entity = OrganizationalEntity::default();
+    entity.name = config.name.as_deref().map(NormalizedString::new);
+    entity.url = (!url.is_empty()).then_some(url);
+    entity.contact = (!contact.is_empty()).then_some(contact);
+    Ok(entity)
+}
+
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum ManifestConfigError {
+    #[error(transparent)]
+    LicenseOverrideError(#[from] LicenseOverrideError),
+
+    #[error("Invalid `{field}`: {reason}")]
+    InvalidOrganization { field: &'static str, reason: String },
+
+    #[error("Invalid `component-type`: {0}")]
+    InvalidComponentType(String),
+
+    #[error("Invalid `{field}`: {reason}")]
+    InvalidPatterns { field: &'static str, reason: String },
+}
+
+fn parse_component_type(value: &serde_json::Value) -> Result<Classification, ManifestConfigError> {
+    value
+        .as_str()
+        .ok_or_else(|| format!("expected a string, got {}", value))
+        .and_then(Classification::from_str)
+        .map_err(ManifestConfigError::InvalidComponentType)
+}
+
+fn parse_patterns(value: &serde_json::Value) -> Result<Vec<glob::Pattern>, String> {
+    Vec::<String>::deserialize(value)
+        .map_err(|e| e.to_string())?
+        .iter()
+        .map(|pattern| {
+            glob::Pattern::new(pattern).map_err(|e| format!("Invalid pattern `{}`: {}", pattern, e))
+        })
+        .collect()
+}
+
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
 #[serde(rename_all(deserialize = "kebab-case"))]
 pub enum ParseMode {
@@ -257,6 +767,177 @@ pub enum ParseMode {
 mod test {
     use super::*;
 
+    #[test]
+    fn it_should_filter_packages_by_name() {
+        let pattern = |p: &str| glob::Pattern::new(p).unwrap();
+
+        assert!(PackageFilter::default().allows("anything"));
+
+        let filter = PackageFilter {
+            include: vec![pattern("serde*"), pattern("tokio")],
+            exclude: vec![pattern("*_derive")],
+        };
+        assert!(filter.allows("serde"));
+        assert!(filter.allows("serde_json"));
+        assert!(filter.allows("tokio"));
+        assert!(!filter.allows("tokio-util"));
+        assert!(!filter.allows("serde_derive"));
+
+        let filter = PackageFilter {
+            include: vec![],
+            exclude: vec![pattern("internal-*")],
+        };
+        assert!(filter.allows("regex"));
+        assert!(!filter.allows("internal-helpers"));
+    }
+
+    #[test]
+    fn it_should_read_license_overrides_from_manifest_metadata() {
+        let metadata = serde_json::json!({
+            "cyclonedx": {
+                "license-overrides": {
+                    "ring": "MIT AND ISC AND OpenSSL",
+                    "ring@0.16.20": "ISC",
+                    "pkg:cargo/internal-*": "LicenseRef-Proprietary",
+                }
+            }
+        });
+
+        let overrides = SbomConfig::from_manifest_metadata(&metadata)
+            .unwrap()
+            .license_overrides();
+        let find =
+            |name, version, purl| overrides.find(name, version, purl).map(|o| o.rule.as_str());
+
+        assert_eq!(find("ring", "0.16.20", None), Some("ring@0.16.20"));
+        assert_eq!(find("ring", "0.17.0", None), Some("ring"));
+        assert_eq!(
+            find(
+                "internal-util",
+                "1.0.0",
+                Some("pkg:cargo/internal-util@1.0.0")
+            ),
+            Some("pkg:cargo/internal-*")
+        );
+        assert_eq!(find("serde", "1.0.0", Some("pkg:cargo/serde@1.0.0")), None);
+
+        assert_eq!(
+            SbomConfig::from_manifest_metadata(&serde_json::Value::Null),
+            Ok(SbomConfig::empty_config())
+        );
+    }
+
+    #[test]
+    fn it_should_reject_invalid_license_overrides() {
+        let overrides = |table| LicenseOverrides::from_table(&table);
+
+        assert_eq!(
+            overrides(serde_json::json!(["MIT"])),
+            Err(LicenseOverrideError::NotATable)
+        );
+        assert!(matches!(
+            overrides(serde_json::json!({ "ring@": "MIT" })),
+            Err(LicenseOverrideError::InvalidOverride { .. })
+        ));
+        assert!(matches!(
+            overrides(serde_json::json!({ "ring": "MIT/Apache-2.0" })),
+            Err(LicenseOverrideError::InvalidOverride { .. })
+        ));
+    }
+
+    #[test]
+    fn it_should_read_organizations_from_manifest_metadata() {
+        let metadata = serde_json::json!({
+            "cyclonedx": {
+                "supplier": {
+                    "name": "Example Inc.",
+                    "url": ["https://example.com"],
+                    "contact": [{ "name": "Jane Doe", "email": "jane@example.com" }],
+                },
+                "manufacture": { "name": "Example Build Services" },
+            }
+        });
+
+        let config = SbomConfig::from_manifest_metadata(&metadata).unwrap();
+
+        let mut supplier = OrganizationalEntity::new("Example Inc.");
+        supplier.url = Some(vec![
+            Uri::try_from("https://example.com".to_string()).unwrap()
+        ]);
+        supplier.contact = Some(vec![OrganizationalContact::new(
+            "Jane Doe",
+            Some("jane@example.com"),
+        )]);
+        assert_eq!(config.supplier, Some(supplier));
+        assert_eq!(
+            config.manufacture,
+            Some(OrganizationalEntity::new("Example Build Services"))
+        );
+
+        let invalid = |supplier| {
+            SbomConfig::from_manifest_metadata(
+                &serde_json::json!({ "cyclonedx": { "supplier": supplier } }),
+            )
+        };
+        assert!(matches!(
+            invalid(serde_json::json!({ "contact": [{ "email": "not an email" }] })),
+            Err(ManifestConfigError::InvalidOrganization {
+                field: "supplier",
+                ..
+            })
+        ));
+        assert!(matches!(
+            invalid(serde_json::json!({ "homepage": "https://example.com" })),
+            Err(ManifestConfigError::InvalidOrganization { .. })
+        ));
+    }
+
+    #[test]
+    fn it_should_read_the_component_type_from_manifest_metadata() {
+        let config = SbomConfig::from_manifest_metadata(
+            &serde_json::json!({ "cyclonedx": { "component-type": "firmware" } }),
+        )
+        .unwrap();
+        assert_eq!(config.component_type, Some(Classification::Firmware));
+
+        assert!(matches!(
+            SbomConfig::from_manifest_metadata(
+                &serde_json::json!({ "cyclonedx": { "component-type": "binary" } }),
+            ),
+            Err(ManifestConfigError::InvalidComponentType(_))
+        ));
+    }
+
+    #[test]
+    fn it_should_read_the_package_filter_from_manifest_metadata() {
+        let config = SbomConfig::from_manifest_metadata(
+            &serde_json::json!({ "cyclonedx": { "exclude": ["internal-*"] } }),
+        )
+        .unwrap();
+        let filter = config.package_filter.unwrap();
+        assert!(filter.include.is_empty());
+        assert!(!filter.allows("internal-macros"));
+        assert!(filter.allows("serde"));
+
+        assert_eq!(
+            SbomConfig::from_manifest_metadata(&serde_json::json!({ "cyclonedx": {} }))
+                .unwrap()
+                .package_filter,
+            None
+        );
+        for include in [serde_json::json!("serde*"), serde_json::json!(["[invalid"])] {
+            assert!(matches!(
+                SbomConfig::from_manifest_metadata(
+                    &serde_json::json!({ "cyclonedx": { "include": include } }),
+                ),
+                Err(ManifestConfigError::InvalidPatterns {
+                    field: "include",
+                    ..
+                })
+            ));
+        }
+    }
+
     #[test]
     fn it_should_error_for_a_prefix_with_a_path_separator() {
         let prefix = format!("directory{}prefix", std::path::MAIN_SEPARATOR);
@@ -0,0 +1,390 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Reads the options of a `cyclonedx.toml` file.
+//!
+//! The keys of the file are the long names of the command-line options, e.g.
+//! `format = "json"` or `exclude = ["internal-*"]`, and are turned into the equivalent
+//! arguments, so that they are validated like the command line. Options given on the
+//! command line take precedence over the file. The keys of the `[package.metadata.cyclonedx]`
+//! table of a manifest that have no command-line equivalent, such as `license-overrides`,
+//! are accepted as well.
+
+use std::ffi::OsString;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use cargo_cyclonedx::config::{ManifestConfigError, SbomConfig};
+use clap::builder::ValueHint;
+use clap::parser::ValueSource;
+use clap::{Arg, ArgAction, ArgMatches, Command};
+use thiserror::Error;
+
+pub const CONFIG_FILE_NAME: &str = "cyclonedx.toml";
+
+/// The keys of the manifest configuration that are not command-line options
+const MANIFEST_KEYS: &[&str] = &["license-overrides", "supplier", "manufacture"];
+
+#[derive(Debug)]
+pub struct ConfigFile {
+    pub path: PathBuf,
+    table: toml::Table,
+}
+
+impl ConfigFile {
+    /// Looks for a `cyclonedx.toml` file in `dir` and its parent directories
+    pub fn find(dir: &Path) -> Option<PathBuf> {
+        dir.ancestors()
+            .map(|dir| dir.join(CONFIG_FILE_NAME))
+            .find(|path| path.is_file())
+    }
+
+    pub fn load(path: &Path) -> Result<Self, ConfigFileError> {
+        let content = fs::read_to_string(path).map_err(|source| ConfigFileError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        let table = toml::from_str(&content).map_err(|source| ConfigFileError::InvalidToml {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        Ok(Self {
+            path: path.to_path_buf(),
+            table,
+        })
+    }
+
+    /// Returns the command-line arguments equivalent to the options in the file,
+    /// leaving out the ones that are set in `matches` or conflict with them
+    pub fn arguments(
+        &self,
+        command: &Command,
+        matches: &ArgMatches,
+    ) -> Result<Vec<OsString>, ConfigFileError> {
+        let explicit: Vec<&Arg> = command
+            .get_arguments()
+            .filter(|arg| {
+                matches!(
+                    matches.value_source(arg.get_id().as_str()),
+                    Some(ValueSource::CommandLine | ValueSource::EnvVariable)
+                )
+            })
+            .collect();
+
+        let mut arguments = Vec::new();
+        for (key, value) in &self.table {
+            if MANIFEST_KEYS.contains(&key.as_str()) {
+                continue;
+            }
+            let arg = command
+                .get_arguments()
+                .find(|arg| arg.get_long() == Some(key.as_str()))
+                .filter(|arg| !matches!(arg.get_id().as_str(), "config" | "help" | "version"))
+                .ok_or_else(|| self.error(key, ConfigFileErrorKind::UnknownOption))?;
+
+            if explicit
+                .iter()
+                .any(|other| conflicting(command, arg, other))
+            {
+                log::debug!(
+                    "Ignoring `{}` from {}, as the command line overrides it",
+                    key,
+                    self.path.display()
+                );
+                continue;
+            }
+
+            let flag = format!("--{key}");
+            match (arg.get_action(), value) {
+                (ArgAction::SetTrue, toml::Value::Boolean(set)) => {
+                    if *set {
+                        arguments.push(flag.into());
+                    }
+                }
+                (ArgAction::SetTrue, _) => {
+                    return Err(self.error(key, ConfigFileErrorKind::ExpectedBoolean))
+                }
+                (ArgAction::Count, toml::Value::Integer(count)) => {
+                    arguments.extend((0..*count).map(|_| OsString::from(&flag)));
+                }
+                (ArgAction::Count, _) => {
+                    return Err(self.error(key, ConfigFileErrorKind::ExpectedInteger))
+                }
+                (ArgAction::Append, toml::Value::Array(values)) => {
+                    for value in values {
+                        arguments.push(self.argument(&flag, arg, key, value)?);
+                    }
+                }
+                (_, value) => arguments.push(self.argument(&flag, arg, key, value)?),
+            }
+        }
+        Ok(arguments)
+    }
+
+    /// Returns the configuration under the keys that are not command-line options
+    pub fn sbom_config(&self) -> Result<SbomConfig, ConfigFileError> {
+        let table: serde_json::Map<String, serde_json::Value> = self
+            .table
+            .iter()
+            .filter(|(key, _)| MANIFEST_KEYS.contains(&key.as_str()))
+            .map(|(key, value)| {
+                let value = serde_json::to_value(value).expect("TOML values are valid JSON");
+                (key.clone(), value)
+            })
+            .collect();
+        let metadata = serde_json::json!({ "cyclonedx": table });
+        SbomConfig::from_manifest_metadata(&metadata).map_err(|source| {
+            ConfigFileError::InvalidConfig {
+                path: self.path.clone(),
+                source,
+            }
+        })
+    }
+
+    /// Formats a single value of an option, resolving relative paths against the directory of the file
+    fn argument(
+        &self,
+        flag: &str,
+        arg: &Arg,
+        key: &str,
+        value: &toml::Value,
+    ) -> Result<OsString, ConfigFileError> {
+        let value = match value {
+            toml::Value::String(value) => value.clone(),
+            toml::Value::Integer(value) => value.to_string(),
+            toml::Value::Float(value) => value.to_string(),
+            toml::Value::Boolean(value) => value.to_string(),
+            _ => return Err(self.error(key, ConfigFileErrorKind::ExpectedValue)),
+        };
+
+        if arg.get_value_hint() == ValueHint::AnyPath {
+            let dir = self.path.parent().unwrap_or_else(|| Path::new(""));
+            let mut argument = OsString::from(format!("{flag}="));
+            argument.push(dir.join(value));
+            Ok(argument)
+        } else {
+            Ok(format!("{flag}={value}").into())
+        }
+    }
+
+    fn error(&self, key: &str, kind: ConfigFileErrorKind) -> ConfigFileError {
+        ConfigFileError::InvalidOption {
+            path: self.path.clone(),
+            key: key.to_string(),
+            kind,
+        }
+    }
+}
+
+/// Whether the two arguments cannot be used together
+fn conflicting(command: &Command, arg: &Arg, other: &Arg) -> bool {
+    let conflicts_with = |a: &Arg, b: &Arg| {
+        a.get_id() == b.get_id()
+            || command
+                .get_arg_conflicts_with(a)
+                .iter()
+                .any(|conflict| conflict.get_id() == b.get_id())
+    };
+    let exclusive_group = command.get_groups().any(|group| {
+        !group.clone().is_multiple()
+            && group.get_args().any(|id| id == arg.get_id())
+            && group.get_args().any(|id| id == other.get_id())
+    });
+    conflicts_with(arg, other) || conflicts_with(other, arg) || exclusive_group
+}
+
+#[derive(Debug, Error)]
+pub enum ConfigFileError {
+    #[error("Failed to read the configuration file {path}")]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("Failed to parse the configuration file {path}")]
+    InvalidToml {
+        path: PathBuf,
+        #[source]
+        source: toml::de::Error,
+    },
+
+    #[error("Invalid option `{key}` in the configuration file {path}: {kind}")]
+    InvalidOption {
+        path: PathBuf,
+        key: String,
+        kind: ConfigFileErrorKind,
+    },
+
+    #[error("Invalid configuration in the configuration file {path}")]
+    InvalidConfig {
+        path: PathBuf,
+        #[source]
+        source: ManifestConfigError,
+    },
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum ConfigFileErrorKind {
+    #[error("there is no such option")]
+    UnknownOption,
+
+    #[error("expected true or false")]
+    ExpectedBoolean,
+
+    #[error("expected an integer")]
+    ExpectedInteger,
+
+    #[error("expected a string, a number or a boolean")]
+    ExpectedValue,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::cli::{Args, Opts};
+    use clap::{CommandFactory, Parser};
+
+    fn config_file(content: &str) -> ConfigFile {
+        ConfigFile {
+            path: PathBuf::from("/project/cyclonedx.toml"),
+            table: toml::from_str(content).unwrap(),
+        }
+    }
+
+    fn arguments(file: &ConfigFile, cli: &[&str]) -> Result<Vec<String>, ConfigFileError> {
+        let matches = Opts::command().get_matches_from(cli);
+        let matches = matches.subcommand_matches("cyclonedx").unwrap();
+        let arguments = file.arguments(&Args::command(), matches)?;
+        Ok(arguments
+            .into_iter()
+            .map(|arg| arg.into_string().unwrap())
+            .collect())
+    }
+
+    #[test]
+    fn it_should_convert_options_to_arguments() {
+        let file = config_file(
+            r#"
+            all = true
+            format = "json"
+            exclude = ["internal-*", "*-fixtures"]
+            verbose = 2
+            advisory-db = "advisory-db"
+            "#,
+        );
+
+        let arguments = arguments(&file, &["cargo", "cyclonedx"]).unwrap();
+
+        assert_eq!(
+            arguments,
+            vec![
+                "--advisory-db=/project/advisory-db",
+                "--all",
+                "--exclude=internal-*",
+                "--exclude=*-fixtures",
+                "--format=json",
+                "--verbose",
+                "--verbose",
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_prefer_the_command_line() {
+        let file = config_file(
+            r#"
+            all = true
+            format = "json"
+            exclude = ["internal-*"]
+            target = "x86_64-unknown-linux-gnu"
+            "#,
+        );
+
+        let arguments = arguments(
+            &file,
+            &[
+                "cargo",
+                "cyclonedx",
+                "--top-level",
+                "--format",
+                "xml",
+                "--lockfile",
+                "Cargo.lock",
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(arguments, vec!["--exclude=internal-*"]);
+        let mut cli = vec!["cargo", "cyclonedx"];
+        cli.extend(arguments.iter().map(String::as_str));
+        cli.extend(["--top-level", "--format", "xml"]);
+        assert!(Opts::try_parse_from(cli).is_ok());
+    }
+
+    #[test]
+    fn it_should_reject_invalid_options() {
+        let error = |content: &str| match arguments(&config_file(content), &["cargo", "cyclonedx"])
+        {
+            Err(ConfigFileError::InvalidOption { kind, .. }) => kind,
+            result => panic!("Unexpected result {result:?}"),
+        };
+
+        assert_eq!(error("unknown = 1"), ConfigFileErrorKind::UnknownOption);
+        assert_eq!(
+            error(r#"config = "other.toml""#),
+            ConfigFileErrorKind::UnknownOption
+        );
+        assert_eq!(
+            error(r#"all = "yes""#),
+            ConfigFileErrorKind::ExpectedBoolean
+        );
+        assert_eq!(
+            error("verbose = true"),
+            ConfigFileErrorKind::ExpectedInteger
+        );
+        assert_eq!(
+            error("format = [\"json\"]"),
+            ConfigFileErrorKind::ExpectedValue
+        );
+    }
+
+    #[test]
+    fn it_should_read_the_manifest_configuration() {
+        let file = config_file(
+            r#"
+            format = "json"
+
+            [license-overrides]
+            "openssl" = "Apache-2.0"
+
+            [supplier]
+            name = "Example Corp"
+            "#,
+        );
+
+        let config = file.sbom_config().unwrap();
+
+        assert!(config.license_overrides.is_some());
+        assert_eq!(
+            config.supplier.unwrap().name.unwrap().to_string(),
+            "Example Corp"
+        );
+        assert!(config.format.is_none());
+    }
+}
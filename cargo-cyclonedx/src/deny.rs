@@ -0,0 +1,582 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Checks the components of a BOM against the license and source policies
+//! of a [cargo-deny](https://embarkstudios.github.io/cargo-deny/) configuration file.
+//!
+//! Only the `[licenses]` and `[sources]` tables of `deny.toml` are read. Licenses are
+//! checked as they are in the BOM, i.e. after license overrides, so that the policy is
+//! evaluated on exactly what the SBOM reports. An expression is accepted if it can be
+//! satisfied with the allowed licenses, taking the exceptions for the crate into account.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use cargo_metadata::Package;
+use cyclonedx_bom::models::bom::Bom;
+use cyclonedx_bom::models::component::Component;
+use cyclonedx_bom::models::license::{LicenseChoice, LicenseIdentifier};
+use cyclonedx_bom::models::property::{Properties, Property};
+use semver::VersionReq;
+use serde::Deserialize;
+use thiserror::Error;
+
+use crate::generator::is_crates_io;
+
+const CRATES_IO_INDEX: &str = "https://github.com/rust-lang/crates.io-index";
+
+/// How a violation of a policy is treated
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Level {
+    Deny,
+    Warn,
+    Allow,
+}
+
+/// A crate that does not comply with the policy
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    pub level: Level,
+    /// The crate as `name@version`
+    pub package: String,
+    pub message: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct DenyConfig {
+    #[serde(default)]
+    licenses: LicensePolicy,
+    #[serde(default)]
+    sources: SourcePolicy,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct LicensePolicy {
+    #[serde(default)]
+    allow: Vec<String>,
+    /// Only supported by older versions of cargo-deny
+    #[serde(default)]
+    deny: Vec<String>,
+    #[serde(default)]
+    exceptions: Vec<LicenseException>,
+    #[serde(default)]
+    private: PrivatePolicy,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct LicenseException {
+    /// The crate, as `name` or `name@version`
+    #[serde(alias = "name")]
+    #[serde(rename = "crate")]
+    krate: String,
+    version: Option<VersionReq>,
+    allow: Vec<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct PrivatePolicy {
+    /// Whether crates that are not published are exempt
+    #[serde(default)]
+    ignore: bool,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct SourcePolicy {
+    #[serde(default = "warn")]
+    unknown_registry: Level,
+    #[serde(default = "warn")]
+    unknown_git: Level,
+    #[serde(default = "crates_io")]
+    allow_registry: Vec<String>,
+    #[serde(default)]
+    allow_git: Vec<String>,
+    /// Git hosts, e.g. `github`, and the organizations whose repositories are allowed
+    #[serde(default)]
+    allow_org: HashMap<String, Vec<String>>,
+}
+
+impl Default for SourcePolicy {
+    fn default() -> Self {
+        Self {
+            unknown_registry: warn(),
+            unknown_git: warn(),
+            allow_registry: crates_io(),
+            allow_git: Vec::new(),
+            allow_org: HashMap::new(),
+        }
+    }
+}
+
+fn warn() -> Level {
+    Level::Warn
+}
+
+fn crates_io() -> Vec<String> {
+    vec![CRATES_IO_INDEX.to_string()]
+}
+
+/// The license and source policies of a `deny.toml` file
+#[derive(Debug, Default)]
+pub struct DenyPolicy {
+    config: DenyConfig,
+}
+
+impl DenyPolicy {
+    pub fn load(path: &Path) -> Result<Self, DenyError> {
+        let content = fs::read_to_string(path).map_err(|source| DenyError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        Self::parse(&content).map_err(|source| DenyError::InvalidConfig {
+            path: path.to_path_buf(),
+            source,
+        })
+    }
+
+    fn parse(content: &str) -> Result<Self, toml::de::Error> {
+        Ok(Self {
+            config: toml::from_str(content)?,
+        })
+    }
+
+    /// Checks the components of the packages, records the violations on them
+    /// and returns all violations
+    pub fn check_bom<'a>(
+        &self,
+        bom: &mut Bom,
+        packages: impl Iterator<Item = &'a Package>,
+    ) -> Vec<Violation> {
+        let packages: HashMap<&str, &Package> = packages
+            .map(|package| (package.id.repr.as_str(), package))
+            .collect();
+
+        let metadata_component = bom
+            .metadata
+            .as_mut()
+            .and_then(|metadata| metadata.component.as_mut());
+        let components = bom
+            .components
+            .as_mut()
+            .map(|components| components.0.iter_mut());
+
+        let mut violations = Vec::new();
+        for component in metadata_component
+            .into_iter()
+            .chain(components.into_iter().flatten())
+        {
+            self.check_component(component, &packages, &mut violations);
+        }
+        violations
+    }
+
+    /// Checks a component and the components nested in it, recording the violations on each of them
+    fn check_component(
+        &self,
+        component: &mut Component,
+        packages: &HashMap<&str, &Package>,
+        violations: &mut Vec<Violation>,
+    ) {
+        if let Some(package) = component
+            .bom_ref
+            .as_deref()
+            .and_then(|bom_ref| packages.get(bom_ref))
+        {
+            let found: Vec<Violation> = self
+                .check_license(package, component)
+                .into_iter()
+                .chain(self.check_source(package))
+                .collect();
+            for violation in &found {
+                let property = Property::new("cargo-cyclonedx:deny:violation", &violation.message);
+                component
+                    .properties
+                    .get_or_insert_with(|| Properties(Vec::new()))
+                    .0
+                    .push(property);
+            }
+            violations.extend(found);
+        }
+        if let Some(nested) = component.components.as_mut() {
+            for nested in nested.0.iter_mut() {
+                self.check_component(nested, packages, violations);
+            }
+        }
+    }
+
+    fn check_license(&self, package: &Package, component: &Component) -> Option<Violation> {
+        let policy = &self.config.licenses;
+        let is_private = package.publish.as_ref().is_some_and(Vec::is_empty);
+        if policy.private.ignore && is_private {
+            return None;
+        }
+        let violation = |message: String| {
+            Some(Violation {
+                level: Level::Deny,
+                package: format!("{}@{}", package.name, package.version),
+                message,
+            })
+        };
+
+        let expressions: Vec<String> = component
+            .licenses
+            .iter()
+            .flat_map(|licenses| licenses.0.iter())
+            .map(|choice| match choice {
+                LicenseChoice::Expression(expression) => expression.to_string(),
+                LicenseChoice::License(license) => match &license.license_identifier {
+                    LicenseIdentifier::SpdxId(id) => id.to_string(),
+                    LicenseIdentifier::Name(name) => name.to_string(),
+                },
+            })
+            .collect();
+        if expressions.is_empty() {
+            return violation("no license".to_string());
+        }
+
+        let exceptions: Vec<&str> = policy
+            .exceptions
+            .iter()
+            .filter(|exception| exception.applies_to(package))
+            .flat_map(|exception| exception.allow.iter().map(String::as_str))
+            .collect();
+        let is_allowed = |id: &str| {
+            !policy.deny.iter().any(|denied| denied == id)
+                && (policy.allow.iter().any(|allowed| allowed == id) || exceptions.contains(&id))
+        };
+
+        for expression in expressions {
+            // A license that is too recent for the SPDX license list we know can still be allowed by its identifier
+            if is_allowed(&expression) {
+                continue;
+            }
+            let parsed = match spdx::Expression::parse_mode(&expression, spdx::ParseMode::LAX) {
+                Ok(parsed) => parsed,
+                Err(_) => {
+                    return violation(format!("license `{expression}` is not an SPDX expression"))
+                }
+            };
+            let satisfied = parsed.evaluate(|requirement| match &requirement.license {
+                spdx::LicenseItem::Spdx { id, .. } => is_allowed(id.name),
+                spdx::LicenseItem::Other { lic_ref, .. } => {
+                    is_allowed(&format!("LicenseRef-{lic_ref}"))
+                }
+            });
+            if !satisfied {
+                return violation(format!("license `{expression}` is not allowed"));
+            }
+        }
+        None
+    }
+
+    fn check_source(&self, package: &Package) -> Option<Violation> {
+        let policy = &self.config.sources;
+        let source = package.source.as_ref()?;
+        let violation = |level: Level, kind: &str| {
+            (level != Level::Allow).then(|| Violation {
+                level,
+                package: format!("{}@{}", package.name, package.version),
+                message: format!("{kind} `{}` is not allowed", source.repr),
+            })
+        };
+
+        if let Some(url) = source
+            .repr
+            .strip_prefix("registry+")
+            .or_else(|| source.repr.strip_prefix("sparse+"))
+        {
+            let allowed = policy.allow_registry.iter().any(|allowed| {
+                same_url(allowed, url)
+                    || (same_url(allowed, CRATES_IO_INDEX) && is_crates_io(source))
+            });
+            return if allowed {
+                None
+            } else {
+                violation(policy.unknown_registry, "registry")
+            };
+        }
+
+        if let Some(url) = source.repr.strip_prefix("git+") {
+            // The revision and commit are in the query and fragment
+            let url = url.split(['?', '#']).next().unwrap_or(url);
+            let in_org = policy.allow_org.iter().any(|(host, orgs)| {
+                let host = match host.as_str() {
+                    "github" => "github.com",
+                    "gitlab" => "gitlab.com",
+                    "bitbucket" => "bitbucket.org",
+                    other => other,
+                };
+                orgs.iter().any(|org| {
+                    let prefix = format!("https://{host}/{org}/");
+                    url.len() > prefix.len() && url[..prefix.len()].eq_ignore_ascii_case(&prefix)
+                })
+            });
+            let allowed = in_org
+                || policy
+                    .allow_git
+                    .iter()
+                    .any(|allowed| same_url(allowed, url));
+            return if allowed {
+                None
+            } else {
+                violation(policy.unknown_git, "git source")
+            };
+        }
+        None
+    }
+}
+
+impl LicenseException {
+    fn applies_to(&self, package: &Package) -> bool {
+        let (name, version) = match self.krate.split_once('@') {
+            Some((name, version)) => (name, Some(version)),
+            None => (self.krate.as_str(), None),
+        };
+        let version_matches = match (version, &self.version) {
+            (Some(version), _) => package.version.to_string() == version,
+            (None, Some(requirement)) => requirement.matches(&package.version),
+            (None, None) => true,
+        };
+        name == package.name && version_matches
+    }
+}
+
+/// Compares URLs of repositories, ignoring a trailing slash and `.git` suffix
+fn same_url(a: &str, b: &str) -> bool {
+    let normalize = |url: &str| {
+        let url = url.trim_end_matches('/');
+        url.strip_suffix(".git").unwrap_or(url).to_lowercase()
+    };
+    normalize(a) == normalize(b)
+}
+
+#[derive(Debug, Error)]
+pub enum DenyError {
+    #[error("Failed to read the cargo-deny configuration {path}")]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("Failed to parse the cargo-deny configuration {path}")]
+    InvalidConfig {
+        path: PathBuf,
+        #[source]
+        source: toml::de::Error,
+    },
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use cyclonedx_bom::external_models::spdx::SpdxExpression;
+    use cyclonedx_bom::models::component::{Classification, Components};
+    use cyclonedx_bom::models::license::{License, Licenses};
+
+    fn package(name: &str, version: &str, source: Option<&str>) -> Package {
+        serde_json::from_value(serde_json::json!({
+            "name": name,
+            "version": version,
+            "id": format!("{name} {version}"),
+            "license": null,
+            "license_file": null,
+            "description": null,
+            "source": source,
+            "dependencies": [],
+            "targets": [],
+            "features": {},
+            "manifest_path": format!("/{name}/Cargo.toml"),
+            "categories": [],
+            "keywords": [],
+            "readme": null,
+            "repository": null,
+            "homepage": null,
+            "documentation": null,
+            "edition": "2021",
+            "metadata": null,
+            "links": null,
+            "publish": null,
+            "authors": [],
+            "default_run": null,
+            "rust_version": null
+        }))
+        .unwrap()
+    }
+
+    fn component(package: &Package, license: Option<&str>) -> Component {
+        let mut component = Component::new(
+            Classification::Library,
+            &package.name,
+            &package.version.to_string(),
+            Some(package.id.repr.clone()),
+        );
+        component.licenses = license.map(|license| {
+            Licenses(vec![LicenseChoice::Expression(
+                SpdxExpression::parse_lax(license.to_string()).unwrap(),
+            )])
+        });
+        component
+    }
+
+    const CONFIG: &str = r#"
+        [licenses]
+        allow = ["MIT", "Apache-2.0"]
+        exceptions = [{ allow = ["Zlib"], crate = "adler32" }]
+
+        [sources]
+        unknown-registry = "deny"
+        unknown-git = "warn"
+        allow-git = ["https://github.com/example/allowed.git"]
+
+        [sources.allow-org]
+        github = ["trusted-org"]
+
+        [bans]
+        multiple-versions = "warn"
+    "#;
+
+    #[test]
+    fn it_should_check_licenses() {
+        let policy = DenyPolicy::parse(CONFIG).unwrap();
+        let check = |name: &str, license: Option<&str>| {
+            let package = package(name, "1.0.0", None);
+            policy
+                .check_license(&package, &component(&package, license))
+                .map(|violation| violation.message)
+        };
+
+        assert_eq!(check("a", Some("MIT OR GPL-3.0")), None);
+        assert_eq!(
+            check("b", Some("MIT AND GPL-3.0")),
+            Some("license `MIT AND GPL-3.0` is not allowed".to_string())
+        );
+        assert_eq!(check("adler32", Some("Zlib")), None);
+        assert!(check("other", Some("Zlib")).is_some());
+        assert_eq!(check("c", None), Some("no license".to_string()));
+
+        let policy = DenyPolicy::parse("[licenses]\nallow = [\"Unicode-3.0\"]").unwrap();
+        let package = package("icu", "1.0.0", None);
+        let mut component = component(&package, None);
+        component.licenses = Some(Licenses(vec![LicenseChoice::License(
+            License::named_license("Unicode-3.0"),
+        )]));
+        assert_eq!(policy.check_license(&package, &component), None);
+    }
+
+    #[test]
+    fn it_should_check_sources() {
+        let policy = DenyPolicy::parse(CONFIG).unwrap();
+        let check = |source: &str| {
+            policy
+                .check_source(&package("a", "1.0.0", Some(source)))
+                .map(|violation| violation.level)
+        };
+
+        assert_eq!(
+            check("registry+https://github.com/rust-lang/crates.io-index"),
+            None
+        );
+        assert_eq!(check("sparse+https://index.crates.io/"), None);
+        assert_eq!(
+            check("registry+https://example.com/index"),
+            Some(Level::Deny)
+        );
+        assert_eq!(
+            check("git+https://github.com/example/allowed?branch=main#0123abcd"),
+            None
+        );
+        assert_eq!(
+            check("git+https://github.com/trusted-org/repo#0123abcd"),
+            None
+        );
+        assert_eq!(
+            check("git+https://github.com/someone/repo#0123abcd"),
+            Some(Level::Warn)
+        );
+    }
+
+    #[test]
+    fn it_should_record_violations_on_components() {
+        let policy = DenyPolicy::parse(CONFIG).unwrap();
+        let allowed = package("allowed", "1.0.0", None);
+        let denied = package("denied", "2.0.0", None);
+        let mut bom = Bom::default();
+        bom.components = Some(Components(vec![
+            component(&allowed, Some("MIT")),
+            component(&denied, Some("GPL-3.0-only")),
+        ]));
+
+        let violations = policy.check_bom(&mut bom, [&allowed, &denied].into_iter());
+
+        assert_eq!(
+            violations,
+            vec![Violation {
+                level: Level::Deny,
+                package: "denied@2.0.0".to_string(),
+                message: "license `GPL-3.0-only` is not allowed".to_string(),
+            }]
+        );
+        let components = bom.components.unwrap().0;
+        assert_eq!(components[0].properties, None);
+        assert_eq!(
+            components[1].properties,
+            Some(Properties(vec![Property::new(
+                "cargo-cyclonedx:deny:violation",
+                "license `GPL-3.0-only` is not allowed"
+            )]))
+        );
+    }
+
+    #[test]
+    fn it_should_check_nested_components() {
+        let policy = DenyPolicy::parse(CONFIG).unwrap();
+        let parent = package("parent", "1.0.0", None);
+        let denied = package("denied", "2.0.0", None);
+        let mut outer = component(&parent, Some("MIT"));
+        outer.components = Some(Components(vec![component(&denied, Some("GPL-3.0-only"))]));
+        let mut bom = Bom::default();
+        bom.components = Some(Components(vec![outer]));
+
+        let violations = policy.check_bom(&mut bom, [&parent, &denied].into_iter());
+
+        assert_eq!(
+            violations,
+            vec![Violation {
+                level: Level::Deny,
+                package: "denied@2.0.0".to_string(),
+                message: "license `GPL-3.0-only` is not allowed".to_string(),
+            }]
+        );
+        let components = bom.components.unwrap().0;
+        assert_eq!(components[0].properties, None);
+        assert_eq!(
+            components[0].components.as_ref().unwrap().0[0].properties,
+            Some(Properties(vec![Property::new(
+                "cargo-cyclonedx:deny:violation",
+                "license `GPL-3.0-only` is not allowed"
+            )]))
+        );
+    }
+}
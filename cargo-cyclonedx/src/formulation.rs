@@ -0,0 +1,242 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Describes how a package is built, as a CycloneDX 1.5 formula.
+//!
+//! cargo-cyclonedx does not run the build itself, so the formula records the Cargo invocation
+//! equivalent to the configuration the SBOM was generated with, together with the environment
+//! variables that Cargo and rustc read and that change the build output.
+
+use cyclonedx_bom::external_models::normalized_string::NormalizedString;
+use cyclonedx_bom::models::formulation::{
+    Command, EnvironmentVar, Formula, Formulation, Input, Parameter, RequiredInputField, Step,
+    TaskType, Workflow,
+};
+
+use crate::config::{Features, Target};
+
+/// Environment variables that affect the output of a build
+const BUILD_ENVIRONMENT_VARS: &[&str] = &[
+    "CARGO_BUILD_RUSTFLAGS",
+    "CARGO_BUILD_TARGET",
+    "CARGO_ENCODED_RUSTFLAGS",
+    "CARGO_INCREMENTAL",
+    "RUSTC",
+    "RUSTC_BOOTSTRAP",
+    "RUSTC_WORKSPACE_WRAPPER",
+    "RUSTC_WRAPPER",
+    "RUSTFLAGS",
+    "SOURCE_DATE_EPOCH",
+];
+
+/// Prefixes of environment variables that override profile and per-target settings,
+/// e.g. `CARGO_PROFILE_RELEASE_LTO` or `CARGO_TARGET_X86_64_UNKNOWN_LINUX_GNU_LINKER`
+const BUILD_ENVIRONMENT_PREFIXES: &[&str] = &["CARGO_PROFILE_", "CARGO_TARGET_"];
+
+/// The settings of the build to describe
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BuildDescription<'a> {
+    pub package: &'a str,
+    /// The `bom-ref` of the component that is built
+    pub bom_ref: &'a str,
+    /// The name of the Cargo profile, e.g. `release`
+    pub profile: &'a str,
+    pub features: Option<&'a Features>,
+    pub target: Option<&'a Target>,
+}
+
+impl BuildDescription<'_> {
+    /// Describes the build, recording the relevant variables of the given environment
+    pub fn formulation(
+        &self,
+        environment: impl IntoIterator<Item = (String, String)>,
+    ) -> Formulation {
+        let command_line = self.command_line();
+
+        let mut inputs = vec![Input::new(RequiredInputField::Parameters(
+            self.parameters(),
+        ))];
+        let environment_vars = build_environment(environment);
+        if !environment_vars.is_empty() {
+            inputs.push(Input::new(RequiredInputField::EnvironmentVars(
+                environment_vars,
+            )));
+        }
+
+        let mut command = Command::default();
+        command.executed = Some(command_line);
+
+        let mut step = Step::default();
+        step.name = Some(NormalizedString::new("cargo build"));
+        step.commands = Some(vec![command]);
+
+        let bom_ref = format!("{} build", self.bom_ref);
+        let mut workflow = Workflow::new(&bom_ref, &bom_ref, vec![TaskType::Build]);
+        workflow.name = Some(NormalizedString::new(&format!("Build {}", self.package)));
+        workflow.steps = Some(vec![step]);
+        workflow.inputs = Some(inputs);
+
+        let mut formula = Formula::default();
+        formula.bom_ref = Some(format!("{} formula", self.bom_ref));
+        formula.workflows = Some(vec![workflow]);
+
+        Formulation(vec![formula])
+    }
+
+    /// The Cargo invocation that builds the package with these settings
+    fn command_line(&self) -> String {
+        let mut args = vec![
+            "cargo".to_string(),
+            "build".to_string(),
+            "--package".to_string(),
+            self.package.to_string(),
+        ];
+        match self.profile {
+            "dev" => {}
+            "release" => args.push("--release".to_string()),
+            profile => args.extend(["--profile".to_string(), profile.to_string()]),
+        }
+        if let Some(features) = self.features {
+            if features.all_features {
+                args.push("--all-features".to_string());
+            }
+            if features.no_default_features {
+                args.push("--no-default-features".to_string());
+            }
+            if !features.features.is_empty() {
+                args.extend(["--features".to_string(), features.features.join(",")]);
+            }
+        }
+        if let Some(Target::SingleTarget(triple)) = self.target {
+            args.extend(["--target".to_string(), triple.clone()]);
+        }
+        args.join(" ")
+    }
+
+    fn parameters(&self) -> Vec<Parameter> {
+        let parameter = |name: &str, value: String| {
+            let mut parameter = Parameter::default();
+            parameter.name = Some(name.to_string());
+            parameter.value = Some(value);
+            parameter
+        };
+
+        let mut parameters = vec![parameter("profile", self.profile.to_string())];
+        if let Some(features) = self.features {
+            if features.all_features {
+                parameters.push(parameter("all-features", "true".to_string()));
+            }
+            if features.no_default_features {
+                parameters.push(parameter("no-default-features", "true".to_string()));
+            }
+            if !features.features.is_empty() {
+                parameters.push(parameter("features", features.features.join(",")));
+            }
+        }
+        if let Some(target) = self.target {
+            parameters.push(parameter("target", target.as_str().to_string()));
+        }
+        parameters
+    }
+}
+
+/// Picks the variables that affect the build from the environment, sorted by name.
+/// Anything else is left out, as it might contain credentials.
+fn build_environment(
+    environment: impl IntoIterator<Item = (String, String)>,
+) -> Vec<EnvironmentVar> {
+    let mut vars: Vec<(String, String)> = environment
+        .into_iter()
+        .filter(|(name, _)| {
+            BUILD_ENVIRONMENT_VARS.contains(&name.as_str())
+                || (BUILD_ENVIRONMENT_PREFIXES
+                    .iter()
+                    .any(|prefix| name.starts_with(prefix))
+                    && name != "CARGO_TARGET_DIR")
+        })
+        .collect();
+    vars.sort();
+
+    vars.into_iter()
+        .map(|(name, value)| EnvironmentVar::Property { name, value })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_should_describe_the_cargo_invocation() {
+        let features = Features {
+            all_features: false,
+            no_default_features: true,
+            features: vec!["std".to_string(), "serde".to_string()],
+        };
+        let target = Target::SingleTarget("x86_64-unknown-linux-gnu".to_string());
+        let build = BuildDescription {
+            package: "app",
+            bom_ref: "app 0.1.0",
+            profile: "release",
+            features: Some(&features),
+            target: Some(&target),
+        };
+
+        assert_eq!(
+            build.command_line(),
+            "cargo build --package app --release --no-default-features --features std,serde --target x86_64-unknown-linux-gnu"
+        );
+
+        let build = BuildDescription {
+            profile: "dist",
+            features: None,
+            target: Some(&Target::AllTargets),
+            ..build
+        };
+        assert_eq!(
+            build.command_line(),
+            "cargo build --package app --profile dist"
+        );
+    }
+
+    #[test]
+    fn it_should_only_record_variables_affecting_the_build() {
+        let environment = [
+            ("RUSTFLAGS", "-C target-cpu=native"),
+            ("CARGO_PROFILE_RELEASE_LTO", "true"),
+            ("CARGO_TARGET_DIR", "/tmp/target"),
+            ("CARGO_REGISTRIES_MY_REGISTRY_TOKEN", "secret"),
+            ("HOME", "/home/user"),
+        ]
+        .map(|(name, value)| (name.to_string(), value.to_string()));
+
+        assert_eq!(
+            build_environment(environment),
+            vec![
+                EnvironmentVar::Property {
+                    name: "CARGO_PROFILE_RELEASE_LTO".to_string(),
+                    value: "true".to_string()
+                },
+                EnvironmentVar::Property {
+                    name: "RUSTFLAGS".to_string(),
+                    value: "-C target-cpu=native".to_string()
+                },
+            ]
+        );
+    }
+}
@@ -15,13 +15,30 @@
  *
  * SPDX-License-Identifier: Apache-2.0
  */
+use crate::advisories::{AdvisoryDatabase, AdvisoryError};
+use crate::artifacts::{executable_hashes, ArtifactDirectory};
 use crate::config::Pattern;
 use crate::config::PlatformSuffix;
 use crate::config::Prefix;
 use crate::config::SbomConfig;
-use crate::config::{IncludedDependencies, ParseMode};
-use crate::format::Format;
+use crate::config::Target;
+use crate::config::Validation;
+use crate::config::{
+    BuildTimeDependencies, IncludedDependencies, LicenseOverride, ManifestConfigError,
+    PackageFilter, ParseMode, SourceHashing,
+};
+use crate::deny::{self, DenyError, DenyPolicy};
+use crate::format::{Compression, Format};
+use crate::formulation::BuildDescription;
+use crate::license_detection::scan_licenses;
+use crate::platform::{host_platform, Toolchain};
 use crate::purl::get_purl;
+use crate::resolution::{BuiltGraph, ResolutionError};
+use crate::signing::{SigningError, SigningKey};
+use crate::sources::SourceHashes;
+use crate::urlencode::urlencode;
+use crate::vendor::{vendored_source, VendoredSource};
+use crate::yanked::{mark_yanked, RegistryIndex, YankedError};
 
 use cargo_metadata;
 use cargo_metadata::DependencyKind;
@@ -34,12 +51,15 @@ use cargo_metadata::PackageId;
 use cargo_lock::package::Checksum;
 use cargo_lock::Lockfile;
 use cargo_metadata::camino::Utf8PathBuf;
+use cyclonedx_bom::external_models::date_time::DateTime;
 use cyclonedx_bom::external_models::normalized_string::NormalizedString;
 use cyclonedx_bom::external_models::spdx::SpdxExpression;
 use cyclonedx_bom::external_models::uri::Uri;
 use cyclonedx_bom::models::attached_text::AttachedText;
-use cyclonedx_bom::models::bom::Bom;
-use cyclonedx_bom::models::component::{Classification, Component, Components, Scope};
+use cyclonedx_bom::models::bom::{Bom, SpecVersion};
+use cyclonedx_bom::models::component::{
+    Classification, Component, ComponentEvidence, Components, Scope,
+};
 use cyclonedx_bom::models::dependency::{Dependencies, Dependency};
 use cyclonedx_bom::models::external_reference::{
     ExternalReference, ExternalReferenceType, ExternalReferences,
@@ -48,15 +68,19 @@ use cyclonedx_bom::models::license::{License, LicenseChoice, Licenses};
 use cyclonedx_bom::models::metadata::Metadata;
 use cyclonedx_bom::models::metadata::MetadataError;
 use cyclonedx_bom::models::organization::OrganizationalContact;
+use cyclonedx_bom::models::property::{Properties, Property};
 use cyclonedx_bom::models::tool::{Tool, Tools};
 use cyclonedx_bom::validation::Validate;
 use cyclonedx_bom::validation::ValidationResult;
+use flate2::write::GzEncoder;
 use once_cell::sync::Lazy;
 use regex::Regex;
 
 use log::Level;
 use std::collections::BTreeMap;
+use std::collections::BTreeSet;
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::convert::TryFrom;
 use std::fs::File;
 use std::io::BufWriter;
@@ -73,7 +97,11 @@ type ResolveMap = BTreeMap<PackageId, Node>;
 pub struct SbomGenerator {
     config: SbomConfig,
     workspace_root: Utf8PathBuf,
+    target_directory: Utf8PathBuf,
     crate_hashes: HashMap<cargo_metadata::PackageId, Checksum>,
+    toolchain: Toolchain,
+    /// The first-party crates, which are supplied by the configured supplier
+    workspace_members: HashSet<PackageId>,
 }
 
 /// Contains a map from `bom_ref` of a subcomponent to the kinds of Cargo targets it has,
@@ -95,21 +123,81 @@ impl SbomGenerator {
         let packages = index_packages(meta.packages);
         let resolve = index_resolve(meta.resolve.unwrap().nodes);
 
+        let advisory_db = match &config.advisory_db {
+            Some(path) => Some(AdvisoryDatabase::open(path)?),
+            None => None,
+        };
+        let mut registry_index = config
+            .yanked_check
+            .as_ref()
+            .map(|check| RegistryIndex::new(check.crates_io_index.as_deref(), config.offline()))
+            .transpose()?;
+        let deny_policy = match &config.deny_check {
+            Some(check) => Some(DenyPolicy::load(&check.path)?),
+            None => None,
+        };
+
+        // The configuration in the manifest of a member takes precedence over the workspace one,
+        // and the configuration passed in over both
+        let workspace_config = SbomConfig::from_manifest_metadata(&meta.workspace_metadata)?;
+
+        let source_date_epoch = source_date_epoch();
+        let toolchain = Toolchain::detect();
+
         let mut result = Vec::with_capacity(members.len());
         for member in members.iter() {
             log::trace!("Processing the package {}", member);
 
-            let (dependencies, pruned_resolve) =
-                if config.included_dependencies() == IncludedDependencies::AllDependencies {
-                    all_dependencies(member, &packages, &resolve)
-                } else {
-                    top_level_dependencies(member, &packages, &resolve)
-                };
+            let manifest_config = workspace_config.merge(&SbomConfig::from_manifest_metadata(
+                &packages[member].metadata,
+            )?);
+            let member_config = manifest_config.merge(config);
+
+            let exact = match member_config.exact_features() {
+                true => {
+                    let manifest_path = packages[member].manifest_path.as_std_path();
+                    let built = BuiltGraph::resolve(manifest_path, &member_config)?;
+                    Some(built_dependencies(&built, &packages, &resolve))
+                }
+                false => None,
+            };
+            let (packages, resolve) = match &exact {
+                Some((packages, resolve)) => (packages, resolve),
+                None => (&packages, &resolve),
+            };
+
+            let filtered =
+                filter_packages(member, &member_config.package_filter(), packages, resolve);
+            let (packages, resolve) = match &filtered {
+                Some((packages, resolve)) => (packages, resolve),
+                None => (packages, resolve),
+            };
+
+            let (mut dependencies, mut pruned_resolve) = if member_config.included_dependencies()
+                == IncludedDependencies::AllDependencies
+            {
+                all_dependencies(member, packages, resolve, member_config.dev_dependencies())
+            } else {
+                top_level_dependencies(member, packages, resolve, member_config.dev_dependencies())
+            };
+
+            let build_time = build_time_dependencies(member, packages, resolve);
+            if member_config.build_time_dependencies() == BuildTimeDependencies::Exclude {
+                exclude_packages(&build_time, &mut dependencies, &mut pruned_resolve);
+            }
+            let dev_only = match member_config.dev_dependencies() {
+                true => dev_only_dependencies(member, &dependencies, packages, resolve),
+                false => HashSet::new(),
+            };
 
             let manifest_path = packages[member].manifest_path.clone().into_std_path_buf();
 
             let mut crate_hashes = HashMap::new();
-            match locate_cargo_lock(&manifest_path) {
+            let lockfile = match &config.lockfile {
+                Some(lockfile) => Ok(lockfile.clone()),
+                None => locate_cargo_lock(&manifest_path),
+            };
+            match lockfile {
                 Ok(path) => match Lockfile::load(path) {
                     Ok(lockfile_contents) => crate_hashes = package_hashes(&lockfile_contents),
                     Err(err) => log::warn!(
@@ -124,12 +212,78 @@ impl SbomGenerator {
             }
 
             let generator = SbomGenerator {
-                config: config.clone(),
+                config: member_config,
                 workspace_root: meta.workspace_root.to_owned(),
+                target_directory: meta.target_directory.to_owned(),
                 crate_hashes,
+                toolchain: toolchain.clone(),
+                workspace_members: members.iter().cloned().collect(),
             };
-            let (bom, target_kinds) =
-                generator.create_bom(member, &dependencies, &pruned_resolve)?;
+            let (mut bom, target_kinds) = generator.create_bom(
+                member,
+                &dependencies,
+                &pruned_resolve,
+                &build_time,
+                &dev_only,
+            )?;
+
+            if let Some(advisory_db) = &advisory_db {
+                bom.vulnerabilities =
+                    advisory_db.vulnerabilities(dependencies.values().filter(|p| &p.id != member));
+            }
+
+            if let Some(registry_index) = &mut registry_index {
+                let yanked =
+                    registry_index.yanked(dependencies.values().filter(|p| &p.id != member))?;
+                for package in &yanked {
+                    log::warn!(
+                        "{} depends on {} {}, which has been yanked",
+                        packages[member].name,
+                        package.name,
+                        package.version
+                    );
+                }
+                mark_yanked(&mut bom, &yanked);
+
+                let fail = config.yanked_check.as_ref().is_some_and(|check| check.fail);
+                if fail && !yanked.is_empty() {
+                    return Err(GeneratorError::YankedDependencies(
+                        yanked
+                            .iter()
+                            .map(|p| format!("{}@{}", p.name, p.version))
+                            .collect(),
+                    ));
+                }
+            }
+
+            if let Some(deny_policy) = &deny_policy {
+                let violations = deny_policy.check_bom(&mut bom, dependencies.values());
+                for violation in &violations {
+                    log::warn!(
+                        "{} violates the cargo-deny policy: {}",
+                        violation.package,
+                        violation.message
+                    );
+                }
+
+                let fail = config.deny_check.as_ref().is_some_and(|check| check.fail);
+                let denied: Vec<String> = violations
+                    .iter()
+                    .filter(|violation| violation.level == deny::Level::Deny)
+                    .map(|violation| format!("{}: {}", violation.package, violation.message))
+                    .collect();
+                if fail && !denied.is_empty() {
+                    return Err(GeneratorError::PolicyViolations(denied));
+                }
+            }
+
+            // Builds that honor SOURCE_DATE_EPOCH expect identical output for identical inputs
+            if let Some(timestamp) = &source_date_epoch {
+                if let Some(metadata) = bom.metadata.as_mut() {
+                    metadata.timestamp = Some(timestamp.clone());
+                }
+                bom.serial_number = Some(bom.content_serial_number());
+            }
 
             let generated = GeneratedSbom {
                 bom,
@@ -142,6 +296,8 @@ impl SbomGenerator {
             result.push(generated);
         }
 
+        link_workspace_members(&mut result);
+
         Ok(result)
     }
 
@@ -150,24 +306,71 @@ impl SbomGenerator {
         package: &PackageId,
         packages: &PackageMap,
         resolve: &ResolveMap,
+        build_time: &BuildTimeMap,
+        dev_only: &HashSet<PackageId>,
     ) -> Result<(Bom, TargetKinds), GeneratorError> {
         let mut bom = Bom::default();
         let root_package = &packages[package];
+        let edges = incoming_edges(packages, resolve);
+        let duplicates = duplicate_versions(packages, resolve);
 
-        let components: Vec<_> = packages
+        let mut components: Vec<_> = packages
             .values()
             .filter(|p| &p.id != package)
-            .map(|component| self.create_component(component, root_package))
+            .map(|dependency| {
+                let mut component = self.create_component(dependency, root_package);
+                if let Some(uses) = build_time.get(&dependency.id) {
+                    Self::mark_build_time(&mut component, uses);
+                }
+                // Only the tests, examples and benchmarks of the root use them
+                if dev_only.contains(&dependency.id) {
+                    component.scope = Some(Scope::Excluded);
+                }
+                if let Some(edges) = edges.get(&dependency.id) {
+                    Self::add_dependency_kinds(&mut component, edges);
+                }
+                if let Some(duplicate) = duplicates.get(&dependency.id) {
+                    Self::add_duplicate_properties(&mut component, duplicate);
+                }
+                component
+            })
             .collect();
+        // Package IDs are sorted by their source first, which is neither intuitive nor stable
+        // across Cargo versions, so sort by name and version instead
+        components.sort_by_cached_key(|c| {
+            (
+                c.name.to_string(),
+                c.version.as_ref().map(|v| v.to_string()),
+                c.bom_ref.clone(),
+            )
+        });
 
         bom.components = Some(Components(components));
 
-        let (metadata, target_kinds) = self.create_metadata(&packages[package])?;
+        let enabled_features = resolve
+            .get(package)
+            .map_or(&[][..], |node| node.features.as_slice());
+        let (metadata, target_kinds) =
+            self.create_metadata(&packages[package], enabled_features)?;
 
         bom.metadata = Some(metadata);
 
         bom.dependencies = Some(create_dependencies(resolve));
 
+        if let Some(profile) = &self.config.build_profile {
+            let build = BuildDescription {
+                package: &root_package.name,
+                bom_ref: &root_package.id.repr,
+                profile,
+                features: self.config.features.as_ref(),
+                target: self.config.target.as_ref(),
+            };
+            let environment = std::env::vars_os().filter_map(|(name, value)| {
+                Some((name.into_string().ok()?, value.into_string().ok()?))
+            });
+            bom.formulation = Some(build.formulation(environment));
+        }
+
         Ok((bom, target_kinds))
     }
 
@@ -190,44 +393,111 @@ impl SbomGenerator {
             Some(package.id.to_string()),
         );
 
+        let license_override = self
+            .config
+            .license_overrides
+            .as_ref()
+            .and_then(|overrides| {
+                overrides.find(
+                    &name,
+                    &version,
+                    purl.as_ref().map(|p| p.to_string()).as_deref(),
+                )
+            });
+
         component.purl = purl;
         component.scope = Some(Scope::Required);
         component.external_references = Self::get_external_references(package);
-        component.licenses = self.get_licenses(package);
-        component.hashes = self.get_hashes(package);
+        match license_override {
+            Some(license_override) => {
+                component.licenses = Some(Licenses(vec![LicenseChoice::Expression(
+                    license_override.expression.clone(),
+                )]));
+                component.properties = Some(Self::get_license_override_properties(
+                    package,
+                    license_override,
+                ));
+            }
+            None => component.licenses = self.get_licenses(package),
+        }
+        if package.license.is_none() {
+            if let Some((evidence, confidence)) = Self::get_license_evidence(package) {
+                component.evidence = Some(evidence);
+                let mut properties = component.properties.take().unwrap_or(Properties(vec![]));
+                properties.0.extend(confidence);
+                component.properties = Some(properties);
+            }
+        }
+        let vendored = vendored_source(&package.manifest_path);
+        component.hashes = self.get_hashes(package, vendored.as_ref());
+        let registry_references = Self::get_registry_references(package, component.hashes.as_ref());
+        if !registry_references.is_empty() {
+            component
+                .external_references
+                .get_or_insert_with(|| ExternalReferences(vec![]))
+                .0
+                .extend(registry_references);
+        }
+        if let Some(vendored) = &vendored {
+            let mut properties = component.properties.take().unwrap_or(Properties(vec![]));
+            properties.0.push(self.get_vendored_property(vendored));
+            component.properties = Some(properties);
+        }
 
         component.description = package
             .description
             .as_ref()
             .map(|s| NormalizedString::new(s));
 
+        if !package.authors.is_empty() {
+            component.author = Some(NormalizedString::new(&package.authors.join(", ")));
+        }
+        if self.workspace_members.contains(&package.id) {
+            component.supplier = self.config.supplier.clone();
+            component.publisher = self
+                .config
+                .supplier
+                .as_ref()
+                .and_then(|supplier| supplier.name.clone());
+        }
+
         component
     }
 
     /// Same as [Self::create_component] but also includes information
     /// on binaries and libraries comprising it as subcomponents
-    fn create_toplevel_component(&self, package: &Package) -> (Component, TargetKinds) {
+    ///
+    /// Targets that need features which are not among the `enabled_features` are left out,
+    /// as Cargo does not build them.
+    fn create_toplevel_component(
+        &self,
+        package: &Package,
+        enabled_features: &[String],
+    ) -> (Component, TargetKinds) {
         let mut top_component = self.create_component(package, package);
         let mut subcomponents: Vec<Component> = Vec::new();
         let mut target_kinds = HashMap::new();
+        let artifacts = self.artifact_directory();
         for tgt in filter_targets(&package.targets) {
-            // classification
-            #[allow(clippy::if_same_then_else)]
-            let cdx_type = if tgt.is_bin() {
-                Classification::Application
-            // sadly no .is_proc_macro() yet
-            } else if tgt.kind.iter().any(|kind| kind == "proc-macro") {
-                // There isn't a better way to express it with CycloneDX types
-                Classification::Library
-            } else if tgt.kind.iter().any(|kind| kind.contains("lib")) {
-                Classification::Library
-            } else {
-                log::warn!(
-                    "Target {} is neither a binary nor a library! Kinds: {}",
+            if !has_required_features(tgt, enabled_features) {
+                log::debug!(
+                    "Skipping target {}, which requires the features {}",
                     tgt.name,
-                    tgt.kind.join(", ")
+                    tgt.required_features.join(", ")
                 );
                 continue;
+            }
+
+            let cdx_type = match classify_target(tgt) {
+                Some(cdx_type) => cdx_type,
+                None => {
+                    log::warn!(
+                        "Target {} is neither a binary nor a library! Kinds: {}",
+                        tgt.name,
+                        tgt.kind.join(", ")
+                    );
+                    continue;
+                }
             };
 
             // bom_ref
@@ -271,12 +541,33 @@ impl SbomGenerator {
                 );
             }
 
+            if let Some(artifacts) = &artifacts {
+                subcomponent.hashes = artifacts.hashes(tgt);
+            }
+
             subcomponents.push(subcomponent);
         }
         top_component.components = Some(Components(subcomponents));
         (top_component, TargetKinds(target_kinds))
     }
 
+    /// Where to look for the build artifacts if they should be hashed
+    fn artifact_directory(&self) -> Option<ArtifactDirectory> {
+        let profile = self.config.artifact_profile.as_ref()?;
+        let triple = match &self.config.target {
+            Some(Target::SingleTarget(triple)) => triple.clone(),
+            _ => match &self.toolchain.rustc {
+                Some(rustc) => rustc.host.clone(),
+                None => host_platform(),
+            },
+        };
+        Some(ArtifactDirectory::new(
+            self.target_directory.as_std_path(),
+            profile,
+            &triple,
+        ))
+    }
+
     fn get_classification(pkg: &Package) -> Classification {
         // Transitive dependencies that contain both libraries and binaries
         // get surfaces only as a library by `cargo metadata`.
@@ -286,7 +577,10 @@ impl SbomGenerator {
         //
         // We have to make a judgement call how to summarise having both bin and lib targets,
         // and that call is "consider it a binary".
-        if pkg.targets.iter().any(|tgt| tgt.is_bin()) {
+        if filter_targets(&pkg.targets)
+            .filter_map(classify_target)
+            .any(|cdx_type| cdx_type == Classification::Application)
+        {
             return Classification::Application;
         }
 
@@ -358,6 +652,37 @@ impl SbomGenerator {
         None
     }
 
+    /// Where a crate from crates.io can be downloaded, with the checksum of the download,
+    /// and its entry in the registry index, so that every input can be fetched and checked again
+    fn get_registry_references(
+        package: &Package,
+        hashes: Option<&cyclonedx_bom::models::hash::Hashes>,
+    ) -> Vec<ExternalReference> {
+        if !package.source.as_ref().is_some_and(is_crates_io) {
+            return Vec::new();
+        }
+
+        let name = &package.name;
+        let download = format!(
+            "https://static.crates.io/crates/{name}/{name}-{}.crate",
+            package.version
+        );
+        let index_entry = format!("https://index.crates.io/{}", index_path(name));
+        let (Ok(download), Ok(index_entry)) = (Uri::try_from(download), Uri::try_from(index_entry))
+        else {
+            log::warn!("Package {} has no valid crates.io URLs", package.name);
+            return Vec::new();
+        };
+
+        let mut distribution =
+            ExternalReference::new(ExternalReferenceType::Distribution, download);
+        distribution.hashes = hashes.cloned();
+        let mut index = ExternalReference::new(ExternalReferenceType::Other, index_entry);
+        index.comment = Some("Registry index entry".to_string());
+
+        vec![distribution, index]
+    }
+
     fn get_licenses(&self, package: &Package) -> Option<Licenses> {
         let mut licenses = vec![];
 
@@ -433,8 +758,143 @@ impl SbomGenerator {
         Some(Licenses(licenses))
     }
 
-    fn get_hashes(&self, package: &Package) -> Option<cyclonedx_bom::models::hash::Hashes> {
-        match self.crate_hashes.get(&package.id) {
+    /// Records which override replaced the license of the manifest, and what that license was
+    fn get_license_override_properties(
+        package: &Package,
+        license_override: &LicenseOverride,
+    ) -> Properties {
+        log::debug!(
+            "Overriding the license of {}@{} with {} (rule `{}`)",
+            package.name,
+            package.version,
+            license_override.expression.to_string(),
+            license_override.rule
+        );
+        let mut properties = vec![Property::new(
+            "cargo-cyclonedx:license_override:rule",
+            &license_override.rule,
+        )];
+        if let Some(license) = &package.license {
+            properties.push(Property::new(
+                "cargo-cyclonedx:license_override:original",
+                license,
+            ));
+        }
+        Properties(properties)
+    }
+
+    /// Marks a crate that only runs during the build, as it is not part of the artifacts
+    fn mark_build_time(component: &mut Component, uses: &BTreeSet<BuildTimeUse>) {
+        component.scope = Some(Scope::Excluded);
+        let mut properties = component.properties.take().unwrap_or(Properties(vec![]));
+        for build_time_use in uses {
+            properties.0.push(Property::new(
+                "cargo-cyclonedx:build_time:use",
+                build_time_use.as_str(),
+            ));
+        }
+        component.properties = Some(properties);
+    }
+
+    /// Records how the crates in the SBOM depend on a crate
+    fn add_dependency_kinds(component: &mut Component, edges: &IncomingEdges) {
+        let mut properties = component.properties.take().unwrap_or(Properties(vec![]));
+        for kind in &edges.kinds {
+            properties.0.push(Property::new(
+                "cdx:cargo:dependency_kind",
+                &kind.to_string(),
+            ));
+        }
+        if edges.optional {
+            properties
+                .0
+                .push(Property::new("cdx:cargo:optional", "true"));
+        }
+        component.properties = Some(properties);
+    }
+
+    /// Records the other versions of a crate in the SBOM, and the crates pulling in this version
+    fn add_duplicate_properties(component: &mut Component, duplicate: &DuplicateVersion) {
+        let mut properties = component.properties.take().unwrap_or(Properties(vec![]));
+        for version in &duplicate.other_versions {
+            properties.0.push(Property::new(
+                "cargo-cyclonedx:duplicate:other_version",
+                version,
+            ));
+        }
+        for dependent in &duplicate.dependents {
+            properties.0.push(Property::new(
+                "cargo-cyclonedx:duplicate:dependent",
+                dependent,
+            ));
+        }
+        component.properties = Some(properties);
+    }
+
+    /// Records the vendor directory a crate was read from, relative to the workspace root if possible
+    fn get_vendored_property(&self, vendored: &VendoredSource) -> Property {
+        let directory = pathdiff::diff_utf8_paths(&vendored.directory, &self.workspace_root)
+            .filter(|directory| !directory.starts_with(".."))
+            .unwrap_or_else(|| vendored.directory.clone());
+        Property::new("cargo-cyclonedx:vendored:directory", directory.as_str())
+    }
+
+    /// Tries to recognize the license files of a package without a `license` field.
+    /// The result is only a guess, so it is recorded as evidence instead of a declared license,
+    /// along with the confidence in every license found.
+    ///
+    /// The whole source tree of path and git dependencies is scanned, as the sources of
+    /// a registry crate are only what its author chose to publish.
+    fn get_license_evidence(package: &Package) -> Option<(ComponentEvidence, Vec<Property>)> {
+        let package_dir = package.manifest_path.parent()?;
+        let deep = package
+            .source
+            .as_ref()
+            .map_or(true, |source| source.repr.starts_with("git+"));
+        let license_file = package.license_file().map(Utf8PathBuf::into_std_path_buf);
+        let detected = scan_licenses(package_dir.as_std_path(), license_file.as_deref(), deep);
+
+        let mut licenses = Vec::new();
+        let mut properties = Vec::new();
+        for detected in detected {
+            let license = match License::license_id(&detected.license) {
+                Ok(license) => LicenseChoice::License(license),
+                Err(_) => match SpdxExpression::try_from(detected.license.clone()) {
+                    Ok(expression) => LicenseChoice::Expression(expression),
+                    Err(error) => {
+                        log::debug!(
+                            "Package {} has an invalid license tag {}: {}",
+                            package.name,
+                            detected.license,
+                            error
+                        );
+                        continue;
+                    }
+                },
+            };
+            licenses.push(license);
+            properties.push(Property::new(
+                "cargo-cyclonedx:license_evidence:confidence",
+                &format!("{}: {:.2}", detected.license, detected.confidence),
+            ));
+        }
+
+        (!licenses.is_empty()).then(|| {
+            let mut evidence = ComponentEvidence::default();
+            evidence.licenses = Some(Licenses(licenses));
+            (evidence, properties)
+        })
+    }
+
+    fn get_hashes(
+        &self,
+        package: &Package,
+        vendored: Option<&VendoredSource>,
+    ) -> Option<cyclonedx_bom::models::hash::Hashes> {
+        // Vendored crates record the checksum of the package they were vendored from,
+        // which is what Cargo.lock would record too
+        let vendored_hash = vendored.and_then(|vendored| vendored.checksum.as_ref());
+        match self.crate_hashes.get(&package.id).or(vendored_hash) {
             Some(hash) => Some(cyclonedx_bom::models::hash::Hashes(vec![to_bom_hash(hash)])),
             None => {
                 // Log level is set to debug because this is perfectly normal:
@@ -454,6 +914,7 @@ impl SbomGenerator {
     fn create_metadata(
         &self,
         package: &Package,
+        enabled_features: &[String],
     ) -> Result<(Metadata, TargetKinds), GeneratorError> {
         let authors = Self::create_authors(package);
 
@@ -462,19 +923,107 @@ impl SbomGenerator {
             metadata.authors = Some(authors);
         }
 
-        let (mut component, target_kinds) = self.create_toplevel_component(package);
+        let (mut component, target_kinds) =
+            self.create_toplevel_component(package, enabled_features);
 
-        component.component_type = Self::get_classification(package);
+        component.component_type = match &self.config.component_type {
+            Some(component_type) => component_type.clone(),
+            None => Self::get_classification(package),
+        };
+        // The scope describes how the subject of the SBOM uses a component,
+        // so it is meaningless for the subject itself
+        component.scope = None;
+
+        if let Some(source_hashing) = self.config.source_hashing {
+            let manifest_path = package.manifest_path.as_std_path();
+            match SourceHashes::of_package(manifest_path, self.workspace_root.as_std_path()) {
+                Ok(hashes) => component
+                    .properties
+                    .get_or_insert_with(|| Properties(Vec::new()))
+                    .0
+                    .extend(hashes.properties(source_hashing == SourceHashing::Files)),
+                Err(error) => {
+                    log::warn!("Failed to hash the sources of {}: {}", package.name, error)
+                }
+            }
+        }
 
         metadata.component = Some(component);
+        metadata.supplier = self.config.supplier.clone();
+        metadata.manufacture = self.config.manufacture.clone();
 
-        let tool = Tool::new("CycloneDX", "cargo-cyclonedx", env!("CARGO_PKG_VERSION"));
+        let mut cargo_cyclonedx =
+            Tool::new("CycloneDX", "cargo-cyclonedx", env!("CARGO_PKG_VERSION"));
+        cargo_cyclonedx.hashes = executable_hashes();
+        let mut tools = vec![cargo_cyclonedx];
+        tools.extend(self.create_toolchain_tools());
 
-        metadata.tools = Some(Tools(vec![tool]));
+        // Spec versions before 1.5 convert the components back to the list of tools
+        metadata.tools = Some(Tools::List(tools).into_components());
+
+        let properties: Vec<Property> = self
+            .create_target_property()
+            .into_iter()
+            .chain(self.create_toolchain_properties())
+            .collect();
+        if !properties.is_empty() {
+            metadata.properties = Some(Properties(properties));
+        }
 
         Ok((metadata, target_kinds))
     }
 
+    /// Records the platform the dependency graph was resolved for, using the
+    /// [CycloneDX property taxonomy](https://github.com/CycloneDX/cyclonedx-property-taxonomy/blob/main/cdx/rustc.md)
+    fn create_target_property(&self) -> Option<Property> {
+        let property = match self.config.target.as_ref()? {
+            Target::AllTargets => Property::new("cdx:rustc:sbom:target:all_targets", "true"),
+            Target::SingleTarget(triple) => Property::new("cdx:rustc:sbom:target:triple", triple),
+        };
+        Some(property)
+    }
+
+    /// The compiler and build tool, whose exact versions are part of the provenance of the build
+    fn create_toolchain_tools(&self) -> Vec<Tool> {
+        let rustc = self
+            .toolchain
+            .rustc
+            .as_ref()
+            .map(|rustc| Tool::new("The Rust Project", "rustc", &rustc.release));
+        let cargo = self
+            .toolchain
+            .cargo
+            .as_ref()
+            .map(|cargo| Tool::new("The Rust Project", "cargo", cargo));
+        rustc.into_iter().chain(cargo).collect()
+    }
+
+    /// Details of the `rustc` build that don't fit into a [`Tool`]
+    fn create_toolchain_properties(&self) -> Vec<Property> {
+        let rustc = match &self.toolchain.rustc {
+            Some(rustc) => rustc,
+            None => return Vec::new(),
+        };
+
+        let mut properties = vec![
+            Property::new("cdx:rustc:toolchain:channel", rustc.channel()),
+            Property::new("cdx:rustc:toolchain:host", &rustc.host),
+        ];
+        if let Some(commit_hash) = &rustc.commit_hash {
+            properties.push(Property::new(
+                "cdx:rustc:toolchain:commit_hash",
+                commit_hash,
+            ));
+        }
+        if let Some(commit_date) = &rustc.commit_date {
+            properties.push(Property::new(
+                "cdx:rustc:toolchain:commit_date",
+                commit_date,
+            ));
+        }
+        properties
+    }
+
     fn create_authors(package: &Package) -> Vec<OrganizationalContact> {
         let mut authors = vec![];
         let mut invalid_authors = vec![];
@@ -529,6 +1078,37 @@ fn filter_targets(
     })
 }
 
+/// Checks whether Cargo builds the target with these features of its package enabled
+pub(crate) fn has_required_features(
+    tgt: &cargo_metadata::Target,
+    enabled_features: &[String],
+) -> bool {
+    tgt.required_features
+        .iter()
+        .all(|feature| enabled_features.contains(feature))
+}
+
+/// Maps the kinds of a Cargo target to the closest CycloneDX classification.
+/// Returns `None` if the target does not produce a binary or a library.
+fn classify_target(tgt: &cargo_metadata::Target) -> Option<Classification> {
+    if tgt.is_bin() {
+        return Some(Classification::Application);
+    }
+
+    // sadly no .is_proc_macro() or .is_cdylib() yet
+    let is_library = tgt.kind.iter().any(|kind| {
+        matches!(
+            kind.as_str(),
+            // `cdylib` and `staticlib` are meant to be linked into programs written in other
+            // languages, the rest into Rust programs. Either way the artifact is a library.
+            // There isn't a better way to express proc-macros with CycloneDX types either.
+            "lib" | "rlib" | "dylib" | "cdylib" | "staticlib" | "proc-macro"
+        )
+    });
+
+    is_library.then_some(Classification::Library)
+}
+
 fn index_packages(packages: Vec<Package>) -> PackageMap {
     packages
         .into_iter()
@@ -565,34 +1145,163 @@ pub enum GeneratorError {
     #[error("Error creating Metadata")]
     MetadataError(#[from] MetadataError),
 
+    #[error("Invalid configuration in the manifest")]
+    ManifestConfigError(#[from] ManifestConfigError),
+
     #[error("Could not parse author string: {}", .0)]
     AuthorParseError(String),
+
+    #[error("Failed to load the advisory database")]
+    AdvisoryDatabaseError(#[from] AdvisoryError),
+
+    #[error("Failed to check the dependencies for yanked versions")]
+    YankedCheckError(#[from] YankedError),
+
+    #[error("The dependencies include yanked versions: {}", .0.join(", "))]
+    YankedDependencies(Vec<String>),
+
+    #[error("Failed to resolve the features of the dependencies")]
+    ResolutionError(#[from] ResolutionError),
+
+    #[error("Failed to load the cargo-deny policy")]
+    DenyPolicyError(#[from] DenyError),
+
+    #[error("The dependencies violate the cargo-deny policy: {}", .0.join("; "))]
+    PolicyViolations(Vec<String>),
+}
+
+/// The oldest version of the specification that can represent everything in the BOM,
+/// so that the SBOM can be read by as many tools as possible
+fn required_spec_version(bom: &Bom, signed: bool) -> SpecVersion {
+    // Component types such as `platform` were only added in CycloneDX 1.5
+    let component_type_allowed = |version| {
+        bom.metadata
+            .as_ref()
+            .and_then(|meta| meta.component.as_ref())
+            .map_or(true, |component| {
+                component.component_type.is_allowed_in(version)
+            })
+    };
+
+    if bom.formulation.is_some() || !component_type_allowed(SpecVersion::V1_4) {
+        SpecVersion::V1_5
+    } else if signed || bom.vulnerabilities.is_some() || bom.signature.is_some() {
+        // Vulnerabilities and signatures were only added in CycloneDX 1.4
+        SpecVersion::V1_4
+    } else {
+        SpecVersion::V1_3
+    }
 }
 
 /// Generates the `Dependencies` field in the final SBOM
 fn create_dependencies(resolve: &ResolveMap) -> Dependencies {
     let deps = resolve
         .values()
-        .map(|node| Dependency {
-            dependency_ref: node.id.to_string(),
-            dependencies: node.dependencies.iter().map(|d| d.to_string()).collect(),
+        .map(|node| {
+            let mut dependencies: Vec<String> =
+                node.dependencies.iter().map(|d| d.to_string()).collect();
+            dependencies.sort();
+            Dependency::new(node.id.to_string(), dependencies)
         })
         .collect();
     Dependencies(deps)
 }
 
+/// Reads the timestamp to use for reproducible builds from the `SOURCE_DATE_EPOCH`
+/// environment variable, as specified by <https://reproducible-builds.org/specs/source-date-epoch/>
+fn source_date_epoch() -> Option<DateTime> {
+    let value = std::env::var("SOURCE_DATE_EPOCH").ok()?;
+    match value
+        .trim()
+        .parse::<i64>()
+        .map_err(|e| e.to_string())
+        .and_then(|seconds| DateTime::from_unix_timestamp(seconds).map_err(|e| e.to_string()))
+    {
+        Ok(timestamp) => Some(timestamp),
+        Err(e) => {
+            log::warn!("Ignoring invalid SOURCE_DATE_EPOCH \"{}\": {}", value, e);
+            None
+        }
+    }
+}
+
+/// Workspace members that depend on each other are recorded as regular components
+/// in each other's SBOMs. Since the SBOMs of all members are generated together,
+/// we can also point from such a component to the SBOM that describes it in detail
+/// using a [BOM-Link](https://cyclonedx.org/capabilities/bomlink/).
+fn link_workspace_members(sboms: &mut [GeneratedSbom]) {
+    let bom_links: HashMap<String, Uri> = sboms
+        .iter()
+        .filter_map(|sbom| {
+            let bom_ref = sbom
+                .bom
+                .metadata
+                .as_ref()?
+                .component
+                .as_ref()?
+                .bom_ref
+                .clone()?;
+            let link = bom_link(&sbom.bom, &bom_ref)?;
+            Some((bom_ref, link))
+        })
+        .collect();
+
+    for sbom in sboms.iter_mut() {
+        let Some(components) = sbom.bom.components.as_mut() else {
+            continue;
+        };
+        for component in components.0.iter_mut() {
+            let Some(link) = component
+                .bom_ref
+                .as_ref()
+                .and_then(|bom_ref| bom_links.get(bom_ref))
+            else {
+                continue;
+            };
+
+            let mut reference = ExternalReference::new(ExternalReferenceType::Bom, link.clone());
+            reference.comment = Some(format!("SBOM of workspace member {}", component.name));
+            component
+                .external_references
+                .get_or_insert_with(|| ExternalReferences(Vec::new()))
+                .0
+                .push(reference);
+        }
+    }
+}
+
+/// Builds a BOM-Link of the form `urn:cdx:serial-number/version#bom-ref`
+/// pointing to a component within the given SBOM
+fn bom_link(bom: &Bom, bom_ref: &str) -> Option<Uri> {
+    let serial_number = bom.serial_number.as_ref()?.to_string();
+    let uuid = serial_number.strip_prefix("urn:uuid:")?;
+    let link = format!("urn:cdx:{}/{}#{}", uuid, bom.version, urlencode(bom_ref));
+    match Uri::try_from(link) {
+        Ok(uri) => Some(uri),
+        Err(e) => {
+            log::warn!("Failed to create a BOM-Link to {}: {}", bom_ref, e);
+            None
+        }
+    }
+}
+
+/// The dev-dependencies of the root are only kept with `dev`
 fn top_level_dependencies(
     root: &PackageId,
     packages: &PackageMap,
     resolve: &ResolveMap,
+    dev: bool,
 ) -> (PackageMap, ResolveMap) {
     log::trace!("Adding top-level dependencies to SBOM");
 
     // Only include packages that have dependency kinds other than "Development"
-    let root_node = strip_dev_dependencies(&resolve[root]);
+    let root_node = match dev {
+        true => resolve[root].clone(),
+        false => strip_dev_dependencies(&resolve[root]),
+    };
 
     let mut pkg_result = PackageMap::new();
-    // Record the root package, then its direct non-dev dependencies
+    // Record the root package, then its direct dependencies
     pkg_result.insert(root.to_owned(), packages[root].to_owned());
     for id in &root_node.dependencies {
         pkg_result.insert((*id).to_owned(), packages[id].to_owned());
@@ -612,10 +1321,13 @@ fn top_level_dependencies(
     (pkg_result, resolve_result)
 }
 
+/// The dev-dependencies of the root are only kept with `dev`,
+/// the ones of other crates are never built
 fn all_dependencies(
     root: &PackageId,
     packages: &PackageMap,
     resolve: &ResolveMap,
+    dev: bool,
 ) -> (PackageMap, ResolveMap) {
     log::trace!("Adding all dependencies to SBOM");
 
@@ -635,10 +1347,14 @@ fn all_dependencies(
         for node in current_queue.drain(..) {
             // If we haven't processed this node yet...
             if !out_resolve.contains_key(&node.id) {
-                // Add the node to the output
-                out_resolve.insert(node.id.to_owned(), strip_dev_dependencies(node));
+                let node = match dev && &node.id == root {
+                    true => node.clone(),
+                    false => strip_dev_dependencies(node),
+                };
                 // Queue its dependencies for the next BFS loop iteration
-                next_queue.extend(non_dev_dependencies(&node.deps).map(|dep| &resolve[&dep.pkg]));
+                next_queue.extend(node.deps.iter().map(|dep| &resolve[&dep.pkg]));
+                // Add the node to the output
+                out_resolve.insert(node.id.to_owned(), node);
             }
         }
         std::mem::swap(&mut current_queue, &mut next_queue);
@@ -654,6 +1370,301 @@ fn all_dependencies(
     (out_packages, out_resolve)
 }
 
+/// How a crate that only runs during the build is used
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+enum BuildTimeUse {
+    /// In a build script, as a build dependency or one of its dependencies
+    BuildScript,
+    /// In the compiler, as a procedural macro or one of its dependencies
+    ProcMacro,
+}
+
+impl BuildTimeUse {
+    fn as_str(&self) -> &'static str {
+        match self {
+            BuildTimeUse::BuildScript => "build-script",
+            BuildTimeUse::ProcMacro => "proc-macro",
+        }
+    }
+}
+
+type BuildTimeMap = BTreeMap<PackageId, BTreeSet<BuildTimeUse>>;
+
+/// Finds the crates that only run during the build of the root: its build dependencies,
+/// the procedural macros it uses, and the crates nothing but those depend on.
+/// A crate that is also a normal dependency of the artifacts is not one of them.
+fn build_time_dependencies(
+    root: &PackageId,
+    packages: &PackageMap,
+    resolve: &ResolveMap,
+) -> BuildTimeMap {
+    // Crates reached through normal dependencies only are visited without a use,
+    // as they end up in the artifacts
+    let mut visited: HashSet<(&PackageId, Option<BuildTimeUse>)> = HashSet::new();
+    let mut queue = vec![(root, None)];
+    while let Some((id, build_time_use)) = queue.pop() {
+        if !visited.insert((id, build_time_use)) {
+            continue;
+        }
+        let Some(node) = resolve.get(id) else {
+            continue;
+        };
+        for dep in non_dev_dependencies(&node.deps) {
+            let normal = dep
+                .dep_kinds
+                .iter()
+                .any(|kind| kind.kind == DependencyKind::Normal);
+            let dep_use = build_time_use.or(match normal {
+                false => Some(BuildTimeUse::BuildScript),
+                true if packages.get(&dep.pkg).is_some_and(is_proc_macro) => {
+                    Some(BuildTimeUse::ProcMacro)
+                }
+                true => None,
+            });
+            queue.push((&dep.pkg, dep_use));
+        }
+    }
+
+    let runtime: HashSet<&PackageId> = visited
+        .iter()
+        .filter(|(_, build_time_use)| build_time_use.is_none())
+        .map(|(id, _)| *id)
+        .collect();
+    let mut build_time = BuildTimeMap::new();
+    for (id, build_time_use) in visited {
+        if let (Some(build_time_use), false) = (build_time_use, runtime.contains(id)) {
+            build_time
+                .entry(id.to_owned())
+                .or_default()
+                .insert(build_time_use);
+        }
+    }
+    build_time
+}
+
+fn is_proc_macro(package: &Package) -> bool {
+    package
+        .targets
+        .iter()
+        .any(|target| target.kind.iter().any(|kind| kind == "proc-macro"))
+}
+
+/// The crates in `dependencies` that are only reachable through the dev-dependencies of the root
+fn dev_only_dependencies(
+    root: &PackageId,
+    dependencies: &PackageMap,
+    packages: &PackageMap,
+    resolve: &ResolveMap,
+) -> HashSet<PackageId> {
+    let (built, _) = all_dependencies(root, packages, resolve, false);
+    dependencies
+        .keys()
+        .filter(|id| !built.contains_key(id))
+        .cloned()
+        .collect()
+}
+
+/// The kinds of the dependencies on a crate, in the order normal, build, dev
+#[derive(Debug, Default)]
+struct IncomingEdges {
+    kinds: Vec<DependencyKind>,
+    /// Whether every dependency on the crate is optional, i.e. only enabled by a feature
+    optional: bool,
+}
+
+fn incoming_edges(
+    packages: &PackageMap,
+    resolve: &ResolveMap,
+) -> BTreeMap<PackageId, IncomingEdges> {
+    let mut edges: BTreeMap<PackageId, IncomingEdges> = BTreeMap::new();
+    let mut required: HashSet<&PackageId> = HashSet::new();
+    for node in resolve.values() {
+        let declared = packages
+            .get(&node.id)
+            .map_or(&[][..], |package| package.dependencies.as_slice());
+        for dep in &node.deps {
+            let entry = edges.entry(dep.pkg.clone()).or_default();
+            for kind in &dep.dep_kinds {
+                if !entry.kinds.contains(&kind.kind) {
+                    entry.kinds.push(kind.kind);
+                }
+                // The resolved dependency is named after its library target, or the rename
+                let optional = declared.iter().any(|declared| {
+                    declared.optional
+                        && declared.kind == kind.kind
+                        && match &declared.rename {
+                            Some(rename) => rename.replace('-', "_") == dep.name,
+                            None => packages
+                                .get(&dep.pkg)
+                                .is_some_and(|package| package.name == declared.name),
+                        }
+                });
+                if !optional {
+                    required.insert(&dep.pkg);
+                }
+            }
+        }
+    }
+
+    for (id, edge) in edges.iter_mut() {
+        edge.optional = !required.contains(id);
+        edge.kinds.sort_by_key(|kind| match kind {
+            DependencyKind::Normal => 0,
+            DependencyKind::Build => 1,
+            _ => 2,
+        });
+    }
+    edges
+}
+
+/// A crate that is in the dependency graph in more than one version
+#[derive(Debug, Default, PartialEq, Eq)]
+struct DuplicateVersion {
+    other_versions: Vec<String>,
+    /// The crates depending on this version, as `name@version`
+    dependents: Vec<String>,
+}
+
+/// Finds the crates that are in the dependency graph in several versions
+fn duplicate_versions(
+    packages: &PackageMap,
+    resolve: &ResolveMap,
+) -> BTreeMap<PackageId, DuplicateVersion> {
+    let mut by_name: BTreeMap<&str, Vec<&Package>> = BTreeMap::new();
+    for package in packages.values() {
+        by_name.entry(&package.name).or_default().push(package);
+    }
+
+    let mut duplicates = BTreeMap::new();
+    for versions in by_name.values().filter(|versions| versions.len() > 1) {
+        log::info!(
+            "The dependency graph contains {} versions of {}",
+            versions.len(),
+            versions[0].name
+        );
+        for package in versions {
+            let mut other_versions: Vec<String> = versions
+                .iter()
+                .filter(|other| other.id != package.id)
+                .map(|other| other.version.to_string())
+                .collect();
+            other_versions.sort();
+            other_versions.dedup();
+
+            let mut dependents: Vec<String> = resolve
+                .values()
+                .filter(|node| node.dependencies.contains(&package.id))
+                .filter_map(|node| packages.get(&node.id))
+                .map(|dependent| format!("{}@{}", dependent.name, dependent.version))
+                .collect();
+            dependents.sort();
+            dependents.dedup();
+
+            duplicates.insert(
+                package.id.clone(),
+                DuplicateVersion {
+                    other_versions,
+                    dependents,
+                },
+            );
+        }
+    }
+    duplicates
+}
+
+/// Removes the crates and the dependencies on them from the dependency graph
+fn exclude_packages(excluded: &BuildTimeMap, packages: &mut PackageMap, resolve: &mut ResolveMap) {
+    packages.retain(|id, _| !excluded.contains_key(id));
+    resolve.retain(|id, _| !excluded.contains_key(id));
+    for node in resolve.values_mut() {
+        node.deps.retain(|dep| !excluded.contains_key(&dep.pkg));
+        node.dependencies.retain(|id| !excluded.contains_key(id));
+    }
+}
+
+/// Keeps only the dependency edges Cargo builds. The crates no longer reachable
+/// from the root are dropped when collecting the dependencies.
+fn built_dependencies(
+    built: &BuiltGraph,
+    packages: &PackageMap,
+    resolve: &ResolveMap,
+) -> (PackageMap, ResolveMap) {
+    let resolve = resolve
+        .values()
+        .map(|node| {
+            let mut node = node.clone();
+            let package = &packages[&node.id];
+            node.deps
+                .retain(|dep| built.has_edge(package, &packages[&dep.pkg]));
+            node.dependencies = node.deps.iter().map(|d| d.pkg.to_owned()).collect();
+            (node.id.to_owned(), node)
+        })
+        .collect();
+    (packages.clone(), resolve)
+}
+
+/// Removes the crates rejected by the filter from the dependency graph, except for the root.
+/// The dependencies of a removed crate are attached to the crates depending on it,
+/// so the graph still shows everything that is built. Returns `None` if nothing is filtered.
+fn filter_packages(
+    root: &PackageId,
+    filter: &PackageFilter,
+    packages: &PackageMap,
+    resolve: &ResolveMap,
+) -> Option<(PackageMap, ResolveMap)> {
+    if filter.is_empty() {
+        return None;
+    }
+    let is_removed = |id: &PackageId| id != root && !filter.allows(&packages[id].name);
+
+    let out_resolve: ResolveMap = resolve
+        .values()
+        .filter(|node| !is_removed(&node.id))
+        .map(|node| {
+            let mut node = node.clone();
+            node.deps = contract_dependencies(&node.deps, resolve, &is_removed);
+            node.dependencies = node.deps.iter().map(|d| d.pkg.to_owned()).collect();
+            (node.id.to_owned(), node)
+        })
+        .collect();
+
+    let out_packages = packages
+        .iter()
+        .filter(|(id, _pkg)| !is_removed(id))
+        .map(|(id, pkg)| (id.to_owned(), pkg.to_owned()))
+        .collect();
+
+    Some((out_packages, out_resolve))
+}
+
+/// Replaces the removed crates among `deps` by their own dependencies, recursively.
+/// Those inherit the kind of the dependency on the removed crate.
+fn contract_dependencies(
+    deps: &[NodeDep],
+    resolve: &ResolveMap,
+    is_removed: &impl Fn(&PackageId) -> bool,
+) -> Vec<NodeDep> {
+    let mut result: Vec<NodeDep> = Vec::new();
+    let mut visited_removed = HashSet::new();
+    let mut stack: Vec<NodeDep> = deps.to_vec();
+
+    while let Some(dep) = stack.pop() {
+        if !is_removed(&dep.pkg) {
+            if !result.iter().any(|d| d.pkg == dep.pkg) {
+                result.push(dep);
+            }
+        } else if visited_removed.insert(dep.pkg.to_owned()) {
+            // dev-dependencies of a dependency are never built
+            for inner in non_dev_dependencies(&resolve[&dep.pkg].deps) {
+                let mut inner = inner.clone();
+                inner.dep_kinds = dep.dep_kinds.clone();
+                stack.push(inner);
+            }
+        }
+    }
+    result
+}
+
 fn strip_dev_dependencies(node: &Node) -> Node {
     let mut node = node.clone();
     node.deps = non_dev_dependencies(&node.deps).cloned().collect();
@@ -686,65 +1697,212 @@ pub struct GeneratedSbom {
     pub target_kinds: TargetKinds,
 }
 
+/// A file an SBOM was written to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SbomFile {
+    pub path: PathBuf,
+    /// The name of the component described by the SBOM
+    pub name: String,
+    /// The version of the component described by the SBOM
+    pub version: Option<String>,
+}
+
 impl GeneratedSbom {
     /// Writes SBOM to either a JSON or XML file in the same folder as `Cargo.toml` manifest
-    pub fn write_to_files(self) -> Result<(), SbomWriterError> {
+    pub fn write_to_files(self) -> Result<Vec<SbomFile>, SbomWriterError> {
+        self.write_artifacts_to_files(None)
+    }
+
+    /// Same as [Self::write_to_files], but if an SBOM is written per artifact,
+    /// only writes the SBOMs of the artifacts with the given names
+    pub fn write_selected_artifacts_to_files(
+        self,
+        artifacts: &[String],
+    ) -> Result<Vec<SbomFile>, SbomWriterError> {
+        self.write_artifacts_to_files(Some(artifacts))
+    }
+
+    fn write_artifacts_to_files(
+        self,
+        artifacts: Option<&[String]>,
+    ) -> Result<Vec<SbomFile>, SbomWriterError> {
         match self.sbom_config.output_options().prefix {
             Prefix::Pattern(Pattern::Bom | Pattern::Package) | Prefix::Custom(_) => {
-                let path = self.manifest_path.with_file_name(self.filename(None, &[]));
-                Self::write_to_file(self.bom, &path, &self.sbom_config)
+                self.write_spec_versions(&self.bom, None, &[])
             }
             Prefix::Pattern(pattern @ (Pattern::Binary | Pattern::CargoTarget)) => {
-                for (sbom, target_kind) in
-                    Self::per_artifact_sboms(&self.bom, &self.target_kinds, pattern)
-                {
+                let mut files = Vec::new();
+                for (sbom, target_kind) in self.per_artifact_sboms(pattern) {
                     let meta = sbom.metadata.as_ref().unwrap();
-                    let name = meta.component.as_ref().unwrap().name.as_ref();
-                    let path = self
-                        .manifest_path
-                        .with_file_name(self.filename(Some(name), &target_kind));
-                    Self::write_to_file(sbom, &path, &self.sbom_config)?;
+                    let name = meta.component.as_ref().unwrap().name.to_string();
+                    if artifacts.is_some_and(|artifacts| !artifacts.contains(&name)) {
+                        continue;
+                    }
+                    files.extend(self.write_spec_versions(&sbom, Some(&name), &target_kind)?);
                 }
-                Ok(())
+                Ok(files)
             }
         }
     }
 
-    fn write_to_file(bom: Bom, path: &Path, config: &SbomConfig) -> Result<(), SbomWriterError> {
-        // If running in debug mode, validate that the SBOM is self-consistent and well-formed
-        if cfg!(debug_assertions) {
-            let result = bom.validate();
-            if let ValidationResult::Failed { reasons } = result {
-                panic!("The generated SBOM failed validation: {:?}", &reasons);
+    /// Writes a file for every configured spec version, or for the lowest version
+    /// that can represent all of the SBOM if none is configured
+    fn write_spec_versions(
+        &self,
+        bom: &Bom,
+        binary_name: Option<&str>,
+        target_kind: &[String],
+    ) -> Result<Vec<SbomFile>, SbomWriterError> {
+        self.validate(bom)?;
+
+        let required = required_spec_version(bom, self.sbom_config.signing.is_some());
+        let spec_versions = match &self.sbom_config.spec_versions {
+            Some(spec_versions) => spec_versions.clone(),
+            None => vec![required],
+        };
+
+        let mut files = Vec::new();
+        for &spec_version in &spec_versions {
+            if spec_version < required {
+                log::warn!(
+                    "Parts of the SBOM need spec version {} and are omitted from the version {} document",
+                    required.to_string(),
+                    spec_version.to_string()
+                );
             }
+            // Only tell the documents apart by their names if there are several
+            let version_suffix = (spec_versions.len() > 1).then_some(spec_version);
+            let path = self.manifest_path.with_file_name(self.filename(
+                binary_name,
+                target_kind,
+                version_suffix,
+            ));
+            files.push(Self::write_to_file(
+                bom.clone(),
+                &path,
+                &self.sbom_config,
+                spec_version,
+            )?);
+        }
+        Ok(files)
+    }
+
+    /// Checks that the SBOM is self-consistent and well-formed, reporting every failure
+    fn validate(&self, bom: &Bom) -> Result<(), SbomWriterError> {
+        let ValidationResult::Failed { reasons } = bom.validate() else {
+            return Ok(());
+        };
+
+        let validation = self.sbom_config.validation();
+        let level = match validation {
+            Validation::Fail => log::Level::Error,
+            Validation::Warn => log::Level::Warn,
+        };
+        for reason in &reasons {
+            log::log!(level, "{}: {}", reason.context, reason.message);
+        }
+
+        match validation {
+            Validation::Fail => Err(SbomWriterError::ValidationFailed(reasons.len())),
+            Validation::Warn => Ok(()),
+        }
+    }
+
+    fn write_to_file(
+        bom: Bom,
+        path: &Path,
+        config: &SbomConfig,
+        spec_version: SpecVersion,
+    ) -> Result<SbomFile, SbomWriterError> {
+        let component = bom
+            .metadata
+            .as_ref()
+            .and_then(|meta| meta.component.as_ref());
+        let sbom_file = SbomFile {
+            path: path.to_path_buf(),
+            name: component.map_or_else(String::new, |component| component.name.to_string()),
+            version: component
+                .and_then(|component| component.version.as_ref())
+                .map(|version| version.to_string()),
+        };
+
+        let signing_key = config
+            .signing
+            .as_ref()
+            .map(|signing| SigningKey::load(&signing.key, signing.algorithm))
+            .transpose()?;
+        let mut bom = bom;
+        if let (Some(key), Format::Json) = (&signing_key, config.format()) {
+            key.sign(&mut bom, spec_version)?;
+        }
+
+        let mut document = Vec::new();
+        Self::output_bom(bom, config.format(), spec_version, &mut document)?;
+        // The enveloped signature of an XML document covers the document as it is written
+        if let (Some(key), Format::Xml) = (&signing_key, config.format()) {
+            document = key.sign_xml(&document)?;
         }
 
         log::info!("Outputting {}", path.display());
         let file = File::create(path)?;
         let mut writer = BufWriter::new(file);
-        match config.format() {
-            Format::Json => {
-                bom.output_as_json_v1_3(&mut writer)
-                    .map_err(SbomWriterError::JsonWriteError)?;
+        match config.compression {
+            None => writer.write_all(&document)?,
+            Some(Compression::Gzip) => {
+                let mut encoder = GzEncoder::new(&mut writer, flate2::Compression::default());
+                encoder.write_all(&document)?;
+                encoder.finish()?;
             }
-            Format::Xml => {
-                bom.output_as_xml_v1_3(&mut writer)
-                    .map_err(SbomWriterError::XmlWriteError)?;
+            Some(Compression::Zstd) => {
+                let mut encoder = zstd::Encoder::new(&mut writer, 0)?;
+                encoder.write_all(&document)?;
+                encoder.finish()?;
             }
         }
 
         // Flush the writer explicitly to catch and report any I/O errors
         writer.flush()?;
 
-        Ok(())
+        Ok(sbom_file)
+    }
+
+    fn output_bom<W: Write>(
+        bom: Bom,
+        format: Format,
+        spec_version: SpecVersion,
+        writer: &mut W,
+    ) -> Result<(), SbomWriterError> {
+        match (format, spec_version) {
+            (Format::Json, SpecVersion::V1_3) => bom
+                .output_as_json_v1_3(writer)
+                .map_err(SbomWriterError::JsonWriteError),
+            (Format::Json, SpecVersion::V1_4) => bom
+                .output_as_json_v1_4(writer)
+                .map_err(SbomWriterError::JsonWriteError),
+            (Format::Json, _) => bom
+                .output_as_json_v1_5(writer)
+                .map_err(SbomWriterError::JsonWriteError),
+            (Format::Xml, SpecVersion::V1_3) => bom
+                .output_as_xml_v1_3(writer)
+                .map_err(SbomWriterError::XmlWriteError),
+            (Format::Xml, SpecVersion::V1_4) => bom
+                .output_as_xml_v1_4(writer)
+                .map_err(SbomWriterError::XmlWriteError),
+            (Format::Xml, _) => bom
+                .output_as_xml_v1_5(writer)
+                .map_err(SbomWriterError::XmlWriteError),
+        }
     }
 
     /// Returns an iterator over SBOMs and their associated target kinds
-    fn per_artifact_sboms<'a>(
-        bom: &'a Bom,
-        target_kinds: &'a TargetKinds,
+    fn per_artifact_sboms(
+        &self,
         pattern: Pattern,
-    ) -> impl Iterator<Item = (Bom, Vec<String>)> + 'a {
+    ) -> impl Iterator<Item = (Bom, Vec<String>)> + '_ {
+        let bom = &self.bom;
+        let target_kinds = &self.target_kinds;
+        // A configured type describes whatever the SBOM is about, so it applies to each artifact
+        let keep_component_type = self.sbom_config.component_type.is_some();
         let meta = bom.metadata.as_ref().unwrap();
         let crate_component = meta.component.as_ref().unwrap();
         let components = crate_component.components.as_ref().unwrap();
@@ -766,7 +1924,7 @@ impl GeneratedSbom {
                     Pattern::Bom | Pattern::Package => unreachable!(),
                 }
             })
-            .map(|component| {
+            .map(move |component| {
                 let target_kind = &target_kinds.0[component.bom_ref.as_ref().unwrap()];
                 // In the original SBOM the toplevel component describes a crate.
                 // We need to change it to describe a specific binary.
@@ -776,14 +1934,21 @@ impl GeneratedSbom {
                 let metadata = new_bom.metadata.as_mut().unwrap();
                 let toplevel_component = metadata.component.as_mut().unwrap();
                 toplevel_component.name = component.name.clone();
-                toplevel_component.component_type = component.component_type.clone();
+                if !keep_component_type {
+                    toplevel_component.component_type = component.component_type.clone();
+                }
                 toplevel_component.purl = component.purl.clone();
 
                 (new_bom, target_kind.clone())
             })
     }
 
-    fn filename(&self, binary_name: Option<&str>, target_kind: &[String]) -> String {
+    fn filename(
+        &self,
+        binary_name: Option<&str>,
+        target_kind: &[String],
+        spec_version: Option<SpecVersion>,
+    ) -> String {
         let output_options = self.sbom_config.output_options();
         let prefix = match &output_options.prefix {
             Prefix::Pattern(Pattern::Bom) => "bom".to_string(),
@@ -811,13 +1976,25 @@ impl GeneratedSbom {
             }
         };
 
+        let spec_version_suffix = match spec_version {
+            Some(spec_version) => format!("_{}", spec_version.to_string()),
+            None => "".to_owned(),
+        };
+
+        let compression_suffix = match self.sbom_config.compression {
+            Some(compression) => format!(".{}", compression.extension()),
+            None => "".to_owned(),
+        };
+
         format!(
-            "{}{}{}{}.{}",
+            "{}{}{}{}{}.{}{}",
             prefix,
             target_kind_suffix,
             platform_suffix,
+            spec_version_suffix,
             output_options.cdx_extension.extension(),
-            self.sbom_config.format()
+            self.sbom_config.format(),
+            compression_suffix
         )
     }
 }
@@ -855,13 +2032,30 @@ fn package_hashes(lockfile: &Lockfile) -> HashMap<cargo_metadata::PackageId, Che
 
 /// Returns a Cargo unique identifier for a package.
 /// See `cargo help pkgid` for more info.
-fn pkgid(pkg: &cargo_lock::Package) -> String {
+pub(crate) fn pkgid(pkg: &cargo_lock::Package) -> String {
     match pkg.source.as_ref() {
         Some(source) => format!("{}#{}@{}", source, pkg.name, pkg.version),
         None => format!("{}@{}", pkg.name, pkg.version),
     }
 }
 
+/// Whether the source is crates.io, through either the git or the sparse protocol
+pub(crate) fn is_crates_io(source: &cargo_metadata::Source) -> bool {
+    source.is_crates_io() || source.repr == "sparse+https://index.crates.io/"
+}
+
+/// The path of the entry of a crate in a registry index, as specified in
+/// <https://doc.rust-lang.org/cargo/reference/registry-index.html#index-files>
+pub(crate) fn index_path(name: &str) -> String {
+    let name = name.to_lowercase();
+    match name.len() {
+        1 => format!("1/{name}"),
+        2 => format!("2/{name}"),
+        3 => format!("3/{}/{name}", &name[..1]),
+        _ => format!("{}/{}/{name}", &name[..2], &name[2..4]),
+    }
+}
+
 /// Converts a checksum from the `cargo-lock` crate format to `cyclonedx-bom` crate format
 fn to_bom_hash(hash: &Checksum) -> cyclonedx_bom::models::hash::Hash {
     use cyclonedx_bom::models::hash::{Hash, HashAlgorithm, HashValue};
@@ -869,11 +2063,8 @@ fn to_bom_hash(hash: &Checksum) -> cyclonedx_bom::models::hash::Hash {
     // if/when more variants are added
     match hash {
         Checksum::Sha256(_) => {
-            Hash {
-                alg: HashAlgorithm::SHA256,
-                // {:x} means "format as lowercase hex"
-                content: HashValue(format!("{hash:x}")),
-            }
+            // {:x} means "format as lowercase hex"
+            Hash::new(HashAlgorithm::SHA256, HashValue(format!("{hash:x}")))
         }
     }
 }
@@ -891,6 +2082,12 @@ pub enum SbomWriterError {
 
     #[error("Error serializing to XML")]
     SerializeXmlError(#[source] std::io::Error),
+
+    #[error("Error signing the SBOM")]
+    SigningError(#[from] SigningError),
+
+    #[error("The generated SBOM failed validation with {0} error(s)")]
+    ValidationFailed(usize),
 }
 
 impl From<std::io::Error> for SbomWriterError {
@@ -902,6 +2099,685 @@ impl From<std::io::Error> for SbomWriterError {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::platform::RustcVersion;
+    use cyclonedx_bom::models::organization::OrganizationalEntity;
+
+    const ROOT_PACKAGE_JSON: &str = include_str!("../tests/fixtures/root_package.json");
+    const WORKSPACE_PACKAGE_JSON: &str = include_str!("../tests/fixtures/workspace_package.json");
+
+    fn test_generator() -> SbomGenerator {
+        SbomGenerator {
+            config: SbomConfig::empty_config(),
+            workspace_root: Utf8PathBuf::from("/home/shnatsel/Code/cargo-cyclonedx/"),
+            target_directory: Utf8PathBuf::from("/home/shnatsel/Code/cargo-cyclonedx/target/"),
+            crate_hashes: HashMap::new(),
+            toolchain: Toolchain::default(),
+            workspace_members: HashSet::new(),
+        }
+    }
+
+    fn target_with_kinds(kinds: &[&str]) -> cargo_metadata::Target {
+        serde_json::from_value(serde_json::json!({
+            "name": "target",
+            "kind": kinds,
+            "crate_types": kinds,
+            "src_path": "/src/lib.rs",
+        }))
+        .unwrap()
+    }
+
+    fn member_sbom(bom_ref: &str, dependencies: &[&str]) -> GeneratedSbom {
+        let mut metadata = Metadata::new().unwrap();
+        metadata.component = Some(Component::new(
+            Classification::Library,
+            bom_ref,
+            "0.1.0",
+            Some(bom_ref.to_string()),
+        ));
+        let components = dependencies
+            .iter()
+            .map(|dep| Component::new(Classification::Library, dep, "0.1.0", Some(dep.to_string())))
+            .collect();
+
+        let mut bom = Bom::default();
+        bom.metadata = Some(metadata);
+        bom.components = Some(Components(components));
+
+        GeneratedSbom {
+            bom,
+            manifest_path: PathBuf::from(format!("/workspace/{bom_ref}/Cargo.toml")),
+            package_name: bom_ref.to_string(),
+            sbom_config: SbomConfig::empty_config(),
+            target_kinds: TargetKinds(HashMap::new()),
+        }
+    }
+
+    #[test]
+    fn it_should_link_workspace_members() {
+        let mut sboms = vec![
+            member_sbom("app", &["lib", "external"]),
+            member_sbom("lib", &["external"]),
+        ];
+
+        link_workspace_members(&mut sboms);
+
+        let lib_serial_number = sboms[1].bom.serial_number.as_ref().unwrap().to_string();
+        let expected_link = format!(
+            "urn:cdx:{}/1#lib",
+            lib_serial_number.strip_prefix("urn:uuid:").unwrap()
+        );
+
+        let app_components = &sboms[0].bom.components.as_ref().unwrap().0;
+        let references = app_components[0].external_references.as_ref().unwrap();
+        assert_eq!(references.0.len(), 1);
+        assert_eq!(
+            references.0[0].external_reference_type,
+            ExternalReferenceType::Bom
+        );
+        assert_eq!(references.0[0].url.to_string(), expected_link);
+        assert!(app_components[1].external_references.is_none());
+
+        let lib_components = &sboms[1].bom.components.as_ref().unwrap().0;
+        assert!(lib_components[0].external_references.is_none());
+        assert_eq!(sboms[1].bom.validate(), ValidationResult::Passed);
+        assert_eq!(sboms[0].bom.validate(), ValidationResult::Passed);
+    }
+
+    fn package(name: &str) -> Package {
+        let mut package: Package = serde_json::from_str(ROOT_PACKAGE_JSON).unwrap();
+        package.name = name.to_string();
+        package.id = PackageId {
+            repr: name.to_string(),
+        };
+        package
+    }
+
+    fn node(name: &str, deps: &[(&str, Option<&str>)]) -> Node {
+        let deps: Vec<_> = deps
+            .iter()
+            .map(|(dep, kind)| {
+                serde_json::json!({
+                    "name": dep,
+                    "pkg": dep,
+                    "dep_kinds": [{ "kind": kind, "target": null }],
+                })
+            })
+            .collect();
+        serde_json::from_value(serde_json::json!({
+            "id": name,
+            "deps": deps,
+            "dependencies": deps.iter().map(|d| d["pkg"].clone()).collect::<Vec<_>>(),
+            "features": [],
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn it_should_filter_packages_and_keep_the_graph_connected() {
+        // app -> helper -> {regex, test-fixture (dev)}, app -> log, helper -> log
+        let packages = index_packages(
+            ["app", "helper", "regex", "log", "test-fixture"]
+                .into_iter()
+                .map(package)
+                .collect(),
+        );
+        let resolve = index_resolve(vec![
+            node("app", &[("helper", Some("build")), ("log", None)]),
+            node(
+                "helper",
+                &[
+                    ("regex", None),
+                    ("log", None),
+                    ("test-fixture", Some("dev")),
+                ],
+            ),
+            node("regex", &[]),
+            node("log", &[]),
+            node("test-fixture", &[]),
+        ]);
+        let root = PackageId {
+            repr: "app".to_string(),
+        };
+        let filter = PackageFilter {
+            include: vec![],
+            exclude: vec![glob::Pattern::new("helper").unwrap()],
+        };
+
+        let (packages, resolve) = filter_packages(&root, &filter, &packages, &resolve).unwrap();
+        let (packages, resolve) = all_dependencies(&root, &packages, &resolve, false);
+
+        let names: Vec<_> = packages.keys().map(|id| id.repr.as_str()).collect();
+        assert_eq!(names, vec!["app", "log", "regex"]);
+        let app_deps: Vec<_> = resolve[&root]
+            .deps
+            .iter()
+            .map(|d| (d.pkg.repr.as_str(), d.dep_kinds[0].kind))
+            .collect();
+        assert_eq!(
+            app_deps,
+            vec![
+                ("log", DependencyKind::Normal),
+                ("regex", DependencyKind::Build)
+            ]
+        );
+        assert!(filter_packages(&root, &PackageFilter::default(), &packages, &resolve).is_none());
+    }
+
+    #[test]
+    fn it_should_find_the_crates_that_only_run_during_the_build() {
+        // app -> {serde_derive -> syn, log, cc (build) -> log}, app -> libc (dev)
+        let mut packages = index_packages(
+            ["app", "serde_derive", "syn", "log", "cc", "libc"]
+                .into_iter()
+                .map(package)
+                .collect(),
+        );
+        packages
+            .get_mut(&PackageId {
+                repr: "serde_derive".to_string(),
+            })
+            .unwrap()
+            .targets = vec![serde_json::from_value(serde_json::json!({
+            "name": "serde_derive",
+            "kind": ["proc-macro"],
+            "crate_types": ["proc-macro"],
+            "src_path": "/registry/serde_derive/src/lib.rs",
+        }))
+        .unwrap()];
+        let mut resolve = index_resolve(vec![
+            node(
+                "app",
+                &[
+                    ("serde_derive", None),
+                    ("log", None),
+                    ("cc", Some("build")),
+                    ("libc", Some("dev")),
+                ],
+            ),
+            node("serde_derive", &[("syn", None)]),
+            node("syn", &[]),
+            node("log", &[]),
+            node("cc", &[("log", None)]),
+            node("libc", &[]),
+        ]);
+        let root = PackageId {
+            repr: "app".to_string(),
+        };
+
+        let build_time = build_time_dependencies(&root, &packages, &resolve);
+        let uses: Vec<_> = build_time
+            .iter()
+            .map(|(id, uses)| (id.repr.as_str(), uses.iter().copied().collect::<Vec<_>>()))
+            .collect();
+        assert_eq!(
+            uses,
+            vec![
+                ("cc", vec![BuildTimeUse::BuildScript]),
+                ("serde_derive", vec![BuildTimeUse::ProcMacro]),
+                ("syn", vec![BuildTimeUse::ProcMacro]),
+            ]
+        );
+
+        let mut component = Component::new(Classification::Library, "syn", "2.0.0", None);
+        SbomGenerator::mark_build_time(
+            &mut component,
+            &build_time[&PackageId {
+                repr: "syn".to_string(),
+            }],
+        );
+        assert_eq!(component.scope, Some(Scope::Excluded));
+        assert_eq!(
+            component.properties,
+            Some(Properties(vec![Property::new(
+                "cargo-cyclonedx:build_time:use",
+                "proc-macro"
+            )]))
+        );
+
+        exclude_packages(&build_time, &mut packages, &mut resolve);
+        let names: Vec<_> = packages.keys().map(|id| id.repr.as_str()).collect();
+        assert_eq!(names, vec!["app", "libc", "log"]);
+        let app_deps: Vec<_> = resolve[&root]
+            .dependencies
+            .iter()
+            .map(|id| id.repr.as_str())
+            .collect();
+        assert_eq!(app_deps, vec!["log", "libc"]);
+    }
+
+    #[test]
+    fn it_should_record_the_kinds_of_dependencies() {
+        // app -> {log (optional), cc (build), proptest (dev) -> rand}
+        let mut app = package("app");
+        app.dependencies = vec![serde_json::from_value(serde_json::json!({
+            "name": "log",
+            "source": null,
+            "req": "^0.4",
+            "kind": null,
+            "optional": true,
+            "uses_default_features": true,
+            "features": [],
+            "target": null,
+            "rename": null,
+            "registry": null,
+        }))
+        .unwrap()];
+        let packages = index_packages(
+            std::iter::once(app)
+                .chain(["log", "cc", "proptest", "rand"].into_iter().map(package))
+                .collect(),
+        );
+        let resolve = index_resolve(vec![
+            node(
+                "app",
+                &[
+                    ("log", None),
+                    ("cc", Some("build")),
+                    ("proptest", Some("dev")),
+                ],
+            ),
+            node("log", &[]),
+            node("cc", &[]),
+            node("proptest", &[("rand", None)]),
+            node("rand", &[]),
+        ]);
+        let root = PackageId {
+            repr: "app".to_string(),
+        };
+
+        let (without_dev, _) = all_dependencies(&root, &packages, &resolve, false);
+        let names: Vec<_> = without_dev.keys().map(|id| id.repr.as_str()).collect();
+        assert_eq!(names, vec!["app", "cc", "log"]);
+
+        let (with_dev, with_dev_resolve) = all_dependencies(&root, &packages, &resolve, true);
+        let mut dev_only: Vec<_> = dev_only_dependencies(&root, &with_dev, &packages, &resolve)
+            .into_iter()
+            .map(|id| id.repr)
+            .collect();
+        dev_only.sort();
+        assert_eq!(dev_only, vec!["proptest", "rand"]);
+
+        let edges = incoming_edges(&with_dev, &with_dev_resolve);
+        let kinds: Vec<_> = edges
+            .iter()
+            .map(|(id, edge)| (id.repr.as_str(), edge.kinds.clone(), edge.optional))
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                ("cc", vec![DependencyKind::Build], false),
+                ("log", vec![DependencyKind::Normal], true),
+                ("proptest", vec![DependencyKind::Development], false),
+                ("rand", vec![DependencyKind::Normal], false),
+            ]
+        );
+
+        let mut component = Component::new(Classification::Library, "log", "0.4.20", None);
+        SbomGenerator::add_dependency_kinds(
+            &mut component,
+            &edges[&PackageId {
+                repr: "log".to_string(),
+            }],
+        );
+        assert_eq!(
+            component.properties,
+            Some(Properties(vec![
+                Property::new("cdx:cargo:dependency_kind", "normal"),
+                Property::new("cdx:cargo:optional", "true"),
+            ]))
+        );
+    }
+
+    #[test]
+    fn it_should_keep_only_the_built_dependencies() {
+        // app -> {serde -> serde_derive, tokio}, where tokio is only enabled by a dev-dependency
+        // and serde_derive by another target
+        let packages = index_packages(
+            ["app", "serde", "serde_derive", "tokio"]
+                .into_iter()
+                .map(package)
+                .collect(),
+        );
+        let resolve = index_resolve(vec![
+            node("app", &[("serde", None), ("tokio", None)]),
+            node("serde", &[("serde_derive", None)]),
+            node("serde_derive", &[]),
+            node("tokio", &[]),
+        ]);
+        let version = packages[&PackageId {
+            repr: "app".to_string(),
+        }]
+            .version
+            .to_string();
+        // The test packages are path packages, which `cargo tree` prints with their directory
+        let dir = packages[&PackageId {
+            repr: "app".to_string(),
+        }]
+            .manifest_path
+            .parent()
+            .unwrap()
+            .to_string();
+        let built = BuiltGraph::parse(&format!(
+            "0app v{version} ({dir})\n1serde v{version} ({dir})\n"
+        ));
+
+        let (packages, resolve) = built_dependencies(&built, &packages, &resolve);
+        let root = PackageId {
+            repr: "app".to_string(),
+        };
+        let (dependencies, _) = all_dependencies(&root, &packages, &resolve, false);
+
+        let mut names: Vec<_> = dependencies.values().map(|p| p.name.as_str()).collect();
+        names.sort();
+        assert_eq!(names, vec!["app", "serde"]);
+    }
+
+    #[test]
+    fn it_should_report_duplicate_versions() {
+        // app -> {rand 0.8, legacy -> rand 0.7}
+        let versioned = |name: &str, id: &str, version: (u64, u64, u64)| {
+            let mut package = package(name);
+            package.id = PackageId {
+                repr: id.to_string(),
+            };
+            package.version = semver::Version::new(version.0, version.1, version.2);
+            package
+        };
+        let packages = index_packages(vec![
+            versioned("app", "app", (0, 1, 0)),
+            versioned("legacy", "legacy", (1, 0, 0)),
+            versioned("rand", "rand-0.8", (0, 8, 5)),
+            versioned("rand", "rand-0.7", (0, 7, 3)),
+        ]);
+        let resolve = index_resolve(vec![
+            node("app", &[("rand-0.8", None), ("legacy", None)]),
+            node("legacy", &[("rand-0.7", None)]),
+            node("rand-0.8", &[]),
+            node("rand-0.7", &[]),
+        ]);
+
+        let duplicates = duplicate_versions(&packages, &resolve);
+        assert_eq!(duplicates.len(), 2);
+        let old = &duplicates[&PackageId {
+            repr: "rand-0.7".to_string(),
+        }];
+        assert_eq!(
+            old,
+            &DuplicateVersion {
+                other_versions: vec!["0.8.5".to_string()],
+                dependents: vec!["legacy@1.0.0".to_string()],
+            }
+        );
+
+        let mut component = Component::new(Classification::Library, "rand", "0.7.3", None);
+        SbomGenerator::add_duplicate_properties(&mut component, old);
+        assert_eq!(
+            component.properties,
+            Some(Properties(vec![
+                Property::new("cargo-cyclonedx:duplicate:other_version", "0.8.5"),
+                Property::new("cargo-cyclonedx:duplicate:dependent", "legacy@1.0.0"),
+            ]))
+        );
+    }
+
+    #[test]
+    fn it_should_not_filter_the_root_package() {
+        let packages = index_packages(vec![package("app"), package("log")]);
+        let resolve = index_resolve(vec![node("app", &[("log", None)]), node("log", &[])]);
+        let root = PackageId {
+            repr: "app".to_string(),
+        };
+        let filter = PackageFilter {
+            include: vec![glob::Pattern::new("l*").unwrap()],
+            exclude: vec![],
+        };
+
+        let (packages, resolve) = filter_packages(&root, &filter, &packages, &resolve).unwrap();
+
+        assert_eq!(packages.len(), 2);
+        assert_eq!(resolve[&root].dependencies.len(), 1);
+    }
+
+    #[test]
+    fn it_should_reject_sboms_failing_validation() {
+        let sbom = member_sbom("app", &["dep"]);
+        assert!(sbom.validate(&sbom.bom).is_ok());
+
+        // The bom-ref of the dependency is not unique
+        let mut sbom = member_sbom("app", &["app"]);
+        assert!(matches!(
+            sbom.validate(&sbom.bom),
+            Err(SbomWriterError::ValidationFailed(1))
+        ));
+
+        sbom.sbom_config.validation = Some(Validation::Warn);
+        assert!(sbom.validate(&sbom.bom).is_ok());
+    }
+
+    #[test]
+    fn it_should_classify_targets() {
+        assert_eq!(
+            classify_target(&target_with_kinds(&["bin"])),
+            Some(Classification::Application)
+        );
+        for kind in ["lib", "rlib", "dylib", "cdylib", "staticlib", "proc-macro"] {
+            assert_eq!(
+                classify_target(&target_with_kinds(&[kind])),
+                Some(Classification::Library),
+                "unexpected classification for {kind}"
+            );
+        }
+        assert_eq!(
+            classify_target(&target_with_kinds(&["cdylib", "rlib"])),
+            Some(Classification::Library)
+        );
+        assert_eq!(classify_target(&target_with_kinds(&["example"])), None);
+    }
+
+    #[test]
+    fn it_should_classify_packages_by_their_targets() {
+        let root_package: Package = serde_json::from_str(ROOT_PACKAGE_JSON).unwrap();
+        let workspace_package: Package = serde_json::from_str(WORKSPACE_PACKAGE_JSON).unwrap();
+
+        assert_eq!(
+            SbomGenerator::get_classification(&root_package),
+            Classification::Application
+        );
+        assert_eq!(
+            SbomGenerator::get_classification(&workspace_package),
+            Classification::Library
+        );
+    }
+
+    #[test]
+    fn it_should_describe_the_package_in_metadata_component() {
+        let root_package: Package = serde_json::from_str(ROOT_PACKAGE_JSON).unwrap();
+
+        let (metadata, target_kinds) = test_generator()
+            .create_metadata(&root_package, &[])
+            .expect("Failed to create metadata");
+        let component = metadata.component.expect("Missing metadata component");
+
+        assert_eq!(component.component_type, Classification::Application);
+        assert_eq!(component.name.to_string(), "cargo-cyclonedx");
+        assert!(component.purl.is_some());
+        assert!(component.description.is_some());
+        assert!(component.licenses.is_some());
+        assert!(component.scope.is_none());
+
+        // the test target is not part of the build artifacts
+        let subcomponents = component.components.expect("Missing subcomponents").0;
+        let types: Vec<_> = subcomponents
+            .iter()
+            .map(|c| c.component_type.clone())
+            .collect();
+        assert_eq!(
+            types,
+            vec![Classification::Library, Classification::Application]
+        );
+        assert_eq!(target_kinds.0.len(), 2);
+    }
+
+    #[test]
+    fn it_should_override_the_component_type() {
+        let root_package: Package = serde_json::from_str(ROOT_PACKAGE_JSON).unwrap();
+        let mut generator = test_generator();
+        generator.config.component_type = Some(Classification::Firmware);
+
+        let (metadata, _) = generator
+            .create_metadata(&root_package, &[])
+            .expect("Failed to create metadata");
+        let component = metadata.component.expect("Missing metadata component");
+
+        assert_eq!(component.component_type, Classification::Firmware);
+        // the targets are still described by what they are
+        let subcomponents = component.components.expect("Missing subcomponents").0;
+        assert_eq!(subcomponents[1].component_type, Classification::Application);
+    }
+
+    #[test]
+    fn it_should_require_the_spec_version_of_the_component_type() {
+        let mut sbom = member_sbom("app", &[]);
+        assert_eq!(required_spec_version(&sbom.bom, false), SpecVersion::V1_3);
+
+        let meta = sbom.bom.metadata.as_mut().unwrap();
+        meta.component.as_mut().unwrap().component_type = Classification::Platform;
+        assert_eq!(required_spec_version(&sbom.bom, false), SpecVersion::V1_5);
+    }
+
+    #[test]
+    fn it_should_record_the_toolchain_in_metadata() {
+        let root_package: Package = serde_json::from_str(ROOT_PACKAGE_JSON).unwrap();
+        let mut generator = test_generator();
+        generator.toolchain = Toolchain {
+            rustc: Some(RustcVersion {
+                release: "1.77.0-nightly".to_string(),
+                commit_hash: Some("bf3c6c5bed498f41ad815641319a1ad9bcecb8e8".to_string()),
+                commit_date: None,
+                host: "x86_64-unknown-linux-gnu".to_string(),
+            }),
+            cargo: Some("1.77.0-nightly".to_string()),
+        };
+
+        let (metadata, _) = generator.create_metadata(&root_package, &[]).unwrap();
+
+        let tools = metadata.tools.unwrap().into_list();
+        assert!(tools[0].hashes.is_some());
+        let tools: Vec<_> = tools
+            .into_iter()
+            .map(|tool| {
+                (
+                    tool.name.unwrap().to_string(),
+                    tool.version.unwrap().to_string(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            tools,
+            vec![
+                (
+                    "cargo-cyclonedx".to_string(),
+                    env!("CARGO_PKG_VERSION").to_string()
+                ),
+                ("rustc".to_string(), "1.77.0-nightly".to_string()),
+                ("cargo".to_string(), "1.77.0-nightly".to_string()),
+            ]
+        );
+        assert_eq!(
+            metadata.properties,
+            Some(Properties(vec![
+                Property::new("cdx:rustc:toolchain:channel", "nightly"),
+                Property::new("cdx:rustc:toolchain:host", "x86_64-unknown-linux-gnu"),
+                Property::new(
+                    "cdx:rustc:toolchain:commit_hash",
+                    "bf3c6c5bed498f41ad815641319a1ad9bcecb8e8"
+                ),
+            ]))
+        );
+    }
+
+    #[test]
+    fn it_should_attribute_components_to_authors_and_supplier() {
+        let supplier = OrganizationalEntity::new("Example Inc.");
+        let mut generator = test_generator();
+        generator.config.supplier = Some(supplier.clone());
+        generator.workspace_members.insert(package("app").id);
+
+        let mut dependency = package("regex");
+        dependency.authors = vec![
+            "First Last <user@domain.tld>".to_string(),
+            "The Rust Project Developers".to_string(),
+        ];
+
+        let first_party = generator.create_component(&package("app"), &package("app"));
+        assert_eq!(first_party.supplier, Some(supplier.clone()));
+        assert_eq!(first_party.publisher, supplier.name);
+
+        let third_party = generator.create_component(&dependency, &package("app"));
+        assert_eq!(third_party.supplier, None);
+        assert_eq!(third_party.publisher, None);
+        assert_eq!(
+            third_party.author,
+            Some(NormalizedString::new(
+                "First Last <user@domain.tld>, The Rust Project Developers"
+            ))
+        );
+    }
+
+    #[test]
+    fn it_should_reference_the_crates_io_download_and_index_entry() {
+        let checksum = "25dd9975e68d0cb5aa1120c288333fc98731bd1dd12f561e468ea4728c042b89";
+        let mut generator = test_generator();
+        let mut dependency = package("serde");
+        dependency.version = semver::Version::new(1, 0, 193);
+        dependency.source = Some(cargo_metadata::Source {
+            repr: "registry+https://github.com/rust-lang/crates.io-index".to_string(),
+        });
+        generator
+            .crate_hashes
+            .insert(dependency.id.clone(), checksum.parse::<Checksum>().unwrap());
+
+        let component = generator.create_component(&dependency, &package("app"));
+        let references = &component.external_references.unwrap().0;
+        let registry_references: Vec<_> = references
+            .iter()
+            .filter(|reference| reference.url.to_string().contains("crates.io"))
+            .collect();
+        assert_eq!(registry_references.len(), 2);
+        assert_eq!(
+            registry_references[0].external_reference_type,
+            ExternalReferenceType::Distribution
+        );
+        assert_eq!(
+            registry_references[0].url.to_string(),
+            "https://static.crates.io/crates/serde/serde-1.0.193.crate"
+        );
+        assert_eq!(
+            registry_references[0].hashes.as_ref().unwrap().0[0]
+                .content
+                .0,
+            checksum
+        );
+        assert_eq!(
+            registry_references[1].url.to_string(),
+            "https://index.crates.io/se/rd/serde"
+        );
+
+        let local = generator.create_component(&package("app"), &package("app"));
+        assert!(local
+            .external_references
+            .iter()
+            .flat_map(|references| &references.0)
+            .all(|reference| !reference.url.to_string().contains("crates.io")));
+    }
+
+    #[test]
+    fn it_should_compute_registry_index_paths() {
+        assert_eq!(index_path("a"), "1/a");
+        assert_eq!(index_path("cc"), "2/cc");
+        assert_eq!(index_path("syn"), "3/s/syn");
+        assert_eq!(index_path("Inflector"), "in/fl/inflector");
+    }
 
     #[test]
     fn it_should_parse_author_and_email() {
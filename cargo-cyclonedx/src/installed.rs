@@ -0,0 +1,603 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Generation for binaries that have already been built, e.g. tools installed with
+//! `cargo install` whose build trees are long gone.
+//!
+//! Binaries built with [`cargo auditable`](https://github.com/rust-secure-code/cargo-auditable)
+//! embed their dependency tree as zlib-compressed JSON in a `.dep-v0` section. Like a
+//! [lockfile](crate::lockfile), it is turned into the output `cargo metadata` would produce.
+//! The binary only records whether a package comes from crates.io, a git repository, another
+//! registry or the local filesystem, so only the purls of crates.io packages are exact.
+//!
+//! Without that section, the record `cargo install` keeps in `$CARGO_HOME/.crates2.json`
+//! still identifies the package the binary was built from, but not its dependencies.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use cargo_metadata::Metadata as CargoMetadata;
+use cyclonedx_bom::models::bom::Bom;
+use cyclonedx_bom::models::composition::{AggregateType, BomReference, Composition, Compositions};
+use flate2::read::ZlibDecoder;
+use object::{Object, ObjectSection};
+use serde::Deserialize;
+use serde_json::{json, Value};
+use thiserror::Error;
+
+use crate::artifacts::file_hashes;
+
+/// The section `cargo auditable` stores the dependency tree in
+const SECTION: &str = ".dep-v0";
+
+/// The limit `cargo auditable` itself applies to the decompressed dependency tree
+const MAX_DEPENDENCY_DATA: u64 = 8 * 1024 * 1024;
+
+const CRATES_IO: &str = "registry+https://github.com/rust-lang/crates.io-index";
+
+/// The `cargo metadata` output reconstructed for a built binary
+#[derive(Clone, Debug)]
+pub struct InspectedBinary {
+    pub path: PathBuf,
+    pub metadata: CargoMetadata,
+    /// Whether the binary records its dependencies. Otherwise only the package
+    /// it was built from is known
+    pub dependencies_recorded: bool,
+}
+
+impl InspectedBinary {
+    /// The name of the Cargo target the binary was built from
+    pub fn name(&self) -> String {
+        let file_name = self.path.file_name().unwrap().to_string_lossy();
+        target_name(&file_name).to_string()
+    }
+
+    /// Attaches the hash of the binary to the component of its target, and records
+    /// whether its dependencies are known in the compositions of the SBOM
+    pub fn annotate(&self, bom: &mut Bom) {
+        let Some(component) = bom
+            .metadata
+            .as_mut()
+            .and_then(|metadata| metadata.component.as_mut())
+        else {
+            return;
+        };
+        let Some(bom_ref) = component.bom_ref.clone() else {
+            return;
+        };
+
+        let name = self.name();
+        let target = component
+            .components
+            .iter_mut()
+            .flat_map(|components| components.0.iter_mut())
+            .find(|subcomponent| subcomponent.name.to_string() == name);
+        if let Some(target) = target {
+            target.hashes = file_hashes(&self.path);
+        }
+
+        let aggregate = match self.dependencies_recorded {
+            true => AggregateType::Complete,
+            false => AggregateType::Unknown,
+        };
+        let mut composition = Composition::new(aggregate);
+        composition.dependencies = Some(vec![BomReference::new(bom_ref)]);
+        bom.compositions
+            .get_or_insert_with(|| Compositions(Vec::new()))
+            .0
+            .push(composition);
+    }
+}
+
+/// Reads the dependency tree embedded in the binary, falling back to the package
+/// recorded for it by `cargo install` in `cargo_home`
+pub fn inspect_binary(
+    path: &Path,
+    cargo_home: Option<&Path>,
+) -> Result<InspectedBinary, InstalledError> {
+    let path = path.canonicalize().map_err(|source| InstalledError::Io {
+        path: path.to_path_buf(),
+        source,
+    })?;
+    let file_name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+
+    let install = match cargo_home {
+        Some(cargo_home) => install_records(cargo_home)?
+            .into_iter()
+            .find(|record| record.info.bins.contains(&file_name)),
+        None => None,
+    };
+
+    let data = std::fs::read(&path).map_err(|source| InstalledError::Io {
+        path: path.clone(),
+        source,
+    })?;
+    let dependencies = match dependency_data(&data) {
+        Ok(Some(compressed)) => Some(decompress(&path, compressed)?),
+        Ok(None) => None,
+        Err(source) => {
+            return Err(InstalledError::InvalidBinary {
+                path: path.clone(),
+                source,
+            })
+        }
+    };
+
+    let (packages, dependencies_recorded) = match (dependencies, &install) {
+        (Some(dependencies), _) => (dependencies.packages, true),
+        (None, Some(install)) => {
+            log::warn!(
+                "{} was not built with `cargo auditable`, its dependencies are unknown",
+                path.display()
+            );
+            let package = AuditablePackage {
+                name: install.name.clone(),
+                version: install.version.clone(),
+                source: String::from("local"),
+                kind: PackageKind::Runtime,
+                dependencies: Vec::new(),
+                root: true,
+            };
+            (vec![package], false)
+        }
+        (None, None) => return Err(InstalledError::NoDependencyData(path)),
+    };
+
+    let metadata = metadata_json(&path, &packages, install.as_ref())?;
+    Ok(InspectedBinary {
+        metadata: serde_json::from_value(metadata)?,
+        path,
+        dependencies_recorded,
+    })
+}
+
+/// The binaries installed with `cargo install` into `cargo_home`
+pub fn installed_binaries(cargo_home: &Path) -> Result<Vec<PathBuf>, InstalledError> {
+    let bin = cargo_home.join("bin");
+    Ok(install_records(cargo_home)?
+        .into_iter()
+        .flat_map(|record| record.info.bins)
+        .map(|name| bin.join(name))
+        .filter(|path| {
+            let exists = path.is_file();
+            if !exists {
+                log::warn!("The installed binary {} is missing", path.display());
+            }
+            exists
+        })
+        .collect())
+}
+
+/// The directory Cargo keeps its installed binaries and their records in
+pub fn cargo_home() -> Option<PathBuf> {
+    match std::env::var_os("CARGO_HOME") {
+        Some(cargo_home) => Some(PathBuf::from(cargo_home)),
+        None => std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".cargo")),
+    }
+}
+
+/// The compressed dependency tree, if the binary has one
+fn dependency_data(data: &[u8]) -> Result<Option<&[u8]>, object::Error> {
+    let file = object::File::parse(data)?;
+    match file.section_by_name(SECTION) {
+        Some(section) => Ok(Some(section.data()?)),
+        None => Ok(None),
+    }
+}
+
+fn decompress(path: &Path, compressed: &[u8]) -> Result<VersionInfo, InstalledError> {
+    let mut json = Vec::new();
+    ZlibDecoder::new(compressed)
+        .take(MAX_DEPENDENCY_DATA)
+        .read_to_end(&mut json)
+        .map_err(|source| InstalledError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+    serde_json::from_slice(&json).map_err(|source| InstalledError::InvalidDependencyData {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+/// The dependency tree in the format of `cargo auditable`
+#[derive(Debug, Deserialize)]
+struct VersionInfo {
+    packages: Vec<AuditablePackage>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AuditablePackage {
+    name: String,
+    version: String,
+    /// One of `crates.io`, `git`, `local`, `registry` or `other`
+    source: String,
+    #[serde(default)]
+    kind: PackageKind,
+    /// The indices of the dependencies in the list of packages
+    #[serde(default)]
+    dependencies: Vec<usize>,
+    #[serde(default)]
+    root: bool,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum PackageKind {
+    #[default]
+    Runtime,
+    Build,
+}
+
+/// An entry of `.crates2.json`, keyed by `<name> <version> (<source>)`
+#[derive(Debug)]
+struct InstallRecord {
+    name: String,
+    version: String,
+    source: Option<String>,
+    info: InstallInfo,
+}
+
+#[derive(Debug, Deserialize)]
+struct CrateListing {
+    installs: BTreeMap<String, InstallInfo>,
+}
+
+#[derive(Debug, Deserialize)]
+struct InstallInfo {
+    #[serde(default)]
+    bins: BTreeSet<String>,
+    #[serde(default)]
+    features: BTreeSet<String>,
+}
+
+fn install_records(cargo_home: &Path) -> Result<Vec<InstallRecord>, InstalledError> {
+    let path = cargo_home.join(".crates2.json");
+    let content = match std::fs::read(&path) {
+        Ok(content) => content,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(source) => return Err(InstalledError::Io { path, source }),
+    };
+    let listing: CrateListing = serde_json::from_slice(&content)
+        .map_err(|source| InstalledError::InvalidInstallRecords { path, source })?;
+
+    Ok(listing
+        .installs
+        .into_iter()
+        .filter_map(|(key, info)| {
+            let (name, rest) = key.split_once(' ')?;
+            let (version, source) = rest.split_once(' ')?;
+            let source = source.strip_prefix('(')?.strip_suffix(')')?;
+            Some(InstallRecord {
+                name: name.to_string(),
+                version: version.to_string(),
+                source: Some(source.to_string()).filter(|source| !source.starts_with("path+")),
+                info,
+            })
+        })
+        .collect())
+}
+
+/// Builds the `cargo metadata` output for the packages of the binary. The package the
+/// binary was built from is the only workspace member, located next to the binary.
+fn metadata_json(
+    binary: &Path,
+    packages: &[AuditablePackage],
+    install: Option<&InstallRecord>,
+) -> Result<Value, InstalledError> {
+    let workspace_root = binary.parent().unwrap();
+    let root = packages
+        .iter()
+        .position(|package| package.root)
+        .ok_or_else(|| InstalledError::NoRootPackage(binary.to_path_buf()))?;
+
+    let sources: Vec<Option<String>> = packages
+        .iter()
+        .enumerate()
+        .map(|(index, package)| match package.source.as_str() {
+            // `cargo install` knows where the package came from exactly
+            _ if index == root && install.is_some_and(|install| install.source.is_some()) => {
+                install.and_then(|install| install.source.clone())
+            }
+            "crates.io" => Some(CRATES_IO.to_string()),
+            "local" => None,
+            other => {
+                log::warn!(
+                    "{} {} comes from a {} source whose location is not recorded in the binary",
+                    package.name,
+                    package.version,
+                    other
+                );
+                None
+            }
+        })
+        .collect();
+    let manifest_paths: Vec<PathBuf> = packages
+        .iter()
+        .enumerate()
+        .map(|(index, package)| match index == root {
+            true => workspace_root.join("Cargo.toml"),
+            false => workspace_root
+                .join("binary-only")
+                .join(format!("{}-{}", package.name, package.version))
+                .join("Cargo.toml"),
+        })
+        .collect();
+    let ids: Vec<String> = packages
+        .iter()
+        .zip(&sources)
+        .zip(&manifest_paths)
+        .map(|((package, source), manifest_path)| match source {
+            Some(source) => format!("{}#{}@{}", source, package.name, package.version),
+            None => format!(
+                "path+file://{}#{}@{}",
+                manifest_path.parent().unwrap().display(),
+                package.name,
+                package.version
+            ),
+        })
+        .collect();
+
+    let mut package_values = Vec::new();
+    let mut nodes = Vec::new();
+    for (index, package) in packages.iter().enumerate() {
+        let targets = match index == root {
+            true => vec![json!({
+                "name": target_name(&binary.file_name().unwrap().to_string_lossy()),
+                "kind": ["bin"],
+                "crate_types": ["bin"],
+                "src_path": workspace_root.join("src").join("main.rs"),
+            })],
+            false => Vec::new(),
+        };
+        package_values.push(json!({
+            "name": package.name,
+            "version": package.version,
+            "id": ids[index],
+            "source": sources[index],
+            "authors": [],
+            "dependencies": [],
+            "license": null,
+            "license_file": null,
+            "targets": targets,
+            "features": {},
+            "manifest_path": manifest_paths[index],
+            "categories": [],
+            "keywords": [],
+            "readme": null,
+            "repository": null,
+            "homepage": null,
+            "documentation": null,
+            "metadata": null,
+            "links": null,
+            "publish": null,
+        }));
+
+        let mut deps = Vec::new();
+        for &dependency in &package.dependencies {
+            let Some(target) = packages.get(dependency) else {
+                log::warn!(
+                    "{} depends on the package {}, which is not in the binary",
+                    ids[index],
+                    dependency
+                );
+                continue;
+            };
+            let kind = match target.kind {
+                PackageKind::Runtime => Value::Null,
+                PackageKind::Build => json!("build"),
+            };
+            deps.push(json!({
+                "name": target.name.replace('-', "_"),
+                "pkg": ids[dependency],
+                "dep_kinds": [{ "kind": kind, "target": null }],
+            }));
+        }
+        let features: Vec<&String> = match (index == root, install) {
+            (true, Some(install)) => install.info.features.iter().collect(),
+            _ => Vec::new(),
+        };
+        nodes.push(json!({
+            "id": ids[index],
+            "dependencies": deps.iter().map(|d| d["pkg"].clone()).collect::<Vec<_>>(),
+            "deps": deps,
+            "features": features,
+        }));
+    }
+
+    Ok(json!({
+        "packages": package_values,
+        "workspace_members": [ids[root]],
+        "workspace_default_members": [ids[root]],
+        "resolve": { "nodes": nodes, "root": ids[root] },
+        "workspace_root": workspace_root,
+        "target_directory": workspace_root.join("target"),
+        "metadata": null,
+        "version": 1,
+    }))
+}
+
+/// The name of the Cargo target a binary was built from, without the `.exe` extension
+fn target_name(file_name: &str) -> &str {
+    file_name.strip_suffix(".exe").unwrap_or(file_name)
+}
+
+#[derive(Debug, Error)]
+pub enum InstalledError {
+    #[error("I/O error while reading {}: {source}", .path.display())]
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+
+    #[error("{} is not an executable or library: {source}", .path.display())]
+    InvalidBinary {
+        path: PathBuf,
+        source: object::Error,
+    },
+
+    #[error("{} neither records its dependencies nor was installed with `cargo install`", .0.display())]
+    NoDependencyData(PathBuf),
+
+    #[error("Invalid dependency tree in {}: {source}", .path.display())]
+    InvalidDependencyData {
+        path: PathBuf,
+        source: serde_json::Error,
+    },
+
+    #[error("The dependency tree in {} has no root package", .0.display())]
+    NoRootPackage(PathBuf),
+
+    #[error("Invalid records of installed crates in {}: {source}", .path.display())]
+    InvalidInstallRecords {
+        path: PathBuf,
+        source: serde_json::Error,
+    },
+
+    #[error("Failed to build the package metadata")]
+    InvalidMetadata(#[from] serde_json::Error),
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use std::io::Write;
+
+    use assert_fs::prelude::*;
+    use assert_fs::TempDir;
+    use cargo_metadata::DependencyKind;
+    use flate2::write::ZlibEncoder;
+    use object::write::Object as ObjectFile;
+    use object::{Architecture, BinaryFormat, Endianness, SectionKind};
+
+    const DEPENDENCIES: &str = r#"{"packages":[
+        {"name":"tool","version":"0.3.0","source":"crates.io","dependencies":[1,2],"root":true},
+        {"name":"serde","version":"1.0.193","source":"crates.io","dependencies":[2]},
+        {"name":"cc","version":"1.0.83","source":"crates.io","kind":"build"}
+    ]}"#;
+
+    /// An object file with the dependency tree in the section `cargo auditable` uses
+    fn binary(dependencies: Option<&str>) -> Vec<u8> {
+        let mut object =
+            ObjectFile::new(BinaryFormat::Elf, Architecture::X86_64, Endianness::Little);
+        if let Some(dependencies) = dependencies {
+            let mut encoder = ZlibEncoder::new(Vec::new(), flate2::Compression::default());
+            encoder.write_all(dependencies.as_bytes()).unwrap();
+            let section = object.add_section(
+                Vec::new(),
+                SECTION.as_bytes().to_vec(),
+                SectionKind::ReadOnlyData,
+            );
+            object.append_section_data(section, &encoder.finish().unwrap(), 1);
+        }
+        object.write().unwrap()
+    }
+
+    #[test]
+    fn it_should_read_the_dependencies_embedded_in_a_binary() {
+        let dir = TempDir::new().unwrap();
+        let tool = dir.child("tool");
+        tool.write_binary(&binary(Some(DEPENDENCIES))).unwrap();
+
+        let inspected = inspect_binary(tool.path(), None).unwrap();
+        assert!(inspected.dependencies_recorded);
+        assert_eq!(inspected.name(), "tool");
+
+        let metadata = &inspected.metadata;
+        assert_eq!(metadata.packages.len(), 3);
+        let root = metadata.root_package().unwrap();
+        assert_eq!(root.name, "tool");
+        assert!(root.source.as_ref().unwrap().is_crates_io());
+        assert_eq!(root.targets[0].name, "tool");
+        assert!(root.targets[0].is_bin());
+
+        let resolve = metadata.resolve.as_ref().unwrap();
+        let node = resolve
+            .nodes
+            .iter()
+            .find(|node| node.id == root.id)
+            .unwrap();
+        let kinds: Vec<_> = node
+            .deps
+            .iter()
+            .map(|dep| (dep.name.as_str(), dep.dep_kinds[0].kind))
+            .collect();
+        assert_eq!(
+            kinds,
+            [
+                ("serde", DependencyKind::Normal),
+                ("cc", DependencyKind::Build)
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_fall_back_to_the_install_records() {
+        let cargo_home = TempDir::new().unwrap();
+        cargo_home
+            .child(".crates2.json")
+            .write_str(
+                r#"{"installs":{"tool 0.3.0 (git+https://github.com/example/tool#0123abcd)":
+                    {"version_req":null,"bins":["tool"],"features":["fast"],"all_features":false,
+                    "no_default_features":false,"profile":"release","target":"x86_64-unknown-linux-gnu",
+                    "rustc":"rustc 1.70.0"}}}"#,
+            )
+            .unwrap();
+        let tool = cargo_home.child("bin").child("tool");
+        tool.write_binary(&binary(None)).unwrap();
+
+        let binaries = installed_binaries(cargo_home.path()).unwrap();
+        assert_eq!(binaries, [cargo_home.path().join("bin").join("tool")]);
+
+        let inspected = inspect_binary(tool.path(), Some(cargo_home.path())).unwrap();
+        assert!(!inspected.dependencies_recorded);
+
+        let metadata = &inspected.metadata;
+        assert_eq!(metadata.packages.len(), 1);
+        let root = metadata.root_package().unwrap();
+        assert_eq!(
+            root.source.as_ref().unwrap().repr,
+            "git+https://github.com/example/tool#0123abcd"
+        );
+        let resolve = metadata.resolve.as_ref().unwrap();
+        assert_eq!(resolve.nodes[0].features, ["fast"]);
+    }
+
+    #[test]
+    fn it_should_not_guess_the_dependencies_of_unknown_binaries() {
+        let dir = TempDir::new().unwrap();
+        let tool = dir.child("tool");
+        tool.write_binary(&binary(None)).unwrap();
+        let text = dir.child("notes.txt");
+        text.write_str("not a binary").unwrap();
+
+        assert!(matches!(
+            inspect_binary(tool.path(), Some(dir.path())),
+            Err(InstalledError::NoDependencyData(_))
+        ));
+        assert!(matches!(
+            inspect_binary(text.path(), None),
+            Err(InstalledError::InvalidBinary { .. })
+        ));
+    }
+}
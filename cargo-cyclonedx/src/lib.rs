@@ -16,11 +16,26 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
+pub mod advisories;
+pub mod artifacts;
+pub mod binaries;
 pub mod config;
+pub mod deny;
 pub mod format;
+pub mod formulation;
 pub mod generator;
+pub mod installed;
+pub mod license_detection;
+pub mod lockfile;
 pub mod platform;
 pub mod purl;
+pub mod resolution;
+pub mod signing;
+pub mod sources;
 pub mod urlencode;
+pub mod vendor;
+pub mod yanked;
+
+pub use cyclonedx_bom::dtrack;
 
 pub use crate::generator::*;
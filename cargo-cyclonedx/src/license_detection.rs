@@ -0,0 +1,441 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Best-effort detection of the license of a crate from its license files.
+//!
+//! Quite a few crates don't set the `license` field in their `Cargo.toml` and only ship
+//! a `LICENSE` file, or point to one using `license-file`. We recognize the most common
+//! licenses by a few phrases that are characteristic for their text. This is far less thorough
+//! than a full text comparison, so the result is recorded as evidence rather than as a declared license.
+//!
+//! The GNU licenses are deliberately not detected: their text does not tell whether
+//! "or any later version" applies, so there is no way to pick the correct SPDX identifier.
+//!
+//! Path and git dependencies are often first-party code that never had its license metadata
+//! checked by a registry, so their whole source tree is scanned: license files in subdirectories,
+//! e.g. of vendored third-party code, and `SPDX-License-Identifier` tags in source files.
+//! Every license found gets a confidence score, as a nested license file may only cover
+//! part of the tree.
+
+use std::fs;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+/// Confidence in a license recognized in the license file next to the manifest
+const LICENSE_FILE_CONFIDENCE: f32 = 0.9;
+/// Confidence in a license declared by an `SPDX-License-Identifier` tag in a source file
+const SPDX_TAG_CONFIDENCE: f32 = 0.8;
+/// Confidence in a license recognized in a license file further down the source tree
+const NESTED_LICENSE_FILE_CONFIDENCE: f32 = 0.6;
+
+/// How deep the source tree is scanned
+const MAX_DEPTH: usize = 8;
+/// How much of the beginning of a source file is searched for an `SPDX-License-Identifier` tag
+const HEADER_LENGTH: u64 = 4096;
+
+const SOURCE_EXTENSIONS: &[&str] = &[
+    "rs", "c", "h", "cc", "cpp", "hpp", "s", "go", "js", "ts", "py", "sh",
+];
+const SPDX_TAG: &str = "SPDX-License-Identifier:";
+
+/// A license found for a crate, as an SPDX identifier or expression
+#[derive(Clone, Debug, PartialEq)]
+pub struct DetectedLicense {
+    pub license: String,
+    /// How likely it is that the license applies to the crate, from 0 to 1
+    pub confidence: f32,
+}
+
+/// Characteristic phrases of a license, in normalized form. All of them have to be present
+/// and none of the `absent` ones may be.
+struct Fingerprint {
+    id: &'static str,
+    present: &'static [&'static str],
+    absent: &'static [&'static str],
+}
+
+// Order matters: more specific fingerprints come first.
+const FINGERPRINTS: &[Fingerprint] = &[
+    Fingerprint {
+        id: "Apache-2.0",
+        present: &["apache license", "version 2 0"],
+        absent: &[],
+    },
+    Fingerprint {
+        id: "MPL-2.0",
+        present: &["mozilla public license version 2 0"],
+        absent: &[],
+    },
+    Fingerprint {
+        id: "BSL-1.0",
+        present: &["boost software license version 1 0"],
+        absent: &[],
+    },
+    Fingerprint {
+        id: "Unlicense",
+        present: &["this is free and unencumbered software released into the public domain"],
+        absent: &[],
+    },
+    Fingerprint {
+        id: "CC0-1.0",
+        present: &["cc0 1 0 universal"],
+        absent: &[],
+    },
+    Fingerprint {
+        id: "Zlib",
+        present: &[
+            "in no event will the authors be held liable for any damages arising from the use of this software",
+            "altered source versions must be plainly marked as such",
+        ],
+        absent: &[],
+    },
+    Fingerprint {
+        id: "MIT",
+        present: &[
+            "permission is hereby granted free of charge to any person obtaining a copy",
+            "the above copyright notice and this permission notice shall be included",
+        ],
+        absent: &[],
+    },
+    Fingerprint {
+        id: "ISC",
+        present: &[
+            "permission to use copy modify and or distribute this software for any purpose with or without fee is hereby granted",
+            "provided that the above copyright notice and this permission notice appear in all copies",
+        ],
+        absent: &[],
+    },
+    Fingerprint {
+        id: "0BSD",
+        present: &[
+            "permission to use copy modify and or distribute this software for any purpose with or without fee is hereby granted",
+        ],
+        absent: &[],
+    },
+    Fingerprint {
+        id: "BSD-3-Clause",
+        present: &[
+            "redistribution and use in source and binary forms with or without modification are permitted",
+            "neither the name of",
+        ],
+        absent: &["all advertising materials mentioning features or use of this software"],
+    },
+    Fingerprint {
+        id: "BSD-2-Clause",
+        present: &[
+            "redistribution and use in source and binary forms with or without modification are permitted",
+        ],
+        absent: &[
+            "neither the name of",
+            "all advertising materials mentioning features or use of this software",
+        ],
+    },
+];
+
+/// Lowercases the text and collapses everything that is not alphanumeric into single spaces,
+/// so that line breaks, comment markers and punctuation don't affect the matching.
+fn normalize(text: &str) -> String {
+    let mut normalized = String::with_capacity(text.len() + 2);
+    normalized.push(' ');
+    for word in text
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+    {
+        normalized.push_str(&word.to_lowercase());
+        normalized.push(' ');
+    }
+    normalized
+}
+
+/// Returns the SPDX identifier of the license the text most likely is
+pub fn detect_license(text: &str) -> Option<&'static str> {
+    let text = normalize(text);
+    let contains = |phrase: &str| text.contains(&format!(" {phrase} "));
+
+    FINGERPRINTS
+        .iter()
+        .find(|fp| fp.present.iter().all(|p| contains(p)) && !fp.absent.iter().any(|p| contains(p)))
+        .map(|fp| fp.id)
+}
+
+/// Recognizes the licenses of a crate in `license_file`, or else in the license files
+/// next to its manifest. With `deep`, the rest of the source tree is scanned as well.
+/// The result is ordered by decreasing confidence.
+pub fn scan_licenses(
+    package_dir: &Path,
+    license_file: Option<&Path>,
+    deep: bool,
+) -> Vec<DetectedLicense> {
+    let license_files = match license_file {
+        Some(license_file) => vec![license_file.to_path_buf()],
+        None => find_license_files(package_dir),
+    };
+
+    let mut detected = Vec::new();
+    for license_file in license_files {
+        if let Some(license) = detect_license_file(&license_file) {
+            add(&mut detected, license, LICENSE_FILE_CONFIDENCE);
+        }
+    }
+    if deep {
+        scan_directory(package_dir, 0, &mut detected);
+    }
+
+    // A stable sort keeps the order in which equally likely licenses were found
+    detected.sort_by(|a, b| b.confidence.total_cmp(&a.confidence));
+    detected
+}
+
+fn add(detected: &mut Vec<DetectedLicense>, license: &str, confidence: f32) {
+    match detected.iter_mut().find(|d| d.license == license) {
+        Some(existing) => existing.confidence = existing.confidence.max(confidence),
+        None => detected.push(DetectedLicense {
+            license: license.to_string(),
+            confidence,
+        }),
+    }
+}
+
+fn detect_license_file(path: &Path) -> Option<&'static str> {
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(error) => {
+            log::debug!("Failed to read license file {}: {}", path.display(), error);
+            return None;
+        }
+    };
+    let license = detect_license(&content);
+    if license.is_none() {
+        log::debug!("Could not recognize the license in {}", path.display());
+    }
+    license
+}
+
+/// Scans the source files and nested license files of a directory, leaving out hidden
+/// directories, build output and nested packages, which have licenses of their own
+fn scan_directory(dir: &Path, depth: usize, detected: &mut Vec<DetectedLicense>) {
+    let mut entries: Vec<_> = match fs::read_dir(dir) {
+        Ok(entries) => entries.filter_map(Result::ok).collect(),
+        Err(error) => {
+            log::debug!("Failed to scan {} for licenses: {}", dir.display(), error);
+            return;
+        }
+    };
+    entries.sort_by_key(|entry| entry.file_name());
+
+    for entry in entries {
+        let path = entry.path();
+        let name = entry.file_name().to_string_lossy().into_owned();
+        let Ok(file_type) = entry.file_type() else {
+            continue;
+        };
+        if file_type.is_dir() {
+            let skipped = name.starts_with('.')
+                || name == "target"
+                || depth + 1 >= MAX_DEPTH
+                || path.join("Cargo.toml").is_file();
+            if !skipped {
+                scan_directory(&path, depth + 1, detected);
+            }
+        } else if file_type.is_file() {
+            if is_license_file_name(&name) {
+                // The license files next to the manifest have been recognized already
+                if depth > 0 {
+                    if let Some(license) = detect_license_file(&path) {
+                        add(detected, license, NESTED_LICENSE_FILE_CONFIDENCE);
+                    }
+                }
+            } else if is_source_file(&path) {
+                if let Some(license) = spdx_tag(&path) {
+                    add(detected, &license, SPDX_TAG_CONFIDENCE);
+                }
+            }
+        }
+    }
+}
+
+fn is_license_file_name(name: &str) -> bool {
+    let name = name.to_uppercase();
+    ["LICENSE", "LICENCE", "COPYING", "UNLICENSE"]
+        .iter()
+        .any(|prefix| name.starts_with(prefix))
+}
+
+fn is_source_file(path: &Path) -> bool {
+    path.extension()
+        .and_then(|extension| extension.to_str())
+        .is_some_and(|extension| SOURCE_EXTENSIONS.contains(&extension))
+}
+
+/// The license expression of the `SPDX-License-Identifier` tag at the beginning of a file
+fn spdx_tag(path: &Path) -> Option<String> {
+    let mut header = Vec::new();
+    fs::File::open(path)
+        .and_then(|file| file.take(HEADER_LENGTH).read_to_end(&mut header))
+        .ok()?;
+    let header = String::from_utf8_lossy(&header);
+    let (_, rest) = header.split_once(SPDX_TAG)?;
+    let expression = rest
+        .lines()
+        .next()?
+        .trim()
+        .trim_end_matches("*/")
+        .trim_end_matches("-->")
+        .trim();
+    (!expression.is_empty()).then(|| expression.to_string())
+}
+
+/// Finds the files next to a manifest that conventionally hold license texts,
+/// e.g. `LICENSE`, `LICENSE-MIT` or `COPYING`. The result is sorted by file name.
+pub fn find_license_files(package_dir: &Path) -> Vec<PathBuf> {
+    let entries = match fs::read_dir(package_dir) {
+        Ok(entries) => entries,
+        Err(error) => {
+            log::debug!(
+                "Failed to look for license files in {}: {}",
+                package_dir.display(),
+                error
+            );
+            return Vec::new();
+        }
+    };
+
+    let mut files: Vec<PathBuf> = entries
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_type().is_ok_and(|t| t.is_file()))
+        .filter(|entry| is_license_file_name(&entry.file_name().to_string_lossy()))
+        .map(|entry| entry.path())
+        .collect();
+    files.sort();
+    files
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const MIT: &str = "Copyright (c) 2023 Jane Doe
+
+Permission is hereby granted, free of charge, to any
+person obtaining a copy of this software and associated
+documentation files (the \"Software\"), to deal in the
+Software without restriction.
+
+The above copyright notice and this permission
+notice shall be included in all copies or
+substantial portions of the Software.";
+
+    const BSD_3: &str = "Redistribution and use in source and binary forms, with or without
+modification, are permitted provided that the following conditions are met:
+
+* Neither the name of the copyright holder nor the names of its
+  contributors may be used to endorse or promote products derived from
+  this software without specific prior written permission.";
+
+    #[test]
+    fn it_should_detect_licenses() {
+        assert_eq!(detect_license(MIT), Some("MIT"));
+        assert_eq!(detect_license(BSD_3), Some("BSD-3-Clause"));
+        assert_eq!(
+            detect_license("                Apache License\n          Version 2.0, January 2004"),
+            Some("Apache-2.0")
+        );
+        assert_eq!(
+            detect_license(
+                "This is free and unencumbered software released into the public domain."
+            ),
+            Some("Unlicense")
+        );
+    }
+
+    #[test]
+    fn it_should_not_guess_unknown_licenses() {
+        assert_eq!(detect_license("All rights reserved."), None);
+        assert_eq!(
+            detect_license("GNU GENERAL PUBLIC LICENSE\nVersion 3, 29 June 2007"),
+            None
+        );
+        // a BSD license with an advertising clause is neither 2- nor 3-clause
+        let bsd_4 = format!("{BSD_3}\n* All advertising materials mentioning features or use of this software must display the following acknowledgement");
+        assert_eq!(detect_license(&bsd_4), None);
+    }
+
+    #[test]
+    fn it_should_find_license_files() {
+        let workspace_root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+        let files = find_license_files(&workspace_root);
+
+        assert_eq!(files, vec![workspace_root.join("LICENSE")]);
+        let text = fs::read_to_string(&files[0]).unwrap();
+        assert_eq!(detect_license(&text), Some("Apache-2.0"));
+    }
+
+    #[test]
+    fn it_should_scan_the_source_tree_with_confidence() {
+        use assert_fs::prelude::*;
+
+        let package = assert_fs::TempDir::new().unwrap();
+        package.child("Cargo.toml").touch().unwrap();
+        package.child("LICENSE").write_str(MIT).unwrap();
+        package
+            .child("src/lib.rs")
+            .write_str("// SPDX-License-Identifier: MIT OR Apache-2.0\n")
+            .unwrap();
+        package
+            .child("src/ffi/shim.c")
+            .write_str("/* SPDX-License-Identifier: Zlib */\n")
+            .unwrap();
+        package
+            .child("third_party/fmt/LICENSE")
+            .write_str(BSD_3)
+            .unwrap();
+        // Nested packages and build output have licenses of their own
+        package.child("helper/Cargo.toml").touch().unwrap();
+        package
+            .child("helper/src/lib.rs")
+            .write_str("// SPDX-License-Identifier: MPL-2.0\n")
+            .unwrap();
+        package
+            .child("target/debug/build/out.rs")
+            .write_str("// SPDX-License-Identifier: GPL-3.0-only\n")
+            .unwrap();
+
+        let shallow = scan_licenses(package.path(), None, false);
+        assert_eq!(
+            shallow,
+            vec![DetectedLicense {
+                license: "MIT".to_string(),
+                confidence: 0.9
+            }]
+        );
+
+        let detected: Vec<_> = scan_licenses(package.path(), None, true)
+            .into_iter()
+            .map(|detected| (detected.license, detected.confidence))
+            .collect();
+        assert_eq!(
+            detected,
+            vec![
+                ("MIT".to_string(), 0.9),
+                ("Zlib".to_string(), 0.8),
+                ("MIT OR Apache-2.0".to_string(), 0.8),
+                ("BSD-3-Clause".to_string(), 0.6),
+            ]
+        );
+    }
+}
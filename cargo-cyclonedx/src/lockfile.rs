@@ -0,0 +1,486 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Generation from a `Cargo.lock` alone, e.g. for a third-party project whose sources
+//! are not at hand, or without network access.
+//!
+//! The lockfile is turned into the output `cargo metadata` would produce, so that the
+//! [`SbomGenerator`](crate::generator::SbomGenerator) works as usual. A lockfile lists the
+//! dependencies for all platforms and does not tell dev-dependencies apart, so the SBOM
+//! includes both. Licenses, authors and targets are only known for the packages whose
+//! `Cargo.toml` is available, i.e. the package or workspace of an optional manifest.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use cargo_lock::Lockfile;
+use cargo_metadata::Metadata as CargoMetadata;
+use serde_json::{json, Value};
+use thiserror::Error;
+
+use crate::generator::pkgid;
+
+/// Builds the `cargo metadata` output for the lockfile.
+///
+/// With a manifest of a package, the SBOM describes that package. With a workspace manifest
+/// or without a manifest, every package without a source in the lockfile is a workspace member.
+pub fn metadata_from_lockfile(
+    lockfile_path: &Path,
+    manifest_path: Option<&Path>,
+) -> Result<CargoMetadata, LockfileError> {
+    let lockfile = Lockfile::load(lockfile_path)?;
+
+    let lockfile_path = lockfile_path
+        .canonicalize()
+        .map_err(|source| LockfileError::Io {
+            path: lockfile_path.to_path_buf(),
+            source,
+        })?;
+    let manifest_path = match manifest_path {
+        Some(path) => Some(path.canonicalize().map_err(|source| LockfileError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?),
+        None => None,
+    };
+    let workspace_root = manifest_path
+        .as_deref()
+        .unwrap_or(&lockfile_path)
+        .parent()
+        .unwrap()
+        .to_path_buf();
+
+    let root_manifest = manifest_path.as_deref().map(read_manifest).transpose()?;
+    let manifests = match (&root_manifest, &manifest_path) {
+        (Some(manifest), Some(path)) => member_manifests(manifest, path)?,
+        _ => HashMap::new(),
+    };
+
+    let ids: Vec<String> = lockfile
+        .packages
+        .iter()
+        .map(|package| match &package.source {
+            Some(_) => pkgid(package),
+            None => {
+                let manifest =
+                    manifest_location(&manifests, &workspace_root, package.name.as_str());
+                format!(
+                    "path+file://{}#{}@{}",
+                    manifest.parent().unwrap().display(),
+                    package.name,
+                    package.version
+                )
+            }
+        })
+        .collect();
+
+    let mut packages = Vec::new();
+    let mut nodes = Vec::new();
+    for (package, id) in lockfile.packages.iter().zip(&ids) {
+        let name = package.name.as_str();
+        let manifest = match &package.source {
+            Some(_) => None,
+            None => manifests.get(name),
+        };
+        // The sources of the dependencies are not available, so their manifest paths
+        // point to a directory that does not exist
+        let manifest_path = match &package.source {
+            Some(_) => workspace_root
+                .join("target")
+                .join("lockfile-only")
+                .join(format!("{}-{}", name, package.version))
+                .join("Cargo.toml"),
+            None => manifest_location(&manifests, &workspace_root, name),
+        };
+        packages.push(package_json(
+            name,
+            &package.version.to_string(),
+            id,
+            package.source.as_ref().map(ToString::to_string),
+            &manifest_path,
+            manifest.map(|(_, manifest)| manifest),
+        ));
+
+        let mut deps = Vec::new();
+        for dependency in &package.dependencies {
+            let index = lockfile.packages.iter().position(|candidate| {
+                dependency.matches(candidate)
+                    && (dependency.source.is_none() || dependency.source == candidate.source)
+            });
+            let Some(index) = index else {
+                log::warn!(
+                    "{} depends on {}, which is not in the lockfile",
+                    id,
+                    dependency
+                );
+                continue;
+            };
+            deps.push(json!({
+                "name": dependency.name.as_str().replace('-', "_"),
+                "pkg": ids[index],
+                "dep_kinds": [{ "kind": null, "target": null }],
+            }));
+        }
+        nodes.push(json!({
+            "id": id,
+            "dependencies": deps.iter().map(|d| d["pkg"].clone()).collect::<Vec<_>>(),
+            "deps": deps,
+            "features": [],
+        }));
+    }
+
+    let root_name = root_manifest
+        .as_ref()
+        .and_then(|manifest| manifest.get("package")?.get("name")?.as_str());
+    let mut members: Vec<&String> = lockfile
+        .packages
+        .iter()
+        .zip(&ids)
+        .filter(|(package, _)| package.source.is_none())
+        .filter(|(package, _)| root_name.map_or(true, |root| package.name.as_str() == root))
+        .map(|(_, id)| id)
+        .collect();
+    if let Some(root_name) = root_name {
+        members.truncate(1);
+        if members.is_empty() {
+            return Err(LockfileError::PackageNotInLockfile(root_name.to_string()));
+        }
+    }
+
+    let workspace_metadata = root_manifest
+        .as_ref()
+        .and_then(|manifest| manifest.get("workspace")?.get("metadata"))
+        .map_or(Value::Null, |metadata| json!(metadata));
+
+    let metadata = json!({
+        "packages": packages,
+        "workspace_members": members,
+        "workspace_default_members": members,
+        "resolve": { "nodes": nodes, "root": root_name.and(members.first()) },
+        "workspace_root": workspace_root,
+        "target_directory": workspace_root.join("target"),
+        "metadata": workspace_metadata,
+        "version": 1,
+    });
+    Ok(serde_json::from_value(metadata)?)
+}
+
+fn read_manifest(path: &Path) -> Result<toml::Table, LockfileError> {
+    let content = std::fs::read_to_string(path).map_err(|source| LockfileError::Io {
+        path: path.to_path_buf(),
+        source,
+    })?;
+    toml::from_str(&content).map_err(|source| LockfileError::InvalidManifest {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+/// The manifests of the package and the workspace members, by package name
+fn member_manifests(
+    root: &toml::Table,
+    root_path: &Path,
+) -> Result<HashMap<String, (PathBuf, toml::Table)>, LockfileError> {
+    let mut manifests = HashMap::new();
+    let root_dir = root_path.parent().unwrap();
+
+    let members = root
+        .get("workspace")
+        .and_then(|workspace| workspace.get("members"))
+        .and_then(|members| members.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|member| member.as_str());
+    for member in members {
+        let pattern = root_dir.join(member).join("Cargo.toml");
+        let Ok(paths) = glob::glob(&pattern.to_string_lossy()) else {
+            log::warn!("Invalid workspace member pattern {}", member);
+            continue;
+        };
+        for path in paths.flatten() {
+            let manifest = read_manifest(&path)?;
+            if let Some(name) = package_field(&manifest, "name") {
+                manifests.insert(name.to_string(), (path.clone(), manifest.clone()));
+            }
+        }
+    }
+
+    if let Some(name) = package_field(root, "name") {
+        manifests.insert(name.to_string(), (root_path.to_path_buf(), root.clone()));
+    }
+    Ok(manifests)
+}
+
+/// Where the manifest of a package without a source is, or would be in the common layout
+/// of a workspace with a directory per member
+fn manifest_location(
+    manifests: &HashMap<String, (PathBuf, toml::Table)>,
+    workspace_root: &Path,
+    name: &str,
+) -> PathBuf {
+    match manifests.get(name) {
+        Some((path, _)) => path.clone(),
+        None => workspace_root.join(name).join("Cargo.toml"),
+    }
+}
+
+fn package_field<'a>(manifest: &'a toml::Table, field: &str) -> Option<&'a str> {
+    manifest.get("package")?.get(field)?.as_str()
+}
+
+/// A package in the format of `cargo metadata`, with the details of its manifest if there is one
+fn package_json(
+    name: &str,
+    version: &str,
+    id: &str,
+    source: Option<String>,
+    manifest_path: &Path,
+    manifest: Option<&toml::Table>,
+) -> Value {
+    let field = |field| manifest.and_then(|m| package_field(m, field));
+    let list = |field| {
+        manifest
+            .and_then(|m| m.get("package")?.get(field)?.as_array().cloned())
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|value| value.is_str())
+            .map(|value| json!(value))
+            .collect::<Vec<_>>()
+    };
+    let package_metadata = manifest
+        .and_then(|m| m.get("package")?.get("metadata"))
+        .map_or(Value::Null, |metadata| json!(metadata));
+    let targets = manifest.map_or_else(Vec::new, |manifest| {
+        targets(name, manifest, manifest_path.parent().unwrap())
+    });
+
+    json!({
+        "name": name,
+        "version": version,
+        "id": id,
+        "source": source,
+        "authors": list("authors"),
+        "description": field("description"),
+        "dependencies": [],
+        "license": field("license"),
+        "license_file": field("license-file"),
+        "targets": targets,
+        "features": {},
+        "manifest_path": manifest_path,
+        "categories": list("categories"),
+        "keywords": list("keywords"),
+        "readme": field("readme"),
+        "repository": field("repository"),
+        "homepage": field("homepage"),
+        "documentation": field("documentation"),
+        "metadata": package_metadata,
+        "links": field("links"),
+        "publish": null,
+    })
+}
+
+/// The library and binary targets of a package, found the same way Cargo does:
+/// declared in the manifest, or by the conventional `src/lib.rs` and `src/main.rs`
+fn targets(name: &str, manifest: &toml::Table, package_dir: &Path) -> Vec<Value> {
+    let target = |name: &str, kind: &str, path: PathBuf| {
+        json!({
+            "name": name,
+            "kind": [kind],
+            "crate_types": [kind],
+            "src_path": path,
+        })
+    };
+    let mut targets = Vec::new();
+
+    let lib = manifest.get("lib");
+    let lib_path = lib.and_then(|lib| lib.get("path")?.as_str()).map_or_else(
+        || package_dir.join("src/lib.rs"),
+        |path| package_dir.join(path),
+    );
+    if lib.is_some() || lib_path.is_file() {
+        let lib_name = lib
+            .and_then(|lib| lib.get("name")?.as_str())
+            .map_or_else(|| name.replace('-', "_"), String::from);
+        targets.push(target(&lib_name, "lib", lib_path));
+    }
+
+    let main_path = package_dir.join("src/main.rs");
+    let bins = manifest
+        .get("bin")
+        .and_then(|bins| bins.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|bin| {
+            let bin_name = bin.get("name")?.as_str()?;
+            let path = match bin.get("path").and_then(|path| path.as_str()) {
+                Some(path) => package_dir.join(path),
+                None if bin_name == name && main_path.is_file() => main_path.clone(),
+                None => package_dir.join("src/bin").join(format!("{bin_name}.rs")),
+            };
+            Some((bin_name.to_string(), path))
+        })
+        .collect::<Vec<_>>();
+    if main_path.is_file() && !bins.iter().any(|(_, path)| *path == main_path) {
+        targets.push(target(name, "bin", main_path));
+    }
+    for (bin_name, path) in bins {
+        targets.push(target(&bin_name, "bin", path));
+    }
+
+    targets
+}
+
+#[derive(Debug, Error)]
+pub enum LockfileError {
+    #[error("Failed to read the lockfile")]
+    InvalidLockfile(#[from] cargo_lock::Error),
+
+    #[error("I/O error while reading {}: {source}", .path.display())]
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+
+    #[error("Invalid manifest {}: {source}", .path.display())]
+    InvalidManifest {
+        path: PathBuf,
+        source: toml::de::Error,
+    },
+
+    #[error("The package {0} of the manifest is not in the lockfile")]
+    PackageNotInLockfile(String),
+
+    #[error("Failed to build the package metadata")]
+    InvalidMetadata(#[from] serde_json::Error),
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use assert_fs::prelude::*;
+    use assert_fs::TempDir;
+
+    const LOCKFILE: &str = r#"version = 3
+
+[[package]]
+name = "app"
+version = "0.1.0"
+dependencies = [
+ "helper",
+ "serde",
+]
+
+[[package]]
+name = "helper"
+version = "0.2.0"
+dependencies = [
+ "serde",
+]
+
+[[package]]
+name = "serde"
+version = "1.0.193"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+checksum = "25dd9975e68d0cb5aa1120c288333fc98731bd1dd12f561e468ea4728c042b89"
+"#;
+
+    fn project() -> TempDir {
+        let dir = TempDir::new().unwrap();
+        dir.child("Cargo.lock").write_str(LOCKFILE).unwrap();
+        dir
+    }
+
+    #[test]
+    fn it_should_resolve_the_lockfile_without_a_manifest() {
+        let dir = project();
+
+        let metadata = metadata_from_lockfile(&dir.child("Cargo.lock"), None).unwrap();
+
+        let root = dir.path().canonicalize().unwrap();
+        assert_eq!(metadata.workspace_root, root.to_str().unwrap());
+        let names: Vec<_> = metadata
+            .workspace_members
+            .iter()
+            .map(|id| metadata[id].name.as_str())
+            .collect();
+        assert_eq!(names, ["app", "helper"]);
+
+        let resolve = metadata.resolve.unwrap();
+        let app = &resolve.nodes[0];
+        assert_eq!(
+            app.dependencies
+                .iter()
+                .map(|id| id.repr.as_str())
+                .collect::<Vec<_>>(),
+            [
+                format!("path+file://{}#helper@0.2.0", root.join("helper").display()).as_str(),
+                "registry+https://github.com/rust-lang/crates.io-index#serde@1.0.193"
+            ]
+        );
+        assert!(metadata.packages[2].source.as_ref().unwrap().is_crates_io());
+    }
+
+    #[test]
+    fn it_should_read_the_details_of_the_manifest() {
+        let dir = project();
+        dir.child("Cargo.toml")
+            .write_str(
+                r#"[package]
+name = "app"
+version = "0.1.0"
+license = "MIT"
+authors = ["Jane Doe"]
+
+[package.metadata.cyclonedx]
+component_type = "firmware"
+"#,
+            )
+            .unwrap();
+        dir.child("src/main.rs").touch().unwrap();
+
+        let metadata =
+            metadata_from_lockfile(&dir.child("Cargo.lock"), Some(&dir.child("Cargo.toml")))
+                .unwrap();
+
+        assert_eq!(metadata.workspace_members.len(), 1);
+        let app = &metadata[&metadata.workspace_members[0]];
+        assert_eq!(app.license.as_deref(), Some("MIT"));
+        assert_eq!(app.authors, ["Jane Doe"]);
+        assert_eq!(app.targets.len(), 1);
+        assert!(app.targets[0].is_bin());
+        assert_eq!(
+            app.metadata["cyclonedx"]["component_type"],
+            json!("firmware")
+        );
+    }
+
+    #[test]
+    fn it_should_reject_a_manifest_of_another_package() {
+        let dir = project();
+        dir.child("Cargo.toml")
+            .write_str("[package]\nname = \"other\"\nversion = \"1.0.0\"\n")
+            .unwrap();
+
+        let error =
+            metadata_from_lockfile(&dir.child("Cargo.lock"), Some(&dir.child("Cargo.toml")))
+                .unwrap_err();
+
+        assert!(matches!(error, LockfileError::PackageNotInLockfile(name) if name == "other"));
+    }
+}
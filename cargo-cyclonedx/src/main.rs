@@ -46,11 +46,19 @@
 * SOFTWARE.
 */
 use cargo_cyclonedx::{
-    config::{SbomConfig, Target},
-    generator::SbomGenerator,
+    binaries::feature_gated_binaries,
+    config::{
+        CdxExtension, CustomPrefix, Features, OutputOptions, Pattern, PlatformSuffix, Prefix,
+        SbomConfig, Target,
+    },
+    dtrack::DependencyTrack,
+    generator::{SbomFile, SbomGenerator},
+    installed::{cargo_home, inspect_binary, installed_binaries},
+    lockfile::metadata_from_lockfile,
 };
 
 use std::{
+    ffi::OsString,
     io::{self},
     path::{Path, PathBuf},
 };
@@ -58,18 +66,49 @@ use std::{
 use cargo_metadata::{self, CargoOpt, Metadata};
 
 use anyhow::Result;
-use clap::Parser;
+use clap::{CommandFactory, FromArgMatches, Parser};
 use env_logger::Builder;
 use log::LevelFilter;
 
 mod cli;
 use cli::{Args, Opts};
+mod config_file;
+use config_file::ConfigFile;
 
 fn main() -> anyhow::Result<()> {
-    let Opts::Bom(args) = Opts::parse();
+    let (args, config_file) = parse_args()?;
     setup_logging(&args)?;
 
-    let cli_config = args.as_config()?;
+    let cli_config = match &config_file {
+        Some(config_file) => {
+            log::debug!("Read the options from {}", config_file.path.display());
+            config_file.sbom_config()?.merge(&args.as_config()?)
+        }
+        None => args.as_config()?,
+    };
+
+    if let Some(lockfile) = &args.lockfile {
+        log::debug!("Reading the dependencies from {}", lockfile.display());
+        let metadata = metadata_from_lockfile(lockfile, args.manifest_path.as_deref())?;
+        let dependency_track = args.dependency_track()?;
+        for mut bom in SbomGenerator::create_sboms(metadata, &cli_config)? {
+            // Without their manifests, the SBOMs of the members are written next to the lockfile
+            if !bom.manifest_path.parent().is_some_and(Path::is_dir) {
+                bom.manifest_path = lockfile.with_file_name("Cargo.toml");
+            }
+            for file in bom.write_to_files()? {
+                if let Some(dependency_track) = &dependency_track {
+                    upload(dependency_track, &args, &file)?;
+                }
+            }
+        }
+        return Ok(());
+    }
+
+    if args.inspects_binaries() {
+        return generate_for_binaries(&args, &cli_config);
+    }
+
     let manifest_path = locate_manifest(&args)?;
     log::debug!("Found the Cargo.toml file at {}", manifest_path.display());
 
@@ -77,16 +116,149 @@ fn main() -> anyhow::Result<()> {
     let metadata = get_metadata(&args, &manifest_path, &cli_config)?;
     log::trace!("Running `cargo metadata` finished");
 
+    // Binaries only built with features that are not enabled get their own resolution
+    let gated_binaries = match cli_config.output_options().prefix {
+        Prefix::Pattern(Pattern::Binary | Pattern::CargoTarget) => {
+            feature_gated_binaries(&metadata)
+        }
+        _ => Vec::new(),
+    };
+
     log::trace!("SBOM generation started");
     let boms = SbomGenerator::create_sboms(metadata, &cli_config)?;
     log::trace!("SBOM generation finished");
 
+    let dependency_track = args.dependency_track()?;
+    let output = |files: Vec<SbomFile>| -> anyhow::Result<()> {
+        for file in files {
+            if let Some(dependency_track) = &dependency_track {
+                upload(dependency_track, &args, &file)?;
+            }
+        }
+        Ok(())
+    };
+
     log::trace!("SBOM output started");
     for bom in boms {
-        bom.write_to_files()?;
+        output(bom.write_to_files()?)?;
     }
     log::trace!("SBOM output finished");
 
+    for gated in gated_binaries {
+        log::info!(
+            "Resolving the dependencies of {} with the features {}",
+            gated.binaries.join(", "),
+            gated.features.join(", ")
+        );
+        let mut config = cli_config.clone();
+        config
+            .features
+            .get_or_insert_with(Features::default)
+            .features
+            .extend(gated.cargo_features());
+
+        let metadata = get_metadata(&args, &manifest_path, &config)?;
+        for bom in SbomGenerator::create_sboms(metadata, &config)? {
+            if bom.package_name == gated.package {
+                output(bom.write_selected_artifacts_to_files(&gated.binaries)?)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Parses the command line, completed with the options of the configuration file
+/// that it does not set
+fn parse_args() -> anyhow::Result<(Args, Option<ConfigFile>)> {
+    let argv: Vec<OsString> = std::env::args_os().collect();
+    let matches = Opts::command().get_matches_from(&argv);
+    let Opts::Bom(args) = Opts::from_arg_matches(&matches)?;
+
+    let path = match &args.config {
+        Some(path) => Some(path.clone()),
+        None => {
+            // The file applies to the workspace the manifest or lockfile belongs to
+            let start = args.manifest_path.as_ref().or(args.lockfile.as_ref());
+            let dir = match start.and_then(|path| path.parent()) {
+                Some(dir) => std::env::current_dir()?.join(dir),
+                None => std::env::current_dir()?,
+            };
+            ConfigFile::find(&dir)
+        }
+    };
+    let Some(path) = path else {
+        return Ok((args, None));
+    };
+
+    let config_file = ConfigFile::load(&path)?;
+    let matches = matches
+        .subcommand_matches("cyclonedx")
+        .expect("the only subcommand");
+    let mut argv = argv;
+    let file_args = config_file.arguments(&Args::command(), matches)?;
+    argv.splice(2..2, file_args);
+    let Opts::Bom(args) = Opts::parse_from(argv);
+
+    Ok((args, Some(config_file)))
+}
+
+/// Writes the SBOMs of binaries that have already been built to the current directory
+fn generate_for_binaries(args: &Args, cli_config: &SbomConfig) -> anyhow::Result<()> {
+    let cargo_home = cargo_home();
+    let mut binaries = args.binary.clone();
+    if args.installed {
+        let cargo_home = cargo_home
+            .as_deref()
+            .ok_or_else(|| anyhow::anyhow!("Failed to locate the Cargo home directory"))?;
+        binaries.extend(installed_binaries(cargo_home)?);
+    }
+
+    let output_dir = std::env::current_dir()?;
+    let dependency_track = args.dependency_track()?;
+    for binary in binaries {
+        log::debug!("Reading the dependencies of {}", binary.display());
+        let inspected = inspect_binary(&binary, cargo_home.as_deref())?;
+
+        // The SBOMs of several binaries must not overwrite each other
+        let mut config = cli_config.clone();
+        if config.output_options.is_none() {
+            config.output_options = Some(OutputOptions {
+                cdx_extension: CdxExtension::Included,
+                prefix: Prefix::Custom(CustomPrefix::new(inspected.name())?),
+                platform_suffix: PlatformSuffix::NotIncluded,
+            });
+        }
+
+        for mut bom in SbomGenerator::create_sboms(inspected.metadata.clone(), &config)? {
+            inspected.annotate(&mut bom.bom);
+            bom.manifest_path = output_dir.join("Cargo.toml");
+            for file in bom.write_to_files()? {
+                if let Some(dependency_track) = &dependency_track {
+                    upload(dependency_track, args, &file)?;
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn upload(dependency_track: &DependencyTrack, args: &Args, file: &SbomFile) -> anyhow::Result<()> {
+    let project = args.project(file);
+    let bom = std::fs::read(&file.path)?;
+
+    log::info!(
+        "Uploading {} to the Dependency-Track project {}",
+        file.path.display(),
+        project.name
+    );
+    let token = dependency_track.upload_bom(&project, &bom)?;
+    log::debug!(
+        "Dependency-Track is processing the upload, token {}",
+        token.token
+    );
+
     Ok(())
 }
 
@@ -133,7 +305,7 @@ fn locate_manifest(args: &Args) -> Result<PathBuf, io::Error> {
 }
 
 fn get_metadata(
-    _args: &Args,
+    args: &Args,
     manifest_path: &Path,
     config: &SbomConfig,
 ) -> anyhow::Result<Metadata> {
@@ -154,9 +326,14 @@ fn get_metadata(
         }
     }
 
+    let mut other_options = Vec::new();
     if let Some(Target::SingleTarget(target)) = config.target.as_ref() {
-        cmd.other_options(vec!["--filter-platform".to_owned(), target.to_owned()]);
+        other_options.extend(["--filter-platform".to_owned(), target.to_owned()]);
+    }
+    if args.offline {
+        other_options.push("--offline".to_owned());
     }
+    cmd.other_options(other_options);
 
     Ok(cmd.exec()?)
 }
@@ -29,3 +29,157 @@ pub fn rustc_host_target_triple(rustc_path: &OsStr) -> String {
         .map(|l| l[6..].to_string())
         .expect("Failed to parse rustc output to determine the current platform. Please report this bug!")
 }
+
+/// The toolchain the SBOM is generated with, which is also the one that builds the crate
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Toolchain {
+    pub rustc: Option<RustcVersion>,
+    /// The version of Cargo, e.g. `1.75.0`
+    pub cargo: Option<String>,
+}
+
+impl Toolchain {
+    /// Queries `rustc` and `cargo` for their versions. Failures are logged and leave the version unset.
+    pub fn detect() -> Self {
+        let rustc = version_output(&rustc_location(), "-vV").and_then(|output| {
+            let version = RustcVersion::parse(&output);
+            if version.is_none() {
+                log::warn!("Failed to parse the output of `rustc -vV`:\n{output}");
+            }
+            version
+        });
+        let cargo = version_output(&cargo_location(), "-V").and_then(|output| {
+            // e.g. `cargo 1.75.0 (1d8b05cdd 2023-11-20)`
+            let version = output.split_whitespace().nth(1).map(str::to_string);
+            if version.is_none() {
+                log::warn!("Failed to parse the output of `cargo -V`:\n{output}");
+            }
+            version
+        });
+        Self { rustc, cargo }
+    }
+}
+
+/// The verbose version information of `rustc`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RustcVersion {
+    /// The version, e.g. `1.75.0` or `1.77.0-nightly`
+    pub release: String,
+    pub commit_hash: Option<String>,
+    pub commit_date: Option<String>,
+    /// The target triple of the platform rustc runs on
+    pub host: String,
+}
+
+impl RustcVersion {
+    /// Parses the output of `rustc -vV`
+    pub fn parse(output: &str) -> Option<Self> {
+        let field = |name: &str| {
+            output.lines().find_map(|line| {
+                let value = line.strip_prefix(name)?.strip_prefix(": ")?.trim();
+                (!value.is_empty() && value != "unknown").then(|| value.to_string())
+            })
+        };
+
+        Some(Self {
+            release: field("release")?,
+            commit_hash: field("commit-hash"),
+            commit_date: field("commit-date"),
+            host: field("host")?,
+        })
+    }
+
+    /// The release channel: `stable`, `beta`, `nightly` or `dev`
+    pub fn channel(&self) -> &str {
+        match self.release.split_once('-') {
+            None => "stable",
+            Some((_, pre_release)) if pre_release.starts_with("beta") => "beta",
+            Some((_, pre_release)) if pre_release.starts_with("nightly") => "nightly",
+            Some(_) => "dev",
+        }
+    }
+}
+
+fn cargo_location() -> OsString {
+    // Cargo sets this variable for the subcommands it runs
+    std::env::var_os("CARGO").unwrap_or("cargo".into())
+}
+
+fn version_output(program: &OsStr, arg: &str) -> Option<String> {
+    match Command::new(program).arg(arg).output() {
+        Ok(output) if output.status.success() => {
+            Some(String::from_utf8_lossy(&output.stdout).into_owned())
+        }
+        Ok(output) => {
+            log::warn!(
+                "`{} {}` failed: {}",
+                program.to_string_lossy(),
+                arg,
+                String::from_utf8_lossy(&output.stderr)
+            );
+            None
+        }
+        Err(error) => {
+            log::warn!(
+                "Failed to run `{} {}`: {}",
+                program.to_string_lossy(),
+                arg,
+                error
+            );
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_should_parse_rustc_versions() {
+        let output = "rustc 1.75.0 (82e1608df 2023-12-21)
+binary: rustc
+commit-hash: 82e1608dfa6e0b5569232559e3d385fea5a93112
+commit-date: 2023-12-21
+host: x86_64-unknown-linux-gnu
+release: 1.75.0
+LLVM version: 17.0.6
+";
+        let version = RustcVersion::parse(output).unwrap();
+
+        assert_eq!(
+            version,
+            RustcVersion {
+                release: "1.75.0".to_string(),
+                commit_hash: Some("82e1608dfa6e0b5569232559e3d385fea5a93112".to_string()),
+                commit_date: Some("2023-12-21".to_string()),
+                host: "x86_64-unknown-linux-gnu".to_string(),
+            }
+        );
+        assert_eq!(version.channel(), "stable");
+    }
+
+    #[test]
+    fn it_should_determine_the_channel() {
+        let output = |release: &str| {
+            format!("commit-hash: unknown\nhost: aarch64-apple-darwin\nrelease: {release}\n")
+        };
+
+        let nightly = RustcVersion::parse(&output("1.77.0-nightly")).unwrap();
+        assert_eq!(nightly.channel(), "nightly");
+        assert_eq!(nightly.commit_hash, None);
+        assert_eq!(
+            RustcVersion::parse(&output("1.76.0-beta.3"))
+                .unwrap()
+                .channel(),
+            "beta"
+        );
+        assert_eq!(
+            RustcVersion::parse(&output("1.77.0-dev"))
+                .unwrap()
+                .channel(),
+            "dev"
+        );
+        assert_eq!(RustcVersion::parse("host: x86_64-pc-windows-msvc"), None);
+    }
+}
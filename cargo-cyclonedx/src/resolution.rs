@@ -0,0 +1,250 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Resolves the dependencies of a package with Cargo's feature resolver, through `cargo tree`.
+//!
+//! The dependency graph of `cargo metadata` is the union over all targets and feature
+//! combinations of the workspace: an optional dependency enabled only by a dev-dependency,
+//! or only for a build script, shows up as a dependency of the package as well.
+//! `cargo tree` runs the same feature resolver as a build, so its output only has the
+//! dependency edges that are compiled into the artifacts.
+
+use std::collections::HashSet;
+use std::ffi::OsString;
+use std::io;
+use std::path::Path;
+use std::process::Command;
+
+use cargo_metadata::Package;
+use thiserror::Error;
+
+use crate::config::{SbomConfig, Target};
+use crate::generator::is_crates_io;
+
+/// A crate as `cargo tree` prints it: its name, version and, unless it comes from crates.io,
+/// its source. Crates with the same name and version from different sources are different crates.
+type CrateKey = (String, String, Option<String>);
+
+/// The dependency edges Cargo builds for a package
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct BuiltGraph {
+    edges: HashSet<(CrateKey, CrateKey)>,
+}
+
+impl BuiltGraph {
+    /// Runs `cargo tree` for the package with the manifest at `manifest_path`,
+    /// with the features, target and offline mode of the configuration
+    pub fn resolve(manifest_path: &Path, config: &SbomConfig) -> Result<Self, ResolutionError> {
+        let edges = match config.dev_dependencies() {
+            true => "normal,build,dev",
+            false => "normal,build",
+        };
+        // Cargo sets this variable for the subcommands it runs
+        let cargo = std::env::var_os("CARGO").unwrap_or_else(|| OsString::from("cargo"));
+        let mut command = Command::new(cargo);
+        command
+            .args(["tree", "--quiet", "--prefix", "depth", "--format", "{p}"])
+            .args(["--edges", edges])
+            .arg("--manifest-path")
+            .arg(manifest_path);
+
+        if let Some(features) = &config.features {
+            if features.all_features {
+                command.arg("--all-features");
+            }
+            if features.no_default_features {
+                command.arg("--no-default-features");
+            }
+            if !features.features.is_empty() {
+                command.arg("--features").arg(features.features.join(","));
+            }
+        }
+        match &config.target {
+            Some(Target::SingleTarget(target)) => command.args(["--target", target]),
+            Some(Target::AllTargets) => command.args(["--target", "all"]),
+            None => &mut command,
+        };
+        if config.offline() {
+            command.arg("--offline");
+        }
+
+        let output = command.output().map_err(ResolutionError::CargoNotRun)?;
+        if !output.status.success() {
+            return Err(ResolutionError::CargoFailed(
+                String::from_utf8_lossy(&output.stderr).trim().to_string(),
+            ));
+        }
+        Ok(Self::parse(&String::from_utf8_lossy(&output.stdout)))
+    }
+
+    /// Parses the output of `cargo tree --prefix depth --format {p}`, with lines like
+    /// `2serde v1.0.193` or `1pkg v0.1.0 (/path/to/pkg) (*)`
+    pub(crate) fn parse(output: &str) -> Self {
+        let mut edges = HashSet::new();
+        let mut ancestors: Vec<CrateKey> = Vec::new();
+        for line in output.lines() {
+            let digits = line.chars().take_while(char::is_ascii_digit).count();
+            let Ok(depth) = line[..digits].parse::<usize>() else {
+                continue;
+            };
+            let mut words = line[digits..].splitn(3, ' ');
+            let (Some(name), Some(version)) = (words.next(), words.next()) else {
+                continue;
+            };
+            // After the version come `(proc-macro)`, the source and `(*)`, each only if relevant
+            let rest = words.next().unwrap_or_default().trim();
+            let rest = rest.strip_suffix("(*)").unwrap_or(rest).trim();
+            let rest = rest.strip_prefix("(proc-macro)").unwrap_or(rest).trim();
+            let source = rest
+                .strip_prefix('(')
+                .and_then(|rest| rest.strip_suffix(')'))
+                .map(normalize_source);
+            let key = (
+                name.to_string(),
+                version.trim_start_matches('v').to_string(),
+                source,
+            );
+
+            ancestors.truncate(depth);
+            if let Some(parent) = ancestors.last() {
+                edges.insert((parent.clone(), key.clone()));
+            }
+            ancestors.push(key);
+        }
+        Self { edges }
+    }
+
+    /// Whether `from` depends on `to` in the build
+    pub fn has_edge(&self, from: &Package, to: &Package) -> bool {
+        let key = |package: &Package| {
+            let source = match &package.source {
+                Some(source) if is_crates_io(source) => None,
+                Some(source) => Some(normalize_source(&source.repr)),
+                // `cargo tree` prints the directory of path dependencies
+                None => package
+                    .manifest_path
+                    .parent()
+                    .map(|dir| normalize_source(dir.as_str())),
+            };
+            (package.name.clone(), package.version.to_string(), source)
+        };
+        self.edges.contains(&(key(from), key(to)))
+    }
+}
+
+/// Brings a source as `cargo metadata` reports it, like `git+https://host/repo?branch=main#<commit>`,
+/// and as `cargo tree` prints it, like `https://host/repo?branch=main#<short commit>`, to the same form
+fn normalize_source(source: &str) -> String {
+    let source = source
+        .strip_prefix("registry `")
+        .and_then(|source| source.strip_suffix('`'))
+        .unwrap_or(source);
+    let source = ["registry+", "sparse+", "git+"]
+        .iter()
+        .find_map(|kind| source.strip_prefix(kind))
+        .unwrap_or(source);
+    // Only URLs have a fragment; a `#` in a path is part of the path
+    let source = match source.contains("://") {
+        true => source.split('#').next().unwrap_or(source),
+        false => source,
+    };
+    source.trim_end_matches('/').to_string()
+}
+
+#[derive(Debug, Error)]
+pub enum ResolutionError {
+    #[error("Failed to run `cargo tree`")]
+    CargoNotRun(#[source] io::Error),
+
+    #[error("`cargo tree` failed: {0}")]
+    CargoFailed(String),
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn key(name: &str, version: &str) -> CrateKey {
+        (name.to_string(), version.to_string(), None)
+    }
+
+    #[test]
+    fn it_should_parse_the_tree() {
+        let graph = BuiltGraph::parse(
+            "0pkg v0.1.0 (/work/pkg)\n\
+             1serde v1.0.193\n\
+             2serde_derive v1.0.193 (proc-macro)\n\
+             3syn v2.0.39\n\
+             1log v0.4.20\n\
+             1serde_derive v1.0.193 (proc-macro) (*)\n",
+        );
+
+        let pkg = (
+            "pkg".to_string(),
+            "0.1.0".to_string(),
+            Some("/work/pkg".to_string()),
+        );
+        let expected: HashSet<_> = [
+            (pkg.clone(), key("serde", "1.0.193")),
+            (key("serde", "1.0.193"), key("serde_derive", "1.0.193")),
+            (key("serde_derive", "1.0.193"), key("syn", "2.0.39")),
+            (pkg.clone(), key("log", "0.4.20")),
+            (pkg, key("serde_derive", "1.0.193")),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(graph.edges, expected);
+    }
+
+    #[test]
+    fn it_should_tell_apart_crates_with_the_same_name_and_version_from_different_sources() {
+        // pkg -> foo from git, which has the same name and version as foo from crates.io
+        let graph = BuiltGraph::parse(
+            "0pkg v0.1.0 (/work/pkg)\n\
+             1foo v1.0.0 (https://github.com/example/foo?branch=main#0123abcd)\n",
+        );
+
+        let package = |name: &str, source: Option<&str>, manifest_path: &str| -> Package {
+            serde_json::from_value(serde_json::json!({
+                "name": name,
+                "version": if name == "pkg" { "0.1.0" } else { "1.0.0" },
+                "id": name,
+                "source": source,
+                "dependencies": [],
+                "targets": [],
+                "features": {},
+                "manifest_path": manifest_path,
+            }))
+            .unwrap()
+        };
+        let pkg = package("pkg", None, "/work/pkg/Cargo.toml");
+        let from_git = package(
+            "foo",
+            Some("git+https://github.com/example/foo?branch=main#0123abcdef0123abcdef0123abcdef0123abcdef"),
+            "/home/user/.cargo/git/checkouts/foo/0123abc/Cargo.toml",
+        );
+        let from_crates_io = package(
+            "foo",
+            Some("registry+https://github.com/rust-lang/crates.io-index"),
+            "/home/user/.cargo/registry/src/foo-1.0.0/Cargo.toml",
+        );
+
+        assert!(graph.has_edge(&pkg, &from_git));
+        assert!(!graph.has_edge(&pkg, &from_crates_io));
+    }
+}
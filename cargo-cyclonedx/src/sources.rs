@@ -0,0 +1,223 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Hashes the source files Cargo packages for a crate, as listed by `cargo package --list`,
+//! so that the SBOM can be tied to the exact sources and not just the version in the manifest.
+//!
+//! The list includes files that Cargo generates while packaging. `Cargo.toml.orig` and
+//! `.cargo_vcs_info.json` are left out, `Cargo.toml` is the manifest as written,
+//! not the normalized one Cargo puts into the `.crate` file, and `Cargo.lock` is the lockfile
+//! of the workspace, if there is one.
+
+use std::ffi::OsString;
+use std::fs::File;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use cyclonedx_bom::models::property::Property;
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+
+/// Files in the output of `cargo package --list` that are generated by Cargo
+const GENERATED_FILES: &[&str] = &["Cargo.toml.orig", ".cargo_vcs_info.json"];
+
+/// The SHA-256 hashes of the packaged source files of a crate, sorted by path
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceHashes {
+    files: Vec<(String, String)>,
+}
+
+impl SourceHashes {
+    /// Hashes the files Cargo would package for the crate with the manifest at `manifest_path`.
+    /// The lockfile of a workspace member is looked up in the `workspace_root`.
+    pub fn of_package(manifest_path: &Path, workspace_root: &Path) -> Result<Self, SourcesError> {
+        let package_dir = manifest_path.parent().unwrap_or_else(|| Path::new(""));
+        let mut files = Vec::new();
+        for relative_path in packaged_files(manifest_path)? {
+            if GENERATED_FILES.contains(&relative_path.as_str()) {
+                continue;
+            }
+            let mut path = package_dir.join(&relative_path);
+            // Cargo packages the lockfile of the workspace, or generates one if there is none
+            if relative_path == "Cargo.lock" && !path.is_file() {
+                path = workspace_root.join("Cargo.lock");
+                if !path.is_file() {
+                    log::debug!("Not hashing Cargo.lock, which Cargo would generate");
+                    continue;
+                }
+            }
+            let digest = sha256_file(&path).map_err(|source| SourcesError::Io {
+                path: path.clone(),
+                source,
+            })?;
+            files.push((relative_path, digest));
+        }
+        Ok(Self::new(files))
+    }
+
+    /// `files` are pairs of relative paths and hex-encoded SHA-256 hashes
+    pub fn new(mut files: Vec<(String, String)>) -> Self {
+        files.sort();
+        Self { files }
+    }
+
+    /// A single hash of all files: the SHA-256 of the lines `sha256sum` prints for them,
+    /// i.e. `<hash>  <path>`, sorted by path
+    pub fn combined(&self) -> String {
+        let mut hasher = Sha256::new();
+        for line in self.lines() {
+            hasher.update(line.as_bytes());
+            hasher.update(b"\n");
+        }
+        format!("{:x}", hasher.finalize())
+    }
+
+    /// The combined hash as a property and, if `per_file` is set, the hash of every file
+    pub fn properties(&self, per_file: bool) -> Vec<Property> {
+        let mut properties = vec![Property::new(
+            "cargo-cyclonedx:source:sha256",
+            &self.combined(),
+        )];
+        if per_file {
+            properties.extend(
+                self.lines()
+                    .map(|line| Property::new("cargo-cyclonedx:source:file", &line)),
+            );
+        }
+        properties
+    }
+
+    fn lines(&self) -> impl Iterator<Item = String> + '_ {
+        self.files
+            .iter()
+            .map(|(path, digest)| format!("{digest}  {path}"))
+    }
+}
+
+/// Lists the files Cargo would package, relative to the package directory
+fn packaged_files(manifest_path: &Path) -> Result<Vec<String>, SourcesError> {
+    // Cargo sets this variable for the subcommands it runs
+    let cargo = std::env::var_os("CARGO").unwrap_or_else(|| OsString::from("cargo"));
+    let output = Command::new(cargo)
+        .args([
+            "package",
+            "--list",
+            "--allow-dirty",
+            "--quiet",
+            "--manifest-path",
+        ])
+        .arg(manifest_path)
+        .output()
+        .map_err(SourcesError::CargoNotRun)?;
+    if !output.status.success() {
+        return Err(SourcesError::CargoFailed(
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        ));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter(|line| !line.is_empty())
+        // Cargo lists the paths with the separator of the platform
+        .map(|line| line.replace('\\', "/"))
+        .collect())
+}
+
+fn sha256_file(path: &Path) -> io::Result<String> {
+    let mut hasher = Sha256::new();
+    io::copy(&mut File::open(path)?, &mut hasher)?;
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+#[derive(Debug, Error)]
+pub enum SourcesError {
+    #[error("Failed to run `cargo package --list`")]
+    CargoNotRun(#[source] io::Error),
+
+    #[error("`cargo package --list` failed: {0}")]
+    CargoFailed(String),
+
+    #[error("Failed to hash the source file {path}")]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use assert_fs::prelude::*;
+
+    #[test]
+    fn it_should_combine_the_file_hashes() {
+        let hashes = SourceHashes::new(vec![
+            ("src/main.rs".to_string(), "b".repeat(64)),
+            ("Cargo.toml".to_string(), "a".repeat(64)),
+        ]);
+
+        // printf 'aaa…  Cargo.toml\nbbb…  src/main.rs\n' | sha256sum
+        let mut expected = Sha256::new();
+        expected.update(format!(
+            "{}  Cargo.toml\n{}  src/main.rs\n",
+            "a".repeat(64),
+            "b".repeat(64)
+        ));
+        assert_eq!(hashes.combined(), format!("{:x}", expected.finalize()));
+
+        let properties = hashes.properties(true);
+        assert_eq!(properties.len(), 3);
+        assert_eq!(
+            properties[1],
+            Property::new(
+                "cargo-cyclonedx:source:file",
+                &format!("{}  Cargo.toml", "a".repeat(64))
+            )
+        );
+        assert_eq!(hashes.properties(false).len(), 1);
+    }
+
+    #[test]
+    fn it_should_hash_the_packaged_files() {
+        let dir = assert_fs::TempDir::new().unwrap();
+        dir.child("Cargo.toml")
+            .write_str(
+                r#"
+                [package]
+                name = "pkg"
+                version = "0.1.0"
+                edition = "2021"
+                exclude = ["notes.txt"]
+                "#,
+            )
+            .unwrap();
+        dir.child("src/lib.rs").write_str("").unwrap();
+        dir.child("notes.txt").write_str("not packaged").unwrap();
+
+        let hashes = SourceHashes::of_package(&dir.path().join("Cargo.toml"), dir.path()).unwrap();
+
+        let paths: Vec<&str> = hashes.files.iter().map(|(path, _)| path.as_str()).collect();
+        assert_eq!(paths, vec!["Cargo.toml", "src/lib.rs"]);
+        assert_eq!(
+            hashes.files[1].1,
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+    }
+}
@@ -0,0 +1,118 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Recognizes crates that are read from a directory created by `cargo vendor`.
+//!
+//! Source replacement is transparent in the output of `cargo metadata`, so vendored crates
+//! still appear to come from their registry. They can be told apart by the
+//! `.cargo-checksum.json` file that Cargo requires next to the manifest of every crate
+//! in a directory source, which also records the checksum of the original package.
+
+use std::str::FromStr;
+
+use cargo_lock::package::Checksum;
+use cargo_metadata::camino::{Utf8Path, Utf8PathBuf};
+use serde::Deserialize;
+
+const CHECKSUM_FILE: &str = ".cargo-checksum.json";
+
+/// A crate read from a vendor directory
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VendoredSource {
+    /// The directory holding all vendored crates, e.g. `vendor`
+    pub directory: Utf8PathBuf,
+    /// The checksum of the package the crate was vendored from.
+    /// Crates vendored from git repositories have none.
+    pub checksum: Option<Checksum>,
+}
+
+#[derive(Deserialize)]
+struct ChecksumFile {
+    package: Option<String>,
+}
+
+/// Returns where the crate with this manifest was vendored, if it was
+pub fn vendored_source(manifest_path: &Utf8Path) -> Option<VendoredSource> {
+    let crate_directory = manifest_path.parent()?;
+    let checksum_path = crate_directory.join(CHECKSUM_FILE);
+    let checksum_file = std::fs::read_to_string(&checksum_path).ok()?;
+
+    let checksum = match serde_json::from_str::<ChecksumFile>(&checksum_file) {
+        Ok(ChecksumFile {
+            package: Some(checksum),
+        }) => Checksum::from_str(&checksum)
+            .map_err(|error| log::warn!("Invalid package checksum in {}: {}", checksum_path, error))
+            .ok(),
+        Ok(ChecksumFile { package: None }) => None,
+        Err(error) => {
+            log::warn!("Failed to parse {}: {}", checksum_path, error);
+            None
+        }
+    };
+
+    Some(VendoredSource {
+        directory: crate_directory.parent()?.to_path_buf(),
+        checksum,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use assert_fs::prelude::*;
+    use assert_fs::TempDir;
+
+    const CHECKSUM: &str = "b5e8b1ba7dab5cc3a7ee3b6dd17ba09cd8e6ee6d77ad2fc0fee7bbcaab0cd67e";
+
+    fn manifest_path(tmp_dir: &TempDir, path: &str) -> Utf8PathBuf {
+        Utf8PathBuf::from_path_buf(tmp_dir.child(path).path().to_path_buf()).unwrap()
+    }
+
+    #[test]
+    fn it_should_recognize_vendored_crates() {
+        let tmp_dir = TempDir::new().unwrap();
+        tmp_dir
+            .child("vendor/dep/.cargo-checksum.json")
+            .write_str(&format!(r#"{{"files":{{}},"package":"{CHECKSUM}"}}"#))
+            .unwrap();
+        tmp_dir
+            .child("vendor/git-dep/.cargo-checksum.json")
+            .write_str(r#"{"files":{},"package":null}"#)
+            .unwrap();
+
+        assert_eq!(
+            vendored_source(&manifest_path(&tmp_dir, "vendor/dep/Cargo.toml")),
+            Some(VendoredSource {
+                directory: manifest_path(&tmp_dir, "vendor"),
+                checksum: Some(Checksum::from_str(CHECKSUM).unwrap()),
+            })
+        );
+        assert_eq!(
+            vendored_source(&manifest_path(&tmp_dir, "vendor/git-dep/Cargo.toml")),
+            Some(VendoredSource {
+                directory: manifest_path(&tmp_dir, "vendor"),
+                checksum: None,
+            })
+        );
+        assert_eq!(
+            vendored_source(&manifest_path(&tmp_dir, "src/Cargo.toml")),
+            None
+        );
+    }
+}
@@ -0,0 +1,371 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Looks up the resolved versions of the dependencies in their registry index
+//! to find the ones that have been yanked.
+//!
+//! Every crate has a file in the index with one line of JSON per published version,
+//! including a `yanked` flag, as described in
+//! <https://doc.rust-lang.org/cargo/reference/registry-index.html#index-files>.
+//! Sparse indexes serve these files over HTTP, and a local checkout of a git index
+//! has them in the same layout. Only crates.io and sparse registries are checked.
+//! Offline, only a local index can be used.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+use cargo_metadata::{Package, Source};
+use cyclonedx_bom::models::bom::Bom;
+use cyclonedx_bom::models::property::{Properties, Property};
+use serde::Deserialize;
+use thiserror::Error;
+
+use crate::generator::{index_path, is_crates_io};
+
+/// The sparse index of crates.io
+const CRATES_IO_INDEX: &str = "https://index.crates.io/";
+
+/// Looks up versions in registry indexes, caching the index entry of every crate
+pub struct RegistryIndex {
+    crates_io: IndexLocation,
+    agent: ureq::Agent,
+    /// Whether sparse indexes must not be requested
+    offline: bool,
+    /// The yanked status of every version of a crate, by index location and crate name
+    entries: HashMap<(IndexLocation, String), HashMap<String, bool>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum IndexLocation {
+    /// The base URL of a sparse index, ending with a slash
+    Sparse(String),
+    /// A directory with the layout of an index
+    Local(PathBuf),
+}
+
+impl IndexLocation {
+    fn parse(index: &str) -> Self {
+        if index.starts_with("http://") || index.starts_with("https://") {
+            Self::Sparse(format!("{}/", index.trim_end_matches('/')))
+        } else {
+            Self::Local(PathBuf::from(index))
+        }
+    }
+
+    /// Where the index of the crates from `source` is, if it can be checked
+    fn of_source(source: &Source, crates_io: &Self) -> Option<Self> {
+        if is_crates_io(source) {
+            Some(crates_io.clone())
+        } else {
+            source
+                .repr
+                .strip_prefix("sparse+")
+                .map(|url| Self::Sparse(format!("{}/", url.trim_end_matches('/'))))
+        }
+    }
+}
+
+/// A line of the index entry of a crate
+#[derive(Debug, Deserialize)]
+struct IndexVersion {
+    vers: String,
+    #[serde(default)]
+    yanked: bool,
+}
+
+impl RegistryIndex {
+    /// Creates an index that looks up crates.io crates in `crates_io_index`,
+    /// the URL of a sparse index or a local directory, instead of <https://index.crates.io/>
+    ///
+    /// When `offline`, the crates.io index has to be a local directory.
+    pub fn new(crates_io_index: Option<&str>, offline: bool) -> Result<Self, YankedError> {
+        let crates_io = IndexLocation::parse(crates_io_index.unwrap_or(CRATES_IO_INDEX));
+        if let (true, IndexLocation::Sparse(url)) = (offline, &crates_io) {
+            return Err(YankedError::Offline { url: url.clone() });
+        }
+
+        Ok(Self {
+            crates_io,
+            agent: ureq::AgentBuilder::new()
+                .user_agent(concat!("cargo-cyclonedx/", env!("CARGO_PKG_VERSION")))
+                .build(),
+            offline,
+            entries: HashMap::new(),
+        })
+    }
+
+    /// Returns the packages whose version has been yanked from their registry
+    pub fn yanked<'a>(
+        &mut self,
+        packages: impl Iterator<Item = &'a Package>,
+    ) -> Result<Vec<&'a Package>, YankedError> {
+        let mut yanked = Vec::new();
+        for package in packages {
+            let Some(location) = package
+                .source
+                .as_ref()
+                .and_then(|source| IndexLocation::of_source(source, &self.crates_io))
+            else {
+                continue;
+            };
+
+            let key = (location, package.name.to_lowercase());
+            if !self.entries.contains_key(&key) {
+                let versions = self.fetch(&key.0, &package.name)?;
+                self.entries.insert(key.clone(), versions);
+            }
+
+            match self.entries[&key].get(&package.version.to_string()) {
+                Some(true) => yanked.push(package),
+                Some(false) => (),
+                None => log::warn!(
+                    "The version {} of {} is not in its registry index, it cannot be checked for being yanked",
+                    package.version,
+                    package.name
+                ),
+            }
+        }
+        Ok(yanked)
+    }
+
+    /// Reads the index entry of a crate, an empty one if the crate is not in the index
+    fn fetch(
+        &self,
+        location: &IndexLocation,
+        name: &str,
+    ) -> Result<HashMap<String, bool>, YankedError> {
+        let content = match location {
+            IndexLocation::Sparse(base) if self.offline => {
+                return Err(YankedError::Offline { url: base.clone() })
+            }
+            IndexLocation::Sparse(base) => {
+                let url = format!("{}{}", base, index_path(name));
+                match self.agent.get(&url).call() {
+                    Ok(response) => response
+                        .into_string()
+                        .map_err(|source| YankedError::InvalidResponse { url, source })?,
+                    Err(ureq::Error::Status(404 | 410 | 451, _)) => String::new(),
+                    Err(ureq::Error::Status(status, _)) => {
+                        return Err(YankedError::Rejected { url, status })
+                    }
+                    Err(ureq::Error::Transport(transport)) => {
+                        return Err(YankedError::RequestFailed {
+                            url,
+                            source: Box::new(transport),
+                        })
+                    }
+                }
+            }
+            IndexLocation::Local(dir) => {
+                let path = dir.join(index_path(name));
+                match fs::read_to_string(&path) {
+                    Ok(content) => content,
+                    Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+                    Err(source) => return Err(YankedError::Io { path, source }),
+                }
+            }
+        };
+
+        Ok(content
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .filter_map(|line| match serde_json::from_str::<IndexVersion>(line) {
+                Ok(version) => Some((version.vers, version.yanked)),
+                Err(e) => {
+                    log::warn!("Skipping an invalid index entry of {}: {}", name, e);
+                    None
+                }
+            })
+            .collect())
+    }
+}
+
+/// Records the yanked packages on their components in the BOM
+pub fn mark_yanked(bom: &mut Bom, yanked: &[&Package]) {
+    let Some(components) = bom.components.as_mut() else {
+        return;
+    };
+    for component in components.0.iter_mut() {
+        let is_yanked = component
+            .bom_ref
+            .as_ref()
+            .is_some_and(|bom_ref| yanked.iter().any(|package| &package.id.repr == bom_ref));
+        if is_yanked {
+            let property = Property::new("cargo-cyclonedx:yanked", "true");
+            match component.properties.as_mut() {
+                Some(properties) => properties.0.push(property),
+                None => component.properties = Some(Properties(vec![property])),
+            }
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum YankedError {
+    #[error("Failed to read the registry index entry {path}")]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("Failed to request the registry index entry {url}")]
+    RequestFailed {
+        url: String,
+        source: Box<ureq::Transport>,
+    },
+
+    #[error("The registry index responded to {url} with status {status}")]
+    Rejected { url: String, status: u16 },
+
+    #[error("Failed to read the registry index entry {url}")]
+    InvalidResponse {
+        url: String,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("Cannot check for yanked crates in the index {url} offline, pass a local directory with --crates-io-index")]
+    Offline { url: String },
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use assert_fs::prelude::*;
+    use cyclonedx_bom::models::component::{Classification, Component, Components};
+
+    fn package(name: &str, version: &str, source: &str) -> Package {
+        serde_json::from_value(serde_json::json!({
+            "name": name,
+            "version": version,
+            "id": format!("{name} {version} ({source})"),
+            "license": null,
+            "license_file": null,
+            "description": null,
+            "source": source,
+            "dependencies": [],
+            "targets": [],
+            "features": {},
+            "manifest_path": format!("/{name}/Cargo.toml"),
+            "categories": [],
+            "keywords": [],
+            "readme": null,
+            "repository": null,
+            "homepage": null,
+            "documentation": null,
+            "edition": "2021",
+            "metadata": null,
+            "links": null,
+            "publish": null,
+            "authors": [],
+            "default_run": null,
+            "rust_version": null
+        }))
+        .unwrap()
+    }
+
+    const CRATES_IO: &str = "registry+https://github.com/rust-lang/crates.io-index";
+
+    #[test]
+    fn it_should_find_yanked_versions_in_a_local_index() {
+        let index = assert_fs::TempDir::new().unwrap();
+        index
+            .child("se/rd/serde")
+            .write_str(concat!(
+                r#"{"name":"serde","vers":"1.0.0","deps":[],"cksum":"","features":{},"yanked":false}"#,
+                "\n",
+                r#"{"name":"serde","vers":"1.0.1","deps":[],"cksum":"","features":{},"yanked":true}"#,
+                "\n",
+            ))
+            .unwrap();
+        index
+            .child("3/l/log")
+            .write_str(r#"{"name":"log","vers":"0.4.0","deps":[],"cksum":"","features":{}}"#)
+            .unwrap();
+
+        let packages = [
+            package("serde", "1.0.0", CRATES_IO),
+            package("serde", "1.0.1", CRATES_IO),
+            package("log", "0.4.0", "sparse+https://index.crates.io/"),
+            package("unknown", "1.0.0", CRATES_IO),
+            package("local", "1.0.0", "git+https://example.com/local"),
+        ];
+        let mut registry = RegistryIndex::new(Some(index.path().to_str().unwrap()), true).unwrap();
+        let yanked = registry.yanked(packages.iter()).unwrap();
+
+        assert_eq!(
+            yanked
+                .iter()
+                .map(|p| format!("{}@{}", p.name, p.version))
+                .collect::<Vec<_>>(),
+            vec!["serde@1.0.1"]
+        );
+    }
+
+    #[test]
+    fn it_should_not_request_sparse_indexes_offline() {
+        assert!(matches!(
+            RegistryIndex::new(None, true),
+            Err(YankedError::Offline { url }) if url == CRATES_IO_INDEX
+        ));
+
+        let index = assert_fs::TempDir::new().unwrap();
+        let mut registry = RegistryIndex::new(Some(index.path().to_str().unwrap()), true).unwrap();
+        let packages = [package(
+            "private",
+            "1.0.0",
+            "sparse+https://registry.example.com/",
+        )];
+        assert!(matches!(
+            registry.yanked(packages.iter()),
+            Err(YankedError::Offline { url }) if url == "https://registry.example.com/"
+        ));
+    }
+
+    #[test]
+    fn it_should_mark_yanked_components() {
+        let yanked = package("serde", "1.0.1", CRATES_IO);
+        let component = |package: &Package| {
+            Component::new(
+                Classification::Library,
+                &package.name,
+                &package.version.to_string(),
+                Some(package.id.repr.clone()),
+            )
+        };
+        let mut bom = Bom::default();
+        bom.components = Some(Components(vec![
+            component(&yanked),
+            component(&package("log", "0.4.0", CRATES_IO)),
+        ]));
+
+        mark_yanked(&mut bom, &[&yanked]);
+
+        let components = bom.components.unwrap().0;
+        assert_eq!(
+            components[0].properties,
+            Some(Properties(vec![Property::new(
+                "cargo-cyclonedx:yanked",
+                "true"
+            )]))
+        );
+        assert_eq!(components[1].properties, None);
+    }
+}
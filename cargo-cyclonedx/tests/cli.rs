@@ -138,6 +138,398 @@ fn find_content_in_stderr() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+#[test]
+fn license_is_detected_from_license_file() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = make_temp_rust_project()?;
+
+    let pkg_name = "nested-pkg";
+
+    tmp_dir.child("Cargo.toml").write_str(&format!(
+        r#"
+        [package]
+        name = "test"
+        version = "0.0.0"
+
+        [dependencies.{0}]
+        path = "{0}"
+        "#,
+        pkg_name,
+    ))?;
+
+    let pkg_dir = tmp_dir.child(pkg_name);
+    pkg_dir.child("src/lib.rs").touch()?;
+    pkg_dir.child("Cargo.toml").write_str(&format!(
+        r#"
+        [package]
+        name = "{}"
+        version = "0.0.0"
+        "#,
+        pkg_name,
+    ))?;
+    pkg_dir.child("LICENSE-MIT").write_str(
+        "Permission is hereby granted, free of charge, to any person obtaining a copy \
+        of this software. The above copyright notice and this permission notice shall \
+        be included in all copies or substantial portions of the Software.",
+    )?;
+    pkg_dir
+        .child("src/vendored/zlib.c")
+        .write_str("/* SPDX-License-Identifier: Zlib */")?;
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--format")
+        .arg("json");
+
+    cmd.assert().success().stdout("");
+
+    let bom: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(tmp_dir.child("bom.json").path())?)?;
+    let component = &bom["components"][0];
+    assert_eq!(component["name"], pkg_name);
+    assert!(component.get("licenses").is_none());
+    assert_eq!(component["evidence"]["licenses"][0]["license"]["id"], "MIT");
+    assert_eq!(
+        component["evidence"]["licenses"][1]["license"]["id"],
+        "Zlib"
+    );
+    assert_eq!(component["properties"][1]["value"], "Zlib: 0.80");
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn target_specific_dependencies_are_resolved_for_the_target(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = make_temp_rust_project()?;
+
+    tmp_dir.child("Cargo.toml").write_str(
+        r#"
+        [package]
+        name = "test"
+        version = "0.0.0"
+
+        [target.'cfg(windows)'.dependencies]
+        windows-only = { path = "windows-only" }
+        "#,
+    )?;
+
+    let pkg_dir = tmp_dir.child("windows-only");
+    pkg_dir.child("src/lib.rs").touch()?;
+    pkg_dir
+        .child("Cargo.toml")
+        .write_str(r#"package = { name = "windows-only", version = "0.0.0" }"#)?;
+
+    for (target, included) in [
+        ("x86_64-unknown-linux-musl", false),
+        ("x86_64-pc-windows-msvc", true),
+    ] {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+        cmd.current_dir(tmp_dir.path())
+            .arg("cyclonedx")
+            .arg("--format")
+            .arg("json")
+            .arg("--target")
+            .arg(target);
+
+        cmd.assert().success().stdout("");
+
+        let bom: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(tmp_dir.child("bom.json").path())?)?;
+        let components = bom["components"].as_array().unwrap();
+        assert_eq!(
+            components.iter().any(|c| c["name"] == "windows-only"),
+            included,
+            "{target}"
+        );
+        assert_eq!(
+            bom["metadata"]["properties"][0],
+            serde_json::json!({ "name": "cdx:rustc:sbom:target:triple", "value": target })
+        );
+    }
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn source_date_epoch_makes_the_bom_reproducible() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = make_temp_rust_project()?;
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .env("SOURCE_DATE_EPOCH", "1700000000")
+        .arg("cyclonedx")
+        .arg("--format")
+        .arg("json");
+
+    cmd.assert().success().stdout("");
+    let first = std::fs::read_to_string(tmp_dir.child("bom.json").path())?;
+
+    cmd.assert().success().stdout("");
+    let second = std::fs::read_to_string(tmp_dir.child("bom.json").path())?;
+
+    assert_eq!(first, second);
+    assert!(first.contains(r#""timestamp": "2023-11-14T22:13:20.000000000Z""#));
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn bom_is_signed_with_the_given_key() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = make_temp_rust_project()?;
+    tmp_dir.child("secret.key").write_str("secret")?;
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--format")
+        .arg("json")
+        .arg("--sign-key")
+        .arg("secret.key")
+        .arg("--sign-algorithm")
+        .arg("HS256");
+
+    cmd.assert().success().stdout("");
+
+    let bom: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(tmp_dir.child("bom.json").path())?)?;
+    assert_eq!(bom["specVersion"], "1.4");
+    assert_eq!(bom["signature"]["algorithm"], "HS256");
+    assert!(bom["signature"]["value"].is_string());
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn xml_bom_gets_an_enveloped_signature() -> Result<(), Box<dyn std::error::Error>> {
+    use cyclonedx_bom::verification::{verify_document, PublicKey};
+
+    let tmp_dir = make_temp_rust_project()?;
+    tmp_dir.child("secret.key").write_str("secret")?;
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--format")
+        .arg("xml")
+        .arg("--sign-key")
+        .arg("secret.key")
+        .arg("--sign-algorithm")
+        .arg("HS384");
+
+    cmd.assert().success().stdout("");
+
+    let bom = std::fs::read(tmp_dir.child("bom.xml").path())?;
+    let parts = verify_document(&bom, &PublicKey::hmac(b"secret".to_vec()))?;
+    assert!(parts[0].is_document());
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn hashes_of_build_artifacts_are_attached() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = make_temp_rust_project()?;
+    // stands in for the binary built by `cargo build --release`
+    tmp_dir.child("target/release/pkg").write_str("binary")?;
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .env_remove("CARGO_TARGET_DIR")
+        .arg("cyclonedx")
+        .arg("--format")
+        .arg("json")
+        .arg("--target")
+        .arg("all")
+        .arg("--hash-artifacts")
+        .arg("release");
+
+    cmd.assert().success().stdout("");
+
+    let bom: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(tmp_dir.child("bom.json").path())?)?;
+    let target = &bom["metadata"]["component"]["components"][0];
+    assert_eq!(target["name"], "pkg");
+    assert_eq!(target["hashes"][0]["alg"], "SHA-256");
+    assert_eq!(
+        target["hashes"][0]["content"],
+        "9a3a45d01531a20e89ac6ae10b0b0beb0492acd7216a368aa062d1a5fecaf9cd"
+    );
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn build_is_described_in_formulation() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = make_temp_rust_project()?;
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .env("RUSTFLAGS", "-C target-cpu=native")
+        .env("CARGO_REGISTRY_TOKEN", "secret")
+        .arg("cyclonedx")
+        .arg("--format")
+        .arg("json")
+        .arg("--describe-build")
+        .arg("release");
+
+    cmd.assert().success().stdout("");
+
+    let bom = std::fs::read_to_string(tmp_dir.child("bom.json").path())?;
+    assert!(!bom.contains("secret"));
+
+    let bom: serde_json::Value = serde_json::from_str(&bom)?;
+    assert_eq!(bom["specVersion"], "1.5");
+    let workflow = &bom["formulation"][0]["workflows"][0];
+    assert_eq!(workflow["taskTypes"][0], "build");
+    // the host target is passed explicitly, as that is what the SBOM describes by default
+    assert!(workflow["steps"][0]["commands"][0]["executed"]
+        .as_str()
+        .is_some_and(
+            |command| command.starts_with("cargo build --package pkg --release --target ")
+        ));
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn license_is_overridden_from_manifest_metadata() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = make_temp_rust_project()?;
+    tmp_dir.child("Cargo.toml").write_str(
+        r#"[package]
+name = "pkg"
+version = "0.0.0"
+license = "MIT/Apache-2.0"
+
+[package.metadata.cyclonedx.license-overrides]
+"pkg@0.0.0" = "MIT OR Apache-2.0"
+"#,
+    )?;
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--format")
+        .arg("json");
+
+    cmd.assert().success().stdout("");
+
+    let bom: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(tmp_dir.child("bom.json").path())?)?;
+    let component = &bom["metadata"]["component"];
+    assert_eq!(
+        component["licenses"],
+        serde_json::json!([{ "expression": "MIT OR Apache-2.0" }])
+    );
+    assert_eq!(
+        component["properties"],
+        serde_json::json!([
+            { "name": "cargo-cyclonedx:license_override:rule", "value": "pkg@0.0.0" },
+            { "name": "cargo-cyclonedx:license_override:original", "value": "MIT/Apache-2.0" },
+        ])
+    );
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn crates_are_excluded_by_manifest_metadata() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = make_temp_rust_project()?;
+    tmp_dir.child("Cargo.toml").write_str(
+        r#"[package]
+name = "pkg"
+version = "0.0.0"
+
+[dependencies]
+internal-helper = { path = "internal-helper" }
+
+[package.metadata.cyclonedx]
+exclude = ["internal-*"]
+"#,
+    )?;
+
+    let helper_dir = tmp_dir.child("internal-helper");
+    helper_dir.child("src/lib.rs").touch()?;
+    helper_dir
+        .child("Cargo.toml")
+        .write_str(r#"package = { name = "internal-helper", version = "0.0.0" }"#)?;
+
+    // No filter is passed on the command line, only the manifest excludes the helper
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--format")
+        .arg("json");
+
+    cmd.assert().success().stdout("");
+
+    let bom: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(tmp_dir.child("bom.json").path())?)?;
+    assert_eq!(bom["metadata"]["component"]["name"], "pkg");
+    assert!(bom["components"]
+        .as_array()
+        .map_or(true, |components| components.is_empty()));
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn supplier_is_read_from_manifest_metadata() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = make_temp_rust_project()?;
+    tmp_dir.child("Cargo.toml").write_str(
+        r#"[package]
+name = "pkg"
+version = "0.0.0"
+authors = ["Jane Doe <jane@example.com>"]
+
+[package.metadata.cyclonedx.supplier]
+name = "Example Inc."
+url = ["https://example.com/"]
+"#,
+    )?;
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--format")
+        .arg("json");
+
+    cmd.assert().success().stdout("");
+
+    let bom: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(tmp_dir.child("bom.json").path())?)?;
+    let supplier = serde_json::json!({ "name": "Example Inc.", "url": ["https://example.com/"] });
+    assert_eq!(bom["metadata"]["supplier"], supplier);
+    assert_eq!(bom["metadata"]["component"]["supplier"], supplier);
+    assert_eq!(bom["metadata"]["component"]["publisher"], "Example Inc.");
+    assert_eq!(
+        bom["metadata"]["component"]["author"],
+        "Jane Doe <jane@example.com>"
+    );
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
 #[test]
 fn bom_file_name_extension_is_prepended_with_cdx() -> Result<(), Box<dyn std::error::Error>> {
     let tmp_dir = make_temp_rust_project()?;
@@ -161,6 +553,439 @@ fn bom_file_name_extension_is_prepended_with_cdx() -> Result<(), Box<dyn std::er
     Ok(())
 }
 
+#[test]
+fn vendored_sources_are_recognized_offline() -> Result<(), Box<dyn std::error::Error>> {
+    let checksum = "b5e8b1ba7dab5cc3a7ee3b6dd17ba09cd8e6ee6d77ad2fc0fee7bbcaab0cd67e";
+    let tmp_dir = make_temp_rust_project()?;
+    tmp_dir.child("Cargo.toml").write_str(
+        r#"[package]
+name = "pkg"
+version = "0.0.0"
+
+[dependencies]
+dep = "0.1.0"
+"#,
+    )?;
+    // The layout created by `cargo vendor`
+    tmp_dir.child(".cargo/config.toml").write_str(
+        r#"[source.crates-io]
+replace-with = "vendored-sources"
+
+[source.vendored-sources]
+directory = "vendor"
+"#,
+    )?;
+    tmp_dir
+        .child("vendor/dep/Cargo.toml")
+        .write_str(r#"package = { name = "dep", version = "0.1.0" }"#)?;
+    tmp_dir.child("vendor/dep/src/lib.rs").touch()?;
+    tmp_dir
+        .child("vendor/dep/.cargo-checksum.json")
+        .write_str(&format!(r#"{{"files":{{}},"package":"{checksum}"}}"#))?;
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--offline")
+        .arg("--format")
+        .arg("json");
+
+    cmd.assert().success().stdout("");
+
+    let bom: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(tmp_dir.child("bom.json").path())?)?;
+    let dep = &bom["components"][0];
+    assert_eq!(dep["name"], "dep");
+    assert_eq!(dep["hashes"][0]["content"], checksum);
+    assert_eq!(
+        dep["properties"][0]["name"],
+        "cargo-cyclonedx:vendored:directory"
+    );
+    assert_eq!(dep["properties"][0]["value"], "vendor");
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+// Cargo is replaced with a script that records the arguments it is run with
+#[cfg(unix)]
+#[test]
+fn exact_features_are_resolved_offline() -> Result<(), Box<dyn std::error::Error>> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let tmp_dir = make_temp_rust_project()?;
+    let log = tmp_dir.child("cargo.log");
+    let wrapper = tmp_dir.child("cargo-wrapper.sh");
+    wrapper.write_str(&format!(
+        "#!/bin/sh\necho \"$@\" >> '{}'\nexec '{}' \"$@\"\n",
+        log.path().display(),
+        env!("CARGO")
+    ))?;
+    std::fs::set_permissions(wrapper.path(), std::fs::Permissions::from_mode(0o755))?;
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .env("CARGO", wrapper.path())
+        .arg("cyclonedx")
+        .arg("--offline")
+        .arg("--exact-features")
+        .arg("--format")
+        .arg("json");
+
+    cmd.assert().success().stdout("");
+
+    let log = std::fs::read_to_string(log.path())?;
+    let tree = log
+        .lines()
+        .find(|line| line.starts_with("tree "))
+        .expect("cargo tree was not run");
+    assert!(tree.split(' ').any(|arg| arg == "--offline"), "{tree}");
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn several_spec_versions_are_written() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = make_temp_rust_project()?;
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--format")
+        .arg("json")
+        .arg("--spec-version")
+        .arg("1.3,1.5");
+
+    cmd.assert().success().stdout("");
+
+    for version in ["1.3", "1.5"] {
+        let path = tmp_dir.child(format!("bom_{version}.cdx.json"));
+        let bom: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(path.path())?)?;
+        assert_eq!(bom["specVersion"], version);
+    }
+    tmp_dir.child("bom.json").assert(predicate::path::missing());
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn binaries_are_resolved_with_their_required_features() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = make_temp_rust_project()?;
+    tmp_dir.child("Cargo.toml").write_str(
+        r#"[package]
+name = "pkg"
+version = "0.0.0"
+edition = "2021"
+
+[dependencies]
+dep = { path = "dep", optional = true }
+
+[features]
+cli = ["dep:dep"]
+
+[[bin]]
+name = "tool"
+path = "src/tool.rs"
+required-features = ["cli"]
+"#,
+    )?;
+    tmp_dir.child("src/tool.rs").touch()?;
+    tmp_dir
+        .child("dep/Cargo.toml")
+        .write_str(r#"package = { name = "dep", version = "0.1.0" }"#)?;
+    tmp_dir.child("dep/src/lib.rs").touch()?;
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--format")
+        .arg("json")
+        .arg("--output-pattern")
+        .arg("binary");
+
+    cmd.assert().success().stdout("");
+
+    let read = |name: &str| -> Result<serde_json::Value, Box<dyn std::error::Error>> {
+        Ok(serde_json::from_str(&std::fs::read_to_string(
+            tmp_dir.child(name).path(),
+        )?)?)
+    };
+
+    let pkg = read("pkg_bin.cdx.json")?;
+    assert_eq!(pkg["metadata"]["component"]["name"], "pkg");
+    assert_eq!(pkg["components"], serde_json::json!([]));
+
+    let tool = read("tool_bin.cdx.json")?;
+    assert_eq!(tool["metadata"]["component"]["name"], "tool");
+    assert_eq!(tool["components"][0]["name"], "dep");
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn bom_is_compressed() -> Result<(), Box<dyn std::error::Error>> {
+    use cyclonedx_bom::models::bom::Bom;
+
+    let tmp_dir = make_temp_rust_project()?;
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--format")
+        .arg("json")
+        .arg("--compress")
+        .arg("gzip");
+
+    cmd.assert().success().stdout("");
+
+    tmp_dir.child("bom.json").assert(predicate::path::missing());
+    let bom = Bom::parse_from_json(std::fs::File::open(tmp_dir.child("bom.json.gz").path())?)?;
+    assert_eq!(
+        bom.metadata.unwrap().component.unwrap().name.to_string(),
+        "pkg"
+    );
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--compress")
+        .arg("zstd");
+
+    cmd.assert().success().stdout("");
+
+    let bom = Bom::parse_from_xml_v1_3(std::fs::File::open(tmp_dir.child("bom.xml.zst").path())?)?;
+    assert_eq!(
+        bom.metadata.unwrap().component.unwrap().name.to_string(),
+        "pkg"
+    );
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn bom_is_uploaded_to_dependency_track() -> Result<(), Box<dyn std::error::Error>> {
+    use std::io::{BufRead, BufReader, Read, Write};
+
+    let tmp_dir = make_temp_rust_project()?;
+
+    // A stand-in for Dependency-Track, accepting a single request
+    let listener = std::net::TcpListener::bind("127.0.0.1:0")?;
+    let url = format!("http://{}", listener.local_addr()?);
+    let server = std::thread::spawn(move || -> std::io::Result<(String, String)> {
+        let (stream, _) = listener.accept()?;
+        let mut reader = BufReader::new(stream.try_clone()?);
+        let mut head = String::new();
+        let mut content_length = 0;
+        loop {
+            let mut line = String::new();
+            reader.read_line(&mut line)?;
+            if let Some(length) = line.to_ascii_lowercase().strip_prefix("content-length:") {
+                content_length = length.trim().parse().unwrap();
+            }
+            if line == "\r\n" {
+                break;
+            }
+            head.push_str(&line);
+        }
+        let mut body = vec![0; content_length];
+        reader.read_exact(&mut body)?;
+
+        let response = r#"{"token":"c6b0b6e8-0b1a-4b5e-9b4e-5d5a0d1f4a3c"}"#;
+        write!(
+            &stream,
+            "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{}",
+            response.len(),
+            response
+        )?;
+        Ok((head, String::from_utf8(body).unwrap()))
+    });
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .env("DTRACK_API_KEY", "secret")
+        .arg("cyclonedx")
+        .arg("--upload")
+        .arg(&url)
+        .arg("--auto-create");
+
+    cmd.assert().success().stdout("");
+
+    let (head, body) = server.join().unwrap()?;
+    assert!(head.starts_with("PUT /api/v1/bom HTTP/1.1"));
+    assert!(head.to_ascii_lowercase().contains("x-api-key: secret"));
+
+    let request: serde_json::Value = serde_json::from_str(&body)?;
+    assert_eq!(request["projectName"], "pkg");
+    assert_eq!(request["projectVersion"], "0.0.0");
+    assert_eq!(request["autoCreate"], true);
+
+    use base64::Engine;
+    let bom = base64::engine::general_purpose::STANDARD.decode(request["bom"].as_str().unwrap())?;
+    assert_eq!(bom, std::fs::read(tmp_dir.child("bom.xml").path())?);
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn upload_requires_api_key() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = make_temp_rust_project()?;
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .env_remove("DTRACK_API_KEY")
+        .arg("cyclonedx")
+        .arg("--upload")
+        .arg("http://127.0.0.1:1");
+
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("requires an API key"));
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn bom_is_generated_from_the_lockfile_alone() -> Result<(), Box<dyn std::error::Error>> {
+    let checksum = "25dd9975e68d0cb5aa1120c288333fc98731bd1dd12f561e468ea4728c042b89";
+    let tmp_dir = assert_fs::TempDir::new()?;
+    tmp_dir.child("third-party.lock").write_str(&format!(
+        r#"version = 3
+
+[[package]]
+name = "app"
+version = "0.1.0"
+dependencies = [
+ "serde",
+]
+
+[[package]]
+name = "serde"
+version = "1.0.193"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+checksum = "{checksum}"
+"#
+    ))?;
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--lockfile")
+        .arg("third-party.lock")
+        .arg("--format")
+        .arg("json");
+
+    cmd.assert().success().stdout("");
+
+    let bom: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(tmp_dir.child("bom.json").path())?)?;
+    assert_eq!(bom["metadata"]["component"]["name"], "app");
+    let serde = &bom["components"][0];
+    assert_eq!(serde["purl"], "pkg:cargo/serde@1.0.193");
+    assert_eq!(serde["hashes"][0]["content"], checksum);
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn bom_is_generated_for_a_built_binary() -> Result<(), Box<dyn std::error::Error>> {
+    use object::write::Object;
+    use object::{Architecture, BinaryFormat, Endianness, SectionKind};
+    use std::io::Write;
+
+    let dependencies = r#"{"packages":[
+        {"name":"tool","version":"0.3.0","source":"crates.io","dependencies":[1],"root":true},
+        {"name":"serde","version":"1.0.193","source":"crates.io"}
+    ]}"#;
+    let mut encoder = flate2::write::ZlibEncoder::new(Vec::new(), flate2::Compression::default());
+    encoder.write_all(dependencies.as_bytes())?;
+    let mut binary = Object::new(BinaryFormat::Elf, Architecture::X86_64, Endianness::Little);
+    let section = binary.add_section(Vec::new(), b".dep-v0".to_vec(), SectionKind::ReadOnlyData);
+    binary.append_section_data(section, &encoder.finish()?, 1);
+
+    let tmp_dir = assert_fs::TempDir::new()?;
+    tmp_dir.child("dist/tool").write_binary(&binary.write()?)?;
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--binary")
+        .arg("dist/tool")
+        .arg("--format")
+        .arg("json");
+
+    cmd.assert().success().stdout("");
+
+    let bom: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(
+        tmp_dir.child("tool.cdx.json").path(),
+    )?)?;
+    let component = &bom["metadata"]["component"];
+    assert_eq!(component["purl"], "pkg:cargo/tool@0.3.0");
+    assert_eq!(component["components"][0]["hashes"][0]["alg"], "SHA-256");
+    assert_eq!(bom["components"][0]["purl"], "pkg:cargo/serde@1.0.193");
+    assert_eq!(bom["compositions"][0]["aggregate"], "complete");
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn options_are_read_from_the_config_file() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = make_temp_rust_project()?;
+    tmp_dir.child("cyclonedx.toml").write_str(
+        r#"
+        format = "json"
+        output-prefix = "configured"
+        "#,
+    )?;
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path()).arg("cyclonedx");
+    cmd.assert().success().stdout("");
+    tmp_dir
+        .child("configured.cdx.json")
+        .assert(predicate::path::exists());
+
+    // The command line takes precedence, including over options it conflicts with
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--format=xml")
+        .arg("--output-pattern=package");
+    cmd.assert().success().stdout("");
+    tmp_dir
+        .child("pkg.cdx.xml")
+        .assert(predicate::path::exists());
+
+    tmp_dir
+        .child("cyclonedx.toml")
+        .write_str("unknown-option = true")?;
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path()).arg("cyclonedx");
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("unknown-option"));
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
 fn make_temp_rust_project() -> Result<assert_fs::TempDir, assert_fs::fixture::FixtureError> {
     let tmp_dir = assert_fs::TempDir::new()?;
     tmp_dir.child("src/main.rs").touch()?;
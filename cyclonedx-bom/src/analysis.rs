@@ -0,0 +1,1103 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Health checks for a BOM that go beyond schema validation.
+//!
+//! A [`Bom`] can pass [`Validate`](crate::validation::Validate) and still be of little use to a
+//! consumer, e.g. because half of its components carry no license or are not connected to the
+//! dependency graph. The [`Analyzer`] runs all such checks at once and returns an [`AnalysisReport`].
+//!
+//! ```
+//! use cyclonedx_bom::analysis::{AnalysisConfig, Analyzer};
+//! use cyclonedx_bom::models::bom::Bom;
+//!
+//! let report = Analyzer::run(&Bom::default(), AnalysisConfig::default());
+//! assert_eq!(report.orphans, Some(vec![]));
+//! // an empty BOM has neither a timestamp nor an author
+//! assert!(!report.ntia.unwrap().is_compliant());
+//! ```
+
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+use std::fmt;
+
+use serde::Serialize;
+
+use crate::localization::{English, LocalizedMessage, MessageCatalog};
+use crate::models::bom::Bom;
+use crate::models::component::{Component, Components};
+use crate::models::composition::{AggregateType, BomReference};
+use crate::models::service::Services;
+use crate::models::vulnerability_rating::Severity;
+
+/// Selects which checks the [`Analyzer`] runs. All checks are enabled by default.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AnalysisConfig {
+    /// Find components that are not reachable in the dependency graph
+    pub orphans: bool,
+    /// Find components that are listed more than once
+    pub duplicates: bool,
+    /// Count the components that declare a license
+    pub license_coverage: bool,
+    /// Check the [NTIA minimum elements](https://www.ntia.gov/report/2021/minimum-elements-software-bill-materials-sbom)
+    pub ntia: bool,
+    /// Summarize the vulnerabilities by severity
+    pub severities: bool,
+    /// Find contradictions between the compositions and the contents of the BOM
+    pub compositions: bool,
+}
+
+impl Default for AnalysisConfig {
+    fn default() -> Self {
+        Self {
+            orphans: true,
+            duplicates: true,
+            license_coverage: true,
+            ntia: true,
+            severities: true,
+            compositions: true,
+        }
+    }
+}
+
+/// Runs the checks selected in an [`AnalysisConfig`] against a [`Bom`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Analyzer;
+
+impl Analyzer {
+    /// Analyzes the given BOM, including all nested components.
+    ///
+    /// The component in `metadata.component` is the subject of the BOM and therefore
+    /// not part of the component checks.
+    pub fn run(bom: &Bom, config: AnalysisConfig) -> AnalysisReport {
+        let mut components = Vec::new();
+        if let Some(bom_components) = &bom.components {
+            collect_components(bom_components, &mut components);
+        }
+
+        AnalysisReport {
+            orphans: config.orphans.then(|| find_orphans(bom, &components)),
+            duplicates: config.duplicates.then(|| find_duplicates(&components)),
+            license_coverage: config
+                .license_coverage
+                .then(|| license_coverage(&components)),
+            ntia: config.ntia.then(|| check_ntia(bom, &components)),
+            severities: config.severities.then(|| severity_rollup(bom)),
+            compositions: config
+                .compositions
+                .then(|| check_compositions(bom, &components)),
+        }
+    }
+}
+
+/// The outcome of [`Analyzer::run`]. Each field is `None` if the check was disabled.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AnalysisReport {
+    /// Components that are not reachable from the root of the dependency graph
+    pub orphans: Option<Vec<String>>,
+    pub duplicates: Option<Vec<Duplicate>>,
+    pub license_coverage: Option<LicenseCoverage>,
+    pub ntia: Option<NtiaReport>,
+    pub severities: Option<SeverityRollup>,
+    pub compositions: Option<Vec<CompositionFinding>>,
+}
+
+impl AnalysisReport {
+    /// Returns `true` if any of the checks found something worth looking at.
+    ///
+    /// Vulnerabilities only count as findings if they are rated above `none`/`info`.
+    pub fn has_findings(&self) -> bool {
+        self.orphans.as_ref().is_some_and(|o| !o.is_empty())
+            || self.duplicates.as_ref().is_some_and(|d| !d.is_empty())
+            || self
+                .license_coverage
+                .as_ref()
+                .is_some_and(|l| !l.unlicensed.is_empty())
+            || self.ntia.as_ref().is_some_and(|n| !n.is_compliant())
+            || self.severities.as_ref().is_some_and(|s| s.actionable() > 0)
+            || self.compositions.as_ref().is_some_and(|c| !c.is_empty())
+    }
+
+    /// Lists the findings as messages that can be rendered with a [`MessageCatalog`].
+    pub fn messages(&self) -> Vec<LocalizedMessage> {
+        let mut messages = Vec::new();
+
+        for orphan in self.orphans.iter().flatten() {
+            messages.push(LocalizedMessage::new("report.orphan", vec![orphan.clone()]));
+        }
+
+        for duplicate in self.duplicates.iter().flatten() {
+            messages.push(LocalizedMessage::new(
+                "report.duplicate",
+                vec![duplicate.identity.clone(), duplicate.occurrences.join(", ")],
+            ));
+        }
+
+        if let Some(coverage) = &self.license_coverage {
+            messages.push(LocalizedMessage::new(
+                "report.license_coverage",
+                vec![coverage.licensed.to_string(), coverage.total.to_string()],
+            ));
+            for component in &coverage.unlicensed {
+                messages.push(LocalizedMessage::new(
+                    "report.unlicensed",
+                    vec![component.clone()],
+                ));
+            }
+        }
+
+        if let Some(ntia) = &self.ntia {
+            if ntia.missing_author {
+                messages.push(LocalizedMessage::new("report.ntia.missing_author", vec![]));
+            }
+            if ntia.missing_timestamp {
+                messages.push(LocalizedMessage::new(
+                    "report.ntia.missing_timestamp",
+                    vec![],
+                ));
+            }
+            for finding in &ntia.components {
+                for element in &finding.missing {
+                    let key = match element {
+                        NtiaElement::Supplier => "report.ntia.missing_supplier",
+                        NtiaElement::Version => "report.ntia.missing_version",
+                        NtiaElement::UniqueIdentifier => "report.ntia.missing_unique_identifier",
+                        NtiaElement::DependencyRelationship => {
+                            "report.ntia.missing_dependency_relationship"
+                        }
+                    };
+                    messages.push(LocalizedMessage::new(key, vec![finding.component.clone()]));
+                }
+            }
+        }
+
+        if let Some(severities) = &self.severities {
+            let counts = [
+                severities.critical,
+                severities.high,
+                severities.medium,
+                severities.low,
+                severities.info,
+                severities.none,
+                severities.unknown,
+            ];
+            messages.push(LocalizedMessage::new(
+                "report.severities",
+                counts.iter().map(|c| c.to_string()).collect(),
+            ));
+        }
+
+        for finding in self.compositions.iter().flatten() {
+            messages.push(match finding {
+                CompositionFinding::UnknownReference { bom_ref } => LocalizedMessage::new(
+                    "report.composition.unknown_reference",
+                    vec![bom_ref.clone()],
+                ),
+                CompositionFinding::MissingDependencies { bom_ref } => LocalizedMessage::new(
+                    "report.composition.missing_dependencies",
+                    vec![bom_ref.clone()],
+                ),
+                CompositionFinding::UnknownDependency {
+                    bom_ref,
+                    dependency,
+                } => LocalizedMessage::new(
+                    "report.composition.unknown_dependency",
+                    vec![bom_ref.clone(), dependency.clone()],
+                ),
+                CompositionFinding::ConflictingAggregates {
+                    bom_ref,
+                    part,
+                    aggregates,
+                } => LocalizedMessage::new(
+                    match part {
+                        CompositionPart::Assemblies => "report.composition.conflicting_assemblies",
+                        CompositionPart::Dependencies => {
+                            "report.composition.conflicting_dependencies"
+                        }
+                    },
+                    vec![bom_ref.clone(), aggregates.join(", ")],
+                ),
+            });
+        }
+
+        messages
+    }
+
+    /// Renders the report as text, one finding per line.
+    pub fn render(&self, catalog: &dyn MessageCatalog) -> String {
+        self.messages()
+            .iter()
+            .map(|message| message.render(catalog))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl fmt::Display for AnalysisReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.render(&English))
+    }
+}
+
+/// A component that is listed more than once, identified by its purl or by `name@version`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Duplicate {
+    pub identity: String,
+    /// The bom-refs (or names, if there is no bom-ref) of all occurrences
+    pub occurrences: Vec<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LicenseCoverage {
+    pub total: usize,
+    pub licensed: usize,
+    /// The bom-refs (or names, if there is no bom-ref) of the components without a license
+    pub unlicensed: Vec<String>,
+}
+
+impl LicenseCoverage {
+    /// The share of licensed components between `0.0` and `1.0`. An empty BOM is fully covered.
+    pub fn ratio(&self) -> f64 {
+        if self.total == 0 {
+            1.0
+        } else {
+            self.licensed as f64 / self.total as f64
+        }
+    }
+}
+
+/// A data field required by the NTIA minimum elements.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum NtiaElement {
+    Supplier,
+    Version,
+    /// One of purl, CPE or SWID
+    UniqueIdentifier,
+    DependencyRelationship,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NtiaFinding {
+    /// The bom-ref (or name, if there is no bom-ref) of the component
+    pub component: String,
+    pub missing: Vec<NtiaElement>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct NtiaReport {
+    /// Neither `metadata.authors` nor `metadata.tools` are present
+    pub missing_author: bool,
+    pub missing_timestamp: bool,
+    pub components: Vec<NtiaFinding>,
+}
+
+impl NtiaReport {
+    pub fn is_compliant(&self) -> bool {
+        !self.missing_author && !self.missing_timestamp && self.components.is_empty()
+    }
+}
+
+/// The number of vulnerabilities per severity. A vulnerability with several ratings
+/// is counted once, with the highest of its severities.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SeverityRollup {
+    pub critical: usize,
+    pub high: usize,
+    pub medium: usize,
+    pub low: usize,
+    pub info: usize,
+    pub none: usize,
+    /// Vulnerabilities without a (known) severity
+    pub unknown: usize,
+    /// The number of vulnerabilities affecting each bom-ref
+    pub affected: BTreeMap<String, usize>,
+}
+
+impl SeverityRollup {
+    /// The number of vulnerabilities that require action, i.e. all except `info` and `none`
+    pub fn actionable(&self) -> usize {
+        self.critical + self.high + self.medium + self.low + self.unknown
+    }
+}
+
+/// Whether a composition makes a statement about the assemblies or the dependencies of a bom-ref.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CompositionPart {
+    Assemblies,
+    Dependencies,
+}
+
+/// A contradiction between the `compositions` of a BOM and what the BOM actually contains.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CompositionFinding {
+    /// A composition refers to a bom-ref that is neither a component nor a service of the BOM
+    UnknownReference { bom_ref: String },
+    /// The dependencies of `bom_ref` are claimed to be complete, but the dependency graph
+    /// has no entry for it
+    MissingDependencies { bom_ref: String },
+    /// The dependencies of `bom_ref` are claimed to be complete, but one of them is
+    /// neither a component nor a service of the BOM
+    UnknownDependency { bom_ref: String, dependency: String },
+    /// Several compositions describe the same part of `bom_ref` with different aggregates
+    ConflictingAggregates {
+        bom_ref: String,
+        part: CompositionPart,
+        aggregates: Vec<String>,
+    },
+}
+
+/// Counts that summarize a BOM, e.g. for a dashboard, see [`Bom::stats`].
+///
+/// Like the [`Analyzer`], the component counts include nested components,
+/// but not the component in `metadata.component`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct BomStats {
+    pub components: usize,
+    /// The number of components per type, e.g. `library`
+    pub components_by_type: BTreeMap<String, usize>,
+    pub with_hashes: usize,
+    pub with_purls: usize,
+    pub with_licenses: usize,
+    /// The number of distinct supplier names
+    pub suppliers: usize,
+    /// The number of services, including nested ones
+    pub services: usize,
+    /// The number of edges in the dependency graph
+    pub dependencies: usize,
+    /// The number of dependencies of the subject of the BOM, or of all roots of the graph
+    pub direct_dependencies: usize,
+    /// The length of the longest path from a root of the dependency graph to any component
+    /// reachable from it, following the shortest path to each component
+    pub max_dependency_depth: usize,
+    pub vulnerabilities: usize,
+}
+
+impl BomStats {
+    pub(crate) fn collect(bom: &Bom) -> Self {
+        let mut components = Vec::new();
+        if let Some(bom_components) = &bom.components {
+            collect_components(bom_components, &mut components);
+        }
+
+        let mut stats = BomStats {
+            components: components.len(),
+            ..BomStats::default()
+        };
+        let mut suppliers = HashSet::new();
+        for component in &components {
+            *stats
+                .components_by_type
+                .entry(component.component_type.to_string())
+                .or_default() += 1;
+            stats.with_hashes +=
+                usize::from(component.hashes.as_ref().is_some_and(|h| !h.0.is_empty()));
+            stats.with_purls += usize::from(component.purl.is_some());
+            stats.with_licenses +=
+                usize::from(component.licenses.as_ref().is_some_and(|l| !l.0.is_empty()));
+            suppliers.extend(component.supplier.as_ref().and_then(|s| s.name.as_ref()));
+        }
+        stats.suppliers = suppliers.len();
+
+        stats.services = bom.services.as_ref().map_or(0, count_services);
+
+        let graph = dependency_graph(bom);
+        stats.dependencies = graph.values().map(|deps| deps.len()).sum();
+        let roots = dependency_roots(bom, &graph);
+        stats.direct_dependencies = roots
+            .iter()
+            .filter_map(|root| graph.get(root))
+            .map(|deps| deps.len())
+            .sum();
+        let mut depths: HashMap<&str, usize> = HashMap::new();
+        let mut queue: VecDeque<(&str, usize)> = roots.into_iter().map(|r| (r, 0)).collect();
+        while let Some((bom_ref, depth)) = queue.pop_front() {
+            if depths.contains_key(bom_ref) {
+                continue;
+            }
+            depths.insert(bom_ref, depth);
+            if let Some(deps) = graph.get(bom_ref) {
+                queue.extend(deps.iter().map(|d| (d.as_str(), depth + 1)));
+            }
+        }
+        stats.max_dependency_depth = depths.values().copied().max().unwrap_or_default();
+
+        stats.vulnerabilities = bom.vulnerabilities.as_ref().map_or(0, |v| v.0.len());
+        stats
+    }
+}
+
+fn collect_components<'a>(components: &'a Components, out: &mut Vec<&'a Component>) {
+    for component in &components.0 {
+        out.push(component);
+        if let Some(nested) = &component.components {
+            collect_components(nested, out);
+        }
+    }
+}
+
+fn count_services(services: &Services) -> usize {
+    services
+        .0
+        .iter()
+        .map(|service| 1 + service.services.as_ref().map_or(0, count_services))
+        .sum()
+}
+
+fn describe(component: &Component) -> String {
+    match (&component.bom_ref, &component.version) {
+        (Some(bom_ref), _) => bom_ref.clone(),
+        (None, Some(version)) => format!("{}@{}", component.name, version),
+        (None, None) => component.name.to_string(),
+    }
+}
+
+fn dependency_graph(bom: &Bom) -> HashMap<&str, &[String]> {
+    let mut graph: HashMap<&str, &[String]> = HashMap::new();
+    if let Some(dependencies) = &bom.dependencies {
+        for dependency in &dependencies.0 {
+            graph.insert(&dependency.dependency_ref, &dependency.dependencies);
+        }
+    }
+    graph
+}
+
+/// The roots of the dependency graph, i.e. the subject of the BOM,
+/// or without one, everything that nobody depends on
+fn dependency_roots<'a>(bom: &'a Bom, graph: &HashMap<&'a str, &'a [String]>) -> Vec<&'a str> {
+    let root = bom
+        .metadata
+        .as_ref()
+        .and_then(|m| m.component.as_ref())
+        .and_then(|c| c.bom_ref.as_deref());
+    match root {
+        Some(root) => vec![root],
+        None => {
+            let depended_on: HashSet<&str> = graph
+                .values()
+                .flat_map(|deps| deps.iter().map(String::as_str))
+                .collect();
+            graph
+                .keys()
+                .copied()
+                .filter(|r| !depended_on.contains(r))
+                .collect()
+        }
+    }
+}
+
+fn find_orphans(bom: &Bom, components: &[&Component]) -> Vec<String> {
+    let graph = dependency_graph(bom);
+    let mut queue: VecDeque<&str> = dependency_roots(bom, &graph).into();
+
+    let mut reachable: HashSet<&str> = HashSet::new();
+    while let Some(bom_ref) = queue.pop_front() {
+        if reachable.insert(bom_ref) {
+            if let Some(deps) = graph.get(bom_ref) {
+                queue.extend(deps.iter().map(String::as_str));
+            }
+        }
+    }
+
+    components
+        .iter()
+        .filter(|c| match &c.bom_ref {
+            Some(bom_ref) => !reachable.contains(bom_ref.as_str()),
+            None => true,
+        })
+        .map(|c| describe(c))
+        .collect()
+}
+
+fn find_duplicates(components: &[&Component]) -> Vec<Duplicate> {
+    let mut seen: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for component in components {
+        let identity = match (&component.purl, &component.version) {
+            (Some(purl), _) => purl.to_string(),
+            (None, Some(version)) => format!("{}@{}", component.name, version),
+            (None, None) => component.name.to_string(),
+        };
+        seen.entry(identity).or_default().push(describe(component));
+    }
+
+    seen.into_iter()
+        .filter(|(_, occurrences)| occurrences.len() > 1)
+        .map(|(identity, occurrences)| Duplicate {
+            identity,
+            occurrences,
+        })
+        .collect()
+}
+
+fn license_coverage(components: &[&Component]) -> LicenseCoverage {
+    let unlicensed: Vec<String> = components
+        .iter()
+        .filter(|c| c.licenses.as_ref().map_or(true, |l| l.0.is_empty()))
+        .map(|c| describe(c))
+        .collect();
+
+    LicenseCoverage {
+        total: components.len(),
+        licensed: components.len() - unlicensed.len(),
+        unlicensed,
+    }
+}
+
+fn check_ntia(bom: &Bom, components: &[&Component]) -> NtiaReport {
+    let metadata = bom.metadata.as_ref();
+    let dependency_refs: HashSet<&str> = bom
+        .dependencies
+        .iter()
+        .flat_map(|d| d.0.iter())
+        .map(|d| d.dependency_ref.as_str())
+        .collect();
+
+    let components = components
+        .iter()
+        .filter_map(|component| {
+            let mut missing = Vec::new();
+            // Cargo has no notion of a supplier, so the author is accepted in its place.
+            if component.supplier.is_none() && component.author.is_none() {
+                missing.push(NtiaElement::Supplier);
+            }
+            if component.version.is_none() {
+                missing.push(NtiaElement::Version);
+            }
+            if component.purl.is_none() && component.cpe.is_none() && component.swid.is_none() {
+                missing.push(NtiaElement::UniqueIdentifier);
+            }
+            let has_relationship = component
+                .bom_ref
+                .as_ref()
+                .is_some_and(|r| dependency_refs.contains(r.as_str()));
+            if !has_relationship {
+                missing.push(NtiaElement::DependencyRelationship);
+            }
+
+            (!missing.is_empty()).then(|| NtiaFinding {
+                component: describe(component),
+                missing,
+            })
+        })
+        .collect();
+
+    NtiaReport {
+        missing_author: metadata.map_or(true, |m| m.authors.is_none() && m.tools.is_none()),
+        missing_timestamp: metadata.map_or(true, |m| m.timestamp.is_none()),
+        components,
+    }
+}
+
+fn collect_service_refs<'a>(services: &'a Services, out: &mut HashSet<&'a str>) {
+    for service in &services.0 {
+        out.extend(service.bom_ref.as_deref());
+        if let Some(nested) = &service.services {
+            collect_service_refs(nested, out);
+        }
+    }
+}
+
+fn check_compositions(bom: &Bom, components: &[&Component]) -> Vec<CompositionFinding> {
+    let Some(compositions) = &bom.compositions else {
+        return Vec::new();
+    };
+
+    let mut known: HashSet<&str> = components
+        .iter()
+        .filter_map(|c| c.bom_ref.as_deref())
+        .collect();
+    known.extend(
+        bom.metadata
+            .as_ref()
+            .and_then(|m| m.component.as_ref())
+            .and_then(|c| c.bom_ref.as_deref()),
+    );
+    if let Some(services) = &bom.services {
+        collect_service_refs(services, &mut known);
+    }
+    let graph = dependency_graph(bom);
+
+    let mut findings = Vec::new();
+    let mut unknown = BTreeSet::new();
+    let mut aggregates: BTreeMap<(&str, CompositionPart), BTreeSet<String>> = BTreeMap::new();
+    for composition in &compositions.0 {
+        let parts = [
+            (CompositionPart::Assemblies, &composition.assemblies),
+            (CompositionPart::Dependencies, &composition.dependencies),
+        ];
+        for (part, bom_refs) in parts {
+            for BomReference(bom_ref) in bom_refs.iter().flatten() {
+                if !known.contains(bom_ref.as_str()) {
+                    unknown.insert(bom_ref.as_str());
+                    continue;
+                }
+                aggregates
+                    .entry((bom_ref, part))
+                    .or_default()
+                    .insert(composition.aggregate.to_string());
+
+                if part != CompositionPart::Dependencies
+                    || composition.aggregate != AggregateType::Complete
+                {
+                    continue;
+                }
+                match graph.get(bom_ref.as_str()) {
+                    None => findings.push(CompositionFinding::MissingDependencies {
+                        bom_ref: bom_ref.clone(),
+                    }),
+                    Some(dependencies) => findings.extend(
+                        dependencies
+                            .iter()
+                            .filter(|d| !known.contains(d.as_str()))
+                            .map(|dependency| CompositionFinding::UnknownDependency {
+                                bom_ref: bom_ref.clone(),
+                                dependency: dependency.clone(),
+                            }),
+                    ),
+                }
+            }
+        }
+    }
+
+    let unknown = unknown
+        .into_iter()
+        .map(|bom_ref| CompositionFinding::UnknownReference {
+            bom_ref: bom_ref.to_string(),
+        });
+    let conflicts = aggregates
+        .into_iter()
+        .filter(|(_, aggregates)| aggregates.len() > 1)
+        .map(
+            |((bom_ref, part), aggregates)| CompositionFinding::ConflictingAggregates {
+                bom_ref: bom_ref.to_string(),
+                part,
+                aggregates: aggregates.into_iter().collect(),
+            },
+        );
+    findings.dedup();
+    unknown.chain(findings).chain(conflicts).collect()
+}
+
+fn severity_rank(severity: &Severity) -> u8 {
+    match severity {
+        Severity::Critical => 6,
+        Severity::High => 5,
+        Severity::Medium => 4,
+        Severity::Low => 3,
+        Severity::Info => 2,
+        Severity::None => 1,
+        Severity::Unknown | Severity::UndefinedSeverity(_) => 0,
+    }
+}
+
+fn severity_rollup(bom: &Bom) -> SeverityRollup {
+    let mut rollup = SeverityRollup::default();
+
+    for vulnerability in bom.vulnerabilities.iter().flat_map(|v| v.0.iter()) {
+        let highest = vulnerability
+            .vulnerability_ratings
+            .iter()
+            .flat_map(|r| r.0.iter())
+            .filter_map(|r| r.severity.as_ref())
+            .max_by_key(|s| severity_rank(s));
+
+        match highest {
+            Some(Severity::Critical) => rollup.critical += 1,
+            Some(Severity::High) => rollup.high += 1,
+            Some(Severity::Medium) => rollup.medium += 1,
+            Some(Severity::Low) => rollup.low += 1,
+            Some(Severity::Info) => rollup.info += 1,
+            Some(Severity::None) => rollup.none += 1,
+            _ => rollup.unknown += 1,
+        }
+
+        for target in vulnerability
+            .vulnerability_targets
+            .iter()
+            .flat_map(|t| t.0.iter())
+        {
+            *rollup.affected.entry(target.bom_ref.clone()).or_default() += 1;
+        }
+    }
+
+    rollup
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::external_models::normalized_string::NormalizedString;
+    use crate::external_models::spdx::SpdxExpression;
+    use crate::external_models::uri::Purl;
+    use crate::localization::Translations;
+    use crate::models::component::Classification;
+    use crate::models::composition::{Composition, Compositions};
+    use crate::models::dependency::{Dependencies, Dependency};
+    use crate::models::license::{LicenseChoice, Licenses};
+    use crate::models::metadata::Metadata;
+    use crate::models::organization::OrganizationalEntity;
+    use crate::models::service::Service;
+    use crate::models::tool::{Tool, Tools};
+    use crate::models::vulnerability::{Vulnerabilities, Vulnerability};
+    use crate::models::vulnerability_rating::{VulnerabilityRating, VulnerabilityRatings};
+    use crate::models::vulnerability_target::{VulnerabilityTarget, VulnerabilityTargets};
+    use pretty_assertions::assert_eq;
+
+    fn component(name: &str, bom_ref: &str) -> Component {
+        let mut component = Component::new(
+            Classification::Library,
+            name,
+            "1.0.0",
+            Some(bom_ref.to_string()),
+        );
+        component.author = Some(NormalizedString::new("Jane Doe"));
+        component.purl = Some(Purl::new("cargo", name, "1.0.0").unwrap());
+        component.licenses = Some(Licenses(vec![LicenseChoice::Expression(
+            SpdxExpression::parse_lax("MIT".to_string()).unwrap(),
+        )]));
+        component
+    }
+
+    fn dependency(dependency_ref: &str, dependencies: &[&str]) -> Dependency {
+        Dependency {
+            dependency_ref: dependency_ref.to_string(),
+            dependencies: dependencies.iter().map(|d| d.to_string()).collect(),
+        }
+    }
+
+    fn healthy_bom() -> Bom {
+        let mut metadata = Metadata::new().unwrap();
+        metadata.tools = Some(Tools::List(vec![Tool::new(
+            "CycloneDX",
+            "cargo-cyclonedx",
+            "1.0.0",
+        )]));
+        metadata.component = Some(component("app", "app"));
+
+        Bom {
+            metadata: Some(metadata),
+            components: Some(Components(vec![component("a", "a"), component("b", "b")])),
+            dependencies: Some(Dependencies(vec![
+                dependency("app", &["a"]),
+                dependency("a", &["b"]),
+                dependency("b", &[]),
+            ])),
+            ..Bom::default()
+        }
+    }
+
+    fn rated(severities: &[Severity], target: &str) -> Vulnerability {
+        let mut vulnerability = Vulnerability::new(None);
+        vulnerability.vulnerability_ratings = Some(VulnerabilityRatings(
+            severities
+                .iter()
+                .map(|s| VulnerabilityRating::new(None, Some(s.clone()), None))
+                .collect(),
+        ));
+        vulnerability.vulnerability_targets =
+            Some(VulnerabilityTargets(vec![VulnerabilityTarget::new(
+                target.to_string(),
+            )]));
+        vulnerability
+    }
+
+    #[test]
+    fn it_should_not_report_findings_for_a_healthy_bom() {
+        let report = Analyzer::run(&healthy_bom(), AnalysisConfig::default());
+
+        assert_eq!(report.orphans, Some(vec![]));
+        assert_eq!(report.duplicates, Some(vec![]));
+        assert_eq!(
+            report.license_coverage,
+            Some(LicenseCoverage {
+                total: 2,
+                licensed: 2,
+                unlicensed: vec![],
+            })
+        );
+        assert_eq!(report.ntia, Some(NtiaReport::default()));
+        assert_eq!(report.severities, Some(SeverityRollup::default()));
+        assert_eq!(report.compositions, Some(vec![]));
+        assert!(!report.has_findings());
+    }
+
+    #[test]
+    fn it_should_only_run_the_configured_checks() {
+        let config = AnalysisConfig {
+            orphans: false,
+            duplicates: true,
+            license_coverage: false,
+            ntia: false,
+            severities: false,
+            compositions: false,
+        };
+        let report = Analyzer::run(&healthy_bom(), config);
+
+        assert_eq!(
+            report,
+            AnalysisReport {
+                duplicates: Some(vec![]),
+                ..AnalysisReport::default()
+            }
+        );
+    }
+
+    #[test]
+    fn it_should_find_orphans() {
+        let mut bom = healthy_bom();
+        let components = bom.components.as_mut().unwrap();
+        let mut nested = component("c", "c");
+        nested.components = Some(Components(vec![component("d", "d")]));
+        components.0.push(nested);
+        bom.dependencies
+            .as_mut()
+            .unwrap()
+            .0
+            .push(dependency("c", &["b"]));
+
+        let report = Analyzer::run(&bom, AnalysisConfig::default());
+
+        assert_eq!(report.orphans, Some(vec!["c".to_string(), "d".to_string()]));
+        assert!(report.has_findings());
+    }
+
+    #[test]
+    fn it_should_find_orphans_without_a_root_component() {
+        let mut bom = healthy_bom();
+        bom.metadata.as_mut().unwrap().component = None;
+        bom.components.as_mut().unwrap().0.push(component("c", "c"));
+
+        let report = Analyzer::run(&bom, AnalysisConfig::default());
+
+        assert_eq!(report.orphans, Some(vec!["c".to_string()]));
+    }
+
+    #[test]
+    fn it_should_find_duplicates() {
+        let mut bom = healthy_bom();
+        bom.components
+            .as_mut()
+            .unwrap()
+            .0
+            .push(component("a", "a-again"));
+
+        let report = Analyzer::run(&bom, AnalysisConfig::default());
+
+        assert_eq!(
+            report.duplicates,
+            Some(vec![Duplicate {
+                identity: "pkg:cargo/a@1.0.0".to_string(),
+                occurrences: vec!["a".to_string(), "a-again".to_string()],
+            }])
+        );
+    }
+
+    #[test]
+    fn it_should_measure_license_coverage() {
+        let mut bom = healthy_bom();
+        bom.components.as_mut().unwrap().0[1].licenses = None;
+
+        let coverage = Analyzer::run(&bom, AnalysisConfig::default())
+            .license_coverage
+            .unwrap();
+
+        assert_eq!(coverage.unlicensed, vec!["b".to_string()]);
+        assert_eq!(coverage.ratio(), 0.5);
+    }
+
+    #[test]
+    fn it_should_check_the_ntia_minimum_elements() {
+        let mut bom = healthy_bom();
+        let metadata = bom.metadata.as_mut().unwrap();
+        metadata.tools = None;
+        metadata.timestamp = None;
+        let b = &mut bom.components.as_mut().unwrap().0[1];
+        b.author = None;
+        b.version = None;
+        b.purl = None;
+        bom.dependencies.as_mut().unwrap().0.pop();
+
+        let ntia = Analyzer::run(&bom, AnalysisConfig::default()).ntia.unwrap();
+
+        assert_eq!(
+            ntia,
+            NtiaReport {
+                missing_author: true,
+                missing_timestamp: true,
+                components: vec![NtiaFinding {
+                    component: "b".to_string(),
+                    missing: vec![
+                        NtiaElement::Supplier,
+                        NtiaElement::Version,
+                        NtiaElement::UniqueIdentifier,
+                        NtiaElement::DependencyRelationship,
+                    ],
+                }],
+            }
+        );
+        assert!(!ntia.is_compliant());
+    }
+
+    #[test]
+    fn it_should_render_the_report() {
+        let mut bom = healthy_bom();
+        bom.components.as_mut().unwrap().0[1].licenses = None;
+        let config = AnalysisConfig {
+            severities: false,
+            ..AnalysisConfig::default()
+        };
+
+        let report = Analyzer::run(&bom, config);
+
+        assert_eq!(
+            report.to_string(),
+            "1 of 2 components declare a license\nComponent b does not declare a license"
+        );
+
+        let translations = Translations::from_iter([(
+            "report.unlicensed",
+            "Komponente {0} deklariert keine Lizenz",
+        )]);
+        assert_eq!(
+            report.render(&translations),
+            "1 of 2 components declare a license\nKomponente b deklariert keine Lizenz"
+        );
+    }
+
+    #[test]
+    fn it_should_roll_up_severities() {
+        let mut bom = healthy_bom();
+        bom.vulnerabilities = Some(Vulnerabilities(vec![
+            rated(&[Severity::Medium, Severity::Critical], "a"),
+            rated(&[Severity::High], "a"),
+            rated(&[Severity::Info], "b"),
+            rated(&[], "b"),
+        ]));
+
+        let rollup = Analyzer::run(&bom, AnalysisConfig::default())
+            .severities
+            .unwrap();
+
+        assert_eq!(
+            rollup,
+            SeverityRollup {
+                critical: 1,
+                high: 1,
+                info: 1,
+                unknown: 1,
+                affected: BTreeMap::from([("a".to_string(), 2), ("b".to_string(), 2)]),
+                ..SeverityRollup::default()
+            }
+        );
+        assert_eq!(rollup.actionable(), 3);
+    }
+
+    #[test]
+    fn it_should_count_the_contents_of_a_bom() {
+        let mut bom = healthy_bom();
+        let components = &mut bom.components.as_mut().unwrap().0;
+        components[0].supplier = Some(OrganizationalEntity::new("Acme"));
+        components[0].components = Some(Components(vec![Component::new(
+            Classification::Firmware,
+            "blob",
+            "1.0.0",
+            None,
+        )]));
+        components[1].supplier = Some(OrganizationalEntity::new("Acme"));
+        bom.services = Some(Services(vec![Service {
+            services: Some(Services(vec![Service::new("nested", None)])),
+            ..Service::new("api", None)
+        }]));
+        bom.vulnerabilities = Some(Vulnerabilities(vec![rated(&[], "b")]));
+
+        let stats = bom.stats();
+
+        assert_eq!(
+            stats,
+            BomStats {
+                components: 3,
+                components_by_type: BTreeMap::from([
+                    ("firmware".to_string(), 1),
+                    ("library".to_string(), 2)
+                ]),
+                with_hashes: 0,
+                with_purls: 2,
+                with_licenses: 2,
+                suppliers: 1,
+                services: 2,
+                dependencies: 2,
+                direct_dependencies: 1,
+                max_dependency_depth: 2,
+                vulnerabilities: 1,
+            }
+        );
+        assert_eq!(
+            serde_json::to_value(&stats).unwrap()["components_by_type"]["library"],
+            2
+        );
+    }
+
+    #[test]
+    fn it_should_find_contradicting_compositions() {
+        let bom_refs =
+            |refs: &[&str]| Some(refs.iter().map(|r| BomReference(r.to_string())).collect());
+        let composition = |aggregate, assemblies: &[&str], dependencies: &[&str]| Composition {
+            assemblies: bom_refs(assemblies),
+            dependencies: bom_refs(dependencies),
+            ..Composition::new(aggregate)
+        };
+
+        let mut bom = healthy_bom();
+        bom.components.as_mut().unwrap().0.push(component("c", "c"));
+        bom.dependencies
+            .as_mut()
+            .unwrap()
+            .0
+            .push(dependency("b", &["gone"]));
+        bom.compositions = Some(Compositions(vec![
+            composition(AggregateType::Complete, &["a"], &["app", "b", "c"]),
+            composition(AggregateType::Unknown, &["missing"], &["a"]),
+            composition(AggregateType::Incomplete, &["a"], &[]),
+        ]));
+
+        let report = Analyzer::run(&bom, AnalysisConfig::default());
+
+        assert_eq!(
+            report.compositions,
+            Some(vec![
+                CompositionFinding::UnknownReference {
+                    bom_ref: "missing".to_string(),
+                },
+                CompositionFinding::UnknownDependency {
+                    bom_ref: "b".to_string(),
+                    dependency: "gone".to_string(),
+                },
+                CompositionFinding::MissingDependencies {
+                    bom_ref: "c".to_string(),
+                },
+                CompositionFinding::ConflictingAggregates {
+                    bom_ref: "a".to_string(),
+                    part: CompositionPart::Assemblies,
+                    aggregates: vec!["complete".to_string(), "incomplete".to_string()],
+                },
+            ])
+        );
+        assert!(report.has_findings());
+        assert!(report
+            .to_string()
+            .contains("Component b is claimed to have complete dependencies, but depends on gone, which is not part of the BOM"));
+    }
+}
@@ -0,0 +1,184 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Transparent decompression of the input of the parse functions.
+//!
+//! The compression format is detected from the magic bytes at the start of the input,
+//! so plain documents are read as before.
+
+use std::borrow::Cow;
+use std::io::{self, Chain, Cursor, Read};
+
+const GZIP_MAGIC: &[u8] = &[0x1f, 0x8b];
+const ZSTD_MAGIC: &[u8] = &[0x28, 0xb5, 0x2f, 0xfd];
+
+/// The bytes read to detect the format, followed by the rest of the input
+type Prefixed<R> = Chain<Cursor<Vec<u8>>, R>;
+
+/// A reader that decompresses its input if it is compressed with a supported format.
+///
+/// The format is only detected on the first read, so that I/O errors are reported
+/// by the parser like any other error reading the input.
+pub(crate) struct Decompressed<R: Read> {
+    state: State<R>,
+}
+
+enum State<R: Read> {
+    Unread(R),
+    Plain(Prefixed<R>),
+    #[cfg(feature = "gzip")]
+    Gzip(Box<flate2::read::MultiGzDecoder<Prefixed<R>>>),
+    #[cfg(feature = "zstd")]
+    Zstd(Box<zstd::stream::read::Decoder<'static, io::BufReader<Prefixed<R>>>>),
+    Failed,
+}
+
+impl<R: Read> Decompressed<R> {
+    pub(crate) fn new(reader: R) -> Self {
+        Self {
+            state: State::Unread(reader),
+        }
+    }
+
+    fn detect(mut reader: R) -> io::Result<State<R>> {
+        let mut magic = Vec::with_capacity(ZSTD_MAGIC.len());
+        (&mut reader)
+            .take(ZSTD_MAGIC.len() as u64)
+            .read_to_end(&mut magic)?;
+        let is_gzip = magic.starts_with(GZIP_MAGIC);
+        let is_zstd = magic.starts_with(ZSTD_MAGIC);
+        let reader = Cursor::new(magic).chain(reader);
+
+        if is_gzip {
+            #[cfg(feature = "gzip")]
+            return Ok(State::Gzip(Box::new(flate2::read::MultiGzDecoder::new(
+                reader,
+            ))));
+            #[cfg(not(feature = "gzip"))]
+            return Err(unsupported("gzip"));
+        }
+
+        if is_zstd {
+            #[cfg(feature = "zstd")]
+            return Ok(State::Zstd(Box::new(zstd::stream::read::Decoder::new(
+                reader,
+            )?)));
+            #[cfg(not(feature = "zstd"))]
+            return Err(unsupported("zstd"));
+        }
+
+        Ok(State::Plain(reader))
+    }
+}
+
+/// Decompresses the input if it is compressed, or borrows it otherwise
+pub(crate) fn decompress_slice(input: &[u8]) -> io::Result<Cow<'_, [u8]>> {
+    if input.starts_with(GZIP_MAGIC) || input.starts_with(ZSTD_MAGIC) {
+        let mut output = Vec::new();
+        Decompressed::new(input).read_to_end(&mut output)?;
+        Ok(Cow::Owned(output))
+    } else {
+        Ok(Cow::Borrowed(input))
+    }
+}
+
+#[cfg(not(all(feature = "gzip", feature = "zstd")))]
+fn unsupported(format: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("The input is {format} compressed, but the `{format}` feature of cyclonedx-bom is not enabled"),
+    )
+}
+
+impl<R: Read> Read for Decompressed<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if let State::Unread(_) = self.state {
+            let State::Unread(reader) = std::mem::replace(&mut self.state, State::Failed) else {
+                unreachable!()
+            };
+            self.state = Self::detect(reader)?;
+        }
+
+        match &mut self.state {
+            State::Plain(reader) => reader.read(buf),
+            #[cfg(feature = "gzip")]
+            State::Gzip(reader) => reader.read(buf),
+            #[cfg(feature = "zstd")]
+            State::Zstd(reader) => reader.read(buf),
+            State::Unread(_) | State::Failed => Ok(0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn read_to_string(input: &[u8]) -> io::Result<String> {
+        let mut output = String::new();
+        Decompressed::new(input).read_to_string(&mut output)?;
+        Ok(output)
+    }
+
+    #[test]
+    fn it_should_pass_plain_input_through() {
+        assert_eq!(read_to_string(b"{}").unwrap(), "{}");
+        assert_eq!(read_to_string(b"").unwrap(), "");
+        assert_eq!(read_to_string(b"<bom/>").unwrap(), "<bom/>");
+    }
+
+    #[test]
+    fn it_should_borrow_plain_slices() {
+        assert!(matches!(
+            decompress_slice(b"{}").unwrap(),
+            Cow::Borrowed(b"{}")
+        ));
+    }
+
+    #[cfg(feature = "gzip")]
+    #[test]
+    fn it_should_decompress_gzip() {
+        use std::io::Write;
+
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(b"{\"bomFormat\":\"CycloneDX\"}").unwrap();
+        let compressed = encoder.finish().unwrap();
+
+        assert_eq!(
+            read_to_string(&compressed).unwrap(),
+            "{\"bomFormat\":\"CycloneDX\"}"
+        );
+    }
+
+    #[cfg(feature = "zstd")]
+    #[test]
+    fn it_should_decompress_zstd() {
+        let compressed = zstd::encode_all(&b"<bom/>"[..], 0).unwrap();
+
+        assert_eq!(read_to_string(&compressed).unwrap(), "<bom/>");
+        assert_eq!(decompress_slice(&compressed).unwrap().as_ref(), b"<bom/>");
+    }
+
+    #[cfg(not(feature = "gzip"))]
+    #[test]
+    fn it_should_reject_gzip_without_the_feature() {
+        let error = read_to_string(&[0x1f, 0x8b, 0x08, 0x00]).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+    }
+}
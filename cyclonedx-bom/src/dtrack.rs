@@ -0,0 +1,812 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! A small client for the [Dependency-Track](https://dependencytrack.org/) API: uploading BOMs,
+//! polling the processing of uploads and fetching the findings of a project back as vulnerabilities.
+//!
+//! The client does not depend on an HTTP stack. Every operation is a [`Call`], which is a [`Request`]
+//! together with the parser of its [`Response`], so the request can be sent with any HTTP client,
+//! blocking or async, through [`Call::send_with`]. With the `dtrack-blocking` feature enabled,
+//! [`Call::send`] and the blocking methods of [`DependencyTrack`] send them with [ureq](https://crates.io/crates/ureq).
+//!
+//! ```
+//! use cyclonedx_bom::dtrack::{DependencyTrack, Project, Response};
+//!
+//! let client = DependencyTrack::new("https://dtrack.example.com", "api-key");
+//! let project = Project {
+//!     name: "app".to_string(),
+//!     version: Some("1.0.0".to_string()),
+//!     auto_create: true,
+//! };
+//! let call = client.upload(&project, br#"{"bomFormat": "CycloneDX"}"#);
+//! assert_eq!(call.request().url, "https://dtrack.example.com/api/v1/bom");
+//!
+//! // In an async service, send the request with the HTTP client of the service
+//! # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+//! let token = call
+//!     .send_with(|request| async move {
+//!         // e.g. with reqwest: client.request(...).headers(...).body(...).send().await
+//!         # let _ = request;
+//!         Ok::<_, std::io::Error>(Response {
+//!             status: 200,
+//!             body: br#"{"token": "4a0d2b4c-05f8-4d3f-a56c-7b8a7d4c6a1e"}"#.to_vec(),
+//!         })
+//!     })
+//!     .await
+//!     .unwrap();
+//! assert_eq!(token.token, "4a0d2b4c-05f8-4d3f-a56c-7b8a7d4c6a1e");
+//! # });
+//! ```
+
+use std::collections::HashSet;
+use std::convert::TryFrom;
+use std::future::Future;
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+
+use crate::errors::DtrackError;
+use crate::external_models::normalized_string::NormalizedString;
+use crate::external_models::uri::Uri;
+use crate::models::bom::Bom;
+use crate::models::component::{Component, Components};
+use crate::models::vulnerability::{Vulnerabilities, Vulnerability};
+use crate::models::vulnerability_analysis::{ImpactAnalysisState, VulnerabilityAnalysis};
+use crate::models::vulnerability_rating::{
+    Score, ScoreMethod, Severity, VulnerabilityRating, VulnerabilityRatings,
+};
+use crate::models::vulnerability_reference::{VulnerabilityReference, VulnerabilityReferences};
+use crate::models::vulnerability_source::VulnerabilitySource;
+use crate::models::vulnerability_target::{VulnerabilityTarget, VulnerabilityTargets};
+
+/// A Dependency-Track server, authenticated with an API key
+#[derive(Debug, Clone)]
+pub struct DependencyTrack {
+    /// The base URL of the API server, e.g. `https://dtrack.example.com`
+    url: String,
+    api_key: String,
+    #[cfg(feature = "dtrack-blocking")]
+    agent: ureq::Agent,
+}
+
+/// The project a BOM is uploaded to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Project {
+    pub name: String,
+    pub version: Option<String>,
+    /// Create the project if it does not exist yet.
+    /// This requires the `PROJECT_CREATION_UPLOAD` permission.
+    pub auto_create: bool,
+}
+
+/// The token identifying the processing of an uploaded BOM.
+/// Dependency-Track processes uploads asynchronously, so a successful upload
+/// only means that the BOM was accepted.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct UploadToken {
+    pub token: String,
+}
+
+/// A project as stored by Dependency-Track, of which only the identifying fields are read
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct ProjectInfo {
+    pub uuid: String,
+    pub name: String,
+    pub version: Option<String>,
+}
+
+/// A vulnerability Dependency-Track found in a component of a project
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Finding {
+    pub component: FindingComponent,
+    pub vulnerability: FindingVulnerability,
+    pub analysis: Option<FindingAnalysis>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct FindingComponent {
+    pub uuid: String,
+    pub name: String,
+    pub group: Option<String>,
+    pub version: Option<String>,
+    pub purl: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FindingVulnerability {
+    /// The ID of the vulnerability in its source, e.g. `CVE-2023-1234` or `GHSA-xxxx-xxxx-xxxx`
+    pub vuln_id: String,
+    /// The database the vulnerability comes from, e.g. `NVD`, `GITHUB` or `OSV`
+    pub source: String,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub recommendation: Option<String>,
+    /// `CRITICAL`, `HIGH`, `MEDIUM`, `LOW`, `INFO` or `UNASSIGNED`
+    pub severity: Option<String>,
+    #[serde(rename = "cvssV2BaseScore")]
+    pub cvss_v2_base_score: Option<f32>,
+    #[serde(rename = "cvssV2Vector")]
+    pub cvss_v2_vector: Option<String>,
+    #[serde(rename = "cvssV3BaseScore")]
+    pub cvss_v3_base_score: Option<f32>,
+    #[serde(rename = "cvssV3Vector")]
+    pub cvss_v3_vector: Option<String>,
+    #[serde(default)]
+    pub cwes: Vec<FindingCwe>,
+    /// The IDs of the same vulnerability in other sources, by the kind of ID, e.g. `cveId` or `ghsaId`
+    #[serde(default)]
+    pub aliases: Vec<std::collections::BTreeMap<String, String>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FindingCwe {
+    pub cwe_id: u32,
+}
+
+/// The audit of a finding
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FindingAnalysis {
+    /// `NOT_SET`, `EXPLOITABLE`, `IN_TRIAGE`, `FALSE_POSITIVE`, `NOT_AFFECTED` or `RESOLVED`
+    pub state: Option<String>,
+    #[serde(default)]
+    pub is_suppressed: bool,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct BomSubmitRequest<'a> {
+    project_name: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    project_version: Option<&'a str>,
+    auto_create: bool,
+    /// The BOM, base64 encoded
+    bom: String,
+}
+
+impl<'a> BomSubmitRequest<'a> {
+    fn new(project: &'a Project, bom: &[u8]) -> Self {
+        Self {
+            project_name: &project.name,
+            project_version: project.version.as_deref(),
+            auto_create: project.auto_create,
+            bom: STANDARD.encode(bom),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct ProcessingStatus {
+    processing: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Method {
+    Get,
+    Put,
+}
+
+impl Method {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Method::Get => "GET",
+            Method::Put => "PUT",
+        }
+    }
+}
+
+/// An HTTP request to the API
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Request {
+    pub method: Method,
+    pub url: String,
+    /// The headers, including the API key and the content type of the body
+    pub headers: Vec<(&'static str, String)>,
+    pub body: Option<Vec<u8>>,
+}
+
+/// The HTTP response to a [`Request`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: Vec<u8>,
+}
+
+/// A request to the API and the parser of its response
+#[derive(Debug)]
+pub struct Call<T> {
+    request: Request,
+    parse: fn(&[u8]) -> Result<T, serde_json::Error>,
+}
+
+impl<T> Call<T> {
+    pub fn request(&self) -> &Request {
+        &self.request
+    }
+
+    /// Reads the result of the call from the response to its request
+    pub fn parse_response(&self, response: Response) -> Result<T, DtrackError> {
+        if !(200..300).contains(&response.status) {
+            return Err(DtrackError::Rejected {
+                url: self.request.url.clone(),
+                status: response.status,
+                message: String::from_utf8_lossy(&response.body).into_owned(),
+            });
+        }
+        (self.parse)(&response.body).map_err(|source| DtrackError::InvalidResponse {
+            url: self.request.url.clone(),
+            source,
+        })
+    }
+
+    /// Sends the request with `execute`, e.g. the async HTTP client of a service, and reads the result
+    pub async fn send_with<F, Fut, E>(self, execute: F) -> Result<T, DtrackError>
+    where
+        F: FnOnce(Request) -> Fut,
+        Fut: Future<Output = Result<Response, E>>,
+        E: std::error::Error + Send + Sync + 'static,
+    {
+        let url = self.request.url.clone();
+        let response =
+            execute(self.request.clone())
+                .await
+                .map_err(|source| DtrackError::RequestFailed {
+                    url,
+                    source: Box::new(source),
+                })?;
+        self.parse_response(response)
+    }
+
+    /// Sends the request with a blocking ureq agent and reads the result
+    #[cfg(feature = "dtrack-blocking")]
+    pub fn send(self, agent: &ureq::Agent) -> Result<T, DtrackError> {
+        let url = &self.request.url;
+        let mut request = agent.request(self.request.method.as_str(), url);
+        for (name, value) in &self.request.headers {
+            request = request.set(name, value);
+        }
+        let result = match &self.request.body {
+            Some(body) => request.send_bytes(body),
+            None => request.call(),
+        };
+
+        let response = match result {
+            Ok(response) | Err(ureq::Error::Status(_, response)) => response,
+            Err(ureq::Error::Transport(transport)) => {
+                return Err(DtrackError::RequestFailed {
+                    url: url.clone(),
+                    source: Box::new(transport),
+                })
+            }
+        };
+        let status = response.status();
+        let mut body = Vec::new();
+        std::io::Read::read_to_end(&mut response.into_reader(), &mut body).map_err(|source| {
+            DtrackError::RequestFailed {
+                url: url.clone(),
+                source: Box::new(source),
+            }
+        })?;
+        self.parse_response(Response { status, body })
+    }
+}
+
+impl DependencyTrack {
+    pub fn new(url: &str, api_key: &str) -> Self {
+        Self {
+            url: url.trim_end_matches('/').to_string(),
+            api_key: api_key.to_string(),
+            #[cfg(feature = "dtrack-blocking")]
+            agent: ureq::AgentBuilder::new()
+                .user_agent(concat!("cyclonedx-bom/", env!("CARGO_PKG_VERSION")))
+                .build(),
+        }
+    }
+
+    /// Uploads a BOM, in either JSON or XML format, to the given project
+    pub fn upload(&self, project: &Project, bom: &[u8]) -> Call<UploadToken> {
+        let body = serde_json::to_vec(&BomSubmitRequest::new(project, bom))
+            .expect("the request is valid JSON");
+        Call {
+            request: self.request(Method::Put, "api/v1/bom", Some(body)),
+            parse: |body| serde_json::from_slice(body),
+        }
+    }
+
+    /// Whether Dependency-Track is still processing the upload with the token
+    pub fn is_processing(&self, token: &UploadToken) -> Call<bool> {
+        Call {
+            request: self.request(
+                Method::Get,
+                &format!("api/v1/event/token/{}", encode(&token.token)),
+                None,
+            ),
+            parse: |body| Ok(serde_json::from_slice::<ProcessingStatus>(body)?.processing),
+        }
+    }
+
+    /// Looks up a project by its name and version
+    pub fn project(&self, name: &str, version: Option<&str>) -> Call<ProjectInfo> {
+        let mut path = format!("api/v1/project/lookup?name={}", encode(name));
+        if let Some(version) = version {
+            path.push_str(&format!("&version={}", encode(version)));
+        }
+        Call {
+            request: self.request(Method::Get, &path, None),
+            parse: |body| serde_json::from_slice(body),
+        }
+    }
+
+    /// The findings of the project with the UUID, including suppressed ones
+    pub fn findings(&self, project_uuid: &str) -> Call<Vec<Finding>> {
+        Call {
+            request: self.request(
+                Method::Get,
+                &format!(
+                    "api/v1/finding/project/{}?suppressed=true",
+                    encode(project_uuid)
+                ),
+                None,
+            ),
+            parse: |body| serde_json::from_slice(body),
+        }
+    }
+
+    /// Uploads a BOM, in either JSON or XML format, to the given project
+    #[cfg(feature = "dtrack-blocking")]
+    pub fn upload_bom(&self, project: &Project, bom: &[u8]) -> Result<UploadToken, DtrackError> {
+        self.upload(project, bom).send(&self.agent)
+    }
+
+    /// Polls the processing of an upload every `interval` until it is done, or fails after `timeout`
+    #[cfg(feature = "dtrack-blocking")]
+    pub fn wait_for_processing(
+        &self,
+        token: &UploadToken,
+        interval: std::time::Duration,
+        timeout: std::time::Duration,
+    ) -> Result<(), DtrackError> {
+        let start = std::time::Instant::now();
+        while self.is_processing(token).send(&self.agent)? {
+            if start.elapsed() >= timeout {
+                return Err(DtrackError::Timeout {
+                    token: token.token.clone(),
+                });
+            }
+            std::thread::sleep(interval);
+        }
+        Ok(())
+    }
+
+    /// The findings of a project by its name and version
+    #[cfg(feature = "dtrack-blocking")]
+    pub fn project_findings(&self, project: &Project) -> Result<Vec<Finding>, DtrackError> {
+        let info = self
+            .project(&project.name, project.version.as_deref())
+            .send(&self.agent)?;
+        self.findings(&info.uuid).send(&self.agent)
+    }
+
+    fn request(&self, method: Method, path: &str, body: Option<Vec<u8>>) -> Request {
+        let mut headers = vec![
+            ("X-Api-Key", self.api_key.clone()),
+            ("Accept", "application/json".to_string()),
+        ];
+        if body.is_some() {
+            headers.push(("Content-Type", "application/json".to_string()));
+        }
+        Request {
+            method,
+            url: format!("{}/{}", self.url, path),
+            headers,
+            body,
+        }
+    }
+}
+
+impl Finding {
+    /// Converts the finding into a vulnerability without targets
+    fn to_vulnerability(&self) -> Vulnerability {
+        let found = &self.vulnerability;
+        let mut vulnerability = Vulnerability::new(None);
+        vulnerability.id = Some(NormalizedString::new(&found.vuln_id));
+        vulnerability.vulnerability_source = Some(source(&found.source, &found.vuln_id));
+        vulnerability.description = found.title.clone().or_else(|| found.description.clone());
+        vulnerability.detail = found.title.as_ref().and_then(|_| found.description.clone());
+        vulnerability.recommendation = found.recommendation.clone();
+
+        let severity = found.severity.as_deref().map(|severity| match severity {
+            "UNASSIGNED" => Severity::Unknown,
+            severity => Severity::new_unchecked(severity.to_lowercase()),
+        });
+        let mut ratings = Vec::new();
+        if found.cvss_v3_base_score.is_some() || found.cvss_v3_vector.is_some() {
+            let method = match &found.cvss_v3_vector {
+                Some(vector) if vector.starts_with("CVSS:3.0/") => ScoreMethod::CVSSv3,
+                _ => ScoreMethod::CVSSv31,
+            };
+            ratings.push(rating(
+                found.cvss_v3_base_score,
+                &found.cvss_v3_vector,
+                severity.clone(),
+                method,
+            ));
+        }
+        if found.cvss_v2_base_score.is_some() || found.cvss_v2_vector.is_some() {
+            let severity = severity.clone().filter(|_| ratings.is_empty());
+            ratings.push(rating(
+                found.cvss_v2_base_score,
+                &found.cvss_v2_vector,
+                severity,
+                ScoreMethod::CVSSv2,
+            ));
+        }
+        if ratings.is_empty() {
+            if let Some(severity) = severity {
+                ratings.push(VulnerabilityRating::new(None, Some(severity), None));
+            }
+        }
+        if !ratings.is_empty() {
+            vulnerability.vulnerability_ratings = Some(VulnerabilityRatings(ratings));
+        }
+
+        if !found.cwes.is_empty() {
+            vulnerability.cwes = Some(found.cwes.iter().map(|cwe| cwe.cwe_id).collect());
+        }
+
+        let mut references: Vec<VulnerabilityReference> = Vec::new();
+        for alias in found.aliases.iter().flat_map(|alias| alias.values()) {
+            let is_known = alias == &found.vuln_id
+                || references
+                    .iter()
+                    .any(|reference| reference.id.to_string() == *alias);
+            if !is_known {
+                references.push(VulnerabilityReference::new(alias, alias_source(alias)));
+            }
+        }
+        if !references.is_empty() {
+            vulnerability.vulnerability_references = Some(VulnerabilityReferences(references));
+        }
+
+        let state = self
+            .analysis
+            .as_ref()
+            .and_then(|analysis| analysis.state.as_deref())
+            .and_then(|state| match state {
+                "NOT_SET" => None,
+                state => Some(ImpactAnalysisState::new_unchecked(state.to_lowercase())),
+            });
+        if state.is_some() {
+            vulnerability.vulnerability_analysis =
+                Some(VulnerabilityAnalysis::new(state, None, None));
+        }
+
+        vulnerability
+    }
+}
+
+/// Adds a vulnerability for every vulnerability of the findings that affects components of `bom`,
+/// unless the BOM already has a vulnerability with its ID. Components are matched by their purl,
+/// or by their name and version if the finding has no purl.
+/// Returns the number of added vulnerabilities.
+pub fn add_findings(bom: &mut Bom, findings: &[Finding]) -> usize {
+    let mut components = Vec::new();
+    if let Some(component) = bom.metadata.as_ref().and_then(|m| m.component.as_ref()) {
+        components.push(component);
+    }
+    if let Some(bom_components) = &bom.components {
+        collect_components(bom_components, &mut components);
+    }
+
+    let known: HashSet<String> = bom
+        .vulnerabilities
+        .iter()
+        .flat_map(|vulnerabilities| vulnerabilities.0.iter())
+        .filter_map(|vulnerability| vulnerability.id.as_ref().map(|id| id.to_string()))
+        .collect();
+
+    let mut added: Vec<Vulnerability> = Vec::new();
+    for finding in findings {
+        let id = &finding.vulnerability.vuln_id;
+        if known.contains(id) {
+            continue;
+        }
+        let targets: Vec<VulnerabilityTarget> = components
+            .iter()
+            .filter(|component| affects(finding, component))
+            .filter_map(|component| component.bom_ref.clone())
+            .map(VulnerabilityTarget::new)
+            .collect();
+        if targets.is_empty() {
+            continue;
+        }
+
+        let existing = added.iter_mut().find(|vulnerability| {
+            vulnerability.id.as_ref().map(ToString::to_string).as_ref() == Some(id)
+        });
+        let vulnerability = match existing {
+            Some(vulnerability) => vulnerability,
+            None => {
+                added.push(finding.to_vulnerability());
+                added.last_mut().expect("just added")
+            }
+        };
+        let existing_targets = &mut vulnerability
+            .vulnerability_targets
+            .get_or_insert_with(|| VulnerabilityTargets(Vec::new()))
+            .0;
+        for target in targets {
+            if !existing_targets.contains(&target) {
+                existing_targets.push(target);
+            }
+        }
+    }
+
+    let count = added.len();
+    if count > 0 {
+        bom.vulnerabilities
+            .get_or_insert_with(|| Vulnerabilities(Vec::new()))
+            .0
+            .extend(added);
+    }
+    count
+}
+
+fn affects(finding: &Finding, component: &Component) -> bool {
+    let found = &finding.component;
+    match (&found.purl, &component.purl) {
+        (Some(found), Some(purl)) => found == &purl.0,
+        _ => {
+            found.name == component.name.to_string()
+                && found.version.as_deref()
+                    == component.version.as_ref().map(|v| v.to_string()).as_deref()
+        }
+    }
+}
+
+fn collect_components<'a>(components: &'a Components, out: &mut Vec<&'a Component>) {
+    for component in &components.0 {
+        out.push(component);
+        if let Some(nested) = &component.components {
+            collect_components(nested, out);
+        }
+    }
+}
+
+fn rating(
+    score: Option<f32>,
+    vector: &Option<String>,
+    severity: Option<Severity>,
+    method: ScoreMethod,
+) -> VulnerabilityRating {
+    let mut rating =
+        VulnerabilityRating::new(score.and_then(Score::from_f32), severity, Some(method));
+    rating.vector = vector.as_deref().map(NormalizedString::new);
+    rating
+}
+
+/// The source of a vulnerability, with a link to it for the well-known databases
+fn source(name: &str, id: &str) -> VulnerabilitySource {
+    let url = match name {
+        "NVD" => Some(format!("https://nvd.nist.gov/vuln/detail/{}", id)),
+        "GITHUB" => Some(format!("https://github.com/advisories/{}", id)),
+        "OSV" => Some(format!("https://osv.dev/vulnerability/{}", id)),
+        _ => None,
+    };
+    VulnerabilitySource::new(
+        Some(name.to_string()),
+        url.and_then(|url| Uri::try_from(url).ok()),
+    )
+}
+
+fn alias_source(id: &str) -> VulnerabilitySource {
+    if id.starts_with("CVE-") {
+        source("NVD", id)
+    } else if id.starts_with("GHSA-") {
+        source("GITHUB", id)
+    } else {
+        source("OSV", id)
+    }
+}
+
+/// Percent-encodes a path segment or query value
+fn encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (byte as char).to_string()
+            }
+            byte => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::external_models::uri::Purl;
+    use crate::models::component::Classification;
+
+    fn block_on<F: Future>(future: F) -> F::Output {
+        tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap()
+            .block_on(future)
+    }
+
+    #[test]
+    fn it_should_encode_the_bom_submit_request() {
+        let project = Project {
+            name: "app".to_string(),
+            version: Some("1.0.0".to_string()),
+            auto_create: true,
+        };
+        let request = BomSubmitRequest::new(&project, b"{}");
+        assert_eq!(
+            serde_json::to_value(request).unwrap(),
+            serde_json::json!({
+                "projectName": "app",
+                "projectVersion": "1.0.0",
+                "autoCreate": true,
+                "bom": "e30="
+            })
+        );
+
+        let project = Project {
+            version: None,
+            auto_create: false,
+            ..project
+        };
+        let request = BomSubmitRequest::new(&project, b"{}");
+        assert!(serde_json::to_value(request)
+            .unwrap()
+            .get("projectVersion")
+            .is_none());
+    }
+
+    #[test]
+    fn it_should_build_requests() {
+        let client = DependencyTrack::new("https://dtrack.example.com/", "key");
+
+        let call = client.project("my app", Some("1.0+build"));
+        assert_eq!(call.request().method, Method::Get);
+        assert_eq!(
+            call.request().url,
+            "https://dtrack.example.com/api/v1/project/lookup?name=my%20app&version=1.0%2Bbuild"
+        );
+        assert!(call
+            .request()
+            .headers
+            .contains(&("X-Api-Key", "key".to_string())));
+
+        let token = UploadToken {
+            token: "abc".to_string(),
+        };
+        let call = client.is_processing(&token);
+        assert_eq!(
+            call.request().url,
+            "https://dtrack.example.com/api/v1/event/token/abc"
+        );
+        let processing = block_on(call.send_with(|_| async {
+            Ok::<_, std::io::Error>(Response {
+                status: 200,
+                body: br#"{"processing": false}"#.to_vec(),
+            })
+        }));
+        assert!(!processing.unwrap());
+    }
+
+    #[test]
+    fn it_should_report_rejected_requests() {
+        let client = DependencyTrack::new("https://dtrack.example.com", "key");
+        let call = client.findings("uuid");
+        let result = call.parse_response(Response {
+            status: 403,
+            body: b"Forbidden".to_vec(),
+        });
+        match result {
+            Err(DtrackError::Rejected {
+                status, message, ..
+            }) => {
+                assert_eq!(status, 403);
+                assert_eq!(message, "Forbidden");
+            }
+            result => panic!("Unexpected result {result:?}"),
+        }
+
+        let result = block_on(call.send_with(|_| async {
+            Err::<Response, _>(std::io::Error::from(std::io::ErrorKind::ConnectionRefused))
+        }));
+        assert!(matches!(result, Err(DtrackError::RequestFailed { .. })));
+    }
+
+    #[test]
+    fn it_should_add_findings_as_vulnerabilities() {
+        let findings: Vec<Finding> = serde_json::from_value(serde_json::json!([
+            {
+                "component": {
+                    "uuid": "c1", "name": "openssl", "version": "0.10.55",
+                    "purl": "pkg:cargo/openssl@0.10.55"
+                },
+                "vulnerability": {
+                    "uuid": "v1", "vulnId": "GHSA-xcf7-rvmh-g6q4", "source": "GITHUB",
+                    "title": "openssl `X509VerifyParamRef::set_host` buffer over-read",
+                    "severity": "HIGH", "severityRank": 1,
+                    "cvssV3BaseScore": 7.5,
+                    "cvssV3Vector": "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:N/A:N",
+                    "cwes": [{"cweId": 125, "name": "Out-of-bounds Read"}],
+                    "aliases": [{"cveId": "CVE-2023-1234", "ghsaId": "GHSA-xcf7-rvmh-g6q4"}]
+                },
+                "analysis": {"state": "NOT_AFFECTED", "isSuppressed": true},
+                "attribution": {"analyzerIdentity": "INTERNAL_ANALYZER"}
+            },
+            {
+                "component": {"uuid": "c2", "name": "other", "version": "1.0.0"},
+                "vulnerability": {"uuid": "v2", "vulnId": "CVE-2020-0001", "source": "NVD"}
+            }
+        ]))
+        .unwrap();
+
+        let mut component = Component::new(
+            Classification::Library,
+            "openssl",
+            "0.10.55",
+            Some("openssl-ref".to_string()),
+        );
+        component.purl = Some(Purl::new("cargo", "openssl", "0.10.55").unwrap());
+        let mut bom = Bom {
+            components: Some(Components(vec![component])),
+            ..Bom::default()
+        };
+
+        assert_eq!(add_findings(&mut bom, &findings), 1);
+        assert_eq!(add_findings(&mut bom, &findings), 0);
+
+        let vulnerability = &bom.vulnerabilities.unwrap().0[0];
+        assert_eq!(
+            vulnerability.id,
+            Some(NormalizedString::new("GHSA-xcf7-rvmh-g6q4"))
+        );
+        assert_eq!(
+            vulnerability.vulnerability_targets,
+            Some(VulnerabilityTargets(vec![VulnerabilityTarget::new(
+                "openssl-ref".to_string()
+            )]))
+        );
+        assert_eq!(vulnerability.cwes, Some(vec![125]));
+        let rating = &vulnerability.vulnerability_ratings.as_ref().unwrap().0[0];
+        assert_eq!(rating.severity, Some(Severity::High));
+        assert_eq!(rating.score_method, Some(ScoreMethod::CVSSv31));
+        assert_eq!(rating.score, Score::from_f32(7.5));
+        assert_eq!(
+            vulnerability.vulnerability_references,
+            Some(VulnerabilityReferences(vec![VulnerabilityReference::new(
+                "CVE-2023-1234",
+                source("NVD", "CVE-2023-1234")
+            )]))
+        );
+        assert_eq!(
+            vulnerability.vulnerability_analysis.as_ref().unwrap().state,
+            Some(ImpactAnalysisState::NotAffected)
+        );
+    }
+}
@@ -21,6 +21,7 @@ use crate::models::bom::SpecVersion;
 #[derive(Debug, thiserror::Error)]
 #[non_exhaustive]
 pub enum BomError {
+    #[cfg(feature = "json")]
     #[error("Failed to serialize BOM to JSON: {0}")]
     JsonSerializationError(#[from] serde_json::Error),
 
@@ -34,6 +35,7 @@ pub enum BomError {
     UnsupportedSpecVersion(String),
 }
 
+#[cfg(feature = "json")]
 #[derive(Debug, thiserror::Error)]
 #[non_exhaustive]
 pub enum JsonWriteError {
@@ -49,6 +51,23 @@ pub enum JsonWriteError {
     },
 }
 
+#[cfg(feature = "yaml")]
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum YamlWriteError {
+    #[error("Failed to serialize YAML: {error}")]
+    YamlElementWriteError {
+        #[from]
+        error: serde_yaml::Error,
+    },
+    #[error("Failed to convert Bom: {error}")]
+    BomError {
+        #[from]
+        error: BomError,
+    },
+}
+
+#[cfg(feature = "xml")]
 #[derive(Debug, thiserror::Error)]
 #[non_exhaustive]
 pub enum XmlWriteError {
@@ -65,6 +84,31 @@ pub enum XmlWriteError {
     },
 }
 
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum BomWriteError {
+    #[cfg(feature = "json")]
+    #[error("Failed to write JSON: {error}")]
+    JsonWriteError {
+        #[from]
+        error: JsonWriteError,
+    },
+    #[cfg(feature = "xml")]
+    #[error("Failed to write XML: {error}")]
+    XmlWriteError {
+        #[from]
+        error: XmlWriteError,
+    },
+    #[error("Failed to convert Bom: {error}")]
+    BomError {
+        #[from]
+        error: BomError,
+    },
+    #[error("Components must be written before dependencies")]
+    ComponentAfterDependencies,
+}
+
+#[cfg(feature = "json")]
 #[derive(Debug, thiserror::Error)]
 #[non_exhaustive]
 pub enum JsonReadError {
@@ -78,8 +122,32 @@ pub enum JsonReadError {
         #[from]
         error: BomError,
     },
+    #[error("Input exceeds the parse limits: {error}")]
+    LimitExceeded {
+        #[from]
+        error: LimitError,
+    },
+    #[error("Unknown field {field} for spec version {spec_version}")]
+    UnknownField { field: String, spec_version: String },
+}
+
+#[cfg(feature = "yaml")]
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum YamlReadError {
+    #[error("Failed to deserialize YAML: {error}")]
+    YamlElementReadError {
+        #[from]
+        error: serde_yaml::Error,
+    },
+    #[error("Invalid input format found: {error}")]
+    BomError {
+        #[from]
+        error: BomError,
+    },
 }
 
+#[cfg(feature = "xml")]
 #[derive(Debug, thiserror::Error)]
 #[non_exhaustive]
 pub enum XmlReadError {
@@ -115,4 +183,122 @@ pub enum XmlReadError {
         expected_namespace: String,
         actual_namespace: Option<String>,
     },
+
+    #[error("Input exceeds the parse limits: {error}")]
+    LimitExceeded {
+        #[from]
+        error: LimitError,
+    },
+}
+
+/// A limit of [`ParseLimits`](crate::limits::ParseLimits) that was exceeded by the input
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+#[non_exhaustive]
+pub enum LimitError {
+    #[error("Document is larger than {limit} bytes")]
+    MaxSizeExceeded { limit: usize },
+
+    #[error("Document is nested deeper than {limit} levels")]
+    MaxDepthExceeded { limit: usize },
+
+    #[error("Document has more than {limit} elements")]
+    MaxElementsExceeded { limit: usize },
+
+    #[error("Attachment of {size} bytes is larger than {limit} bytes")]
+    MaxAttachmentSizeExceeded { size: usize, limit: usize },
+}
+
+/// The reasons why [`rewrite_component`](crate::rewrite::rewrite_component) did not change the BOM
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+#[non_exhaustive]
+pub enum RewriteError {
+    #[error("No component with the bom-ref {bom_ref}")]
+    ComponentNotFound { bom_ref: String },
+
+    #[error("Failed to update the purl {purl}: {error}")]
+    InvalidPurl { purl: String, error: String },
+
+    #[error("The new bom-ref {bom_ref} is already used")]
+    DuplicateBomRef { bom_ref: String },
+}
+
+/// The reasons why a [`Selector`](crate::query::Selector) could not be parsed
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+#[non_exhaustive]
+pub enum QueryError {
+    #[error("Unknown collection {0}, expected components, services or vulnerabilities")]
+    UnknownCollection(String),
+
+    #[error("Unknown field {0}")]
+    UnknownField(String),
+
+    #[error("Unexpected {found} at position {position}, expected {expected}")]
+    UnexpectedToken {
+        position: usize,
+        found: String,
+        expected: String,
+    },
+
+    #[error("Unexpected end of the selector, expected {expected}")]
+    UnexpectedEnd { expected: String },
+
+    #[error("The value starting at position {position} is missing its closing quote")]
+    UnterminatedLiteral { position: usize },
+}
+
+/// The reasons why the signatures of a document could not be verified,
+/// see [`verification`](crate::verification)
+#[cfg(feature = "verify")]
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+#[non_exhaustive]
+pub enum SignatureError {
+    #[error("The document is not signed")]
+    Unsigned,
+
+    #[error("None of the signatures uses an algorithm of the key")]
+    NoMatchingSignature,
+
+    #[error("The signature at '{location}' does not match the signed content")]
+    InvalidSignature { location: String },
+
+    #[error("Malformed signature at '{location}': {reason}")]
+    MalformedSignature { location: String, reason: String },
+
+    #[error("Unsupported signature algorithm {algorithm}")]
+    UnsupportedAlgorithm { algorithm: String },
+
+    #[error("Failed to read the signed document: {0}")]
+    InvalidDocument(String),
+
+    #[error("Invalid public key: {0}")]
+    InvalidKey(String),
+}
+
+/// The reasons why a call to the Dependency-Track API failed
+#[cfg(feature = "dtrack")]
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum DtrackError {
+    #[error("Failed to send the request to {url}")]
+    RequestFailed {
+        url: String,
+        source: Box<dyn std::error::Error + Send + Sync>,
+    },
+
+    #[error("Dependency-Track rejected the request to {url} with status {status}: {message}")]
+    Rejected {
+        url: String,
+        status: u16,
+        message: String,
+    },
+
+    #[error("Failed to read the response of Dependency-Track to {url}")]
+    InvalidResponse {
+        url: String,
+        #[source]
+        source: serde_json::Error,
+    },
+
+    #[error("Dependency-Track did not finish processing the upload {token} in time")]
+    Timeout { token: String },
 }
@@ -0,0 +1,411 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use std::str::FromStr;
+
+use thiserror::Error;
+
+/// A version of the [Common Vulnerability Scoring System](https://www.first.org/cvss/)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CvssVersion {
+    V2,
+    V3_0,
+    V3_1,
+}
+
+impl std::fmt::Display for CvssVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            CvssVersion::V2 => "2.0",
+            CvssVersion::V3_0 => "3.0",
+            CvssVersion::V3_1 => "3.1",
+        })
+    }
+}
+
+/// A metric of a CVSS vector, its possible values and whether the vector requires it
+struct Metric {
+    name: &'static str,
+    values: &'static [&'static str],
+    required: bool,
+}
+
+const fn base(name: &'static str, values: &'static [&'static str]) -> Metric {
+    Metric {
+        name,
+        values,
+        required: true,
+    }
+}
+
+const fn optional(name: &'static str, values: &'static [&'static str]) -> Metric {
+    Metric {
+        name,
+        values,
+        required: false,
+    }
+}
+
+/// The metrics of [CVSS v2](https://www.first.org/cvss/v2/guide#2-4-Base-Temporal-Environmental-Vectors)
+const V2_METRICS: &[Metric] = &[
+    base("AV", &["L", "A", "N"]),
+    base("AC", &["H", "M", "L"]),
+    base("Au", &["M", "S", "N"]),
+    base("C", &["N", "P", "C"]),
+    base("I", &["N", "P", "C"]),
+    base("A", &["N", "P", "C"]),
+    optional("E", &["U", "POC", "F", "H", "ND"]),
+    optional("RL", &["OF", "TF", "W", "U", "ND"]),
+    optional("RC", &["UC", "UR", "C", "ND"]),
+    optional("CDP", &["N", "L", "LM", "MH", "H", "ND"]),
+    optional("TD", &["N", "L", "M", "H", "ND"]),
+    optional("CR", &["L", "M", "H", "ND"]),
+    optional("IR", &["L", "M", "H", "ND"]),
+    optional("AR", &["L", "M", "H", "ND"]),
+];
+
+/// The metrics of [CVSS v3.x](https://www.first.org/cvss/v3.1/specification-document#6-Vector-String)
+const V3_METRICS: &[Metric] = &[
+    base("AV", &["N", "A", "L", "P"]),
+    base("AC", &["L", "H"]),
+    base("PR", &["N", "L", "H"]),
+    base("UI", &["N", "R"]),
+    base("S", &["U", "C"]),
+    base("C", &["H", "L", "N"]),
+    base("I", &["H", "L", "N"]),
+    base("A", &["H", "L", "N"]),
+    optional("E", &["X", "U", "P", "F", "H"]),
+    optional("RL", &["X", "O", "T", "W", "U"]),
+    optional("RC", &["X", "U", "R", "C"]),
+    optional("CR", &["X", "L", "M", "H"]),
+    optional("IR", &["X", "L", "M", "H"]),
+    optional("AR", &["X", "L", "M", "H"]),
+    optional("MAV", &["X", "N", "A", "L", "P"]),
+    optional("MAC", &["X", "L", "H"]),
+    optional("MPR", &["X", "N", "L", "H"]),
+    optional("MUI", &["X", "N", "R"]),
+    optional("MS", &["X", "U", "C"]),
+    optional("MC", &["X", "N", "L", "H"]),
+    optional("MI", &["X", "N", "L", "H"]),
+    optional("MA", &["X", "N", "L", "H"]),
+];
+
+/// A CVSS v2 or v3.x vector string such as `CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H`
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CvssVector {
+    version: CvssVersion,
+    metrics: Vec<(String, String)>,
+}
+
+impl CvssVector {
+    /// Parses a vector string, failing if it does not conform to its version of CVSS
+    ///
+    /// CVSS v2 vectors have no prefix and may be enclosed in parentheses.
+    /// ```
+    /// use cyclonedx_bom::external_models::cvss::{CvssVector, CvssVersion};
+    ///
+    /// let vector = CvssVector::parse("(AV:N/AC:L/Au:N/C:P/I:P/A:P)").unwrap();
+    /// assert_eq!(vector.version(), CvssVersion::V2);
+    /// assert_eq!(vector.base_score(), 7.5);
+    /// assert!(CvssVector::parse("CVSS:3.1/AV:N").is_err());
+    /// ```
+    pub fn parse(value: &str) -> Result<Self, CvssError> {
+        let value = value.trim();
+        let (version, metrics) = match value.strip_prefix("CVSS:") {
+            Some(rest) => {
+                let (version, metrics) = rest.split_once('/').unwrap_or((rest, ""));
+                let version = match version {
+                    "3.0" => CvssVersion::V3_0,
+                    "3.1" => CvssVersion::V3_1,
+                    other => return Err(CvssError::UnsupportedVersion(other.to_string())),
+                };
+                (version, metrics)
+            }
+            None => {
+                let metrics = value
+                    .strip_prefix('(')
+                    .and_then(|value| value.strip_suffix(')'))
+                    .unwrap_or(value);
+                (CvssVersion::V2, metrics)
+            }
+        };
+
+        let definitions = match version {
+            CvssVersion::V2 => V2_METRICS,
+            CvssVersion::V3_0 | CvssVersion::V3_1 => V3_METRICS,
+        };
+
+        let mut parsed: Vec<(String, String)> = Vec::new();
+        for metric in metrics.split('/') {
+            let (name, metric_value) = metric
+                .split_once(':')
+                .ok_or_else(|| CvssError::MalformedMetric(metric.to_string()))?;
+            let definition = definitions
+                .iter()
+                .find(|definition| definition.name == name)
+                .ok_or_else(|| CvssError::UnknownMetric(name.to_string()))?;
+            if !definition.values.contains(&metric_value) {
+                return Err(CvssError::InvalidValue {
+                    metric: name.to_string(),
+                    value: metric_value.to_string(),
+                });
+            }
+            if parsed.iter().any(|(parsed_name, _)| parsed_name == name) {
+                return Err(CvssError::DuplicateMetric(name.to_string()));
+            }
+            parsed.push((name.to_string(), metric_value.to_string()));
+        }
+
+        if let Some(missing) = definitions.iter().find(|definition| {
+            definition.required && !parsed.iter().any(|(name, _)| name == definition.name)
+        }) {
+            return Err(CvssError::MissingMetric(missing.name.to_string()));
+        }
+
+        Ok(Self {
+            version,
+            metrics: parsed,
+        })
+    }
+
+    pub fn version(&self) -> CvssVersion {
+        self.version
+    }
+
+    /// The value of a metric, e.g. `"N"` for `AV` of `CVSS:3.1/AV:N/...`
+    pub fn metric(&self, name: &str) -> Option<&str> {
+        self.metrics
+            .iter()
+            .find(|(metric, _)| metric == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Computes the base score of the vector, ignoring temporal and environmental metrics
+    /// ```
+    /// use cyclonedx_bom::external_models::cvss::CvssVector;
+    ///
+    /// let vector = CvssVector::parse("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H").unwrap();
+    /// assert_eq!(vector.base_score(), 9.8);
+    /// ```
+    pub fn base_score(&self) -> f32 {
+        let score = match self.version {
+            CvssVersion::V2 => self.v2_base_score(),
+            CvssVersion::V3_0 | CvssVersion::V3_1 => self.v3_base_score(),
+        };
+        score as f32
+    }
+
+    /// The weight of a base metric, which `parse` guarantees to be present
+    fn weight(&self, name: &str, weights: &[(&str, f64)]) -> f64 {
+        let value = self.metric(name).unwrap_or_default();
+        weights
+            .iter()
+            .find(|(weight_value, _)| *weight_value == value)
+            .map_or(0.0, |(_, weight)| *weight)
+    }
+
+    /// See the [CVSS v2 equations](https://www.first.org/cvss/v2/guide#3-2-1-Base-Equation)
+    fn v2_base_score(&self) -> f64 {
+        let impact_weights = [("N", 0.0), ("P", 0.275), ("C", 0.660)];
+        let impact = 10.41
+            * (1.0
+                - (1.0 - self.weight("C", &impact_weights))
+                    * (1.0 - self.weight("I", &impact_weights))
+                    * (1.0 - self.weight("A", &impact_weights)));
+        let exploitability = 20.0
+            * self.weight("AV", &[("L", 0.395), ("A", 0.646), ("N", 1.0)])
+            * self.weight("AC", &[("H", 0.35), ("M", 0.61), ("L", 0.71)])
+            * self.weight("Au", &[("M", 0.45), ("S", 0.56), ("N", 0.704)]);
+        let f_impact = if impact == 0.0 { 0.0 } else { 1.176 };
+
+        let score = ((0.6 * impact) + (0.4 * exploitability) - 1.5) * f_impact;
+        (score * 10.0).round() / 10.0
+    }
+
+    /// See the [CVSS v3.1 equations](https://www.first.org/cvss/v3.1/specification-document#7-1-Base-Metrics-Equations),
+    /// which only differ from v3.0 in rounding
+    fn v3_base_score(&self) -> f64 {
+        let scope_changed = self.metric("S") == Some("C");
+
+        let impact_weights = [("H", 0.56), ("L", 0.22), ("N", 0.0)];
+        let iss = 1.0
+            - (1.0 - self.weight("C", &impact_weights))
+                * (1.0 - self.weight("I", &impact_weights))
+                * (1.0 - self.weight("A", &impact_weights));
+        let impact = if scope_changed {
+            7.52 * (iss - 0.029) - 3.25 * (iss - 0.02).powi(15)
+        } else {
+            6.42 * iss
+        };
+
+        let privileges_weights = if scope_changed {
+            [("N", 0.85), ("L", 0.68), ("H", 0.5)]
+        } else {
+            [("N", 0.85), ("L", 0.62), ("H", 0.27)]
+        };
+        let exploitability = 8.22
+            * self.weight("AV", &[("N", 0.85), ("A", 0.62), ("L", 0.55), ("P", 0.2)])
+            * self.weight("AC", &[("L", 0.77), ("H", 0.44)])
+            * self.weight("PR", &privileges_weights)
+            * self.weight("UI", &[("N", 0.85), ("R", 0.62)]);
+
+        if impact <= 0.0 {
+            return 0.0;
+        }
+        let score = if scope_changed {
+            (1.08 * (impact + exploitability)).min(10.0)
+        } else {
+            (impact + exploitability).min(10.0)
+        };
+        self.round_up(score)
+    }
+
+    /// The smallest number with one decimal place that is equal to or higher than `value`
+    fn round_up(&self, value: f64) -> f64 {
+        match self.version {
+            // Avoids floating point errors such as 4.000000001 being rounded up to 4.1
+            CvssVersion::V3_1 => {
+                let int_input = (value * 100_000.0).round() as i64;
+                if int_input % 10_000 == 0 {
+                    int_input as f64 / 100_000.0
+                } else {
+                    ((int_input / 10_000) + 1) as f64 / 10.0
+                }
+            }
+            _ => (value * 10.0).ceil() / 10.0,
+        }
+    }
+}
+
+impl FromStr for CvssVector {
+    type Err = CvssError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl std::fmt::Display for CvssVector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.version != CvssVersion::V2 {
+            write!(f, "CVSS:{}/", self.version)?;
+        }
+        let metrics: Vec<String> = self
+            .metrics
+            .iter()
+            .map(|(name, value)| format!("{}:{}", name, value))
+            .collect();
+        f.write_str(&metrics.join("/"))
+    }
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum CvssError {
+    #[error("unsupported CVSS version {}", .0)]
+    UnsupportedVersion(String),
+    #[error("malformed metric \"{}\"", .0)]
+    MalformedMetric(String),
+    #[error("unknown metric {}", .0)]
+    UnknownMetric(String),
+    #[error("invalid value {value} of metric {metric}")]
+    InvalidValue { metric: String, value: String },
+    #[error("duplicate metric {}", .0)]
+    DuplicateMetric(String),
+    #[error("missing metric {}", .0)]
+    MissingMetric(String),
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn it_should_compute_base_scores() {
+        for (vector, score) in [
+            ("AV:N/AC:L/Au:N/C:P/I:P/A:P", 7.5),
+            ("(AV:N/AC:L/Au:N/C:C/I:C/A:C)", 10.0),
+            ("AV:L/AC:H/Au:M/C:N/I:N/A:N", 0.0),
+            ("AV:N/AC:M/Au:N/C:N/I:P/A:N/E:F/RL:OF/RC:C", 4.3),
+            ("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H", 9.8),
+            ("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:C/C:H/I:H/A:H", 10.0),
+            ("CVSS:3.1/AV:N/AC:L/PR:L/UI:N/S:C/C:L/I:L/A:N", 6.4),
+            ("CVSS:3.1/AV:L/AC:L/PR:L/UI:N/S:U/C:H/I:N/A:N", 5.5),
+            ("CVSS:3.1/AV:N/AC:H/PR:N/UI:R/S:U/C:L/I:N/A:N", 3.1),
+            ("CVSS:3.1/AV:P/AC:H/PR:H/UI:R/S:U/C:N/I:N/A:N", 0.0),
+            ("CVSS:3.0/AV:N/AC:L/PR:N/UI:R/S:C/C:L/I:L/A:N", 6.1),
+            (
+                "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:N/A:N/E:P/RL:O/MAV:L",
+                7.5,
+            ),
+        ] {
+            let parsed = CvssVector::parse(vector).unwrap();
+            assert_eq!(parsed.base_score(), score, "{}", vector);
+        }
+    }
+
+    #[test]
+    fn it_should_reject_invalid_vectors() {
+        for (vector, error) in [
+            (
+                "CVSS:4.0/AV:N",
+                CvssError::UnsupportedVersion("4.0".to_string()),
+            ),
+            (
+                "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H",
+                CvssError::MissingMetric("A".to_string()),
+            ),
+            (
+                "CVSS:3.1/AV:X/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H",
+                CvssError::InvalidValue {
+                    metric: "AV".to_string(),
+                    value: "X".to_string(),
+                },
+            ),
+            (
+                "CVSS:3.1/AV:N/AV:N",
+                CvssError::DuplicateMetric("AV".to_string()),
+            ),
+            (
+                "AV:N/AC:L/Au:N/C:P/I:P/A:P/PR:N",
+                CvssError::UnknownMetric("PR".to_string()),
+            ),
+            ("AV:N/AC:L/Au", CvssError::MalformedMetric("Au".to_string())),
+            ("", CvssError::MalformedMetric("".to_string())),
+        ] {
+            assert_eq!(CvssVector::parse(vector), Err(error), "{}", vector);
+        }
+    }
+
+    #[test]
+    fn it_should_display_the_vector() {
+        for vector in [
+            "AV:N/AC:L/Au:N/C:P/I:P/A:P",
+            "CVSS:3.0/AV:N/AC:L/PR:N/UI:R/S:C/C:L/I:L/A:N",
+        ] {
+            assert_eq!(CvssVector::parse(vector).unwrap().to_string(), vector);
+        }
+        assert_eq!(
+            CvssVector::parse("(AV:N/AC:L/Au:N/C:P/I:P/A:P)")
+                .unwrap()
+                .to_string(),
+            "AV:N/AC:L/Au:N/C:P/I:P/A:P"
+        );
+    }
+}
@@ -38,7 +38,7 @@ use crate::validation::{Validate, ValidationContext, ValidationResult};
 ///
 /// assert_eq!(date_time.to_string(), timestamp);
 /// ```
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct DateTime(pub(crate) String);
 
 impl DateTime {
@@ -48,6 +48,16 @@ impl DateTime {
             .map_err(|_| DateTimeError::FailedCurrentTime)?;
         Ok(Self(now))
     }
+
+    /// Creates a timestamp from the number of seconds since the Unix epoch,
+    /// e.g. the value of [`SOURCE_DATE_EPOCH`](https://reproducible-builds.org/specs/source-date-epoch/)
+    pub fn from_unix_timestamp(seconds: i64) -> Result<Self, DateTimeError> {
+        let date_time = OffsetDateTime::from_unix_timestamp(seconds)
+            .map_err(|e| DateTimeError::InvalidDateTime(e.to_string()))?
+            .format(&Iso8601::DEFAULT)
+            .map_err(|e| DateTimeError::InvalidDateTime(e.to_string()))?;
+        Ok(Self(date_time))
+    }
 }
 
 impl TryFrom<String> for DateTime {
@@ -88,6 +98,15 @@ pub enum DateTimeError {
     FailedCurrentTime,
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for DateTime {
+    /// Generates a timestamp between 1970 and 2100
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let seconds = u.int_in_range(0..=4_102_444_800)?;
+        Self::from_unix_timestamp(seconds).map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -100,6 +119,15 @@ mod test {
         assert_eq!(validation_result, ValidationResult::Passed)
     }
 
+    #[test]
+    fn it_should_create_datetimes_from_unix_timestamps() {
+        let date_time = DateTime::from_unix_timestamp(1_700_000_000).unwrap();
+
+        assert_eq!(date_time.to_string(), "2023-11-14T22:13:20.000000000Z");
+        assert_eq!(date_time.validate(), ValidationResult::Passed);
+        assert!(DateTime::from_unix_timestamp(i64::MAX).is_err());
+    }
+
     #[test]
     fn invalid_datetimes_should_fail_validation() {
         let validation_result = DateTime("invalid date".to_string()).validate();
@@ -0,0 +1,239 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use std::str::FromStr;
+
+use thiserror::Error;
+
+use crate::validation::{Validate, ValidationContext, ValidationResult};
+
+/// An email address such as `jane.doe@example.com`
+///
+/// Checks the basic syntax of a mailbox of [RFC 5321](https://www.rfc-editor.org/rfc/rfc5321#section-4.1.2):
+/// a local part of dot-separated atoms or a quoted string, and a domain name or address literal.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Email(pub(crate) String);
+
+impl Email {
+    /// Constructs an `Email`, failing if it does not conform to RFC 5321
+    /// ```
+    /// use cyclonedx_bom::external_models::email::Email;
+    ///
+    /// let email = Email::new("jane.doe@example.com").unwrap();
+    /// assert_eq!(email.domain(), "example.com");
+    /// assert!(Email::new("jane.doe").is_err());
+    /// ```
+    pub fn new(value: &str) -> Result<Self, EmailError> {
+        match parse(value) {
+            Ok(_) => Ok(Self(value.to_string())),
+            Err(reason) => Err(EmailError::InvalidEmail(reason.to_string())),
+        }
+    }
+
+    /// Allow for the existence of invalid inputs from other data sources
+    pub(crate) fn new_unchecked(value: String) -> Self {
+        Self(value)
+    }
+
+    /// The part after the last `@`, or an empty string for a malformed address
+    pub fn domain(&self) -> &str {
+        self.0
+            .rsplit_once('@')
+            .map(|(_, domain)| domain)
+            .unwrap_or_default()
+    }
+}
+
+impl FromStr for Email {
+    type Err = EmailError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+impl std::fmt::Display for Email {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl Validate for Email {
+    fn validate_with_context(&self, context: ValidationContext) -> ValidationResult {
+        match parse(&self.0) {
+            Ok(_) => ValidationResult::Passed,
+            Err(reason) => ValidationResult::failure(
+                &format!("Email does not conform to RFC 5321: {}", reason),
+                context,
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum EmailError {
+    #[error("Invalid email: {}", .0)]
+    InvalidEmail(String),
+}
+
+/// Checks the syntax of a mailbox, returning what is wrong with it otherwise
+fn parse(value: &str) -> Result<(), &'static str> {
+    let (local_part, domain) = value.rsplit_once('@').ok_or("missing @")?;
+
+    if value.len() > 254 {
+        return Err("address is too long");
+    }
+
+    let valid_local_part = match local_part
+        .strip_prefix('"')
+        .and_then(|quoted| quoted.strip_suffix('"'))
+    {
+        Some(quoted) => is_quoted_content(quoted),
+        None => is_dot_string(local_part),
+    };
+    if local_part.len() > 64 || !valid_local_part {
+        return Err("invalid local part");
+    }
+
+    let valid_domain = match domain
+        .strip_prefix('[')
+        .and_then(|literal| literal.strip_suffix(']'))
+    {
+        Some(literal) => is_address_literal(literal),
+        None => is_domain(domain),
+    };
+    if !valid_domain {
+        return Err("invalid domain");
+    }
+
+    Ok(())
+}
+
+/// `Dot-string` of RFC 5321: atoms separated by single dots
+fn is_dot_string(value: &str) -> bool {
+    value.split('.').all(|atom| {
+        !atom.is_empty()
+            && atom
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || "!#$%&'*+-/=?^_`{|}~".contains(c))
+    })
+}
+
+/// The content of a `Quoted-string` of RFC 5321: printable ASCII with `"` and `\` escaped
+fn is_quoted_content(value: &str) -> bool {
+    let mut escaped = false;
+    for c in value.chars() {
+        match c {
+            ' '..='~' if escaped => escaped = false,
+            '\\' => escaped = true,
+            '"' => return false,
+            ' '..='~' => {}
+            _ => return false,
+        }
+    }
+    !escaped
+}
+
+/// `Domain` of RFC 5321: labels of letters, digits and inner hyphens separated by dots
+fn is_domain(value: &str) -> bool {
+    value.len() <= 253
+        && value.split('.').all(|label| {
+            !label.is_empty()
+                && label.len() <= 63
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        })
+}
+
+/// The content of an `address-literal` of RFC 5321, e.g. `192.0.2.1` or `IPv6:2001:db8::1`
+fn is_address_literal(value: &str) -> bool {
+    match value.strip_prefix("IPv6:") {
+        Some(ipv6) => ipv6.parse::<std::net::Ipv6Addr>().is_ok(),
+        None => value.parse::<std::net::Ipv4Addr>().is_ok(),
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Email {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        use crate::utilities::{arbitrary_token, LOWERCASE_ALPHANUMERIC};
+
+        Ok(Self(format!(
+            "{}@{}.example",
+            arbitrary_token(u, LOWERCASE_ALPHANUMERIC, 16)?,
+            arbitrary_token(u, LOWERCASE_ALPHANUMERIC, 16)?
+        )))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn valid_emails_should_pass_validation() {
+        for email in [
+            "jane@example.com",
+            "jane.doe+sbom@mail.example.co.uk",
+            "security-team@localhost",
+            "\"jane doe\"@example.com",
+            "jane@[192.0.2.1]",
+            "jane@[IPv6:2001:db8::1]",
+        ] {
+            assert_eq!(
+                Email(email.to_string()).validate(),
+                ValidationResult::Passed,
+                "{}",
+                email
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_emails_should_fail_validation() {
+        for (email, reason) in [
+            ("invalid email", "missing @"),
+            ("@example.com", "invalid local part"),
+            ("jane..doe@example.com", "invalid local part"),
+            ("jane doe@example.com", "invalid local part"),
+            ("jane@", "invalid domain"),
+            ("jane@-example.com", "invalid domain"),
+            ("jane@example..com", "invalid domain"),
+            ("jane@[300.0.0.1]", "invalid domain"),
+        ] {
+            assert_eq!(
+                Email(email.to_string()).validate(),
+                ValidationResult::failure(
+                    &format!("Email does not conform to RFC 5321: {}", reason),
+                    ValidationContext::default()
+                ),
+                "{}",
+                email
+            );
+        }
+
+        let long_local_part = format!("{}@example.com", "a".repeat(65));
+        assert_eq!(
+            Email::new(&long_local_part),
+            Err(EmailError::InvalidEmail("invalid local part".to_string()))
+        );
+    }
+}
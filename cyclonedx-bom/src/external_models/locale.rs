@@ -0,0 +1,214 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use std::str::FromStr;
+
+use thiserror::Error;
+
+use crate::validation::{Validate, ValidationContext, ValidationResult};
+
+/// A language tag such as `en` or `pt-BR`, e.g. of the notes of a release
+///
+/// The language is an [ISO 639](https://www.iso.org/iso-639-language-code) code, optionally
+/// followed by a script, a region and variants as described by [BCP 47](https://www.rfc-editor.org/rfc/bcp/bcp47.txt).
+/// Private use and extension subtags are not supported.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Locale(pub(crate) String);
+
+impl Locale {
+    /// Constructs a `Locale`, failing if it is not a well-formed language tag
+    /// ```
+    /// use cyclonedx_bom::external_models::locale::Locale;
+    ///
+    /// let locale = Locale::new("zh-Hant-TW").unwrap();
+    /// assert_eq!(locale.language(), "zh");
+    /// assert!(Locale::new("en_US").is_err());
+    /// ```
+    pub fn new(value: &str) -> Result<Self, LocaleError> {
+        match parse(value) {
+            Ok(_) => Ok(Self(value.to_string())),
+            Err(reason) => Err(LocaleError::InvalidLocale(reason.to_string())),
+        }
+    }
+
+    /// The ISO 639 language code in lowercase, e.g. `en` for `en-US`
+    pub fn language(&self) -> String {
+        let language = self.0.split('-').next().unwrap_or_default();
+        language.to_ascii_lowercase()
+    }
+}
+
+impl FromStr for Locale {
+    type Err = LocaleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+impl std::fmt::Display for Locale {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl Validate for Locale {
+    fn validate_with_context(&self, context: ValidationContext) -> ValidationResult {
+        match parse(&self.0) {
+            Ok(_) => ValidationResult::Passed,
+            Err(reason) => ValidationResult::failure(
+                &format!("Locale does not conform to BCP 47: {}", reason),
+                context,
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum LocaleError {
+    #[error("Invalid locale: {}", .0)]
+    InvalidLocale(String),
+}
+
+/// Checks the syntax of a language tag, returning what is wrong with it otherwise
+fn parse(value: &str) -> Result<(), &'static str> {
+    let mut subtags = value.split('-').peekable();
+
+    let language = subtags.next().unwrap_or_default();
+    if !(2..=3).contains(&language.len()) || !language.chars().all(|c| c.is_ascii_alphabetic()) {
+        return Err("invalid language");
+    }
+
+    // the script and the region are optional, but must come in this order
+    subtags.next_if(|subtag| is_script(subtag));
+    subtags.next_if(|subtag| is_region(subtag));
+
+    let mut variants: Vec<String> = vec![];
+    for subtag in subtags {
+        if !is_variant(subtag) {
+            return Err("invalid subtag");
+        }
+
+        let variant = subtag.to_ascii_lowercase();
+        if variants.contains(&variant) {
+            return Err("duplicate variant");
+        }
+        variants.push(variant);
+    }
+
+    Ok(())
+}
+
+/// `script` of BCP 47: an ISO 15924 code of four letters, e.g. `Latn`
+fn is_script(subtag: &str) -> bool {
+    subtag.len() == 4 && subtag.chars().all(|c| c.is_ascii_alphabetic())
+}
+
+/// `region` of BCP 47: an ISO 3166-1 code of two letters or a UN M.49 code of three digits
+fn is_region(subtag: &str) -> bool {
+    (subtag.len() == 2 && subtag.chars().all(|c| c.is_ascii_alphabetic()))
+        || (subtag.len() == 3 && subtag.chars().all(|c| c.is_ascii_digit()))
+}
+
+/// `variant` of BCP 47: five to eight letters or digits, or four starting with a digit
+fn is_variant(subtag: &str) -> bool {
+    let alphanumeric = subtag.chars().all(|c| c.is_ascii_alphanumeric());
+    match subtag.len() {
+        5..=8 => alphanumeric,
+        4 => alphanumeric && subtag.starts_with(|c: char| c.is_ascii_digit()),
+        _ => false,
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Locale {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let locale = u.choose(&[
+            "en",
+            "en-US",
+            "de",
+            "de-CH",
+            "pt-BR",
+            "zh-Hant-TW",
+            "es-419",
+        ])?;
+        Ok(Self(locale.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn valid_locales_should_pass_validation() {
+        for locale in [
+            "en",
+            "EN-us",
+            "gsw",
+            "zh-Hant",
+            "zh-Hant-TW",
+            "es-419",
+            "sl-rozaj-biske",
+            "de-CH-1901",
+        ] {
+            assert_eq!(
+                Locale(locale.to_string()).validate(),
+                ValidationResult::Passed,
+                "{}",
+                locale
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_locales_should_fail_validation() {
+        for (locale, reason) in [
+            ("", "invalid language"),
+            ("english", "invalid language"),
+            ("e1", "invalid language"),
+            ("en_US", "invalid language"),
+            ("en-", "invalid subtag"),
+            ("en-US-Latn", "invalid subtag"),
+            ("en-x-private", "invalid subtag"),
+            ("de-1901-1901", "duplicate variant"),
+        ] {
+            assert_eq!(
+                Locale(locale.to_string()).validate(),
+                ValidationResult::failure(
+                    &format!("Locale does not conform to BCP 47: {}", reason),
+                    ValidationContext::default()
+                ),
+                "{}",
+                locale
+            );
+        }
+    }
+
+    #[test]
+    fn it_should_construct_only_valid_locales() {
+        assert_eq!(Locale::new("pt-BR"), Ok(Locale("pt-BR".to_string())));
+        assert_eq!(
+            Locale::new("pt_BR"),
+            Err(LocaleError::InvalidLocale("invalid language".to_string()))
+        );
+        assert_eq!(Locale::new("PT-br").unwrap().language(), "pt");
+    }
+}
@@ -0,0 +1,241 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use std::str::FromStr;
+
+use thiserror::Error;
+
+use crate::validation::{Validate, ValidationContext, ValidationResult};
+
+/// A media type such as `text/plain; charset=utf-8`
+///
+/// The type and subtype follow the naming rules of [RFC 6838](https://www.rfc-editor.org/rfc/rfc6838#section-4.2),
+/// the optional parameters the syntax of [RFC 2045](https://www.rfc-editor.org/rfc/rfc2045#section-5.1).
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MimeType(pub(crate) String);
+
+impl MimeType {
+    /// Constructs a `MimeType`, failing if it does not conform to RFC 6838 and RFC 2045
+    /// ```
+    /// use cyclonedx_bom::external_models::mime_type::MimeType;
+    ///
+    /// let mime_type = MimeType::new("Text/HTML; charset=\"utf-8\"").unwrap();
+    /// assert_eq!(mime_type.essence(), "text/html");
+    /// assert!(MimeType::new("text").is_err());
+    /// ```
+    pub fn new(value: &str) -> Result<Self, MimeTypeError> {
+        match parse(value) {
+            Ok(_) => Ok(Self(value.to_string())),
+            Err(reason) => Err(MimeTypeError::InvalidMimeType(reason.to_string())),
+        }
+    }
+
+    /// The type and subtype without parameters, in lowercase, e.g. `text/plain`
+    pub fn essence(&self) -> String {
+        let essence = self.0.split(';').next().unwrap_or_default();
+        essence.trim().to_ascii_lowercase()
+    }
+}
+
+impl FromStr for MimeType {
+    type Err = MimeTypeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+impl std::fmt::Display for MimeType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl Validate for MimeType {
+    fn validate_with_context(&self, context: ValidationContext) -> ValidationResult {
+        match parse(&self.0) {
+            Ok(_) => ValidationResult::Passed,
+            Err(reason) => ValidationResult::failure(
+                &format!("MimeType does not conform to RFC 6838: {}", reason),
+                context,
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum MimeTypeError {
+    #[error("Invalid MIME type: {}", .0)]
+    InvalidMimeType(String),
+}
+
+/// Checks the syntax of a media type, returning what is wrong with it otherwise
+fn parse(value: &str) -> Result<(), &'static str> {
+    let (essence, mut parameters) = match value.find(';') {
+        Some(index) => value.split_at(index),
+        None => (value, ""),
+    };
+
+    let (type_name, subtype_name) = essence
+        .trim_end()
+        .split_once('/')
+        .ok_or("missing subtype")?;
+    if !is_restricted_name(type_name) {
+        return Err("invalid type name");
+    }
+    if !is_restricted_name(subtype_name) {
+        return Err("invalid subtype name");
+    }
+
+    while let Some(rest) = parameters.strip_prefix(';') {
+        let rest = rest.trim_start();
+        let (attribute, rest) = rest.split_once('=').ok_or("missing parameter value")?;
+        if attribute.is_empty() || !attribute.chars().all(is_token_char) {
+            return Err("invalid parameter name");
+        }
+
+        let value_length = match rest.strip_prefix('"') {
+            Some(quoted) => quoted_string_length(quoted).ok_or("unterminated quoted string")? + 1,
+            None => rest.find(|c| !is_token_char(c)).unwrap_or(rest.len()),
+        };
+        if value_length == 0 {
+            return Err("missing parameter value");
+        }
+
+        parameters = rest[value_length..].trim_start();
+        if !parameters.is_empty() && !parameters.starts_with(';') {
+            return Err("invalid parameter value");
+        }
+    }
+
+    Ok(())
+}
+
+/// `restricted-name` of RFC 6838: up to 127 letters, digits and `!#$&-^_.+`, starting with a letter or digit
+fn is_restricted_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().is_some_and(|c| c.is_ascii_alphanumeric())
+        && name.len() <= 127
+        && chars.all(|c| c.is_ascii_alphanumeric() || "!#$&-^_.+".contains(c))
+}
+
+/// `token` of RFC 2045: visible ASCII characters except `tspecials`
+fn is_token_char(c: char) -> bool {
+    c.is_ascii_graphic() && !"()<>@,;:\\\"/[]?=".contains(c)
+}
+
+/// The length of a quoted string up to and including the closing quote,
+/// given the text after the opening quote
+fn quoted_string_length(quoted: &str) -> Option<usize> {
+    let mut escaped = false;
+    for (index, c) in quoted.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            '"' => return Some(index + 1),
+            _ => {}
+        }
+    }
+    None
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for MimeType {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let mime_type = u.choose(&[
+            "text/plain",
+            "text/plain; charset=utf-8",
+            "text/html",
+            "application/json",
+            "application/octet-stream",
+            "application/vnd.cyclonedx+json",
+            "image/png",
+        ])?;
+        Ok(Self(mime_type.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn valid_mime_types_should_pass_validation() {
+        for mime_type in [
+            "text/plain",
+            "application/vnd.cyclonedx+json",
+            "APPLICATION/X-GZIP",
+            "text/plain; charset=utf-8",
+            "text/plain;charset=utf-8;format=flowed",
+            r#"multipart/mixed; boundary="a;b \"c\"""#,
+        ] {
+            assert_eq!(
+                MimeType(mime_type.to_string()).validate(),
+                ValidationResult::Passed,
+                "{}",
+                mime_type
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_mime_types_should_fail_validation() {
+        for (mime_type, reason) in [
+            ("invalid mime type", "missing subtype"),
+            ("text/", "invalid subtype name"),
+            ("/plain", "invalid type name"),
+            ("text/pl ain", "invalid subtype name"),
+            ("text/plain; charset", "missing parameter value"),
+            ("text/plain; charset=", "missing parameter value"),
+            ("text/plain; charset=utf 8", "invalid parameter value"),
+            (r#"text/plain; a="b"#, "unterminated quoted string"),
+        ] {
+            assert_eq!(
+                MimeType(mime_type.to_string()).validate(),
+                ValidationResult::failure(
+                    &format!("MimeType does not conform to RFC 6838: {}", reason),
+                    ValidationContext::default()
+                ),
+                "{}",
+                mime_type
+            );
+        }
+    }
+
+    #[test]
+    fn it_should_construct_only_valid_mime_types() {
+        assert_eq!(
+            MimeType::new("text/plain"),
+            Ok(MimeType("text/plain".to_string()))
+        );
+        assert_eq!(
+            MimeType::new("text"),
+            Err(MimeTypeError::InvalidMimeType(
+                "missing subtype".to_string()
+            ))
+        );
+        assert_eq!(
+            MimeType::new("Text/Plain; charset=utf-8")
+                .unwrap()
+                .essence(),
+            "text/plain"
+        );
+    }
+}
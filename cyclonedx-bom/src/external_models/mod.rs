@@ -16,7 +16,12 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
+pub mod cvss;
 pub mod date_time;
+pub mod email;
+pub mod locale;
+pub mod mime_type;
 pub mod normalized_string;
 pub mod spdx;
 pub mod uri;
+pub mod vers;
@@ -23,7 +23,7 @@ use std::ops::Deref;
 /// A string that does not contain carriage return, line feed, or tab characters
 ///
 /// Defined via the [XML schema](https://www.w3.org/TR/xmlschema-2/#normalizedString)
-#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct NormalizedString(pub(crate) String);
 
 impl NormalizedString {
@@ -82,6 +82,13 @@ impl Validate for NormalizedString {
     }
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for NormalizedString {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self::new(u.arbitrary()?))
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -16,8 +16,11 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
+use std::collections::BTreeSet;
 use std::convert::TryFrom;
+use std::sync::{RwLock, RwLockReadGuard};
 
+use once_cell::sync::Lazy;
 use spdx::{Expression, ParseMode};
 use thiserror::Error;
 
@@ -36,7 +39,7 @@ use crate::validation::{Validate, ValidationResult};
 /// assert_eq!(spdx_identifier.to_string(), identifier);
 /// # Ok::<(), SpdxIdentifierError>(())
 /// ```
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct SpdxIdentifier(pub(crate) String);
 
 impl SpdxIdentifier {
@@ -64,9 +67,9 @@ impl TryFrom<String> for SpdxIdentifier {
     type Error = SpdxIdentifierError;
 
     fn try_from(value: String) -> Result<Self, Self::Error> {
-        match spdx::license_id(&value) {
-            Some(_) => Ok(Self(value)),
-            None => Err(SpdxIdentifierError::InvalidSpdxIdentifier(format!(
+        match SpdxLicenseList::read_installed().is_license(&value) {
+            true => Ok(Self(value)),
+            false => Err(SpdxIdentifierError::InvalidSpdxIdentifier(format!(
                 "Not a valid identifier: {}",
                 value
             ))),
@@ -114,7 +117,7 @@ pub enum SpdxIdentifierError {
 /// assert_eq!(spdx_expression.to_string(), expression);
 /// # Ok::<(), SpdxExpressionError>(())
 /// ```
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct SpdxExpression(pub(crate) String);
 
 impl SpdxExpression {
@@ -142,6 +145,70 @@ impl SpdxExpression {
         }
     }
 
+    /// Whether the licenses can be chosen so that only OSI approved licenses apply,
+    /// e.g. `MIT OR LicenseRef-Proprietary`. Always `false` for a malformed expression.
+    /// ```
+    /// use cyclonedx_bom::prelude::*;
+    ///
+    /// let expression = SpdxExpression::parse_lax("MIT OR LicenseRef-Proprietary".to_string()).unwrap();
+    /// assert!(expression.is_osi_approved());
+    /// assert!(!expression.contains_copyleft());
+    /// ```
+    pub fn is_osi_approved(&self) -> bool {
+        self.evaluate(|id| id.is_osi_approved())
+    }
+
+    /// Whether the licenses can be chosen so that only licenses the FSF considers free/libre apply.
+    /// Always `false` for a malformed expression.
+    pub fn is_fsf_libre(&self) -> bool {
+        self.evaluate(|id| id.is_fsf_free_libre())
+    }
+
+    /// Whether any license or exception of the expression is deprecated, e.g. `GPL-2.0`
+    pub fn contains_deprecated_id(&self) -> bool {
+        let Ok(expression) = Expression::parse(&self.0) else {
+            return false;
+        };
+
+        // The `spdx` crate folds e.g. `GPL-2.0-only` into the deprecated `GPL-2.0`,
+        // so licenses are looked up as written in the expression.
+        let mut requirements = expression.requirements();
+        requirements.any(|req| {
+            let written = &self.0[req.span.start as usize..req.span.end as usize];
+            let license = written.split_whitespace().next().unwrap_or_default();
+            spdx::license_id(license.trim_end_matches('+')).is_some_and(|id| id.is_deprecated())
+                || req
+                    .req
+                    .exception
+                    .is_some_and(|exception| exception.is_deprecated())
+        })
+    }
+
+    /// Whether any license of the expression is a copyleft license, even if it can be avoided
+    /// by choosing another license, e.g. `MIT OR GPL-3.0-only`
+    pub fn contains_copyleft(&self) -> bool {
+        self.requirements()
+            .into_iter()
+            .any(|req| req.license.id().is_some_and(|id| id.is_copyleft()))
+    }
+
+    fn evaluate(&self, allowed: impl Fn(spdx::LicenseId) -> bool) -> bool {
+        Expression::parse(&self.0).is_ok_and(|expression| {
+            expression.evaluate(|req| req.license.id().is_some_and(&allowed))
+        })
+    }
+
+    fn requirements(&self) -> Vec<spdx::LicenseReq> {
+        Expression::parse(&self.0)
+            .map(|expression| {
+                expression
+                    .requirements()
+                    .map(|req| req.req.clone())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
     fn convert_lax(self) -> Result<Self, SpdxExpressionError> {
         let converted = self.0.replace('/', " OR ");
 
@@ -159,12 +226,9 @@ impl TryFrom<String> for SpdxExpression {
     type Error = SpdxExpressionError;
 
     fn try_from(value: String) -> Result<Self, Self::Error> {
-        match Expression::parse(&value) {
+        match SpdxLicenseList::read_installed().parse_expression(&value) {
             Ok(_) => Ok(Self(value)),
-            Err(e) => Err(SpdxExpressionError::InvalidSpdxExpression(format!(
-                "{}",
-                e.reason
-            ))),
+            Err(reason) => Err(SpdxExpressionError::InvalidSpdxExpression(reason)),
         }
     }
 }
@@ -196,6 +260,215 @@ pub enum SpdxExpressionError {
     InvalidLaxSpdxExpression(String),
 }
 
+/// The license list that [`SpdxIdentifier`]s and [`SpdxExpression`]s are validated against
+///
+/// By default this is the list vendored by the `spdx` crate, see [`SpdxLicenseList::builtin_version`].
+/// A list can add licenses and exceptions that were published after that snapshot, or replace
+/// the vendored list entirely, e.g. to pin a specific version of the list. It takes effect
+/// once it is [installed](SpdxLicenseList::install).
+/// ```
+/// use cyclonedx_bom::external_models::spdx::{SpdxExpression, SpdxLicenseList};
+/// use std::convert::TryFrom;
+///
+/// let list = SpdxLicenseList::extending_builtin().with_license("Acme-Public-1.0");
+/// assert!(list.is_license("MIT"));
+/// assert!(list.validate_expression("MIT OR Acme-Public-1.0").is_ok());
+///
+/// list.install();
+/// assert!(SpdxExpression::try_from("Acme-Public-1.0".to_string()).is_ok());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SpdxLicenseList {
+    licenses: BTreeSet<String>,
+    exceptions: BTreeSet<String>,
+    replaces_builtin: bool,
+    version: Option<String>,
+}
+
+static INSTALLED_LICENSE_LIST: Lazy<RwLock<SpdxLicenseList>> = Lazy::new(Default::default);
+
+/// Stands in for listed licenses unknown to the `spdx` crate when parsing an expression
+const LICENSE_PLACEHOLDER: &str = "LicenseRef-listed";
+/// Stands in for listed exceptions unknown to the `spdx` crate when parsing an expression
+const EXCEPTION_PLACEHOLDER: &str = "Classpath-exception-2.0";
+
+impl SpdxLicenseList {
+    /// A list that accepts the vendored licenses and exceptions and the ones added to it
+    pub fn extending_builtin() -> Self {
+        Self::default()
+    }
+
+    /// A list that only accepts the licenses and exceptions added to it
+    pub fn replacing_builtin() -> Self {
+        Self {
+            replaces_builtin: true,
+            ..Self::default()
+        }
+    }
+
+    /// The version of the license list vendored by the `spdx` crate, e.g. `"3.23"`
+    pub fn builtin_version() -> &'static str {
+        spdx::license_version()
+    }
+
+    /// The version of the list, if it was loaded from the SPDX license list data
+    pub fn version(&self) -> Option<&str> {
+        self.version.as_deref()
+    }
+
+    pub fn with_license(mut self, id: &str) -> Self {
+        self.licenses.insert(id.to_string());
+        self
+    }
+
+    pub fn with_exception(mut self, id: &str) -> Self {
+        self.exceptions.insert(id.to_string());
+        self
+    }
+
+    /// Adds the licenses and exceptions of a `licenses.json` or `exceptions.json` file
+    /// of the [SPDX license list data](https://github.com/spdx/license-list-data/tree/main/json)
+    #[cfg(feature = "json")]
+    pub fn with_spdx_json(mut self, json: &str) -> Result<Self, SpdxLicenseListError> {
+        #[derive(serde::Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct ListData {
+            license_list_version: Option<String>,
+            #[serde(default)]
+            licenses: Vec<LicenseData>,
+            #[serde(default)]
+            exceptions: Vec<ExceptionData>,
+        }
+
+        #[derive(serde::Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct LicenseData {
+            license_id: String,
+        }
+
+        #[derive(serde::Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct ExceptionData {
+            license_exception_id: String,
+        }
+
+        let data: ListData = serde_json::from_str(json)
+            .map_err(|e| SpdxLicenseListError::InvalidLicenseListData(e.to_string()))?;
+        self.version = data.license_list_version.or(self.version);
+        self.licenses
+            .extend(data.licenses.into_iter().map(|license| license.license_id));
+        self.exceptions.extend(
+            data.exceptions
+                .into_iter()
+                .map(|exception| exception.license_exception_id),
+        );
+        Ok(self)
+    }
+
+    /// Uses the list to validate all identifiers and expressions from now on
+    pub fn install(self) {
+        *INSTALLED_LICENSE_LIST
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = self;
+    }
+
+    /// The currently installed list
+    pub fn installed() -> Self {
+        Self::read_installed().clone()
+    }
+
+    fn read_installed() -> RwLockReadGuard<'static, Self> {
+        INSTALLED_LICENSE_LIST
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    pub fn is_license(&self, id: &str) -> bool {
+        self.licenses.contains(id) || (!self.replaces_builtin && spdx::license_id(id).is_some())
+    }
+
+    pub fn is_exception(&self, id: &str) -> bool {
+        self.exceptions.contains(id) || (!self.replaces_builtin && spdx::exception_id(id).is_some())
+    }
+
+    /// Checks that the expression is well-formed and only uses licenses and exceptions of the list
+    pub fn validate_expression(&self, expression: &str) -> Result<(), SpdxExpressionError> {
+        self.parse_expression(expression)
+            .map_err(SpdxExpressionError::InvalidSpdxExpression)
+    }
+
+    fn parse_expression(&self, expression: &str) -> Result<(), String> {
+        if *self == Self::default() {
+            return Expression::parse(expression)
+                .map(|_| ())
+                .map_err(|e| e.reason.to_string());
+        }
+
+        // The `spdx` crate only knows the vendored list, so listed identifiers are replaced
+        // with placeholders it accepts before the syntax is checked.
+        let spaced = expression.replace('(', " ( ").replace(')', " ) ");
+        let mut tokens: Vec<&str> = vec![];
+        for token in spaced.split_whitespace() {
+            let after_with = tokens.last() == Some(&"WITH");
+            let id = token.strip_suffix('+').unwrap_or(token);
+            let replacement = match token {
+                "AND" | "OR" | "WITH" | "(" | ")" => token,
+                _ if after_with && self.exceptions.contains(token) => EXCEPTION_PLACEHOLDER,
+                _ if after_with && self.replaces_builtin => {
+                    return Err(format!("unknown exception {}", token))
+                }
+                _ if id.starts_with("LicenseRef-") || id.starts_with("DocumentRef-") => token,
+                _ if self.licenses.contains(id) => LICENSE_PLACEHOLDER,
+                _ if self.replaces_builtin => return Err(format!("unknown license {}", id)),
+                _ => token,
+            };
+            tokens.push(replacement);
+        }
+
+        Expression::parse(&tokens.join(" "))
+            .map(|_| ())
+            .map_err(|e| e.reason.to_string())
+    }
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum SpdxLicenseListError {
+    #[error("Invalid SPDX license list data: {}", .0)]
+    InvalidLicenseListData(String),
+}
+
+/// Well-known licenses to generate identifiers and expressions from
+#[cfg(feature = "arbitrary")]
+const ARBITRARY_LICENSES: &[&str] = &[
+    "MIT",
+    "Apache-2.0",
+    "BSD-3-Clause",
+    "ISC",
+    "MPL-2.0",
+    "GPL-3.0-only",
+    "Unlicense",
+];
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for SpdxIdentifier {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self(u.choose(ARBITRARY_LICENSES)?.to_string()))
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for SpdxExpression {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let mut expression = u.choose(ARBITRARY_LICENSES)?.to_string();
+        for _ in 0..u.int_in_range(0..=2)? {
+            let operator = u.choose(&["AND", "OR"])?;
+            let license = u.choose(ARBITRARY_LICENSES)?;
+            expression = format!("{} {} {}", expression, operator, license);
+        }
+        Ok(Self(expression))
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::validation::{ValidationContext, ValidationResult};
@@ -302,4 +575,110 @@ mod test {
             ValidationResult::failure("SPDX expression is not valid", ValidationContext::default())
         );
     }
+
+    #[test]
+    fn it_should_accept_licenses_added_to_the_builtin_list() {
+        let list = SpdxLicenseList::extending_builtin()
+            .with_license("Acme-Public-1.0")
+            .with_exception("Acme-exception-1.0");
+
+        assert!(list.is_license("Acme-Public-1.0"));
+        assert!(list.is_license("MIT"));
+        assert!(list.is_exception("Acme-exception-1.0"));
+        assert_eq!(
+            list.validate_expression(
+                "(MIT OR Acme-Public-1.0+) AND Apache-2.0 WITH Acme-exception-1.0"
+            ),
+            Ok(())
+        );
+        assert_eq!(
+            list.validate_expression("Apache-2.0 WITH Classpath-exception-2.0 OR LicenseRef-acme"),
+            Ok(())
+        );
+        assert!(list.validate_expression("Acme-Private-1.0").is_err());
+        assert!(list.validate_expression("MIT OR").is_err());
+    }
+
+    #[test]
+    fn it_should_only_accept_listed_licenses_when_replacing_the_builtin_list() {
+        let list = SpdxLicenseList::replacing_builtin()
+            .with_license("MIT")
+            .with_exception("LLVM-exception");
+
+        assert!(list.is_license("MIT"));
+        assert!(!list.is_license("Apache-2.0"));
+        assert_eq!(list.validate_expression("MIT WITH LLVM-exception"), Ok(()));
+        assert_eq!(
+            list.validate_expression("MIT OR Apache-2.0"),
+            Err(SpdxExpressionError::InvalidSpdxExpression(
+                "unknown license Apache-2.0".to_string()
+            ))
+        );
+        assert_eq!(
+            list.validate_expression("MIT WITH Classpath-exception-2.0"),
+            Err(SpdxExpressionError::InvalidSpdxExpression(
+                "unknown exception Classpath-exception-2.0".to_string()
+            ))
+        );
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn it_should_load_the_spdx_license_list_data() {
+        let licenses = r#"{
+            "licenseListVersion": "3.99",
+            "licenses": [{ "licenseId": "MIT", "name": "MIT License" }, { "licenseId": "Future-1.0" }]
+        }"#;
+        let exceptions = r#"{ "exceptions": [{ "licenseExceptionId": "Future-exception" }] }"#;
+
+        let list = SpdxLicenseList::replacing_builtin()
+            .with_spdx_json(licenses)
+            .and_then(|list| list.with_spdx_json(exceptions))
+            .unwrap();
+
+        assert_eq!(list.version(), Some("3.99"));
+        assert_eq!(
+            list.validate_expression("Future-1.0 WITH Future-exception OR MIT"),
+            Ok(())
+        );
+        assert!(SpdxLicenseList::replacing_builtin()
+            .with_spdx_json("[]")
+            .is_err());
+    }
+
+    #[test]
+    fn it_should_validate_against_the_installed_list() {
+        assert!(SpdxIdentifier::try_from("Installed-Test-1.0".to_string()).is_err());
+
+        SpdxLicenseList::extending_builtin()
+            .with_license("Installed-Test-1.0")
+            .install();
+
+        assert!(SpdxIdentifier::try_from("Installed-Test-1.0".to_string()).is_ok());
+        assert_eq!(
+            SpdxExpression("MIT OR Installed-Test-1.0".to_string()).validate(),
+            ValidationResult::Passed
+        );
+    }
+
+    #[test]
+    fn it_should_answer_policy_queries_about_expressions() {
+        let expression = |value: &str| SpdxExpression(value.to_string());
+
+        assert!(expression("MIT AND Apache-2.0").is_osi_approved());
+        assert!(expression("MIT OR LicenseRef-Proprietary").is_osi_approved());
+        assert!(!expression("MIT AND LicenseRef-Proprietary").is_osi_approved());
+        assert!(!expression("not a real license").is_osi_approved());
+
+        assert!(expression("GPL-3.0-only").is_fsf_libre());
+        assert!(!expression("LicenseRef-Proprietary").is_fsf_libre());
+
+        assert!(expression("MIT OR GPL-2.0").contains_deprecated_id());
+        assert!(expression("LGPL-2.1-only WITH Nokia-Qt-exception-1.1").contains_deprecated_id());
+        assert!(!expression("GPL-2.0-only").contains_deprecated_id());
+
+        assert!(expression("MIT OR GPL-3.0-only").contains_copyleft());
+        assert!(expression("MPL-2.0").contains_copyleft());
+        assert!(!expression("MIT OR Apache-2.0").contains_copyleft());
+    }
 }
@@ -24,7 +24,7 @@ use thiserror::Error;
 
 use crate::validation::{Validate, ValidationContext, ValidationResult};
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Purl(pub(crate) String);
 
 impl Purl {
@@ -44,16 +44,54 @@ impl ToString for Purl {
 
 impl Validate for Purl {
     fn validate_with_context(&self, context: ValidationContext) -> ValidationResult {
-        match PackageUrl::from_str(&self.0.to_string()) {
-            Ok(_) => ValidationResult::Passed,
-            Err(e) => ValidationResult::failure(
-                &format!("Purl does not conform to Package URL spec: {}", e),
+        match purl_syntax_error(&self.0) {
+            None => ValidationResult::Passed,
+            Some(problem) => ValidationResult::failure(
+                &format!("Purl does not conform to Package URL spec: {}", problem),
                 context,
             ),
         }
     }
 }
 
+/// Describes the first syntax problem of a purl, including the rules of the Package URL spec that
+/// the `packageurl` parser does not enforce.
+fn purl_syntax_error(purl: &str) -> Option<String> {
+    if let Err(e) = PackageUrl::from_str(purl) {
+        return Some(e.to_string());
+    }
+
+    if let Some(c) = purl.chars().find(|c| c.is_whitespace() || c.is_control()) {
+        return Some(format!("{:?} must be percent-encoded", c));
+    }
+
+    let without_subpath = purl.split_once('#').map_or(purl, |(rest, _)| rest);
+    let (path, qualifiers) = match without_subpath.split_once('?') {
+        Some((path, qualifiers)) => (path, Some(qualifiers)),
+        None => (without_subpath, None),
+    };
+
+    if let Some((_, version)) = path.rsplit_once('@') {
+        if version.is_empty() {
+            return Some("version is empty".to_string());
+        }
+    }
+
+    let mut keys: Vec<String> = Vec::new();
+    for pair in qualifiers.into_iter().flat_map(|q| q.split('&')) {
+        let key = pair
+            .split_once('=')
+            .map_or(pair, |(key, _)| key)
+            .to_ascii_lowercase();
+        if keys.contains(&key) {
+            return Some(format!("duplicate qualifier key {:?}", key));
+        }
+        keys.push(key);
+    }
+
+    None
+}
+
 impl FromStr for Purl {
     type Err = ();
     fn from_str(s: &str) -> Result<Self, Self::Err> {
@@ -61,7 +99,7 @@ impl FromStr for Purl {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Uri(pub(crate) String);
 
 impl TryFrom<String> for Uri {
@@ -101,6 +139,35 @@ pub enum UriError {
     InvalidPurl(String),
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Purl {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        use crate::utilities::{arbitrary_token, LOWERCASE_ALPHANUMERIC};
+
+        let package_type = u.choose(&["cargo", "npm", "pypi", "generic"])?;
+        let name = arbitrary_token(u, LOWERCASE_ALPHANUMERIC, 16)?;
+        let version = format!(
+            "{}.{}.{}",
+            u.arbitrary::<u8>()?,
+            u.arbitrary::<u8>()?,
+            u.arbitrary::<u8>()?
+        );
+        Purl::new(package_type, &name, &version).map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Uri {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        use crate::utilities::{arbitrary_token, LOWERCASE_ALPHANUMERIC};
+
+        let scheme = u.choose(&["https", "http"])?;
+        let host = arbitrary_token(u, LOWERCASE_ALPHANUMERIC, 16)?;
+        let path = arbitrary_token(u, b"abcdefghijklmnopqrstuvwxyz0123456789-._~/", 32)?;
+        Ok(Self(format!("{}://{}.example/{}", scheme, host, path)))
+    }
+}
+
 #[cfg(test)]
 mod test {
     use pretty_assertions::assert_eq;
@@ -127,6 +194,46 @@ mod test {
         );
     }
 
+    #[test]
+    fn purls_with_spec_violations_should_fail_validation() {
+        let cases = [
+            (
+                "pkg:cargo/cyclonedx bom@0.3.1",
+                "Purl does not conform to Package URL spec: ' ' must be percent-encoded",
+            ),
+            (
+                "pkg:cargo/cyclonedx-bom@",
+                "Purl does not conform to Package URL spec: version is empty",
+            ),
+            (
+                "pkg:cargo/cyclonedx-bom@0.3.1?arch=x86&Arch=arm",
+                "Purl does not conform to Package URL spec: duplicate qualifier key \"arch\"",
+            ),
+            (
+                "pkg:cargo/cyclonedx-bom@0.3.1?a%20b=c",
+                "Purl does not conform to Package URL spec: invalid key: \"a%20b\"",
+            ),
+        ];
+
+        for (purl, message) in cases {
+            assert_eq!(
+                Purl(purl.to_string()).validate(),
+                ValidationResult::failure(message, ValidationContext::default()),
+                "{}",
+                purl
+            );
+        }
+    }
+
+    #[test]
+    fn purls_with_qualifiers_and_subpath_should_pass_validation() {
+        let validation_result =
+            Purl("pkg:npm/%40angular/core@16.0.0?os=linux&arch=x86#lib/index.js".to_string())
+                .validate();
+
+        assert_eq!(validation_result, ValidationResult::Passed);
+    }
+
     #[test]
     fn valid_uris_should_pass_validation() {
         let validation_result = Uri("https://example.com".to_string()).validate();
@@ -0,0 +1,491 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+use thiserror::Error;
+
+use crate::validation::{Validate, ValidationContext, ValidationResult};
+
+/// A version range such as `vers:cargo/>=1.0.0|<1.2.0`
+///
+/// Defined via the [VERS specification](https://github.com/package-url/purl-spec/blob/version-range-spec/VERSION-RANGE-SPEC.rst)
+/// of the Package URL project. Versions of the `semver`, `npm`, `cargo`, `golang` and `nuget`
+/// schemes are compared as semantic versions, all other schemes use a generic comparison of
+/// their numeric and alphabetic segments.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Vers(pub(crate) String);
+
+impl Vers {
+    /// Constructs a `Vers`, failing if it does not conform to the VERS specification
+    /// ```
+    /// use cyclonedx_bom::external_models::vers::Vers;
+    ///
+    /// let vers = Vers::new("vers:cargo/<1.2.0 | >=1.0.0").unwrap();
+    /// assert_eq!(vers.normalize().to_string(), "vers:cargo/>=1.0.0|<1.2.0");
+    /// assert!(vers.contains("1.1.5"));
+    /// assert!(!vers.contains("1.2.0"));
+    /// assert!(Vers::new("vers:cargo/>=1.0.0|>=1.1.0").is_err());
+    /// ```
+    pub fn new(value: &str) -> Result<Self, VersError> {
+        match parse(value) {
+            Ok(_) => Ok(Self(value.to_string())),
+            Err(reason) => Err(VersError::InvalidVers(reason.to_string())),
+        }
+    }
+
+    /// The versioning scheme in lowercase, e.g. `cargo`, or `None` for a malformed range
+    pub fn scheme(&self) -> Option<String> {
+        parse(&self.0).ok().map(|range| range.scheme)
+    }
+
+    /// The constraints sorted by version, or `None` for a malformed range
+    ///
+    /// The range `*` that matches every version has no constraints.
+    pub fn constraints(&self) -> Option<Vec<VersConstraint>> {
+        parse(&self.0).ok().map(|range| range.constraints)
+    }
+
+    /// The canonical form of the range: without spaces, with a lowercase scheme and the
+    /// constraints sorted by version. Malformed ranges are returned unchanged.
+    pub fn normalize(&self) -> Self {
+        match parse(&self.0) {
+            Ok(range) => Self(range.to_string()),
+            Err(_) => self.clone(),
+        }
+    }
+
+    /// Whether `version` is within the range, always `false` for a malformed range
+    pub fn contains(&self, version: &str) -> bool {
+        parse(&self.0).is_ok_and(|range| range.contains(version))
+    }
+}
+
+impl FromStr for Vers {
+    type Err = VersError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+impl std::fmt::Display for Vers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl Validate for Vers {
+    fn validate_with_context(&self, context: ValidationContext) -> ValidationResult {
+        match parse(&self.0) {
+            Ok(_) => ValidationResult::Passed,
+            Err(reason) => ValidationResult::failure(
+                &format!(
+                    "Vers does not conform to the VERS specification: {}",
+                    reason
+                ),
+                context,
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum VersError {
+    #[error("Invalid version range: {}", .0)]
+    InvalidVers(String),
+}
+
+/// A single constraint of a [`Vers`], e.g. `>=1.0.0`
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct VersConstraint {
+    pub comparator: Comparator,
+    pub version: String,
+}
+
+impl std::fmt::Display for VersConstraint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}", self.comparator, self.version)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Comparator {
+    Equal,
+    NotEqual,
+    LessThan,
+    LessThanOrEqual,
+    GreaterThan,
+    GreaterThanOrEqual,
+}
+
+impl Comparator {
+    /// Whether the comparator bounds the range from below, i.e. is `>` or `>=`
+    fn is_lower_bound(&self) -> bool {
+        matches!(self, Self::GreaterThan | Self::GreaterThanOrEqual)
+    }
+
+    /// Whether the comparator bounds the range from above, i.e. is `<` or `<=`
+    fn is_upper_bound(&self) -> bool {
+        matches!(self, Self::LessThan | Self::LessThanOrEqual)
+    }
+
+    /// Whether a version that compares to the constraint version as `ordering` satisfies it
+    fn accepts(&self, ordering: Ordering) -> bool {
+        match self {
+            Self::Equal => ordering == Ordering::Equal,
+            Self::NotEqual => ordering != Ordering::Equal,
+            Self::LessThan => ordering == Ordering::Less,
+            Self::LessThanOrEqual => ordering != Ordering::Greater,
+            Self::GreaterThan => ordering == Ordering::Greater,
+            Self::GreaterThanOrEqual => ordering != Ordering::Less,
+        }
+    }
+}
+
+impl std::fmt::Display for Comparator {
+    /// The equality comparator is implied and therefore empty
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Equal => "",
+            Self::NotEqual => "!=",
+            Self::LessThan => "<",
+            Self::LessThanOrEqual => "<=",
+            Self::GreaterThan => ">",
+            Self::GreaterThanOrEqual => ">=",
+        })
+    }
+}
+
+/// A well-formed range with its constraints sorted by version
+struct ParsedVers {
+    scheme: String,
+    constraints: Vec<VersConstraint>,
+}
+
+impl ParsedVers {
+    /// Checks a version against the constraints as described by the VERS specification
+    fn contains(&self, version: &str) -> bool {
+        if self.constraints.is_empty() {
+            return true;
+        }
+
+        let compare =
+            |constraint: &VersConstraint| compare(&self.scheme, version, &constraint.version);
+        for constraint in &self.constraints {
+            if compare(constraint) == Ordering::Equal {
+                match constraint.comparator {
+                    Comparator::Equal => return true,
+                    Comparator::NotEqual => return false,
+                    _ => {}
+                }
+            }
+        }
+
+        let bounds: Vec<&VersConstraint> = self
+            .constraints
+            .iter()
+            .filter(|constraint| {
+                !matches!(
+                    constraint.comparator,
+                    Comparator::Equal | Comparator::NotEqual
+                )
+            })
+            .collect();
+        let (Some(first), Some(last)) = (bounds.first(), bounds.last()) else {
+            return false;
+        };
+
+        if first.comparator.is_upper_bound() && first.comparator.accepts(compare(first)) {
+            return true;
+        }
+        if last.comparator.is_lower_bound() && last.comparator.accepts(compare(last)) {
+            return true;
+        }
+        bounds.windows(2).any(|pair| {
+            pair[0].comparator.is_lower_bound()
+                && pair[1].comparator.is_upper_bound()
+                && pair[0].comparator.accepts(compare(pair[0]))
+                && pair[1].comparator.accepts(compare(pair[1]))
+        })
+    }
+}
+
+impl std::fmt::Display for ParsedVers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "vers:{}/", self.scheme)?;
+        if self.constraints.is_empty() {
+            return f.write_str("*");
+        }
+        for (index, constraint) in self.constraints.iter().enumerate() {
+            if index > 0 {
+                f.write_str("|")?;
+            }
+            write!(f, "{}", constraint)?;
+        }
+        Ok(())
+    }
+}
+
+/// Parses a version range, returning what is wrong with it otherwise
+fn parse(value: &str) -> Result<ParsedVers, &'static str> {
+    let value: String = value.chars().filter(|c| !c.is_whitespace()).collect();
+    let rest = value.strip_prefix("vers:").ok_or("missing vers scheme")?;
+    let (scheme, constraints) = rest.split_once('/').ok_or("missing constraints")?;
+
+    if scheme.is_empty()
+        || !scheme
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '+'))
+    {
+        return Err("invalid versioning scheme");
+    }
+    let scheme = scheme.to_ascii_lowercase();
+
+    if constraints == "*" {
+        return Ok(ParsedVers {
+            scheme,
+            constraints: vec![],
+        });
+    }
+
+    let mut parsed = constraints
+        .split('|')
+        .map(parse_constraint)
+        .collect::<Result<Vec<_>, _>>()?;
+    parsed.sort_by(|a, b| compare(&scheme, &a.version, &b.version));
+
+    if parsed
+        .windows(2)
+        .any(|pair| compare(&scheme, &pair[0].version, &pair[1].version) == Ordering::Equal)
+    {
+        return Err("duplicate version");
+    }
+
+    let bounds: Vec<Comparator> = parsed
+        .iter()
+        .map(|constraint| constraint.comparator)
+        .filter(|comparator| comparator.is_lower_bound() || comparator.is_upper_bound())
+        .collect();
+    if bounds
+        .windows(2)
+        .any(|pair| pair[0].is_lower_bound() == pair[1].is_lower_bound())
+    {
+        return Err("constraints do not alternate between lower and upper bounds");
+    }
+
+    Ok(ParsedVers {
+        scheme,
+        constraints: parsed,
+    })
+}
+
+fn parse_constraint(value: &str) -> Result<VersConstraint, &'static str> {
+    if value == "*" {
+        return Err("* must be the only constraint");
+    }
+
+    let (comparator, version) = [
+        (">=", Comparator::GreaterThanOrEqual),
+        ("<=", Comparator::LessThanOrEqual),
+        ("!=", Comparator::NotEqual),
+        (">", Comparator::GreaterThan),
+        ("<", Comparator::LessThan),
+        ("=", Comparator::Equal),
+    ]
+    .into_iter()
+    .find_map(|(prefix, comparator)| value.strip_prefix(prefix).map(|rest| (comparator, rest)))
+    .unwrap_or((Comparator::Equal, value));
+
+    if version.is_empty() {
+        return Err("missing version");
+    }
+    if version.starts_with(['<', '>', '=', '!']) {
+        return Err("invalid comparator");
+    }
+
+    Ok(VersConstraint {
+        comparator,
+        version: version.to_string(),
+    })
+}
+
+/// Compares two versions according to the versioning scheme
+fn compare(scheme: &str, a: &str, b: &str) -> Ordering {
+    if matches!(scheme, "semver" | "npm" | "cargo" | "golang" | "nuget") {
+        let semver = |version: &str| semver::Version::parse(version.trim_start_matches('v')).ok();
+        if let (Some(a), Some(b)) = (semver(a), semver(b)) {
+            return a.cmp(&b);
+        }
+    }
+
+    compare_generic(a, b)
+}
+
+/// Compares the runs of digits numerically and the runs of letters alphabetically,
+/// ignoring separators, e.g. `1.10` is newer than `1.9` and `2.0-beta` than `2.0-alpha`
+fn compare_generic(a: &str, b: &str) -> Ordering {
+    let segments = |version: &str| -> Vec<String> {
+        let mut segments: Vec<String> = vec![];
+        let mut previous: Option<bool> = None;
+        for c in version.chars() {
+            if !c.is_ascii_alphanumeric() {
+                previous = None;
+                continue;
+            }
+            let digit = c.is_ascii_digit();
+            match segments.last_mut() {
+                Some(segment) if previous == Some(digit) => segment.push(c),
+                _ => segments.push(c.to_string()),
+            }
+            previous = Some(digit);
+        }
+        segments
+    };
+
+    let (a, b) = (segments(a), segments(b));
+    for (a, b) in a.iter().zip(b.iter()) {
+        let ordering = match (a.parse::<u64>(), b.parse::<u64>()) {
+            (Ok(a), Ok(b)) => a.cmp(&b),
+            (Ok(_), Err(_)) => Ordering::Greater,
+            (Err(_), Ok(_)) => Ordering::Less,
+            (Err(_), Err(_)) => a.cmp(b),
+        };
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+    a.len().cmp(&b.len())
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Vers {
+    /// Generates a range between two semantic versions
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let scheme = u.choose(&["cargo", "npm", "semver"])?;
+        let lower: (u8, u8) = u.arbitrary()?;
+        let upper = (lower.0 as u16 + 1, u.arbitrary::<u8>()?);
+        Ok(Self(format!(
+            "vers:{}/>={}.{}.0|<{}.{}.0",
+            scheme, lower.0, lower.1, upper.0, upper.1
+        )))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn valid_ranges_should_pass_validation() {
+        for vers in [
+            "vers:cargo/>=1.0.0|<1.2.0",
+            "vers:npm/1.2.3|>=2.0.0|<5.0.0",
+            "vers:pypi/0.0.0|0.0.1|0.0.2",
+            "vers:deb/*",
+            "vers:generic/<1.0|!=0.9|>=2.0",
+            "vers:tomee/>=1.0.0-beta1|<=1.7.5|>=7.0.0-M1|<=7.0.7",
+        ] {
+            assert_eq!(
+                Vers(vers.to_string()).validate(),
+                ValidationResult::Passed,
+                "{}",
+                vers
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_ranges_should_fail_validation() {
+        for (vers, reason) in [
+            ("cargo/>=1.0.0", "missing vers scheme"),
+            ("vers:cargo", "missing constraints"),
+            ("vers:/1.0.0", "invalid versioning scheme"),
+            ("vers:cargo/>=", "missing version"),
+            ("vers:cargo/>==1.0.0", "invalid comparator"),
+            ("vers:cargo/*|1.0.0", "* must be the only constraint"),
+            ("vers:cargo/1.0.0|<=1.0.0", "duplicate version"),
+            (
+                "vers:cargo/>=1.0.0|>=1.1.0",
+                "constraints do not alternate between lower and upper bounds",
+            ),
+        ] {
+            assert_eq!(
+                Vers(vers.to_string()).validate(),
+                ValidationResult::failure(
+                    &format!(
+                        "Vers does not conform to the VERS specification: {}",
+                        reason
+                    ),
+                    ValidationContext::default()
+                ),
+                "{}",
+                vers
+            );
+        }
+    }
+
+    #[test]
+    fn it_should_normalize_ranges() {
+        assert_eq!(
+            Vers::new("vers:Cargo/ <2.0.0 | 1.5.0 | >= 1.0.0 ")
+                .unwrap()
+                .normalize(),
+            Vers("vers:cargo/>=1.0.0|1.5.0|<2.0.0".to_string())
+        );
+        assert_eq!(
+            Vers::new("vers:generic/1.10|1.9").unwrap().constraints(),
+            Some(vec![
+                VersConstraint {
+                    comparator: Comparator::Equal,
+                    version: "1.9".to_string()
+                },
+                VersConstraint {
+                    comparator: Comparator::Equal,
+                    version: "1.10".to_string()
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn it_should_match_versions() {
+        let vers = Vers::new("vers:npm/<1.0.0|>=2.0.0|<3.0.0|!=2.5.0|>=4.0.0-rc.1").unwrap();
+        for (version, expected) in [
+            ("0.9.9", true),
+            ("1.0.0", false),
+            ("2.0.0", true),
+            ("2.5.0", false),
+            ("3.0.0", false),
+            ("4.0.0-beta.1", false),
+            ("4.0.0", true),
+        ] {
+            assert_eq!(vers.contains(version), expected, "{}", version);
+        }
+
+        assert!(Vers::new("vers:deb/*").unwrap().contains("1:2.30-1"));
+        assert!(Vers::new("vers:pypi/1.2|1.4").unwrap().contains("1.4"));
+        assert!(!Vers::new("vers:pypi/1.2|1.4").unwrap().contains("1.3"));
+        assert!(Vers::new("vers:maven/>=1.9|<1.10.2")
+            .unwrap()
+            .contains("1.10.1"));
+    }
+}
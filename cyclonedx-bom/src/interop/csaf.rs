@@ -0,0 +1,574 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Exports the vulnerabilities of a BOM as a [CSAF 2.0](https://docs.oasis-open.org/csaf/csaf/v2.0/csaf-v2.0.html)
+//! document of the VEX profile.
+//!
+//! The component in `metadata.component` becomes the subject of the document and every
+//! target of a vulnerability a product of its product tree, identified by its bom-ref.
+//! The state of the impact analysis of a vulnerability determines the product status:
+//!
+//! | Analysis state                         | Product status        |
+//! |----------------------------------------|-----------------------|
+//! | `resolved`, `resolved_with_pedigree`   | `fixed`               |
+//! | `exploitable` or no analysis           | `known_affected`      |
+//! | `in_triage`                            | `under_investigation` |
+//! | `false_positive`, `not_affected`       | `known_not_affected`  |
+//!
+//! ```
+//! use cyclonedx_bom::interop::csaf::{to_csaf_vex, CsafOptions, CsafPublisher};
+//! use cyclonedx_bom::models::bom::Bom;
+//! use cyclonedx_bom::models::component::{Classification, Component};
+//! use cyclonedx_bom::models::metadata::Metadata;
+//!
+//! let mut metadata = Metadata::new().unwrap();
+//! metadata.component = Some(Component::new(Classification::Application, "app", "1.0.0", None));
+//! let mut bom = Bom::default();
+//! bom.metadata = Some(metadata);
+//!
+//! let options = CsafOptions::new(CsafPublisher::new("Acme", "https://acme.example"));
+//! let document = to_csaf_vex(&bom, &options).unwrap();
+//! assert_eq!(document["document"]["category"], "csaf_vex");
+//! ```
+
+use serde_json::{json, Map, Value};
+use thiserror::Error;
+
+use crate::external_models::cvss::CvssVersion;
+use crate::external_models::date_time::DateTime;
+use crate::models::bom::Bom;
+use crate::models::component::{Component, Components};
+use crate::models::vulnerability::Vulnerability;
+use crate::models::vulnerability_analysis::{
+    ImpactAnalysisJustification, ImpactAnalysisResponse, ImpactAnalysisState,
+};
+
+/// The organization that publishes the CSAF document
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CsafPublisher {
+    /// `vendor`, `discoverer`, `coordinator`, `user` or `other`
+    pub category: String,
+    pub name: String,
+    /// A URL identifying the publisher, e.g. its website
+    pub namespace: String,
+}
+
+impl CsafPublisher {
+    /// Constructs a publisher of the category `vendor`
+    pub fn new(name: &str, namespace: &str) -> Self {
+        Self {
+            category: "vendor".to_string(),
+            name: name.to_string(),
+            namespace: namespace.to_string(),
+        }
+    }
+}
+
+/// Options for [`to_csaf_vex`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CsafOptions {
+    pub publisher: CsafPublisher,
+    /// The ID of the document, by default the serial number of the BOM
+    /// or the name and version of its component
+    pub tracking_id: Option<String>,
+}
+
+impl CsafOptions {
+    pub fn new(publisher: CsafPublisher) -> Self {
+        Self {
+            publisher,
+            tracking_id: None,
+        }
+    }
+
+    pub fn with_tracking_id(mut self, tracking_id: &str) -> Self {
+        self.tracking_id = Some(tracking_id.to_string());
+        self
+    }
+}
+
+/// Converts the vulnerabilities of `bom` into a CSAF VEX document
+pub fn to_csaf_vex(bom: &Bom, options: &CsafOptions) -> Result<Value, CsafError> {
+    let metadata = bom.metadata.as_ref();
+    let root = metadata
+        .and_then(|metadata| metadata.component.as_ref())
+        .ok_or(CsafError::MissingComponent)?;
+    let timestamp = match metadata.and_then(|metadata| metadata.timestamp.as_ref()) {
+        Some(timestamp) => timestamp.to_string(),
+        None => DateTime::now()
+            .map_err(|error| CsafError::InvalidTimestamp(error.to_string()))?
+            .to_string(),
+    };
+
+    let root_name = product_name(root);
+    let tracking_id = options
+        .tracking_id
+        .clone()
+        .or_else(|| bom.serial_number.as_ref().map(ToString::to_string))
+        .unwrap_or_else(|| format!("{}-vex", root_name.replace(' ', "-")));
+    let version = bom.version.to_string();
+
+    let mut components = Vec::new();
+    if let Some(bom_components) = &bom.components {
+        collect_components(bom_components, &mut components);
+    }
+
+    let vulnerabilities: Vec<&Vulnerability> = bom
+        .vulnerabilities
+        .iter()
+        .flat_map(|vulnerabilities| vulnerabilities.0.iter())
+        .collect();
+
+    let mut product_ids: Vec<String> = vec![product_id(root)];
+    for vulnerability in &vulnerabilities {
+        for id in target_ids(vulnerability) {
+            if !product_ids.contains(&id) {
+                product_ids.push(id);
+            }
+        }
+    }
+    let products: Vec<Value> = product_ids
+        .iter()
+        .map(|id| {
+            let component = std::iter::once(root)
+                .chain(components.iter().copied())
+                .find(|component| &product_id(component) == id);
+            full_product_name(id, component)
+        })
+        .collect();
+
+    Ok(json!({
+        "document": {
+            "category": "csaf_vex",
+            "csaf_version": "2.0",
+            "publisher": {
+                "category": options.publisher.category,
+                "name": options.publisher.name,
+                "namespace": options.publisher.namespace,
+            },
+            "title": format!("VEX for {}", root_name),
+            "tracking": {
+                "id": tracking_id,
+                "status": "final",
+                "version": version,
+                "initial_release_date": timestamp,
+                "current_release_date": timestamp,
+                "revision_history": [{
+                    "date": timestamp,
+                    "number": version,
+                    "summary": "Converted from a CycloneDX BOM",
+                }],
+            },
+        },
+        "product_tree": {
+            "full_product_names": products,
+        },
+        "vulnerabilities": vulnerabilities
+            .iter()
+            .map(|vulnerability| csaf_vulnerability(vulnerability, &product_id(root)))
+            .collect::<Vec<Value>>(),
+    }))
+}
+
+fn csaf_vulnerability(vulnerability: &Vulnerability, root_id: &str) -> Value {
+    let mut result = Map::new();
+
+    if let Some(id) = &vulnerability.id {
+        if is_cve(id) {
+            result.insert("cve".to_string(), json!(id.to_string()));
+        } else {
+            let system_name = vulnerability
+                .vulnerability_source
+                .as_ref()
+                .and_then(|source| source.name.as_ref())
+                .map_or_else(|| "unknown".to_string(), ToString::to_string);
+            result.insert(
+                "ids".to_string(),
+                json!([{ "system_name": system_name, "text": id.to_string() }]),
+            );
+        }
+    }
+
+    let mut notes = Vec::new();
+    if let Some(description) = &vulnerability.description {
+        notes.push(json!({ "category": "description", "text": description }));
+    }
+    if let Some(detail) = &vulnerability.detail {
+        notes.push(json!({ "category": "details", "text": detail }));
+    }
+    if !notes.is_empty() {
+        result.insert("notes".to_string(), Value::Array(notes));
+    }
+
+    let mut product_ids = target_ids(vulnerability);
+    if product_ids.is_empty() {
+        product_ids.push(root_id.to_string());
+    }
+
+    let analysis = vulnerability.vulnerability_analysis.as_ref();
+    let status = match analysis.and_then(|analysis| analysis.state.as_ref()) {
+        Some(ImpactAnalysisState::Resolved | ImpactAnalysisState::ResolvedWithPedigree) => "fixed",
+        Some(ImpactAnalysisState::InTriage) => "under_investigation",
+        Some(ImpactAnalysisState::FalsePositive | ImpactAnalysisState::NotAffected) => {
+            "known_not_affected"
+        }
+        _ => "known_affected",
+    };
+    result.insert(
+        "product_status".to_string(),
+        json!({ status: product_ids.clone() }),
+    );
+
+    let detail = analysis.and_then(|analysis| analysis.detail.clone());
+    if status == "known_not_affected" {
+        // the VEX profile requires an impact statement for products that are not affected
+        let label = analysis
+            .and_then(|analysis| analysis.justification.as_ref())
+            .and_then(flag_label);
+        match label {
+            Some(label) => {
+                result.insert(
+                    "flags".to_string(),
+                    json!([{ "label": label, "product_ids": product_ids.clone() }]),
+                );
+            }
+            None => {
+                let details = detail
+                    .clone()
+                    .unwrap_or_else(|| "The product is not affected".to_string());
+                let threat = statement("impact", &details, &product_ids);
+                result.insert("threats".to_string(), json!([threat]));
+            }
+        }
+    }
+
+    let mut remediations = Vec::new();
+    let responses = analysis.and_then(|analysis| analysis.responses.as_ref());
+    for response in responses.into_iter().flatten() {
+        let category = match response {
+            ImpactAnalysisResponse::Update | ImpactAnalysisResponse::Rollback => "vendor_fix",
+            ImpactAnalysisResponse::WorkaroundAvailable => "workaround",
+            ImpactAnalysisResponse::CanNotFix | ImpactAnalysisResponse::WillNotFix => {
+                "no_fix_planned"
+            }
+            _ => continue,
+        };
+        let details = vulnerability
+            .recommendation
+            .clone()
+            .or_else(|| detail.clone())
+            .unwrap_or_else(|| response.to_string());
+        remediations.push(statement(category, &details, &product_ids));
+    }
+    if remediations.is_empty() && status == "known_affected" {
+        // the VEX profile requires an action statement for affected products
+        let remediation = match &vulnerability.recommendation {
+            Some(recommendation) => statement("vendor_fix", recommendation, &product_ids),
+            None => statement("none_available", "No remediation is known", &product_ids),
+        };
+        remediations.push(remediation);
+    }
+    if !remediations.is_empty() {
+        result.insert("remediations".to_string(), Value::Array(remediations));
+    }
+
+    let scores: Vec<Value> = vulnerability
+        .vulnerability_ratings
+        .iter()
+        .flat_map(|ratings| ratings.0.iter())
+        .filter_map(|rating| rating.cvss_vector()?.ok())
+        .map(|vector| {
+            // scores are multiples of 0.1, which f32 cannot represent exactly
+            let base_score = (f64::from(vector.base_score()) * 10.0).round() / 10.0;
+            let (key, cvss) = match vector.version() {
+                CvssVersion::V2 => (
+                    "cvss_v2",
+                    json!({
+                        "version": "2.0",
+                        "vectorString": vector.to_string(),
+                        "baseScore": base_score,
+                    }),
+                ),
+                version => (
+                    "cvss_v3",
+                    json!({
+                        "version": version.to_string(),
+                        "vectorString": vector.to_string(),
+                        "baseScore": base_score,
+                        "baseSeverity": base_severity(base_score),
+                    }),
+                ),
+            };
+            json!({ key: cvss, "products": product_ids.clone() })
+        })
+        .collect();
+    if !scores.is_empty() {
+        result.insert("scores".to_string(), Value::Array(scores));
+    }
+
+    Value::Object(result)
+}
+
+/// A threat or remediation of the products
+fn statement(category: &str, details: &str, product_ids: &[String]) -> Value {
+    json!({ "category": category, "details": details, "product_ids": product_ids })
+}
+
+/// The IDs of the products a vulnerability targets, i.e. their bom-refs
+fn target_ids(vulnerability: &Vulnerability) -> Vec<String> {
+    vulnerability
+        .vulnerability_targets
+        .iter()
+        .flat_map(|targets| targets.0.iter())
+        .map(|target| target.bom_ref.clone())
+        .collect()
+}
+
+fn product_id(component: &Component) -> String {
+    component
+        .bom_ref
+        .clone()
+        .unwrap_or_else(|| product_name(component))
+}
+
+fn product_name(component: &Component) -> String {
+    match &component.version {
+        Some(version) => format!("{} {}", component.name, version),
+        None => component.name.to_string(),
+    }
+}
+
+fn full_product_name(id: &str, component: Option<&Component>) -> Value {
+    let mut product = json!({
+        "name": component.map_or_else(|| id.to_string(), product_name),
+        "product_id": id,
+    });
+    if let Some(purl) = component.and_then(|component| component.purl.as_ref()) {
+        product["product_identification_helper"] = json!({ "purl": purl.to_string() });
+    }
+    product
+}
+
+fn flag_label(justification: &ImpactAnalysisJustification) -> Option<&'static str> {
+    match justification {
+        ImpactAnalysisJustification::CodeNotPresent => Some("vulnerable_code_not_present"),
+        ImpactAnalysisJustification::CodeNotReachable => {
+            Some("vulnerable_code_not_in_execute_path")
+        }
+        ImpactAnalysisJustification::RequiresConfiguration
+        | ImpactAnalysisJustification::RequiresDependency
+        | ImpactAnalysisJustification::RequiresEnvironment => {
+            Some("vulnerable_code_cannot_be_controlled_by_adversary")
+        }
+        ImpactAnalysisJustification::ProtectedByCompiler
+        | ImpactAnalysisJustification::ProtectedAtRuntime
+        | ImpactAnalysisJustification::ProtectedAtPerimeter
+        | ImpactAnalysisJustification::ProtectedByMitigatingControl => {
+            Some("inline_mitigations_already_exist")
+        }
+        _ => None,
+    }
+}
+
+/// The qualitative severity rating of a CVSS v3 base score
+fn base_severity(score: f64) -> &'static str {
+    if score < 0.1 {
+        "NONE"
+    } else if score < 4.0 {
+        "LOW"
+    } else if score < 7.0 {
+        "MEDIUM"
+    } else if score < 9.0 {
+        "HIGH"
+    } else {
+        "CRITICAL"
+    }
+}
+
+fn is_cve(id: &str) -> bool {
+    let mut parts = id.splitn(3, '-');
+    parts.next() == Some("CVE")
+        && parts
+            .next()
+            .is_some_and(|year| year.len() == 4 && year.chars().all(|c| c.is_ascii_digit()))
+        && parts
+            .next()
+            .is_some_and(|number| number.len() >= 4 && number.chars().all(|c| c.is_ascii_digit()))
+}
+
+fn collect_components<'a>(components: &'a Components, out: &mut Vec<&'a Component>) {
+    for component in &components.0 {
+        out.push(component);
+        if let Some(nested) = &component.components {
+            collect_components(nested, out);
+        }
+    }
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum CsafError {
+    #[error("The BOM has no metadata.component to describe in the CSAF document")]
+    MissingComponent,
+
+    #[error("Failed to determine the release date of the CSAF document: {0}")]
+    InvalidTimestamp(String),
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::external_models::normalized_string::NormalizedString;
+    use crate::external_models::uri::Purl;
+    use crate::models::component::Classification;
+    use crate::models::metadata::Metadata;
+    use crate::models::vulnerability::Vulnerabilities;
+    use crate::models::vulnerability_analysis::VulnerabilityAnalysis;
+    use crate::models::vulnerability_rating::{
+        ScoreMethod, VulnerabilityRating, VulnerabilityRatings,
+    };
+    use crate::models::vulnerability_target::{VulnerabilityTarget, VulnerabilityTargets};
+    use pretty_assertions::assert_eq;
+
+    fn vulnerability(id: &str, state: Option<ImpactAnalysisState>) -> Vulnerability {
+        let mut vulnerability = Vulnerability::new(None);
+        vulnerability.id = Some(NormalizedString::new(id));
+        vulnerability.vulnerability_analysis = state.map(|state| VulnerabilityAnalysis {
+            state: Some(state),
+            justification: Some(ImpactAnalysisJustification::CodeNotReachable),
+            responses: None,
+            detail: None,
+            first_issued: None,
+            last_updated: None,
+        });
+        vulnerability.vulnerability_targets =
+            Some(VulnerabilityTargets(vec![VulnerabilityTarget::new(
+                "lib@1.0.0".to_string(),
+            )]));
+        vulnerability
+    }
+
+    fn bom() -> Bom {
+        let mut metadata = Metadata::new().unwrap();
+        metadata.timestamp = Some(DateTime::try_from("2024-01-01T00:00:00Z".to_string()).unwrap());
+        metadata.component = Some(Component::new(
+            Classification::Application,
+            "app",
+            "1.0.0",
+            Some("app@1.0.0".to_string()),
+        ));
+        let mut library = Component::new(
+            Classification::Library,
+            "lib",
+            "1.0.0",
+            Some("lib@1.0.0".to_string()),
+        );
+        library.purl = Some(Purl::new("cargo", "lib", "1.0.0").unwrap());
+
+        let mut exploitable = vulnerability("CVE-2024-1234", None);
+        exploitable.recommendation = Some("Upgrade to 1.0.1".to_string());
+        let mut rating = VulnerabilityRating::new(None, None, Some(ScoreMethod::CVSSv31));
+        rating.vector = Some(NormalizedString::new(
+            "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H",
+        ));
+        exploitable.vulnerability_ratings = Some(VulnerabilityRatings(vec![rating]));
+
+        Bom {
+            metadata: Some(metadata),
+            components: Some(Components(vec![library])),
+            vulnerabilities: Some(Vulnerabilities(vec![
+                exploitable,
+                vulnerability("RUSTSEC-2024-0001", Some(ImpactAnalysisState::NotAffected)),
+            ])),
+            ..Bom::default()
+        }
+    }
+
+    #[test]
+    fn it_should_convert_vulnerabilities_into_a_csaf_vex_document() {
+        let options = CsafOptions::new(CsafPublisher::new("Acme", "https://acme.example"))
+            .with_tracking_id("ACME-VEX-1");
+        let document = to_csaf_vex(&bom(), &options).unwrap();
+
+        assert_eq!(document["document"]["tracking"]["id"], "ACME-VEX-1");
+        assert_eq!(
+            document["document"]["tracking"]["current_release_date"],
+            "2024-01-01T00:00:00Z"
+        );
+        assert_eq!(document["document"]["title"], "VEX for app 1.0.0");
+        assert_eq!(
+            document["product_tree"],
+            json!({
+                "full_product_names": [
+                    { "name": "app 1.0.0", "product_id": "app@1.0.0" },
+                    {
+                        "name": "lib 1.0.0",
+                        "product_id": "lib@1.0.0",
+                        "product_identification_helper": { "purl": "pkg:cargo/lib@1.0.0" },
+                    },
+                ],
+            })
+        );
+        assert_eq!(
+            document["vulnerabilities"],
+            json!([
+                {
+                    "cve": "CVE-2024-1234",
+                    "product_status": { "known_affected": ["lib@1.0.0"] },
+                    "remediations": [{
+                        "category": "vendor_fix",
+                        "details": "Upgrade to 1.0.1",
+                        "product_ids": ["lib@1.0.0"],
+                    }],
+                    "scores": [{
+                        "cvss_v3": {
+                            "version": "3.1",
+                            "vectorString": "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H",
+                            "baseScore": 9.8,
+                            "baseSeverity": "CRITICAL",
+                        },
+                        "products": ["lib@1.0.0"],
+                    }],
+                },
+                {
+                    "ids": [{ "system_name": "unknown", "text": "RUSTSEC-2024-0001" }],
+                    "product_status": { "known_not_affected": ["lib@1.0.0"] },
+                    "flags": [{
+                        "label": "vulnerable_code_not_in_execute_path",
+                        "product_ids": ["lib@1.0.0"],
+                    }],
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn it_should_require_a_component() {
+        let options = CsafOptions::new(CsafPublisher::new("Acme", "https://acme.example"));
+        assert_eq!(
+            to_csaf_vex(&Bom::default(), &options),
+            Err(CsafError::MissingComponent)
+        );
+    }
+
+    #[test]
+    fn it_should_recognize_cve_ids() {
+        assert!(is_cve("CVE-2021-44228"));
+        assert!(!is_cve("CVE-21-44228"));
+        assert!(!is_cve("GHSA-jfh8-c2jp-5v3q"));
+    }
+}
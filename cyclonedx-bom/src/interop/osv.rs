@@ -0,0 +1,687 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Imports vulnerabilities from [OSV](https://ossf.github.io/osv-schema/) records,
+//! e.g. as returned by the API of [osv.dev](https://osv.dev).
+//!
+//! Components are matched with the affected packages of a record by their purl,
+//! so only components with a purl and a bom-ref can become targets of a vulnerability.
+//!
+//! ```
+//! use cyclonedx_bom::interop::osv::{add_vulnerabilities, OsvRecord};
+//! use cyclonedx_bom::models::bom::Bom;
+//!
+//! let records = OsvRecord::parse_all(r#"{"vulns": []}"#).unwrap();
+//! let mut bom = Bom::default();
+//! assert_eq!(add_vulnerabilities(&mut bom, &records), 0);
+//! ```
+
+use std::collections::HashSet;
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+use packageurl::PackageUrl;
+use serde::Deserialize;
+use thiserror::Error;
+
+use crate::external_models::date_time::DateTime;
+use crate::external_models::normalized_string::NormalizedString;
+use crate::external_models::uri::Uri;
+use crate::models::advisory::{Advisories, Advisory};
+use crate::models::bom::Bom;
+use crate::models::component::{Component, Components};
+use crate::models::organization::OrganizationalContact;
+use crate::models::vulnerability::{Vulnerabilities, Vulnerability};
+use crate::models::vulnerability_credits::VulnerabilityCredits;
+use crate::models::vulnerability_rating::{ScoreMethod, VulnerabilityRating, VulnerabilityRatings};
+use crate::models::vulnerability_reference::{VulnerabilityReference, VulnerabilityReferences};
+use crate::models::vulnerability_source::VulnerabilitySource;
+use crate::models::vulnerability_target::{
+    Version, Versions, VulnerabilityTarget, VulnerabilityTargets,
+};
+
+/// A single OSV record, of which only the fields with a CycloneDX counterpart are read
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+pub struct OsvRecord {
+    pub id: String,
+    pub modified: String,
+    pub published: Option<String>,
+    pub withdrawn: Option<String>,
+    /// IDs of the same vulnerability in other databases, e.g. CVE or GHSA IDs
+    #[serde(default)]
+    pub aliases: Vec<String>,
+    pub summary: Option<String>,
+    pub details: Option<String>,
+    #[serde(default)]
+    pub severity: Vec<OsvSeverity>,
+    #[serde(default)]
+    pub affected: Vec<OsvAffected>,
+    #[serde(default)]
+    pub references: Vec<OsvReference>,
+    #[serde(default)]
+    pub credits: Vec<OsvCredit>,
+}
+
+/// A person or organization credited for finding, reporting or fixing the vulnerability
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+pub struct OsvCredit {
+    pub name: String,
+    /// URLs to contact the credited party, e.g. `mailto:` or profile URLs
+    #[serde(default)]
+    pub contact: Vec<String>,
+    /// `FINDER`, `REPORTER`, `ANALYST`, `REMEDIATION_DEVELOPER` and others
+    #[serde(rename = "type")]
+    pub credit_type: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+pub struct OsvSeverity {
+    /// `CVSS_V2`, `CVSS_V3`, `CVSS_V4` or an ecosystem specific type such as `Ubuntu`
+    #[serde(rename = "type")]
+    pub severity_type: String,
+    pub score: String,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+pub struct OsvAffected {
+    pub package: Option<OsvPackage>,
+    #[serde(default)]
+    pub severity: Vec<OsvSeverity>,
+    #[serde(default)]
+    pub ranges: Vec<OsvRange>,
+    /// Versions that are affected, in addition to the ones within `ranges`
+    #[serde(default)]
+    pub versions: Vec<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+pub struct OsvPackage {
+    /// The ecosystem of the package, e.g. `crates.io` or `Debian:11`
+    pub ecosystem: String,
+    pub name: String,
+    pub purl: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+pub struct OsvRange {
+    /// `SEMVER`, `ECOSYSTEM` or `GIT`
+    #[serde(rename = "type")]
+    pub range_type: String,
+    #[serde(default)]
+    pub events: Vec<OsvEvent>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum OsvEvent {
+    Introduced(String),
+    Fixed(String),
+    LastAffected(String),
+    Limit(String),
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+pub struct OsvReference {
+    /// E.g. `ADVISORY`, `REPORT`, `FIX` or `WEB`
+    #[serde(rename = "type")]
+    pub reference_type: String,
+    pub url: String,
+}
+
+/// The forms in which OSV records are usually distributed
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum OsvRecords {
+    Query { vulns: Vec<OsvRecord> },
+    List(Vec<OsvRecord>),
+    Single(Box<OsvRecord>),
+}
+
+impl OsvRecord {
+    /// Parses a single OSV record
+    pub fn parse(json: &str) -> Result<Self, OsvError> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    /// Parses a single OSV record, a list of records or a query response of osv.dev
+    pub fn parse_all(json: &str) -> Result<Vec<Self>, OsvError> {
+        match serde_json::from_str(json)? {
+            OsvRecords::Query { vulns } => Ok(vulns),
+            OsvRecords::List(records) => Ok(records),
+            OsvRecords::Single(record) => Ok(vec![*record]),
+        }
+    }
+
+    /// Converts the record into a vulnerability targeting the components of `bom` it affects.
+    ///
+    /// Returns `None` if the record was withdrawn or affects none of the components.
+    pub fn to_vulnerability(&self, bom: &Bom) -> Option<Vulnerability> {
+        if self.withdrawn.is_some() {
+            return None;
+        }
+
+        let mut components = Vec::new();
+        if let Some(component) = bom.metadata.as_ref().and_then(|m| m.component.as_ref()) {
+            components.push(component);
+        }
+        if let Some(bom_components) = &bom.components {
+            collect_components(bom_components, &mut components);
+        }
+
+        let mut targets: Vec<VulnerabilityTarget> = Vec::new();
+        let mut ratings: Vec<VulnerabilityRating> = self.severity.iter().map(rating).collect();
+        for affected in &self.affected {
+            let Some((package_type, namespace, name)) = affected.coordinates() else {
+                continue;
+            };
+            for component in &components {
+                let (Some(bom_ref), Some(purl)) = (&component.bom_ref, &component.purl) else {
+                    continue;
+                };
+                let Ok(purl) = PackageUrl::from_str(&purl.0) else {
+                    continue;
+                };
+                let same_package = purl.ty().eq_ignore_ascii_case(&package_type)
+                    && purl.namespace() == namespace.as_deref()
+                    && purl.name() == name;
+                let version = component.version.as_ref().map(|v| v.to_string());
+                let affects = version.as_deref().and_then(|v| affected.affects(v));
+                if !same_package
+                    || affects == Some(false)
+                    || targets.iter().any(|t| &t.bom_ref == bom_ref)
+                {
+                    continue;
+                }
+
+                let versions = affected.versions(&package_type);
+                targets.push(VulnerabilityTarget {
+                    bom_ref: bom_ref.clone(),
+                    versions: (!versions.is_empty()).then_some(Versions(versions)),
+                });
+                for severity in &affected.severity {
+                    let rating = rating(severity);
+                    if !ratings.contains(&rating) {
+                        ratings.push(rating);
+                    }
+                }
+            }
+        }
+
+        if targets.is_empty() {
+            return None;
+        }
+
+        let mut vulnerability = Vulnerability::new(None);
+        vulnerability.id = Some(NormalizedString::new(&self.id));
+        vulnerability.vulnerability_source = Some(osv_source(&self.id));
+        vulnerability.description = self.summary.clone();
+        vulnerability.detail = self.details.clone();
+        vulnerability.published = self.published.clone().and_then(date_time);
+        vulnerability.updated = date_time(self.modified.clone());
+
+        if !self.aliases.is_empty() {
+            vulnerability.vulnerability_references = Some(VulnerabilityReferences(
+                self.aliases
+                    .iter()
+                    .map(|alias| VulnerabilityReference::new(alias, osv_source(alias)))
+                    .collect(),
+            ));
+        }
+
+        if !ratings.is_empty() {
+            vulnerability.vulnerability_ratings = Some(VulnerabilityRatings(ratings));
+        }
+
+        let advisories: Vec<Advisory> = self
+            .references
+            .iter()
+            .filter(|reference| {
+                matches!(
+                    reference.reference_type.as_str(),
+                    "ADVISORY" | "ARTICLE" | "REPORT" | "WEB"
+                )
+            })
+            .filter_map(|reference| Uri::try_from(reference.url.clone()).ok())
+            .map(Advisory::new)
+            .collect();
+        if !advisories.is_empty() {
+            vulnerability.advisories = Some(Advisories(advisories));
+        }
+
+        let individuals: Vec<OrganizationalContact> = self
+            .credits
+            .iter()
+            .map(|credit| {
+                let email = credit
+                    .contact
+                    .iter()
+                    .find_map(|contact| contact.strip_prefix("mailto:"));
+                OrganizationalContact::new(&credit.name, email)
+            })
+            .collect();
+        if !individuals.is_empty() {
+            vulnerability.vulnerability_credits =
+                Some(VulnerabilityCredits::new(vec![], individuals));
+        }
+
+        vulnerability.vulnerability_targets = Some(VulnerabilityTargets(targets));
+        Some(vulnerability)
+    }
+}
+
+impl OsvAffected {
+    /// The purl type, namespace and name of the affected package
+    fn coordinates(&self) -> Option<(String, Option<String>, String)> {
+        let package = self.package.as_ref()?;
+        if let Some(purl) = package
+            .purl
+            .as_ref()
+            .and_then(|purl| PackageUrl::from_str(purl).ok())
+        {
+            return Some((
+                purl.ty().to_string(),
+                purl.namespace().map(ToString::to_string),
+                purl.name().to_string(),
+            ));
+        }
+
+        let ecosystem = package.ecosystem.split(':').next().unwrap_or_default();
+        let package_type = match ecosystem {
+            "crates.io" => "cargo",
+            "npm" => "npm",
+            "PyPI" => "pypi",
+            "Go" => "golang",
+            "Maven" => "maven",
+            "NuGet" => "nuget",
+            "RubyGems" => "gem",
+            "Packagist" => "composer",
+            "Hex" => "hex",
+            "Pub" => "pub",
+            "Debian" => "deb",
+            "Alpine" => "apk",
+            _ => return None,
+        };
+        let separator = match package_type {
+            "maven" => ':',
+            _ => '/',
+        };
+        let (namespace, name) = match package.name.rsplit_once(separator) {
+            Some((namespace, name)) => (Some(namespace.to_string()), name.to_string()),
+            None => (None, package.name.clone()),
+        };
+        Some((package_type.to_string(), namespace, name))
+    }
+
+    /// Whether `version` is affected, or `None` if that cannot be decided,
+    /// e.g. because the ranges only refer to Git commits.
+    ///
+    /// Versions within `SEMVER` and `ECOSYSTEM` ranges are compared as semantic versions.
+    pub fn affects(&self, version: &str) -> Option<bool> {
+        if self.versions.iter().any(|affected| affected == version) {
+            return Some(true);
+        }
+
+        let mut decided = !self.versions.is_empty();
+        for range in self.version_ranges() {
+            let version = semver::Version::parse(version).ok()?;
+            let lower = match range.introduced {
+                Some(introduced) => semver::Version::parse(introduced).ok()?,
+                None => semver::Version::new(0, 0, 0),
+            };
+            let within_upper = match range.upper {
+                Some(Upper::Fixed(fixed)) => version < semver::Version::parse(fixed).ok()?,
+                Some(Upper::LastAffected(last)) => version <= semver::Version::parse(last).ok()?,
+                None => true,
+            };
+            if lower <= version && within_upper {
+                return Some(true);
+            }
+            decided = true;
+        }
+
+        decided.then_some(false)
+    }
+
+    /// The affected ranges as `vers` ranges and the fixed versions as unaffected versions
+    fn versions(&self, package_type: &str) -> Vec<Version> {
+        let mut versions = Vec::new();
+        for range in self.version_ranges() {
+            let mut constraints = Vec::new();
+            if let Some(introduced) = range.introduced {
+                constraints.push(format!(">={}", introduced));
+            }
+            match range.upper {
+                Some(Upper::Fixed(fixed)) => constraints.push(format!("<{}", fixed)),
+                Some(Upper::LastAffected(last)) => constraints.push(format!("<={}", last)),
+                None => {}
+            }
+            if constraints.is_empty() {
+                constraints.push("*".to_string());
+            }
+            versions.push(Version::new(
+                &format!("vers:{}/{}", package_type, constraints.join("|")),
+                "affected",
+            ));
+        }
+
+        let fixed = self.version_ranges().filter_map(|range| match range.upper {
+            Some(Upper::Fixed(fixed)) => Some(fixed),
+            _ => None,
+        });
+        let mut seen = HashSet::new();
+        for fixed in fixed {
+            if seen.insert(fixed) {
+                versions.push(Version::new(fixed, "unaffected"));
+            }
+        }
+
+        versions
+    }
+
+    /// The intervals described by the events of all `SEMVER` and `ECOSYSTEM` ranges
+    fn version_ranges(&self) -> impl Iterator<Item = Interval<'_>> {
+        self.ranges
+            .iter()
+            .filter(|range| matches!(range.range_type.as_str(), "SEMVER" | "ECOSYSTEM"))
+            .flat_map(|range| {
+                let mut intervals = Vec::new();
+                let mut introduced: Option<Option<&str>> = None;
+                for event in &range.events {
+                    match event {
+                        OsvEvent::Introduced(version) => {
+                            introduced = Some((version != "0").then_some(version.as_str()));
+                        }
+                        OsvEvent::Fixed(version) | OsvEvent::LastAffected(version) => {
+                            let upper = match event {
+                                OsvEvent::Fixed(_) => Upper::Fixed(version),
+                                _ => Upper::LastAffected(version),
+                            };
+                            if let Some(introduced) = introduced.take() {
+                                intervals.push(Interval {
+                                    introduced,
+                                    upper: Some(upper),
+                                });
+                            }
+                        }
+                        OsvEvent::Limit(_) => {}
+                    }
+                }
+                if let Some(introduced) = introduced {
+                    intervals.push(Interval {
+                        introduced,
+                        upper: None,
+                    });
+                }
+                intervals
+            })
+    }
+}
+
+/// Affected versions from `introduced`, or all versions if it is `None`, up to `upper`
+struct Interval<'a> {
+    introduced: Option<&'a str>,
+    upper: Option<Upper<'a>>,
+}
+
+enum Upper<'a> {
+    Fixed(&'a str),
+    LastAffected(&'a str),
+}
+
+/// Adds a vulnerability for every record that affects components of `bom`, unless the BOM
+/// already has a vulnerability with the ID of the record. Returns the number of added vulnerabilities.
+pub fn add_vulnerabilities(bom: &mut Bom, records: &[OsvRecord]) -> usize {
+    let mut known: HashSet<String> = bom
+        .vulnerabilities
+        .iter()
+        .flat_map(|vulnerabilities| vulnerabilities.0.iter())
+        .filter_map(|vulnerability| vulnerability.id.as_ref().map(|id| id.to_string()))
+        .collect();
+
+    let mut added = Vec::new();
+    for record in records {
+        if known.contains(&record.id) {
+            continue;
+        }
+        if let Some(vulnerability) = record.to_vulnerability(bom) {
+            known.insert(record.id.clone());
+            added.push(vulnerability);
+        }
+    }
+
+    let count = added.len();
+    if count > 0 {
+        bom.vulnerabilities
+            .get_or_insert_with(|| Vulnerabilities(Vec::new()))
+            .0
+            .extend(added);
+    }
+    count
+}
+
+fn collect_components<'a>(components: &'a Components, out: &mut Vec<&'a Component>) {
+    for component in &components.0 {
+        out.push(component);
+        if let Some(nested) = &component.components {
+            collect_components(nested, out);
+        }
+    }
+}
+
+fn rating(severity: &OsvSeverity) -> VulnerabilityRating {
+    let score_method = match severity.severity_type.as_str() {
+        "CVSS_V2" => ScoreMethod::CVSSv2,
+        "CVSS_V3" if severity.score.starts_with("CVSS:3.0/") => ScoreMethod::CVSSv3,
+        "CVSS_V3" => ScoreMethod::CVSSv31,
+        "CVSS_V4" => ScoreMethod::CVSSv4,
+        _ => ScoreMethod::Other,
+    };
+    let mut rating = VulnerabilityRating::new(None, None, Some(score_method));
+    rating.vector = Some(NormalizedString::new(&severity.score));
+    rating.score = rating.cvss_base_score();
+    rating
+}
+
+fn osv_source(id: &str) -> VulnerabilitySource {
+    VulnerabilitySource::new(
+        Some("OSV".to_string()),
+        Uri::try_from(format!("https://osv.dev/vulnerability/{}", id)).ok(),
+    )
+}
+
+fn date_time(value: String) -> Option<DateTime> {
+    DateTime::try_from(value).ok()
+}
+
+#[derive(Debug, Error)]
+pub enum OsvError {
+    #[error("Invalid OSV record: {0}")]
+    InvalidRecord(#[from] serde_json::Error),
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::external_models::uri::Purl;
+    use crate::models::component::Classification;
+    use crate::models::vulnerability_target::VersionRange;
+    use pretty_assertions::assert_eq;
+
+    const RECORD: &str = r#"{
+        "schema_version": "1.6.0",
+        "id": "RUSTSEC-2021-0078",
+        "modified": "2023-06-13T13:10:24Z",
+        "published": "2021-07-07T12:00:00Z",
+        "aliases": ["CVE-2021-32715", "GHSA-f3pg-qwvg-p99c"],
+        "summary": "Lenient HTTP/1 header parsing",
+        "details": "hyper's HTTP server code had a flaw.",
+        "severity": [
+            {"type": "CVSS_V3", "score": "CVSS:3.1/AV:N/AC:H/PR:N/UI:N/S:U/C:N/I:L/A:N"}
+        ],
+        "affected": [{
+            "package": {"ecosystem": "crates.io", "name": "hyper", "purl": "pkg:cargo/hyper"},
+            "ranges": [{
+                "type": "SEMVER",
+                "events": [{"introduced": "0"}, {"fixed": "0.14.10"}]
+            }]
+        }],
+        "references": [
+            {"type": "PACKAGE", "url": "https://crates.io/crates/hyper"},
+            {"type": "ADVISORY", "url": "https://rustsec.org/advisories/RUSTSEC-2021-0078.html"}
+        ],
+        "credits": [
+            {"name": "Jane Doe", "contact": ["mailto:jane@example.com"], "type": "FINDER"}
+        ]
+    }"#;
+
+    fn bom(components: &[(&str, &str)]) -> Bom {
+        Bom {
+            components: Some(Components(
+                components
+                    .iter()
+                    .map(|(name, version)| {
+                        let mut component = Component::new(
+                            Classification::Library,
+                            name,
+                            version,
+                            Some(format!("{}@{}", name, version)),
+                        );
+                        component.purl = Some(Purl::new("cargo", name, version).unwrap());
+                        component
+                    })
+                    .collect(),
+            )),
+            ..Bom::default()
+        }
+    }
+
+    #[test]
+    fn it_should_convert_a_record_into_a_vulnerability() {
+        let record = OsvRecord::parse(RECORD).unwrap();
+        let vulnerability = record
+            .to_vulnerability(&bom(&[("hyper", "0.14.9"), ("serde", "1.0.0")]))
+            .unwrap();
+
+        assert_eq!(
+            vulnerability.id,
+            Some(NormalizedString::new("RUSTSEC-2021-0078"))
+        );
+        assert_eq!(
+            vulnerability.description.as_deref(),
+            Some("Lenient HTTP/1 header parsing")
+        );
+        assert_eq!(
+            vulnerability.published,
+            Some(DateTime::try_from("2021-07-07T12:00:00Z".to_string()).unwrap())
+        );
+        assert_eq!(
+            vulnerability.vulnerability_references.unwrap().0[0].id,
+            NormalizedString::new("CVE-2021-32715")
+        );
+
+        let rating = &vulnerability.vulnerability_ratings.unwrap().0[0];
+        assert_eq!(rating.score_method, Some(ScoreMethod::CVSSv31));
+        assert_eq!(rating.score, rating.cvss_base_score());
+        assert!(rating.score.is_some());
+
+        assert_eq!(vulnerability.advisories.unwrap().0.len(), 1);
+        assert_eq!(
+            vulnerability.vulnerability_credits,
+            Some(VulnerabilityCredits::new(
+                vec![],
+                vec![OrganizationalContact::new(
+                    "Jane Doe",
+                    Some("jane@example.com")
+                )]
+            ))
+        );
+        assert_eq!(
+            vulnerability.vulnerability_targets,
+            Some(VulnerabilityTargets(vec![VulnerabilityTarget {
+                bom_ref: "hyper@0.14.9".to_string(),
+                versions: Some(Versions(vec![
+                    Version {
+                        version_range: VersionRange::Range(NormalizedString::new(
+                            "vers:cargo/<0.14.10"
+                        )),
+                        status: crate::models::vulnerability_target::Status::Affected,
+                    },
+                    Version::new("0.14.10", "unaffected"),
+                ])),
+            }]))
+        );
+    }
+
+    #[test]
+    fn it_should_only_target_affected_components() {
+        let record = OsvRecord::parse(RECORD).unwrap();
+
+        assert_eq!(record.to_vulnerability(&bom(&[("hyper", "0.14.10")])), None);
+        assert_eq!(record.to_vulnerability(&bom(&[("serde", "0.14.9")])), None);
+
+        let mut withdrawn = record.clone();
+        withdrawn.withdrawn = Some("2023-01-01T00:00:00Z".to_string());
+        assert_eq!(
+            withdrawn.to_vulnerability(&bom(&[("hyper", "0.14.9")])),
+            None
+        );
+    }
+
+    #[test]
+    fn it_should_decide_which_versions_are_affected() {
+        let affected: OsvAffected = serde_json::from_str(
+            r#"{
+                "ranges": [
+                    {"type": "ECOSYSTEM", "events": [
+                        {"introduced": "1.0.0"}, {"last_affected": "1.2.0"},
+                        {"introduced": "2.0.0"}
+                    ]},
+                    {"type": "GIT", "events": [{"introduced": "abc"}, {"fixed": "def"}]}
+                ],
+                "versions": ["0.9.0-beta"]
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(affected.affects("0.9.0-beta"), Some(true));
+        assert_eq!(affected.affects("0.9.0"), Some(false));
+        assert_eq!(affected.affects("1.2.0"), Some(true));
+        assert_eq!(affected.affects("1.3.0"), Some(false));
+        assert_eq!(affected.affects("2.5.0"), Some(true));
+        assert_eq!(affected.affects("not a version"), None);
+        assert_eq!(
+            affected.versions("cargo"),
+            vec![
+                Version::new("vers:cargo/>=1.0.0|<=1.2.0", "affected"),
+                Version::new("vers:cargo/>=2.0.0", "affected"),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_add_vulnerabilities_only_once() {
+        let records = OsvRecord::parse_all(&format!(r#"{{"vulns": [{}]}}"#, RECORD)).unwrap();
+        let mut bom = bom(&[("hyper", "0.14.9")]);
+
+        assert_eq!(add_vulnerabilities(&mut bom, &records), 1);
+        assert_eq!(add_vulnerabilities(&mut bom, &records), 0);
+        assert_eq!(bom.vulnerabilities.unwrap().0.len(), 1);
+        assert!(OsvRecord::parse_all("[]").unwrap().is_empty());
+        assert!(OsvRecord::parse("{}").is_err());
+    }
+}
@@ -0,0 +1,417 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Links [in-toto](https://in-toto.io) attestations, such as [SLSA provenance](https://slsa.dev/provenance),
+//! to the components of a BOM they were issued for.
+//!
+//! An attestation is read from an in-toto statement, a DSSE envelope, a Sigstore bundle or
+//! the JSON Lines of envelopes that the SLSA generators publish as `*.intoto.jsonl`.
+//! Its subjects are matched with components by their digests, so only components with
+//! hashes can be linked. The attestation is referenced from the BOM with an external
+//! reference of type `attestation`, which was introduced in CycloneDX 1.5.
+//!
+//! ```
+//! use cyclonedx_bom::interop::provenance::{link_provenance, Provenance};
+//! use cyclonedx_bom::models::bom::Bom;
+//!
+//! let statement = r#"{
+//!   "_type": "https://in-toto.io/Statement/v1",
+//!   "subject": [{"name": "app", "digest": {"sha256": "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"}}],
+//!   "predicateType": "https://slsa.dev/provenance/v1",
+//!   "predicate": {}
+//! }"#;
+//! let provenance = Provenance::parse(
+//!     "https://example.com/releases/app.intoto.jsonl",
+//!     statement.as_bytes(),
+//! )
+//! .unwrap();
+//! let mut bom = Bom::default();
+//! let linked = link_provenance(&mut bom, &provenance);
+//! assert!(linked.is_empty());
+//! assert_eq!(bom.external_references.unwrap().0.len(), 1);
+//! ```
+
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+
+use base64::engine::general_purpose::{STANDARD, URL_SAFE};
+use base64::Engine;
+use serde::Deserialize;
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+
+use crate::external_models::uri::Uri;
+use crate::models::bom::Bom;
+use crate::models::component::{Component, Components};
+use crate::models::external_reference::{
+    ExternalReference, ExternalReferenceType, ExternalReferences,
+};
+use crate::models::hash::{Hash, HashAlgorithm, HashValue, Hashes};
+
+/// The payload type of DSSE envelopes that contain in-toto statements
+const IN_TOTO_PAYLOAD_TYPE: &str = "application/vnd.in-toto+json";
+
+/// The subjects of an attestation, and where it is published
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Provenance {
+    /// Where the attestation can be downloaded
+    pub url: Uri,
+    /// The predicate types of the statements, e.g. `https://slsa.dev/provenance/v1`
+    pub predicate_types: Vec<String>,
+    pub subjects: Vec<Subject>,
+    /// The SHA-256 hash of the attestation document
+    pub document_hash: Hash,
+}
+
+/// An artifact an attestation was issued for
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+pub struct Subject {
+    #[serde(default)]
+    pub name: String,
+    /// Hex-encoded digests by the in-toto name of their algorithm, e.g. `sha256`
+    pub digest: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Statement {
+    #[serde(rename = "_type")]
+    statement_type: String,
+    subject: Vec<Subject>,
+    predicate_type: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Envelope {
+    payload_type: String,
+    payload: String,
+}
+
+impl Provenance {
+    /// Reads the attestation in `document`, which is published at `url`
+    pub fn parse(url: &str, document: &[u8]) -> Result<Self, ProvenanceError> {
+        let url =
+            Uri::try_from(url.to_string()).map_err(|_| ProvenanceError::InvalidUrl(url.into()))?;
+        let text = std::str::from_utf8(document).map_err(|_| ProvenanceError::NotUtf8)?;
+
+        // A single JSON value, or JSON Lines with one attestation per line
+        let values: Vec<Value> = match serde_json::from_str(text) {
+            Ok(value) => vec![value],
+            Err(_) => text
+                .lines()
+                .filter(|line| !line.trim().is_empty())
+                .map(serde_json::from_str)
+                .collect::<Result<_, _>>()?,
+        };
+
+        let mut predicate_types = Vec::new();
+        let mut subjects = Vec::new();
+        for value in values {
+            let statement = statement(value)?;
+            if !predicate_types.contains(&statement.predicate_type) {
+                predicate_types.push(statement.predicate_type);
+            }
+            subjects.extend(statement.subject);
+        }
+        if subjects.is_empty() {
+            return Err(ProvenanceError::NoSubjects);
+        }
+
+        Ok(Self {
+            url,
+            predicate_types,
+            subjects,
+            document_hash: Hash {
+                alg: HashAlgorithm::SHA256,
+                content: HashValue(format!("{:x}", Sha256::digest(document))),
+            },
+        })
+    }
+
+    /// Whether a subject of the attestation has the same digest as one of the hashes of the component
+    pub fn matches(&self, component: &Component) -> bool {
+        let Some(hashes) = &component.hashes else {
+            return false;
+        };
+        self.subjects.iter().any(|subject| {
+            subject.digest.iter().any(|(algorithm, digest)| {
+                hashes.0.iter().any(|hash| {
+                    Some(&hash.alg) == hash_algorithm(algorithm).as_ref()
+                        && hash.content.0.eq_ignore_ascii_case(digest)
+                })
+            })
+        })
+    }
+
+    /// An external reference of type `attestation` to the document
+    pub fn external_reference(&self) -> ExternalReference {
+        let mut reference =
+            ExternalReference::new(ExternalReferenceType::Attestation, self.url.clone());
+        reference.comment = Some(format!(
+            "in-toto attestation: {}",
+            self.predicate_types.join(", ")
+        ));
+        reference.hashes = Some(Hashes(vec![self.document_hash.clone()]));
+        reference
+    }
+}
+
+/// Adds a reference to the attestation to the BOM and to every component that is one of its subjects,
+/// including the component in `metadata`. Returns the bom-refs, or the names if they have none,
+/// of the linked components.
+///
+/// A reference is not added twice, so the attestation can be linked again after adding components.
+pub fn link_provenance(bom: &mut Bom, provenance: &Provenance) -> Vec<String> {
+    let reference = provenance.external_reference();
+    add_reference(&mut bom.external_references, &reference);
+
+    let mut linked = Vec::new();
+    if let Some(component) = bom
+        .metadata
+        .as_mut()
+        .and_then(|metadata| metadata.component.as_mut())
+    {
+        link_component(component, provenance, &reference, &mut linked);
+    }
+    if let Some(components) = &mut bom.components {
+        link_components(components, provenance, &reference, &mut linked);
+    }
+    linked
+}
+
+fn link_components(
+    components: &mut Components,
+    provenance: &Provenance,
+    reference: &ExternalReference,
+    linked: &mut Vec<String>,
+) {
+    for component in components.0.iter_mut() {
+        link_component(component, provenance, reference, linked);
+    }
+}
+
+fn link_component(
+    component: &mut Component,
+    provenance: &Provenance,
+    reference: &ExternalReference,
+    linked: &mut Vec<String>,
+) {
+    if provenance.matches(component) {
+        add_reference(&mut component.external_references, reference);
+        linked.push(
+            component
+                .bom_ref
+                .clone()
+                .unwrap_or_else(|| component.name.to_string()),
+        );
+    }
+    if let Some(components) = &mut component.components {
+        link_components(components, provenance, reference, linked);
+    }
+}
+
+fn add_reference(references: &mut Option<ExternalReferences>, reference: &ExternalReference) {
+    let references = references.get_or_insert_with(|| ExternalReferences(Vec::new()));
+    if !references.0.contains(reference) {
+        references.0.push(reference.clone());
+    }
+}
+
+/// Reads the in-toto statement from a statement, a DSSE envelope or a Sigstore bundle
+fn statement(value: Value) -> Result<Statement, ProvenanceError> {
+    let value = match value.get("dsseEnvelope") {
+        Some(envelope) => envelope.clone(),
+        None => value,
+    };
+    if value.get("payloadType").is_none() {
+        return check_statement(serde_json::from_value(value)?);
+    }
+
+    let envelope: Envelope = serde_json::from_value(value)?;
+    if envelope.payload_type != IN_TOTO_PAYLOAD_TYPE {
+        return Err(ProvenanceError::UnsupportedPayloadType(
+            envelope.payload_type,
+        ));
+    }
+    let payload = STANDARD
+        .decode(&envelope.payload)
+        .or_else(|_| URL_SAFE.decode(&envelope.payload))
+        .map_err(|_| ProvenanceError::InvalidPayload)?;
+    check_statement(serde_json::from_slice(&payload)?)
+}
+
+fn check_statement(statement: Statement) -> Result<Statement, ProvenanceError> {
+    if statement
+        .statement_type
+        .starts_with("https://in-toto.io/Statement/")
+    {
+        Ok(statement)
+    } else {
+        Err(ProvenanceError::UnsupportedStatementType(
+            statement.statement_type,
+        ))
+    }
+}
+
+/// The algorithm of a digest in an in-toto `DigestSet`
+fn hash_algorithm(name: &str) -> Option<HashAlgorithm> {
+    match name {
+        "md5" => Some(HashAlgorithm::MD5),
+        "sha1" => Some(HashAlgorithm::SHA1),
+        "sha256" => Some(HashAlgorithm::SHA256),
+        "sha384" => Some(HashAlgorithm::SHA384),
+        "sha512" => Some(HashAlgorithm::SHA512),
+        "sha3_256" => Some(HashAlgorithm::SHA3_256),
+        "sha3_384" => Some(HashAlgorithm::SHA3_384),
+        "sha3_512" => Some(HashAlgorithm::SHA3_512),
+        "blake2b" => Some(HashAlgorithm::BLAKE2b_512),
+        _ => None,
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum ProvenanceError {
+    #[error("Invalid URL {0}")]
+    InvalidUrl(String),
+
+    #[error("The attestation is not UTF-8 encoded")]
+    NotUtf8,
+
+    #[error("Invalid attestation: {0}")]
+    InvalidJson(#[from] serde_json::Error),
+
+    #[error("Unsupported DSSE payload type {0}, expected {IN_TOTO_PAYLOAD_TYPE}")]
+    UnsupportedPayloadType(String),
+
+    #[error("The payload of the DSSE envelope is not base64 encoded")]
+    InvalidPayload,
+
+    #[error("Unsupported statement type {0}, expected an in-toto statement")]
+    UnsupportedStatementType(String),
+
+    #[error("The attestation has no subjects")]
+    NoSubjects,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::models::component::Classification;
+    use crate::models::metadata::Metadata;
+
+    const DIGEST: &str = "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855";
+
+    fn statement() -> String {
+        serde_json::json!({
+            "_type": "https://in-toto.io/Statement/v0.1",
+            "subject": [{"name": "app-x86_64-linux", "digest": {"sha256": DIGEST}}],
+            "predicateType": "https://slsa.dev/provenance/v0.2",
+            "predicate": {"builder": {"id": "https://github.com/slsa-framework/slsa-github-generator"}}
+        })
+        .to_string()
+    }
+
+    fn component(name: &str, digest: &str) -> Component {
+        let mut component = Component::new(Classification::Application, name, "1.0.0", None);
+        component.hashes = Some(Hashes(vec![Hash {
+            alg: HashAlgorithm::SHA256,
+            content: HashValue(digest.to_string()),
+        }]));
+        component
+    }
+
+    #[test]
+    fn it_should_read_statements_from_envelopes() {
+        let envelope = serde_json::json!({
+            "payloadType": IN_TOTO_PAYLOAD_TYPE,
+            "payload": STANDARD.encode(statement()),
+            "signatures": [{"keyid": "", "sig": "MEUC"}]
+        })
+        .to_string();
+        let bundle = serde_json::json!({
+            "mediaType": "application/vnd.dev.sigstore.bundle+json;version=0.2",
+            "dsseEnvelope": serde_json::from_str::<Value>(&envelope).unwrap()
+        })
+        .to_string();
+        let jsonl = format!("{envelope}\n{envelope}\n");
+
+        for document in [statement(), envelope, bundle, jsonl] {
+            let provenance =
+                Provenance::parse("https://example.com/app.intoto.jsonl", document.as_bytes())
+                    .unwrap_or_else(|e| panic!("Failed to parse {document}: {e}"));
+            assert_eq!(
+                provenance.predicate_types,
+                vec!["https://slsa.dev/provenance/v0.2"]
+            );
+            assert_eq!(provenance.subjects[0].name, "app-x86_64-linux");
+        }
+
+        let envelope = serde_json::json!({"payloadType": "text/plain", "payload": ""}).to_string();
+        assert!(matches!(
+            Provenance::parse("https://example.com/app.intoto.jsonl", envelope.as_bytes()),
+            Err(ProvenanceError::UnsupportedPayloadType(_))
+        ));
+    }
+
+    #[test]
+    fn it_should_link_the_attestation_to_matching_components() {
+        let provenance = Provenance::parse(
+            "https://example.com/app.intoto.jsonl",
+            statement().as_bytes(),
+        )
+        .unwrap();
+        let metadata = Metadata {
+            component: Some(component("app", &DIGEST.to_uppercase())),
+            ..Metadata::default()
+        };
+        let mut bom = Bom {
+            metadata: Some(metadata),
+            components: Some(Components(vec![
+                component("lib", &"0".repeat(64)),
+                Component::new(Classification::Library, "unhashed", "1.0.0", None),
+            ])),
+            ..Bom::default()
+        };
+
+        assert_eq!(link_provenance(&mut bom, &provenance), vec!["app"]);
+        assert_eq!(link_provenance(&mut bom, &provenance), vec!["app"]);
+
+        let reference = provenance.external_reference();
+        assert_eq!(
+            reference.external_reference_type,
+            ExternalReferenceType::Attestation
+        );
+        assert_eq!(
+            bom.external_references,
+            Some(ExternalReferences(vec![reference.clone()]))
+        );
+        let metadata_component = bom.metadata.unwrap().component.unwrap();
+        assert_eq!(
+            metadata_component.external_references,
+            Some(ExternalReferences(vec![reference]))
+        );
+        assert!(bom
+            .components
+            .unwrap()
+            .0
+            .iter()
+            .all(|component| component.external_references.is_none()));
+    }
+}
@@ -0,0 +1,1274 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Converts between BOMs and [SPDX 2.3](https://spdx.github.io/spdx-spec/v2.3/) documents
+//! in the JSON and the tag-value format.
+//!
+//! Components become packages and vice versa:
+//! - the bom-ref of a component is the SPDX identifier of its package without the `SPDXRef-` prefix
+//! - `metadata.component` is the package the document `DESCRIBES`
+//! - nested components are related to their parent with `CONTAINS`
+//! - dependencies are `DEPENDS_ON` relationships
+//! - licenses become the declared license expression of a package, named licenses
+//!   are declared as `LicenseRef-` licenses of the document
+//!
+//! Everything else of a BOM, e.g. services or vulnerabilities, has no SPDX counterpart and is dropped.
+//!
+//! ```
+//! use cyclonedx_bom::interop::spdx::SpdxDocument;
+//! use cyclonedx_bom::models::bom::Bom;
+//!
+//! let document = SpdxDocument::from(&Bom::default());
+//! let tag_value = document.to_tag_value();
+//! assert!(tag_value.starts_with("SPDXVersion: SPDX-2.3\n"));
+//!
+//! let bom = Bom::from(&SpdxDocument::parse_tag_value(&tag_value).unwrap());
+//! assert!(bom.components.is_none());
+//! ```
+
+use std::collections::{HashMap, HashSet};
+use std::convert::TryFrom;
+use std::fmt::Write;
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::external_models::date_time::DateTime;
+use crate::external_models::normalized_string::NormalizedString;
+use crate::external_models::spdx::SpdxExpression;
+use crate::external_models::uri::{Purl, Uri};
+use crate::models::bom::Bom;
+use crate::models::component::{Classification, Component, Components, Cpe};
+use crate::models::dependency::{Dependencies, Dependency};
+use crate::models::external_reference::{
+    ExternalReference, ExternalReferenceType, ExternalReferences,
+};
+use crate::models::hash::{Hash, HashAlgorithm, HashValue, Hashes};
+use crate::models::license::{License, LicenseChoice, LicenseIdentifier, Licenses};
+use crate::models::metadata::Metadata;
+use crate::models::organization::{OrganizationalContact, OrganizationalEntity};
+use crate::models::tool::{Tool, Tools};
+
+const DOCUMENT_ID: &str = "SPDXRef-DOCUMENT";
+const NOASSERTION: &str = "NOASSERTION";
+
+/// An SPDX 2.3 document, restricted to the parts that have a CycloneDX counterpart
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpdxDocument {
+    pub spdx_version: String,
+    pub data_license: String,
+    #[serde(rename = "SPDXID")]
+    pub spdx_id: String,
+    pub name: String,
+    pub document_namespace: String,
+    pub creation_info: SpdxCreationInfo,
+    /// The packages described by the document, in addition to the `DESCRIBES` relationships
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub document_describes: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub packages: Vec<SpdxPackage>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub relationships: Vec<SpdxRelationship>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub has_extracted_licensing_infos: Vec<SpdxExtractedLicense>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpdxCreationInfo {
+    pub created: String,
+    /// E.g. `Tool: cargo-cyclonedx-0.5.0` or `Person: Jane Doe (jane@example.com)`
+    #[serde(default)]
+    pub creators: Vec<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpdxPackage {
+    pub name: String,
+    #[serde(rename = "SPDXID")]
+    pub spdx_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version_info: Option<String>,
+    /// E.g. `Organization: Acme` or `Person: Jane Doe`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supplier: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub originator: Option<String>,
+    pub download_location: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub files_analyzed: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub homepage: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub license_concluded: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub license_declared: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub copyright_text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// E.g. `APPLICATION` or `LIBRARY`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub primary_package_purpose: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub checksums: Vec<SpdxChecksum>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub external_refs: Vec<SpdxExternalRef>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpdxChecksum {
+    /// E.g. `SHA256`
+    pub algorithm: String,
+    pub checksum_value: String,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpdxExternalRef {
+    /// E.g. `PACKAGE-MANAGER` or `SECURITY`
+    pub reference_category: String,
+    /// E.g. `purl` or `cpe23Type`
+    pub reference_type: String,
+    pub reference_locator: String,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpdxRelationship {
+    pub spdx_element_id: String,
+    /// E.g. `DESCRIBES`, `CONTAINS` or `DEPENDS_ON`
+    pub relationship_type: String,
+    pub related_spdx_element: String,
+}
+
+/// A license that is not on the SPDX license list, referenced as `LicenseRef-<name>`
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpdxExtractedLicense {
+    pub license_id: String,
+    pub extracted_text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+impl SpdxDocument {
+    pub fn parse_json(json: &str) -> Result<Self, SpdxError> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    pub fn to_json(&self) -> Result<String, SpdxError> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// Parses a document in the tag-value format, ignoring tags without a counterpart in [`SpdxDocument`]
+    pub fn parse_tag_value(text: &str) -> Result<Self, SpdxError> {
+        let mut document = Self {
+            spdx_version: String::new(),
+            data_license: String::new(),
+            spdx_id: String::new(),
+            name: String::new(),
+            document_namespace: String::new(),
+            creation_info: SpdxCreationInfo::default(),
+            document_describes: Vec::new(),
+            packages: Vec::new(),
+            relationships: Vec::new(),
+            has_extracted_licensing_infos: Vec::new(),
+        };
+        let mut in_package = false;
+
+        let mut lines = text.lines().enumerate();
+        while let Some((index, line)) = lines.next() {
+            let line_number = index + 1;
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (tag, value) = line
+                .split_once(':')
+                .ok_or(SpdxError::InvalidTagValue(line_number))?;
+            let mut value = value.trim().to_string();
+            if let Some(text) = value.strip_prefix("<text>") {
+                let mut text = text.to_string();
+                while !text.contains("</text>") {
+                    let (_, line) = lines
+                        .next()
+                        .ok_or(SpdxError::UnterminatedText(line_number))?;
+                    text.push('\n');
+                    text.push_str(line);
+                }
+                value = text[..text.find("</text>").unwrap_or(text.len())].to_string();
+            }
+
+            let package = document.packages.last_mut().filter(|_| in_package);
+            let extracted = document.has_extracted_licensing_infos.last_mut();
+            match (tag, package) {
+                ("SPDXVersion", _) => document.spdx_version = value,
+                ("DataLicense", _) => document.data_license = value,
+                ("DocumentName", _) => document.name = value,
+                ("DocumentNamespace", _) => document.document_namespace = value,
+                ("Creator", _) => document.creation_info.creators.push(value),
+                ("Created", _) => document.creation_info.created = value,
+                ("Relationship", _) => {
+                    let mut parts = value.split_whitespace();
+                    match (parts.next(), parts.next(), parts.next()) {
+                        (Some(element), Some(relationship_type), Some(related)) => {
+                            document.relationships.push(SpdxRelationship {
+                                spdx_element_id: element.to_string(),
+                                relationship_type: relationship_type.to_string(),
+                                related_spdx_element: related.to_string(),
+                            })
+                        }
+                        _ => return Err(SpdxError::InvalidTagValue(line_number)),
+                    }
+                }
+                ("PackageName", _) => {
+                    in_package = true;
+                    document.packages.push(SpdxPackage {
+                        name: value,
+                        ..SpdxPackage::default()
+                    });
+                }
+                ("LicenseID", _) => {
+                    in_package = false;
+                    document
+                        .has_extracted_licensing_infos
+                        .push(SpdxExtractedLicense {
+                            license_id: value,
+                            extracted_text: String::new(),
+                            name: None,
+                        });
+                }
+                ("ExtractedText", _) => {
+                    if let Some(extracted) = extracted {
+                        extracted.extracted_text = value;
+                    }
+                }
+                ("LicenseName", _) => {
+                    if let Some(extracted) = extracted {
+                        extracted.name = Some(value);
+                    }
+                }
+                ("SPDXID", None) => document.spdx_id = value,
+                ("SPDXID", Some(package)) => package.spdx_id = value,
+                ("PackageVersion", Some(package)) => package.version_info = Some(value),
+                ("PackageSupplier", Some(package)) => package.supplier = Some(value),
+                ("PackageOriginator", Some(package)) => package.originator = Some(value),
+                ("PackageDownloadLocation", Some(package)) => package.download_location = value,
+                ("FilesAnalyzed", Some(package)) => {
+                    package.files_analyzed = Some(value.eq_ignore_ascii_case("true"))
+                }
+                ("PackageHomePage", Some(package)) => package.homepage = Some(value),
+                ("PackageLicenseConcluded", Some(package)) => {
+                    package.license_concluded = Some(value)
+                }
+                ("PackageLicenseDeclared", Some(package)) => package.license_declared = Some(value),
+                ("PackageCopyrightText", Some(package)) => package.copyright_text = Some(value),
+                ("PackageDescription", Some(package)) => package.description = Some(value),
+                ("PrimaryPackagePurpose", Some(package)) => {
+                    package.primary_package_purpose = Some(value)
+                }
+                ("PackageChecksum", Some(package)) => {
+                    let (algorithm, checksum_value) = value
+                        .split_once(':')
+                        .ok_or(SpdxError::InvalidTagValue(line_number))?;
+                    package.checksums.push(SpdxChecksum {
+                        algorithm: algorithm.trim().to_string(),
+                        checksum_value: checksum_value.trim().to_string(),
+                    });
+                }
+                ("ExternalRef", Some(package)) => {
+                    let mut parts = value.split_whitespace();
+                    match (parts.next(), parts.next(), parts.next()) {
+                        (Some(category), Some(reference_type), Some(locator)) => {
+                            package.external_refs.push(SpdxExternalRef {
+                                reference_category: category.to_string(),
+                                reference_type: reference_type.to_string(),
+                                reference_locator: locator.to_string(),
+                            })
+                        }
+                        _ => return Err(SpdxError::InvalidTagValue(line_number)),
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Ok(document)
+    }
+
+    pub fn to_tag_value(&self) -> String {
+        let mut out = String::new();
+        tag(&mut out, "SPDXVersion", &self.spdx_version);
+        tag(&mut out, "DataLicense", &self.data_license);
+        tag(&mut out, "SPDXID", &self.spdx_id);
+        tag(&mut out, "DocumentName", &self.name);
+        tag(&mut out, "DocumentNamespace", &self.document_namespace);
+        for creator in &self.creation_info.creators {
+            tag(&mut out, "Creator", creator);
+        }
+        tag(&mut out, "Created", &self.creation_info.created);
+        for described in &self.document_describes {
+            let _ = writeln!(
+                out,
+                "Relationship: {} DESCRIBES {}",
+                self.spdx_id, described
+            );
+        }
+
+        for package in &self.packages {
+            out.push('\n');
+            tag(&mut out, "PackageName", &package.name);
+            tag(&mut out, "SPDXID", &package.spdx_id);
+            optional_tag(&mut out, "PackageVersion", &package.version_info);
+            optional_tag(&mut out, "PackageSupplier", &package.supplier);
+            optional_tag(&mut out, "PackageOriginator", &package.originator);
+            tag(
+                &mut out,
+                "PackageDownloadLocation",
+                &package.download_location,
+            );
+            if let Some(files_analyzed) = package.files_analyzed {
+                tag(&mut out, "FilesAnalyzed", &files_analyzed.to_string());
+            }
+            optional_tag(&mut out, "PackageHomePage", &package.homepage);
+            optional_tag(
+                &mut out,
+                "PackageLicenseConcluded",
+                &package.license_concluded,
+            );
+            optional_tag(
+                &mut out,
+                "PackageLicenseDeclared",
+                &package.license_declared,
+            );
+            optional_tag(&mut out, "PackageCopyrightText", &package.copyright_text);
+            optional_tag(&mut out, "PackageDescription", &package.description);
+            optional_tag(
+                &mut out,
+                "PrimaryPackagePurpose",
+                &package.primary_package_purpose,
+            );
+            for checksum in &package.checksums {
+                let _ = writeln!(
+                    out,
+                    "PackageChecksum: {}: {}",
+                    checksum.algorithm, checksum.checksum_value
+                );
+            }
+            for external_ref in &package.external_refs {
+                let _ = writeln!(
+                    out,
+                    "ExternalRef: {} {} {}",
+                    external_ref.reference_category,
+                    external_ref.reference_type,
+                    external_ref.reference_locator
+                );
+            }
+        }
+
+        if !self.relationships.is_empty() {
+            out.push('\n');
+        }
+        for relationship in &self.relationships {
+            let _ = writeln!(
+                out,
+                "Relationship: {} {} {}",
+                relationship.spdx_element_id,
+                relationship.relationship_type,
+                relationship.related_spdx_element
+            );
+        }
+
+        for extracted in &self.has_extracted_licensing_infos {
+            out.push('\n');
+            tag(&mut out, "LicenseID", &extracted.license_id);
+            tag(&mut out, "ExtractedText", &extracted.extracted_text);
+            optional_tag(&mut out, "LicenseName", &extracted.name);
+        }
+
+        out
+    }
+
+    /// The IDs of the packages the document describes
+    fn described(&self) -> Vec<&str> {
+        let mut described: Vec<&str> = self.document_describes.iter().map(String::as_str).collect();
+        for relationship in &self.relationships {
+            let related = relationship.related_spdx_element.as_str();
+            if relationship.spdx_element_id == self.spdx_id
+                && relationship.relationship_type == "DESCRIBES"
+                && !described.contains(&related)
+            {
+                described.push(related);
+            }
+        }
+        described
+    }
+}
+
+fn tag(out: &mut String, tag: &str, value: &str) {
+    let _ = match value.contains('\n') {
+        true => writeln!(out, "{}: <text>{}</text>", tag, value),
+        false => writeln!(out, "{}: {}", tag, value),
+    };
+}
+
+fn optional_tag(out: &mut String, name: &str, value: &Option<String>) {
+    if let Some(value) = value {
+        tag(out, name, value);
+    }
+}
+
+impl From<&Bom> for SpdxDocument {
+    fn from(bom: &Bom) -> Self {
+        let metadata = bom.metadata.as_ref();
+        let root = metadata.and_then(|metadata| metadata.component.as_ref());
+
+        // components in document order, with the bom-ref of their parent
+        let mut components: Vec<(&Component, Option<&Component>)> = Vec::new();
+        if let Some(root) = root {
+            components.push((root, None));
+            if let Some(nested) = &root.components {
+                flatten(nested, Some(root), &mut components);
+            }
+        }
+        if let Some(bom_components) = &bom.components {
+            flatten(bom_components, None, &mut components);
+        }
+
+        let mut used_ids = HashSet::from([DOCUMENT_ID.to_string()]);
+        let mut ids: HashMap<String, String> = HashMap::new();
+        let mut packages = Vec::new();
+        let mut extracted_licenses: Vec<SpdxExtractedLicense> = Vec::new();
+        for (index, (component, _)) in components.iter().enumerate() {
+            let mut spdx_id = match &component.bom_ref {
+                Some(bom_ref) => format!("SPDXRef-{}", sanitize(bom_ref)),
+                None => format!("SPDXRef-Package-{}", index),
+            };
+            while !used_ids.insert(spdx_id.clone()) {
+                spdx_id.push_str("-dup");
+            }
+            if let Some(bom_ref) = &component.bom_ref {
+                ids.entry(bom_ref.clone())
+                    .or_insert_with(|| spdx_id.clone());
+            }
+            packages.push(to_package(component, spdx_id, &mut extracted_licenses));
+        }
+
+        let mut relationships = Vec::new();
+        match root {
+            Some(_) => {
+                relationships.push(relationship(DOCUMENT_ID, "DESCRIBES", &packages[0].spdx_id))
+            }
+            None => {
+                for (package, (_, parent)) in packages.iter().zip(&components) {
+                    if parent.is_none() {
+                        relationships.push(relationship(
+                            DOCUMENT_ID,
+                            "DESCRIBES",
+                            &package.spdx_id,
+                        ));
+                    }
+                }
+            }
+        }
+        for (index, (_, parent)) in components.iter().enumerate() {
+            let parent_index = parent.and_then(|parent| {
+                components
+                    .iter()
+                    .position(|(component, _)| std::ptr::eq(*component, parent))
+            });
+            if let Some(parent_index) = parent_index {
+                relationships.push(relationship(
+                    &packages[parent_index].spdx_id,
+                    "CONTAINS",
+                    &packages[index].spdx_id,
+                ));
+            }
+        }
+        for dependency in bom.dependencies.iter().flat_map(|d| d.0.iter()) {
+            let Some(from) = ids.get(&dependency.dependency_ref) else {
+                continue;
+            };
+            for to in dependency.dependencies.iter().filter_map(|to| ids.get(to)) {
+                relationships.push(relationship(from, "DEPENDS_ON", to));
+            }
+        }
+
+        let name = match root {
+            Some(root) => match &root.version {
+                Some(version) => format!("{}-{}", root.name, version),
+                None => root.name.to_string(),
+            },
+            None => "cyclonedx-bom".to_string(),
+        };
+        let created = metadata
+            .and_then(|metadata| metadata.timestamp.clone())
+            .or_else(|| DateTime::now().ok())
+            .map(|timestamp| timestamp.to_string())
+            .unwrap_or_default();
+        let uuid = match &bom.serial_number {
+            Some(serial_number) => serial_number.to_string().replace("urn:uuid:", ""),
+            #[cfg(feature = "uuid")]
+            None => uuid::Uuid::new_v4().to_string(),
+            // without random UUIDs the creation time has to keep the namespace unique
+            #[cfg(not(feature = "uuid"))]
+            None => sanitize(&created),
+        };
+
+        Self {
+            spdx_version: "SPDX-2.3".to_string(),
+            data_license: "CC0-1.0".to_string(),
+            spdx_id: DOCUMENT_ID.to_string(),
+            document_namespace: format!("https://spdx.org/spdxdocs/{}-{}", sanitize(&name), uuid),
+            name,
+            creation_info: SpdxCreationInfo {
+                created,
+                creators: creators(metadata),
+            },
+            document_describes: Vec::new(),
+            packages,
+            relationships,
+            has_extracted_licensing_infos: extracted_licenses,
+        }
+    }
+}
+
+fn flatten<'a>(
+    components: &'a Components,
+    parent: Option<&'a Component>,
+    out: &mut Vec<(&'a Component, Option<&'a Component>)>,
+) {
+    for component in &components.0 {
+        out.push((component, parent));
+        if let Some(nested) = &component.components {
+            flatten(nested, Some(component), out);
+        }
+    }
+}
+
+fn creators(metadata: Option<&Metadata>) -> Vec<String> {
+    let mut creators = Vec::new();
+    let tools = metadata.and_then(|metadata| metadata.tools.clone());
+    for tool in tools.map(Tools::into_list).unwrap_or_default() {
+        let Some(name) = tool.name else { continue };
+        creators.push(match tool.version {
+            Some(version) => format!("Tool: {}-{}", name, version),
+            None => format!("Tool: {}", name),
+        });
+    }
+    let authors = metadata.and_then(|metadata| metadata.authors.as_ref());
+    for author in authors.into_iter().flatten() {
+        let Some(name) = &author.name else { continue };
+        creators.push(match &author.email {
+            Some(email) => format!("Person: {} ({})", name, email),
+            None => format!("Person: {}", name),
+        });
+    }
+    if creators.is_empty() {
+        creators.push(format!("Tool: cyclonedx-bom-{}", env!("CARGO_PKG_VERSION")));
+    }
+    creators
+}
+
+fn to_package(
+    component: &Component,
+    spdx_id: String,
+    extracted_licenses: &mut Vec<SpdxExtractedLicense>,
+) -> SpdxPackage {
+    let external_reference = |reference_type: ExternalReferenceType| {
+        component
+            .external_references
+            .iter()
+            .flat_map(|references| references.0.iter())
+            .find(|reference| reference.external_reference_type == reference_type)
+            .map(|reference| reference.url.0.clone())
+    };
+
+    let mut external_refs = Vec::new();
+    if let Some(purl) = &component.purl {
+        external_refs.push(SpdxExternalRef {
+            reference_category: "PACKAGE-MANAGER".to_string(),
+            reference_type: "purl".to_string(),
+            reference_locator: purl.0.clone(),
+        });
+    }
+    if let Some(cpe) = &component.cpe {
+        let reference_type = match cpe.0.starts_with("cpe:2.3:") {
+            true => "cpe23Type",
+            false => "cpe22Type",
+        };
+        external_refs.push(SpdxExternalRef {
+            reference_category: "SECURITY".to_string(),
+            reference_type: reference_type.to_string(),
+            reference_locator: cpe.0.clone(),
+        });
+    }
+
+    SpdxPackage {
+        name: component.name.to_string(),
+        spdx_id,
+        version_info: component.version.as_ref().map(ToString::to_string),
+        supplier: component
+            .supplier
+            .as_ref()
+            .and_then(|supplier| supplier.name.as_ref())
+            .map(|name| format!("Organization: {}", name)),
+        originator: component
+            .author
+            .as_ref()
+            .map(|author| format!("Person: {}", author)),
+        download_location: external_reference(ExternalReferenceType::Distribution)
+            .unwrap_or_else(|| NOASSERTION.to_string()),
+        files_analyzed: Some(false),
+        homepage: external_reference(ExternalReferenceType::Website),
+        license_concluded: Some(NOASSERTION.to_string()),
+        license_declared: Some(
+            component
+                .licenses
+                .as_ref()
+                .and_then(|licenses| license_expression(licenses, extracted_licenses))
+                .unwrap_or_else(|| NOASSERTION.to_string()),
+        ),
+        copyright_text: Some(
+            component
+                .copyright
+                .as_ref()
+                .map_or_else(|| NOASSERTION.to_string(), ToString::to_string),
+        ),
+        description: component.description.as_ref().map(ToString::to_string),
+        primary_package_purpose: Some(purpose(&component.component_type).to_string()),
+        checksums: component
+            .hashes
+            .iter()
+            .flat_map(|hashes| hashes.0.iter())
+            .filter_map(|hash| {
+                Some(SpdxChecksum {
+                    algorithm: checksum_algorithm(&hash.alg)?.to_string(),
+                    checksum_value: hash.content.0.clone(),
+                })
+            })
+            .collect(),
+        external_refs,
+    }
+}
+
+/// Combines the licenses of a component into a single SPDX expression
+fn license_expression(
+    licenses: &Licenses,
+    extracted_licenses: &mut Vec<SpdxExtractedLicense>,
+) -> Option<String> {
+    let expressions: Vec<String> = licenses
+        .0
+        .iter()
+        .map(|license| match license {
+            LicenseChoice::Expression(expression) => expression.0.clone(),
+            LicenseChoice::License(license) => match &license.license_identifier {
+                LicenseIdentifier::SpdxId(id) => id.0.clone(),
+                LicenseIdentifier::Name(name) => {
+                    let license_id = format!("LicenseRef-{}", sanitize(name));
+                    if !extracted_licenses
+                        .iter()
+                        .any(|e| e.license_id == license_id)
+                    {
+                        let text = license
+                            .text
+                            .as_ref()
+                            .filter(|text| text.encoding.is_none())
+                            .map_or_else(|| name.to_string(), |text| text.content.clone());
+                        extracted_licenses.push(SpdxExtractedLicense {
+                            license_id: license_id.clone(),
+                            extracted_text: text,
+                            name: Some(name.to_string()),
+                        });
+                    }
+                    license_id
+                }
+            },
+        })
+        .collect();
+
+    match expressions.len() {
+        0 => None,
+        1 => expressions.into_iter().next(),
+        _ => Some(
+            expressions
+                .iter()
+                .map(|expression| match expression.contains(' ') {
+                    true => format!("({})", expression),
+                    false => expression.clone(),
+                })
+                .collect::<Vec<_>>()
+                .join(" AND "),
+        ),
+    }
+}
+
+fn relationship(from: &str, relationship_type: &str, to: &str) -> SpdxRelationship {
+    SpdxRelationship {
+        spdx_element_id: from.to_string(),
+        relationship_type: relationship_type.to_string(),
+        related_spdx_element: to.to_string(),
+    }
+}
+
+/// Replaces the characters that are not allowed in SPDX identifiers
+fn sanitize(value: &str) -> String {
+    value
+        .chars()
+        .map(
+            |c| match c.is_ascii_alphanumeric() || c == '.' || c == '-' {
+                true => c,
+                false => '-',
+            },
+        )
+        .collect()
+}
+
+fn purpose(classification: &Classification) -> &'static str {
+    match classification {
+        Classification::Application => "APPLICATION",
+        Classification::Framework => "FRAMEWORK",
+        Classification::Library => "LIBRARY",
+        Classification::Container => "CONTAINER",
+        Classification::OperatingSystem => "OPERATING-SYSTEM",
+        Classification::Device => "DEVICE",
+        Classification::Firmware => "FIRMWARE",
+        Classification::File => "FILE",
+        _ => "OTHER",
+    }
+}
+
+fn classification(purpose: Option<&str>) -> Classification {
+    match purpose {
+        Some("APPLICATION") => Classification::Application,
+        Some("FRAMEWORK") => Classification::Framework,
+        Some("CONTAINER") => Classification::Container,
+        Some("OPERATING-SYSTEM") => Classification::OperatingSystem,
+        Some("DEVICE") => Classification::Device,
+        Some("FIRMWARE") => Classification::Firmware,
+        Some("FILE") => Classification::File,
+        _ => Classification::Library,
+    }
+}
+
+fn checksum_algorithm(algorithm: &HashAlgorithm) -> Option<&'static str> {
+    match algorithm {
+        HashAlgorithm::MD5 => Some("MD5"),
+        HashAlgorithm::SHA1 => Some("SHA1"),
+        HashAlgorithm::SHA256 => Some("SHA256"),
+        HashAlgorithm::SHA384 => Some("SHA384"),
+        HashAlgorithm::SHA512 => Some("SHA512"),
+        HashAlgorithm::SHA3_256 => Some("SHA3-256"),
+        HashAlgorithm::SHA3_384 => Some("SHA3-384"),
+        HashAlgorithm::SHA3_512 => Some("SHA3-512"),
+        HashAlgorithm::BLAKE2b_256 => Some("BLAKE2b-256"),
+        HashAlgorithm::BLAKE2b_384 => Some("BLAKE2b-384"),
+        HashAlgorithm::BLAKE2b_512 => Some("BLAKE2b-512"),
+        HashAlgorithm::BLAKE3 => Some("BLAKE3"),
+        HashAlgorithm::UnknownHashAlgorithm(_) => None,
+    }
+}
+
+fn hash_algorithm(algorithm: &str) -> Option<HashAlgorithm> {
+    let algorithm = match algorithm {
+        "MD5" => HashAlgorithm::MD5,
+        "SHA1" => HashAlgorithm::SHA1,
+        "SHA256" => HashAlgorithm::SHA256,
+        "SHA384" => HashAlgorithm::SHA384,
+        "SHA512" => HashAlgorithm::SHA512,
+        "SHA3-256" => HashAlgorithm::SHA3_256,
+        "SHA3-384" => HashAlgorithm::SHA3_384,
+        "SHA3-512" => HashAlgorithm::SHA3_512,
+        "BLAKE2b-256" => HashAlgorithm::BLAKE2b_256,
+        "BLAKE2b-384" => HashAlgorithm::BLAKE2b_384,
+        "BLAKE2b-512" => HashAlgorithm::BLAKE2b_512,
+        "BLAKE3" => HashAlgorithm::BLAKE3,
+        _ => return None,
+    };
+    Some(algorithm)
+}
+
+impl From<&SpdxDocument> for Bom {
+    fn from(document: &SpdxDocument) -> Self {
+        let mut components: HashMap<&str, Component> = document
+            .packages
+            .iter()
+            .map(|package| (package.spdx_id.as_str(), to_component(package, document)))
+            .collect();
+
+        // nest contained packages into their container, in the order of the packages
+        let mut parents: HashMap<&str, &str> = HashMap::new();
+        for relationship in &document.relationships {
+            let (parent, child) = match relationship.relationship_type.as_str() {
+                "CONTAINS" => (
+                    &relationship.spdx_element_id,
+                    &relationship.related_spdx_element,
+                ),
+                "CONTAINED_BY" => (
+                    &relationship.related_spdx_element,
+                    &relationship.spdx_element_id,
+                ),
+                _ => continue,
+            };
+            if components.contains_key(parent.as_str()) && components.contains_key(child.as_str()) {
+                parents.entry(child).or_insert(parent);
+            }
+        }
+        let order: Vec<&str> = document
+            .packages
+            .iter()
+            .map(|p| p.spdx_id.as_str())
+            .collect();
+        for id in order.iter().rev() {
+            let Some(parent) = parents.get(id).copied() else {
+                continue;
+            };
+            if is_ancestor(id, parent, &parents) {
+                continue;
+            }
+            if let Some(child) = components.remove(id) {
+                if let Some(parent) = components.get_mut(parent) {
+                    let nested = parent
+                        .components
+                        .get_or_insert_with(|| Components(Vec::new()));
+                    nested.0.insert(0, child);
+                }
+            }
+        }
+
+        let described = document.described();
+        let root = match described.as_slice() {
+            [root] => components.remove(root),
+            _ => None,
+        };
+        let top_level: Vec<Component> = order
+            .iter()
+            .filter_map(|id| components.remove(id))
+            .collect();
+
+        let mut dependencies: Vec<Dependency> = Vec::new();
+        for relationship in &document.relationships {
+            let (from, to) = match relationship.relationship_type.as_str() {
+                "DEPENDS_ON" => (
+                    &relationship.spdx_element_id,
+                    &relationship.related_spdx_element,
+                ),
+                "DEPENDENCY_OF" => (
+                    &relationship.related_spdx_element,
+                    &relationship.spdx_element_id,
+                ),
+                _ => continue,
+            };
+            if !order.contains(&from.as_str()) || !order.contains(&to.as_str()) {
+                continue;
+            }
+            let (from, to) = (bom_ref(from), bom_ref(to));
+            match dependencies.iter_mut().find(|d| d.dependency_ref == from) {
+                Some(dependency) => dependency.dependencies.push(to),
+                None => dependencies.push(Dependency {
+                    dependency_ref: from,
+                    dependencies: vec![to],
+                }),
+            }
+        }
+
+        let mut tools = Vec::new();
+        let mut authors = Vec::new();
+        for creator in &document.creation_info.creators {
+            if let Some(tool) = creator.strip_prefix("Tool:") {
+                tools.push(Tool {
+                    vendor: None,
+                    name: Some(NormalizedString::new(tool.trim())),
+                    version: None,
+                    hashes: None,
+                });
+            } else if let Some(person) = creator.strip_prefix("Person:") {
+                let (name, email) = name_and_email(person);
+                authors.push(OrganizationalContact::new(name, email));
+            }
+        }
+
+        let metadata = Metadata {
+            timestamp: DateTime::try_from(document.creation_info.created.clone()).ok(),
+            tools: (!tools.is_empty()).then_some(Tools::List(tools)),
+            authors: (!authors.is_empty()).then_some(authors),
+            component: root,
+            ..Metadata::default()
+        };
+
+        Bom {
+            metadata: Some(metadata),
+            components: (!top_level.is_empty()).then_some(Components(top_level)),
+            dependencies: (!dependencies.is_empty()).then_some(Dependencies(dependencies)),
+            ..Bom::default()
+        }
+    }
+}
+
+/// Whether `ancestor` is contained in `id`, directly or indirectly
+fn is_ancestor(id: &str, ancestor: &str, parents: &HashMap<&str, &str>) -> bool {
+    let mut current = ancestor;
+    let mut seen = HashSet::new();
+    while let Some(parent) = parents.get(current).copied() {
+        if parent == id {
+            return true;
+        }
+        if !seen.insert(parent) {
+            return false;
+        }
+        current = parent;
+    }
+    false
+}
+
+fn bom_ref(spdx_id: &str) -> String {
+    spdx_id
+        .strip_prefix("SPDXRef-")
+        .unwrap_or(spdx_id)
+        .to_string()
+}
+
+/// Splits `Jane Doe (jane@example.com)` into the name and the email
+fn name_and_email(value: &str) -> (&str, Option<&str>) {
+    let value = value.trim();
+    match value.strip_suffix(')').and_then(|v| v.rsplit_once('(')) {
+        Some((name, email)) => (name.trim(), Some(email.trim())),
+        None => (value, None),
+    }
+}
+
+/// A value other than `NOASSERTION` and `NONE`
+fn asserted(value: &Option<String>) -> Option<&str> {
+    value
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty() && *value != NOASSERTION && *value != "NONE")
+}
+
+fn to_component(package: &SpdxPackage, document: &SpdxDocument) -> Component {
+    let mut component = Component::new(
+        classification(package.primary_package_purpose.as_deref()),
+        &package.name,
+        package.version_info.as_deref().unwrap_or_default(),
+        Some(bom_ref(&package.spdx_id)),
+    );
+    if package.version_info.is_none() {
+        component.version = None;
+    }
+
+    component.supplier = asserted(&package.supplier).map(|supplier| {
+        let name = supplier
+            .strip_prefix("Organization:")
+            .or_else(|| supplier.strip_prefix("Person:"))
+            .unwrap_or(supplier);
+        OrganizationalEntity {
+            name: Some(NormalizedString::new(name_and_email(name).0)),
+            url: None,
+            contact: None,
+            address: None,
+        }
+    });
+    component.author = asserted(&package.originator).map(|originator| {
+        let name = originator
+            .strip_prefix("Person:")
+            .or_else(|| originator.strip_prefix("Organization:"))
+            .unwrap_or(originator);
+        NormalizedString::new(name_and_email(name).0)
+    });
+    component.description = package.description.as_deref().map(NormalizedString::new);
+    component.copyright = asserted(&package.copyright_text).map(NormalizedString::new);
+
+    let license = asserted(&package.license_declared).or(asserted(&package.license_concluded));
+    component.licenses = license.map(|license| Licenses(vec![license_choice(license, document)]));
+
+    let mut external_references = Vec::new();
+    let download_location = Some(package.download_location.clone());
+    let urls = [
+        (ExternalReferenceType::Website, asserted(&package.homepage)),
+        (
+            ExternalReferenceType::Distribution,
+            asserted(&download_location),
+        ),
+    ];
+    for (reference_type, url) in urls {
+        if let Some(url) = url.and_then(|url| Uri::try_from(url.to_string()).ok()) {
+            external_references.push(ExternalReference::new(reference_type, url));
+        }
+    }
+    if !external_references.is_empty() {
+        component.external_references = Some(ExternalReferences(external_references));
+    }
+
+    let hashes: Vec<Hash> = package
+        .checksums
+        .iter()
+        .filter_map(|checksum| {
+            Some(Hash {
+                alg: hash_algorithm(&checksum.algorithm)?,
+                content: HashValue(checksum.checksum_value.clone()),
+            })
+        })
+        .collect();
+    if !hashes.is_empty() {
+        component.hashes = Some(Hashes(hashes));
+    }
+
+    for external_ref in &package.external_refs {
+        match external_ref.reference_type.as_str() {
+            "purl" => component.purl = Some(Purl(external_ref.reference_locator.clone())),
+            "cpe22Type" | "cpe23Type" => {
+                component.cpe = Some(Cpe(external_ref.reference_locator.clone()))
+            }
+            _ => {}
+        }
+    }
+
+    component
+}
+
+fn license_choice(license: &str, document: &SpdxDocument) -> LicenseChoice {
+    let extracted = document
+        .has_extracted_licensing_infos
+        .iter()
+        .find(|extracted| extracted.license_id == license);
+    if let Some(name) = extracted.and_then(|extracted| extracted.name.as_ref()) {
+        return LicenseChoice::License(License::named_license(name));
+    }
+
+    match SpdxExpression::try_from(license.to_string())
+        .or_else(|_| SpdxExpression::parse_lax(license.to_string()))
+    {
+        Ok(expression) => LicenseChoice::Expression(expression),
+        Err(_) => LicenseChoice::License(License::named_license(license)),
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum SpdxError {
+    #[error("Invalid SPDX JSON document: {0}")]
+    InvalidJson(#[from] serde_json::Error),
+
+    #[error("Invalid SPDX tag-value document, line {0} is malformed")]
+    InvalidTagValue(usize),
+
+    #[error("Invalid SPDX tag-value document, the text starting in line {0} is not terminated")]
+    UnterminatedText(usize),
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::external_models::spdx::SpdxIdentifier;
+    use pretty_assertions::assert_eq;
+
+    fn bom() -> Bom {
+        let mut app = Component::new(
+            Classification::Application,
+            "app",
+            "1.0.0",
+            Some("app 1.0.0".to_string()),
+        );
+        app.licenses = Some(Licenses(vec![LicenseChoice::Expression(
+            SpdxExpression::try_from("MIT OR Apache-2.0".to_string()).unwrap(),
+        )]));
+        let mut plugin = Component::new(
+            Classification::Library,
+            "plugin",
+            "0.1.0",
+            Some("plugin".to_string()),
+        );
+        plugin.licenses = Some(Licenses(vec![LicenseChoice::License(
+            License::named_license("Acme Proprietary"),
+        )]));
+        app.components = Some(Components(vec![plugin]));
+
+        let mut lib = Component::new(
+            Classification::Library,
+            "lib",
+            "2.0.0",
+            Some("lib".to_string()),
+        );
+        lib.purl = Some(Purl::new("cargo", "lib", "2.0.0").unwrap());
+        lib.hashes = Some(Hashes(vec![Hash {
+            alg: HashAlgorithm::SHA256,
+            content: HashValue("abcd".to_string()),
+        }]));
+        lib.licenses = Some(Licenses(vec![LicenseChoice::License(License {
+            license_identifier: LicenseIdentifier::SpdxId(
+                SpdxIdentifier::try_from("MIT".to_string()).unwrap(),
+            ),
+            text: None,
+            url: None,
+        })]));
+        lib.description = Some(NormalizedString::new("A library"));
+
+        Bom {
+            metadata: Some(Metadata {
+                timestamp: Some(DateTime::try_from("2024-01-01T00:00:00Z".to_string()).unwrap()),
+                tools: Some(Tools::List(vec![Tool::new(
+                    "CycloneDX",
+                    "cargo-cyclonedx",
+                    "0.5.0",
+                )])),
+                component: Some(app),
+                ..Metadata::default()
+            }),
+            components: Some(Components(vec![lib])),
+            dependencies: Some(Dependencies(vec![Dependency {
+                dependency_ref: "app 1.0.0".to_string(),
+                dependencies: vec!["lib".to_string()],
+            }])),
+            ..Bom::default()
+        }
+    }
+
+    #[test]
+    fn it_should_convert_a_bom_into_an_spdx_document() {
+        let document = SpdxDocument::from(&bom());
+
+        assert_eq!(document.name, "app-1.0.0");
+        assert_eq!(
+            document.creation_info,
+            SpdxCreationInfo {
+                created: "2024-01-01T00:00:00Z".to_string(),
+                creators: vec!["Tool: cargo-cyclonedx-0.5.0".to_string()],
+            }
+        );
+        let ids: Vec<&str> = document
+            .packages
+            .iter()
+            .map(|p| p.spdx_id.as_str())
+            .collect();
+        assert_eq!(
+            ids,
+            vec!["SPDXRef-app-1.0.0", "SPDXRef-plugin", "SPDXRef-lib"]
+        );
+        assert_eq!(
+            document.relationships,
+            vec![
+                relationship("SPDXRef-DOCUMENT", "DESCRIBES", "SPDXRef-app-1.0.0"),
+                relationship("SPDXRef-app-1.0.0", "CONTAINS", "SPDXRef-plugin"),
+                relationship("SPDXRef-app-1.0.0", "DEPENDS_ON", "SPDXRef-lib"),
+            ]
+        );
+        assert_eq!(
+            document.packages[1].license_declared.as_deref(),
+            Some("LicenseRef-Acme-Proprietary")
+        );
+        assert_eq!(
+            document.has_extracted_licensing_infos,
+            vec![SpdxExtractedLicense {
+                license_id: "LicenseRef-Acme-Proprietary".to_string(),
+                extracted_text: "Acme Proprietary".to_string(),
+                name: Some("Acme Proprietary".to_string()),
+            }]
+        );
+        assert_eq!(
+            document.packages[2],
+            SpdxPackage {
+                name: "lib".to_string(),
+                spdx_id: "SPDXRef-lib".to_string(),
+                version_info: Some("2.0.0".to_string()),
+                download_location: "NOASSERTION".to_string(),
+                files_analyzed: Some(false),
+                license_concluded: Some("NOASSERTION".to_string()),
+                license_declared: Some("MIT".to_string()),
+                copyright_text: Some("NOASSERTION".to_string()),
+                description: Some("A library".to_string()),
+                primary_package_purpose: Some("LIBRARY".to_string()),
+                checksums: vec![SpdxChecksum {
+                    algorithm: "SHA256".to_string(),
+                    checksum_value: "abcd".to_string(),
+                }],
+                external_refs: vec![SpdxExternalRef {
+                    reference_category: "PACKAGE-MANAGER".to_string(),
+                    reference_type: "purl".to_string(),
+                    reference_locator: "pkg:cargo/lib@2.0.0".to_string(),
+                }],
+                ..SpdxPackage::default()
+            }
+        );
+    }
+
+    #[test]
+    fn it_should_convert_an_spdx_document_back_into_a_bom() {
+        let original = bom();
+        let converted = Bom::from(&SpdxDocument::from(&original));
+
+        let metadata = converted.metadata.unwrap();
+        let root = metadata.component.unwrap();
+        assert_eq!(root.bom_ref.as_deref(), Some("app-1.0.0"));
+        assert_eq!(root.component_type, Classification::Application);
+        assert_eq!(
+            root.licenses,
+            original.metadata.unwrap().component.unwrap().licenses
+        );
+        let plugin = &root.components.unwrap().0[0];
+        assert_eq!(
+            plugin.licenses,
+            Some(Licenses(vec![LicenseChoice::License(
+                License::named_license("Acme Proprietary")
+            )]))
+        );
+
+        let lib = &converted.components.unwrap().0[0];
+        assert_eq!(lib.purl, Some(Purl::new("cargo", "lib", "2.0.0").unwrap()));
+        assert_eq!(lib.hashes, original.components.unwrap().0[0].hashes);
+        assert_eq!(lib.copyright, None);
+        assert_eq!(
+            converted.dependencies,
+            Some(Dependencies(vec![Dependency {
+                dependency_ref: "app-1.0.0".to_string(),
+                dependencies: vec!["lib".to_string()],
+            }]))
+        );
+        assert_eq!(
+            metadata.timestamp,
+            Some(DateTime::try_from("2024-01-01T00:00:00Z".to_string()).unwrap())
+        );
+    }
+
+    #[test]
+    fn it_should_round_trip_the_json_and_tag_value_formats() {
+        let mut document = SpdxDocument::from(&bom());
+        document.packages[2].description = Some("A library\nwith two lines".to_string());
+
+        let json = document.to_json().unwrap();
+        assert_eq!(SpdxDocument::parse_json(&json).unwrap(), document);
+
+        let tag_value = document.to_tag_value();
+        assert!(tag_value.contains("PackageDescription: <text>A library\nwith two lines</text>\n"));
+        assert!(tag_value.contains("ExternalRef: PACKAGE-MANAGER purl pkg:cargo/lib@2.0.0\n"));
+        assert_eq!(SpdxDocument::parse_tag_value(&tag_value).unwrap(), document);
+    }
+
+    #[test]
+    fn it_should_reject_malformed_tag_values() {
+        assert!(matches!(
+            SpdxDocument::parse_tag_value("SPDXVersion: SPDX-2.3\nnot a tag"),
+            Err(SpdxError::InvalidTagValue(2))
+        ));
+        assert!(matches!(
+            SpdxDocument::parse_tag_value("PackageName: a\nPackageDescription: <text>open"),
+            Err(SpdxError::UnterminatedText(2))
+        ));
+    }
+}
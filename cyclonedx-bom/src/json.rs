@@ -0,0 +1,470 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Preserving the JSON properties this crate doesn't model.
+//!
+//! [`Bom::parse_from_json_with_unknown_fields`](crate::models::bom::Bom::parse_from_json_with_unknown_fields)
+//! returns the properties of every object that were dropped while parsing, and
+//! [`Bom::output_as_json_with_unknown_fields`](crate::models::bom::Bom::output_as_json_with_unknown_fields)
+//! writes them back, so a document written by a newer or extended tool survives a
+//! read-modify-write cycle.
+//!
+//! ```
+//! use cyclonedx_bom::models::bom::{Bom, SpecVersion};
+//!
+//! let input = r#"{
+//!   "bomFormat": "CycloneDX",
+//!   "specVersion": "1.5",
+//!   "version": 1,
+//!   "x-pipeline": "nightly",
+//!   "components": [
+//!     { "type": "library", "bom-ref": "lib", "name": "lib", "x-reviewed": true }
+//!   ]
+//! }"#;
+//! let (mut bom, unknown_fields) = Bom::parse_from_json_with_unknown_fields(input.as_bytes()).unwrap();
+//! assert_eq!(unknown_fields.len(), 2);
+//!
+//! bom.version = 2;
+//! let mut output = Vec::new();
+//! bom.output_as_json_with_unknown_fields(&mut output, SpecVersion::V1_5, &unknown_fields)
+//!     .unwrap();
+//! let output: serde_json::Value = serde_json::from_slice(&output).unwrap();
+//! assert_eq!(output["x-pipeline"], "nightly");
+//! assert_eq!(output["components"][0]["x-reviewed"], true);
+//! ```
+
+use std::fmt;
+
+use serde::de::{MapAccess, SeqAccess, Visitor};
+use serde::ser::SerializeMap;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+
+/// How a JSON document is laid out, see
+/// [`Bom::output_as_json_with_format`](crate::models::bom::Bom::output_as_json_with_format)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum JsonFormat {
+    /// Indented over multiple lines, e.g. for reviews
+    #[default]
+    Pretty,
+    /// On a single line without any whitespace, e.g. for storage
+    Compact,
+}
+
+impl JsonFormat {
+    pub(crate) fn write<W: std::io::Write, T: Serialize>(
+        self,
+        writer: W,
+        value: &T,
+    ) -> Result<(), serde_json::Error> {
+        match self {
+            JsonFormat::Pretty => serde_json::to_writer_pretty(writer, value),
+            JsonFormat::Compact => serde_json::to_writer(writer, value),
+        }
+    }
+}
+
+/// The properties of a JSON document that were not turned into the model
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct UnknownFields(Vec<UnknownField>);
+
+impl UnknownFields {
+    /// Collects the properties of `original` that are missing from `emitted`, the document written
+    /// back from the parsed model
+    pub(crate) fn between(original: &OrderedValue, emitted: &OrderedValue) -> Self {
+        let mut fields = Vec::new();
+        collect(original, emitted, &mut Vec::new(), &mut fields);
+        Self(fields)
+    }
+
+    /// Adds the properties to the objects of `document` they were found in, unless the objects
+    /// already have a property of the same name
+    ///
+    /// The properties are added after the ones of the object. The properties of objects that no
+    /// longer exist are dropped.
+    pub(crate) fn restore(&self, document: &mut OrderedValue) {
+        for field in &self.0 {
+            if let Some(OrderedValue::Object(object)) = resolve(document, &field.path) {
+                if !object.iter().any(|(name, _)| *name == field.name) {
+                    object.push((field.name.clone(), field.value.clone()));
+                }
+            }
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &UnknownField> {
+        self.0.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+/// A property of a JSON object that was not turned into the model
+#[derive(Clone, Debug, PartialEq)]
+pub struct UnknownField {
+    path: Vec<JsonPathSegment>,
+    name: String,
+    value: OrderedValue,
+}
+
+impl UnknownField {
+    /// The path from the root of the document to the object that contains the property
+    pub fn path(&self) -> &[JsonPathSegment] {
+        &self.path
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The value of the property, the properties of its objects in the order of the document
+    /// are not kept
+    pub fn value(&self) -> Value {
+        self.value.to_value()
+    }
+}
+
+/// Shows the path of the property, e.g. `components[bom-ref="lib"].x-reviewed`
+impl std::fmt::Display for UnknownField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (position, segment) in self.path.iter().enumerate() {
+            match segment {
+                JsonPathSegment::Field(name) if position == 0 => write!(f, "{}", name)?,
+                JsonPathSegment::Field(name) => write!(f, ".{}", name)?,
+                JsonPathSegment::Index(index) => write!(f, "[{}]", index)?,
+                JsonPathSegment::BomRef(reference) => write!(f, "[bom-ref={:?}]", reference)?,
+            }
+        }
+        match self.path.is_empty() {
+            true => write!(f, "{}", self.name),
+            false => write!(f, ".{}", self.name),
+        }
+    }
+}
+
+/// A step from a JSON value to one of its children
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum JsonPathSegment {
+    /// The value of a property of an object
+    Field(String),
+    /// The element of an array at an index
+    Index(usize),
+    /// The element of an array with a `bom-ref`, so that it is found again after the array
+    /// was reordered
+    BomRef(String),
+}
+
+/// A JSON value whose objects keep their properties in the order they were read in
+///
+/// The documents written from the structs of the specification have their properties in the
+/// order of the specification, which is kept when the unknown properties are added to them.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum OrderedValue {
+    Object(Vec<(String, OrderedValue)>),
+    Array(Vec<OrderedValue>),
+    Scalar(Value),
+}
+
+impl OrderedValue {
+    pub(crate) fn get(&self, name: &str) -> Option<&OrderedValue> {
+        match self {
+            OrderedValue::Object(object) => object
+                .iter()
+                .find(|(key, _)| key == name)
+                .map(|(_, value)| value),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn as_str(&self) -> Option<&str> {
+        match self {
+            OrderedValue::Scalar(value) => value.as_str(),
+            _ => None,
+        }
+    }
+
+    fn to_value(&self) -> Value {
+        match self {
+            OrderedValue::Object(object) => Value::Object(
+                object
+                    .iter()
+                    .map(|(name, value)| (name.clone(), value.to_value()))
+                    .collect(),
+            ),
+            OrderedValue::Array(array) => Value::Array(array.iter().map(Self::to_value).collect()),
+            OrderedValue::Scalar(value) => value.clone(),
+        }
+    }
+}
+
+impl Serialize for OrderedValue {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            OrderedValue::Object(object) => {
+                let mut map = serializer.serialize_map(Some(object.len()))?;
+                for (name, value) in object {
+                    map.serialize_entry(name, value)?;
+                }
+                map.end()
+            }
+            OrderedValue::Array(array) => array.serialize(serializer),
+            OrderedValue::Scalar(value) => value.serialize(serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for OrderedValue {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(OrderedValueVisitor)
+    }
+}
+
+struct OrderedValueVisitor;
+
+impl<'de> Visitor<'de> for OrderedValueVisitor {
+    type Value = OrderedValue;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a JSON value")
+    }
+
+    fn visit_bool<E>(self, value: bool) -> Result<Self::Value, E> {
+        Ok(OrderedValue::Scalar(value.into()))
+    }
+
+    fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E> {
+        Ok(OrderedValue::Scalar(value.into()))
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E> {
+        Ok(OrderedValue::Scalar(value.into()))
+    }
+
+    fn visit_f64<E>(self, value: f64) -> Result<Self::Value, E> {
+        Ok(OrderedValue::Scalar(value.into()))
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E> {
+        Ok(OrderedValue::Scalar(value.into()))
+    }
+
+    fn visit_string<E>(self, value: String) -> Result<Self::Value, E> {
+        Ok(OrderedValue::Scalar(value.into()))
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E> {
+        Ok(OrderedValue::Scalar(Value::Null))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut array = Vec::new();
+        while let Some(value) = seq.next_element()? {
+            array.push(value);
+        }
+        Ok(OrderedValue::Array(array))
+    }
+
+    /// A repeated property replaces the value of the first one in its place, like in a `Value`
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut object: Vec<(String, OrderedValue)> = Vec::new();
+        while let Some((name, value)) = map.next_entry::<String, OrderedValue>()? {
+            match object.iter_mut().find(|(key, _)| *key == name) {
+                Some((_, existing)) => *existing = value,
+                None => object.push((name, value)),
+            }
+        }
+        Ok(OrderedValue::Object(object))
+    }
+}
+
+fn bom_ref(value: &OrderedValue) -> Option<&str> {
+    value.get("bom-ref").and_then(OrderedValue::as_str)
+}
+
+fn collect(
+    original: &OrderedValue,
+    emitted: &OrderedValue,
+    path: &mut Vec<JsonPathSegment>,
+    fields: &mut Vec<UnknownField>,
+) {
+    match (original, emitted) {
+        (OrderedValue::Object(original), OrderedValue::Object(_)) => {
+            for (name, value) in original {
+                match emitted.get(name) {
+                    Some(emitted) => {
+                        path.push(JsonPathSegment::Field(name.clone()));
+                        collect(value, emitted, path, fields);
+                        path.pop();
+                    }
+                    None => fields.push(UnknownField {
+                        path: path.clone(),
+                        name: name.clone(),
+                        value: value.clone(),
+                    }),
+                }
+            }
+        }
+        (OrderedValue::Array(original), OrderedValue::Array(emitted)) => {
+            for (index, value) in original.iter().enumerate() {
+                let (segment, emitted) = match bom_ref(value) {
+                    Some(reference) => (
+                        JsonPathSegment::BomRef(reference.to_string()),
+                        emitted
+                            .iter()
+                            .find(|emitted| bom_ref(emitted) == Some(reference)),
+                    ),
+                    None => (JsonPathSegment::Index(index), emitted.get(index)),
+                };
+
+                if let Some(emitted) = emitted {
+                    path.push(segment);
+                    collect(value, emitted, path, fields);
+                    path.pop();
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+fn resolve<'a>(
+    document: &'a mut OrderedValue,
+    path: &[JsonPathSegment],
+) -> Option<&'a mut OrderedValue> {
+    path.iter()
+        .try_fold(document, |value, segment| match (segment, value) {
+            (JsonPathSegment::Field(name), OrderedValue::Object(object)) => object
+                .iter_mut()
+                .find(|(key, _)| key == name)
+                .map(|(_, value)| value),
+            (JsonPathSegment::Index(index), OrderedValue::Array(array)) => array.get_mut(*index),
+            (JsonPathSegment::BomRef(reference), OrderedValue::Array(array)) => array
+                .iter_mut()
+                .find(|element| bom_ref(element) == Some(reference.as_str())),
+            _ => None,
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    fn ordered(document: &str) -> OrderedValue {
+        serde_json::from_str(document).unwrap()
+    }
+
+    #[test]
+    fn it_should_collect_the_fields_missing_from_the_emitted_document() {
+        let original = ordered(
+            r#"{
+                "version": 1,
+                "x-top": "top",
+                "components": [
+                    { "name": "a", "x-index": 0 },
+                    { "bom-ref": "b", "name": "b", "x-ref": { "nested": true } }
+                ]
+            }"#,
+        );
+        let emitted = ordered(
+            r#"{
+                "version": 1,
+                "components": [
+                    { "name": "a" },
+                    { "bom-ref": "b", "name": "b" }
+                ]
+            }"#,
+        );
+
+        let fields = UnknownFields::between(&original, &emitted);
+
+        assert_eq!(
+            fields
+                .iter()
+                .map(|field| (field.path().to_vec(), field.name(), field.value()))
+                .collect::<Vec<_>>(),
+            vec![
+                (vec![], "x-top", json!("top")),
+                (
+                    vec![
+                        JsonPathSegment::Field("components".to_string()),
+                        JsonPathSegment::Index(0)
+                    ],
+                    "x-index",
+                    json!(0)
+                ),
+                (
+                    vec![
+                        JsonPathSegment::Field("components".to_string()),
+                        JsonPathSegment::BomRef("b".to_string())
+                    ],
+                    "x-ref",
+                    json!({ "nested": true })
+                ),
+            ]
+        );
+        assert_eq!(
+            fields.iter().map(ToString::to_string).collect::<Vec<_>>(),
+            vec![
+                "x-top",
+                "components[0].x-index",
+                r#"components[bom-ref="b"].x-ref"#,
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_restore_the_fields_into_a_modified_document() {
+        let original = ordered(
+            r#"{
+                "components": [
+                    { "bom-ref": "a", "x-a": "a" },
+                    { "bom-ref": "b", "x-b": { "z": 1, "a": 2 } },
+                    { "bom-ref": "gone", "x-gone": "gone" }
+                ],
+                "x-kept": "original"
+            }"#,
+        );
+        let emitted = ordered(
+            r#"{
+                "components": [{ "bom-ref": "a" }, { "bom-ref": "b" }, { "bom-ref": "gone" }]
+            }"#,
+        );
+        let fields = UnknownFields::between(&original, &emitted);
+
+        let mut modified = ordered(
+            r#"{
+                "x-kept": "modified",
+                "components": [{ "bom-ref": "new" }, { "bom-ref": "b", "name": "b" }, { "bom-ref": "a" }]
+            }"#,
+        );
+        fields.restore(&mut modified);
+
+        assert_eq!(
+            serde_json::to_string(&modified).unwrap(),
+            r#"{"x-kept":"modified","components":[{"bom-ref":"new"},{"bom-ref":"b","name":"b","x-b":{"z":1,"a":2}},{"bom-ref":"a","x-a":"a"}]}"#
+        );
+    }
+}
@@ -0,0 +1,540 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Reading slightly malformed JSON documents.
+//!
+//! [`Bom::parse_from_json_lenient`](crate::models::bom::Bom::parse_from_json_lenient) fixes up
+//! the deviations below before the document is parsed, and reports each of them as a
+//! [`ParseWarning`]:
+//!
+//! - data after the end of the document is ignored
+//! - numbers written as strings, e.g. `"version": "2"`, are read as numbers
+//! - enum values in the wrong case, e.g. `"type": "Library"`, are read as the value of the
+//!   specification
+//!
+//! [`Bom::parse_from_json_recovering`](crate::models::bom::Bom::parse_from_json_recovering)
+//! instead skips the components and vulnerabilities that cannot be read, and returns them in a
+//! [`RecoveryReport`] together with the rest of the document.
+//!
+//! ```
+//! use cyclonedx_bom::lenient::ParseWarning;
+//! use cyclonedx_bom::models::bom::Bom;
+//! use cyclonedx_bom::models::component::Classification;
+//!
+//! let input = r#"{
+//!   "bomFormat": "CycloneDX",
+//!   "specVersion": "1.5",
+//!   "version": "2",
+//!   "components": [{ "type": "LIBRARY", "name": "lib" }]
+//! }"#;
+//! let (bom, warnings) = Bom::parse_from_json_lenient(input.as_bytes()).unwrap();
+//! assert_eq!(bom.version, 2);
+//! assert_eq!(bom.components.unwrap().0[0].component_type, Classification::Library);
+//! assert_eq!(
+//!     warnings[0],
+//!     ParseWarning::EnumCase {
+//!         field: "components[0].type".to_string(),
+//!         value: "LIBRARY".to_string(),
+//!         coerced: "library".to_string(),
+//!     }
+//! );
+//! ```
+
+use serde_json::{Map, Value};
+
+use crate::models::bom::SpecVersion;
+use crate::models::{
+    code::{IssueClassification, PatchClassification},
+    component::{Classification, Scope},
+    component_data::ComponentDataType,
+    composition::AggregateType,
+    external_reference::ExternalReferenceType,
+    hash::HashAlgorithm,
+    lifecycle::Phase,
+    service::DataFlowType,
+    vulnerability_analysis::{
+        ImpactAnalysisJustification, ImpactAnalysisResponse, ImpactAnalysisState,
+    },
+    vulnerability_rating::{ScoreMethod, Severity},
+};
+use crate::validation::{Validate, ValidationResult};
+
+/// A deviation from the specification that was fixed up while reading a document
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum ParseWarning {
+    /// Data after the end of the document was ignored
+    TrailingData { bytes: usize },
+    /// A number written as a string was read as the number
+    NumericString { field: String, value: String },
+    /// An enum value in the wrong case was read as the value of the specification
+    EnumCase {
+        field: String,
+        value: String,
+        coerced: String,
+    },
+}
+
+impl std::fmt::Display for ParseWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseWarning::TrailingData { bytes } => {
+                write!(f, "Ignored {} bytes after the end of the document", bytes)
+            }
+            ParseWarning::NumericString { field, value } => {
+                write!(f, "Read the string {:?} of {} as a number", value, field)
+            }
+            ParseWarning::EnumCase {
+                field,
+                value,
+                coerced,
+            } => write!(f, "Read {:?} of {} as {:?}", value, field, coerced),
+        }
+    }
+}
+
+/// How the value of a field is fixed up, by the key of the object or list of objects that
+/// contains the field and the name of the field
+type Coercion = (&'static str, &'static str, fn(&str) -> bool);
+
+const NUMBERS: &[Coercion] = &[
+    ("", "version", is_u32),
+    ("swid", "tagVersion", is_u32),
+    ("ratings", "score", is_f32),
+];
+
+const ENUMS: &[Coercion] = &[
+    ("components", "type", is_known::<Classification>),
+    ("component", "type", is_known::<Classification>),
+    ("components", "scope", is_known::<Scope>),
+    ("component", "scope", is_known::<Scope>),
+    ("hashes", "alg", is_known::<HashAlgorithm>),
+    (
+        "externalReferences",
+        "type",
+        is_known::<ExternalReferenceType>,
+    ),
+    ("patches", "type", is_known::<PatchClassification>),
+    ("resolves", "type", is_known::<IssueClassification>),
+    ("data", "type", is_known::<ComponentDataType>),
+    ("data", "flow", is_known::<DataFlowType>),
+    ("compositions", "aggregate", is_known::<AggregateType>),
+    ("lifecycles", "phase", is_known::<Phase>),
+    ("ratings", "severity", is_known::<Severity>),
+    ("ratings", "method", is_known::<ScoreMethod>),
+    ("analysis", "state", is_known::<ImpactAnalysisState>),
+    (
+        "analysis",
+        "justification",
+        is_known::<ImpactAnalysisJustification>,
+    ),
+    ("analysis", "response", is_known::<ImpactAnalysisResponse>),
+];
+
+/// An enum of the model with the values of the specification and a variant for any other value
+trait SpecEnum: Validate {
+    fn from_spec_value(value: &str) -> Self;
+}
+
+macro_rules! spec_enum {
+    ($($enum:ty),*) => {
+        $(impl SpecEnum for $enum {
+            fn from_spec_value(value: &str) -> Self {
+                Self::new_unchecked(value)
+            }
+        })*
+    };
+}
+
+spec_enum!(
+    Classification,
+    Scope,
+    HashAlgorithm,
+    ExternalReferenceType,
+    PatchClassification,
+    IssueClassification,
+    ComponentDataType,
+    DataFlowType,
+    AggregateType,
+    Phase,
+    Severity,
+    ScoreMethod,
+    ImpactAnalysisState,
+    ImpactAnalysisJustification,
+    ImpactAnalysisResponse
+);
+
+fn is_known<E: SpecEnum>(value: &str) -> bool {
+    E::from_spec_value(value).validate() == ValidationResult::Passed
+}
+
+fn is_u32(value: &str) -> bool {
+    value.parse::<u32>().is_ok()
+}
+
+fn is_f32(value: &str) -> bool {
+    value.parse::<f32>().is_ok()
+}
+
+/// Reads the first JSON value of the input, and fixes up its deviations from the specification
+pub(crate) fn read_lenient(input: &[u8]) -> Result<(Value, Vec<ParseWarning>), serde_json::Error> {
+    let mut warnings = Vec::new();
+    let mut values = serde_json::Deserializer::from_slice(input).into_iter::<Value>();
+    let mut document = match values.next() {
+        Some(document) => document?,
+        None => serde_json::from_slice(input)?,
+    };
+
+    let rest = &input[values.byte_offset()..];
+    if !rest.iter().all(u8::is_ascii_whitespace) {
+        warnings.push(ParseWarning::TrailingData { bytes: rest.len() });
+    }
+
+    if let Value::Object(object) = &mut document {
+        coerce_object("", object, "", &mut warnings);
+    }
+    Ok((document, warnings))
+}
+
+fn coerce_object(
+    container: &str,
+    object: &mut Map<String, Value>,
+    path: &str,
+    warnings: &mut Vec<ParseWarning>,
+) {
+    for (name, value) in object.iter_mut() {
+        let field = match path {
+            "" => name.clone(),
+            path => format!("{}.{}", path, name),
+        };
+
+        if let Some((_, _, is_number)) = find(NUMBERS, container, name) {
+            if let Value::String(string) = value {
+                if is_number(string.trim()) {
+                    warnings.push(ParseWarning::NumericString {
+                        field: field.clone(),
+                        value: string.clone(),
+                    });
+                    *value = serde_json::from_str(string.trim()).unwrap_or(Value::Null);
+                }
+            }
+        }
+
+        if let Some(&(_, _, is_known)) = find(ENUMS, container, name) {
+            match value {
+                Value::String(string) => coerce_enum(string, is_known, &field, warnings),
+                Value::Array(strings) => {
+                    for (index, value) in strings.iter_mut().enumerate() {
+                        if let Value::String(string) = value {
+                            let field = format!("{}[{}]", field, index);
+                            coerce_enum(string, is_known, &field, warnings);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        coerce_value(name, value, &field, warnings);
+    }
+}
+
+fn coerce_value(container: &str, value: &mut Value, path: &str, warnings: &mut Vec<ParseWarning>) {
+    match value {
+        Value::Object(object) => coerce_object(container, object, path, warnings),
+        Value::Array(values) => {
+            for (index, value) in values.iter_mut().enumerate() {
+                if let Value::Object(object) = value {
+                    let path = format!("{}[{}]", path, index);
+                    coerce_object(container, object, &path, warnings);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+fn find<'a>(coercions: &'a [Coercion], container: &str, name: &str) -> Option<&'a Coercion> {
+    coercions
+        .iter()
+        .find(|(coercion_container, coercion_name, _)| {
+            *coercion_container == container && *coercion_name == name
+        })
+}
+
+fn coerce_enum(
+    value: &mut String,
+    is_known: fn(&str) -> bool,
+    field: &str,
+    warnings: &mut Vec<ParseWarning>,
+) {
+    if is_known(value) {
+        return;
+    }
+
+    let coerced = [value.to_lowercase(), value.to_uppercase()]
+        .into_iter()
+        .find(|candidate| is_known(candidate));
+    if let Some(coerced) = coerced {
+        warnings.push(ParseWarning::EnumCase {
+            field: field.to_string(),
+            value: value.clone(),
+            coerced: coerced.clone(),
+        });
+        *value = coerced;
+    }
+}
+
+/// The entries of a document that were skipped by
+/// [`Bom::parse_from_json_recovering`](crate::models::bom::Bom::parse_from_json_recovering)
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RecoveryReport {
+    pub skipped: Vec<SkippedEntry>,
+}
+
+/// A component or vulnerability that could not be read
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SkippedEntry {
+    /// The path of the entry in the document, e.g. `components[3].components[0]`
+    pub path: String,
+    /// The entry as it was written in the document
+    pub content: String,
+    /// Why the entry could not be read
+    pub error: String,
+}
+
+/// Checks whether an entry can be read in the spec version of the document
+type EntryCheck = fn(&Value) -> Result<(), serde_json::Error>;
+
+fn check<T: serde::de::DeserializeOwned>(value: &Value) -> Result<(), serde_json::Error> {
+    T::deserialize(value).map(|_| ())
+}
+
+/// Removes the components, including nested ones, and the vulnerabilities from the document
+/// that cannot be read in its spec version
+///
+/// A component is checked after its nested components were removed, so one bad nested
+/// component doesn't take its parent with it.
+pub(crate) fn skip_malformed_entries(
+    document: &mut Value,
+    spec_version: SpecVersion,
+) -> RecoveryReport {
+    let (component_check, vulnerability_check): (EntryCheck, Option<EntryCheck>) =
+        match spec_version {
+            #[cfg(feature = "v1_3")]
+            SpecVersion::V1_3 => (check::<crate::specs::v1_3::component::Component>, None),
+            #[cfg(feature = "v1_4")]
+            SpecVersion::V1_4 => (
+                check::<crate::specs::v1_4::component::Component>,
+                Some(check::<crate::specs::v1_4::vulnerability::Vulnerability>),
+            ),
+            #[cfg(feature = "v1_5")]
+            SpecVersion::V1_5 => (
+                check::<crate::specs::v1_5::component::Component>,
+                Some(check::<crate::specs::v1_5::vulnerability::Vulnerability>),
+            ),
+            #[allow(unreachable_patterns)]
+            _ => return RecoveryReport::default(),
+        };
+
+    let mut report = RecoveryReport::default();
+    if let Some(Value::Array(components)) = document.get_mut("components") {
+        skip_malformed_components(components, "components", component_check, &mut report);
+    }
+    if let Some(Value::Array(components)) = document
+        .get_mut("metadata")
+        .and_then(|metadata| metadata.get_mut("component"))
+        .and_then(|component| component.get_mut("components"))
+    {
+        let path = "metadata.component.components";
+        skip_malformed_components(components, path, component_check, &mut report);
+    }
+    if let (Some(Value::Array(vulnerabilities)), Some(vulnerability_check)) =
+        (document.get_mut("vulnerabilities"), vulnerability_check)
+    {
+        skip_malformed(
+            vulnerabilities,
+            "vulnerabilities",
+            vulnerability_check,
+            &mut report,
+        );
+    }
+    report
+}
+
+fn skip_malformed_components(
+    components: &mut Vec<Value>,
+    path: &str,
+    component_check: EntryCheck,
+    report: &mut RecoveryReport,
+) {
+    for (index, component) in components.iter_mut().enumerate() {
+        if let Some(Value::Array(nested)) = component.get_mut("components") {
+            let path = format!("{}[{}].components", path, index);
+            skip_malformed_components(nested, &path, component_check, report);
+        }
+    }
+    skip_malformed(components, path, component_check, report);
+}
+
+fn skip_malformed(
+    entries: &mut Vec<Value>,
+    path: &str,
+    entry_check: EntryCheck,
+    report: &mut RecoveryReport,
+) {
+    let mut index = 0;
+    entries.retain(|entry| {
+        let result = entry_check(entry);
+        if let Err(error) = result.as_ref() {
+            report.skipped.push(SkippedEntry {
+                path: format!("{}[{}]", path, index),
+                content: entry.to_string(),
+                error: error.to_string(),
+            });
+        }
+        index += 1;
+        result.is_ok()
+    });
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn it_should_fix_up_the_deviations() {
+        let input = json!({
+            "version": "3",
+            "components": [{
+                "type": "Library",
+                "swid": { "tagVersion": " 2 " },
+                "hashes": [{ "alg": "sha-256" }, { "alg": "MD5" }],
+                "components": [{ "type": "APPLICATION", "scope": "Required" }]
+            }],
+            "vulnerabilities": [{
+                "ratings": [{ "score": "9.8", "severity": "CRITICAL", "method": "CVSSv31" }],
+                "analysis": { "state": "Not_Affected", "response": ["Update", "unknown-response"] }
+            }],
+            "metadata": { "component": { "type": "Firmware", "version": "1.0" } }
+        });
+        let input = format!("{} \n{{\"trailing\": true}}", input);
+
+        let (document, warnings) = read_lenient(input.as_bytes()).unwrap();
+
+        assert_eq!(
+            document,
+            json!({
+                "version": 3,
+                "components": [{
+                    "type": "library",
+                    "swid": { "tagVersion": 2 },
+                    "hashes": [{ "alg": "SHA-256" }, { "alg": "MD5" }],
+                    "components": [{ "type": "application", "scope": "required" }]
+                }],
+                "vulnerabilities": [{
+                    "ratings": [{ "score": 9.8, "severity": "critical", "method": "CVSSv31" }],
+                    "analysis": { "state": "not_affected", "response": ["update", "unknown-response"] }
+                }],
+                "metadata": { "component": { "type": "firmware", "version": "1.0" } }
+            })
+        );
+        assert_eq!(
+            warnings.iter().map(ToString::to_string).collect::<Vec<_>>(),
+            vec![
+                "Ignored 20 bytes after the end of the document",
+                r#"Read "Required" of components[0].components[0].scope as "required""#,
+                r#"Read "APPLICATION" of components[0].components[0].type as "application""#,
+                r#"Read "sha-256" of components[0].hashes[0].alg as "SHA-256""#,
+                r#"Read the string " 2 " of components[0].swid.tagVersion as a number"#,
+                r#"Read "Library" of components[0].type as "library""#,
+                r#"Read "Firmware" of metadata.component.type as "firmware""#,
+                r#"Read the string "3" of version as a number"#,
+                r#"Read "Update" of vulnerabilities[0].analysis.response[0] as "update""#,
+                r#"Read "Not_Affected" of vulnerabilities[0].analysis.state as "not_affected""#,
+                r#"Read the string "9.8" of vulnerabilities[0].ratings[0].score as a number"#,
+                r#"Read "CRITICAL" of vulnerabilities[0].ratings[0].severity as "critical""#,
+            ]
+        );
+    }
+
+    #[cfg(feature = "v1_5")]
+    #[test]
+    fn it_should_skip_the_malformed_entries() {
+        let mut document = json!({
+            "metadata": {
+                "component": {
+                    "type": "application",
+                    "name": "app",
+                    "components": [{ "type": "library" }]
+                }
+            },
+            "components": [
+                {
+                    "type": "library",
+                    "name": "parent",
+                    "components": [
+                        { "type": "library", "name": "good" },
+                        { "type": "library", "name": ["bad"] }
+                    ]
+                },
+                { "type": "library", "name": "bad", "hashes": {} }
+            ],
+            "vulnerabilities": [{ "id": 1 }, { "id": "CVE-2021-44228" }]
+        });
+
+        let report = skip_malformed_entries(&mut document, SpecVersion::V1_5);
+
+        assert_eq!(
+            document,
+            json!({
+                "metadata": {
+                    "component": { "type": "application", "name": "app", "components": [] }
+                },
+                "components": [{
+                    "type": "library",
+                    "name": "parent",
+                    "components": [{ "type": "library", "name": "good" }]
+                }],
+                "vulnerabilities": [{ "id": "CVE-2021-44228" }]
+            })
+        );
+        assert_eq!(
+            report
+                .skipped
+                .iter()
+                .map(|entry| (entry.path.as_str(), entry.content.as_str()))
+                .collect::<Vec<_>>(),
+            vec![
+                (
+                    "components[0].components[1]",
+                    r#"{"name":["bad"],"type":"library"}"#
+                ),
+                (
+                    "components[1]",
+                    r#"{"hashes":{},"name":"bad","type":"library"}"#
+                ),
+                ("metadata.component.components[0]", r#"{"type":"library"}"#),
+                ("vulnerabilities[0]", r#"{"id":1}"#),
+            ]
+        );
+        assert_eq!(report.skipped[2].error, "missing field `name`");
+    }
+}
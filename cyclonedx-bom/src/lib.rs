@@ -57,24 +57,19 @@
 //!
 //! ```rust
 //! use cyclonedx_bom::prelude::*;
-//! use cyclonedx_bom::models::{
-//!     tool::{Tool, Tools},
-//! };
-//!
-//! let bom = Bom {
-//!     serial_number: Some(
-//!         UrnUuid::new("urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79".to_string())
-//!             .expect("Failed to create UrnUuid"),
-//!     ),
-//!     metadata: Some(Metadata {
-//!         tools: Some(Tools(vec![Tool {
-//!             name: Some(NormalizedString::new("my_tool")),
-//!             ..Tool::default()
-//!         }])),
-//!         ..Metadata::default()
-//!     }),
-//!     ..Bom::default()
-//! };
+//!
+//! let mut tool = Tool::default();
+//! tool.name = Some(NormalizedString::new("my_tool"));
+//!
+//! let mut metadata = Metadata::default();
+//! metadata.tools = Some(Tools::List(vec![tool]));
+//!
+//! let mut bom = Bom::default();
+//! bom.serial_number = Some(
+//!     UrnUuid::new("urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79".to_string())
+//!         .expect("Failed to create UrnUuid"),
+//! );
+//! bom.metadata = Some(metadata);
 //!
 //! let mut output = Vec::<u8>::new();
 //!
@@ -86,8 +81,8 @@
 //!     r#"{
 //!   "bomFormat": "CycloneDX",
 //!   "specVersion": "1.3",
-//!   "version": 1,
 //!   "serialNumber": "urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79",
+//!   "version": 1,
 //!   "metadata": {
 //!     "tools": [
 //!       {
@@ -114,20 +109,74 @@
 //! [`Validate`](crate::validation::Validate) trait to enable you to find invalid data in a parsed
 //! SBOM. An example of this can be seen in the "Read and validate an SBOM" code snippet.
 //!
+//! ### Forward compatibility
+//!
+//! Every CycloneDX release adds fields, so the structs in the `models` module are marked
+//! `#[non_exhaustive]`. Their fields are public and can be read and assigned as usual, but the
+//! structs are created through their constructors, e.g. `Component::new` or `Metadata::default`,
+//! instead of struct expressions. This way new fields can be added without breaking your code.
+//!
 //! ### Prelude
 //!
-//! The library provides a prelude (similar to the [Rust Standard Library's prelude](https://doc.rust-lang.org/std/prelude/index.html)) to make it easier to use the code. The prelude contains commonly used types and traits. To use this in your library, include the following code snippet:
+//! The library provides a prelude (similar to the [Rust Standard Library's prelude](https://doc.rust-lang.org/std/prelude/index.html)) to make it easier to use the code. The prelude contains commonly used types and traits, e.g. the components, licenses, hashes, external references, properties and tools of a BOM. To use this in your library, include the following code snippet:
 //!
 //! ```
 //! use cyclonedx_bom::prelude::*;
 //! ```
 
+// Conversions and XML tag names shared by the formats and spec versions are unused when only some
+// of them are enabled
+#![cfg_attr(
+    not(all(
+        feature = "json",
+        feature = "xml",
+        feature = "v1_3",
+        feature = "v1_4",
+        feature = "v1_5"
+    )),
+    allow(dead_code)
+)]
+// Without a spec version, the functions of the formats only reject every document as unsupported
+#![cfg_attr(
+    not(any(feature = "v1_3", feature = "v1_4", feature = "v1_5")),
+    allow(unreachable_code, unused_variables)
+)]
+
+pub mod analysis;
+#[cfg(feature = "dtrack")]
+pub mod dtrack;
 pub mod errors;
 pub mod external_models;
+#[cfg(feature = "json")]
+pub mod interop;
+#[cfg(feature = "json")]
+pub mod json;
+#[cfg(feature = "json")]
+pub mod lenient;
+#[cfg(any(feature = "json", feature = "xml"))]
+pub mod limits;
+pub mod localization;
+pub mod merge;
 pub mod models;
 pub mod prelude;
+pub mod query;
+pub mod rewrite;
+#[cfg(feature = "specs")]
+pub mod specs;
+#[cfg(all(
+    any(feature = "json", feature = "xml"),
+    feature = "v1_3",
+    feature = "v1_4",
+    feature = "v1_5"
+))]
+pub mod streaming;
 pub mod validation;
+#[cfg(feature = "verify")]
+pub mod verification;
+#[cfg(feature = "xml")]
+pub mod xml;
 
+mod compression;
+#[cfg(not(feature = "specs"))]
 mod specs;
 mod utilities;
-mod xml;
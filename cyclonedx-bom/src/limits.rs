@@ -0,0 +1,423 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Limits for parsing BOMs from untrusted sources.
+//!
+//! The limits are checked on the decompressed input before it is turned into a
+//! [`Bom`](crate::models::bom::Bom), see
+//! [`Bom::parse_from_json_with_limits`](crate::models::bom::Bom::parse_from_json_with_limits) and
+//! [`Bom::parse_from_xml_with_limits`](crate::models::bom::Bom::parse_from_xml_with_limits).
+//!
+//! ```
+//! use cyclonedx_bom::limits::ParseLimits;
+//! use cyclonedx_bom::models::bom::Bom;
+//!
+//! let limits = ParseLimits {
+//!     max_elements: 1_000,
+//!     ..ParseLimits::default()
+//! };
+//! let input = r#"{"bomFormat": "CycloneDX", "specVersion": "1.5", "version": 1}"#;
+//! let bom = Bom::parse_from_json_with_limits(input.as_bytes(), &limits).unwrap();
+//! assert_eq!(bom.version, 1);
+//! ```
+
+use std::io::Read;
+
+#[cfg(feature = "json")]
+use serde::de::{self, DeserializeSeed, Deserializer, MapAccess, SeqAccess, Visitor};
+#[cfg(feature = "xml")]
+use xml::{reader, EventReader, ParserConfig};
+
+use crate::compression::Decompressed;
+#[cfg(feature = "json")]
+use crate::errors::JsonReadError;
+use crate::errors::LimitError;
+#[cfg(feature = "xml")]
+use crate::errors::XmlReadError;
+#[cfg(feature = "xml")]
+use crate::xml::to_xml_read_error;
+
+/// Upper bounds for the size and shape of a BOM document
+///
+/// The defaults are generous for real-world BOMs, but keep the memory and time needed to parse
+/// a document bounded.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseLimits {
+    /// Maximum size in bytes of the document after decompression
+    pub max_size: usize,
+    /// Maximum nesting depth of JSON objects and arrays, or of XML elements
+    pub max_depth: usize,
+    /// Maximum number of JSON values, or of XML elements, in the document
+    pub max_elements: usize,
+    /// Maximum size in bytes of the content of a single attachment, e.g. a license text
+    pub max_attachment_size: usize,
+}
+
+impl Default for ParseLimits {
+    fn default() -> Self {
+        Self {
+            max_size: 256 * 1024 * 1024,
+            max_depth: 64,
+            max_elements: 10_000_000,
+            max_attachment_size: 16 * 1024 * 1024,
+        }
+    }
+}
+
+impl ParseLimits {
+    /// Reads the decompressed input into memory, failing if it is larger than `max_size`
+    pub(crate) fn read_input<R: Read, E: From<LimitError>>(
+        &self,
+        reader: R,
+        io_error: impl FnOnce(std::io::Error) -> E,
+    ) -> Result<Vec<u8>, E> {
+        let mut input = Vec::new();
+        Decompressed::new(reader)
+            .take(self.max_size as u64 + 1)
+            .read_to_end(&mut input)
+            .map_err(io_error)?;
+
+        if input.len() > self.max_size {
+            return Err(LimitError::MaxSizeExceeded {
+                limit: self.max_size,
+            }
+            .into());
+        }
+        Ok(input)
+    }
+
+    fn enter(&self, depth: usize, elements: &mut usize) -> Result<(), LimitError> {
+        if depth > self.max_depth {
+            return Err(LimitError::MaxDepthExceeded {
+                limit: self.max_depth,
+            });
+        }
+        self.count(elements)
+    }
+
+    fn count(&self, elements: &mut usize) -> Result<(), LimitError> {
+        *elements += 1;
+        if *elements > self.max_elements {
+            return Err(LimitError::MaxElementsExceeded {
+                limit: self.max_elements,
+            });
+        }
+        Ok(())
+    }
+
+    fn check_attachment(&self, content: &str) -> Result<(), LimitError> {
+        if content.len() > self.max_attachment_size {
+            return Err(LimitError::MaxAttachmentSizeExceeded {
+                size: content.len(),
+                limit: self.max_attachment_size,
+            });
+        }
+        Ok(())
+    }
+
+    /// Checks a JSON document against the limits without building it
+    ///
+    /// The content of an attachment is the `content` field of an object.
+    #[cfg(feature = "json")]
+    pub(crate) fn check_json(&self, input: &[u8]) -> Result<(), JsonReadError> {
+        let mut scanner = JsonScanner {
+            limits: self,
+            elements: 0,
+            exceeded: None,
+        };
+        let mut deserializer = serde_json::Deserializer::from_slice(input);
+        let result = JsonValue {
+            scanner: &mut scanner,
+            depth: 0,
+            is_attachment: false,
+        }
+        .deserialize(&mut deserializer)
+        .and_then(|()| deserializer.end());
+
+        match (scanner.exceeded, result) {
+            (Some(error), _) => Err(error.into()),
+            (None, result) => Ok(result?),
+        }
+    }
+
+    /// Checks an XML document against the limits without building it
+    ///
+    /// The content of an attachment is the text of a `text`, `attachment` or `image` element.
+    /// Returns the namespace of the root element.
+    #[cfg(feature = "xml")]
+    pub(crate) fn check_xml(&self, input: &[u8]) -> Result<Option<String>, XmlReadError> {
+        let config = ParserConfig::default().trim_whitespace(true);
+        let mut event_reader = EventReader::new_with_config(input, config);
+        let mut elements = 0;
+        let mut open_elements: Vec<String> = vec![];
+        let mut namespace = None;
+
+        loop {
+            let element = open_elements.last().map_or("bom", String::as_str);
+            match event_reader.next().map_err(to_xml_read_error(element))? {
+                reader::XmlEvent::StartElement {
+                    name,
+                    namespace: element_namespace,
+                    ..
+                } => {
+                    if open_elements.is_empty() {
+                        namespace = element_namespace
+                            .get(xml::namespace::NS_NO_PREFIX)
+                            .map(String::from);
+                    }
+                    self.enter(open_elements.len() + 1, &mut elements)?;
+                    open_elements.push(name.local_name);
+                }
+                reader::XmlEvent::EndElement { .. } => {
+                    open_elements.pop();
+                }
+                reader::XmlEvent::Characters(content) | reader::XmlEvent::CData(content)
+                    if ATTACHMENT_TAGS.contains(&element) =>
+                {
+                    self.check_attachment(&content)?;
+                }
+                reader::XmlEvent::EndDocument => return Ok(namespace),
+                _ => {}
+            }
+        }
+    }
+}
+
+#[cfg(feature = "xml")]
+const ATTACHMENT_TAGS: &[&str] = &["text", "attachment", "image"];
+#[cfg(feature = "json")]
+const ATTACHMENT_FIELD: &str = "content";
+
+/// Counts the values of a JSON document, stopping at the first exceeded limit
+#[cfg(feature = "json")]
+struct JsonScanner<'l> {
+    limits: &'l ParseLimits,
+    elements: usize,
+    exceeded: Option<LimitError>,
+}
+
+#[cfg(feature = "json")]
+impl JsonScanner<'_> {
+    fn check<E: de::Error>(&mut self, result: Result<(), LimitError>) -> Result<(), E> {
+        result.map_err(|error| {
+            let message = E::custom(&error);
+            self.exceeded = Some(error);
+            message
+        })
+    }
+}
+
+/// A JSON value at the given depth, which is discarded after it has been checked
+#[cfg(feature = "json")]
+struct JsonValue<'s, 'l> {
+    scanner: &'s mut JsonScanner<'l>,
+    depth: usize,
+    is_attachment: bool,
+}
+
+#[cfg(feature = "json")]
+impl<'de> DeserializeSeed<'de> for JsonValue<'_, '_> {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_any(self)
+    }
+}
+
+#[cfg(feature = "json")]
+impl<'l> JsonValue<'_, 'l> {
+    fn child(&mut self, is_attachment: bool) -> JsonValue<'_, 'l> {
+        JsonValue {
+            scanner: self.scanner,
+            depth: self.depth + 1,
+            is_attachment,
+        }
+    }
+
+    fn scalar<E: de::Error>(self) -> Result<(), E> {
+        let result = self.scanner.limits.count(&mut self.scanner.elements);
+        self.scanner.check(result)
+    }
+}
+
+#[cfg(feature = "json")]
+impl<'de> Visitor<'de> for JsonValue<'_, '_> {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("a JSON value")
+    }
+
+    fn visit_bool<E: de::Error>(self, _value: bool) -> Result<(), E> {
+        self.scalar()
+    }
+
+    fn visit_i64<E: de::Error>(self, _value: i64) -> Result<(), E> {
+        self.scalar()
+    }
+
+    fn visit_u64<E: de::Error>(self, _value: u64) -> Result<(), E> {
+        self.scalar()
+    }
+
+    fn visit_f64<E: de::Error>(self, _value: f64) -> Result<(), E> {
+        self.scalar()
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<(), E> {
+        self.scalar()
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<(), E> {
+        if self.is_attachment {
+            let result = self.scanner.limits.check_attachment(value);
+            self.scanner.check(result)?;
+        }
+        self.scalar()
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(mut self, mut seq: A) -> Result<(), A::Error> {
+        let result = (self.scanner.limits).enter(self.depth + 1, &mut self.scanner.elements);
+        self.scanner.check(result)?;
+        while seq.next_element_seed(self.child(false))?.is_some() {}
+        Ok(())
+    }
+
+    fn visit_map<A: MapAccess<'de>>(mut self, mut map: A) -> Result<(), A::Error> {
+        let result = (self.scanner.limits).enter(self.depth + 1, &mut self.scanner.elements);
+        self.scanner.check(result)?;
+        while let Some(key) = map.next_key::<String>()? {
+            map.next_value_seed(self.child(key == ATTACHMENT_FIELD))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn limits() -> ParseLimits {
+        ParseLimits {
+            max_size: 1024,
+            max_depth: 3,
+            max_elements: 10,
+            max_attachment_size: 8,
+        }
+    }
+
+    #[cfg(feature = "json")]
+    fn json_limit_error(input: &str) -> Option<LimitError> {
+        match limits().check_json(input.as_bytes()) {
+            Ok(()) => None,
+            Err(JsonReadError::LimitExceeded { error }) => Some(error),
+            Err(error) => panic!("unexpected error: {}", error),
+        }
+    }
+
+    #[cfg(feature = "xml")]
+    fn xml_limit_error(input: &str) -> Option<LimitError> {
+        match limits().check_xml(input.as_bytes()) {
+            Ok(_) => None,
+            Err(XmlReadError::LimitExceeded { error }) => Some(error),
+            Err(error) => panic!("unexpected error: {}", error),
+        }
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn it_should_accept_json_within_the_limits() {
+        assert_eq!(
+            json_limit_error(r#"{"a": [1, {"content": "12345678"}], "b": null}"#),
+            None
+        );
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn it_should_reject_json_exceeding_the_limits() {
+        assert_eq!(
+            json_limit_error(r#"{"a": [[{"b": 1}]]}"#),
+            Some(LimitError::MaxDepthExceeded { limit: 3 })
+        );
+        assert_eq!(
+            json_limit_error("[1, 2, 3, 4, 5, 6, 7, 8, 9, 10]"),
+            Some(LimitError::MaxElementsExceeded { limit: 10 })
+        );
+        assert_eq!(
+            json_limit_error(r#"{"text": {"content": "123456789"}}"#),
+            Some(LimitError::MaxAttachmentSizeExceeded { size: 9, limit: 8 })
+        );
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn it_should_report_invalid_json_as_such() {
+        assert!(matches!(
+            limits().check_json(b"{\"a\": "),
+            Err(JsonReadError::JsonElementReadError { .. })
+        ));
+    }
+
+    #[cfg(feature = "xml")]
+    #[test]
+    fn it_should_accept_xml_within_the_limits() {
+        let input = r#"<?xml version="1.0" encoding="utf-8"?>
+<bom xmlns="http://cyclonedx.org/schema/bom/1.5">
+  <component>
+    <text>12345678</text>
+  </component>
+</bom>"#;
+        assert_eq!(
+            limits().check_xml(input.as_bytes()).unwrap(),
+            Some("http://cyclonedx.org/schema/bom/1.5".to_string())
+        );
+    }
+
+    #[cfg(feature = "xml")]
+    #[test]
+    fn it_should_reject_xml_exceeding_the_limits() {
+        assert_eq!(
+            xml_limit_error("<bom><a><b><c/></b></a></bom>"),
+            Some(LimitError::MaxDepthExceeded { limit: 3 })
+        );
+        assert_eq!(
+            xml_limit_error("<bom><a/><a/><a/><a/><a/><a/><a/><a/><a/><a/></bom>"),
+            Some(LimitError::MaxElementsExceeded { limit: 10 })
+        );
+        assert_eq!(
+            xml_limit_error("<bom><license><text>123456789</text></license></bom>"),
+            Some(LimitError::MaxAttachmentSizeExceeded { size: 9, limit: 8 })
+        );
+    }
+
+    #[test]
+    fn it_should_reject_input_exceeding_the_size_limit() {
+        let input = vec![b' '; 1025];
+        let read_input = |input: &[u8]| {
+            limits().read_input(input, |error| panic!("unexpected error: {}", error))
+        };
+
+        assert_eq!(
+            read_input(&input),
+            Err(LimitError::MaxSizeExceeded { limit: 1024 })
+        );
+        assert_eq!(read_input(&input[..1024]), Ok(input[..1024].to_vec()));
+    }
+}
@@ -0,0 +1,473 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Translatable validation and report messages.
+//!
+//! Every message has a stable key, e.g. `validation.uri.invalid`, and a template with
+//! numbered placeholders like `{0}` for its arguments. Consumers should match on the key
+//! instead of the English text, and can render messages in another language by providing
+//! a [`MessageCatalog`]. Messages missing from a catalog fall back to English.
+//!
+//! ```
+//! use cyclonedx_bom::localization::Translations;
+//! use cyclonedx_bom::validation::{FailureReason, ValidationContext};
+//!
+//! let reason = FailureReason::new(r#"Bom ref "a" is not unique"#, ValidationContext::default());
+//! let message = reason.localized_message().unwrap();
+//! assert_eq!(message.key, "validation.bom_ref.not_unique");
+//! assert_eq!(message.args, vec!["a".to_string()]);
+//!
+//! let german = Translations::from_iter([(
+//!     "validation.bom_ref.not_unique",
+//!     r#"Bom-Ref "{0}" ist nicht eindeutig"#,
+//! )]);
+//! assert_eq!(reason.localize(&german), r#"Bom-Ref "a" ist nicht eindeutig"#);
+//! ```
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::iter::FromIterator;
+
+/// The built-in English templates, indexed by message key
+const ENGLISH: &[(&str, &str)] = &[
+    // validation
+    (
+        "validation.annotation.no_subjects",
+        "Annotation has no subjects",
+    ),
+    (
+        "validation.annotation.unknown_subject",
+        "Annotation subject does not exist in the BOM",
+    ),
+    (
+        "validation.attached_text.not_base64",
+        "Content is not Base64 encoded",
+    ),
+    ("validation.attached_text.unknown_encoding", "Unknown encoding"),
+    (
+        "validation.bom_link.invalid",
+        "BomLink does not conform to the BOM-Link syntax",
+    ),
+    ("validation.bom_ref.not_unique", r#"Bom ref "{0}" is not unique"#),
+    (
+        "validation.component.data_not_allowed",
+        "Data is only allowed on components of type data",
+    ),
+    (
+        "validation.composition.unknown_reference",
+        "Composition reference does not exist in the BOM",
+    ),
+    (
+        "validation.composition.unknown_aggregate",
+        "Unknown aggregate type",
+    ),
+    (
+        "validation.contact.invalid_email",
+        "Email does not conform to RFC 5321: {0}",
+    ),
+    (
+        "validation.contact.invalid_phone",
+        "Phone number is not valid",
+    ),
+    (
+        "validation.cpe.invalid",
+        "Cpe does not match regular expression",
+    ),
+    (
+        "validation.cpe.not_formatted_string",
+        "Cpe is not a CPE 2.3 formatted string",
+    ),
+    (
+        "validation.cvss.score_mismatch",
+        "Score {0} does not match the base score {1} of the CVSS vector",
+    ),
+    (
+        "validation.cvss.version_mismatch",
+        "CVSS vector version {0} does not match the score method {1}",
+    ),
+    (
+        "validation.date_time.invalid",
+        "DateTime does not conform to ISO 8601",
+    ),
+    (
+        "validation.dependency.unknown_reference",
+        "Dependency reference does not exist in the BOM",
+    ),
+    (
+        "validation.hash.invalid",
+        "HashValue does not match regular expression",
+    ),
+    ("validation.hash.unknown_algorithm", "Unknown HashAlgorithm"),
+    (
+        "validation.locale.invalid",
+        "Locale does not conform to BCP 47: {0}",
+    ),
+    (
+        "validation.mime_type.invalid",
+        "MimeType does not conform to RFC 6838: {0}",
+    ),
+    (
+        "validation.normalized_string.invalid",
+        "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n",
+    ),
+    (
+        "validation.omnibor_id.invalid",
+        "OmniborId does not conform to the gitoid syntax",
+    ),
+    (
+        "validation.purl.invalid",
+        "Purl does not conform to Package URL spec: {0}",
+    ),
+    (
+        "validation.spec_version.classification",
+        "Classification {0} was introduced in spec version {1} and is not allowed in {2}",
+    ),
+    (
+        "validation.spec_version.external_reference_type",
+        "External reference type {0} was introduced in spec version {1} and is not allowed in {2}",
+    ),
+    (
+        "validation.spec_version.hash_algorithm",
+        "Hash algorithm {0} was introduced in spec version {1} and is not allowed in {2}",
+    ),
+    (
+        "validation.spdx_expression.invalid",
+        "SPDX expression is not valid",
+    ),
+    (
+        "validation.spdx_identifier.invalid",
+        "SPDX identifier is not valid",
+    ),
+    (
+        "validation.swhid.invalid",
+        "Swhid does not conform to the SWHID syntax",
+    ),
+    (
+        "validation.uri.invalid",
+        "Uri does not conform to RFC 3986",
+    ),
+    (
+        "validation.urn_uuid.invalid",
+        "UrnUuid does not match regular expression",
+    ),
+    (
+        "validation.vers.invalid",
+        "Vers does not conform to the VERS specification: {0}",
+    ),
+    (
+        "validation.unknown.classification",
+        "Unknown classification",
+    ),
+    (
+        "validation.unknown.component_data_type",
+        "Unknown component data type",
+    ),
+    (
+        "validation.unknown.data_flow",
+        "Unknown data flow type",
+    ),
+    (
+        "validation.unknown.external_reference_type",
+        "Unknown external reference type",
+    ),
+    (
+        "validation.unknown.issue_classification",
+        "Unknown issue classification",
+    ),
+    (
+        "validation.unknown.lifecycle_phase",
+        "Unknown lifecycle phase",
+    ),
+    (
+        "validation.unknown.patch_classification",
+        "Unknown patch classification",
+    ),
+    ("validation.unknown.scope", "Unknown scope"),
+    (
+        "validation.unknown.score_method",
+        "Unknown score method",
+    ),
+    (
+        "validation.undefined.impact_analysis_justification",
+        "Undefined impact analysis justification",
+    ),
+    (
+        "validation.undefined.impact_analysis_state",
+        "Undefined impact analysis state",
+    ),
+    ("validation.undefined.response", "Undefined response"),
+    ("validation.undefined.severity", "Undefined severity"),
+    ("validation.undefined.status", "Undefined status"),
+    (
+        "validation.undefined.version_range",
+        "Undefined version range",
+    ),
+    // analysis report
+    (
+        "report.orphan",
+        "Component {0} is not reachable from the root of the dependency graph",
+    ),
+    ("report.duplicate", "Component {0} is listed more than once: {1}"),
+    (
+        "report.license_coverage",
+        "{0} of {1} components declare a license",
+    ),
+    ("report.unlicensed", "Component {0} does not declare a license"),
+    (
+        "report.ntia.missing_author",
+        "The BOM names neither an author nor a tool",
+    ),
+    ("report.ntia.missing_timestamp", "The BOM has no timestamp"),
+    ("report.ntia.missing_supplier", "Component {0} has no supplier"),
+    ("report.ntia.missing_version", "Component {0} has no version"),
+    (
+        "report.ntia.missing_unique_identifier",
+        "Component {0} has neither a purl, a CPE nor a SWID",
+    ),
+    (
+        "report.ntia.missing_dependency_relationship",
+        "Component {0} is not part of the dependency graph",
+    ),
+    (
+        "report.composition.unknown_reference",
+        "A composition refers to {0}, which is not part of the BOM",
+    ),
+    (
+        "report.composition.missing_dependencies",
+        "Component {0} is claimed to have complete dependencies, but the dependency graph does not list them",
+    ),
+    (
+        "report.composition.unknown_dependency",
+        "Component {0} is claimed to have complete dependencies, but depends on {1}, which is not part of the BOM",
+    ),
+    (
+        "report.composition.conflicting_assemblies",
+        "The assemblies of {0} are described with different aggregates: {1}",
+    ),
+    (
+        "report.composition.conflicting_dependencies",
+        "The dependencies of {0} are described with different aggregates: {1}",
+    ),
+    (
+        "report.severities",
+        "Vulnerabilities: {0} critical, {1} high, {2} medium, {3} low, {4} info, {5} none, {6} unknown",
+    ),
+];
+
+/// Provides the message templates for a language.
+pub trait MessageCatalog {
+    /// Returns the template for the message `key`, or `None` to fall back to English.
+    fn template(&self, key: &str) -> Option<Cow<'_, str>>;
+}
+
+/// The built-in English messages.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct English;
+
+impl MessageCatalog for English {
+    fn template(&self, key: &str) -> Option<Cow<'_, str>> {
+        english_template(key).map(Cow::Borrowed)
+    }
+}
+
+/// A catalog of translated templates, e.g. loaded from a file of the integrating application.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Translations(HashMap<String, String>);
+
+impl Translations {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, key: impl Into<String>, template: impl Into<String>) {
+        self.0.insert(key.into(), template.into());
+    }
+}
+
+impl<K: Into<String>, V: Into<String>> FromIterator<(K, V)> for Translations {
+    fn from_iter<T: IntoIterator<Item = (K, V)>>(iter: T) -> Self {
+        Self(
+            iter.into_iter()
+                .map(|(key, template)| (key.into(), template.into()))
+                .collect(),
+        )
+    }
+}
+
+impl MessageCatalog for Translations {
+    fn template(&self, key: &str) -> Option<Cow<'_, str>> {
+        self.0
+            .get(key)
+            .map(|template| Cow::Borrowed(template.as_str()))
+    }
+}
+
+/// A message identified by its key, together with the arguments for its template.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LocalizedMessage {
+    pub key: &'static str,
+    pub args: Vec<String>,
+}
+
+impl LocalizedMessage {
+    /// Creates a message with a key from the built-in catalog.
+    ///
+    /// # Panics
+    ///
+    /// Panics in debug builds if the key is unknown.
+    pub(crate) fn new(key: &'static str, args: Vec<String>) -> Self {
+        debug_assert!(english_template(key).is_some(), "unknown message {key}");
+        Self { key, args }
+    }
+
+    /// Renders the message with the template from the catalog, falling back to English
+    pub fn render(&self, catalog: &dyn MessageCatalog) -> String {
+        let template = catalog
+            .template(self.key)
+            .or_else(|| English.template(self.key))
+            .unwrap_or(Cow::Borrowed(self.key));
+
+        let mut rendered = template.into_owned();
+        for (index, arg) in self.args.iter().enumerate() {
+            rendered = rendered.replace(&format!("{{{index}}}"), arg);
+        }
+        rendered
+    }
+}
+
+fn english_template(key: &str) -> Option<&'static str> {
+    ENGLISH
+        .iter()
+        .find(|(k, _)| *k == key)
+        .map(|(_, template)| *template)
+}
+
+/// Finds the key and arguments of an English message by matching it against the built-in templates.
+pub(crate) fn identify(message: &str) -> Option<LocalizedMessage> {
+    ENGLISH.iter().find_map(|(key, template)| {
+        match_template(template, message).map(|args| LocalizedMessage { key, args })
+    })
+}
+
+/// Matches a message against a template with the placeholders `{0}`, `{1}`, ... in ascending order
+fn match_template(template: &str, message: &str) -> Option<Vec<String>> {
+    let mut literals = Vec::new();
+    let mut rest = template;
+    for index in 0.. {
+        match rest.split_once(&format!("{{{index}}}")) {
+            Some((literal, remainder)) => {
+                literals.push(literal);
+                rest = remainder;
+            }
+            None => break,
+        }
+    }
+    literals.push(rest);
+
+    let (first, others) = literals.split_first()?;
+    let mut remaining = message.strip_prefix(first)?;
+    let mut args = Vec::new();
+    for (index, literal) in others.iter().enumerate() {
+        let is_last = index == others.len() - 1;
+        let (arg, after) = if is_last {
+            (remaining.strip_suffix(literal)?, "")
+        } else if literal.is_empty() {
+            return None;
+        } else {
+            remaining.split_once(literal)?
+        };
+        args.push(arg.to_string());
+        remaining = after;
+    }
+
+    remaining.is_empty().then_some(args)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn it_should_have_unique_keys() {
+        for (index, (key, _)) in ENGLISH.iter().enumerate() {
+            assert!(
+                ENGLISH[index + 1..].iter().all(|(other, _)| other != key),
+                "duplicate key {key}"
+            );
+        }
+    }
+
+    #[test]
+    fn it_should_identify_messages() {
+        assert_eq!(
+            identify("Unknown scope"),
+            Some(LocalizedMessage::new("validation.unknown.scope", vec![]))
+        );
+        assert_eq!(
+            identify("Purl does not conform to Package URL spec: missing scheme"),
+            Some(LocalizedMessage::new(
+                "validation.purl.invalid",
+                vec!["missing scheme".to_string()]
+            ))
+        );
+        assert_eq!(
+            identify(
+                "Vulnerabilities: 1 critical, 2 high, 3 medium, 4 low, 5 info, 6 none, 7 unknown"
+            )
+            .unwrap()
+            .args,
+            vec!["1", "2", "3", "4", "5", "6", "7"]
+        );
+        assert_eq!(identify("Something else entirely"), None);
+        assert_eq!(identify("Unknown scope, really"), None);
+    }
+
+    #[test]
+    fn it_should_fall_back_to_english() {
+        let message = LocalizedMessage::new("report.orphan", vec!["a".to_string()]);
+        let translations = Translations::from_iter([("report.unlicensed", "Komponente {0}")]);
+
+        assert_eq!(
+            message.render(&translations),
+            "Component a is not reachable from the root of the dependency graph"
+        );
+    }
+
+    #[test]
+    fn it_should_render_translations() {
+        let message = LocalizedMessage::new(
+            "report.license_coverage",
+            vec!["1".to_string(), "2".to_string()],
+        );
+        let mut translations = Translations::new();
+        translations.insert(
+            "report.license_coverage",
+            "{0} von {1} Komponenten deklarieren eine Lizenz",
+        );
+
+        assert_eq!(
+            message.render(&translations),
+            "1 von 2 Komponenten deklarieren eine Lizenz"
+        );
+        assert_eq!(
+            message.render(&English),
+            "1 of 2 components declare a license"
+        );
+    }
+}
@@ -0,0 +1,767 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Merges many BOMs into a single, flat BOM.
+//!
+//! Merging happens in two stages: first the input BOMs are split up and their components
+//! are keyed by purl on several threads, then a single pass reconciles the results.
+//! This keeps the cost linear in the total number of components, no matter how many BOMs are merged.
+//!
+//! Components, services and vulnerabilities that occur in several BOMs are only kept once.
+//! A component is identified by its purl, or by its bom-ref if it has no purl.
+//! The hashes, licenses and external references of all occurrences of a component are combined.
+//! If different components share a bom-ref, the later one gets a new bom-ref and all
+//! references to it from its own BOM are updated.
+//!
+//! The `metadata.component` of every input BOM becomes a regular component of the result.
+//! Compositions, external references and properties of the input BOMs describe those
+//! BOMs as a whole and are not carried over.
+//!
+//! ```
+//! use cyclonedx_bom::merge::{merge_all, MergeOptions};
+//! use cyclonedx_bom::models::bom::Bom;
+//!
+//! let boms = vec![Bom::default(), Bom::default()];
+//! let options = MergeOptions::default().with_progress(|progress| {
+//!     println!("{:?}: {}/{}", progress.stage, progress.completed, progress.total);
+//! });
+//! let merged = merge_all(boms, options);
+//! assert!(merged.components.is_none());
+//! ```
+
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::num::NonZeroUsize;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::models::bom::Bom;
+use crate::models::component::{Component, Components};
+use crate::models::dependency::{Dependencies, Dependency};
+use crate::models::external_reference::ExternalReferences;
+use crate::models::hash::Hashes;
+use crate::models::license::Licenses;
+use crate::models::metadata::Metadata;
+use crate::models::service::{Service, Services};
+use crate::models::vulnerability::{Vulnerabilities, Vulnerability};
+use crate::models::vulnerability_target::VulnerabilityTargets;
+
+/// The stages of [`merge_all`] reported to the progress callback
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MergeStage {
+    /// The input BOMs are being split up and indexed, possibly in parallel
+    Indexing,
+    /// The indexed BOMs are being combined into the result
+    Reconciling,
+}
+
+/// The number of BOMs that have completed a stage
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MergeProgress {
+    pub stage: MergeStage,
+    pub completed: usize,
+    pub total: usize,
+}
+
+type ProgressCallback = Box<dyn Fn(MergeProgress) + Send + Sync>;
+
+/// Options for [`merge_all`]
+#[derive(Default)]
+pub struct MergeOptions {
+    /// The metadata of the merged BOM, e.g. describing the product the BOMs belong to
+    pub metadata: Option<Metadata>,
+    /// The number of threads used for indexing, defaults to the available parallelism
+    pub threads: Option<NonZeroUsize>,
+    progress: Option<ProgressCallback>,
+}
+
+impl MergeOptions {
+    /// Sets a callback that is called every time a BOM completes a stage.
+    /// It may be called from several threads at once.
+    pub fn with_progress(
+        mut self,
+        progress: impl Fn(MergeProgress) + Send + Sync + 'static,
+    ) -> Self {
+        self.progress = Some(Box::new(progress));
+        self
+    }
+
+    fn report(&self, stage: MergeStage, completed: usize, total: usize) {
+        if let Some(progress) = &self.progress {
+            progress(MergeProgress {
+                stage,
+                completed,
+                total,
+            });
+        }
+    }
+}
+
+impl fmt::Debug for MergeOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MergeOptions")
+            .field("metadata", &self.metadata)
+            .field("threads", &self.threads)
+            .field("progress", &self.progress.is_some())
+            .finish()
+    }
+}
+
+/// Merges all BOMs into a new BOM with a fresh serial number.
+pub fn merge_all(boms: impl IntoIterator<Item = Bom>, options: MergeOptions) -> Bom {
+    let boms: Vec<Bom> = boms.into_iter().collect();
+    let total = boms.len();
+
+    let indexed = index_all(boms, &options);
+
+    let mut merged = Merged::default();
+    for (completed, bom) in indexed.into_iter().enumerate() {
+        merged.add(bom);
+        options.report(MergeStage::Reconciling, completed + 1, total);
+    }
+
+    merged.into_bom(options.metadata)
+}
+
+/// Components merged by [`deduplicate_components`]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DeduplicatedComponents {
+    /// The surviving components, in the order in which they first occurred
+    pub components: Vec<Component>,
+    /// Maps the bom-ref of every dropped component to the bom-ref of the component it was merged into
+    pub renamed_refs: HashMap<String, String>,
+}
+
+impl DeduplicatedComponents {
+    /// Returns the bom-ref that replaces the given one
+    pub fn resolve_ref<'a>(&'a self, bom_ref: &'a str) -> &'a str {
+        self.renamed_refs
+            .get(bom_ref)
+            .map_or(bom_ref, String::as_str)
+    }
+
+    /// Rewrites the dependencies to refer to the surviving bom-refs.
+    ///
+    /// Entries that end up with the same bom-ref are combined, and a component that was merged
+    /// into one of its own dependencies no longer depends on itself.
+    pub fn rewrite_dependencies(&self, dependencies: Dependencies) -> Dependencies {
+        let mut rewritten: Vec<Dependency> = Vec::new();
+        let mut indices: HashMap<String, usize> = HashMap::new();
+        for dependency in dependencies.0 {
+            let dependency_ref = self.resolve_ref(&dependency.dependency_ref).to_string();
+            let index = *indices.entry(dependency_ref.clone()).or_insert_with(|| {
+                rewritten.push(Dependency {
+                    dependency_ref: dependency_ref.clone(),
+                    dependencies: Vec::new(),
+                });
+                rewritten.len() - 1
+            });
+            let depends_on = &mut rewritten[index].dependencies;
+            for dependency in &dependency.dependencies {
+                let dependency = self.resolve_ref(dependency);
+                if dependency != dependency_ref && !depends_on.iter().any(|d| d == dependency) {
+                    depends_on.push(dependency.to_string());
+                }
+            }
+        }
+        Dependencies(rewritten)
+    }
+}
+
+/// Merges the components that share a purl, e.g. because they were collected from several BOMs.
+///
+/// The first component with a purl survives and receives the hashes, licenses and
+/// external references of the later ones that it does not have yet.
+/// Components without a purl are kept as they are, and nested components are not considered.
+/// Use [`DeduplicatedComponents::rewrite_dependencies`] to update the dependency graph afterwards.
+pub fn deduplicate_components(
+    components: impl IntoIterator<Item = Component>,
+) -> DeduplicatedComponents {
+    let mut deduplicated = DeduplicatedComponents::default();
+    let mut purls: HashMap<String, usize> = HashMap::new();
+    for component in components {
+        let Some(purl) = component.purl.as_ref().map(|purl| purl.to_string()) else {
+            deduplicated.components.push(component);
+            continue;
+        };
+        match purls.get(&purl) {
+            Some(&existing) => {
+                let survivor = &mut deduplicated.components[existing];
+                if let (Some(old), Some(new)) = (&component.bom_ref, &survivor.bom_ref) {
+                    if old != new {
+                        deduplicated.renamed_refs.insert(old.clone(), new.clone());
+                    }
+                }
+                combine_components(survivor, component);
+            }
+            None => {
+                purls.insert(purl, deduplicated.components.len());
+                deduplicated.components.push(component);
+            }
+        }
+    }
+    deduplicated
+}
+
+/// Adds the hashes, licenses and external references of the duplicate that the survivor is missing
+fn combine_components(survivor: &mut Component, duplicate: Component) {
+    fn union<T: PartialEq>(survivor: &mut Vec<T>, duplicate: Vec<T>) {
+        for item in duplicate {
+            if !survivor.contains(&item) {
+                survivor.push(item);
+            }
+        }
+    }
+
+    if let Some(hashes) = duplicate.hashes {
+        union(
+            &mut survivor.hashes.get_or_insert_with(|| Hashes(Vec::new())).0,
+            hashes.0,
+        );
+    }
+    if let Some(licenses) = duplicate.licenses {
+        union(
+            &mut survivor
+                .licenses
+                .get_or_insert_with(|| Licenses(Vec::new()))
+                .0,
+            licenses.0,
+        );
+    }
+    if let Some(references) = duplicate.external_references {
+        union(
+            &mut survivor
+                .external_references
+                .get_or_insert_with(|| ExternalReferences(Vec::new()))
+                .0,
+            references.0,
+        );
+    }
+}
+
+/// A BOM split up into its parts, with the keys used for deduplication already computed
+struct IndexedBom {
+    components: Vec<(String, Component)>,
+    services: Vec<(String, Service)>,
+    dependencies: Vec<Dependency>,
+    vulnerabilities: Vec<(Option<String>, Vulnerability)>,
+}
+
+fn index_all(boms: Vec<Bom>, options: &MergeOptions) -> Vec<IndexedBom> {
+    let total = boms.len();
+    let threads = options
+        .threads
+        .or_else(|| std::thread::available_parallelism().ok())
+        .map_or(1, NonZeroUsize::get)
+        .min(total.max(1));
+    let completed = AtomicUsize::new(0);
+    let index_and_report = &|bom: Bom| {
+        let indexed = index(bom);
+        let completed = completed.fetch_add(1, Ordering::Relaxed) + 1;
+        options.report(MergeStage::Indexing, completed, total);
+        indexed
+    };
+
+    if threads == 1 {
+        return boms.into_iter().map(index_and_report).collect();
+    }
+
+    // Hand out contiguous chunks so that the order of the input is preserved
+    let chunk_size = (total + threads - 1) / threads;
+    let mut chunks: Vec<Vec<Bom>> = Vec::with_capacity(threads);
+    let mut boms = boms.into_iter();
+    loop {
+        let chunk: Vec<Bom> = boms.by_ref().take(chunk_size).collect();
+        if chunk.is_empty() {
+            break;
+        }
+        chunks.push(chunk);
+    }
+
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = chunks
+            .into_iter()
+            .map(|chunk| {
+                scope.spawn(move || chunk.into_iter().map(index_and_report).collect::<Vec<_>>())
+            })
+            .collect();
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().expect("indexing a BOM panicked"))
+            .collect()
+    })
+}
+
+fn index(bom: Bom) -> IndexedBom {
+    let mut components = Vec::new();
+    if let Some(component) = bom.metadata.and_then(|m| m.component) {
+        components.push((component_key(&component), component));
+    }
+    for component in bom.components.map(|c| c.0).unwrap_or_default() {
+        components.push((component_key(&component), component));
+    }
+
+    IndexedBom {
+        components,
+        services: bom
+            .services
+            .map(|s| s.0)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|service| (service_key(&service), service))
+            .collect(),
+        dependencies: bom.dependencies.map(|d| d.0).unwrap_or_default(),
+        vulnerabilities: bom
+            .vulnerabilities
+            .map(|v| v.0)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|vulnerability| (vulnerability_key(&vulnerability), vulnerability))
+            .collect(),
+    }
+}
+
+fn component_key(component: &Component) -> String {
+    match (&component.purl, &component.bom_ref) {
+        (Some(purl), _) => purl.to_string(),
+        (None, Some(bom_ref)) => format!("bom-ref:{bom_ref}"),
+        (None, None) => format!(
+            "name:{}@{}",
+            component.name,
+            component
+                .version
+                .as_ref()
+                .map(|v| v.to_string())
+                .unwrap_or_default()
+        ),
+    }
+}
+
+fn service_key(service: &Service) -> String {
+    match &service.bom_ref {
+        Some(bom_ref) => format!("bom-ref:{bom_ref}"),
+        None => format!(
+            "name:{}@{}",
+            service.name,
+            service
+                .version
+                .as_ref()
+                .map(|v| v.to_string())
+                .unwrap_or_default()
+        ),
+    }
+}
+
+fn vulnerability_key(vulnerability: &Vulnerability) -> Option<String> {
+    vulnerability
+        .id
+        .as_ref()
+        .map(|id| id.to_string())
+        .or_else(|| vulnerability.bom_ref.clone())
+}
+
+#[derive(Default)]
+struct Merged {
+    components: Vec<Component>,
+    component_keys: HashMap<String, usize>,
+    services: Vec<Service>,
+    service_keys: HashMap<String, usize>,
+    dependencies: Vec<Dependency>,
+    dependency_refs: HashMap<String, usize>,
+    vulnerabilities: Vec<Vulnerability>,
+    vulnerability_keys: HashMap<String, usize>,
+    bom_refs: HashSet<String>,
+}
+
+impl Merged {
+    fn add(&mut self, bom: IndexedBom) {
+        // Maps the bom-refs of this BOM to the ones used in the merged BOM
+        let mut renamed: HashMap<String, String> = HashMap::new();
+
+        for (key, mut component) in bom.components {
+            match self.component_keys.get(&key) {
+                Some(&existing) => {
+                    if let (Some(old), Some(new)) =
+                        (&component.bom_ref, &self.components[existing].bom_ref)
+                    {
+                        renamed.insert(old.clone(), new.clone());
+                    }
+                    combine_components(&mut self.components[existing], component);
+                }
+                None => {
+                    self.claim_component_refs(&mut component, &mut renamed);
+                    self.component_keys.insert(key, self.components.len());
+                    self.components.push(component);
+                }
+            }
+        }
+
+        for (key, mut service) in bom.services {
+            match self.service_keys.get(&key) {
+                Some(&existing) => {
+                    if let (Some(old), Some(new)) =
+                        (&service.bom_ref, &self.services[existing].bom_ref)
+                    {
+                        renamed.insert(old.clone(), new.clone());
+                    }
+                }
+                None => {
+                    self.claim_service_refs(&mut service, &mut renamed);
+                    self.service_keys.insert(key, self.services.len());
+                    self.services.push(service);
+                }
+            }
+        }
+
+        let rename = |bom_ref: String| renamed.get(&bom_ref).cloned().unwrap_or(bom_ref);
+
+        for dependency in bom.dependencies {
+            let dependency_ref = rename(dependency.dependency_ref);
+            let index = *self
+                .dependency_refs
+                .entry(dependency_ref.clone())
+                .or_insert_with(|| {
+                    self.dependencies.push(Dependency {
+                        dependency_ref,
+                        dependencies: Vec::new(),
+                    });
+                    self.dependencies.len() - 1
+                });
+            let depends_on = &mut self.dependencies[index].dependencies;
+            for dependency in dependency.dependencies.into_iter().map(rename) {
+                if !depends_on.contains(&dependency) {
+                    depends_on.push(dependency);
+                }
+            }
+        }
+
+        for (key, mut vulnerability) in bom.vulnerabilities {
+            for target in vulnerability
+                .vulnerability_targets
+                .iter_mut()
+                .flat_map(|t| t.0.iter_mut())
+            {
+                target.bom_ref = rename(std::mem::take(&mut target.bom_ref));
+            }
+
+            let existing = key.as_ref().and_then(|k| self.vulnerability_keys.get(k));
+            match existing {
+                Some(&existing) => {
+                    let targets = self.vulnerabilities[existing]
+                        .vulnerability_targets
+                        .get_or_insert_with(|| VulnerabilityTargets(Vec::new()));
+                    for target in vulnerability
+                        .vulnerability_targets
+                        .map(|t| t.0)
+                        .unwrap_or_default()
+                    {
+                        if !targets.0.iter().any(|t| t.bom_ref == target.bom_ref) {
+                            targets.0.push(target);
+                        }
+                    }
+                }
+                None => {
+                    if let Some(key) = key {
+                        self.vulnerability_keys
+                            .insert(key, self.vulnerabilities.len());
+                    }
+                    self.vulnerabilities.push(vulnerability);
+                }
+            }
+        }
+    }
+
+    /// Returns a bom-ref that is not used yet, based on the given one
+    fn claim_ref(&mut self, bom_ref: &str, renamed: &mut HashMap<String, String>) -> String {
+        let mut unique = bom_ref.to_string();
+        let mut suffix = 1;
+        while self.bom_refs.contains(&unique) {
+            suffix += 1;
+            unique = format!("{bom_ref}-{suffix}");
+        }
+        if unique != bom_ref {
+            renamed.insert(bom_ref.to_string(), unique.clone());
+        }
+        self.bom_refs.insert(unique.clone());
+        unique
+    }
+
+    fn claim_component_refs(
+        &mut self,
+        component: &mut Component,
+        renamed: &mut HashMap<String, String>,
+    ) {
+        if let Some(bom_ref) = component.bom_ref.take() {
+            component.bom_ref = Some(self.claim_ref(&bom_ref, renamed));
+        }
+        for nested in component.components.iter_mut().flat_map(|c| c.0.iter_mut()) {
+            self.claim_component_refs(nested, renamed);
+        }
+    }
+
+    fn claim_service_refs(&mut self, service: &mut Service, renamed: &mut HashMap<String, String>) {
+        if let Some(bom_ref) = service.bom_ref.take() {
+            service.bom_ref = Some(self.claim_ref(&bom_ref, renamed));
+        }
+        for nested in service.services.iter_mut().flat_map(|s| s.0.iter_mut()) {
+            self.claim_service_refs(nested, renamed);
+        }
+    }
+
+    fn into_bom(self, metadata: Option<Metadata>) -> Bom {
+        Bom {
+            metadata,
+            components: (!self.components.is_empty()).then_some(Components(self.components)),
+            services: (!self.services.is_empty()).then_some(Services(self.services)),
+            dependencies: (!self.dependencies.is_empty())
+                .then_some(Dependencies(self.dependencies)),
+            vulnerabilities: (!self.vulnerabilities.is_empty())
+                .then_some(Vulnerabilities(self.vulnerabilities)),
+            ..Bom::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::external_models::normalized_string::NormalizedString;
+    use crate::external_models::spdx::SpdxExpression;
+    use crate::external_models::uri::Purl;
+    use crate::models::component::Classification;
+    use crate::models::hash::{Hash, HashAlgorithm, HashValue};
+    use crate::models::license::LicenseChoice;
+    use crate::models::vulnerability_target::VulnerabilityTarget;
+    use crate::validation::{Validate, ValidationResult};
+    use pretty_assertions::assert_eq;
+    use std::sync::{Arc, Mutex};
+
+    fn component(name: &str, bom_ref: &str) -> Component {
+        let mut component = Component::new(
+            Classification::Library,
+            name,
+            "1.0.0",
+            Some(bom_ref.to_string()),
+        );
+        component.purl = Some(Purl::new("cargo", name, "1.0.0").unwrap());
+        component
+    }
+
+    fn dependency(dependency_ref: &str, dependencies: &[&str]) -> Dependency {
+        Dependency {
+            dependency_ref: dependency_ref.to_string(),
+            dependencies: dependencies.iter().map(|d| d.to_string()).collect(),
+        }
+    }
+
+    fn bom(root: Component, components: Vec<Component>, dependencies: Vec<Dependency>) -> Bom {
+        Bom {
+            metadata: Some(Metadata {
+                component: Some(root),
+                ..Metadata::default()
+            }),
+            components: Some(Components(components)),
+            dependencies: Some(Dependencies(dependencies)),
+            ..Bom::default()
+        }
+    }
+
+    fn refs(bom: &Bom) -> Vec<String> {
+        bom.components
+            .iter()
+            .flat_map(|c| c.0.iter())
+            .map(|c| c.bom_ref.clone().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn it_should_deduplicate_components_by_purl() {
+        let first = bom(
+            component("app", "app"),
+            vec![component("shared", "shared")],
+            vec![dependency("app", &["shared"])],
+        );
+        let second = bom(
+            component("tool", "tool"),
+            vec![component("shared", "shared-other-ref")],
+            vec![
+                dependency("tool", &["shared-other-ref"]),
+                dependency("shared-other-ref", &[]),
+            ],
+        );
+
+        let merged = merge_all([first, second], MergeOptions::default());
+
+        assert_eq!(refs(&merged), vec!["app", "shared", "tool"]);
+        assert_eq!(
+            merged.dependencies,
+            Some(Dependencies(vec![
+                dependency("app", &["shared"]),
+                dependency("tool", &["shared"]),
+                dependency("shared", &[]),
+            ]))
+        );
+        assert_eq!(merged.validate(), ValidationResult::Passed);
+    }
+
+    #[test]
+    fn it_should_combine_duplicates_from_several_sources() {
+        let hash = |content: &str| Hash {
+            alg: HashAlgorithm::SHA256,
+            content: HashValue(content.to_string()),
+        };
+        let mut first = component("shared", "shared");
+        first.hashes = Some(Hashes(vec![hash("a")]));
+        let mut second = component("shared", "shared-other-ref");
+        second.hashes = Some(Hashes(vec![hash("a"), hash("b")]));
+        second.licenses = Some(Licenses(vec![LicenseChoice::Expression(SpdxExpression(
+            "MIT".to_string(),
+        ))]));
+        let mut unidentified = component("local", "local");
+        unidentified.purl = None;
+
+        let deduplicated =
+            deduplicate_components(vec![component("app", "app"), first, unidentified, second]);
+
+        assert_eq!(deduplicated.components.len(), 3);
+        let shared = &deduplicated.components[1];
+        assert_eq!(shared.bom_ref.as_deref(), Some("shared"));
+        assert_eq!(shared.hashes, Some(Hashes(vec![hash("a"), hash("b")])));
+        assert_eq!(
+            shared.licenses,
+            Some(Licenses(vec![LicenseChoice::Expression(SpdxExpression(
+                "MIT".to_string()
+            ))]))
+        );
+        assert_eq!(deduplicated.resolve_ref("shared-other-ref"), "shared");
+        assert_eq!(deduplicated.resolve_ref("app"), "app");
+
+        let dependencies = deduplicated.rewrite_dependencies(Dependencies(vec![
+            dependency("app", &["shared", "shared-other-ref", "local"]),
+            dependency("shared", &[]),
+            dependency("shared-other-ref", &["shared"]),
+        ]));
+        assert_eq!(
+            dependencies,
+            Dependencies(vec![
+                dependency("app", &["shared", "local"]),
+                dependency("shared", &[]),
+            ])
+        );
+    }
+
+    #[test]
+    fn it_should_rename_colliding_bom_refs() {
+        let first = bom(
+            component("app", "root"),
+            vec![component("a", "dep")],
+            vec![dependency("root", &["dep"])],
+        );
+        let second = bom(
+            component("tool", "root"),
+            vec![component("b", "dep")],
+            vec![dependency("root", &["dep"])],
+        );
+
+        let merged = merge_all([first, second], MergeOptions::default());
+
+        assert_eq!(refs(&merged), vec!["root", "dep", "root-2", "dep-2"]);
+        assert_eq!(
+            merged.dependencies,
+            Some(Dependencies(vec![
+                dependency("root", &["dep"]),
+                dependency("root-2", &["dep-2"]),
+            ]))
+        );
+        assert_eq!(merged.validate(), ValidationResult::Passed);
+    }
+
+    #[test]
+    fn it_should_merge_vulnerability_targets() {
+        let vulnerability = |target: &str| {
+            let mut vulnerability = Vulnerability::new(None);
+            vulnerability.id = Some(NormalizedString::new("RUSTSEC-2020-0071"));
+            vulnerability.vulnerability_targets =
+                Some(VulnerabilityTargets(vec![VulnerabilityTarget::new(
+                    target.to_string(),
+                )]));
+            vulnerability
+        };
+        let mut first = bom(component("a", "a"), vec![], vec![]);
+        first.vulnerabilities = Some(Vulnerabilities(vec![vulnerability("a")]));
+        let mut second = bom(component("b", "b"), vec![], vec![]);
+        second.vulnerabilities = Some(Vulnerabilities(vec![vulnerability("b")]));
+
+        let merged = merge_all([first, second], MergeOptions::default());
+
+        let vulnerabilities = merged.vulnerabilities.unwrap();
+        assert_eq!(vulnerabilities.0.len(), 1);
+        assert_eq!(
+            vulnerabilities.0[0].vulnerability_targets,
+            Some(VulnerabilityTargets(vec![
+                VulnerabilityTarget::new("a".to_string()),
+                VulnerabilityTarget::new("b".to_string()),
+            ]))
+        );
+    }
+
+    #[test]
+    fn it_should_preserve_the_order_when_merging_in_parallel() {
+        let boms: Vec<Bom> = (0..100)
+            .map(|i| {
+                bom(
+                    component(&format!("root{i}"), &format!("root{i}")),
+                    vec![component("shared", &format!("shared{i}"))],
+                    vec![dependency(&format!("root{i}"), &[&format!("shared{i}")])],
+                )
+            })
+            .collect();
+        let progress = Arc::new(Mutex::new(Vec::new()));
+        let recorded = progress.clone();
+        let options = MergeOptions {
+            threads: NonZeroUsize::new(4),
+            ..MergeOptions::default()
+        }
+        .with_progress(move |p| recorded.lock().unwrap().push(p));
+
+        let merged = merge_all(boms, options);
+
+        let refs = refs(&merged);
+        assert_eq!(refs.len(), 101);
+        assert_eq!(&refs[..3], &["root0", "shared0", "root1"]);
+        assert_eq!(
+            merged.dependencies.as_ref().unwrap().0[99],
+            dependency("root99", &["shared0"])
+        );
+
+        let progress = progress.lock().unwrap();
+        assert_eq!(progress.len(), 200);
+        assert!(progress.contains(&MergeProgress {
+            stage: MergeStage::Indexing,
+            completed: 100,
+            total: 100,
+        }));
+        assert_eq!(
+            progress.last(),
+            Some(&MergeProgress {
+                stage: MergeStage::Reconciling,
+                completed: 100,
+                total: 100,
+            })
+        );
+    }
+}
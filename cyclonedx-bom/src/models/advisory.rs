@@ -22,7 +22,9 @@ use crate::validation::{Validate, ValidationContext, ValidationResult};
 /// Represents an advisory, a notification of a threat to a component, service, or system.
 ///
 /// Defined via the [XML schema](https://cyclonedx.org/docs/1.4/xml/#type_advisoryType)
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub struct Advisory {
     pub title: Option<NormalizedString>,
     pub url: Uri,
@@ -63,7 +65,8 @@ impl Validate for Advisory {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Advisories(pub Vec<Advisory>);
 
 impl Validate for Advisories {
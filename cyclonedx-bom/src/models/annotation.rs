@@ -0,0 +1,357 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::external_models::date_time::DateTime;
+use crate::models::component::Component;
+use crate::models::composition::BomReference;
+use crate::models::organization::{OrganizationalContact, OrganizationalEntity};
+use crate::models::service::Service;
+use crate::models::signature::Signature;
+use crate::validation::{Validate, ValidationContext, ValidationResult};
+
+/// Comments, notes or explanations made by an annotator about parts of the BOM
+///
+/// Defined via the [CycloneDX XML schema](https://cyclonedx.org/docs/1.5/xml/#type_annotationsType).
+/// Added in version 1.5
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct Annotations(pub Vec<Annotation>);
+
+impl Validate for Annotations {
+    fn validate_with_context(&self, context: ValidationContext) -> ValidationResult {
+        let mut results: Vec<ValidationResult> = vec![];
+
+        for (index, annotation) in self.0.iter().enumerate() {
+            let context = context.with_index(index);
+            results.push(annotation.validate_with_context(context));
+        }
+
+        results
+            .into_iter()
+            .fold(ValidationResult::default(), |acc, result| acc.merge(result))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct Annotation {
+    pub bom_ref: Option<String>,
+    /// The objects in the BOM the annotation is about
+    ///
+    /// Subjects that refer to the BOM itself are checked to exist when validating the [`Bom`](crate::models::bom::Bom).
+    pub subjects: Vec<AnnotationSubject>,
+    pub annotator: Annotator,
+    pub timestamp: DateTime,
+    pub text: String,
+    pub signature: Option<Signature>,
+}
+
+impl Annotation {
+    /// Constructs an `Annotation` of the subjects by an annotator
+    pub fn new(
+        subjects: Vec<AnnotationSubject>,
+        annotator: Annotator,
+        timestamp: DateTime,
+        text: impl ToString,
+    ) -> Self {
+        Self {
+            bom_ref: None,
+            subjects,
+            annotator,
+            timestamp,
+            text: text.to_string(),
+            signature: None,
+        }
+    }
+}
+
+impl Validate for Annotation {
+    fn validate_with_context(&self, context: ValidationContext) -> ValidationResult {
+        let mut results: Vec<ValidationResult> = vec![];
+
+        if self.subjects.is_empty() {
+            results.push(ValidationResult::failure(
+                "Annotation has no subjects",
+                context.with_struct("Annotation", "subjects"),
+            ));
+        }
+
+        for (index, subject) in self.subjects.iter().enumerate() {
+            let context = context
+                .with_struct("Annotation", "subjects")
+                .with_index(index);
+            results.push(subject.validate_with_context(context));
+        }
+
+        let annotator_context = context.with_struct("Annotation", "annotator");
+        results.push(self.annotator.validate_with_context(annotator_context));
+
+        let timestamp_context = context.with_struct("Annotation", "timestamp");
+        results.push(self.timestamp.validate_with_context(timestamp_context));
+
+        results
+            .into_iter()
+            .fold(ValidationResult::default(), |acc, result| acc.merge(result))
+    }
+}
+
+/// The object an annotation is about, either in the same BOM or in another one
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum AnnotationSubject {
+    BomReference(BomReference),
+    BomLink(BomLink),
+}
+
+impl AnnotationSubject {
+    /// Parses a `urn:cdx:` BOM-Link, or takes any other value as a reference into the same BOM
+    pub(crate) fn new_unchecked(value: String) -> Self {
+        if value.starts_with(BOM_LINK_PREFIX) {
+            Self::BomLink(BomLink(value))
+        } else {
+            Self::BomReference(BomReference(value))
+        }
+    }
+}
+
+impl std::fmt::Display for AnnotationSubject {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnnotationSubject::BomReference(BomReference(bom_ref)) => f.write_str(bom_ref),
+            AnnotationSubject::BomLink(bom_link) => bom_link.fmt(f),
+        }
+    }
+}
+
+impl Validate for AnnotationSubject {
+    fn validate_with_context(&self, context: ValidationContext) -> ValidationResult {
+        match self {
+            AnnotationSubject::BomReference(_) => ValidationResult::Passed,
+            AnnotationSubject::BomLink(bom_link) => bom_link.validate_with_context(context),
+        }
+    }
+}
+
+const BOM_LINK_PREFIX: &str = "urn:cdx:";
+
+static BOM_LINK_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"^urn:cdx:([0-9a-f]{8}-[0-9a-f]{4}-[1-5][0-9a-f]{3}-[89ab][0-9a-f]{3}-[0-9a-f]{12})/([1-9][0-9]*)(#(.+))?$",
+    )
+    .expect("Failed to compile regex.")
+});
+
+/// A link to a BOM, or to an element within it, in the form `urn:cdx:serial-number/version#bom-ref`
+///
+/// See the [BOM-Link](https://cyclonedx.org/capabilities/bomlink/) documentation
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct BomLink(pub(crate) String);
+
+impl BomLink {
+    /// Constructs a link to the element `bom_ref` of the BOM with the given serial number and version
+    /// ```
+    /// use cyclonedx_bom::models::annotation::BomLink;
+    /// use cyclonedx_bom::models::bom::UrnUuid;
+    ///
+    /// let serial_number = UrnUuid::new("urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79".to_string()).unwrap();
+    /// let link = BomLink::new(&serial_number, 1, Some("pkg:cargo/serde@1.0.0"));
+    /// assert_eq!(
+    ///     link.to_string(),
+    ///     "urn:cdx:3e671687-395b-41f5-a30f-a58921a69b79/1#pkg:cargo/serde@1.0.0"
+    /// );
+    /// assert_eq!(link.bom_ref(), Some("pkg:cargo/serde@1.0.0"));
+    /// ```
+    pub fn new(
+        serial_number: &crate::models::bom::UrnUuid,
+        version: u32,
+        bom_ref: Option<&str>,
+    ) -> Self {
+        let uuid = serial_number
+            .0
+            .strip_prefix("urn:uuid:")
+            .unwrap_or(&serial_number.0);
+        let link = match bom_ref {
+            Some(bom_ref) => format!("{}{}/{}#{}", BOM_LINK_PREFIX, uuid, version, bom_ref),
+            None => format!("{}{}/{}", BOM_LINK_PREFIX, uuid, version),
+        };
+        Self(link)
+    }
+
+    /// The serial number of the linked BOM as `urn:uuid:` URN
+    pub fn serial_number(&self) -> Option<String> {
+        let captures = BOM_LINK_REGEX.captures(&self.0)?;
+        Some(format!("urn:uuid:{}", &captures[1]))
+    }
+
+    /// The version of the linked BOM
+    pub fn version(&self) -> Option<u32> {
+        let captures = BOM_LINK_REGEX.captures(&self.0)?;
+        captures[2].parse().ok()
+    }
+
+    /// The bom-ref of the linked element, `None` if the link refers to the whole BOM
+    pub fn bom_ref(&self) -> Option<&str> {
+        let captures = BOM_LINK_REGEX.captures(&self.0)?;
+        captures.get(4).map(|bom_ref| bom_ref.as_str())
+    }
+}
+
+impl std::fmt::Display for BomLink {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl Validate for BomLink {
+    fn validate_with_context(&self, context: ValidationContext) -> ValidationResult {
+        match BOM_LINK_REGEX.is_match(&self.0) {
+            true => ValidationResult::Passed,
+            false => ValidationResult::failure(
+                "BomLink does not conform to the BOM-Link syntax",
+                context,
+            ),
+        }
+    }
+}
+
+/// The organization, person, component, or service which created the annotation
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum Annotator {
+    Organization(OrganizationalEntity),
+    Individual(OrganizationalContact),
+    Component(Box<Component>),
+    Service(Box<Service>),
+}
+
+impl Validate for Annotator {
+    fn validate_with_context(&self, context: ValidationContext) -> ValidationResult {
+        match self {
+            Annotator::Organization(organization) => {
+                organization.validate_with_context(context.with_struct("Annotator", "organization"))
+            }
+            Annotator::Individual(individual) => {
+                individual.validate_with_context(context.with_struct("Annotator", "individual"))
+            }
+            Annotator::Component(component) => {
+                component.validate_with_context(context.with_struct("Annotator", "component"))
+            }
+            Annotator::Service(service) => {
+                service.validate_with_context(context.with_struct("Annotator", "service"))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{models::bom::UrnUuid, validation::FailureReason};
+    use pretty_assertions::assert_eq;
+
+    fn annotation(subjects: Vec<AnnotationSubject>) -> Annotation {
+        Annotation {
+            bom_ref: None,
+            subjects,
+            annotator: Annotator::Individual(OrganizationalContact::new("Jane Doe", None)),
+            timestamp: DateTime("2023-09-01T10:00:00Z".to_string()),
+            text: "Reviewed".to_string(),
+            signature: None,
+        }
+    }
+
+    #[test]
+    fn it_should_parse_subjects() {
+        assert_eq!(
+            AnnotationSubject::new_unchecked("component-a".to_string()),
+            AnnotationSubject::BomReference(BomReference("component-a".to_string()))
+        );
+
+        let link = "urn:cdx:3e671687-395b-41f5-a30f-a58921a69b79/2#component-a";
+        let subject = AnnotationSubject::new_unchecked(link.to_string());
+        assert_eq!(subject.to_string(), link);
+        let AnnotationSubject::BomLink(bom_link) = subject else {
+            panic!("expected a BOM-Link");
+        };
+        assert_eq!(
+            Some(bom_link.clone()),
+            Some(BomLink::new(
+                &UrnUuid("urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79".to_string()),
+                2,
+                Some("component-a")
+            ))
+        );
+        assert_eq!(bom_link.version(), Some(2));
+        assert_eq!(bom_link.bom_ref(), Some("component-a"));
+    }
+
+    #[test]
+    fn valid_annotations_should_pass_validation() {
+        let validation_result = Annotations(vec![annotation(vec![
+            AnnotationSubject::BomReference(BomReference("component-a".to_string())),
+            AnnotationSubject::BomLink(BomLink(
+                "urn:cdx:3e671687-395b-41f5-a30f-a58921a69b79/1".to_string(),
+            )),
+        ])])
+        .validate();
+
+        assert_eq!(validation_result, ValidationResult::Passed);
+    }
+
+    #[test]
+    fn invalid_annotations_should_fail_validation() {
+        let mut invalid = annotation(vec![AnnotationSubject::BomLink(BomLink(
+            "urn:cdx:not-a-uuid/1".to_string(),
+        ))]);
+        invalid.timestamp = DateTime("yesterday".to_string());
+
+        let validation_result = Annotations(vec![annotation(vec![]), invalid]).validate();
+
+        assert_eq!(
+            validation_result,
+            ValidationResult::Failed {
+                reasons: vec![
+                    FailureReason::new(
+                        "Annotation has no subjects",
+                        ValidationContext::new()
+                            .with_index(0)
+                            .with_struct("Annotation", "subjects")
+                    ),
+                    FailureReason::new(
+                        "BomLink does not conform to the BOM-Link syntax",
+                        ValidationContext::new()
+                            .with_index(1)
+                            .with_struct("Annotation", "subjects")
+                            .with_index(0)
+                    ),
+                    FailureReason::new(
+                        "DateTime does not conform to ISO 8601",
+                        ValidationContext::new()
+                            .with_index(1)
+                            .with_struct("Annotation", "timestamp")
+                    ),
+                ]
+            }
+        );
+    }
+}
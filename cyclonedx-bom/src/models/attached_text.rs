@@ -17,15 +17,17 @@
  */
 
 use base64::{engine::general_purpose::STANDARD, Engine};
+use thiserror::Error;
 
 use crate::{
-    external_models::normalized_string::NormalizedString,
+    external_models::mime_type::MimeType,
     validation::{Validate, ValidationContext, ValidationResult},
 };
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
 pub struct AttachedText {
-    pub(crate) content_type: Option<NormalizedString>,
+    pub(crate) content_type: Option<MimeType>,
     pub(crate) encoding: Option<Encoding>,
     pub(crate) content: String,
 }
@@ -35,13 +37,125 @@ impl AttachedText {
     ///
     /// - `content_type` - Content type of the attached text (default: `"text/plain"`)
     /// - `content` - Raw content, which will be base64 encoded when added to the BOM
-    pub fn new<T: AsRef<[u8]>>(content_type: Option<NormalizedString>, content: T) -> Self {
+    pub fn new<T: AsRef<[u8]>>(content_type: Option<MimeType>, content: T) -> Self {
         Self {
             content_type,
             encoding: Some(Encoding::Base64),
             content: STANDARD.encode(content),
         }
     }
+
+    /// Construct a new `AttachedText` that holds `content` as is, without encoding it
+    pub fn plain(content_type: Option<MimeType>, content: impl Into<String>) -> Self {
+        Self {
+            content_type,
+            encoding: None,
+            content: content.into(),
+        }
+    }
+
+    /// Construct a new base64 encoded `AttachedText`, detecting the content type from the content
+    ///
+    /// ```
+    /// use cyclonedx_bom::models::attached_text::AttachedText;
+    ///
+    /// let text = AttachedText::detect("MIT License");
+    /// assert_eq!(text.content_type().unwrap().essence(), "text/plain");
+    /// assert_eq!(text.decode_to_string().unwrap(), "MIT License");
+    ///
+    /// let image = AttachedText::detect(b"\x89PNG\r\n\x1a\n");
+    /// assert_eq!(image.content_type().unwrap().essence(), "image/png");
+    /// ```
+    pub fn detect<T: AsRef<[u8]>>(content: T) -> Self {
+        let content_type = detect_content_type(content.as_ref());
+        Self::new(Some(content_type), content)
+    }
+
+    /// The content type of the text, `"text/plain"` when not given
+    pub fn content_type(&self) -> Option<&MimeType> {
+        self.content_type.as_ref()
+    }
+
+    pub fn set_content_type(&mut self, content_type: Option<MimeType>) {
+        self.content_type = content_type;
+    }
+
+    /// Whether the content is stored base64 encoded
+    pub fn is_base64(&self) -> bool {
+        self.encoding == Some(Encoding::Base64)
+    }
+
+    /// The content as stored in the BOM, possibly still encoded
+    pub fn raw_content(&self) -> &str {
+        &self.content
+    }
+
+    /// Returns the content with its encoding removed
+    pub fn decode(&self) -> Result<Vec<u8>, AttachedTextError> {
+        match &self.encoding {
+            None => Ok(self.content.as_bytes().to_vec()),
+            Some(Encoding::Base64) => STANDARD
+                .decode(&self.content)
+                .map_err(|e| AttachedTextError::InvalidBase64(e.to_string())),
+            Some(Encoding::UnknownEncoding(encoding)) => {
+                Err(AttachedTextError::UnknownEncoding(encoding.clone()))
+            }
+        }
+    }
+
+    /// Returns the decoded content as text, failing if it is not valid UTF-8
+    pub fn decode_to_string(&self) -> Result<String, AttachedTextError> {
+        String::from_utf8(self.decode()?).map_err(|_| AttachedTextError::InvalidUtf8)
+    }
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum AttachedTextError {
+    #[error("Content is not Base64 encoded: {}", .0)]
+    InvalidBase64(String),
+
+    #[error("Unknown encoding: {}", .0)]
+    UnknownEncoding(String),
+
+    #[error("Decoded content is not valid UTF-8")]
+    InvalidUtf8,
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for AttachedText {
+    /// Generates either Base64 encoded bytes or plain text
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let content_type = u.arbitrary()?;
+        match u.arbitrary()? {
+            true => Ok(Self::new(content_type, u.arbitrary::<Vec<u8>>()?)),
+            false => Ok(Self::plain(content_type, u.arbitrary::<String>()?)),
+        }
+    }
+}
+
+/// Guesses the content type from well-known file signatures, falling back to
+/// `text/plain` for UTF-8 and `application/octet-stream` for anything else
+fn detect_content_type(content: &[u8]) -> MimeType {
+    const SIGNATURES: &[(&[u8], &str)] = &[
+        (b"\x89PNG\r\n\x1a\n", "image/png"),
+        (b"\xff\xd8\xff", "image/jpeg"),
+        (b"GIF87a", "image/gif"),
+        (b"GIF89a", "image/gif"),
+        (b"%PDF-", "application/pdf"),
+        (b"PK\x03\x04", "application/zip"),
+        (b"\x1f\x8b", "application/gzip"),
+    ];
+
+    let content_type = SIGNATURES
+        .iter()
+        .find(|(signature, _)| content.starts_with(signature))
+        .map(|(_, content_type)| *content_type)
+        .unwrap_or_else(|| match std::str::from_utf8(content) {
+            Ok(_) => "text/plain",
+            Err(_) => "application/octet-stream",
+        });
+
+    MimeType(content_type.to_string())
 }
 
 impl Validate for AttachedText {
@@ -79,10 +193,12 @@ impl Validate for AttachedText {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub(crate) enum Encoding {
     Base64,
     #[doc(hidden)]
+    #[cfg_attr(feature = "arbitrary", arbitrary(skip))]
     UnknownEncoding(String),
 }
 
@@ -123,23 +239,63 @@ mod test {
     #[test]
     fn it_should_construct_attached_text() {
         let actual = AttachedText::new(
-            Some(NormalizedString::new("text/plain")),
+            Some(MimeType::new("text/plain").unwrap()),
             "this text is plain",
         );
         assert_eq!(
             actual,
             AttachedText {
-                content_type: Some(NormalizedString::new("text/plain")),
+                content_type: Some(MimeType::new("text/plain").unwrap()),
                 encoding: Some(Encoding::Base64),
                 content: "dGhpcyB0ZXh0IGlzIHBsYWlu".to_string(),
             }
         )
     }
 
+    #[test]
+    fn it_should_decode_attached_text() {
+        let text = AttachedText::detect("this text is plain");
+        assert_eq!(
+            text.content_type(),
+            Some(&MimeType("text/plain".to_string()))
+        );
+        assert!(text.is_base64());
+        assert_eq!(
+            text.decode_to_string(),
+            Ok("this text is plain".to_string())
+        );
+
+        let binary = AttachedText::detect([0x00, 0xff, 0xfe]);
+        assert_eq!(
+            binary.content_type(),
+            Some(&MimeType("application/octet-stream".to_string()))
+        );
+        assert_eq!(binary.decode(), Ok(vec![0x00, 0xff, 0xfe]));
+        assert_eq!(
+            binary.decode_to_string(),
+            Err(AttachedTextError::InvalidUtf8)
+        );
+
+        let plain = AttachedText::plain(None, "not encoded");
+        assert!(!plain.is_base64());
+        assert_eq!(plain.raw_content(), "not encoded");
+        assert_eq!(plain.decode_to_string(), Ok("not encoded".to_string()));
+
+        let corrupted = AttachedText {
+            content_type: None,
+            encoding: Some(Encoding::Base64),
+            content: "not base64 encoded".to_string(),
+        };
+        assert!(matches!(
+            corrupted.decode(),
+            Err(AttachedTextError::InvalidBase64(_))
+        ));
+    }
+
     #[test]
     fn valid_attached_text_should_pass_validation() {
         let validation_result = AttachedText {
-            content_type: Some(NormalizedString("text/plain".to_string())),
+            content_type: Some(MimeType("text/plain".to_string())),
             encoding: Some(Encoding::Base64),
             content: "dGhpcyB0ZXh0IGlzIHBsYWlu".to_string(),
         }
@@ -151,7 +307,7 @@ mod test {
     #[test]
     fn invalid_attached_text_should_fail_validation() {
         let validation_result = AttachedText {
-            content_type: Some(NormalizedString("spaces and \ttabs".to_string())),
+            content_type: Some(MimeType("spaces and \ttabs".to_string())),
             encoding: Some(Encoding::Base64),
             content: "not base64 encoded".to_string(),
         }
@@ -162,7 +318,7 @@ mod test {
             ValidationResult::Failed {
                 reasons: vec![
                     FailureReason::new(
-                        "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n",
+                        "MimeType does not conform to RFC 6838: missing subtype",
                         ValidationContext::new().with_struct("AttachedText", "content_type")
                     ),
                     FailureReason::new(
@@ -177,7 +333,7 @@ mod test {
     #[test]
     fn an_unknown_encoding_should_fail_validation() {
         let validation_result = AttachedText {
-            content_type: Some(NormalizedString("text/plain".to_string())),
+            content_type: Some(MimeType("text/plain".to_string())),
             encoding: Some(Encoding::UnknownEncoding("unknown".to_string())),
             content: "not base64 encoded".to_string(),
         }
@@ -195,7 +351,7 @@ mod test {
     #[test]
     fn no_supplied_encoding_should_pass_validation() {
         let validation_result = AttachedText {
-            content_type: Some(NormalizedString("text/plain".to_string())),
+            content_type: Some(MimeType("text/plain".to_string())),
             encoding: None,
             content: "not base64 encoded".to_string(),
         }
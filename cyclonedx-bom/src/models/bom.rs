@@ -16,38 +16,66 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
+#[cfg(all(feature = "v1_3", any(feature = "json", feature = "xml")))]
 use std::convert::TryInto;
 use std::fmt;
+#[cfg(any(feature = "json", feature = "xml"))]
+use std::io::Read;
 use std::str::FromStr;
 
 use once_cell::sync::Lazy;
 use regex::Regex;
+#[cfg(feature = "json")]
+use serde::de::{
+    self, value::MapAccessDeserializer, DeserializeSeed, Deserializer, IntoDeserializer, MapAccess,
+    Visitor,
+};
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "json")]
 use serde_json::Value;
-use xml::{EmitterConfig, EventReader, EventWriter, ParserConfig};
-
-use crate::errors::BomError;
-use crate::models::component::{Component, Components};
+#[cfg(feature = "xml")]
+use xml::EventReader;
+
+use crate::analysis::BomStats;
+#[cfg(any(feature = "json", feature = "xml"))]
+use crate::compression::{decompress_slice, Decompressed};
+use crate::errors::{BomError, QueryError};
+use crate::external_models::date_time::{DateTime, DateTimeError};
+use crate::external_models::uri::Uri;
+#[cfg(any(feature = "json", feature = "xml"))]
+use crate::limits::ParseLimits;
+use crate::models::annotation::{AnnotationSubject, Annotations, Annotator, BomLink};
+use crate::models::component::{Component, Components, Scope};
 use crate::models::composition::{BomReference, Compositions};
-use crate::models::dependency::Dependencies;
-use crate::models::external_reference::ExternalReferences;
+use crate::models::dependency::{Dependencies, Dependency};
+use crate::models::external_reference::{
+    ExternalReference, ExternalReferenceType, ExternalReferences,
+};
+use crate::models::formulation::Formulation;
 use crate::models::metadata::Metadata;
+use crate::models::organization::OrganizationalEntity;
 use crate::models::property::Properties;
 use crate::models::service::{Service, Services};
 use crate::models::signature::Signature;
-use crate::models::vulnerability::Vulnerabilities;
-use crate::validation::{Validate, ValidationContext, ValidationPathComponent, ValidationResult};
-use crate::xml::{FromXmlDocument, ToXml};
+use crate::models::tool::{Tool, Tools};
+use crate::models::vulnerability::{Vulnerabilities, Vulnerability};
+use crate::query::{self, Predicate, Selection, Selector};
+use crate::validation::{
+    FailureReason, Validate, ValidationContext, ValidationPathComponent, ValidationResult,
+};
 
 /// Represents the spec version of a BOM.
-#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy)]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub enum SpecVersion {
     #[serde(rename = "1.3")]
     V1_3,
     #[serde(rename = "1.4")]
     V1_4,
+    #[serde(rename = "1.5")]
+    V1_5,
 }
 
 impl FromStr for SpecVersion {
@@ -57,6 +85,7 @@ impl FromStr for SpecVersion {
         match input {
             "1.3" => Ok(SpecVersion::V1_3),
             "1.4" => Ok(SpecVersion::V1_4),
+            "1.5" => Ok(SpecVersion::V1_5),
             s => Err(BomError::UnsupportedSpecVersion(s.to_string())),
         }
     }
@@ -67,12 +96,14 @@ impl ToString for SpecVersion {
         let s = match self {
             SpecVersion::V1_3 => "1.3",
             SpecVersion::V1_4 => "1.4",
+            SpecVersion::V1_5 => "1.5",
         };
         s.to_string()
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
 pub struct Bom {
     pub version: u32,
     pub serial_number: Option<UrnUuid>,
@@ -87,247 +118,1868 @@ pub struct Bom {
     pub vulnerabilities: Option<Vulnerabilities>,
     /// Added in version 1.4
     pub signature: Option<Signature>,
+    /// Added in version 1.5
+    pub formulation: Option<Formulation>,
+    /// Added in version 1.5
+    pub annotations: Option<Annotations>,
 }
 
 impl Bom {
-    /// General function to parse a JSON file, fetches the `specVersion` field first then applies the right conversion.
-    pub fn parse_from_json<R: std::io::Read>(
-        mut reader: R,
-    ) -> Result<Self, crate::errors::JsonReadError> {
-        let json: serde_json::Value = serde_json::from_reader(&mut reader)?;
-
-        if let Some(version) = json.get("specVersion") {
-            let version = version
-                .as_str()
-                .ok_or_else(|| BomError::UnsupportedSpecVersion(version.to_string()))?;
+    /// Starts building a BOM with a serial number, a timestamp and this crate as the tool that
+    /// produced it, see [`BomBuilder`]
+    pub fn builder() -> BomBuilder {
+        BomBuilder::default()
+    }
 
-            match SpecVersion::from_str(version)? {
-                SpecVersion::V1_3 => Ok(crate::specs::v1_3::bom::Bom::deserialize(json)?.into()),
-                SpecVersion::V1_4 => Ok(crate::specs::v1_4::bom::Bom::deserialize(json)?.into()),
+    /// Calls `f` with every component of the BOM to change it in place
+    ///
+    /// This visits the component of the metadata first, then the components of the BOM,
+    /// each followed by its nested components.
+    /// ```
+    /// use cyclonedx_bom::models::bom::Bom;
+    /// use cyclonedx_bom::models::property::{Properties, Property};
+    ///
+    /// let mut bom = Bom::default();
+    /// bom.map_components(|component| {
+    ///     let properties = component.properties.get_or_insert(Properties(vec![]));
+    ///     properties.0.push(Property::new("reviewed", "true"));
+    /// });
+    /// ```
+    pub fn map_components(&mut self, mut f: impl FnMut(&mut Component)) {
+        if let Some(component) = self
+            .metadata
+            .as_mut()
+            .and_then(|metadata| metadata.component.as_mut())
+        {
+            f(component);
+            if let Some(components) = &mut component.components {
+                components.map_components(&mut f);
             }
-        } else {
-            Err(BomError::UnsupportedSpecVersion("No field 'specVersion' found".to_string()).into())
         }
-    }
 
-    /// Parse the input as a JSON document conforming to [version 1.3 of the specification](https://cyclonedx.org/docs/1.3/json/)
-    pub fn parse_from_json_v1_3<R: std::io::Read>(
-        mut reader: R,
-    ) -> Result<Self, crate::errors::JsonReadError> {
-        let bom: crate::specs::v1_3::bom::Bom = serde_json::from_reader(&mut reader)?;
-        Ok(bom.into())
+        if let Some(components) = &mut self.components {
+            components.map_components(&mut f);
+        }
     }
 
-    /// Parse the input as a JSON document conforming to [version 1.3 of the specification](https://cyclonedx.org/docs/1.3/json/)
-    /// from an existing [`Value`].
-    pub fn parse_from_json_value_v1_3(value: Value) -> Result<Self, crate::errors::JsonReadError> {
-        let bom: crate::specs::v1_3::bom::Bom = serde_json::from_value(value)?;
-        Ok(bom.into())
+    /// Calls `f` with every service of the BOM, each followed by its nested services,
+    /// to change it in place
+    pub fn map_services(&mut self, f: impl FnMut(&mut Service)) {
+        if let Some(services) = &mut self.services {
+            services.map_services(f);
+        }
     }
 
-    /// Parse the input as an XML document conforming to [version 1.3 of the specification](https://cyclonedx.org/docs/1.3/xml/)
-    pub fn parse_from_xml_v1_3<R: std::io::Read>(
-        reader: R,
-    ) -> Result<Self, crate::errors::XmlReadError> {
-        let config = ParserConfig::default().trim_whitespace(true);
-        let mut event_reader = EventReader::new_with_config(reader, config);
-        let bom = crate::specs::v1_3::bom::Bom::read_xml_document(&mut event_reader)?;
-        Ok(bom.into())
-    }
+    /// Removes the components whose scope is not in `keep`, together with their nested components,
+    /// and all references to them
+    ///
+    /// Components without a scope are `required`. The component of the metadata is the subject of
+    /// the BOM and always kept. Dependencies, composition entries, vulnerability targets and
+    /// annotation subjects that refer to a removed component are dropped, as are compositions,
+    /// vulnerabilities and annotations that referred to removed components only.
+    /// ```
+    /// use cyclonedx_bom::models::bom::Bom;
+    /// use cyclonedx_bom::models::component::Scope;
+    ///
+    /// # let mut bom = Bom::parse_from_json(r#"{
+    /// #   "bomFormat": "CycloneDX", "specVersion": "1.5", "version": 1,
+    /// #   "components": [
+    /// #     { "type": "library", "bom-ref": "serde", "name": "serde" },
+    /// #     { "type": "library", "bom-ref": "criterion", "name": "criterion", "scope": "excluded" }
+    /// #   ]
+    /// # }"#.as_bytes()).unwrap();
+    /// bom.filter_scope(&[Scope::Required, Scope::Optional]);
+    /// assert_eq!(bom.components.unwrap().0.len(), 1);
+    /// ```
+    pub fn filter_scope(&mut self, keep: &[Scope]) {
+        fn retain(components: &mut Components, keep: &[Scope], removed: &mut HashSet<String>) {
+            components.0.retain_mut(|component| {
+                let scope = component.scope.as_ref().unwrap_or(&Scope::Required);
+                if keep.contains(scope) {
+                    if let Some(nested) = &mut component.components {
+                        retain(nested, keep, removed);
+                        if nested.0.is_empty() {
+                            component.components = None;
+                        }
+                    }
+                    true
+                } else {
+                    collect_refs(component, removed);
+                    false
+                }
+            });
+        }
 
-    /// Output as a JSON document conforming to [version 1.3 of the specification](https://cyclonedx.org/docs/1.3/json/)
-    pub fn output_as_json_v1_3<W: std::io::Write>(
-        self,
-        writer: &mut W,
-    ) -> Result<(), crate::errors::JsonWriteError> {
-        let bom: crate::specs::v1_3::bom::Bom = self.try_into()?;
-        serde_json::to_writer_pretty(writer, &bom)?;
-        Ok(())
+        fn collect_refs(component: &Component, refs: &mut HashSet<String>) {
+            refs.extend(component.bom_ref.clone());
+            for nested in component.components.iter().flat_map(|c| &c.0) {
+                collect_refs(nested, refs);
+            }
+        }
+
+        let mut removed = HashSet::new();
+        if let Some(components) = &mut self.components {
+            retain(components, keep, &mut removed);
+        }
+        if !removed.is_empty() {
+            self.retain_refs(|bom_ref| !removed.contains(bom_ref));
+        }
     }
 
-    /// Output as an XML document conforming to [version 1.3 of the specification](https://cyclonedx.org/docs/1.3/xml/)
-    pub fn output_as_xml_v1_3<W: std::io::Write>(
-        self,
-        writer: &mut W,
-    ) -> Result<(), crate::errors::XmlWriteError> {
-        let config = EmitterConfig::default().perform_indent(true);
-        let mut event_writer = EventWriter::new_with_config(writer, config);
+    /// Creates a new BOM about the component with the given bom-ref, containing the component
+    /// as `metadata.component` and everything it transitively depends on
+    ///
+    /// The new BOM has its own serial number and keeps the rest of the metadata.
+    /// Dependencies, compositions, vulnerabilities and annotations are carried over
+    /// as far as they refer to the extracted components and services, see [`Bom::filter_scope`].
+    /// Nested components are extracted together with their parent; a dependency nested in a
+    /// component outside the closure becomes a top-level component.
+    /// External references, properties and the formulation describe the original BOM and
+    /// are not carried over.
+    ///
+    /// Returns `None` if no component has the bom-ref.
+    /// ```
+    /// use cyclonedx_bom::models::bom::Bom;
+    ///
+    /// # let bom = Bom::parse_from_json(r#"{
+    /// #   "bomFormat": "CycloneDX", "specVersion": "1.5", "version": 1,
+    /// #   "components": [
+    /// #     { "type": "application", "bom-ref": "server", "name": "server" },
+    /// #     { "type": "application", "bom-ref": "client", "name": "client" },
+    /// #     { "type": "library", "bom-ref": "serde", "name": "serde" }
+    /// #   ],
+    /// #   "dependencies": [
+    /// #     { "ref": "server", "dependsOn": ["serde"] },
+    /// #     { "ref": "client", "dependsOn": ["serde"] }
+    /// #   ]
+    /// # }"#.as_bytes()).unwrap();
+    /// let server = bom.extract("server").unwrap();
+    /// let root = server.metadata.unwrap().component.unwrap();
+    /// assert_eq!(root.bom_ref.as_deref(), Some("server"));
+    /// assert_eq!(server.components.unwrap().0.len(), 1);
+    /// ```
+    pub fn extract(&self, bom_ref: &str) -> Option<Self> {
+        fn index<'a>(components: &'a [Component], by_ref: &mut HashMap<&'a str, &'a Component>) {
+            for component in components {
+                if let Some(bom_ref) = &component.bom_ref {
+                    by_ref.entry(bom_ref).or_insert(component);
+                }
+                if let Some(nested) = &component.components {
+                    index(&nested.0, by_ref);
+                }
+            }
+        }
 
-        let bom: crate::specs::v1_3::bom::Bom = self.try_into()?;
-        bom.write_xml_element(&mut event_writer)
+        fn nested_refs<'a>(component: &'a Component, refs: &mut Vec<&'a str>) {
+            for nested in component.components.iter().flat_map(|c| &c.0) {
+                refs.extend(nested.bom_ref.as_deref());
+                nested_refs(nested, refs);
+            }
+        }
+
+        fn extract_components(
+            components: &[Component],
+            closure: &HashSet<&str>,
+            root: &str,
+            extracted: &mut Vec<Component>,
+        ) {
+            for component in components {
+                match component.bom_ref.as_deref() {
+                    Some(bom_ref) if bom_ref == root => {}
+                    Some(bom_ref) if closure.contains(bom_ref) => extracted.push(component.clone()),
+                    _ => {
+                        if let Some(nested) = &component.components {
+                            extract_components(&nested.0, closure, root, extracted);
+                        }
+                    }
+                }
+            }
+        }
+
+        fn extract_services(
+            services: &[Service],
+            closure: &HashSet<&str>,
+            extracted: &mut Vec<Service>,
+        ) {
+            for service in services {
+                match service.bom_ref.as_deref() {
+                    Some(bom_ref) if closure.contains(bom_ref) => extracted.push(service.clone()),
+                    _ => {
+                        if let Some(nested) = &service.services {
+                            extract_services(&nested.0, closure, extracted);
+                        }
+                    }
+                }
+            }
+        }
+
+        let metadata_component = self
+            .metadata
+            .as_ref()
+            .and_then(|m| m.component.as_ref())
+            .map(std::slice::from_ref)
+            .unwrap_or_default();
+        let mut components = HashMap::new();
+        index(metadata_component, &mut components);
+        if let Some(bom_components) = &self.components {
+            index(&bom_components.0, &mut components);
+        }
+        let root = *components.get(bom_ref)?;
+
+        let dependencies: HashMap<&str, &[String]> = self
+            .dependencies
+            .iter()
+            .flat_map(|d| &d.0)
+            .map(|d| (d.dependency_ref.as_str(), d.dependencies.as_slice()))
+            .collect();
+        let mut closure = HashSet::new();
+        let mut pending = vec![bom_ref];
+        while let Some(next) = pending.pop() {
+            if !closure.insert(next) {
+                continue;
+            }
+            pending.extend(
+                dependencies
+                    .get(next)
+                    .into_iter()
+                    .flat_map(|d| d.iter())
+                    .map(String::as_str),
+            );
+            if let Some(component) = components.get(next) {
+                nested_refs(component, &mut pending);
+            }
+        }
+
+        let mut extracted_components = Vec::new();
+        extract_components(
+            metadata_component,
+            &closure,
+            bom_ref,
+            &mut extracted_components,
+        );
+        if let Some(bom_components) = &self.components {
+            extract_components(
+                &bom_components.0,
+                &closure,
+                bom_ref,
+                &mut extracted_components,
+            );
+        }
+        let mut extracted_services = Vec::new();
+        if let Some(services) = &self.services {
+            extract_services(&services.0, &closure, &mut extracted_services);
+        }
+
+        let mut extracted = Bom {
+            metadata: Some(Metadata {
+                component: Some(root.clone()),
+                ..self.metadata.clone().unwrap_or_default()
+            }),
+            components: (!extracted_components.is_empty())
+                .then_some(Components(extracted_components)),
+            services: (!extracted_services.is_empty()).then_some(Services(extracted_services)),
+            dependencies: self.dependencies.clone(),
+            compositions: self.compositions.clone(),
+            vulnerabilities: self.vulnerabilities.clone(),
+            annotations: self.annotations.clone(),
+            ..Bom::default()
+        };
+
+        let mut kept = HashSet::new();
+        extracted.map_components(|component| kept.extend(component.bom_ref.clone()));
+        extracted.map_services(|service| kept.extend(service.bom_ref.clone()));
+        extracted.retain_refs(|bom_ref| kept.contains(bom_ref));
+
+        Some(extracted)
     }
 
-    /// Parse the input as a JSON document conforming to [version 1.4 of the specification](https://cyclonedx.org/docs/1.4/json/)
-    pub fn parse_from_json_v1_4<R: std::io::Read>(
-        mut reader: R,
-    ) -> Result<Self, crate::errors::JsonReadError> {
-        let bom: crate::specs::v1_4::bom::Bom = serde_json::from_reader(&mut reader)?;
-        Ok(bom.into())
+    /// Counts the components, their types and identifiers, the dependencies and
+    /// the vulnerabilities of the BOM
+    /// ```
+    /// use cyclonedx_bom::models::bom::Bom;
+    ///
+    /// let stats = Bom::default().stats();
+    /// assert_eq!(stats.components, 0);
+    /// ```
+    pub fn stats(&self) -> BomStats {
+        BomStats::collect(self)
     }
 
-    /// Parse the input as an XML document conforming to [version 1.4 of the specification](https://cyclonedx.org/docs/1.4/xml/)
-    pub fn parse_from_xml_v1_4<R: std::io::Read>(
-        reader: R,
-    ) -> Result<Self, crate::errors::XmlReadError> {
-        let config = ParserConfig::default().trim_whitespace(true);
-        let mut event_reader = EventReader::new_with_config(reader, config);
-        let bom = crate::specs::v1_4::bom::Bom::read_xml_document(&mut event_reader)?;
-        Ok(bom.into())
+    /// Selects components, services or vulnerabilities with a selector,
+    /// e.g. `components[?licenses contains 'GPL-3.0-only']`, see [`query`](crate::query)
+    pub fn select(&self, selector: &str) -> Result<Selection<'_>, QueryError> {
+        Ok(selector.parse::<Selector>()?.select(self))
     }
 
-    /// Output as a JSON document conforming to [version 1.4 of the specification](https://cyclonedx.org/docs/1.4/json/)
-    pub fn output_as_json_v1_4<W: std::io::Write>(
-        self,
-        writer: &mut W,
-    ) -> Result<(), crate::errors::JsonWriteError> {
-        let bom: crate::specs::v1_4::bom::Bom = self.into();
-        serde_json::to_writer_pretty(writer, &bom)?;
-        Ok(())
+    /// Returns all components that match the predicate, including nested ones,
+    /// but not the component in `metadata.component`
+    pub fn select_components(&self, predicate: &Predicate) -> Vec<&Component> {
+        let mut selected = Vec::new();
+        if let Some(components) = &self.components {
+            query::select_components(components, predicate, &mut selected);
+        }
+        selected
     }
 
-    /// Output as an XML document conforming to [version 1.4 of the specification](https://cyclonedx.org/docs/1.4/xml/)
-    pub fn output_as_xml_v1_4<W: std::io::Write>(
-        self,
-        writer: &mut W,
-    ) -> Result<(), crate::errors::XmlWriteError> {
-        let config = EmitterConfig::default().perform_indent(true);
-        let mut event_writer = EventWriter::new_with_config(writer, config);
+    /// Returns all services that match the predicate, including nested ones
+    pub fn select_services(&self, predicate: &Predicate) -> Vec<&Service> {
+        let mut selected = Vec::new();
+        if let Some(services) = &self.services {
+            query::select_services(services, predicate, &mut selected);
+        }
+        selected
+    }
 
-        let bom: crate::specs::v1_4::bom::Bom = self.into();
-        bom.write_xml_element(&mut event_writer)
+    /// Returns all vulnerabilities that match the predicate
+    pub fn select_vulnerabilities(&self, predicate: &Predicate) -> Vec<&Vulnerability> {
+        self.vulnerabilities
+            .iter()
+            .flat_map(|v| v.0.iter())
+            .filter(|v| predicate.matches(*v))
+            .collect()
     }
-}
 
-impl Default for Bom {
-    /// Construct a BOM with a default `version` of `1` and `serial_number` with a random UUID
-    fn default() -> Self {
-        Self {
-            version: 1,
-            serial_number: Some(UrnUuid::generate()),
-            metadata: None,
-            components: None,
-            services: None,
-            external_references: None,
-            dependencies: None,
-            compositions: None,
-            properties: None,
-            vulnerabilities: None,
-            signature: None,
+    /// Verifies the JSF signatures of the BOM with the key, see [`verification`](crate::verification)
+    ///
+    /// The signatures are verified against the BOM as written in JSON with each spec version
+    /// that supports signatures, since a parsed BOM does not know the version it was read from.
+    /// Fields of the original document that the models do not keep, e.g. the public key of a signer,
+    /// make the verification fail; prefer [`verify_document`](crate::verification::verify_document)
+    /// on the original input when possible, which also supports XML-DSig.
+    #[cfg(feature = "verify")]
+    pub fn verify_signature(
+        &self,
+        key: &crate::verification::PublicKey,
+    ) -> Result<Vec<crate::verification::SignedPart>, crate::errors::SignatureError> {
+        let mut result = Err(crate::errors::SignatureError::Unsigned);
+        for spec_version in [SpecVersion::V1_5, SpecVersion::V1_4] {
+            let mut json = Vec::new();
+            if self
+                .clone()
+                .output_as_json_with_format(&mut json, spec_version, Default::default())
+                .is_err()
+            {
+                continue;
+            }
+            match crate::verification::verify_json(&json, key) {
+                Ok(parts) => return Ok(parts),
+                // Keep the error of the most recent spec version
+                Err(error) if result == Err(crate::errors::SignatureError::Unsigned) => {
+                    result = Err(error)
+                }
+                Err(_) => {}
+            }
         }
+        result
     }
-}
 
-impl Validate for Bom {
-    fn validate_with_context(&self, context: ValidationContext) -> ValidationResult {
-        let mut results: Vec<ValidationResult> = vec![];
+    /// Drops all references within the BOM for which `keep` returns `false`, along with the
+    /// compositions, vulnerabilities and annotations that are left without any of their references
+    fn retain_refs(&mut self, keep: impl Fn(&str) -> bool) {
+        if let Some(dependencies) = &mut self.dependencies {
+            dependencies
+                .0
+                .retain(|dependency| keep(&dependency.dependency_ref));
+            for dependency in &mut dependencies.0 {
+                dependency.dependencies.retain(|bom_ref| keep(bom_ref));
+            }
+        }
 
-        let mut bom_refs_context = BomReferencesContext::default();
+        if let Some(compositions) = &mut self.compositions {
+            compositions.0.retain_mut(|composition| {
+                let mut had_refs = false;
+                let mut has_refs = false;
+                for refs in [&mut composition.assemblies, &mut composition.dependencies]
+                    .into_iter()
+                    .flatten()
+                {
+                    had_refs |= !refs.is_empty();
+                    refs.retain(|BomReference(bom_ref)| keep(bom_ref));
+                    has_refs |= !refs.is_empty();
+                }
+                has_refs || !had_refs
+            });
+        }
 
-        if let Some(serial_number) = &self.serial_number {
-            let context = context.with_struct("Bom", "serial_number");
+        if let Some(vulnerabilities) = &mut self.vulnerabilities {
+            vulnerabilities.0.retain_mut(|vulnerability| {
+                match &mut vulnerability.vulnerability_targets {
+                    Some(targets) if !targets.0.is_empty() => {
+                        targets.0.retain(|target| keep(&target.bom_ref));
+                        !targets.0.is_empty()
+                    }
+                    _ => true,
+                }
+            });
+        }
 
-            results.push(serial_number.validate_with_context(context));
+        if let Some(annotations) = &mut self.annotations {
+            annotations.0.retain_mut(|annotation| {
+                annotation.subjects.retain(|subject| match subject {
+                    AnnotationSubject::BomReference(BomReference(bom_ref)) => keep(bom_ref),
+                    AnnotationSubject::BomLink(_) => true,
+                });
+                !annotation.subjects.is_empty()
+            });
         }
+    }
 
-        if let Some(metadata) = &self.metadata {
-            let context = context.with_struct("Bom", "metadata");
-            let component_bom_ref_context = context.with_struct("Metadata", "component");
+    /// Checks that the BOM only uses component types, hash algorithms and external reference
+    /// types allowed in documents of the given spec version, e.g. before writing it as 1.3.
+    ///
+    /// Unknown values are reported by [`Validate`] instead.
+    pub fn validate_version(&self, version: SpecVersion) -> ValidationResult {
+        let context = ValidationContext::new();
+        let mut results: Vec<ValidationResult> = vec![];
 
-            results.push(metadata.validate_with_context(context));
+        if let Some(component) = self
+            .metadata
+            .as_ref()
+            .and_then(|metadata| metadata.component.as_ref())
+        {
+            results.push(
+                component.validate_version_with_context(
+                    version,
+                    context
+                        .with_struct("Bom", "metadata")
+                        .with_struct("Metadata", "component"),
+                ),
+            );
+        }
 
-            if let Some(component) = &metadata.component {
-                validate_component_bom_refs(
-                    component,
-                    &mut bom_refs_context,
-                    &component_bom_ref_context,
-                    &mut results,
-                );
+        if let Some(metadata) = &self.metadata {
+            for (field, organization) in [
+                ("manufacture", &metadata.manufacture),
+                ("supplier", &metadata.supplier),
+            ] {
+                if let Some(organization) = organization {
+                    results.push(
+                        organization.validate_version_with_context(
+                            version,
+                            context
+                                .with_struct("Bom", "metadata")
+                                .with_struct("Metadata", field),
+                        ),
+                    );
+                }
             }
         }
 
         if let Some(components) = &self.components {
-            let context = context.with_struct("Bom", "components");
-            let component_bom_ref_context = context.clone();
-
-            results.push(components.validate_with_context(context));
-
-            // record the component references
-            validate_components(
-                components,
-                &mut bom_refs_context,
-                &component_bom_ref_context,
-                &mut results,
+            results.push(
+                components.validate_version_with_context(
+                    version,
+                    context.with_struct("Bom", "components"),
+                ),
             );
         }
 
-        if let Some(services) = &self.services {
-            let context = context.with_struct("Bom", "services");
-            let service_bom_ref_context = context.clone();
+        if let Some(external_references) = &self.external_references {
+            results.push(external_references.validate_version_with_context(
+                version,
+                context.with_struct("Bom", "external_references"),
+            ));
+        }
 
-            results.push(services.validate_with_context(context));
+        results
+            .into_iter()
+            .fold(ValidationResult::default(), |acc, result| acc.merge(result))
+    }
 
-            // record the service references
-            validate_services(
-                services,
-                &mut bom_refs_context,
-                &service_bom_ref_context,
-                &mut results,
+    /// Fails if the BOM has a field that documents of the given spec version cannot hold,
+    /// which would otherwise be dropped when writing it.
+    ///
+    /// Unlike [`Bom::validate_version`], this is checked by every `output_as_*` method.
+    pub(crate) fn ensure_writable_as(&self, version: SpecVersion) -> Result<(), BomError> {
+        let mut components: Vec<&Component> = vec![];
+        let mut services: Vec<&Service> = vec![];
+        let mut organizations: Vec<&OrganizationalEntity> = vec![];
+
+        if let Some(metadata) = &self.metadata {
+            components.extend(&metadata.component);
+            organizations.extend(metadata.manufacture.iter().chain(&metadata.supplier));
+            if let Some(Tools::Object {
+                components: tool_components,
+                services: tool_services,
+            }) = &metadata.tools
+            {
+                components.extend(tool_components.iter().flat_map(|components| &components.0));
+                services.extend(tool_services.iter().flat_map(|services| &services.0));
+            }
+        }
+        components.extend(self.components.iter().flat_map(|components| &components.0));
+        services.extend(self.services.iter().flat_map(|services| &services.0));
+        for formula in self
+            .formulation
+            .iter()
+            .flat_map(|formulation| &formulation.0)
+        {
+            components.extend(
+                formula
+                    .components
+                    .iter()
+                    .flat_map(|components| &components.0),
             );
+            services.extend(formula.services.iter().flat_map(|services| &services.0));
         }
-
-        if let Some(external_references) = &self.external_references {
-            let context = context.with_struct("Bom", "external_references");
-
-            results.push(external_references.validate_with_context(context));
+        for vulnerability in self
+            .vulnerabilities
+            .iter()
+            .flat_map(|vulnerabilities| &vulnerabilities.0)
+        {
+            if let Some(credits) = &vulnerability.vulnerability_credits {
+                organizations.extend(credits.organizations.iter().flatten());
+            }
+        }
+        for annotation in self
+            .annotations
+            .iter()
+            .flat_map(|annotations| &annotations.0)
+        {
+            match &annotation.annotator {
+                Annotator::Organization(organization) => organizations.push(organization),
+                Annotator::Component(component) => components.push(component),
+                Annotator::Service(service) => services.push(service),
+                Annotator::Individual(_) => {}
+            }
         }
 
-        if let Some(dependencies) = &self.dependencies {
-            let context = context.with_struct("Bom", "dependencies");
+        components
+            .into_iter()
+            .try_for_each(|component| component.ensure_writable_as(version))?;
+        services
+            .into_iter()
+            .try_for_each(|service| service.ensure_writable_as(version))?;
+        organizations
+            .into_iter()
+            .try_for_each(|organization| organization.ensure_writable_as(version))
+    }
 
-            for (dependency_index, dependency) in dependencies.0.iter().enumerate() {
-                let context = context.extend_context(vec![ValidationPathComponent::Array {
-                    index: dependency_index,
-                }]);
-                if !bom_refs_context.contains(&dependency.dependency_ref) {
-                    let dependency_context = context.with_struct("Dependency", "dependency_ref");
+    /// Derives a serial number from the content of the BOM, so that the same BOM
+    /// always gets the same serial number. The current serial number is ignored.
+    ///
+    /// This is meant for reproducible builds; a BOM whose content changes, e.g. because
+    /// of an updated timestamp, also gets a different serial number.
+    #[cfg(all(feature = "json", feature = "uuid", feature = "v1_5"))]
+    pub fn content_serial_number(&self) -> UrnUuid {
+        let mut bom = self.clone();
+        bom.serial_number = None;
+        let bom: crate::specs::v1_5::bom::Bom = bom.into();
+        let content = serde_json::to_vec(&bom).expect("Failed to serialize BOM");
+        UrnUuid::from_content(&content)
+    }
 
-                    results.push(ValidationResult::failure(
-                        "Dependency reference does not exist in the BOM",
-                        dependency_context,
-                    ));
-                }
+    /// Creates the next revision of the BOM, with the same serial number, the version incremented
+    /// and an external reference of type `bom` with a BOM-Link to this revision
+    ///
+    /// The link to the predecessor of this revision is replaced, so that every revision only
+    /// links to the one it was made from.
+    /// ```
+    /// use cyclonedx_bom::models::bom::{Bom, UrnUuid};
+    ///
+    /// let mut bom = Bom::default();
+    /// bom.serial_number = Some(
+    ///     UrnUuid::new("urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79".to_string()).unwrap(),
+    /// );
+    ///
+    /// let revision = bom.next_revision().unwrap();
+    /// assert_eq!(revision.version, 2);
+    /// assert_eq!(revision.serial_number, bom.serial_number);
+    /// assert_eq!(
+    ///     revision.previous_revision().unwrap().to_string(),
+    ///     "urn:cdx:3e671687-395b-41f5-a30f-a58921a69b79/1"
+    /// );
+    /// ```
+    /// # Errors
+    ///
+    /// Returns an error if the BOM has no serial number, or its version is already the highest.
+    pub fn next_revision(&self) -> Result<Self, BomRevisionError> {
+        let serial_number = self
+            .serial_number
+            .as_ref()
+            .ok_or(BomRevisionError::MissingSerialNumber)?;
+        let version = self
+            .version
+            .checked_add(1)
+            .ok_or(BomRevisionError::VersionOverflow)?;
+
+        let mut revision = self.clone();
+        revision.version = version;
+        let external_references = revision
+            .external_references
+            .get_or_insert_with(|| ExternalReferences(Vec::new()));
+        external_references
+            .0
+            .retain(|external_reference| self.revision_link(external_reference).is_none());
+        external_references.0.push(ExternalReference {
+            external_reference_type: ExternalReferenceType::Bom,
+            url: Uri(BomLink::new(serial_number, self.version, None).to_string()),
+            comment: Some("Previous revision".to_string()),
+            hashes: None,
+        });
+
+        Ok(revision)
+    }
 
-                for (sub_dependency_index, sub_dependency) in
-                    dependency.dependencies.iter().enumerate()
-                {
-                    if !bom_refs_context.contains(sub_dependency) {
-                        let context = context.extend_context(vec![
-                            ValidationPathComponent::Struct {
-                                struct_name: "Dependency".to_string(),
-                                field_name: "dependencies".to_string(),
-                            },
-                            ValidationPathComponent::Array {
-                                index: sub_dependency_index,
-                            },
-                        ]);
+    /// The BOM-Link to the revision this BOM was made from, see [`Bom::next_revision`]
+    pub fn previous_revision(&self) -> Option<BomLink> {
+        self.external_references
+            .iter()
+            .flat_map(|external_references| &external_references.0)
+            .find_map(|external_reference| self.revision_link(external_reference))
+    }
 
-                        results.push(ValidationResult::failure(
-                            "Dependency reference does not exist in the BOM",
-                            context,
-                        ));
-                    }
-                }
-            }
+    /// The BOM-Link of an external reference to an earlier revision of this BOM
+    fn revision_link(&self, external_reference: &ExternalReference) -> Option<BomLink> {
+        if external_reference.external_reference_type != ExternalReferenceType::Bom {
+            return None;
         }
 
-        if let Some(compositions) = &self.compositions {
-            let context = context.with_struct("Bom", "compositions");
-            let compositions_context = context.clone();
+        let link = BomLink(external_reference.url.0.clone());
+        let same_bom =
+            link.serial_number().as_ref() == self.serial_number.as_ref().map(|serial| &serial.0);
+        let earlier = link.version().is_some_and(|version| version < self.version);
+        (same_bom && earlier && link.bom_ref().is_none()).then_some(link)
+    }
 
-            results.push(compositions.validate_with_context(context));
+    /// Whether the BOM-Link points into this BOM, i.e. matches its serial number and version
+    fn is_linked_by(&self, bom_link: &BomLink) -> bool {
+        self.serial_number.as_ref().is_some_and(|serial_number| {
+            bom_link.serial_number().as_ref() == Some(&serial_number.0)
+                && bom_link.version() == Some(self.version)
+        })
+    }
 
-            for (composition_index, composition) in compositions.0.iter().enumerate() {
-                let compositions_context =
+    /// General function to parse a JSON file, fetches the `specVersion` field first then applies the right conversion.
+    #[cfg(feature = "json")]
+    pub fn parse_from_json<R: std::io::Read>(
+        mut reader: R,
+    ) -> Result<Self, crate::errors::JsonReadError> {
+        let mut input = Vec::new();
+        Decompressed::new(&mut reader)
+            .read_to_end(&mut input)
+            .map_err(serde_json::Error::io)?;
+
+        Self::parse_from_plain_json_slice(&input)
+    }
+
+    /// Like [`Bom::parse_from_json`], but fails if the input exceeds the given [`ParseLimits`]
+    ///
+    /// Use this for documents from untrusted sources.
+    #[cfg(feature = "json")]
+    pub fn parse_from_json_with_limits<R: std::io::Read>(
+        reader: R,
+        limits: &ParseLimits,
+    ) -> Result<Self, crate::errors::JsonReadError> {
+        let input = limits.read_input(reader, |error| {
+            crate::errors::JsonReadError::from(serde_json::Error::io(error))
+        })?;
+        limits.check_json(&input)?;
+
+        Self::parse_from_plain_json_slice(&input)
+    }
+
+    /// Parse a JSON document held in memory, e.g. in a memory-mapped file, like [`Bom::parse_from_json`]
+    ///
+    /// An uncompressed document is parsed in place, without copying it into a buffer first.
+    /// ```
+    /// use cyclonedx_bom::models::bom::Bom;
+    ///
+    /// let input = br#"{"bomFormat": "CycloneDX", "specVersion": "1.5", "version": 2}"#;
+    /// let bom = Bom::parse_from_json_slice(input).unwrap();
+    /// assert_eq!(bom.version, 2);
+    /// ```
+    #[cfg(feature = "json")]
+    pub fn parse_from_json_slice(input: &[u8]) -> Result<Self, crate::errors::JsonReadError> {
+        let input = decompress_slice(input).map_err(serde_json::Error::io)?;
+        Self::parse_from_plain_json_slice(&input)
+    }
+
+    #[cfg(feature = "json")]
+    fn parse_from_plain_json_slice(input: &[u8]) -> Result<Self, crate::errors::JsonReadError> {
+        let mut unsupported = None;
+        let mut deserializer = serde_json::Deserializer::from_slice(input);
+        let bom = VersionedBomSeed {
+            unsupported: &mut unsupported,
+        }
+        .deserialize(&mut deserializer)
+        .and_then(|bom| deserializer.end().map(|()| bom));
+
+        match (unsupported, bom) {
+            (Some(error), _) => Err(error.into()),
+            (None, bom) => Ok(bom?),
+        }
+    }
+
+    /// Parse a JSON document like [`Bom::parse_from_json`], together with the properties of its
+    /// objects that are not part of the model
+    ///
+    /// See [`crate::json`] for how to write them back.
+    #[cfg(feature = "json")]
+    pub fn parse_from_json_with_unknown_fields<R: std::io::Read>(
+        reader: R,
+    ) -> Result<(Self, crate::json::UnknownFields), crate::errors::JsonReadError> {
+        let (bom, _, unknown_fields) = Self::read_json_with_unknown_fields(reader)?;
+        Ok((bom, unknown_fields))
+    }
+
+    /// Parses the document, then compares it to the document written back from the BOM in the
+    /// same spec version
+    #[cfg(feature = "json")]
+    fn read_json_with_unknown_fields<R: std::io::Read>(
+        mut reader: R,
+    ) -> Result<(Self, SpecVersion, crate::json::UnknownFields), crate::errors::JsonReadError> {
+        let mut input = Vec::new();
+        Decompressed::new(&mut reader)
+            .read_to_end(&mut input)
+            .map_err(serde_json::Error::io)?;
+
+        let bom = Self::parse_from_plain_json_slice(&input)?;
+        let original: crate::json::OrderedValue = serde_json::from_slice(&input)?;
+        let spec_version = SpecVersion::from_str(
+            original
+                .get(SPEC_VERSION_FIELD)
+                .and_then(crate::json::OrderedValue::as_str)
+                .unwrap_or_default(),
+        )?;
+        let emitted =
+            bom.clone()
+                .output_as_ordered_json(spec_version)
+                .map_err(|error| match error {
+                    crate::errors::JsonWriteError::JsonElementWriteError { error } => {
+                        crate::errors::JsonReadError::from(error)
+                    }
+                    crate::errors::JsonWriteError::BomError { error } => error.into(),
+                })?;
+
+        let unknown_fields = crate::json::UnknownFields::between(&original, &emitted);
+        Ok((bom, spec_version, unknown_fields))
+    }
+
+    /// Parse a JSON document like [`Bom::parse_from_json`], but fail on the first property that is
+    /// not part of the model of the document's spec version, e.g. a misspelled field
+    ///
+    /// The properties of the specification that this crate doesn't model yet are rejected too.
+    /// ```
+    /// use cyclonedx_bom::errors::JsonReadError;
+    /// use cyclonedx_bom::models::bom::Bom;
+    ///
+    /// let input = r#"{"bomFormat": "CycloneDX", "specVersion": "1.5", "version": 1, "metdata": {}}"#;
+    /// let error = Bom::parse_from_json_strict(input.as_bytes()).unwrap_err();
+    /// assert!(matches!(error, JsonReadError::UnknownField { field, .. } if field == "metdata"));
+    /// ```
+    #[cfg(feature = "json")]
+    pub fn parse_from_json_strict<R: std::io::Read>(
+        reader: R,
+    ) -> Result<Self, crate::errors::JsonReadError> {
+        let (bom, spec_version, unknown_fields) = Self::read_json_with_unknown_fields(reader)?;
+        if let Some(field) = unknown_fields.iter().next() {
+            return Err(crate::errors::JsonReadError::UnknownField {
+                field: field.to_string(),
+                spec_version: spec_version.to_string(),
+            });
+        }
+        Ok(bom)
+    }
+
+    /// Parse a JSON document like [`Bom::parse_from_json`], but fix up minor deviations from the
+    /// specification instead of failing on them or keeping the invalid values
+    ///
+    /// See [`crate::lenient`] for the deviations that are fixed up.
+    #[cfg(feature = "json")]
+    pub fn parse_from_json_lenient<R: std::io::Read>(
+        mut reader: R,
+    ) -> Result<(Self, Vec<crate::lenient::ParseWarning>), crate::errors::JsonReadError> {
+        let mut input = Vec::new();
+        Decompressed::new(&mut reader)
+            .read_to_end(&mut input)
+            .map_err(serde_json::Error::io)?;
+
+        let (document, warnings) = crate::lenient::read_lenient(&input)?;
+        let mut unsupported = None;
+        let bom = VersionedBomSeed {
+            unsupported: &mut unsupported,
+        }
+        .deserialize(document);
+
+        match (unsupported, bom) {
+            (Some(error), _) => Err(error.into()),
+            (None, bom) => Ok((bom?, warnings)),
+        }
+    }
+
+    /// Parse a JSON document like [`Bom::parse_from_json`], but skip the components and
+    /// vulnerabilities that cannot be read instead of failing on them
+    ///
+    /// The skipped entries are returned with their content and error. Any other error, e.g. in
+    /// the metadata, still fails the whole document.
+    /// ```
+    /// use cyclonedx_bom::models::bom::Bom;
+    ///
+    /// let input = r#"{
+    ///   "bomFormat": "CycloneDX",
+    ///   "specVersion": "1.5",
+    ///   "version": 1,
+    ///   "components": [
+    ///     { "type": "library", "name": "good" },
+    ///     { "type": "library", "name": 42 }
+    ///   ]
+    /// }"#;
+    /// let (bom, report) = Bom::parse_from_json_recovering(input.as_bytes()).unwrap();
+    /// assert_eq!(bom.components.unwrap().0.len(), 1);
+    /// assert_eq!(report.skipped[0].path, "components[1]");
+    /// ```
+    #[cfg(feature = "json")]
+    pub fn parse_from_json_recovering<R: std::io::Read>(
+        mut reader: R,
+    ) -> Result<(Self, crate::lenient::RecoveryReport), crate::errors::JsonReadError> {
+        let mut input = Vec::new();
+        Decompressed::new(&mut reader)
+            .read_to_end(&mut input)
+            .map_err(serde_json::Error::io)?;
+
+        let mut document: Value = serde_json::from_slice(&input)?;
+        let report = match document[SPEC_VERSION_FIELD]
+            .as_str()
+            .map(SpecVersion::from_str)
+        {
+            Some(Ok(spec_version)) => {
+                crate::lenient::skip_malformed_entries(&mut document, spec_version)
+            }
+            _ => crate::lenient::RecoveryReport::default(),
+        };
+
+        let mut unsupported = None;
+        let bom = VersionedBomSeed {
+            unsupported: &mut unsupported,
+        }
+        .deserialize(document);
+
+        match (unsupported, bom) {
+            (Some(error), _) => Err(error.into()),
+            (None, bom) => Ok((bom?, report)),
+        }
+    }
+
+    /// Output as a JSON document conforming to the given version of the specification, with the
+    /// properties returned by [`Bom::parse_from_json_with_unknown_fields`] added back to their objects
+    ///
+    /// A property is not added if its object is gone or already has a property of the same name.
+    /// The added properties follow the ones of the specification, which keep their order.
+    #[cfg(feature = "json")]
+    pub fn output_as_json_with_unknown_fields<W: std::io::Write>(
+        self,
+        writer: &mut W,
+        spec_version: SpecVersion,
+        unknown_fields: &crate::json::UnknownFields,
+    ) -> Result<(), crate::errors::JsonWriteError> {
+        let mut document = self.output_as_ordered_json(spec_version)?;
+        unknown_fields.restore(&mut document);
+        serde_json::to_writer_pretty(writer, &document)?;
+        Ok(())
+    }
+
+    /// Output as a JSON document conforming to the given version of the specification, laid out
+    /// in the given format
+    /// ```
+    /// use cyclonedx_bom::json::JsonFormat;
+    /// use cyclonedx_bom::models::bom::{Bom, SpecVersion};
+    ///
+    /// let mut output = Vec::new();
+    /// Bom::default()
+    ///     .output_as_json_with_format(&mut output, SpecVersion::V1_5, JsonFormat::Compact)
+    ///     .unwrap();
+    /// assert!(!output.contains(&b'\n'));
+    /// ```
+    #[cfg(feature = "json")]
+    pub fn output_as_json_with_format<W: std::io::Write>(
+        self,
+        writer: &mut W,
+        spec_version: SpecVersion,
+        format: crate::json::JsonFormat,
+    ) -> Result<(), crate::errors::JsonWriteError> {
+        self.ensure_writable_as(spec_version)?;
+        match spec_version {
+            #[cfg(feature = "v1_3")]
+            SpecVersion::V1_3 => {
+                let bom: crate::specs::v1_3::bom::Bom = self.try_into()?;
+                format.write(writer, &bom)?;
+            }
+            #[cfg(feature = "v1_4")]
+            SpecVersion::V1_4 => {
+                format.write(writer, &crate::specs::v1_4::bom::Bom::from(self))?;
+            }
+            #[cfg(feature = "v1_5")]
+            SpecVersion::V1_5 => {
+                format.write(writer, &crate::specs::v1_5::bom::Bom::from(self))?;
+            }
+            #[allow(unreachable_patterns)]
+            _ => return Err(BomError::UnsupportedSpecVersion(spec_version.to_string()).into()),
+        }
+        Ok(())
+    }
+
+    /// Writes the document and reads it back with its properties in the order of the specification
+    #[cfg(feature = "json")]
+    fn output_as_ordered_json(
+        self,
+        spec_version: SpecVersion,
+    ) -> Result<crate::json::OrderedValue, crate::errors::JsonWriteError> {
+        self.ensure_writable_as(spec_version)?;
+        let document: Vec<u8> = match spec_version {
+            #[cfg(feature = "v1_3")]
+            SpecVersion::V1_3 => {
+                let bom: crate::specs::v1_3::bom::Bom = self.try_into()?;
+                serde_json::to_vec(&bom)?
+            }
+            #[cfg(feature = "v1_4")]
+            SpecVersion::V1_4 => serde_json::to_vec(&crate::specs::v1_4::bom::Bom::from(self))?,
+            #[cfg(feature = "v1_5")]
+            SpecVersion::V1_5 => serde_json::to_vec(&crate::specs::v1_5::bom::Bom::from(self))?,
+            #[allow(unreachable_patterns)]
+            _ => return Err(BomError::UnsupportedSpecVersion(spec_version.to_string()).into()),
+        };
+        Ok(serde_json::from_slice(&document)?)
+    }
+
+    /// Output as an XML document conforming to the given version of the specification,
+    /// with the extension elements at the end of the `bom` element
+    ///
+    /// See [`crate::xml`] for how to implement an extension.
+    #[cfg(feature = "xml")]
+    pub fn output_as_xml_with_extensions<W: std::io::Write, E: crate::xml::ToXml>(
+        self,
+        writer: &mut W,
+        spec_version: SpecVersion,
+        extensions: &[E],
+    ) -> Result<(), crate::errors::XmlWriteError> {
+        self.ensure_writable_as(spec_version)?;
+        match spec_version {
+            #[cfg(feature = "v1_3")]
+            SpecVersion::V1_3 => {
+                let bom: crate::specs::v1_3::bom::Bom = self.try_into()?;
+                bom.output_as_xml_with_extensions(writer, extensions)
+            }
+            #[cfg(feature = "v1_4")]
+            SpecVersion::V1_4 => {
+                let bom: crate::specs::v1_4::bom::Bom = self.into();
+                bom.output_as_xml_with_extensions(writer, extensions)
+            }
+            #[cfg(feature = "v1_5")]
+            SpecVersion::V1_5 => {
+                let bom: crate::specs::v1_5::bom::Bom = self.into();
+                bom.output_as_xml_with_extensions(writer, extensions)
+            }
+            #[allow(unreachable_patterns)]
+            _ => Err(BomError::UnsupportedSpecVersion(spec_version.to_string()).into()),
+        }
+    }
+
+    /// Parse an XML document like [`Bom::parse_from_xml_slice`], together with its elements and
+    /// attributes of other namespaces, e.g. vendor extensions
+    ///
+    /// ```
+    /// use cyclonedx_bom::models::bom::{Bom, SpecVersion};
+    ///
+    /// let input = r#"<bom xmlns="http://cyclonedx.org/schema/bom/1.5" xmlns:acme="urn:example:acme" version="1" acme:signed="true">
+    ///   <components>
+    ///     <component type="library">
+    ///       <name>lib</name>
+    ///       <acme:review>approved</acme:review>
+    ///     </component>
+    ///   </components>
+    /// </bom>"#;
+    /// let (mut bom, extensions) = Bom::parse_from_xml_preserving_extensions(input.as_bytes()).unwrap();
+    /// assert_eq!(extensions.len(), 2);
+    ///
+    /// bom.version = 2;
+    /// let mut output = Vec::new();
+    /// bom.output_as_xml_preserving_extensions(&mut output, SpecVersion::V1_5, &extensions)
+    ///     .unwrap();
+    /// let output = String::from_utf8(output).unwrap();
+    /// assert!(output.contains(r#"acme:signed="true""#));
+    /// assert!(output.contains("<acme:review>approved</acme:review>"));
+    /// ```
+    #[cfg(feature = "xml")]
+    pub fn parse_from_xml_preserving_extensions<R: std::io::Read>(
+        mut reader: R,
+    ) -> Result<(Self, crate::xml::XmlExtensions), crate::errors::XmlReadError> {
+        let mut input = Vec::new();
+        Decompressed::new(&mut reader)
+            .read_to_end(&mut input)
+            .map_err(|error| crate::errors::XmlReadError::ElementReadError {
+                error: error.into(),
+                element: "bom".to_string(),
+            })?;
+
+        let bom = Self::parse_from_xml_slice(&input)?;
+        let extensions = crate::xml::XmlExtensions::read(&input)?;
+        Ok((bom, extensions))
+    }
+
+    /// Output as an XML document conforming to the given version of the specification, with the
+    /// extensions returned by [`Bom::parse_from_xml_preserving_extensions`] written back into the
+    /// elements they were found in
+    #[cfg(feature = "xml")]
+    pub fn output_as_xml_preserving_extensions<W: std::io::Write>(
+        self,
+        writer: &mut W,
+        spec_version: SpecVersion,
+        extensions: &crate::xml::XmlExtensions,
+    ) -> Result<(), crate::errors::XmlWriteError> {
+        let no_extensions: &[crate::xml::XmlElement] = &[];
+        if extensions.is_empty() {
+            return self.output_as_xml_with_extensions(writer, spec_version, no_extensions);
+        }
+
+        let mut output = Vec::new();
+        self.output_as_xml_with_extensions(&mut output, spec_version, no_extensions)?;
+        extensions.restore(&output, writer)
+    }
+
+    /// Asynchronously reads a JSON document, then parses it like [`Bom::parse_from_json`]
+    ///
+    /// The whole document is read into memory before it is parsed.
+    #[cfg(feature = "async")]
+    pub async fn parse_from_json_async<R: tokio::io::AsyncRead + Unpin>(
+        mut reader: R,
+    ) -> Result<Self, crate::errors::JsonReadError> {
+        use tokio::io::AsyncReadExt;
+
+        let mut input = Vec::new();
+        reader
+            .read_to_end(&mut input)
+            .await
+            .map_err(serde_json::Error::io)?;
+
+        Self::parse_from_json(input.as_slice())
+    }
+
+    /// Output as a JSON document conforming to the given version of the specification to an async writer
+    ///
+    /// The document is serialized into memory before it is written.
+    #[cfg(feature = "async")]
+    pub async fn output_as_json_async<W: tokio::io::AsyncWrite + Unpin>(
+        self,
+        writer: &mut W,
+        spec_version: SpecVersion,
+    ) -> Result<(), crate::errors::JsonWriteError> {
+        use tokio::io::AsyncWriteExt;
+
+        let mut output = Vec::new();
+        match spec_version {
+            #[cfg(feature = "v1_3")]
+            SpecVersion::V1_3 => self.output_as_json_v1_3(&mut output)?,
+            #[cfg(feature = "v1_4")]
+            SpecVersion::V1_4 => self.output_as_json_v1_4(&mut output)?,
+            #[cfg(feature = "v1_5")]
+            SpecVersion::V1_5 => self.output_as_json_v1_5(&mut output)?,
+            #[allow(unreachable_patterns)]
+            _ => return Err(BomError::UnsupportedSpecVersion(spec_version.to_string()).into()),
+        }
+
+        writer
+            .write_all(&output)
+            .await
+            .map_err(serde_json::Error::io)?;
+        writer.flush().await.map_err(serde_json::Error::io)?;
+        Ok(())
+    }
+
+    /// Parse a YAML document, which has the structure of the JSON document of the same spec version
+    ///
+    /// The spec version is detected from the `specVersion` field, like [`Bom::parse_from_json`].
+    /// ```
+    /// use cyclonedx_bom::models::bom::Bom;
+    ///
+    /// let input = "
+    /// bomFormat: CycloneDX
+    /// specVersion: '1.5'
+    /// version: 2
+    /// components:
+    ///   - type: library
+    ///     name: lib
+    /// ";
+    /// let bom = Bom::parse_from_yaml(input.as_bytes()).unwrap();
+    /// assert_eq!(bom.version, 2);
+    /// assert_eq!(bom.components.unwrap().0[0].name.to_string(), "lib");
+    /// ```
+    #[cfg(feature = "yaml")]
+    pub fn parse_from_yaml<R: std::io::Read>(
+        reader: R,
+    ) -> Result<Self, crate::errors::YamlReadError> {
+        let mut unsupported = None;
+        let bom = VersionedBomSeed {
+            unsupported: &mut unsupported,
+        }
+        .deserialize(serde_yaml::Deserializer::from_reader(Decompressed::new(
+            reader,
+        )));
+
+        match (unsupported, bom) {
+            (Some(error), _) => Err(error.into()),
+            (None, bom) => Ok(bom?),
+        }
+    }
+
+    /// Output as a YAML document with the structure of the JSON document of the given version of
+    /// the specification
+    #[cfg(feature = "yaml")]
+    pub fn output_as_yaml<W: std::io::Write>(
+        self,
+        writer: &mut W,
+        spec_version: SpecVersion,
+    ) -> Result<(), crate::errors::YamlWriteError> {
+        self.ensure_writable_as(spec_version)?;
+        match spec_version {
+            #[cfg(feature = "v1_3")]
+            SpecVersion::V1_3 => {
+                let bom: crate::specs::v1_3::bom::Bom = self.try_into()?;
+                serde_yaml::to_writer(writer, &bom)?;
+            }
+            #[cfg(feature = "v1_4")]
+            SpecVersion::V1_4 => {
+                serde_yaml::to_writer(writer, &crate::specs::v1_4::bom::Bom::from(self))?;
+            }
+            #[cfg(feature = "v1_5")]
+            SpecVersion::V1_5 => {
+                serde_yaml::to_writer(writer, &crate::specs::v1_5::bom::Bom::from(self))?;
+            }
+            #[allow(unreachable_patterns)]
+            _ => return Err(BomError::UnsupportedSpecVersion(spec_version.to_string()).into()),
+        }
+        Ok(())
+    }
+
+    /// Parse an XML document from an untrusted source, failing if it exceeds the given [`ParseLimits`]
+    ///
+    /// The spec version is detected from the namespace of the document.
+    #[cfg(feature = "xml")]
+    pub fn parse_from_xml_with_limits<R: std::io::Read>(
+        reader: R,
+        limits: &ParseLimits,
+    ) -> Result<Self, crate::errors::XmlReadError> {
+        let input = limits.read_input(reader, |error| {
+            crate::errors::XmlReadError::ElementReadError {
+                error: error.into(),
+                element: "bom".to_string(),
+            }
+        })?;
+        let namespace = limits.check_xml(&input)?;
+
+        Self::parse_from_plain_xml_slice(&input, namespace)
+    }
+
+    /// Parse an XML document held in memory, e.g. in a memory-mapped file
+    ///
+    /// The spec version is detected from the namespace of the document.
+    /// An uncompressed document is parsed in place, without copying it into a buffer first.
+    /// ```
+    /// use cyclonedx_bom::models::bom::Bom;
+    ///
+    /// let input = br#"<bom xmlns="http://cyclonedx.org/schema/bom/1.4" version="2"/>"#;
+    /// let bom = Bom::parse_from_xml_slice(input).unwrap();
+    /// assert_eq!(bom.version, 2);
+    /// ```
+    #[cfg(feature = "xml")]
+    pub fn parse_from_xml_slice(input: &[u8]) -> Result<Self, crate::errors::XmlReadError> {
+        let input = decompress_slice(input).map_err(|error| {
+            crate::errors::XmlReadError::ElementReadError {
+                error: error.into(),
+                element: "bom".to_string(),
+            }
+        })?;
+        let namespace = xml_root_namespace(&input)?;
+
+        Self::parse_from_plain_xml_slice(&input, namespace)
+    }
+
+    /// Parses the document as the spec version of the given namespace of its root element
+    #[cfg(feature = "xml")]
+    fn parse_from_plain_xml_slice(
+        input: &[u8],
+        namespace: Option<String>,
+    ) -> Result<Self, crate::errors::XmlReadError> {
+        match namespace
+            .as_deref()
+            .and_then(|namespace| namespace.strip_prefix(XML_NAMESPACE_PREFIX))
+        {
+            #[cfg(feature = "v1_3")]
+            Some("1.3") => Ok(crate::specs::v1_3::bom::Bom::parse_from_xml(input)?.into()),
+            #[cfg(feature = "v1_4")]
+            Some("1.4") => Ok(crate::specs::v1_4::bom::Bom::parse_from_xml(input)?.into()),
+            #[cfg(feature = "v1_5")]
+            Some("1.5") => Ok(crate::specs::v1_5::bom::Bom::parse_from_xml(input)?.into()),
+            _ => Err(crate::errors::XmlReadError::InvalidNamespaceError {
+                expected_namespace: format!("{}{{1.3,1.4,1.5}}", XML_NAMESPACE_PREFIX),
+                actual_namespace: namespace,
+            }),
+        }
+    }
+
+    /// Parse the input as a JSON document conforming to [version 1.3 of the specification](https://cyclonedx.org/docs/1.3/json/)
+    #[cfg(all(feature = "json", feature = "v1_3"))]
+    pub fn parse_from_json_v1_3<R: std::io::Read>(
+        mut reader: R,
+    ) -> Result<Self, crate::errors::JsonReadError> {
+        let bom: crate::specs::v1_3::bom::Bom =
+            serde_json::from_reader(Decompressed::new(&mut reader))?;
+        Ok(bom.into())
+    }
+
+    /// Parse the input as a JSON document conforming to [version 1.3 of the specification](https://cyclonedx.org/docs/1.3/json/)
+    /// from an existing [`Value`].
+    #[cfg(all(feature = "json", feature = "v1_3"))]
+    pub fn parse_from_json_value_v1_3(value: Value) -> Result<Self, crate::errors::JsonReadError> {
+        let bom: crate::specs::v1_3::bom::Bom = serde_json::from_value(value)?;
+        Ok(bom.into())
+    }
+
+    /// Parse the input as an XML document conforming to [version 1.3 of the specification](https://cyclonedx.org/docs/1.3/xml/)
+    #[cfg(all(feature = "xml", feature = "v1_3"))]
+    pub fn parse_from_xml_v1_3<R: std::io::Read>(
+        reader: R,
+    ) -> Result<Self, crate::errors::XmlReadError> {
+        let bom = crate::specs::v1_3::bom::Bom::parse_from_xml(Decompressed::new(reader))?;
+        Ok(bom.into())
+    }
+
+    /// Output as a JSON document conforming to [version 1.3 of the specification](https://cyclonedx.org/docs/1.3/json/)
+    #[cfg(all(feature = "json", feature = "v1_3"))]
+    pub fn output_as_json_v1_3<W: std::io::Write>(
+        self,
+        writer: &mut W,
+    ) -> Result<(), crate::errors::JsonWriteError> {
+        self.ensure_writable_as(SpecVersion::V1_3)?;
+        let bom: crate::specs::v1_3::bom::Bom = self.try_into()?;
+        serde_json::to_writer_pretty(writer, &bom)?;
+        Ok(())
+    }
+
+    /// Output as an XML document conforming to [version 1.3 of the specification](https://cyclonedx.org/docs/1.3/xml/)
+    #[cfg(all(feature = "xml", feature = "v1_3"))]
+    pub fn output_as_xml_v1_3<W: std::io::Write>(
+        self,
+        writer: &mut W,
+    ) -> Result<(), crate::errors::XmlWriteError> {
+        self.ensure_writable_as(SpecVersion::V1_3)?;
+        let bom: crate::specs::v1_3::bom::Bom = self.try_into()?;
+        bom.output_as_xml(writer)
+    }
+
+    /// Parse the input as a JSON document conforming to [version 1.4 of the specification](https://cyclonedx.org/docs/1.4/json/)
+    #[cfg(all(feature = "json", feature = "v1_4"))]
+    pub fn parse_from_json_v1_4<R: std::io::Read>(
+        mut reader: R,
+    ) -> Result<Self, crate::errors::JsonReadError> {
+        let bom: crate::specs::v1_4::bom::Bom =
+            serde_json::from_reader(Decompressed::new(&mut reader))?;
+        Ok(bom.into())
+    }
+
+    /// Parse the input as an XML document conforming to [version 1.4 of the specification](https://cyclonedx.org/docs/1.4/xml/)
+    #[cfg(all(feature = "xml", feature = "v1_4"))]
+    pub fn parse_from_xml_v1_4<R: std::io::Read>(
+        reader: R,
+    ) -> Result<Self, crate::errors::XmlReadError> {
+        let bom = crate::specs::v1_4::bom::Bom::parse_from_xml(Decompressed::new(reader))?;
+        Ok(bom.into())
+    }
+
+    /// Output as a JSON document conforming to [version 1.4 of the specification](https://cyclonedx.org/docs/1.4/json/)
+    #[cfg(all(feature = "json", feature = "v1_4"))]
+    pub fn output_as_json_v1_4<W: std::io::Write>(
+        self,
+        writer: &mut W,
+    ) -> Result<(), crate::errors::JsonWriteError> {
+        self.ensure_writable_as(SpecVersion::V1_4)?;
+        let bom: crate::specs::v1_4::bom::Bom = self.into();
+        serde_json::to_writer_pretty(writer, &bom)?;
+        Ok(())
+    }
+
+    /// Output as an XML document conforming to [version 1.4 of the specification](https://cyclonedx.org/docs/1.4/xml/)
+    #[cfg(all(feature = "xml", feature = "v1_4"))]
+    pub fn output_as_xml_v1_4<W: std::io::Write>(
+        self,
+        writer: &mut W,
+    ) -> Result<(), crate::errors::XmlWriteError> {
+        self.ensure_writable_as(SpecVersion::V1_4)?;
+        let bom: crate::specs::v1_4::bom::Bom = self.into();
+        bom.output_as_xml(writer)
+    }
+
+    /// Parse the input as a JSON document conforming to [version 1.5 of the specification](https://cyclonedx.org/docs/1.5/json/)
+    #[cfg(all(feature = "json", feature = "v1_5"))]
+    pub fn parse_from_json_v1_5<R: std::io::Read>(
+        mut reader: R,
+    ) -> Result<Self, crate::errors::JsonReadError> {
+        let bom: crate::specs::v1_5::bom::Bom =
+            serde_json::from_reader(Decompressed::new(&mut reader))?;
+        Ok(bom.into())
+    }
+
+    /// Parse the input as an XML document conforming to [version 1.5 of the specification](https://cyclonedx.org/docs/1.5/xml/)
+    #[cfg(all(feature = "xml", feature = "v1_5"))]
+    pub fn parse_from_xml_v1_5<R: std::io::Read>(
+        reader: R,
+    ) -> Result<Self, crate::errors::XmlReadError> {
+        let bom = crate::specs::v1_5::bom::Bom::parse_from_xml(Decompressed::new(reader))?;
+        Ok(bom.into())
+    }
+
+    /// Output as a JSON document conforming to [version 1.5 of the specification](https://cyclonedx.org/docs/1.5/json/)
+    #[cfg(all(feature = "json", feature = "v1_5"))]
+    pub fn output_as_json_v1_5<W: std::io::Write>(
+        self,
+        writer: &mut W,
+    ) -> Result<(), crate::errors::JsonWriteError> {
+        self.ensure_writable_as(SpecVersion::V1_5)?;
+        let bom: crate::specs::v1_5::bom::Bom = self.into();
+        serde_json::to_writer_pretty(writer, &bom)?;
+        Ok(())
+    }
+
+    /// Output as an XML document conforming to [version 1.5 of the specification](https://cyclonedx.org/docs/1.5/xml/)
+    #[cfg(all(feature = "xml", feature = "v1_5"))]
+    pub fn output_as_xml_v1_5<W: std::io::Write>(
+        self,
+        writer: &mut W,
+    ) -> Result<(), crate::errors::XmlWriteError> {
+        self.ensure_writable_as(SpecVersion::V1_5)?;
+        let bom: crate::specs::v1_5::bom::Bom = self.into();
+        bom.output_as_xml(writer)
+    }
+}
+
+#[cfg(feature = "xml")]
+const XML_NAMESPACE_PREFIX: &str = "http://cyclonedx.org/schema/bom/";
+
+/// Reads the default namespace of the root element of an XML document
+#[cfg(feature = "xml")]
+fn xml_root_namespace(input: &[u8]) -> Result<Option<String>, crate::errors::XmlReadError> {
+    let mut event_reader = EventReader::new(input);
+    loop {
+        match event_reader
+            .next()
+            .map_err(crate::xml::to_xml_read_error("bom"))?
+        {
+            xml::reader::XmlEvent::StartElement { namespace, .. } => {
+                return Ok(namespace
+                    .get(xml::namespace::NS_NO_PREFIX)
+                    .map(String::from))
+            }
+            xml::reader::XmlEvent::EndDocument => return Ok(None),
+            _ => {}
+        }
+    }
+}
+
+#[cfg(feature = "json")]
+const SPEC_VERSION_FIELD: &str = "specVersion";
+
+/// Deserializes a JSON document into the BOM of the spec version named in its `specVersion` field
+///
+/// The fields before `specVersion`, usually only `bomFormat`, are buffered and handed to the
+/// spec version's BOM together with the rest of the document, so the input is only read once.
+/// The components are converted into the model while they are read.
+#[cfg(feature = "json")]
+struct VersionedBomSeed<'a> {
+    /// Set if the spec version is missing or not supported
+    unsupported: &'a mut Option<BomError>,
+}
+
+#[cfg(feature = "json")]
+impl VersionedBomSeed<'_> {
+    fn fail<E: de::Error>(self, error: BomError) -> E {
+        let message = E::custom(&error);
+        *self.unsupported = Some(error);
+        message
+    }
+}
+
+#[cfg(feature = "json")]
+impl<'de> DeserializeSeed<'de> for VersionedBomSeed<'_> {
+    type Value = Bom;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Bom, D::Error> {
+        deserializer.deserialize_map(self)
+    }
+}
+
+#[cfg(feature = "json")]
+impl<'de> Visitor<'de> for VersionedBomSeed<'_> {
+    type Value = Bom;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a CycloneDX BOM")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Bom, A::Error> {
+        let mut fields = Vec::new();
+        while let Some(key) = map.next_key::<String>()? {
+            let value: Value = map.next_value()?;
+            if key != SPEC_VERSION_FIELD {
+                fields.push((key, value));
+                continue;
+            }
+
+            let spec_version = match value.as_str() {
+                Some(version) => SpecVersion::from_str(version),
+                None => Err(BomError::UnsupportedSpecVersion(value.to_string())),
+            };
+            let spec_version = match spec_version {
+                Ok(spec_version) => spec_version,
+                Err(error) => return Err(self.fail(error)),
+            };
+
+            fields.push((key, value));
+            let deserializer = MapAccessDeserializer::new(ReplayedMap {
+                fields: fields.into_iter(),
+                value: None,
+                map,
+            });
+            return match spec_version {
+                #[cfg(feature = "v1_3")]
+                SpecVersion::V1_3 => {
+                    crate::specs::v1_3::bom::ModelComponentsBom::deserialize(deserializer)
+                        .map(Bom::from)
+                }
+                #[cfg(feature = "v1_4")]
+                SpecVersion::V1_4 => {
+                    crate::specs::v1_4::bom::ModelComponentsBom::deserialize(deserializer)
+                        .map(Bom::from)
+                }
+                #[cfg(feature = "v1_5")]
+                SpecVersion::V1_5 => {
+                    crate::specs::v1_5::bom::ModelComponentsBom::deserialize(deserializer)
+                        .map(Bom::from)
+                }
+                #[allow(unreachable_patterns)]
+                _ => Err(self.fail(BomError::UnsupportedSpecVersion(spec_version.to_string()))),
+            };
+        }
+
+        Err(self.fail(BomError::UnsupportedSpecVersion(
+            "No field 'specVersion' found".to_string(),
+        )))
+    }
+}
+
+/// The buffered fields of a JSON object, followed by the fields that have not been read yet
+#[cfg(feature = "json")]
+struct ReplayedMap<A> {
+    fields: std::vec::IntoIter<(String, Value)>,
+    value: Option<Value>,
+    map: A,
+}
+
+#[cfg(feature = "json")]
+impl<'de, A: MapAccess<'de>> MapAccess<'de> for ReplayedMap<A> {
+    type Error = A::Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, A::Error> {
+        match self.fields.next() {
+            Some((key, value)) => {
+                self.value = Some(value);
+                seed.deserialize(key.into_deserializer()).map(Some)
+            }
+            None => self.map.next_key_seed(seed),
+        }
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, A::Error> {
+        match self.value.take() {
+            Some(value) => seed.deserialize(value).map_err(de::Error::custom),
+            None => self.map.next_value_seed(seed),
+        }
+    }
+}
+
+impl Default for Bom {
+    /// Construct a BOM with a default `version` of `1` and `serial_number` with a random UUID,
+    /// or no `serial_number` without the `uuid` feature
+    fn default() -> Self {
+        #[cfg(feature = "uuid")]
+        let serial_number = Some(UrnUuid::generate());
+        #[cfg(not(feature = "uuid"))]
+        let serial_number = None;
+
+        Self {
+            version: 1,
+            serial_number,
+            metadata: None,
+            components: None,
+            services: None,
+            external_references: None,
+            dependencies: None,
+            compositions: None,
+            properties: None,
+            vulnerabilities: None,
+            signature: None,
+            formulation: None,
+            annotations: None,
+        }
+    }
+}
+
+/// Builds a [`Bom`] whose metadata is filled in, and which is validated before it is returned
+///
+/// Unless they are given, the BOM gets a random serial number (with the `uuid` feature) and the
+/// current time as the timestamp of its metadata. This crate is registered as a tool, after the
+/// tools added with [`BomBuilder::tool`].
+/// ```
+/// use cyclonedx_bom::models::bom::Bom;
+/// use cyclonedx_bom::models::component::{Classification, Component};
+/// use cyclonedx_bom::models::tool::Tool;
+///
+/// let bom = Bom::builder()
+///     .tool(Tool::new("ACME", "sbom-generator", "2.1.0"))
+///     .metadata_component(Component::new(Classification::Application, "app", "1.0.0", None))
+///     .component(Component::new(Classification::Library, "lib", "0.3.0", None))
+///     .build()
+///     .unwrap();
+///
+/// let metadata = bom.metadata.unwrap();
+/// assert!(metadata.timestamp.is_some());
+/// assert_eq!(metadata.tools.unwrap().into_list().len(), 2);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct BomBuilder {
+    bom: Bom,
+    tools: Vec<Tool>,
+}
+
+impl BomBuilder {
+    pub fn version(mut self, version: u32) -> Self {
+        self.bom.version = version;
+        self
+    }
+
+    pub fn serial_number(mut self, serial_number: UrnUuid) -> Self {
+        self.bom.serial_number = Some(serial_number);
+        self
+    }
+
+    /// Starts from the given metadata, which keeps its timestamp and tools if it has them
+    pub fn metadata(mut self, metadata: Metadata) -> Self {
+        self.bom.metadata = Some(metadata);
+        self
+    }
+
+    pub fn timestamp(mut self, timestamp: DateTime) -> Self {
+        self.metadata_mut().timestamp = Some(timestamp);
+        self
+    }
+
+    /// Registers a tool that produced the BOM
+    pub fn tool(mut self, tool: Tool) -> Self {
+        self.tools.push(tool);
+        self
+    }
+
+    /// Sets the component the BOM describes
+    pub fn metadata_component(mut self, component: Component) -> Self {
+        self.metadata_mut().component = Some(component);
+        self
+    }
+
+    pub fn component(mut self, component: Component) -> Self {
+        self.bom
+            .components
+            .get_or_insert_with(|| Components(Vec::new()))
+            .0
+            .push(component);
+        self
+    }
+
+    pub fn service(mut self, service: Service) -> Self {
+        self.bom
+            .services
+            .get_or_insert_with(|| Services(Vec::new()))
+            .0
+            .push(service);
+        self
+    }
+
+    pub fn dependency(mut self, dependency: Dependency) -> Self {
+        self.bom
+            .dependencies
+            .get_or_insert_with(|| Dependencies(Vec::new()))
+            .0
+            .push(dependency);
+        self
+    }
+
+    pub fn external_reference(mut self, external_reference: ExternalReference) -> Self {
+        self.bom
+            .external_references
+            .get_or_insert_with(|| ExternalReferences(Vec::new()))
+            .0
+            .push(external_reference);
+        self
+    }
+
+    /// Fills in the metadata and validates the BOM
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the current time cannot be determined, or with the reasons the BOM
+    /// failed validation.
+    pub fn build(mut self) -> Result<Bom, BomBuildError> {
+        let mut tools = std::mem::take(&mut self.tools);
+        tools.push(Tool::new(
+            "CycloneDX",
+            "cyclonedx-bom",
+            env!("CARGO_PKG_VERSION"),
+        ));
+
+        let metadata = self.metadata_mut();
+        if metadata.timestamp.is_none() {
+            metadata.timestamp = Some(DateTime::now()?);
+        }
+        metadata.tools = Some(match metadata.tools.take() {
+            Some(Tools::List(mut existing)) => {
+                existing.extend(tools);
+                Tools::List(existing)
+            }
+            Some(Tools::Object {
+                components,
+                services,
+            }) => {
+                let mut components = components.unwrap_or_else(|| Components(Vec::new()));
+                components.0.extend(tools.into_iter().map(Component::from));
+                Tools::Object {
+                    components: Some(components),
+                    services,
+                }
+            }
+            None => Tools::List(tools),
+        });
+
+        match self.bom.validate() {
+            ValidationResult::Passed => Ok(self.bom),
+            ValidationResult::Failed { reasons } => Err(BomBuildError::Invalid { reasons }),
+        }
+    }
+
+    fn metadata_mut(&mut self) -> &mut Metadata {
+        self.bom.metadata.get_or_insert_with(Metadata::default)
+    }
+}
+
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum BomRevisionError {
+    #[error("A BOM without a serial number cannot be revised")]
+    MissingSerialNumber,
+    #[error("The version of the BOM cannot be incremented")]
+    VersionOverflow,
+}
+
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum BomBuildError {
+    #[error("Failed to set the timestamp: {0}")]
+    Timestamp(#[from] DateTimeError),
+    #[error("The BOM failed validation with {} failures", .reasons.len())]
+    Invalid { reasons: Vec<FailureReason> },
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Bom {
+    /// Generates a BOM whose bom-refs are unique, and whose dependencies, compositions and
+    /// annotations only refer to its own components and services
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        use crate::models::dependency::Dependency;
+
+        let mut bom = Self {
+            version: u.int_in_range(1..=u32::MAX)?,
+            serial_number: u.arbitrary()?,
+            metadata: u.arbitrary()?,
+            components: u.arbitrary()?,
+            services: u.arbitrary()?,
+            external_references: u.arbitrary()?,
+            dependencies: None,
+            compositions: None,
+            properties: u.arbitrary()?,
+            vulnerabilities: u.arbitrary()?,
+            signature: u.arbitrary()?,
+            formulation: u.arbitrary()?,
+            annotations: None,
+        };
+
+        let mut bom_refs: Vec<String> = Vec::new();
+        let mut claim = |bom_ref: &mut Option<String>| {
+            if let Some(bom_ref) = bom_ref {
+                while bom_refs.contains(bom_ref) {
+                    bom_ref.push('~');
+                }
+                bom_refs.push(bom_ref.clone());
+            }
+        };
+        bom.map_components(|component| claim(&mut component.bom_ref));
+        bom.map_services(|service| claim(&mut service.bom_ref));
+
+        if bom_refs.is_empty() {
+            return Ok(bom);
+        }
+
+        let mut dependencies = Vec::new();
+        for _ in 0..u.arbitrary_len::<u8>()? {
+            let dependency_ref = u.choose(&bom_refs)?.clone();
+            let depends_on = (0..u.arbitrary_len::<u8>()?)
+                .map(|_| u.choose(&bom_refs).cloned())
+                .collect::<arbitrary::Result<_>>()?;
+            dependencies.push(Dependency::new(dependency_ref, depends_on));
+        }
+        bom.dependencies = Some(Dependencies(dependencies));
+
+        let mut compositions: Option<Compositions> = u.arbitrary()?;
+        for composition in compositions.iter_mut().flat_map(|c| c.0.iter_mut()) {
+            for BomReference(bom_ref) in composition
+                .assemblies
+                .iter_mut()
+                .chain(composition.dependencies.iter_mut())
+                .flatten()
+            {
+                *bom_ref = u.choose(&bom_refs)?.clone();
+            }
+        }
+        bom.compositions = compositions;
+
+        let mut annotations: Option<Annotations> = u.arbitrary()?;
+        for annotation in annotations.iter_mut().flat_map(|a| a.0.iter_mut()) {
+            if annotation.subjects.is_empty() {
+                annotation
+                    .subjects
+                    .push(AnnotationSubject::BomReference(BomReference(String::new())));
+            }
+            for subject in annotation.subjects.iter_mut() {
+                *subject =
+                    AnnotationSubject::BomReference(BomReference(u.choose(&bom_refs)?.clone()));
+            }
+        }
+        bom.annotations = annotations;
+
+        Ok(bom)
+    }
+}
+
+impl Validate for Bom {
+    fn validate_with_context(&self, context: ValidationContext) -> ValidationResult {
+        let mut results: Vec<ValidationResult> = vec![];
+
+        let mut bom_refs_context = BomReferencesContext::default();
+
+        if let Some(serial_number) = &self.serial_number {
+            let context = context.with_struct("Bom", "serial_number");
+
+            results.push(serial_number.validate_with_context(context));
+        }
+
+        if let Some(metadata) = &self.metadata {
+            let context = context.with_struct("Bom", "metadata");
+            let component_bom_ref_context = context.with_struct("Metadata", "component");
+
+            results.push(metadata.validate_with_context(context));
+
+            if let Some(component) = &metadata.component {
+                validate_component_bom_refs(
+                    component,
+                    &mut bom_refs_context,
+                    &component_bom_ref_context,
+                    &mut results,
+                );
+            }
+        }
+
+        if let Some(components) = &self.components {
+            let context = context.with_struct("Bom", "components");
+            let component_bom_ref_context = context.clone();
+
+            results.push(components.validate_with_context(context));
+
+            // record the component references
+            validate_components(
+                components,
+                &mut bom_refs_context,
+                &component_bom_ref_context,
+                &mut results,
+            );
+        }
+
+        if let Some(services) = &self.services {
+            let context = context.with_struct("Bom", "services");
+            let service_bom_ref_context = context.clone();
+
+            results.push(services.validate_with_context(context));
+
+            // record the service references
+            validate_services(
+                services,
+                &mut bom_refs_context,
+                &service_bom_ref_context,
+                &mut results,
+            );
+        }
+
+        if let Some(external_references) = &self.external_references {
+            let context = context.with_struct("Bom", "external_references");
+
+            results.push(external_references.validate_with_context(context));
+        }
+
+        if let Some(dependencies) = &self.dependencies {
+            let context = context.with_struct("Bom", "dependencies");
+
+            for (dependency_index, dependency) in dependencies.0.iter().enumerate() {
+                let context = context.extend_context(vec![ValidationPathComponent::Array {
+                    index: dependency_index,
+                }]);
+                if !bom_refs_context.contains(&dependency.dependency_ref) {
+                    let dependency_context = context.with_struct("Dependency", "dependency_ref");
+
+                    results.push(ValidationResult::failure(
+                        "Dependency reference does not exist in the BOM",
+                        dependency_context,
+                    ));
+                }
+
+                for (sub_dependency_index, sub_dependency) in
+                    dependency.dependencies.iter().enumerate()
+                {
+                    if !bom_refs_context.contains(sub_dependency) {
+                        let context = context.extend_context(vec![
+                            ValidationPathComponent::Struct {
+                                struct_name: "Dependency".to_string(),
+                                field_name: "dependencies".to_string(),
+                            },
+                            ValidationPathComponent::Array {
+                                index: sub_dependency_index,
+                            },
+                        ]);
+
+                        results.push(ValidationResult::failure(
+                            "Dependency reference does not exist in the BOM",
+                            context,
+                        ));
+                    }
+                }
+            }
+        }
+
+        if let Some(compositions) = &self.compositions {
+            let context = context.with_struct("Bom", "compositions");
+            let compositions_context = context.clone();
+
+            results.push(compositions.validate_with_context(context));
+
+            for (composition_index, composition) in compositions.0.iter().enumerate() {
+                let compositions_context =
                     compositions_context.extend_context(vec![ValidationPathComponent::Array {
                         index: composition_index,
                     }]);
@@ -383,6 +2035,52 @@ impl Validate for Bom {
             results.push(vulnerabilities.validate_with_context(context));
         }
 
+        if let Some(formulation) = &self.formulation {
+            let context = context.with_struct("Bom", "formulation");
+            results.push(formulation.validate_with_context(context));
+        }
+
+        if let Some(annotations) = &self.annotations {
+            let context = context.with_struct("Bom", "annotations");
+            let annotations_context = context.clone();
+
+            results.push(annotations.validate_with_context(context));
+
+            for vulnerability in self.vulnerabilities.iter().flat_map(|v| v.0.iter()) {
+                if let Some(bom_ref) = &vulnerability.bom_ref {
+                    bom_refs_context.add_vulnerability_bom_ref(bom_ref);
+                }
+            }
+
+            for (annotation_index, annotation) in annotations.0.iter().enumerate() {
+                let annotations_context = annotations_context
+                    .with_index(annotation_index)
+                    .with_struct("Annotation", "subjects");
+
+                for (subject_index, subject) in annotation.subjects.iter().enumerate() {
+                    let bom_ref = match subject {
+                        AnnotationSubject::BomReference(BomReference(bom_ref)) => {
+                            Some(bom_ref.as_str())
+                        }
+                        // only links to an element of this very BOM can be resolved
+                        AnnotationSubject::BomLink(bom_link) if self.is_linked_by(bom_link) => {
+                            bom_link.bom_ref()
+                        }
+                        AnnotationSubject::BomLink(_) => None,
+                    };
+
+                    if let Some(bom_ref) = bom_ref {
+                        if !bom_refs_context.contains(bom_ref) {
+                            results.push(ValidationResult::failure(
+                                "Annotation subject does not exist in the BOM",
+                                annotations_context.with_index(subject_index),
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+
         results
             .into_iter()
             .fold(ValidationResult::default(), |acc, result| acc.merge(result))
@@ -393,11 +2091,14 @@ impl Validate for Bom {
 struct BomReferencesContext {
     component_bom_refs: HashSet<String>,
     service_bom_refs: HashSet<String>,
+    vulnerability_bom_refs: HashSet<String>,
 }
 
 impl BomReferencesContext {
-    fn contains(&self, bom_ref: &String) -> bool {
-        self.component_bom_refs.contains(bom_ref) || self.service_bom_refs.contains(bom_ref)
+    fn contains(&self, bom_ref: &str) -> bool {
+        self.component_bom_refs.contains(bom_ref)
+            || self.service_bom_refs.contains(bom_ref)
+            || self.vulnerability_bom_refs.contains(bom_ref)
     }
 
     fn add_component_bom_ref(&mut self, bom_ref: impl ToString) {
@@ -407,6 +2108,10 @@ impl BomReferencesContext {
     fn add_service_bom_ref(&mut self, bom_ref: impl ToString) {
         self.service_bom_refs.insert(bom_ref.to_string());
     }
+
+    fn add_vulnerability_bom_ref(&mut self, bom_ref: impl ToString) {
+        self.vulnerability_bom_refs.insert(bom_ref.to_string());
+    }
 }
 
 fn validate_component_bom_refs(
@@ -465,112 +2170,906 @@ fn validate_service_bom_refs(
         bom_refs.add_service_bom_ref(bom_ref);
     }
 
-    if let Some(services) = &service.services {
-        let context = context.with_struct("Service", "services");
-        validate_services(services, bom_refs, &context, results);
-    }
-}
+    if let Some(services) = &service.services {
+        let context = context.with_struct("Service", "services");
+        validate_services(services, bom_refs, &context, results);
+    }
+}
+
+fn validate_services(
+    services: &Services,
+    bom_refs: &mut BomReferencesContext,
+    context: &ValidationContext,
+    results: &mut Vec<ValidationResult>,
+) {
+    // record the service references
+    for (service_index, service) in services.0.iter().enumerate() {
+        let context = context.extend_context(vec![ValidationPathComponent::Array {
+            index: service_index,
+        }]);
+
+        validate_service_bom_refs(service, bom_refs, &context, results);
+    }
+}
+
+/// The namespace of the UUIDs derived from the content of a BOM
+#[cfg(feature = "uuid")]
+const CONTENT_NAMESPACE: uuid::Uuid = uuid::Uuid::from_u128(0x326e4bf4_6ba9_4335_b7f7_849993902486);
+
+/// A serial number in the `urn:uuid:` format of RFC 4122, e.g. of a BOM
+/// ```
+/// use cyclonedx_bom::models::bom::UrnUuid;
+///
+/// assert!(UrnUuid::new("urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79".to_string()).is_ok());
+/// assert!(UrnUuid::new("3e671687-395b-41f5-a30f-a58921a69b79".to_string()).is_err());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UrnUuid(pub(crate) String);
+
+impl UrnUuid {
+    pub fn new(value: String) -> Result<Self, UrnUuidError> {
+        match matches_urn_uuid_regex(&value) {
+            true => Ok(Self(value)),
+            false => Err(UrnUuidError::InvalidUrnUuid(
+                "UrnUuid does not match regular expression".to_string(),
+            )),
+        }
+    }
+
+    /// Creates a random (version 4) UUID
+    #[cfg(feature = "uuid")]
+    pub fn generate() -> Self {
+        Self::from(uuid::Uuid::new_v4())
+    }
+
+    /// Creates a name-based (version 5) UUID from the given content, so that the same
+    /// content always gets the same UUID
+    #[cfg(feature = "uuid")]
+    pub fn from_content(content: &[u8]) -> Self {
+        Self::from(uuid::Uuid::new_v5(&CONTENT_NAMESPACE, content))
+    }
+}
+
+impl fmt::Display for UrnUuid {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(feature = "uuid")]
+impl From<uuid::Uuid> for UrnUuid {
+    fn from(uuid: uuid::Uuid) -> Self {
+        Self(format!("urn:uuid:{}", uuid))
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for UrnUuid {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        use crate::utilities::arbitrary_hex;
+
+        Ok(Self(format!(
+            "urn:uuid:{}-{}-{}-{}-{}",
+            arbitrary_hex(u, 8)?,
+            arbitrary_hex(u, 4)?,
+            arbitrary_hex(u, 4)?,
+            arbitrary_hex(u, 4)?,
+            arbitrary_hex(u, 12)?
+        )))
+    }
+}
+
+impl Validate for UrnUuid {
+    fn validate_with_context(&self, context: ValidationContext) -> ValidationResult {
+        match matches_urn_uuid_regex(&self.0) {
+            true => ValidationResult::Passed,
+            false => {
+                ValidationResult::failure("UrnUuid does not match regular expression", context)
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum UrnUuidError {
+    InvalidUrnUuid(String),
+}
+
+fn matches_urn_uuid_regex(value: &str) -> bool {
+    static UUID_REGEX: Lazy<Regex> = Lazy::new(|| {
+        Regex::new(r"^urn:uuid:[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}$")
+            .expect("Failed to compile regex.")
+    });
+    UUID_REGEX.is_match(value)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        external_models::{date_time::DateTime, normalized_string::NormalizedString, uri::Uri},
+        models::{
+            annotation::{Annotation, Annotator},
+            component::{Classification, Component},
+            composition::{AggregateType, BomReference, Composition},
+            dependency::Dependency,
+            external_reference::{ExternalReference, ExternalReferenceType},
+            organization::OrganizationalEntity,
+            property::Property,
+            service::Service,
+            vulnerability::Vulnerability,
+            vulnerability_target::{VulnerabilityTarget, VulnerabilityTargets},
+        },
+        validation::{FailureReason, ValidationPathComponent},
+    };
+
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[cfg(all(feature = "json", feature = "v1_3"))]
+    #[test]
+    fn it_should_parse_json_using_function_without_suffix() {
+        let input = r#"{
+            "bomFormat": "CycloneDX",
+            "specVersion": "1.3",
+            "serialNumber": "urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79",
+            "version": 1,
+            "components": []
+        }"#;
+        let result = Bom::parse_from_json(input.as_bytes());
+        assert!(result.is_ok());
+    }
+
+    #[cfg(all(feature = "json", feature = "v1_4"))]
+    #[test]
+    fn it_should_parse_json_with_the_spec_version_after_other_fields() {
+        let expected = Bom::parse_from_json(
+            r#"{"bomFormat": "CycloneDX", "specVersion": "1.4", "version": 2, "components": [{"type": "library", "name": "serde"}]}"#
+                .as_bytes(),
+        )
+        .unwrap();
+
+        let actual = Bom::parse_from_json(
+            r#"{"components": [{"type": "library", "name": "serde"}], "version": 2, "bomFormat": "CycloneDX", "specVersion": "1.4"}"#
+                .as_bytes(),
+        )
+        .unwrap();
+
+        assert_eq!(actual, expected);
+        assert_eq!(actual.components.unwrap().0.len(), 1);
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn it_should_reject_unsupported_spec_versions_in_json() {
+        for (input, expected) in [
+            (r#"{"specVersion": "2.0"}"#, "2.0"),
+            (r#"{"specVersion": 1.5}"#, "1.5"),
+            (r#"{"version": 1}"#, "No field 'specVersion' found"),
+        ] {
+            match Bom::parse_from_json(input.as_bytes()) {
+                Err(crate::errors::JsonReadError::BomError {
+                    error: BomError::UnsupportedSpecVersion(version),
+                }) => assert_eq!(version, expected),
+                other => panic!("unexpected result {:?}", other),
+            }
+        }
+    }
+
+    #[cfg(all(feature = "json", not(feature = "v1_3")))]
+    #[test]
+    fn it_should_reject_disabled_spec_versions_in_json() {
+        let input = r#"{"bomFormat": "CycloneDX", "specVersion": "1.3", "version": 1}"#;
+        match Bom::parse_from_json(input.as_bytes()) {
+            Err(crate::errors::JsonReadError::BomError {
+                error: BomError::UnsupportedSpecVersion(version),
+            }) => assert_eq!(version, "1.3"),
+            other => panic!("unexpected result {:?}", other),
+        }
+    }
+
+    #[cfg(all(feature = "json", feature = "xml"))]
+    #[test]
+    fn it_should_parse_within_limits() {
+        let bom = Bom {
+            serial_number: None,
+            ..Bom::default()
+        };
+        let limits = ParseLimits::default();
+
+        let mut json = Vec::new();
+        bom.clone().output_as_json_v1_4(&mut json).unwrap();
+        assert_eq!(
+            Bom::parse_from_json_with_limits(json.as_slice(), &limits).unwrap(),
+            bom
+        );
+
+        let mut xml = Vec::new();
+        bom.clone().output_as_xml_v1_3(&mut xml).unwrap();
+        assert_eq!(
+            Bom::parse_from_xml_with_limits(xml.as_slice(), &limits).unwrap(),
+            bom
+        );
+    }
+
+    #[cfg(all(feature = "json", feature = "xml"))]
+    #[test]
+    fn it_should_reject_documents_exceeding_limits() {
+        let limits = ParseLimits {
+            max_depth: 2,
+            ..ParseLimits::default()
+        };
+
+        let json = r#"{"specVersion": "1.5", "metadata": {"tools": [{"name": "tool"}]}}"#;
+        assert!(matches!(
+            Bom::parse_from_json_with_limits(json.as_bytes(), &limits),
+            Err(crate::errors::JsonReadError::LimitExceeded {
+                error: crate::errors::LimitError::MaxDepthExceeded { limit: 2 }
+            })
+        ));
+
+        let xml = r#"<bom xmlns="http://cyclonedx.org/schema/bom/1.5"><metadata><tools/></metadata></bom>"#;
+        assert!(matches!(
+            Bom::parse_from_xml_with_limits(xml.as_bytes(), &limits),
+            Err(crate::errors::XmlReadError::LimitExceeded {
+                error: crate::errors::LimitError::MaxDepthExceeded { limit: 2 }
+            })
+        ));
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn it_should_read_and_write_json_asynchronously() {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        let bom = Bom {
+            serial_number: None,
+            ..Bom::default()
+        };
+
+        let mut output = Vec::new();
+        runtime
+            .block_on(
+                bom.clone()
+                    .output_as_json_async(&mut output, SpecVersion::V1_4),
+            )
+            .unwrap();
+
+        let mut expected = Vec::new();
+        bom.clone().output_as_json_v1_4(&mut expected).unwrap();
+        assert_eq!(output, expected);
+
+        let actual = runtime
+            .block_on(Bom::parse_from_json_async(output.as_slice()))
+            .unwrap();
+        assert_eq!(actual, bom);
+    }
+
+    #[cfg(all(feature = "json", feature = "xml"))]
+    #[test]
+    fn it_should_parse_from_slices() {
+        let bom = Bom {
+            serial_number: None,
+            ..Bom::default()
+        };
+
+        let mut json = Vec::new();
+        bom.clone().output_as_json_v1_3(&mut json).unwrap();
+        assert_eq!(Bom::parse_from_json_slice(&json).unwrap(), bom);
+
+        let mut xml = Vec::new();
+        bom.clone().output_as_xml_v1_5(&mut xml).unwrap();
+        assert_eq!(Bom::parse_from_xml_slice(&xml).unwrap(), bom);
+
+        assert!(matches!(
+            Bom::parse_from_xml_slice(br#"<bom xmlns="http://cyclonedx.org/schema/bom/2.0"/>"#),
+            Err(crate::errors::XmlReadError::InvalidNamespaceError { .. })
+        ));
+    }
+
+    #[cfg(all(feature = "xml", feature = "v1_4"))]
+    #[test]
+    fn it_should_skip_written_extensions_when_reading() {
+        struct NamespacedExtension;
+
+        impl crate::xml::ToXml for NamespacedExtension {
+            fn write_xml_element<W: std::io::Write>(
+                &self,
+                writer: &mut xml::EventWriter<W>,
+            ) -> Result<(), crate::errors::XmlWriteError> {
+                writer
+                    .write(
+                        xml::writer::XmlEvent::start_element("ext:note")
+                            .ns("ext", "urn:example:extension"),
+                    )
+                    .map_err(crate::xml::to_xml_write_error("ext:note"))?;
+                crate::xml::write_close_tag(writer, "ext:note")
+            }
+        }
+
+        let bom = Bom {
+            serial_number: None,
+            ..Bom::default()
+        };
+        let mut output = Vec::new();
+        bom.clone()
+            .output_as_xml_with_extensions(&mut output, SpecVersion::V1_4, &[NamespacedExtension])
+            .unwrap();
+
+        assert!(String::from_utf8_lossy(&output).contains("<ext:note"));
+        assert_eq!(Bom::parse_from_xml_v1_4(output.as_slice()).unwrap(), bom);
+    }
+
+    #[cfg(all(feature = "json", feature = "v1_5"))]
+    #[test]
+    fn it_should_write_back_the_unknown_json_fields() {
+        let input = serde_json::json!({
+            "bomFormat": "CycloneDX",
+            "specVersion": "1.5",
+            "serialNumber": "urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79",
+            "version": 1,
+            "x-top": "top",
+            "metadata": {
+                "component": { "type": "application", "name": "app", "x-metadata": 1 }
+            },
+            "components": [
+                { "type": "library", "name": "first", "x-first": [1, 2] },
+                { "type": "library", "bom-ref": "second", "name": "second", "x-second": {} }
+            ]
+        });
+        let (mut bom, unknown_fields) =
+            Bom::parse_from_json_with_unknown_fields(input.to_string().as_bytes()).unwrap();
+        assert_eq!(unknown_fields.len(), 4);
+
+        let (unchanged, no_fields) = Bom::parse_from_json_with_unknown_fields(
+            br#"{"bomFormat": "CycloneDX", "specVersion": "1.5", "version": 1}"#.as_slice(),
+        )
+        .unwrap();
+        assert!(no_fields.is_empty());
+        assert_eq!(unchanged.version, 1);
+
+        bom.version = 2;
+        let mut output = Vec::new();
+        bom.output_as_json_with_unknown_fields(&mut output, SpecVersion::V1_5, &unknown_fields)
+            .unwrap();
+        let ordered: crate::json::OrderedValue = serde_json::from_slice(&output).unwrap();
+        let output: Value = serde_json::from_slice(&output).unwrap();
+
+        let mut expected = input;
+        expected["version"] = 2.into();
+        assert_eq!(output, expected);
+
+        let keys = |value: &crate::json::OrderedValue| match value {
+            crate::json::OrderedValue::Object(object) => object
+                .iter()
+                .map(|(name, _)| name.clone())
+                .collect::<Vec<_>>(),
+            _ => panic!("expected an object, got {:?}", value),
+        };
+        assert_eq!(
+            keys(&ordered),
+            vec![
+                "bomFormat",
+                "specVersion",
+                "serialNumber",
+                "version",
+                "metadata",
+                "components",
+                "x-top"
+            ]
+        );
+        let second_component = match ordered.get("components") {
+            Some(crate::json::OrderedValue::Array(components)) => &components[1],
+            other => panic!("expected the components, got {:?}", other),
+        };
+        assert_eq!(
+            keys(second_component),
+            vec!["type", "bom-ref", "name", "x-second"]
+        );
+    }
+
+    #[cfg(all(feature = "yaml", feature = "v1_5"))]
+    #[test]
+    fn it_should_read_and_write_yaml_like_json() {
+        let json = r#"{
+            "bomFormat": "CycloneDX",
+            "specVersion": "1.5",
+            "serialNumber": "urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79",
+            "version": 3,
+            "metadata": { "timestamp": "2024-01-02T03:04:05Z" },
+            "components": [
+                { "type": "library", "bom-ref": "lib", "name": "lib", "version": "1.0" }
+            ],
+            "dependencies": [{ "ref": "lib", "dependsOn": [] }]
+        }"#;
+        let bom = Bom::parse_from_json(json.as_bytes()).unwrap();
+
+        let mut output = Vec::new();
+        bom.clone()
+            .output_as_yaml(&mut output, SpecVersion::V1_5)
+            .unwrap();
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.starts_with(
+            "bomFormat: CycloneDX\nspecVersion: '1.5'\nserialNumber: urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79\nversion: 3\n"
+        ));
+        assert_eq!(Bom::parse_from_yaml(output.as_bytes()).unwrap(), bom);
+
+        match Bom::parse_from_yaml("specVersion: '2.0'".as_bytes()) {
+            Err(crate::errors::YamlReadError::BomError {
+                error: BomError::UnsupportedSpecVersion(version),
+            }) => assert_eq!(version, "2.0"),
+            other => panic!("unexpected result {:?}", other),
+        }
+    }
+
+    #[cfg(all(feature = "json", feature = "v1_4"))]
+    #[test]
+    fn it_should_write_json_in_the_given_format() {
+        use crate::json::JsonFormat;
+
+        let bom = Bom {
+            serial_number: None,
+            metadata: Some(Metadata {
+                timestamp: Some(DateTime("1969-06-28T01:20:00.00-04:00".to_string())),
+                ..Metadata::default()
+            }),
+            ..Bom::default()
+        };
+        let output = |format| {
+            let mut output = Vec::new();
+            bom.clone()
+                .output_as_json_with_format(&mut output, SpecVersion::V1_4, format)
+                .unwrap();
+            String::from_utf8(output).unwrap()
+        };
+
+        let mut pretty = Vec::new();
+        bom.clone().output_as_json_v1_4(&mut pretty).unwrap();
+        assert_eq!(
+            output(JsonFormat::Pretty),
+            String::from_utf8(pretty).unwrap()
+        );
+        assert_eq!(
+            output(JsonFormat::Compact),
+            r#"{"bomFormat":"CycloneDX","specVersion":"1.4","serialNumber":null,"version":1,"metadata":{"timestamp":"1969-06-28T01:20:00.00-04:00"}}"#
+        );
+    }
+
+    #[cfg(all(feature = "json", feature = "v1_4"))]
+    #[test]
+    fn it_should_reject_unknown_json_fields_when_strict() {
+        let parse = |input: serde_json::Value| {
+            Bom::parse_from_json_strict(input.to_string().as_bytes()).map_err(|error| match error {
+                crate::errors::JsonReadError::UnknownField {
+                    field,
+                    spec_version,
+                } => format!("{field} in {spec_version}"),
+                other => panic!("Expected an unknown field, got {other}"),
+            })
+        };
+
+        let bom = parse(serde_json::json!({
+            "bomFormat": "CycloneDX",
+            "specVersion": "1.4",
+            "version": 3,
+            "components": [{ "type": "library", "name": "lib" }]
+        }))
+        .unwrap();
+        assert_eq!(bom.version, 3);
+
+        assert_eq!(
+            parse(serde_json::json!({
+                "bomFormat": "CycloneDX",
+                "specVersion": "1.4",
+                "version": 1,
+                "components": [
+                    { "type": "library", "name": "first" },
+                    {
+                        "type": "library",
+                        "name": "second",
+                        "components": [{ "type": "library", "name": "nested", "verison": "1.0" }]
+                    }
+                ]
+            })),
+            Err("components[1].components[0].verison in 1.4".to_string())
+        );
+        assert_eq!(
+            parse(serde_json::json!({
+                "bomFormat": "CycloneDX",
+                "specVersion": "1.4",
+                "version": 1,
+                "metadata": { "lifecycles": [{ "phase": "build" }] }
+            })),
+            Err("metadata.lifecycles in 1.4".to_string())
+        );
+    }
+
+    #[cfg(all(feature = "xml", feature = "v1_5"))]
+    #[test]
+    fn it_should_write_back_the_xml_extensions() {
+        let input = r#"<?xml version="1.0" encoding="utf-8"?>
+<bom xmlns="http://cyclonedx.org/schema/bom/1.5" xmlns:ext="urn:example:extension" serialNumber="urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79" version="1" ext:signed="true">
+  <ext:first/>
+  <components>
+    <component type="library" bom-ref="a">
+      <name>a</name>
+      <ext:note ext:by="reviewer">
+        <name>kept as is</name>
+      </ext:note>
+    </component>
+    <component type="library" bom-ref="b">
+      <name>b</name>
+    </component>
+  </components>
+  <ext:last>text</ext:last>
+</bom>"#;
+        let (mut bom, extensions) =
+            Bom::parse_from_xml_preserving_extensions(input.as_bytes()).unwrap();
+        assert_eq!(extensions.len(), 4);
+
+        bom.version = 2;
+        bom.components.as_mut().unwrap().0.reverse();
+        let mut output = Vec::new();
+        bom.output_as_xml_preserving_extensions(&mut output, SpecVersion::V1_5, &extensions)
+            .unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            r#"<?xml version="1.0" encoding="utf-8"?>
+<bom xmlns="http://cyclonedx.org/schema/bom/1.5" xmlns:ext="urn:example:extension" serialNumber="urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79" version="2" ext:signed="true">
+  <ext:first />
+  <components>
+    <component type="library" bom-ref="b">
+      <name>b</name>
+    </component>
+    <component type="library" bom-ref="a">
+      <name>a</name>
+      <ext:note ext:by="reviewer">
+        <name>kept as is</name>
+      </ext:note>
+    </component>
+  </components>
+  <ext:last>text</ext:last>
+</bom>"#
+        );
+    }
+
+    #[test]
+    fn it_should_map_all_components_and_services() {
+        fn component(name: &str, components: Option<Vec<Component>>) -> Component {
+            let mut component = Component::new(
+                crate::models::component::Classification::Library,
+                name,
+                "1.0.0",
+                None,
+            );
+            component.components = components.map(Components);
+            component
+        }
+
+        let mut bom = Bom {
+            metadata: Some(Metadata {
+                component: Some(component("root", Some(vec![component("root-child", None)]))),
+                ..Metadata::default()
+            }),
+            components: Some(Components(vec![
+                component("a", Some(vec![component("a-child", None)])),
+                component("b", None),
+            ])),
+            services: Some(Services(vec![Service {
+                services: Some(Services(vec![Service::new("nested", None)])),
+                ..Service::new("service", None)
+            }])),
+            ..Bom::default()
+        };
+
+        let mut visited = vec![];
+        bom.map_components(|component| {
+            visited.push(component.name.to_string());
+            component.version = Some(NormalizedString::new("2.0.0"));
+        });
+        assert_eq!(visited, vec!["root", "root-child", "a", "a-child", "b"]);
+
+        let mut visited = vec![];
+        bom.map_services(|service| visited.push(service.name.to_string()));
+        assert_eq!(visited, vec!["service", "nested"]);
+
+        let nested = &bom.components.unwrap().0[0].components.clone().unwrap().0[0];
+        assert_eq!(nested.version, Some(NormalizedString::new("2.0.0")));
+    }
+
+    #[test]
+    fn it_should_drop_components_and_references_outside_the_scopes() {
+        fn component(name: &str, scope: Option<Scope>, components: Vec<Component>) -> Component {
+            let mut component = Component::new(Classification::Library, name, "1.0.0", None);
+            component.bom_ref = Some(name.to_string());
+            component.scope = scope;
+            component.components = (!components.is_empty()).then_some(Components(components));
+            component
+        }
+        let refs = |refs: &[&str]| Some(refs.iter().map(|r| BomReference(r.to_string())).collect());
+        let targeting = |bom_ref: &str| Vulnerability {
+            vulnerability_targets: Some(VulnerabilityTargets(vec![VulnerabilityTarget::new(
+                bom_ref.to_string(),
+            )])),
+            ..Vulnerability::new(None)
+        };
+
+        let mut bom = Bom {
+            components: Some(Components(vec![
+                component(
+                    "app",
+                    None,
+                    vec![component("plugin", Some(Scope::Optional), vec![])],
+                ),
+                component(
+                    "bench",
+                    Some(Scope::Excluded),
+                    vec![component("bench-data", None, vec![])],
+                ),
+            ])),
+            dependencies: Some(Dependencies(vec![
+                Dependency::new("app", vec!["plugin".to_string(), "bench".to_string()]),
+                Dependency::new("bench", vec!["bench-data".to_string()]),
+            ])),
+            compositions: Some(Compositions(vec![
+                Composition {
+                    assemblies: refs(&["app", "bench"]),
+                    ..Composition::new(AggregateType::Complete)
+                },
+                Composition {
+                    assemblies: refs(&["bench-data"]),
+                    ..Composition::new(AggregateType::Incomplete)
+                },
+            ])),
+            vulnerabilities: Some(Vulnerabilities(vec![
+                targeting("plugin"),
+                targeting("bench-data"),
+            ])),
+            annotations: Some(Annotations(vec![Annotation::new(
+                vec![AnnotationSubject::BomReference(BomReference(
+                    "bench".to_string(),
+                ))],
+                Annotator::Organization(OrganizationalEntity::new("Acme")),
+                DateTime("2023-09-01T10:00:00Z".to_string()),
+                "Reviewed",
+            )])),
+            ..Bom::default()
+        };
 
-fn validate_services(
-    services: &Services,
-    bom_refs: &mut BomReferencesContext,
-    context: &ValidationContext,
-    results: &mut Vec<ValidationResult>,
-) {
-    // record the service references
-    for (service_index, service) in services.0.iter().enumerate() {
-        let context = context.extend_context(vec![ValidationPathComponent::Array {
-            index: service_index,
-        }]);
+        bom.filter_scope(&[Scope::Required]);
 
-        validate_service_bom_refs(service, bom_refs, &context, results);
+        assert_eq!(
+            bom.components,
+            Some(Components(vec![component("app", None, vec![])]))
+        );
+        assert_eq!(
+            bom.dependencies,
+            Some(Dependencies(vec![Dependency::new("app", vec![])]))
+        );
+        assert_eq!(
+            bom.compositions,
+            Some(Compositions(vec![Composition {
+                assemblies: refs(&["app"]),
+                ..Composition::new(AggregateType::Complete)
+            }]))
+        );
+        assert_eq!(bom.vulnerabilities, Some(Vulnerabilities(vec![])));
+        assert_eq!(bom.annotations, Some(Annotations(vec![])));
+        assert_eq!(bom.validate(), ValidationResult::Passed);
     }
-}
 
-#[derive(Clone, Debug, PartialEq, Eq)]
-pub struct UrnUuid(pub(crate) String);
-
-impl UrnUuid {
-    pub fn new(value: String) -> Result<Self, UrnUuidError> {
-        match matches_urn_uuid_regex(&value) {
-            true => Ok(Self(value)),
-            false => Err(UrnUuidError::InvalidUrnUuid(
-                "UrnUuid does not match regular expression".to_string(),
-            )),
+    #[test]
+    fn it_should_extract_a_component_and_its_dependencies() {
+        fn component(name: &str, components: Vec<Component>) -> Component {
+            let mut component = Component::new(Classification::Library, name, "1.0.0", None);
+            component.bom_ref = Some(name.to_string());
+            component.components = (!components.is_empty()).then_some(Components(components));
+            component
         }
-    }
+        let targeting = |bom_ref: &str| Vulnerability {
+            vulnerability_targets: Some(VulnerabilityTargets(vec![VulnerabilityTarget::new(
+                bom_ref.to_string(),
+            )])),
+            ..Vulnerability::new(None)
+        };
 
-    pub fn generate() -> Self {
-        Self::from(uuid::Uuid::new_v4())
-    }
-}
+        let bom = Bom {
+            metadata: Some(Metadata {
+                component: Some(component("monorepo", vec![])),
+                ..Metadata::default()
+            }),
+            components: Some(Components(vec![
+                component("server", vec![component("server-macros", vec![])]),
+                component("client", vec![]),
+                component("vendored", vec![component("tls", vec![])]),
+                component("log", vec![]),
+            ])),
+            services: Some(Services(vec![Service::new("db", Some("db".to_string()))])),
+            dependencies: Some(Dependencies(vec![
+                Dependency::new("monorepo", vec!["server".to_string(), "client".to_string()]),
+                Dependency::new("server", vec!["tls".to_string(), "db".to_string()]),
+                Dependency::new("server-macros", vec!["log".to_string()]),
+                Dependency::new("client", vec!["tls".to_string()]),
+            ])),
+            compositions: Some(Compositions(vec![Composition {
+                assemblies: Some(vec![
+                    BomReference("server".to_string()),
+                    BomReference("client".to_string()),
+                ]),
+                ..Composition::new(AggregateType::Complete)
+            }])),
+            vulnerabilities: Some(Vulnerabilities(vec![targeting("tls"), targeting("client")])),
+            ..Bom::default()
+        };
 
-impl fmt::Display for UrnUuid {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.0)
-    }
-}
+        let server = bom.extract("server").unwrap();
 
-impl From<uuid::Uuid> for UrnUuid {
-    fn from(uuid: uuid::Uuid) -> Self {
-        Self(format!("urn:uuid:{}", uuid))
+        assert_eq!(
+            server.metadata.as_ref().unwrap().component,
+            Some(component(
+                "server",
+                vec![component("server-macros", vec![])]
+            ))
+        );
+        assert_eq!(
+            server.components,
+            Some(Components(vec![
+                component("tls", vec![]),
+                component("log", vec![])
+            ]))
+        );
+        assert_eq!(server.services, bom.services);
+        assert_eq!(
+            server.dependencies,
+            Some(Dependencies(vec![
+                Dependency::new("server", vec!["tls".to_string(), "db".to_string()]),
+                Dependency::new("server-macros", vec!["log".to_string()]),
+            ]))
+        );
+        assert_eq!(
+            server.compositions,
+            Some(Compositions(vec![Composition {
+                assemblies: Some(vec![BomReference("server".to_string())]),
+                ..Composition::new(AggregateType::Complete)
+            }]))
+        );
+        assert_eq!(
+            server.vulnerabilities,
+            Some(Vulnerabilities(vec![targeting("tls")]))
+        );
+        assert_eq!(server.validate(), ValidationResult::Passed);
+
+        assert_eq!(bom.extract("missing"), None);
     }
-}
 
-impl Validate for UrnUuid {
-    fn validate_with_context(&self, context: ValidationContext) -> ValidationResult {
-        match matches_urn_uuid_regex(&self.0) {
-            true => ValidationResult::Passed,
-            false => {
-                ValidationResult::failure("UrnUuid does not match regular expression", context)
-            }
-        }
+    #[test]
+    fn it_should_link_a_revision_to_its_predecessor() {
+        let serial_number =
+            UrnUuid::new("urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79".to_string()).unwrap();
+        let other_bom = ExternalReference::new(
+            ExternalReferenceType::Bom,
+            Uri("urn:cdx:8a4b6f7e-2b0f-4c43-b5d2-1e8f9a6f2c11/1".to_string()),
+        );
+        let bom = Bom {
+            serial_number: Some(serial_number.clone()),
+            external_references: Some(ExternalReferences(vec![other_bom.clone()])),
+            ..Bom::default()
+        };
+        assert_eq!(bom.previous_revision(), None);
+
+        let revision = bom.next_revision().unwrap().next_revision().unwrap();
+
+        assert_eq!(revision.version, 3);
+        assert_eq!(revision.serial_number, Some(serial_number.clone()));
+        assert_eq!(
+            revision.external_references.clone().unwrap().0,
+            vec![
+                other_bom,
+                ExternalReference {
+                    external_reference_type: ExternalReferenceType::Bom,
+                    url: Uri("urn:cdx:3e671687-395b-41f5-a30f-a58921a69b79/2".to_string()),
+                    comment: Some("Previous revision".to_string()),
+                    hashes: None,
+                }
+            ]
+        );
+        assert_eq!(
+            revision.previous_revision(),
+            Some(BomLink::new(&serial_number, 2, None))
+        );
+        assert_eq!(revision.validate(), ValidationResult::Passed);
     }
-}
 
-#[derive(Clone, Debug, PartialEq, Eq)]
-pub enum UrnUuidError {
-    InvalidUrnUuid(String),
-}
+    #[test]
+    fn it_should_not_revise_a_bom_without_serial_number_or_next_version() {
+        let bom = Bom {
+            serial_number: None,
+            ..Bom::default()
+        };
+        assert_eq!(
+            bom.next_revision(),
+            Err(BomRevisionError::MissingSerialNumber)
+        );
 
-fn matches_urn_uuid_regex(value: &str) -> bool {
-    static UUID_REGEX: Lazy<Regex> = Lazy::new(|| {
-        Regex::new(r"^urn:uuid:[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}$")
-            .expect("Failed to compile regex.")
-    });
-    UUID_REGEX.is_match(value)
-}
+        let bom = Bom {
+            version: u32::MAX,
+            serial_number: Some(
+                UrnUuid::new("urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79".to_string()).unwrap(),
+            ),
+            ..Bom::default()
+        };
+        assert_eq!(bom.next_revision(), Err(BomRevisionError::VersionOverflow));
+    }
 
-#[cfg(test)]
-mod test {
-    use crate::{
-        external_models::{date_time::DateTime, normalized_string::NormalizedString, uri::Uri},
-        models::{
-            component::{Classification, Component},
-            composition::{AggregateType, BomReference, Composition},
-            dependency::Dependency,
-            external_reference::{ExternalReference, ExternalReferenceType},
-            property::Property,
-            service::Service,
-            vulnerability::Vulnerability,
-        },
-        validation::{FailureReason, ValidationPathComponent},
-    };
+    #[test]
+    fn it_should_fill_in_the_metadata_when_building() {
+        let timestamp = DateTime::from_unix_timestamp(0).unwrap();
+        let bom = Bom::builder()
+            .version(2)
+            .timestamp(timestamp.clone())
+            .tool(Tool::new("ACME", "generator", "1.0.0"))
+            .component(Component::new(
+                Classification::Library,
+                "lib",
+                "1.0.0",
+                Some("lib".to_string()),
+            ))
+            .dependency(Dependency {
+                dependency_ref: "lib".to_string(),
+                dependencies: vec![],
+            })
+            .build()
+            .unwrap();
+
+        assert_eq!(bom.version, 2);
+        #[cfg(feature = "uuid")]
+        assert!(bom.serial_number.is_some());
+        let metadata = bom.metadata.unwrap();
+        assert_eq!(metadata.timestamp, Some(timestamp));
+        assert_eq!(
+            metadata.tools,
+            Some(Tools::List(vec![
+                Tool::new("ACME", "generator", "1.0.0"),
+                Tool::new("CycloneDX", "cyclonedx-bom", env!("CARGO_PKG_VERSION")),
+            ]))
+        );
 
-    use super::*;
-    use pretty_assertions::assert_eq;
+        let built = Bom::builder()
+            .metadata(Metadata {
+                tools: Some(Tools::Object {
+                    components: None,
+                    services: None,
+                }),
+                ..Metadata::default()
+            })
+            .build()
+            .unwrap();
+        assert!(built.metadata.as_ref().unwrap().timestamp.is_some());
+        assert_eq!(
+            built.metadata.unwrap().tools.unwrap().into_list(),
+            vec![Tool::new(
+                "CycloneDX",
+                "cyclonedx-bom",
+                env!("CARGO_PKG_VERSION")
+            )]
+        );
+    }
 
     #[test]
-    fn it_should_parse_json_using_function_without_suffix() {
-        let input = r#"{
-            "bomFormat": "CycloneDX",
-            "specVersion": "1.3",
-            "serialNumber": "urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79",
-            "version": 1,
-            "components": []
-        }"#;
-        let result = Bom::parse_from_json(input.as_bytes());
-        assert!(result.is_ok());
+    fn it_should_reject_an_invalid_bom_when_building() {
+        let result = Bom::builder()
+            .dependency(Dependency {
+                dependency_ref: "missing".to_string(),
+                dependencies: vec![],
+            })
+            .build();
+
+        match result {
+            Err(BomBuildError::Invalid { reasons }) => assert_eq!(reasons.len(), 1),
+            other => panic!("unexpected result {:?}", other),
+        }
     }
 
     #[test]
@@ -587,6 +3086,8 @@ mod test {
             properties: None,
             vulnerabilities: None,
             signature: None,
+            formulation: None,
+            annotations: None,
         };
 
         let actual = bom.validate();
@@ -611,6 +3112,8 @@ mod test {
             properties: None,
             vulnerabilities: None,
             signature: None,
+            formulation: None,
+            annotations: None,
         };
 
         let actual = bom.validate();
@@ -639,6 +3142,156 @@ mod test {
         );
     }
 
+    #[test]
+    fn it_should_validate_the_values_for_the_spec_version() {
+        let bom = Bom {
+            metadata: Some(Metadata {
+                component: Some(Component::new(
+                    Classification::Platform,
+                    "platform",
+                    "1.0.0",
+                    None,
+                )),
+                ..Metadata::default()
+            }),
+            external_references: Some(ExternalReferences(vec![ExternalReference::new(
+                ExternalReferenceType::ReleaseNotes,
+                Uri("https://example.com/releases".to_string()),
+            )])),
+            ..Bom::default()
+        };
+
+        assert_eq!(
+            bom.validate_version(SpecVersion::V1_5),
+            ValidationResult::Passed
+        );
+        assert_eq!(
+            bom.validate_version(SpecVersion::V1_3),
+            ValidationResult::Failed {
+                reasons: vec![
+                    FailureReason::new(
+                        "Classification platform was introduced in spec version 1.5 and is not allowed in 1.3",
+                        ValidationContext::new()
+                            .with_struct("Bom", "metadata")
+                            .with_struct("Metadata", "component")
+                            .with_struct("Component", "component_type")
+                    ),
+                    FailureReason::new(
+                        "External reference type release-notes was introduced in spec version 1.4 and is not allowed in 1.3",
+                        ValidationContext::new()
+                            .with_struct("Bom", "external_references")
+                            .with_index(0)
+                            .with_struct("ExternalReference", "external_reference_type")
+                    ),
+                ]
+            }
+        );
+    }
+
+    #[cfg(all(feature = "json", feature = "xml", feature = "v1_3", feature = "v1_5"))]
+    #[test]
+    fn it_should_fail_to_write_omnibor_ids_and_swhids_instead_of_dropping_them() {
+        use crate::models::component::{Components, OmniborId, Swhid};
+
+        let mut nested = Component::new(Classification::Library, "nested", "1.0.0", None);
+        nested.swhid = Some(vec![Swhid::new(&format!("swh:1:cnt:{}", "0".repeat(40)))]);
+        let mut component = Component::new(Classification::Library, "lib", "1.0.0", None);
+        component.omnibor_id = Some(vec![OmniborId::new(&format!(
+            "gitoid:blob:sha1:{}",
+            "a".repeat(40)
+        ))]);
+        component.components = Some(Components(vec![nested]));
+        let mut bom = Bom {
+            serial_number: None,
+            components: Some(Components(vec![component])),
+            ..Bom::default()
+        };
+
+        match bom.clone().output_as_json_v1_5(&mut Vec::new()) {
+            Err(crate::errors::JsonWriteError::BomError {
+                error: BomError::BomSerializationError(SpecVersion::V1_5, message),
+            }) => assert_eq!(
+                message,
+                "omniborId of component lib requires spec version 1.6"
+            ),
+            other => panic!("unexpected result {:?}", other),
+        }
+        bom.components.as_mut().unwrap().0[0].omnibor_id = None;
+        match bom.clone().output_as_xml_v1_3(&mut Vec::new()) {
+            Err(crate::errors::XmlWriteError::BomError {
+                error: BomError::BomSerializationError(SpecVersion::V1_3, message),
+            }) => assert_eq!(
+                message,
+                "swhid of component nested requires spec version 1.6"
+            ),
+            other => panic!("unexpected result {:?}", other),
+        }
+
+        // Without the identifiers, nothing is lost in a round trip
+        bom.map_components(|component| component.swhid = None);
+        let mut output = Vec::new();
+        bom.clone().output_as_json_v1_5(&mut output).unwrap();
+        assert_eq!(Bom::parse_from_json_v1_5(output.as_slice()).unwrap(), bom);
+    }
+
+    #[cfg(all(feature = "json", feature = "xml", feature = "v1_4", feature = "v1_5"))]
+    #[test]
+    fn it_should_fail_to_write_organization_addresses_instead_of_dropping_them() {
+        use crate::models::organization::PostalAddress;
+
+        let address = PostalAddress {
+            locality: Some(NormalizedString::new("Berlin")),
+            ..PostalAddress::default()
+        };
+        let mut supplier = OrganizationalEntity::new("Acme");
+        supplier.address = Some(address.clone());
+        let mut provider = OrganizationalEntity::new("Provider");
+        provider.address = Some(address);
+        let mut service = Service::new("api", None);
+        service.provider = Some(provider);
+        let mut bom = Bom {
+            serial_number: None,
+            metadata: Some(Metadata {
+                supplier: Some(supplier),
+                ..Metadata::default()
+            }),
+            services: Some(Services(vec![service])),
+            ..Bom::default()
+        };
+
+        match bom.clone().output_as_xml_v1_5(&mut Vec::new()) {
+            Err(crate::errors::XmlWriteError::BomError {
+                error: BomError::BomSerializationError(SpecVersion::V1_5, message),
+            }) => assert_eq!(
+                message,
+                "address of organization Provider requires spec version 1.6"
+            ),
+            other => panic!("unexpected result {:?}", other),
+        }
+        bom.map_services(|service| service.provider.as_mut().unwrap().address = None);
+        match bom.clone().output_as_json_v1_4(&mut Vec::new()) {
+            Err(crate::errors::JsonWriteError::BomError {
+                error: BomError::BomSerializationError(SpecVersion::V1_4, message),
+            }) => assert_eq!(
+                message,
+                "address of organization Acme requires spec version 1.6"
+            ),
+            other => panic!("unexpected result {:?}", other),
+        }
+
+        // Without the addresses, nothing is lost in a round trip
+        bom.metadata
+            .as_mut()
+            .unwrap()
+            .supplier
+            .as_mut()
+            .unwrap()
+            .address = None;
+        let mut output = Vec::new();
+        bom.clone().output_as_xml_v1_5(&mut output).unwrap();
+        assert_eq!(Bom::parse_from_xml_v1_5(output.as_slice()).unwrap(), bom);
+    }
+
     #[test]
     fn it_should_validate_broken_composition_refs_as_failed() {
         let bom = Bom {
@@ -658,6 +3311,8 @@ mod test {
             properties: None,
             vulnerabilities: None,
             signature: None,
+            formulation: None,
+            annotations: None,
         };
 
         let actual = bom.validate();
@@ -687,6 +3342,77 @@ mod test {
         );
     }
 
+    #[test]
+    fn it_should_validate_broken_annotation_subjects_as_failed() {
+        let serial_number = UrnUuid("urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79".to_string());
+        let subjects = vec![
+            AnnotationSubject::BomReference(BomReference("component-a".to_string())),
+            AnnotationSubject::BomReference(BomReference("missing".to_string())),
+            AnnotationSubject::BomLink(BomLink::new(&serial_number, 1, Some("component-a"))),
+            AnnotationSubject::BomLink(BomLink::new(&serial_number, 1, Some("missing"))),
+            // links into other BOMs cannot be resolved and are accepted
+            AnnotationSubject::BomLink(BomLink::new(&serial_number, 2, Some("missing"))),
+        ];
+        let bom = Bom {
+            version: 1,
+            serial_number: Some(serial_number),
+            metadata: None,
+            components: Some(Components(vec![Component::new(
+                Classification::Library,
+                "component-a",
+                "1.0.0",
+                Some("component-a".to_string()),
+            )])),
+            services: None,
+            external_references: None,
+            dependencies: None,
+            compositions: None,
+            properties: None,
+            vulnerabilities: None,
+            signature: None,
+            formulation: None,
+            annotations: Some(Annotations(vec![Annotation {
+                bom_ref: None,
+                subjects,
+                annotator: Annotator::Organization(OrganizationalEntity {
+                    name: Some(NormalizedString::new("Acme")),
+                    url: None,
+                    contact: None,
+                    address: None,
+                }),
+                timestamp: DateTime("2023-09-01T10:00:00Z".to_string()),
+                text: "Reviewed".to_string(),
+                signature: None,
+            }])),
+        };
+
+        let actual = bom.validate();
+
+        assert_eq!(
+            actual,
+            ValidationResult::Failed {
+                reasons: vec![
+                    FailureReason::new(
+                        "Annotation subject does not exist in the BOM",
+                        ValidationContext::new()
+                            .with_struct("Bom", "annotations")
+                            .with_index(0)
+                            .with_struct("Annotation", "subjects")
+                            .with_index(1)
+                    ),
+                    FailureReason::new(
+                        "Annotation subject does not exist in the BOM",
+                        ValidationContext::new()
+                            .with_struct("Bom", "annotations")
+                            .with_index(0)
+                            .with_struct("Annotation", "subjects")
+                            .with_index(3)
+                    ),
+                ]
+            }
+        );
+    }
+
     #[test]
     fn it_should_validate_a_bom_with_multiple_validation_issues_as_failed() {
         let bom = Bom {
@@ -701,6 +3427,7 @@ mod test {
                 supplier: None,
                 licenses: None,
                 properties: None,
+                lifecycles: None,
             }),
             components: Some(Components(vec![Component {
                 component_type: Classification::UnknownClassification("unknown".to_string()),
@@ -720,12 +3447,15 @@ mod test {
                 cpe: None,
                 purl: None,
                 swid: None,
+                omnibor_id: None,
+                swhid: None,
                 modified: None,
                 pedigree: None,
                 external_references: None,
                 properties: None,
                 components: None,
                 evidence: None,
+                data: None,
                 signature: None,
             }])),
             services: Some(Services(vec![Service {
@@ -777,10 +3507,13 @@ mod test {
                 description: None,
                 detail: None,
                 recommendation: None,
+                workaround: None,
+                proof_of_concept: None,
                 advisories: None,
                 created: None,
                 published: None,
                 updated: None,
+                rejected: None,
                 vulnerability_credits: None,
                 tools: None,
                 vulnerability_analysis: None,
@@ -788,6 +3521,8 @@ mod test {
                 properties: None,
             }])),
             signature: None,
+            formulation: None,
+            annotations: None,
         };
 
         let actual = bom.validate();
@@ -798,14 +3533,14 @@ mod test {
                 reasons: vec![
                     FailureReason {
                         message: "UrnUuid does not match regular expression".to_string(),
-                        context: ValidationContext(vec![ValidationPathComponent::Struct {
+                        context: ValidationContext::from(vec![ValidationPathComponent::Struct {
                             struct_name: "Bom".to_string(),
                             field_name: "serial_number".to_string()
                         }])
                     },
                     FailureReason {
                         message: "DateTime does not conform to ISO 8601".to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Struct {
                                 struct_name: "Bom".to_string(),
                                 field_name: "metadata".to_string()
@@ -818,7 +3553,7 @@ mod test {
                     },
                     FailureReason {
                         message: "Unknown classification".to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Struct {
                                 struct_name: "Bom".to_string(),
                                 field_name: "components".to_string()
@@ -834,7 +3569,7 @@ mod test {
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Struct {
                                 struct_name: "Bom".to_string(),
                                 field_name: "services".to_string()
@@ -848,7 +3583,7 @@ mod test {
                     },
                     FailureReason {
                         message: "Unknown external reference type".to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Struct {
                                 struct_name: "Bom".to_string(),
                                 field_name: "external_references".to_string()
@@ -862,7 +3597,7 @@ mod test {
                     },
                     FailureReason {
                         message: "Unknown aggregate type".to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Struct {
                                 struct_name: "Bom".to_string(),
                                 field_name: "compositions".to_string()
@@ -878,7 +3613,7 @@ mod test {
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Struct {
                                 struct_name: "Bom".to_string(),
                                 field_name: "properties".to_string()
@@ -927,6 +3662,7 @@ mod test {
                 supplier: None,
                 licenses: None,
                 properties: None,
+                lifecycles: None,
             }),
             components: Some(Components(vec![
                 component_builder("metadata-component"),
@@ -947,6 +3683,8 @@ mod test {
             properties: None,
             vulnerabilities: None,
             signature: None,
+            formulation: None,
+            annotations: None,
         }
         .validate();
 
@@ -956,7 +3694,7 @@ mod test {
                 reasons: vec![
                     FailureReason {
                         message: r#"Bom ref "metadata-component" is not unique"#.to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Struct {
                                 struct_name: "Bom".to_string(),
                                 field_name: "components".to_string()
@@ -970,7 +3708,7 @@ mod test {
                     },
                     FailureReason {
                         message: r#"Bom ref "component-component" is not unique"#.to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Struct {
                                 struct_name: "Bom".to_string(),
                                 field_name: "components".to_string()
@@ -984,7 +3722,7 @@ mod test {
                     },
                     FailureReason {
                         message: r#"Bom ref "subcomponent-component" is not unique"#.to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Struct {
                                 struct_name: "Bom".to_string(),
                                 field_name: "components".to_string()
@@ -1003,7 +3741,7 @@ mod test {
                     },
                     FailureReason {
                         message: r#"Bom ref "service-service" is not unique"#.to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Struct {
                                 struct_name: "Bom".to_string(),
                                 field_name: "services".to_string()
@@ -1017,7 +3755,7 @@ mod test {
                     },
                     FailureReason {
                         message: r#"Bom ref "subservice-service" is not unique"#.to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Struct {
                                 struct_name: "Bom".to_string(),
                                 field_name: "services".to_string()
@@ -1036,7 +3774,7 @@ mod test {
                     },
                     FailureReason {
                         message: r#"Bom ref "component-service" is not unique"#.to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Struct {
                                 struct_name: "Bom".to_string(),
                                 field_name: "services".to_string()
@@ -1053,6 +3791,7 @@ mod test {
         );
     }
 
+    #[cfg(feature = "uuid")]
     #[test]
     fn valid_uuids_should_pass_validation() {
         let validation_result = UrnUuid::from(uuid::Uuid::new_v4()).validate();
@@ -1060,6 +3799,19 @@ mod test {
         assert_eq!(validation_result, ValidationResult::Passed);
     }
 
+    #[cfg(feature = "uuid")]
+    #[test]
+    fn it_should_generate_valid_serial_numbers() {
+        let random = UrnUuid::generate();
+        assert_eq!(random.validate(), ValidationResult::Passed);
+        assert_ne!(random, UrnUuid::generate());
+
+        let derived = UrnUuid::from_content(b"content");
+        assert_eq!(derived.validate(), ValidationResult::Passed);
+        assert_eq!(derived, UrnUuid::from_content(b"content"));
+        assert_ne!(derived, UrnUuid::from_content(b"other content"));
+    }
+
     #[test]
     fn invalid_uuids_should_fail_validation() {
         let validation_result = UrnUuid("invalid uuid".to_string()).validate();
@@ -1074,4 +3826,65 @@ mod test {
             }
         );
     }
+
+    #[cfg(all(feature = "json", feature = "uuid", feature = "v1_5"))]
+    #[test]
+    fn it_should_derive_serial_numbers_from_the_content() {
+        let bom = Bom {
+            components: Some(Components(vec![Component::new(
+                Classification::Library,
+                "dependency",
+                "1.0.0",
+                None,
+            )])),
+            ..Bom::default()
+        };
+        let regenerated = Bom {
+            serial_number: Some(UrnUuid::generate()),
+            ..bom.clone()
+        };
+        let changed = Bom {
+            version: 2,
+            ..bom.clone()
+        };
+
+        let serial_number = bom.content_serial_number();
+        assert_eq!(serial_number.validate(), ValidationResult::Passed);
+        assert_eq!(serial_number, regenerated.content_serial_number());
+        assert_ne!(serial_number, changed.content_serial_number());
+    }
+
+    #[cfg(all(feature = "arbitrary", feature = "json"))]
+    #[test]
+    fn it_should_generate_valid_arbitrary_boms() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        // xorshift, to get the same input on every run
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+        let mut next_byte = || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state as u8
+        };
+
+        for _ in 0..200 {
+            let input: Vec<u8> = (0..1024).map(|_| next_byte()).collect();
+            let bom = Bom::arbitrary(&mut Unstructured::new(&input)).unwrap();
+            assert_eq!(bom.validate(), ValidationResult::Passed);
+
+            // fields added after 1.5 are dropped, so compare the documents instead of the models
+            let mut output = Vec::new();
+            bom.output_as_json_v1_5(&mut output).unwrap();
+            let mut round_trip = Vec::new();
+            Bom::parse_from_json_v1_5(output.as_slice())
+                .unwrap()
+                .output_as_json_v1_5(&mut round_trip)
+                .unwrap();
+            assert_eq!(
+                String::from_utf8(round_trip).unwrap(),
+                String::from_utf8(output).unwrap()
+            );
+        }
+    }
 }
@@ -25,7 +25,9 @@ use crate::{
 
 use super::attached_text::AttachedText;
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub struct Commit {
     pub uid: Option<NormalizedString>,
     pub url: Option<Uri>,
@@ -74,7 +76,8 @@ impl Validate for Commit {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Commits(pub Vec<Commit>);
 
 impl Validate for Commits {
@@ -93,7 +96,9 @@ impl Validate for Commits {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub struct Diff {
     pub text: Option<AttachedText>,
     pub url: Option<Uri>,
@@ -121,7 +126,9 @@ impl Validate for Diff {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub struct IdentifiableAction {
     pub timestamp: Option<DateTime>,
     pub name: Option<NormalizedString>,
@@ -156,7 +163,9 @@ impl Validate for IdentifiableAction {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub struct Issue {
     pub issue_type: IssueClassification,
     pub id: Option<NormalizedString>,
@@ -166,6 +175,20 @@ pub struct Issue {
     pub references: Option<Vec<Uri>>,
 }
 
+impl Issue {
+    /// Constructs an `Issue` of the given type
+    pub fn new(issue_type: IssueClassification) -> Self {
+        Self {
+            issue_type,
+            id: None,
+            name: None,
+            description: None,
+            source: None,
+            references: None,
+        }
+    }
+}
+
 impl Validate for Issue {
     fn validate_with_context(&self, context: ValidationContext) -> ValidationResult {
         let mut results: Vec<ValidationResult> = vec![];
@@ -217,12 +240,14 @@ impl Validate for Issue {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum IssueClassification {
     Defect,
     Enhancement,
     Security,
     #[doc(hidden)]
+    #[cfg_attr(feature = "arbitrary", arbitrary(skip))]
     UnknownIssueClassification(String),
 }
 
@@ -263,13 +288,26 @@ impl Validate for IssueClassification {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub struct Patch {
     pub patch_type: PatchClassification,
     pub diff: Option<Diff>,
     pub resolves: Option<Vec<Issue>>,
 }
 
+impl Patch {
+    /// Constructs a `Patch` of the given type
+    pub fn new(patch_type: PatchClassification) -> Self {
+        Self {
+            patch_type,
+            diff: None,
+            resolves: None,
+        }
+    }
+}
+
 impl Validate for Patch {
     fn validate_with_context(&self, context: ValidationContext) -> ValidationResult {
         let mut results: Vec<ValidationResult> = vec![];
@@ -303,7 +341,8 @@ impl Validate for Patch {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Patches(pub Vec<Patch>);
 
 impl Validate for Patches {
@@ -321,13 +360,15 @@ impl Validate for Patches {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum PatchClassification {
     Unofficial,
     Monkey,
     Backport,
     CherryPick,
     #[doc(hidden)]
+    #[cfg_attr(feature = "arbitrary", arbitrary(skip))]
     UnknownPatchClassification(String),
 }
 
@@ -370,7 +411,9 @@ impl Validate for PatchClassification {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub struct Source {
     pub name: Option<NormalizedString>,
     pub url: Option<Uri>,
@@ -400,6 +443,7 @@ impl Validate for Source {
 
 #[cfg(test)]
 mod test {
+    use crate::external_models::mime_type::MimeType;
     use crate::validation::FailureReason;
 
     use super::*;
@@ -454,7 +498,7 @@ mod test {
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Commit".to_string(),
@@ -464,7 +508,7 @@ mod test {
                     },
                     FailureReason {
                         message: "Uri does not conform to RFC 3986".to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Commit".to_string(),
@@ -474,7 +518,7 @@ mod test {
                     },
                     FailureReason {
                         message: "DateTime does not conform to ISO 8601".to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Commit".to_string(),
@@ -490,7 +534,7 @@ mod test {
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Commit".to_string(),
@@ -506,7 +550,7 @@ mod test {
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Commit".to_string(),
@@ -520,7 +564,7 @@ mod test {
                     },
                     FailureReason {
                         message: "DateTime does not conform to ISO 8601".to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Commit".to_string(),
@@ -536,7 +580,7 @@ mod test {
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Commit".to_string(),
@@ -552,7 +596,7 @@ mod test {
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Commit".to_string(),
@@ -568,7 +612,7 @@ mod test {
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Commit".to_string(),
@@ -616,7 +660,7 @@ mod test {
             patch_type: PatchClassification::UnknownPatchClassification("unknown".to_string()),
             diff: Some(Diff {
                 text: Some(AttachedText {
-                    content_type: Some(NormalizedString("spaces and \ttabs".to_string())),
+                    content_type: Some(MimeType("spaces and \ttabs".to_string())),
                     encoding: None,
                     content: "content".to_string(),
                 }),
@@ -642,7 +686,7 @@ mod test {
                 reasons: vec![
                     FailureReason {
                         message: "Unknown patch classification".to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Patch".to_string(),
@@ -651,10 +695,9 @@ mod test {
                         ])
                     },
                     FailureReason {
-                        message:
-                            "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
-                                .to_string(),
-                        context: ValidationContext(vec![
+                        message: "MimeType does not conform to RFC 6838: missing subtype"
+                            .to_string(),
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Patch".to_string(),
@@ -672,7 +715,7 @@ mod test {
                     },
                     FailureReason {
                         message: "Uri does not conform to RFC 3986".to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Patch".to_string(),
@@ -686,7 +729,7 @@ mod test {
                     },
                     FailureReason {
                         message: "Unknown issue classification".to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Patch".to_string(),
@@ -703,7 +746,7 @@ mod test {
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Patch".to_string(),
@@ -720,7 +763,7 @@ mod test {
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Patch".to_string(),
@@ -737,7 +780,7 @@ mod test {
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Patch".to_string(),
@@ -754,7 +797,7 @@ mod test {
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Patch".to_string(),
@@ -773,7 +816,7 @@ mod test {
                     },
                     FailureReason {
                         message: "Uri does not conform to RFC 3986".to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Patch".to_string(),
@@ -792,7 +835,7 @@ mod test {
                     },
                     FailureReason {
                         message: "Uri does not conform to RFC 3986".to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Patch".to_string(),
@@ -19,16 +19,20 @@
 use once_cell::sync::Lazy;
 use regex::Regex;
 
+use crate::errors::BomError;
 use crate::models::attached_text::AttachedText;
+use crate::models::bom::SpecVersion;
 use crate::models::code::{Commits, Patches};
+use crate::models::component_data::ComponentData;
 use crate::models::external_reference::ExternalReferences;
 use crate::models::hash::Hashes;
 use crate::models::license::Licenses;
 use crate::models::organization::OrganizationalEntity;
 use crate::models::property::Properties;
-use crate::validation::{FailureReason, ValidationPathComponent};
+use crate::validation::{validate_list, FailureReason, ValidationPathComponent};
 use crate::{
     external_models::{
+        mime_type::MimeType,
         normalized_string::NormalizedString,
         uri::{Purl, Uri},
     },
@@ -37,7 +41,8 @@ use crate::{
 
 use super::signature::Signature;
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
 pub struct Component {
     pub component_type: Classification,
     pub mime_type: Option<MimeType>,
@@ -56,12 +61,18 @@ pub struct Component {
     pub cpe: Option<Cpe>,
     pub purl: Option<Purl>,
     pub swid: Option<Swid>,
+    /// Added in version 1.6, writing a BOM with it fails in any version this crate can write
+    pub omnibor_id: Option<Vec<OmniborId>>,
+    /// Added in version 1.6, writing a BOM with it fails in any version this crate can write
+    pub swhid: Option<Vec<Swhid>>,
     pub modified: Option<bool>,
     pub pedigree: Option<Pedigree>,
     pub external_references: Option<ExternalReferences>,
     pub properties: Option<Properties>,
     pub components: Option<Components>,
     pub evidence: Option<ComponentEvidence>,
+    /// Added in version 1.5
+    pub data: Option<Vec<ComponentData>>,
     /// Added in version 1.4
     pub signature: Option<Signature>,
 }
@@ -91,15 +102,174 @@ impl Component {
             cpe: None,
             purl: None,
             swid: None,
+            omnibor_id: None,
+            swhid: None,
             modified: None,
             pedigree: None,
             external_references: None,
             properties: None,
             components: None,
             evidence: None,
+            data: None,
             signature: None,
         }
     }
+
+    pub(crate) fn validate_version_with_context(
+        &self,
+        version: SpecVersion,
+        context: ValidationContext,
+    ) -> ValidationResult {
+        let mut results: Vec<ValidationResult> = vec![];
+
+        let component_type = &self.component_type;
+        if let Some(introduced_in) = component_type.introduced_in() {
+            if !component_type.is_allowed_in(version) {
+                results.push(ValidationResult::failure(
+                    &format!(
+                        "Classification {} was introduced in spec version {} and is not allowed in {}",
+                        component_type.to_string(),
+                        introduced_in,
+                        version.to_string()
+                    ),
+                    context.with_struct("Component", "component_type"),
+                ));
+            }
+        }
+
+        if let Some(supplier) = &self.supplier {
+            results.push(supplier.validate_version_with_context(
+                version,
+                context.with_struct("Component", "supplier"),
+            ));
+        }
+
+        if self.omnibor_id.is_some() {
+            results.push(ValidationResult::failure(
+                &format!(
+                    "omniborId was introduced in spec version 1.6 and is not allowed in {}",
+                    version.to_string()
+                ),
+                context.with_struct("Component", "omnibor_id"),
+            ));
+        }
+
+        if self.swhid.is_some() {
+            results.push(ValidationResult::failure(
+                &format!(
+                    "swhid was introduced in spec version 1.6 and is not allowed in {}",
+                    version.to_string()
+                ),
+                context.with_struct("Component", "swhid"),
+            ));
+        }
+
+        if let Some(hashes) = &self.hashes {
+            results.push(hashes.validate_version_with_context(
+                version,
+                context.with_struct("Component", "hashes"),
+            ));
+        }
+
+        if let Some(external_references) = &self.external_references {
+            results.push(external_references.validate_version_with_context(
+                version,
+                context.with_struct("Component", "external_references"),
+            ));
+        }
+
+        if let Some(components) = &self.components {
+            results.push(components.validate_version_with_context(
+                version,
+                context.with_struct("Component", "components"),
+            ));
+        }
+
+        results
+            .into_iter()
+            .fold(ValidationResult::default(), |acc, result| acc.merge(result))
+    }
+
+    /// Fails if the component or one of its nested components has a field that documents of the
+    /// given spec version cannot hold, instead of dropping it when writing the document
+    pub(crate) fn ensure_writable_as(&self, version: SpecVersion) -> Result<(), BomError> {
+        // Added in 1.6, which is newer than any version this crate can write
+        let unsupported = match (&self.omnibor_id, &self.swhid) {
+            (Some(_), _) => Some("omniborId"),
+            (None, Some(_)) => Some("swhid"),
+            (None, None) => None,
+        };
+        if let Some(field) = unsupported {
+            return Err(BomError::BomSerializationError(
+                version,
+                format!(
+                    "{field} of component {} requires spec version 1.6",
+                    self.name
+                ),
+            ));
+        }
+
+        if let Some(supplier) = &self.supplier {
+            supplier.ensure_writable_as(version)?;
+        }
+
+        let pedigree = self.pedigree.iter().flat_map(|pedigree| {
+            [
+                &pedigree.ancestors,
+                &pedigree.descendants,
+                &pedigree.variants,
+            ]
+        });
+        [&self.components]
+            .into_iter()
+            .chain(pedigree)
+            .flatten()
+            .flat_map(|components| components.0.iter())
+            .try_for_each(|component| component.ensure_writable_as(version))
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Component {
+    /// Generates a component that only has data if it is of type data
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let component_type: Classification = u.arbitrary()?;
+        let data = match component_type {
+            Classification::Data => u.arbitrary()?,
+            _ => None,
+        };
+
+        Ok(Self {
+            component_type,
+            mime_type: u.arbitrary()?,
+            bom_ref: u.arbitrary()?,
+            supplier: u.arbitrary()?,
+            author: u.arbitrary()?,
+            publisher: u.arbitrary()?,
+            group: u.arbitrary()?,
+            name: u.arbitrary()?,
+            version: u.arbitrary()?,
+            description: u.arbitrary()?,
+            scope: u.arbitrary()?,
+            hashes: u.arbitrary()?,
+            licenses: u.arbitrary()?,
+            copyright: u.arbitrary()?,
+            cpe: u.arbitrary()?,
+            purl: u.arbitrary()?,
+            swid: u.arbitrary()?,
+            // Not written by any supported spec version
+            omnibor_id: None,
+            swhid: None,
+            modified: u.arbitrary()?,
+            pedigree: u.arbitrary()?,
+            external_references: u.arbitrary()?,
+            properties: u.arbitrary()?,
+            components: u.arbitrary()?,
+            evidence: u.arbitrary()?,
+            data,
+            signature: u.arbitrary()?,
+        })
+    }
 }
 
 impl Validate for Component {
@@ -201,6 +371,20 @@ impl Validate for Component {
             results.push(swid.validate_with_context(context));
         }
 
+        for (index, omnibor_id) in self.omnibor_id.iter().flatten().enumerate() {
+            let context = context
+                .with_struct("Component", "omnibor_id")
+                .with_index(index);
+
+            results.push(omnibor_id.validate_with_context(context));
+        }
+
+        for (index, swhid) in self.swhid.iter().flatten().enumerate() {
+            let context = context.with_struct("Component", "swhid").with_index(index);
+
+            results.push(swhid.validate_with_context(context));
+        }
+
         if let Some(pedigree) = &self.pedigree {
             let context = context.with_struct("Component", "pedigree");
 
@@ -231,22 +415,102 @@ impl Validate for Component {
             results.push(evidence.validate_with_context(context));
         }
 
+        if let Some(data) = &self.data {
+            let context = context.with_struct("Component", "data");
+
+            if !data.is_empty() && self.component_type != Classification::Data {
+                results.push(ValidationResult::failure(
+                    "Data is only allowed on components of type data",
+                    context.clone(),
+                ));
+            }
+
+            for (index, data) in data.iter().enumerate() {
+                results.push(data.validate_with_context(context.with_index(index)));
+            }
+        }
+
         results
             .into_iter()
             .fold(ValidationResult::default(), |acc, result| acc.merge(result))
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Components(pub Vec<Component>);
 
-impl Validate for Components {
-    fn validate_with_context(&self, context: ValidationContext) -> ValidationResult {
+impl Components {
+    /// Calls `f` with every component, including nested ones, parents before their children
+    pub fn map_components(&mut self, mut f: impl FnMut(&mut Component)) {
+        self.map_components_with(&mut f);
+    }
+
+    fn map_components_with(&mut self, f: &mut impl FnMut(&mut Component)) {
+        for component in &mut self.0 {
+            f(component);
+            if let Some(components) = &mut component.components {
+                components.map_components_with(f);
+            }
+        }
+    }
+
+    /// Checks that the components, including nested ones, only use types, hash algorithms and
+    /// external reference types allowed in documents of the given spec version.
+    ///
+    /// Unknown types are reported by [`Validate`] instead.
+    pub fn validate_version(&self, version: SpecVersion) -> ValidationResult {
+        self.validate_version_with_context(version, ValidationContext::new())
+    }
+
+    pub(crate) fn validate_version_with_context(
+        &self,
+        version: SpecVersion,
+        context: ValidationContext,
+    ) -> ValidationResult {
         let mut results: Vec<ValidationResult> = vec![];
 
         for (index, component) in self.0.iter().enumerate() {
-            let context = context.extend_context(vec![ValidationPathComponent::Array { index }]);
-            results.push(component.validate_with_context(context));
+            results
+                .push(component.validate_version_with_context(version, context.with_index(index)));
+        }
+
+        results
+            .into_iter()
+            .fold(ValidationResult::default(), |acc, result| acc.merge(result))
+    }
+
+    /// Checks that the CPEs of the components, including nested ones, use a format accepted by
+    /// the given strictness.
+    ///
+    /// Malformed CPEs are reported by [`Validate`] instead.
+    pub fn validate_cpe_strictness(&self, strictness: CpeStrictness) -> ValidationResult {
+        self.validate_cpe_strictness_with_context(strictness, ValidationContext::new())
+    }
+
+    pub(crate) fn validate_cpe_strictness_with_context(
+        &self,
+        strictness: CpeStrictness,
+        context: ValidationContext,
+    ) -> ValidationResult {
+        let mut results: Vec<ValidationResult> = vec![];
+
+        for (index, component) in self.0.iter().enumerate() {
+            let context = context.with_index(index);
+
+            if let Some(cpe) = &component.cpe {
+                results.push(cpe.validate_strictness_with_context(
+                    strictness,
+                    context.with_struct("Component", "cpe"),
+                ));
+            }
+
+            if let Some(components) = &component.components {
+                results.push(components.validate_cpe_strictness_with_context(
+                    strictness,
+                    context.with_struct("Component", "components"),
+                ));
+            }
         }
 
         results
@@ -255,7 +519,14 @@ impl Validate for Components {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+impl Validate for Components {
+    fn validate_with_context(&self, context: ValidationContext) -> ValidationResult {
+        validate_list(&self.0, &context)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum Classification {
     Application,
     Framework,
@@ -265,7 +536,18 @@ pub enum Classification {
     Device,
     Firmware,
     File,
+    /// Added in version 1.5
+    Platform,
+    /// Added in version 1.5
+    DeviceDriver,
+    /// Added in version 1.5
+    MachineLearningModel,
+    /// Added in version 1.5
+    Data,
+    /// Added in version 1.6, not allowed in any version this crate can write
+    CryptographicAsset,
     #[doc(hidden)]
+    #[cfg_attr(feature = "arbitrary", arbitrary(skip))]
     UnknownClassification(String),
 }
 
@@ -280,6 +562,11 @@ impl ToString for Classification {
             Classification::Device => "device",
             Classification::Firmware => "firmware",
             Classification::File => "file",
+            Classification::Platform => "platform",
+            Classification::DeviceDriver => "device-driver",
+            Classification::MachineLearningModel => "machine-learning-model",
+            Classification::Data => "data",
+            Classification::CryptographicAsset => "cryptographic-asset",
             Classification::UnknownClassification(uc) => uc,
         }
         .to_string()
@@ -297,9 +584,60 @@ impl Classification {
             "device" => Self::Device,
             "firmware" => Self::Firmware,
             "file" => Self::File,
+            "platform" => Self::Platform,
+            "device-driver" => Self::DeviceDriver,
+            "machine-learning-model" => Self::MachineLearningModel,
+            "data" => Self::Data,
+            "cryptographic-asset" => Self::CryptographicAsset,
             unknown => Self::UnknownClassification(unknown.to_string()),
         }
     }
+
+    /// The minor version of the CycloneDX 1.x specification that introduced the type
+    fn minor_version(&self) -> Option<u8> {
+        match self {
+            Self::Application
+            | Self::Framework
+            | Self::Library
+            | Self::Container
+            | Self::OperatingSystem
+            | Self::Device
+            | Self::Firmware
+            | Self::File => Some(3),
+            Self::Platform | Self::DeviceDriver | Self::MachineLearningModel | Self::Data => {
+                Some(5)
+            }
+            Self::CryptographicAsset => Some(6),
+            Self::UnknownClassification(_) => None,
+        }
+    }
+
+    /// The version of the specification that introduced the type, e.g. `"1.5"`,
+    /// or `None` for an unknown type
+    pub fn introduced_in(&self) -> Option<String> {
+        self.minor_version().map(|minor| format!("1.{}", minor))
+    }
+
+    /// Whether documents of the given spec version may use the type
+    pub fn is_allowed_in(&self, version: SpecVersion) -> bool {
+        let supported = match version {
+            SpecVersion::V1_3 => 3,
+            SpecVersion::V1_4 => 4,
+            SpecVersion::V1_5 => 5,
+        };
+        self.minor_version().is_some_and(|minor| minor <= supported)
+    }
+}
+
+impl std::str::FromStr for Classification {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match Self::new_unchecked(s) {
+            Self::UnknownClassification(_) => Err(format!("Invalid classification '{}' found", s)),
+            classification => Ok(classification),
+        }
+    }
 }
 
 impl Validate for Classification {
@@ -316,12 +654,14 @@ impl Validate for Classification {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum Scope {
     Required,
     Optional,
     Excluded,
     #[doc(hidden)]
+    #[cfg_attr(feature = "arbitrary", arbitrary(skip))]
     UnknownScope(String),
 }
 
@@ -362,28 +702,9 @@ impl Validate for Scope {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
-pub struct MimeType(pub(crate) String);
-
-impl Validate for MimeType {
-    fn validate_with_context(&self, context: ValidationContext) -> ValidationResult {
-        static UUID_REGEX: Lazy<Regex> = Lazy::new(|| {
-            Regex::new(r"^[-+a-z0-9.]+/[-+a-z0-9.]+$").expect("Failed to compile regex.")
-        });
-
-        match UUID_REGEX.is_match(&self.0) {
-            true => ValidationResult::Passed,
-            false => ValidationResult::Failed {
-                reasons: vec![FailureReason {
-                    message: "MimeType does not match regular expression".to_string(),
-                    context,
-                }],
-            },
-        }
-    }
-}
-
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub struct Swid {
     pub tag_id: String,
     pub name: String,
@@ -394,6 +715,21 @@ pub struct Swid {
     pub url: Option<Uri>,
 }
 
+impl Swid {
+    /// Constructs a `Swid` with a tag id and name
+    pub fn new(tag_id: impl ToString, name: impl ToString) -> Self {
+        Self {
+            tag_id: tag_id.to_string(),
+            name: name.to_string(),
+            version: None,
+            tag_version: None,
+            patch: None,
+            text: None,
+            url: None,
+        }
+    }
+}
+
 impl Validate for Swid {
     fn validate_with_context(&self, context: ValidationContext) -> ValidationResult {
         let mut results: Vec<ValidationResult> = vec![];
@@ -416,18 +752,71 @@ impl Validate for Swid {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Cpe(pub(crate) String);
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Cpe {
+    /// Generates a CPE 2.3 name of an application
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        use crate::utilities::{arbitrary_token, LOWERCASE_ALPHANUMERIC};
+
+        let vendor = arbitrary_token(u, LOWERCASE_ALPHANUMERIC, 16)?;
+        let product = arbitrary_token(u, LOWERCASE_ALPHANUMERIC, 16)?;
+        let version = format!("{}.{}", u.arbitrary::<u8>()?, u.arbitrary::<u8>()?);
+        Ok(Self(format!(
+            "cpe:2.3:a:{}:{}:{}:*:*:*:*:*:*:*",
+            vendor, product, version
+        )))
+    }
+}
+
+impl Cpe {
+    pub fn new(value: &str) -> Self {
+        Self(value.to_string())
+    }
+
+    /// Whether the CPE is a CPE 2.3 formatted string, as opposed to a CPE 2.2 URI
+    pub fn is_formatted_string(&self) -> bool {
+        self.0.starts_with("cpe:2.3:")
+    }
+
+    /// Checks that the CPE uses a format accepted by the given strictness.
+    ///
+    /// Malformed CPEs are reported by [`Validate`] instead.
+    pub fn validate_strictness(&self, strictness: CpeStrictness) -> ValidationResult {
+        self.validate_strictness_with_context(strictness, ValidationContext::new())
+    }
+
+    pub(crate) fn validate_strictness_with_context(
+        &self,
+        strictness: CpeStrictness,
+        context: ValidationContext,
+    ) -> ValidationResult {
+        match strictness {
+            CpeStrictness::Require23 if !self.is_formatted_string() => {
+                ValidationResult::failure("Cpe is not a CPE 2.3 formatted string", context)
+            }
+            _ => ValidationResult::Passed,
+        }
+    }
+}
+
+impl std::fmt::Display for Cpe {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
 impl Validate for Cpe {
     fn validate_with_context(&self, context: ValidationContext) -> ValidationResult {
-        static UUID_REGEX: Lazy<Regex> = Lazy::new(|| {
+        static CPE_REGEX: Lazy<Regex> = Lazy::new(|| {
             Regex::new(
-                r##"([c][pP][eE]:/[AHOaho]?(:[A-Za-z0-9\._\-~%]*){0,6})|(cpe:2\.3:[aho\*\-](:(((\?*|\*?)([a-zA-Z0-9\-\._]|(\\[\\\*\?!"#$$%&'\(\)\+,/:;<=>@\[\]\^`\{\|}~]))+(\?*|\*?))|[\*\-])){5}(:(([a-zA-Z]{2,3}(-([a-zA-Z]{2}|[0-9]{3}))?)|[\*\-]))(:(((\?*|\*?)([a-zA-Z0-9\-\._]|(\\[\\\*\?!"#$$%&'\(\)\+,/:;<=>@\[\]\^`\{\|}~]))+(\?*|\*?))|[\*\-])){4})"##,
+                r##"^(([c][pP][eE]:/[AHOaho]?(:[A-Za-z0-9\._\-~%]*){0,6})|(cpe:2\.3:[aho\*\-](:(((\?*|\*?)([a-zA-Z0-9\-\._]|(\\[\\\*\?!"#$$%&'\(\)\+,/:;<=>@\[\]\^`\{\|}~]))+(\?*|\*?))|[\*\-])){5}(:(([a-zA-Z]{2,3}(-([a-zA-Z]{2}|[0-9]{3}))?)|[\*\-]))(:(((\?*|\*?)([a-zA-Z0-9\-\._]|(\\[\\\*\?!"#$$%&'\(\)\+,/:;<=>@\[\]\^`\{\|}~]))+(\?*|\*?))|[\*\-])){4}))$"##,
             ).expect("Failed to compile regex.")
         });
 
-        if UUID_REGEX.is_match(&self.0) {
+        if CPE_REGEX.is_match(&self.0) {
             ValidationResult::Passed
         } else {
             ValidationResult::Failed {
@@ -440,7 +829,105 @@ impl Validate for Cpe {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+/// The CPE formats accepted by [`Cpe::validate_strictness`]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum CpeStrictness {
+    /// Accepts CPE 2.2 URIs and CPE 2.3 formatted strings
+    #[default]
+    Lenient,
+    /// Only accepts CPE 2.3 formatted strings
+    Require23,
+}
+
+/// An [OmniBOR](https://omnibor.io) Artifact ID, i.e. a gitoid such as `gitoid:blob:sha1:<hex digest>`
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct OmniborId(pub(crate) String);
+
+impl OmniborId {
+    pub fn new(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+impl std::fmt::Display for OmniborId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for OmniborId {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let (algorithm, len) = *u.choose(&[("sha1", 40), ("sha256", 64)])?;
+        let digest = crate::utilities::arbitrary_hex(u, len)?;
+        Ok(Self(format!("gitoid:blob:{}:{}", algorithm, digest)))
+    }
+}
+
+impl Validate for OmniborId {
+    fn validate_with_context(&self, context: ValidationContext) -> ValidationResult {
+        static GITOID_REGEX: Lazy<Regex> = Lazy::new(|| {
+            Regex::new(r"^gitoid:blob:(sha1:[0-9a-f]{40}|sha256:[0-9a-f]{64})$")
+                .expect("Failed to compile regex.")
+        });
+
+        match GITOID_REGEX.is_match(&self.0) {
+            true => ValidationResult::Passed,
+            false => ValidationResult::failure(
+                "OmniborId does not conform to the gitoid syntax",
+                context,
+            ),
+        }
+    }
+}
+
+/// A [Software Heritage persistent identifier](https://www.swhid.org/specification/v1.1/)
+/// such as `swh:1:cnt:<hex digest>`, optionally followed by qualifiers
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Swhid(pub(crate) String);
+
+impl Swhid {
+    pub fn new(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+impl std::fmt::Display for Swhid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Swhid {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let object_type = u.choose(&["cnt", "dir", "rev", "rel", "snp"])?;
+        let digest = crate::utilities::arbitrary_hex(u, 40)?;
+        Ok(Self(format!("swh:1:{}:{}", object_type, digest)))
+    }
+}
+
+impl Validate for Swhid {
+    fn validate_with_context(&self, context: ValidationContext) -> ValidationResult {
+        static SWHID_REGEX: Lazy<Regex> = Lazy::new(|| {
+            Regex::new(
+                r"^swh:1:(cnt|dir|rev|rel|snp):[0-9a-f]{40}(;(origin|visit|anchor|path|lines)=[^;]+)*$",
+            )
+            .expect("Failed to compile regex.")
+        });
+
+        match SWHID_REGEX.is_match(&self.0) {
+            true => ValidationResult::Passed,
+            false => {
+                ValidationResult::failure("Swhid does not conform to the SWHID syntax", context)
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub struct ComponentEvidence {
     pub licenses: Option<Licenses>,
     pub copyright: Option<CopyrightTexts>,
@@ -468,7 +955,9 @@ impl Validate for ComponentEvidence {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub struct Pedigree {
     pub ancestors: Option<Components>,
     pub descendants: Option<Components>,
@@ -518,18 +1007,105 @@ impl Validate for Pedigree {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Copyright(pub String);
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Copyright {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self::new(u.arbitrary()?))
+    }
+}
+
+impl Copyright {
+    /// Construct a `Copyright` by collapsing every run of whitespace, including `\r`, `\n` and
+    /// `\t`, into a single space
+    /// ```
+    /// use cyclonedx_bom::models::component::Copyright;
+    ///
+    /// let copyright = Copyright::new(" Copyright (c)\r\n  2021 Acme Inc. ");
+    /// assert_eq!(copyright.to_string(), "Copyright (c) 2021 Acme Inc.");
+    /// ```
+    pub fn new(text: &str) -> Self {
+        Self(text.split_whitespace().collect::<Vec<_>>().join(" "))
+    }
+
+    /// Returns the statement with its years and year ranges removed
+    /// ```
+    /// use cyclonedx_bom::models::component::Copyright;
+    ///
+    /// let copyright = Copyright::new("Copyright (c) 2019-2021, 2023 Acme Inc.");
+    /// assert_eq!(copyright.without_years().to_string(), "Copyright (c) Acme Inc.");
+    /// ```
+    pub fn without_years(&self) -> Self {
+        static YEARS_REGEX: Lazy<Regex> = Lazy::new(|| {
+            Regex::new(
+                r"(?i)\b(?:19|20)\d{2}\b(?:\s*(?:-|–|,|to)\s*(?:(?:19|20)\d{2}|present)\b)*(?:\s*,)?",
+            )
+            .expect("Failed to compile regex.")
+        });
+
+        Self::new(&YEARS_REGEX.replace_all(&self.0, ""))
+    }
+
+    /// The key under which near-duplicate statements are considered equal
+    fn dedup_key(&self) -> String {
+        Self::new(&self.0).0.to_lowercase()
+    }
+}
+
+impl std::fmt::Display for Copyright {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
 impl Validate for Copyright {
-    fn validate_with_context(&self, _context: ValidationContext) -> ValidationResult {
-        ValidationResult::default()
+    fn validate_with_context(&self, context: ValidationContext) -> ValidationResult {
+        NormalizedString::new_unchecked(self.0.clone()).validate_with_context(context)
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CopyrightTexts(pub(crate) Vec<Copyright>);
 
+impl CopyrightTexts {
+    pub fn new(copyrights: Vec<Copyright>) -> Self {
+        Self(copyrights)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Copyright> {
+        self.0.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Normalizes the whitespace of every statement and removes the statements that only differ
+    /// from an earlier one in whitespace or letter case, keeping the first occurrence.
+    pub fn deduplicate(&mut self) {
+        let mut seen = std::collections::HashSet::new();
+        self.0 = std::mem::take(&mut self.0)
+            .into_iter()
+            .map(|copyright| Copyright::new(&copyright.0))
+            .filter(|copyright| !copyright.0.is_empty() && seen.insert(copyright.dedup_key()))
+            .collect();
+    }
+
+    /// Removes the years from every statement before deduplicating them, so that e.g.
+    /// `Copyright 2019 Acme` and `Copyright 2020-2021 Acme` are consolidated into `Copyright Acme`.
+    pub fn strip_years(&mut self) {
+        self.0 = self.0.iter().map(Copyright::without_years).collect();
+        self.deduplicate();
+    }
+}
+
 impl Validate for CopyrightTexts {
     fn validate_with_context(&self, context: ValidationContext) -> ValidationResult {
         let mut results: Vec<ValidationResult> = vec![];
@@ -552,6 +1128,7 @@ mod test {
         external_models::spdx::SpdxExpression,
         models::{
             code::{Commit, Patch, PatchClassification},
+            component_data::ComponentDataType,
             external_reference::{ExternalReference, ExternalReferenceType},
             hash::{Hash, HashAlgorithm, HashValue},
             license::LicenseChoice,
@@ -574,6 +1151,7 @@ mod test {
                 name: Some(NormalizedString::new("name")),
                 url: None,
                 contact: None,
+                address: None,
             }),
             author: Some(NormalizedString::new("author")),
             publisher: Some(NormalizedString::new("publisher")),
@@ -605,6 +1183,8 @@ mod test {
                 }),
                 url: Some(Uri("https://example.com".to_string())),
             }),
+            omnibor_id: None,
+            swhid: None,
             modified: Some(true),
             pedigree: Some(Pedigree {
                 ancestors: Some(Components(vec![])),
@@ -641,6 +1221,7 @@ mod test {
                 ))])),
                 copyright: Some(CopyrightTexts(vec![Copyright("copyright".to_string())])),
             }),
+            data: None,
             signature: Some(Signature::single(Algorithm::HS512, "abcdefgh")),
         }])
         .validate();
@@ -658,6 +1239,7 @@ mod test {
                 name: Some(NormalizedString("invalid\tname".to_string())),
                 url: None,
                 contact: None,
+                address: None,
             }),
             author: Some(NormalizedString("invalid\tauthor".to_string())),
             publisher: Some(NormalizedString("invalid\tpublisher".to_string())),
@@ -683,12 +1265,14 @@ mod test {
                 tag_version: Some(1),
                 patch: Some(true),
                 text: Some(AttachedText {
-                    content_type: Some(NormalizedString("invalid\tcontent_type".to_string())),
+                    content_type: Some(MimeType("invalid\tcontent_type".to_string())),
                     encoding: None,
                     content: "content".to_string(),
                 }),
                 url: Some(Uri("invalid url".to_string())),
             }),
+            omnibor_id: None,
+            swhid: None,
             modified: Some(true),
             pedigree: Some(Pedigree {
                 ancestors: Some(Components(vec![invalid_component()])),
@@ -729,6 +1313,7 @@ mod test {
                 ))])),
                 copyright: Some(CopyrightTexts(vec![Copyright("copyright".to_string())])),
             }),
+            data: None,
             signature: Some(Signature::single(Algorithm::HS512, "abcdefgh")),
         }])
         .validate();
@@ -739,7 +1324,7 @@ mod test {
                 reasons: vec![
                     FailureReason {
                         message: "Unknown classification".to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Component".to_string(),
@@ -748,8 +1333,9 @@ mod test {
                         ])
                     },
                     FailureReason {
-                        message: "MimeType does not match regular expression".to_string(),
-                        context: ValidationContext(vec![
+                        message: "MimeType does not conform to RFC 6838: missing subtype"
+                            .to_string(),
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Component".to_string(),
@@ -761,7 +1347,7 @@ mod test {
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Component".to_string(),
@@ -777,7 +1363,7 @@ mod test {
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Component".to_string(),
@@ -789,7 +1375,7 @@ mod test {
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Component".to_string(),
@@ -801,7 +1387,7 @@ mod test {
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Component".to_string(),
@@ -813,7 +1399,7 @@ mod test {
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Component".to_string(),
@@ -825,7 +1411,7 @@ mod test {
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Component".to_string(),
@@ -837,7 +1423,7 @@ mod test {
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Component".to_string(),
@@ -847,7 +1433,7 @@ mod test {
                     },
                     FailureReason {
                         message: "Unknown scope".to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Component".to_string(),
@@ -857,7 +1443,7 @@ mod test {
                     },
                     FailureReason {
                         message: "HashValue does not match regular expression".to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Component".to_string(),
@@ -872,7 +1458,7 @@ mod test {
                     },
                     FailureReason {
                         message: "SPDX expression is not valid".to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Component".to_string(),
@@ -888,7 +1474,7 @@ mod test {
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Component".to_string(),
@@ -898,7 +1484,7 @@ mod test {
                     },
                     FailureReason {
                         message: "Cpe does not match regular expression".to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Component".to_string(),
@@ -909,7 +1495,7 @@ mod test {
                     FailureReason {
                         message: "Purl does not conform to Package URL spec: missing scheme"
                             .to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Component".to_string(),
@@ -918,10 +1504,9 @@ mod test {
                         ])
                     },
                     FailureReason {
-                        message:
-                            "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
-                                .to_string(),
-                        context: ValidationContext(vec![
+                        message: "MimeType does not conform to RFC 6838: missing subtype"
+                            .to_string(),
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Component".to_string(),
@@ -939,7 +1524,7 @@ mod test {
                     },
                     FailureReason {
                         message: "Uri does not conform to RFC 3986".to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Component".to_string(),
@@ -953,7 +1538,7 @@ mod test {
                     },
                     FailureReason {
                         message: "Unknown classification".to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Component".to_string(),
@@ -972,7 +1557,7 @@ mod test {
                     },
                     FailureReason {
                         message: "Unknown classification".to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Component".to_string(),
@@ -991,7 +1576,7 @@ mod test {
                     },
                     FailureReason {
                         message: "Unknown classification".to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Component".to_string(),
@@ -1012,7 +1597,7 @@ mod test {
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Component".to_string(),
@@ -1031,7 +1616,7 @@ mod test {
                     },
                     FailureReason {
                         message: "Unknown patch classification".to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Component".to_string(),
@@ -1050,7 +1635,7 @@ mod test {
                     },
                     FailureReason {
                         message: "Unknown external reference type".to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Component".to_string(),
@@ -1067,7 +1652,7 @@ mod test {
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Component".to_string(),
@@ -1082,7 +1667,7 @@ mod test {
                     },
                     FailureReason {
                         message: "Unknown classification".to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Component".to_string(),
@@ -1097,7 +1682,7 @@ mod test {
                     },
                     FailureReason {
                         message: "SPDX expression is not valid".to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Component".to_string(),
@@ -1118,6 +1703,271 @@ mod test {
         );
     }
 
+    #[test]
+    fn it_should_parse_classifications() {
+        assert_eq!("firmware".parse(), Ok(Classification::Firmware));
+        assert_eq!(
+            "operating-system".parse(),
+            Ok(Classification::OperatingSystem)
+        );
+        assert!("binary".parse::<Classification>().is_err());
+        assert_eq!(
+            "machine-learning-model".parse(),
+            Ok(Classification::MachineLearningModel)
+        );
+    }
+
+    #[test]
+    fn it_should_validate_the_types_for_the_spec_version() {
+        let mut application = Component::new(Classification::Application, "app", "1.0.0", None);
+        application.components = Some(Components(vec![
+            Component::new(Classification::Library, "lib", "1.0.0", None),
+            Component::new(Classification::Data, "dataset", "1.0.0", None),
+        ]));
+        let components = Components(vec![application]);
+
+        assert_eq!(
+            components.validate_version(SpecVersion::V1_5),
+            ValidationResult::Passed
+        );
+        assert_eq!(
+            components.validate_version(SpecVersion::V1_3),
+            ValidationResult::failure(
+                "Classification data was introduced in spec version 1.5 and is not allowed in 1.3",
+                ValidationContext::new()
+                    .with_index(0)
+                    .with_struct("Component", "components")
+                    .with_index(1)
+                    .with_struct("Component", "component_type")
+            )
+        );
+
+        assert_eq!(
+            Classification::CryptographicAsset
+                .introduced_in()
+                .as_deref(),
+            Some("1.6")
+        );
+        assert!(!Classification::CryptographicAsset.is_allowed_in(SpecVersion::V1_5));
+        assert!(Classification::DeviceDriver.is_allowed_in(SpecVersion::V1_5));
+        assert!(!Classification::DeviceDriver.is_allowed_in(SpecVersion::V1_4));
+    }
+
+    #[test]
+    fn it_should_only_allow_data_on_data_components() {
+        let mut dataset = Component::new(Classification::Data, "dataset", "1.0.0", None);
+        dataset.data = Some(vec![ComponentData::new(ComponentDataType::Dataset)]);
+        assert_eq!(dataset.validate(), ValidationResult::Passed);
+
+        dataset.component_type = Classification::Library;
+        assert_eq!(
+            dataset.validate(),
+            ValidationResult::failure(
+                "Data is only allowed on components of type data",
+                ValidationContext::new().with_struct("Component", "data")
+            )
+        );
+    }
+
+    #[test]
+    fn it_should_validate_omnibor_ids_and_swhids() {
+        let mut component = Component::new(Classification::Library, "lib", "1.0.0", None);
+        component.omnibor_id = Some(vec![
+            OmniborId::new(&format!("gitoid:blob:sha1:{}", "a".repeat(40))),
+            OmniborId::new("gitoid:blob:sha1:abc"),
+        ]);
+        component.swhid = Some(vec![
+            Swhid::new(&format!(
+                "swh:1:cnt:{};origin=https://github.com/example/lib",
+                "0".repeat(40)
+            )),
+            Swhid::new("swh:2:cnt:0000"),
+        ]);
+
+        assert_eq!(
+            component.validate(),
+            ValidationResult::Failed {
+                reasons: vec![
+                    FailureReason {
+                        message: "OmniborId does not conform to the gitoid syntax".to_string(),
+                        context: ValidationContext::new()
+                            .with_struct("Component", "omnibor_id")
+                            .with_index(1)
+                    },
+                    FailureReason {
+                        message: "Swhid does not conform to the SWHID syntax".to_string(),
+                        context: ValidationContext::new()
+                            .with_struct("Component", "swhid")
+                            .with_index(1)
+                    },
+                ]
+            }
+        );
+    }
+
+    #[test]
+    fn it_should_not_allow_omnibor_ids_and_swhids_in_the_supported_spec_versions() {
+        let mut component = Component::new(Classification::Library, "lib", "1.0.0", None);
+        component.omnibor_id = Some(vec![OmniborId::new(&format!(
+            "gitoid:blob:sha1:{}",
+            "a".repeat(40)
+        ))]);
+        component.swhid = Some(vec![Swhid::new(&format!("swh:1:cnt:{}", "0".repeat(40)))]);
+
+        assert_eq!(
+            component.validate_version_with_context(SpecVersion::V1_5, ValidationContext::new()),
+            ValidationResult::Failed {
+                reasons: vec![
+                    FailureReason::new(
+                        "omniborId was introduced in spec version 1.6 and is not allowed in 1.5",
+                        ValidationContext::new().with_struct("Component", "omnibor_id")
+                    ),
+                    FailureReason::new(
+                        "swhid was introduced in spec version 1.6 and is not allowed in 1.5",
+                        ValidationContext::new().with_struct("Component", "swhid")
+                    ),
+                ]
+            }
+        );
+    }
+
+    #[test]
+    fn it_should_validate_cpe_formats() {
+        assert_eq!(
+            Cpe::new("cpe:/a:example:mylibrary:1.0.0").validate(),
+            ValidationResult::Passed
+        );
+        assert_eq!(
+            Cpe::new("cpe:2.3:a:example:mylibrary:1.0.0:*:*:*:*:*:*:*").validate(),
+            ValidationResult::Passed
+        );
+
+        for cpe in [
+            "see cpe:/a:example:mylibrary:1.0.0",
+            "cpe:2.3:a:example:mylibrary:1.0.0",
+            "cpe:2.3:a:example:mylibrary:1.0.0:*:*:*:*:*:*:*:*",
+        ] {
+            assert_eq!(
+                Cpe::new(cpe).validate(),
+                ValidationResult::failure(
+                    "Cpe does not match regular expression",
+                    ValidationContext::new()
+                ),
+                "{}",
+                cpe
+            );
+        }
+    }
+
+    #[test]
+    fn it_should_require_cpe_2_3_when_strict() {
+        let mut application = Component::new(Classification::Application, "app", "1.0.0", None);
+        application.cpe = Some(Cpe::new("cpe:2.3:a:example:app:1.0.0:*:*:*:*:*:*:*"));
+        let mut library = Component::new(Classification::Library, "lib", "1.0.0", None);
+        library.cpe = Some(Cpe::new("cpe:/a:example:lib:1.0.0"));
+        application.components = Some(Components(vec![library]));
+        let components = Components(vec![application]);
+
+        assert_eq!(
+            components.validate_cpe_strictness(CpeStrictness::Lenient),
+            ValidationResult::Passed
+        );
+        assert_eq!(
+            components.validate_cpe_strictness(CpeStrictness::Require23),
+            ValidationResult::failure(
+                "Cpe is not a CPE 2.3 formatted string",
+                ValidationContext::new()
+                    .with_index(0)
+                    .with_struct("Component", "components")
+                    .with_index(0)
+                    .with_struct("Component", "cpe")
+            )
+        );
+    }
+
+    #[test]
+    fn it_should_deduplicate_copyright_statements() {
+        let mut copyrights = CopyrightTexts::new(vec![
+            Copyright("Copyright (c) 2019 Acme Inc.".to_string()),
+            Copyright("  Copyright (c)\t2019   Acme Inc.\n".to_string()),
+            Copyright("COPYRIGHT (C) 2019 ACME INC.".to_string()),
+            Copyright("Copyright (c) 2020-2021 Acme Inc.".to_string()),
+            Copyright(" ".to_string()),
+        ]);
+        copyrights.deduplicate();
+
+        assert_eq!(
+            copyrights,
+            CopyrightTexts(vec![
+                Copyright("Copyright (c) 2019 Acme Inc.".to_string()),
+                Copyright("Copyright (c) 2020-2021 Acme Inc.".to_string()),
+            ])
+        );
+
+        copyrights.strip_years();
+
+        assert_eq!(
+            copyrights,
+            CopyrightTexts(vec![Copyright("Copyright (c) Acme Inc.".to_string())])
+        );
+    }
+
+    #[test]
+    fn it_should_strip_years_from_copyright_statements() {
+        let stripped = |text: &str| Copyright::new(text).without_years().to_string();
+
+        assert_eq!(stripped("Copyright 2019 Acme"), "Copyright Acme");
+        assert_eq!(stripped("(c) 2019 - 2023 Acme"), "(c) Acme");
+        assert_eq!(
+            stripped("Copyright 2018, 2020–present Acme"),
+            "Copyright Acme"
+        );
+        assert_eq!(stripped("Copyright 1999 to 2004 Acme"), "Copyright Acme");
+        assert_eq!(stripped("Copyright Acme 20190"), "Copyright Acme 20190");
+    }
+
+    #[test]
+    fn it_should_validate_copyright_texts_as_normalized_strings() {
+        let validation_result = CopyrightTexts(vec![
+            Copyright("Copyright Acme".to_string()),
+            Copyright("Copyright\nAcme".to_string()),
+        ])
+        .validate();
+
+        assert_eq!(
+            validation_result,
+            ValidationResult::Failed {
+                reasons: vec![FailureReason {
+                    message: "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
+                        .to_string(),
+                    context: ValidationContext::from(vec![ValidationPathComponent::Array {
+                        index: 1
+                    }])
+                }]
+            }
+        );
+    }
+
+    #[test]
+    fn it_should_deduplicate_and_sort_components_in_sets() {
+        use std::collections::{BTreeSet, HashSet};
+
+        let a = Component::new(Classification::Library, "a", "1.0.0", None);
+        let b = Component::new(Classification::Library, "b", "1.0.0", None);
+        let app = Component::new(Classification::Application, "z", "1.0.0", None);
+        let components = vec![b.clone(), a.clone(), b.clone(), app.clone()];
+
+        let unique: HashSet<Component> = components.iter().cloned().collect();
+        assert_eq!(unique.len(), 3);
+
+        let sorted: Vec<Component> = components
+            .into_iter()
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+        assert_eq!(sorted, vec![app, a, b]);
+    }
+
     fn invalid_component() -> Component {
         Component {
             component_type: Classification::UnknownClassification("unknown".to_string()),
@@ -1137,12 +1987,15 @@ mod test {
             cpe: None,
             purl: None,
             swid: None,
+            omnibor_id: None,
+            swhid: None,
             modified: None,
             pedigree: None,
             external_references: None,
             properties: None,
             components: None,
             evidence: None,
+            data: None,
             signature: None,
         }
     }
@@ -0,0 +1,416 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use crate::external_models::{normalized_string::NormalizedString, uri::Uri};
+use crate::models::attached_text::AttachedText;
+use crate::models::organization::{OrganizationalContact, OrganizationalEntity};
+use crate::models::property::Properties;
+use crate::validation::{Validate, ValidationContext, ValidationResult};
+
+/// Describes the data carried by a component of type `data`, e.g. a dataset
+///
+/// Defined via the [CycloneDX XML schema](https://cyclonedx.org/docs/1.5/xml/#type_componentDataType).
+/// Added in version 1.5
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct ComponentData {
+    pub bom_ref: Option<String>,
+    pub data_type: ComponentDataType,
+    pub name: Option<NormalizedString>,
+    pub contents: Option<DataContents>,
+    /// Data classification tag, e.g. `public` or `confidential`
+    pub classification: Option<NormalizedString>,
+    /// Kinds of sensitive data the contents hold, e.g. personally identifiable information
+    pub sensitive_data: Option<Vec<NormalizedString>>,
+    pub graphics: Option<GraphicsCollection>,
+    pub description: Option<NormalizedString>,
+    pub governance: Option<DataGovernance>,
+}
+
+impl ComponentData {
+    pub fn new(data_type: ComponentDataType) -> Self {
+        Self {
+            bom_ref: None,
+            data_type,
+            name: None,
+            contents: None,
+            classification: None,
+            sensitive_data: None,
+            graphics: None,
+            description: None,
+            governance: None,
+        }
+    }
+}
+
+impl Validate for ComponentData {
+    fn validate_with_context(&self, context: ValidationContext) -> ValidationResult {
+        let mut results: Vec<ValidationResult> = vec![];
+
+        let data_type_context = context.with_struct("ComponentData", "data_type");
+        results.push(self.data_type.validate_with_context(data_type_context));
+
+        if let Some(name) = &self.name {
+            let context = context.with_struct("ComponentData", "name");
+            results.push(name.validate_with_context(context));
+        }
+
+        if let Some(contents) = &self.contents {
+            let context = context.with_struct("ComponentData", "contents");
+            results.push(contents.validate_with_context(context));
+        }
+
+        if let Some(classification) = &self.classification {
+            let context = context.with_struct("ComponentData", "classification");
+            results.push(classification.validate_with_context(context));
+        }
+
+        for (index, sensitive_data) in self.sensitive_data.iter().flatten().enumerate() {
+            let context = context
+                .with_struct("ComponentData", "sensitive_data")
+                .with_index(index);
+            results.push(sensitive_data.validate_with_context(context));
+        }
+
+        if let Some(graphics) = &self.graphics {
+            let context = context.with_struct("ComponentData", "graphics");
+            results.push(graphics.validate_with_context(context));
+        }
+
+        if let Some(description) = &self.description {
+            let context = context.with_struct("ComponentData", "description");
+            results.push(description.validate_with_context(context));
+        }
+
+        if let Some(governance) = &self.governance {
+            let context = context.with_struct("ComponentData", "governance");
+            results.push(governance.validate_with_context(context));
+        }
+
+        results
+            .into_iter()
+            .fold(ValidationResult::default(), |acc, result| acc.merge(result))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum ComponentDataType {
+    /// Any type of code, code snippet, or data-as-code
+    SourceCode,
+    /// Parameters or settings that may be used by other components
+    Configuration,
+    /// A collection of data
+    Dataset,
+    /// Data that can be used to create new instances of what the definition defines
+    Definition,
+    /// Any other type of data that does not fit into existing definitions
+    Other,
+    #[doc(hidden)]
+    #[cfg_attr(feature = "arbitrary", arbitrary(skip))]
+    UnknownComponentDataType(String),
+}
+
+impl std::fmt::Display for ComponentDataType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            ComponentDataType::SourceCode => "source-code",
+            ComponentDataType::Configuration => "configuration",
+            ComponentDataType::Dataset => "dataset",
+            ComponentDataType::Definition => "definition",
+            ComponentDataType::Other => "other",
+            ComponentDataType::UnknownComponentDataType(un) => un,
+        })
+    }
+}
+
+impl ComponentDataType {
+    pub(crate) fn new_unchecked<A: AsRef<str>>(value: A) -> Self {
+        match value.as_ref() {
+            "source-code" => Self::SourceCode,
+            "configuration" => Self::Configuration,
+            "dataset" => Self::Dataset,
+            "definition" => Self::Definition,
+            "other" => Self::Other,
+            unknown => Self::UnknownComponentDataType(unknown.to_string()),
+        }
+    }
+}
+
+impl Validate for ComponentDataType {
+    fn validate_with_context(&self, context: ValidationContext) -> ValidationResult {
+        match self {
+            ComponentDataType::UnknownComponentDataType(_) => {
+                ValidationResult::failure("Unknown component data type", context)
+            }
+            _ => ValidationResult::Passed,
+        }
+    }
+}
+
+/// The contents of the data, either attached or referenced by URL
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct DataContents {
+    pub attachment: Option<AttachedText>,
+    pub url: Option<Uri>,
+    pub properties: Option<Properties>,
+}
+
+impl Validate for DataContents {
+    fn validate_with_context(&self, context: ValidationContext) -> ValidationResult {
+        let mut results: Vec<ValidationResult> = vec![];
+
+        if let Some(attachment) = &self.attachment {
+            let context = context.with_struct("DataContents", "attachment");
+            results.push(attachment.validate_with_context(context));
+        }
+
+        if let Some(url) = &self.url {
+            let context = context.with_struct("DataContents", "url");
+            results.push(url.validate_with_context(context));
+        }
+
+        if let Some(properties) = &self.properties {
+            let context = context.with_struct("DataContents", "properties");
+            results.push(properties.validate_with_context(context));
+        }
+
+        results
+            .into_iter()
+            .fold(ValidationResult::default(), |acc, result| acc.merge(result))
+    }
+}
+
+/// A collection of graphics that visualize the data
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct GraphicsCollection {
+    pub description: Option<NormalizedString>,
+    pub collection: Option<Vec<Graphic>>,
+}
+
+impl Validate for GraphicsCollection {
+    fn validate_with_context(&self, context: ValidationContext) -> ValidationResult {
+        let mut results: Vec<ValidationResult> = vec![];
+
+        if let Some(description) = &self.description {
+            let context = context.with_struct("GraphicsCollection", "description");
+            results.push(description.validate_with_context(context));
+        }
+
+        for (index, graphic) in self.collection.iter().flatten().enumerate() {
+            let context = context
+                .with_struct("GraphicsCollection", "collection")
+                .with_index(index);
+            results.push(graphic.validate_with_context(context));
+        }
+
+        results
+            .into_iter()
+            .fold(ValidationResult::default(), |acc, result| acc.merge(result))
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct Graphic {
+    pub name: Option<NormalizedString>,
+    pub image: Option<AttachedText>,
+}
+
+impl Validate for Graphic {
+    fn validate_with_context(&self, context: ValidationContext) -> ValidationResult {
+        let mut results: Vec<ValidationResult> = vec![];
+
+        if let Some(name) = &self.name {
+            let context = context.with_struct("Graphic", "name");
+            results.push(name.validate_with_context(context));
+        }
+
+        if let Some(image) = &self.image {
+            let context = context.with_struct("Graphic", "image");
+            results.push(image.validate_with_context(context));
+        }
+
+        results
+            .into_iter()
+            .fold(ValidationResult::default(), |acc, result| acc.merge(result))
+    }
+}
+
+/// The parties responsible for the data
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct DataGovernance {
+    /// Parties that hold and keep the data safe
+    pub custodians: Option<Vec<DataGovernanceResponsibleParty>>,
+    /// Parties that manage the quality and use of the data
+    pub stewards: Option<Vec<DataGovernanceResponsibleParty>>,
+    /// Parties that own the data and are accountable for it
+    pub owners: Option<Vec<DataGovernanceResponsibleParty>>,
+}
+
+impl Validate for DataGovernance {
+    fn validate_with_context(&self, context: ValidationContext) -> ValidationResult {
+        let mut results: Vec<ValidationResult> = vec![];
+
+        let parties = [
+            ("custodians", &self.custodians),
+            ("stewards", &self.stewards),
+            ("owners", &self.owners),
+        ];
+
+        for (field_name, parties) in parties {
+            for (index, party) in parties.iter().flatten().enumerate() {
+                let context = context
+                    .with_struct("DataGovernance", field_name)
+                    .with_index(index);
+                results.push(party.validate_with_context(context));
+            }
+        }
+
+        results
+            .into_iter()
+            .fold(ValidationResult::default(), |acc, result| acc.merge(result))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum DataGovernanceResponsibleParty {
+    Organization(OrganizationalEntity),
+    Contact(OrganizationalContact),
+}
+
+impl Validate for DataGovernanceResponsibleParty {
+    fn validate_with_context(&self, context: ValidationContext) -> ValidationResult {
+        match self {
+            DataGovernanceResponsibleParty::Organization(organization) => organization
+                .validate_with_context(
+                    context.with_struct("DataGovernanceResponsibleParty", "organization"),
+                ),
+            DataGovernanceResponsibleParty::Contact(contact) => contact.validate_with_context(
+                context.with_struct("DataGovernanceResponsibleParty", "contact"),
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::validation::FailureReason;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn valid_component_data_should_pass_validation() {
+        let validation_result = ComponentData {
+            bom_ref: Some("dataset-1".to_string()),
+            data_type: ComponentDataType::Dataset,
+            name: Some(NormalizedString::new("Training data")),
+            contents: Some(DataContents {
+                attachment: None,
+                url: Some(Uri("https://example.com/dataset.csv".to_string())),
+                properties: None,
+            }),
+            classification: Some(NormalizedString::new("public")),
+            sensitive_data: Some(vec![NormalizedString::new("PII")]),
+            graphics: Some(GraphicsCollection {
+                description: Some(NormalizedString::new("Distribution")),
+                collection: Some(vec![Graphic {
+                    name: Some(NormalizedString::new("histogram")),
+                    image: Some(AttachedText::new(None, "image")),
+                }]),
+            }),
+            description: Some(NormalizedString::new("description")),
+            governance: Some(DataGovernance {
+                custodians: Some(vec![DataGovernanceResponsibleParty::Organization(
+                    OrganizationalEntity {
+                        name: Some(NormalizedString::new("Acme")),
+                        url: None,
+                        contact: None,
+                        address: None,
+                    },
+                )]),
+                stewards: None,
+                owners: Some(vec![DataGovernanceResponsibleParty::Contact(
+                    OrganizationalContact::new("Jane Doe", None),
+                )]),
+            }),
+        }
+        .validate();
+
+        assert_eq!(validation_result, ValidationResult::Passed);
+    }
+
+    #[test]
+    fn invalid_component_data_should_fail_validation() {
+        let mut data = ComponentData::new(ComponentDataType::UnknownComponentDataType(
+            "unknown".to_string(),
+        ));
+        data.contents = Some(DataContents {
+            attachment: None,
+            url: Some(Uri("invalid url".to_string())),
+            properties: None,
+        });
+        data.governance = Some(DataGovernance {
+            custodians: None,
+            stewards: Some(vec![DataGovernanceResponsibleParty::Contact(
+                OrganizationalContact {
+                    name: Some(NormalizedString("invalid\tname".to_string())),
+                    email: None,
+                    phone: None,
+                },
+            )]),
+            owners: None,
+        });
+
+        assert_eq!(
+            data.validate(),
+            ValidationResult::Failed {
+                reasons: vec![
+                    FailureReason::new(
+                        "Unknown component data type",
+                        ValidationContext::new().with_struct("ComponentData", "data_type")
+                    ),
+                    FailureReason::new(
+                        "Uri does not conform to RFC 3986",
+                        ValidationContext::new()
+                            .with_struct("ComponentData", "contents")
+                            .with_struct("DataContents", "url")
+                    ),
+                    FailureReason::new(
+                        "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n",
+                        ValidationContext::new()
+                            .with_struct("ComponentData", "governance")
+                            .with_struct("DataGovernance", "stewards")
+                            .with_index(0)
+                            .with_struct("DataGovernanceResponsibleParty", "contact")
+                            .with_struct("OrganizationalContact", "name")
+                    ),
+                ]
+            }
+        );
+    }
+}
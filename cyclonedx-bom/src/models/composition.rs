@@ -22,7 +22,9 @@ use crate::validation::{
 
 use super::signature::Signature;
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub struct Composition {
     pub aggregate: AggregateType,
     pub assemblies: Option<Vec<BomReference>>,
@@ -30,6 +32,18 @@ pub struct Composition {
     pub signature: Option<Signature>,
 }
 
+impl Composition {
+    /// Constructs a `Composition` with an aggregate and no assemblies or dependencies
+    pub fn new(aggregate: AggregateType) -> Self {
+        Self {
+            aggregate,
+            assemblies: None,
+            dependencies: None,
+            signature: None,
+        }
+    }
+}
+
 impl Validate for Composition {
     fn validate_with_context(&self, context: ValidationContext) -> ValidationResult {
         let mut results: Vec<ValidationResult> = vec![];
@@ -44,7 +58,8 @@ impl Validate for Composition {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Compositions(pub Vec<Composition>);
 
 impl Validate for Compositions {
@@ -63,7 +78,8 @@ impl Validate for Compositions {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum AggregateType {
     Complete,
     Incomplete,
@@ -72,6 +88,7 @@ pub enum AggregateType {
     Unknown,
     NotSpecified,
     #[doc(hidden)]
+    #[cfg_attr(feature = "arbitrary", arbitrary(skip))]
     UnknownAggregateType(String),
 }
 
@@ -118,9 +135,17 @@ impl Validate for AggregateType {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct BomReference(pub(crate) String);
 
+impl BomReference {
+    /// Refers to the component, service or vulnerability with this `bom-ref`
+    pub fn new(bom_ref: impl Into<String>) -> Self {
+        Self(bom_ref.into())
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::models::signature::Algorithm;
@@ -156,7 +181,7 @@ mod test {
             ValidationResult::Failed {
                 reasons: vec![FailureReason {
                     message: "Unknown aggregate type".to_string(),
-                    context: ValidationContext(vec![
+                    context: ValidationContext::from(vec![
                         ValidationPathComponent::Array { index: 0 },
                         ValidationPathComponent::Struct {
                             struct_name: "Composition".to_string(),
@@ -16,11 +16,24 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Dependencies(pub Vec<Dependency>);
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub struct Dependency {
     pub dependency_ref: String,
     pub dependencies: Vec<String>,
 }
+
+impl Dependency {
+    /// Constructs a `Dependency` of the bom-ref on the given bom-refs
+    pub fn new(dependency_ref: impl ToString, dependencies: Vec<String>) -> Self {
+        Self {
+            dependency_ref: dependency_ref.to_string(),
+            dependencies,
+        }
+    }
+}
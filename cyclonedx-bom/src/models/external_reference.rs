@@ -17,6 +17,7 @@
  */
 
 use crate::external_models::uri::Uri;
+use crate::models::bom::SpecVersion;
 use crate::models::hash::Hashes;
 use crate::validation::{
     FailureReason, Validate, ValidationContext, ValidationPathComponent, ValidationResult,
@@ -25,7 +26,9 @@ use crate::validation::{
 /// Represents a way to document systems, sites, and information that may be relevant but which are not included with the BOM.
 ///
 /// Please see the [CycloneDX use case](https://cyclonedx.org/use-cases/#external-references) for more information and examples.
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub struct ExternalReference {
     pub external_reference_type: ExternalReferenceType,
     pub url: Uri,
@@ -82,9 +85,52 @@ impl Validate for ExternalReference {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ExternalReferences(pub Vec<ExternalReference>);
 
+impl ExternalReferences {
+    /// Checks that the external references only use types allowed in documents of the given spec version.
+    ///
+    /// Unknown types are reported by [`Validate`] instead.
+    pub fn validate_version(&self, version: SpecVersion) -> ValidationResult {
+        self.validate_version_with_context(version, ValidationContext::new())
+    }
+
+    pub(crate) fn validate_version_with_context(
+        &self,
+        version: SpecVersion,
+        context: ValidationContext,
+    ) -> ValidationResult {
+        let mut results: Vec<ValidationResult> = vec![];
+
+        for (index, external_reference) in self.0.iter().enumerate() {
+            let reference_type = &external_reference.external_reference_type;
+            match reference_type.introduced_in() {
+                Some(introduced_in) if !reference_type.is_allowed_in(version) => {
+                    let context = context
+                        .with_index(index)
+                        .with_struct("ExternalReference", "external_reference_type");
+                    results.push(ValidationResult::failure(
+                        &format!(
+                            "External reference type {} was introduced in spec version {} and is not allowed in {}",
+                            reference_type.to_string(),
+                            introduced_in,
+                            version.to_string()
+                        ),
+                        context,
+                    ));
+                }
+                _ => {}
+            }
+        }
+
+        results
+            .into_iter()
+            .fold(ValidationResult::default(), |acc, result| acc.merge(result))
+    }
+}
+
 impl Validate for ExternalReferences {
     fn validate_with_context(&self, context: ValidationContext) -> ValidationResult {
         let mut results: Vec<ValidationResult> = vec![];
@@ -100,8 +146,11 @@ impl Validate for ExternalReferences {
     }
 }
 
-/// Defined via the [CycloneDX XML schema](https://cyclonedx.org/docs/1.3/xml/#type_externalReferenceType).
-#[derive(Clone, Debug, PartialEq, Eq)]
+/// Defined via the [CycloneDX XML schema](https://cyclonedx.org/docs/1.5/xml/#type_externalReferenceType).
+///
+/// Types were added in later versions of the specification, see [`ExternalReferenceType::introduced_in`].
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum ExternalReferenceType {
     Vcs,
     IssueTracker,
@@ -113,12 +162,41 @@ pub enum ExternalReferenceType {
     Chat,
     Documentation,
     Support,
+    SourceDistribution,
     Distribution,
+    DistributionIntake,
     License,
     BuildMeta,
     BuildSystem,
+    ReleaseNotes,
+    SecurityContact,
+    ModelCard,
+    Log,
+    Configuration,
+    Evidence,
+    Formulation,
+    Attestation,
+    ThreatModel,
+    AdversaryModel,
+    RiskAssessment,
+    VulnerabilityAssertion,
+    ExploitabilityStatement,
+    PentestReport,
+    StaticAnalysisReport,
+    DynamicAnalysisReport,
+    RuntimeAnalysisReport,
+    ComponentAnalysisReport,
+    MaturityReport,
+    CertificationReport,
+    CodifiedInfrastructure,
+    QualityMetrics,
+    Poam,
+    ElectronicSignature,
+    DigitalSignature,
+    Rfc9116,
     Other,
     #[doc(hidden)]
+    #[cfg_attr(feature = "arbitrary", arbitrary(skip))]
     UnknownExternalReferenceType(String),
 }
 
@@ -135,10 +213,38 @@ impl ToString for ExternalReferenceType {
             ExternalReferenceType::Chat => "chat",
             ExternalReferenceType::Documentation => "documentation",
             ExternalReferenceType::Support => "support",
+            ExternalReferenceType::SourceDistribution => "source-distribution",
             ExternalReferenceType::Distribution => "distribution",
+            ExternalReferenceType::DistributionIntake => "distribution-intake",
             ExternalReferenceType::License => "license",
             ExternalReferenceType::BuildMeta => "build-meta",
             ExternalReferenceType::BuildSystem => "build-system",
+            ExternalReferenceType::ReleaseNotes => "release-notes",
+            ExternalReferenceType::SecurityContact => "security-contact",
+            ExternalReferenceType::ModelCard => "model-card",
+            ExternalReferenceType::Log => "log",
+            ExternalReferenceType::Configuration => "configuration",
+            ExternalReferenceType::Evidence => "evidence",
+            ExternalReferenceType::Formulation => "formulation",
+            ExternalReferenceType::Attestation => "attestation",
+            ExternalReferenceType::ThreatModel => "threat-model",
+            ExternalReferenceType::AdversaryModel => "adversary-model",
+            ExternalReferenceType::RiskAssessment => "risk-assessment",
+            ExternalReferenceType::VulnerabilityAssertion => "vulnerability-assertion",
+            ExternalReferenceType::ExploitabilityStatement => "exploitability-statement",
+            ExternalReferenceType::PentestReport => "pentest-report",
+            ExternalReferenceType::StaticAnalysisReport => "static-analysis-report",
+            ExternalReferenceType::DynamicAnalysisReport => "dynamic-analysis-report",
+            ExternalReferenceType::RuntimeAnalysisReport => "runtime-analysis-report",
+            ExternalReferenceType::ComponentAnalysisReport => "component-analysis-report",
+            ExternalReferenceType::MaturityReport => "maturity-report",
+            ExternalReferenceType::CertificationReport => "certification-report",
+            ExternalReferenceType::CodifiedInfrastructure => "codified-infrastructure",
+            ExternalReferenceType::QualityMetrics => "quality-metrics",
+            ExternalReferenceType::Poam => "poam",
+            ExternalReferenceType::ElectronicSignature => "electronic-signature",
+            ExternalReferenceType::DigitalSignature => "digital-signature",
+            ExternalReferenceType::Rfc9116 => "rfc-9116",
             ExternalReferenceType::Other => "other",
             ExternalReferenceType::UnknownExternalReferenceType(un) => un,
         }
@@ -159,14 +265,110 @@ impl ExternalReferenceType {
             "chat" => Self::Chat,
             "documentation" => Self::Documentation,
             "support" => Self::Support,
+            "source-distribution" => Self::SourceDistribution,
             "distribution" => Self::Distribution,
+            "distribution-intake" => Self::DistributionIntake,
             "license" => Self::License,
             "build-meta" => Self::BuildMeta,
             "build-system" => Self::BuildSystem,
+            "release-notes" => Self::ReleaseNotes,
+            "security-contact" => Self::SecurityContact,
+            "model-card" => Self::ModelCard,
+            "log" => Self::Log,
+            "configuration" => Self::Configuration,
+            "evidence" => Self::Evidence,
+            "formulation" => Self::Formulation,
+            "attestation" => Self::Attestation,
+            "threat-model" => Self::ThreatModel,
+            "adversary-model" => Self::AdversaryModel,
+            "risk-assessment" => Self::RiskAssessment,
+            "vulnerability-assertion" => Self::VulnerabilityAssertion,
+            "exploitability-statement" => Self::ExploitabilityStatement,
+            "pentest-report" => Self::PentestReport,
+            "static-analysis-report" => Self::StaticAnalysisReport,
+            "dynamic-analysis-report" => Self::DynamicAnalysisReport,
+            "runtime-analysis-report" => Self::RuntimeAnalysisReport,
+            "component-analysis-report" => Self::ComponentAnalysisReport,
+            "maturity-report" => Self::MaturityReport,
+            "certification-report" => Self::CertificationReport,
+            "codified-infrastructure" => Self::CodifiedInfrastructure,
+            "quality-metrics" => Self::QualityMetrics,
+            "poam" => Self::Poam,
+            "electronic-signature" => Self::ElectronicSignature,
+            "digital-signature" => Self::DigitalSignature,
+            "rfc-9116" => Self::Rfc9116,
             "other" => Self::Other,
             unknown => Self::UnknownExternalReferenceType(unknown.to_string()),
         }
     }
+
+    /// The minor version of the CycloneDX 1.x specification that introduced the type
+    fn minor_version(&self) -> Option<u8> {
+        match self {
+            Self::Vcs
+            | Self::IssueTracker
+            | Self::Website
+            | Self::Advisories
+            | Self::Bom
+            | Self::MailingList
+            | Self::Social
+            | Self::Chat
+            | Self::Documentation
+            | Self::Support
+            | Self::Distribution
+            | Self::License
+            | Self::BuildMeta
+            | Self::BuildSystem
+            | Self::Other => Some(3),
+            Self::ReleaseNotes => Some(4),
+            Self::DistributionIntake
+            | Self::SecurityContact
+            | Self::ModelCard
+            | Self::Log
+            | Self::Configuration
+            | Self::Evidence
+            | Self::Formulation
+            | Self::Attestation
+            | Self::ThreatModel
+            | Self::AdversaryModel
+            | Self::RiskAssessment
+            | Self::VulnerabilityAssertion
+            | Self::ExploitabilityStatement
+            | Self::PentestReport
+            | Self::StaticAnalysisReport
+            | Self::DynamicAnalysisReport
+            | Self::RuntimeAnalysisReport
+            | Self::ComponentAnalysisReport
+            | Self::MaturityReport
+            | Self::CertificationReport
+            | Self::CodifiedInfrastructure
+            | Self::QualityMetrics
+            | Self::Poam => Some(5),
+            Self::SourceDistribution
+            | Self::ElectronicSignature
+            | Self::DigitalSignature
+            | Self::Rfc9116 => Some(6),
+            Self::UnknownExternalReferenceType(_) => None,
+        }
+    }
+
+    /// The version of the specification that introduced the type, e.g. `"1.5"`,
+    /// or `None` for an unknown type
+    pub fn introduced_in(&self) -> Option<String> {
+        self.minor_version().map(|minor| format!("1.{}", minor))
+    }
+
+    /// Whether documents of the given spec version may use the type.
+    ///
+    /// Types introduced in CycloneDX 1.6 are not allowed in any version this crate can write.
+    pub fn is_allowed_in(&self, version: SpecVersion) -> bool {
+        let supported = match version {
+            SpecVersion::V1_3 => 3,
+            SpecVersion::V1_4 => 4,
+            SpecVersion::V1_5 => 5,
+        };
+        self.minor_version().is_some_and(|minor| minor <= supported)
+    }
 }
 
 impl Validate for ExternalReferenceType {
@@ -204,6 +406,71 @@ mod test {
         assert_eq!(validation_result, ValidationResult::Passed);
     }
 
+    #[test]
+    fn it_should_know_the_types_of_every_spec_version() {
+        for (name, introduced_in) in [
+            ("vcs", "1.3"),
+            ("release-notes", "1.4"),
+            ("security-contact", "1.5"),
+            ("model-card", "1.5"),
+            ("log", "1.5"),
+            ("configuration", "1.5"),
+            ("evidence", "1.5"),
+            ("source-distribution", "1.6"),
+            ("rfc-9116", "1.6"),
+        ] {
+            let reference_type = ExternalReferenceType::new_unchecked(name);
+            assert_eq!(reference_type.to_string(), name);
+            assert_eq!(
+                reference_type.introduced_in().as_deref(),
+                Some(introduced_in)
+            );
+        }
+
+        let unknown = ExternalReferenceType::new_unchecked("future-type");
+        assert_eq!(unknown.to_string(), "future-type");
+        assert_eq!(unknown.introduced_in(), None);
+        assert!(!unknown.is_allowed_in(SpecVersion::V1_5));
+
+        assert!(ExternalReferenceType::ReleaseNotes.is_allowed_in(SpecVersion::V1_4));
+        assert!(!ExternalReferenceType::ReleaseNotes.is_allowed_in(SpecVersion::V1_3));
+        assert!(!ExternalReferenceType::SourceDistribution.is_allowed_in(SpecVersion::V1_5));
+    }
+
+    #[test]
+    fn it_should_validate_the_types_for_the_spec_version() {
+        let external_references = ExternalReferences(
+            [
+                ExternalReferenceType::Website,
+                ExternalReferenceType::SecurityContact,
+                ExternalReferenceType::UnknownExternalReferenceType("future-type".to_string()),
+            ]
+            .into_iter()
+            .map(|reference_type| {
+                ExternalReference::new(reference_type, Uri("https://example.com".to_string()))
+            })
+            .collect(),
+        );
+
+        assert_eq!(
+            external_references.validate_version(SpecVersion::V1_5),
+            ValidationResult::Passed
+        );
+        assert_eq!(
+            external_references.validate_version(SpecVersion::V1_4),
+            ValidationResult::failure(
+                "External reference type security-contact was introduced in spec version 1.5 and is not allowed in 1.4",
+                ValidationContext::from(vec![
+                    ValidationPathComponent::Array { index: 1 },
+                    ValidationPathComponent::Struct {
+                        struct_name: "ExternalReference".to_string(),
+                        field_name: "external_reference_type".to_string()
+                    }
+                ])
+            )
+        );
+    }
+
     #[test]
     fn it_should_fail_validation() {
         let validation_result = ExternalReferences(vec![ExternalReference {
@@ -225,7 +492,7 @@ mod test {
                 reasons: vec![
                     FailureReason {
                         message: "Unknown external reference type".to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "ExternalReference".to_string(),
@@ -235,7 +502,7 @@ mod test {
                     },
                     FailureReason {
                         message: "Uri does not conform to RFC 3986".to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "ExternalReference".to_string(),
@@ -245,7 +512,7 @@ mod test {
                     },
                     FailureReason {
                         message: "HashValue does not match regular expression".to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "ExternalReference".to_string(),
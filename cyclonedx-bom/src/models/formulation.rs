@@ -0,0 +1,439 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use std::fmt;
+
+use crate::external_models::date_time::DateTime;
+use crate::external_models::normalized_string::NormalizedString;
+use crate::models::component::Components;
+use crate::models::property::Properties;
+use crate::models::service::Services;
+use crate::validation::{
+    FailureReason, Validate, ValidationContext, ValidationPathComponent, ValidationResult,
+};
+
+/// Describes how the components of the BOM were manufactured, e.g. the build that produced them
+///
+/// Defined via the [CycloneDX JSON schema](https://cyclonedx.org/docs/1.5/json/#formulation).
+/// Added in version 1.5
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct Formulation(pub Vec<Formula>);
+
+impl Validate for Formulation {
+    fn validate_with_context(&self, context: ValidationContext) -> ValidationResult {
+        let mut results: Vec<ValidationResult> = vec![];
+
+        for (index, formula) in self.0.iter().enumerate() {
+            let formula_context =
+                context.extend_context(vec![ValidationPathComponent::Array { index }]);
+            results.push(formula.validate_with_context(formula_context));
+        }
+
+        results
+            .into_iter()
+            .fold(ValidationResult::default(), |acc, result| acc.merge(result))
+    }
+}
+
+/// The components, services and workflows that were used to manufacture something
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct Formula {
+    pub bom_ref: Option<String>,
+    pub components: Option<Components>,
+    pub services: Option<Services>,
+    pub workflows: Option<Vec<Workflow>>,
+    pub properties: Option<Properties>,
+}
+
+impl Validate for Formula {
+    fn validate_with_context(&self, context: ValidationContext) -> ValidationResult {
+        let mut results: Vec<ValidationResult> = vec![];
+
+        if let Some(components) = &self.components {
+            let context = context.with_struct("Formula", "components");
+
+            results.push(components.validate_with_context(context));
+        }
+
+        if let Some(services) = &self.services {
+            let context = context.with_struct("Formula", "services");
+
+            results.push(services.validate_with_context(context));
+        }
+
+        if let Some(workflows) = &self.workflows {
+            for (index, workflow) in workflows.iter().enumerate() {
+                let context = context.extend_context(vec![
+                    ValidationPathComponent::Struct {
+                        struct_name: "Formula".to_string(),
+                        field_name: "workflows".to_string(),
+                    },
+                    ValidationPathComponent::Array { index },
+                ]);
+                results.push(workflow.validate_with_context(context));
+            }
+        }
+
+        results
+            .into_iter()
+            .fold(ValidationResult::default(), |acc, result| acc.merge(result))
+    }
+}
+
+/// A sequence of steps that were executed, e.g. a build
+///
+/// Only a subset of the fields of the specification is supported: resource references,
+/// tasks, triggers, outputs, workspaces and the runtime topology are not represented yet.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct Workflow {
+    pub bom_ref: String,
+    pub uid: String,
+    pub name: Option<NormalizedString>,
+    pub description: Option<NormalizedString>,
+    pub task_types: Vec<TaskType>,
+    pub steps: Option<Vec<Step>>,
+    pub inputs: Option<Vec<Input>>,
+    pub time_start: Option<DateTime>,
+    pub time_end: Option<DateTime>,
+    pub properties: Option<Properties>,
+}
+
+impl Workflow {
+    /// Constructs a `Workflow` with a bom-ref, uid and task types
+    pub fn new(bom_ref: impl ToString, uid: impl ToString, task_types: Vec<TaskType>) -> Self {
+        Self {
+            bom_ref: bom_ref.to_string(),
+            uid: uid.to_string(),
+            name: None,
+            description: None,
+            task_types,
+            steps: None,
+            inputs: None,
+            time_start: None,
+            time_end: None,
+            properties: None,
+        }
+    }
+}
+
+impl Validate for Workflow {
+    fn validate_with_context(&self, context: ValidationContext) -> ValidationResult {
+        let mut results: Vec<ValidationResult> = vec![];
+
+        if let Some(name) = &self.name {
+            let context = context.with_struct("Workflow", "name");
+
+            results.push(name.validate_with_context(context));
+        }
+
+        if let Some(description) = &self.description {
+            let context = context.with_struct("Workflow", "description");
+
+            results.push(description.validate_with_context(context));
+        }
+
+        for (index, task_type) in self.task_types.iter().enumerate() {
+            let context = context.extend_context(vec![
+                ValidationPathComponent::Struct {
+                    struct_name: "Workflow".to_string(),
+                    field_name: "task_types".to_string(),
+                },
+                ValidationPathComponent::Array { index },
+            ]);
+            results.push(task_type.validate_with_context(context));
+        }
+
+        if let Some(steps) = &self.steps {
+            for (index, step) in steps.iter().enumerate() {
+                let context = context.extend_context(vec![
+                    ValidationPathComponent::Struct {
+                        struct_name: "Workflow".to_string(),
+                        field_name: "steps".to_string(),
+                    },
+                    ValidationPathComponent::Array { index },
+                ]);
+                results.push(step.validate_with_context(context));
+            }
+        }
+
+        if let Some(time_start) = &self.time_start {
+            let context = context.with_struct("Workflow", "time_start");
+
+            results.push(time_start.validate_with_context(context));
+        }
+
+        if let Some(time_end) = &self.time_end {
+            let context = context.with_struct("Workflow", "time_end");
+
+            results.push(time_end.validate_with_context(context));
+        }
+
+        results
+            .into_iter()
+            .fold(ValidationResult::default(), |acc, result| acc.merge(result))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum TaskType {
+    Copy,
+    Clone,
+    Lint,
+    Scan,
+    Merge,
+    Build,
+    Test,
+    Deliver,
+    Deploy,
+    Release,
+    Clean,
+    Other,
+    #[doc(hidden)]
+    #[cfg_attr(feature = "arbitrary", arbitrary(skip))]
+    UnknownTaskType(String),
+}
+
+impl fmt::Display for TaskType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let task_type = match self {
+            TaskType::Copy => "copy",
+            TaskType::Clone => "clone",
+            TaskType::Lint => "lint",
+            TaskType::Scan => "scan",
+            TaskType::Merge => "merge",
+            TaskType::Build => "build",
+            TaskType::Test => "test",
+            TaskType::Deliver => "deliver",
+            TaskType::Deploy => "deploy",
+            TaskType::Release => "release",
+            TaskType::Clean => "clean",
+            TaskType::Other => "other",
+            TaskType::UnknownTaskType(unknown) => unknown,
+        };
+        write!(f, "{}", task_type)
+    }
+}
+
+impl TaskType {
+    pub(crate) fn new_unchecked<A: AsRef<str>>(value: A) -> Self {
+        match value.as_ref() {
+            "copy" => Self::Copy,
+            "clone" => Self::Clone,
+            "lint" => Self::Lint,
+            "scan" => Self::Scan,
+            "merge" => Self::Merge,
+            "build" => Self::Build,
+            "test" => Self::Test,
+            "deliver" => Self::Deliver,
+            "deploy" => Self::Deploy,
+            "release" => Self::Release,
+            "clean" => Self::Clean,
+            "other" => Self::Other,
+            unknown => Self::UnknownTaskType(unknown.to_string()),
+        }
+    }
+}
+
+impl Validate for TaskType {
+    fn validate_with_context(&self, context: ValidationContext) -> ValidationResult {
+        match self {
+            TaskType::UnknownTaskType(_) => ValidationResult::Failed {
+                reasons: vec![FailureReason {
+                    message: "Unknown task type".to_string(),
+                    context,
+                }],
+            },
+            _ => ValidationResult::Passed,
+        }
+    }
+}
+
+/// One step of a workflow, e.g. the invocation of a command
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct Step {
+    pub name: Option<NormalizedString>,
+    pub description: Option<NormalizedString>,
+    pub commands: Option<Vec<Command>>,
+    pub properties: Option<Properties>,
+}
+
+impl Validate for Step {
+    fn validate_with_context(&self, context: ValidationContext) -> ValidationResult {
+        let mut results: Vec<ValidationResult> = vec![];
+
+        if let Some(name) = &self.name {
+            let context = context.with_struct("Step", "name");
+
+            results.push(name.validate_with_context(context));
+        }
+
+        if let Some(description) = &self.description {
+            let context = context.with_struct("Step", "description");
+
+            results.push(description.validate_with_context(context));
+        }
+
+        results
+            .into_iter()
+            .fold(ValidationResult::default(), |acc, result| acc.merge(result))
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct Command {
+    /// The command line that was executed
+    pub executed: Option<String>,
+    pub properties: Option<Properties>,
+}
+
+/// Something a workflow was given to work with
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct Input {
+    pub required: RequiredInputField,
+    pub properties: Option<Properties>,
+}
+
+impl Input {
+    /// Constructs an `Input` without properties
+    pub fn new(required: RequiredInputField) -> Self {
+        Self {
+            required,
+            properties: None,
+        }
+    }
+}
+
+/// The specification requires each input to be one of several kinds.
+/// Resource references and data inputs are not represented yet.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum RequiredInputField {
+    Parameters(Vec<Parameter>),
+    EnvironmentVars(Vec<EnvironmentVar>),
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct Parameter {
+    pub name: Option<String>,
+    pub value: Option<String>,
+    pub data_type: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum EnvironmentVar {
+    /// A variable with its name and value
+    Property { name: String, value: String },
+    /// A variable given as a single string, e.g. `NAME=value`
+    Value(String),
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn workflow(task_type: TaskType) -> Workflow {
+        Workflow {
+            bom_ref: "build".to_string(),
+            uid: "build".to_string(),
+            name: Some(NormalizedString::new("cargo build")),
+            description: None,
+            task_types: vec![task_type],
+            steps: Some(vec![Step {
+                name: Some(NormalizedString::new("compile")),
+                description: None,
+                commands: Some(vec![Command {
+                    executed: Some("cargo build --release".to_string()),
+                    properties: None,
+                }]),
+                properties: None,
+            }]),
+            inputs: Some(vec![Input {
+                required: RequiredInputField::EnvironmentVars(vec![EnvironmentVar::Property {
+                    name: "RUSTFLAGS".to_string(),
+                    value: "-C target-cpu=native".to_string(),
+                }]),
+                properties: None,
+            }]),
+            time_start: None,
+            time_end: None,
+            properties: None,
+        }
+    }
+
+    #[test]
+    fn it_should_pass_validation() {
+        let validation_result = Formulation(vec![Formula {
+            bom_ref: Some("formula".to_string()),
+            workflows: Some(vec![workflow(TaskType::Build)]),
+            ..Formula::default()
+        }])
+        .validate();
+
+        assert_eq!(validation_result, ValidationResult::Passed);
+    }
+
+    #[test]
+    fn it_should_fail_validation() {
+        let validation_result = Formulation(vec![Formula {
+            bom_ref: None,
+            workflows: Some(vec![workflow(TaskType::UnknownTaskType(
+                "unknown".to_string(),
+            ))]),
+            ..Formula::default()
+        }])
+        .validate();
+
+        assert_eq!(
+            validation_result,
+            ValidationResult::Failed {
+                reasons: vec![FailureReason {
+                    message: "Unknown task type".to_string(),
+                    context: ValidationContext::from(vec![
+                        ValidationPathComponent::Array { index: 0 },
+                        ValidationPathComponent::Struct {
+                            struct_name: "Formula".to_string(),
+                            field_name: "workflows".to_string()
+                        },
+                        ValidationPathComponent::Array { index: 0 },
+                        ValidationPathComponent::Struct {
+                            struct_name: "Workflow".to_string(),
+                            field_name: "task_types".to_string()
+                        },
+                        ValidationPathComponent::Array { index: 0 },
+                    ])
+                }]
+            }
+        );
+    }
+}
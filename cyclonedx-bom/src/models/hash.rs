@@ -19,6 +19,7 @@
 use once_cell::sync::Lazy;
 use regex::Regex;
 
+use crate::models::bom::SpecVersion;
 use crate::validation::{
     FailureReason, Validate, ValidationContext, ValidationPathComponent, ValidationResult,
 };
@@ -26,12 +27,37 @@ use crate::validation::{
 /// Represents the hash of the component
 ///
 /// Defined via the [CycloneDX XML schema](https://cyclonedx.org/docs/1.3/xml/#type_hashType)
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
 pub struct Hash {
     pub alg: HashAlgorithm,
     pub content: HashValue,
 }
 
+impl Hash {
+    /// Constructs a `Hash` with an algorithm and value
+    pub fn new(alg: HashAlgorithm, content: HashValue) -> Self {
+        Self { alg, content }
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Hash {
+    /// Generates a digest of the length produced by the algorithm
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let alg: HashAlgorithm = u.arbitrary()?;
+        let len = match alg {
+            HashAlgorithm::MD5 => 32,
+            HashAlgorithm::SHA1 => 40,
+            HashAlgorithm::SHA384 | HashAlgorithm::SHA3_384 | HashAlgorithm::BLAKE2b_384 => 96,
+            HashAlgorithm::SHA512 | HashAlgorithm::SHA3_512 | HashAlgorithm::BLAKE2b_512 => 128,
+            _ => 64,
+        };
+        let content = HashValue(crate::utilities::arbitrary_hex(u, len)?);
+        Ok(Self::new(alg, content))
+    }
+}
+
 impl Validate for Hash {
     fn validate_with_context(&self, context: ValidationContext) -> ValidationResult {
         let mut results: Vec<ValidationResult> = vec![];
@@ -50,9 +76,49 @@ impl Validate for Hash {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Hashes(pub Vec<Hash>);
 
+impl Hashes {
+    /// Checks that the hashes only use algorithms allowed in documents of the given spec version.
+    ///
+    /// Unknown algorithms are reported by [`Validate`] instead.
+    pub fn validate_version(&self, version: SpecVersion) -> ValidationResult {
+        self.validate_version_with_context(version, ValidationContext::new())
+    }
+
+    pub(crate) fn validate_version_with_context(
+        &self,
+        version: SpecVersion,
+        context: ValidationContext,
+    ) -> ValidationResult {
+        let mut results: Vec<ValidationResult> = vec![];
+
+        for (index, hash) in self.0.iter().enumerate() {
+            let alg = &hash.alg;
+            match alg.introduced_in() {
+                Some(introduced_in) if !alg.is_allowed_in(version) => {
+                    results.push(ValidationResult::failure(
+                        &format!(
+                            "Hash algorithm {} was introduced in spec version {} and is not allowed in {}",
+                            alg.to_string(),
+                            introduced_in,
+                            version.to_string()
+                        ),
+                        context.with_index(index).with_struct("Hash", "alg"),
+                    ));
+                }
+                _ => {}
+            }
+        }
+
+        results
+            .into_iter()
+            .fold(ValidationResult::default(), |acc, result| acc.merge(result))
+    }
+}
+
 impl Validate for Hashes {
     fn validate_with_context(&self, context: ValidationContext) -> ValidationResult {
         let mut results: Vec<ValidationResult> = vec![];
@@ -73,7 +139,8 @@ impl Validate for Hashes {
 ///
 /// Defined via the [CycloneDX XML schema](https://cyclonedx.org/docs/1.3/xml/#type_hashAlg)
 #[allow(non_camel_case_types)]
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum HashAlgorithm {
     MD5,
     SHA1,
@@ -88,6 +155,7 @@ pub enum HashAlgorithm {
     BLAKE2b_512,
     BLAKE3,
     #[doc(hidden)]
+    #[cfg_attr(feature = "arbitrary", arbitrary(skip))]
     UnknownHashAlgorithm(String),
 }
 
@@ -130,6 +198,30 @@ impl HashAlgorithm {
             unknown => Self::UnknownHashAlgorithm(unknown.to_string()),
         }
     }
+
+    /// The minor version of the CycloneDX 1.x specification that introduced the algorithm
+    fn minor_version(&self) -> Option<u8> {
+        match self {
+            Self::UnknownHashAlgorithm(_) => None,
+            _ => Some(3),
+        }
+    }
+
+    /// The version of the specification that introduced the algorithm, e.g. `"1.3"`,
+    /// or `None` for an unknown algorithm
+    pub fn introduced_in(&self) -> Option<String> {
+        self.minor_version().map(|minor| format!("1.{}", minor))
+    }
+
+    /// Whether documents of the given spec version may use the algorithm
+    pub fn is_allowed_in(&self, version: SpecVersion) -> bool {
+        let supported = match version {
+            SpecVersion::V1_3 => 3,
+            SpecVersion::V1_4 => 4,
+            SpecVersion::V1_5 => 5,
+        };
+        self.minor_version().is_some_and(|minor| minor <= supported)
+    }
 }
 
 impl Validate for HashAlgorithm {
@@ -147,9 +239,17 @@ impl Validate for HashAlgorithm {
 }
 
 /// Defined via the [CycloneDX XML schema](https://cyclonedx.org/docs/1.3/xml/#type_hashValue)
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct HashValue(pub String);
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for HashValue {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let len = *u.choose(&[32, 40, 64, 96, 128])?;
+        Ok(Self(crate::utilities::arbitrary_hex(u, len)?))
+    }
+}
+
 impl Validate for HashValue {
     fn validate_with_context(&self, context: ValidationContext) -> ValidationResult {
         static HASH_VALUE_REGEX: Lazy<Regex> = Lazy::new(|| {
@@ -201,7 +301,7 @@ mod test {
                 reasons: vec![
                     FailureReason {
                         message: "Unknown HashAlgorithm".to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Hash".to_string(),
@@ -211,7 +311,7 @@ mod test {
                     },
                     FailureReason {
                         message: "HashValue does not match regular expression".to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Hash".to_string(),
@@ -223,4 +323,23 @@ mod test {
             }
         );
     }
+
+    #[test]
+    fn it_should_allow_the_known_algorithms_in_every_spec_version() {
+        let hashes = Hashes(vec![Hash::new(
+            HashAlgorithm::BLAKE3,
+            HashValue("a".repeat(64)),
+        )]);
+
+        assert_eq!(
+            hashes.validate_version(SpecVersion::V1_3),
+            ValidationResult::Passed
+        );
+        assert_eq!(
+            HashAlgorithm::SHA3_512.introduced_in().as_deref(),
+            Some("1.3")
+        );
+        assert!(!HashAlgorithm::UnknownHashAlgorithm("SHA-224".to_string())
+            .is_allowed_in(SpecVersion::V1_5));
+    }
 }
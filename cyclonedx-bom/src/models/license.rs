@@ -30,7 +30,8 @@ use crate::validation::{Validate, ValidationContext, ValidationPathComponent, Va
 /// Represents whether a license is a named license or an SPDX license expression
 ///
 /// As defined via the [CycloneDX XML schema](https://cyclonedx.org/docs/1.3/xml/#type_licenseChoiceType)
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum LicenseChoice {
     License(License),
     Expression(SpdxExpression),
@@ -76,7 +77,9 @@ impl Validate for LicenseChoice {
 /// Represents a license with identifier, text, and url
 ///
 /// Defined via the [CycloneDX XML schema](https://cyclonedx.org/docs/1.3/xml/#type_licenseType)
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub struct License {
     pub license_identifier: LicenseIdentifier,
     pub text: Option<AttachedText>,
@@ -144,7 +147,8 @@ impl Validate for License {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Licenses(pub Vec<LicenseChoice>);
 
 impl Validate for Licenses {
@@ -163,7 +167,8 @@ impl Validate for Licenses {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum LicenseIdentifier {
     /// An SPDX license identifier from the list on the [SPDX website](https://spdx.org/licenses/).
     SpdxId(SpdxIdentifier),
@@ -226,7 +231,7 @@ mod test {
                 reasons: vec![FailureReason {
                     message: "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                         .to_string(),
-                    context: ValidationContext(vec![
+                    context: ValidationContext::from(vec![
                         ValidationPathComponent::Array { index: 0 },
                         ValidationPathComponent::EnumVariant {
                             variant_name: "License".to_string()
@@ -258,7 +263,7 @@ mod test {
             ValidationResult::Failed {
                 reasons: vec![FailureReason {
                     message: "SPDX identifier is not valid".to_string(),
-                    context: ValidationContext(vec![
+                    context: ValidationContext::from(vec![
                         ValidationPathComponent::Array { index: 0 },
                         ValidationPathComponent::EnumVariant {
                             variant_name: "License".to_string()
@@ -288,7 +293,7 @@ mod test {
             ValidationResult::Failed {
                 reasons: vec![FailureReason {
                     message: "SPDX expression is not valid".to_string(),
-                    context: ValidationContext(vec![
+                    context: ValidationContext::from(vec![
                         ValidationPathComponent::Array { index: 0 },
                         ValidationPathComponent::EnumVariant {
                             variant_name: "Expression".to_string()
@@ -332,7 +337,7 @@ mod test {
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 1 },
                             ValidationPathComponent::EnumVariant {
                                 variant_name: "License".to_string()
@@ -348,7 +353,7 @@ mod test {
                     },
                     FailureReason {
                         message: "SPDX identifier is not valid".to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 2 },
                             ValidationPathComponent::EnumVariant {
                                 variant_name: "License".to_string()
@@ -382,7 +387,7 @@ mod test {
                 reasons: vec![
                     FailureReason {
                         message: "SPDX expression is not valid".to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 1 },
                             ValidationPathComponent::EnumVariant {
                                 variant_name: "Expression".to_string()
@@ -391,7 +396,7 @@ mod test {
                     },
                     FailureReason {
                         message: "SPDX expression is not valid".to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 2 },
                             ValidationPathComponent::EnumVariant {
                                 variant_name: "Expression".to_string()
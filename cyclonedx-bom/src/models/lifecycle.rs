@@ -0,0 +1,188 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use crate::external_models::normalized_string::NormalizedString;
+use crate::validation::{Validate, ValidationContext, ValidationResult};
+
+/// The stages of the product lifecycle that the BOM describes
+///
+/// Defined via the [CycloneDX XML schema](https://cyclonedx.org/docs/1.5/xml/#type_metadata_lifecycles).
+/// Added in version 1.5
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct Lifecycles(pub Vec<Lifecycle>);
+
+impl Validate for Lifecycles {
+    fn validate_with_context(&self, context: ValidationContext) -> ValidationResult {
+        let mut results: Vec<ValidationResult> = vec![];
+
+        for (index, lifecycle) in self.0.iter().enumerate() {
+            let context = context.with_index(index);
+            results.push(lifecycle.validate_with_context(context));
+        }
+
+        results
+            .into_iter()
+            .fold(ValidationResult::default(), |acc, result| acc.merge(result))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum Lifecycle {
+    /// One of the phases predefined by the specification
+    Phase(Phase),
+    /// A phase that is not predefined, e.g. one specific to an organization
+    Description {
+        name: NormalizedString,
+        description: Option<NormalizedString>,
+    },
+}
+
+impl Validate for Lifecycle {
+    fn validate_with_context(&self, context: ValidationContext) -> ValidationResult {
+        match self {
+            Lifecycle::Phase(phase) => {
+                phase.validate_with_context(context.with_struct("Lifecycle", "phase"))
+            }
+            Lifecycle::Description { name, description } => {
+                let mut result =
+                    name.validate_with_context(context.with_struct("Lifecycle", "name"));
+                if let Some(description) = description {
+                    let context = context.with_struct("Lifecycle", "description");
+                    result = result.merge(description.validate_with_context(context));
+                }
+                result
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum Phase {
+    /// BOM produced early in the development lifecycle containing an inventory of components
+    /// and services that are proposed or planned to be used
+    Design,
+    /// BOM consisting of information obtained prior to a build process
+    PreBuild,
+    /// BOM consisting of information obtained during a build process
+    Build,
+    /// BOM consisting of information obtained after a build process has completed
+    PostBuild,
+    /// BOM produced that represents inventory that is running and operational
+    Operations,
+    /// BOM consisting of information observed through network discovery
+    Discovery,
+    /// BOM containing inventory that will be, or has been retired from operations
+    Decommission,
+    #[doc(hidden)]
+    #[cfg_attr(feature = "arbitrary", arbitrary(skip))]
+    UnknownPhase(String),
+}
+
+impl std::fmt::Display for Phase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Phase::Design => "design",
+            Phase::PreBuild => "pre-build",
+            Phase::Build => "build",
+            Phase::PostBuild => "post-build",
+            Phase::Operations => "operations",
+            Phase::Discovery => "discovery",
+            Phase::Decommission => "decommission",
+            Phase::UnknownPhase(un) => un,
+        })
+    }
+}
+
+impl Phase {
+    pub(crate) fn new_unchecked<A: AsRef<str>>(value: A) -> Self {
+        match value.as_ref() {
+            "design" => Self::Design,
+            "pre-build" => Self::PreBuild,
+            "build" => Self::Build,
+            "post-build" => Self::PostBuild,
+            "operations" => Self::Operations,
+            "discovery" => Self::Discovery,
+            "decommission" => Self::Decommission,
+            unknown => Self::UnknownPhase(unknown.to_string()),
+        }
+    }
+}
+
+impl Validate for Phase {
+    fn validate_with_context(&self, context: ValidationContext) -> ValidationResult {
+        match self {
+            Phase::UnknownPhase(_) => ValidationResult::failure("Unknown lifecycle phase", context),
+            _ => ValidationResult::Passed,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn valid_lifecycles_should_pass_validation() {
+        let validation_result = Lifecycles(vec![
+            Lifecycle::Phase(Phase::Build),
+            Lifecycle::Description {
+                name: NormalizedString::new("platform-integration"),
+                description: Some(NormalizedString::new("Integration into the platform image")),
+            },
+        ])
+        .validate();
+
+        assert_eq!(validation_result, ValidationResult::Passed);
+    }
+
+    #[test]
+    fn invalid_lifecycles_should_fail_validation() {
+        let validation_result = Lifecycles(vec![
+            Lifecycle::Phase(Phase::UnknownPhase("unknown".to_string())),
+            Lifecycle::Description {
+                name: NormalizedString("invalid\tname".to_string()),
+                description: None,
+            },
+        ])
+        .validate();
+
+        assert_eq!(
+            validation_result,
+            ValidationResult::Failed {
+                reasons: vec![
+                    crate::validation::FailureReason::new(
+                        "Unknown lifecycle phase",
+                        ValidationContext::new()
+                            .with_index(0)
+                            .with_struct("Lifecycle", "phase")
+                    ),
+                    crate::validation::FailureReason::new(
+                        "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n",
+                        ValidationContext::new()
+                            .with_index(1)
+                            .with_struct("Lifecycle", "name")
+                    ),
+                ]
+            }
+        );
+    }
+}
@@ -21,6 +21,7 @@ use thiserror::Error;
 use crate::external_models::date_time::{DateTime, DateTimeError};
 use crate::models::component::Component;
 use crate::models::license::Licenses;
+use crate::models::lifecycle::Lifecycles;
 use crate::models::organization::{OrganizationalContact, OrganizationalEntity};
 use crate::models::property::Properties;
 use crate::models::tool::Tools;
@@ -29,7 +30,9 @@ use crate::validation::{Validate, ValidationContext, ValidationPathComponent, Va
 /// Represents additional information about a BOM
 ///
 /// Defined via the [CycloneDX XML schema](https://cyclonedx.org/docs/1.3/xml/#type_metadata)
-#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub struct Metadata {
     pub timestamp: Option<DateTime>,
     pub tools: Option<Tools>,
@@ -39,6 +42,8 @@ pub struct Metadata {
     pub supplier: Option<OrganizationalEntity>,
     pub licenses: Option<Licenses>,
     pub properties: Option<Properties>,
+    /// Added in version 1.5
+    pub lifecycles: Option<Lifecycles>,
 }
 
 impl Metadata {
@@ -122,6 +127,12 @@ impl Validate for Metadata {
             results.push(properties.validate_with_context(context));
         }
 
+        if let Some(lifecycles) = &self.lifecycles {
+            let context = context.with_struct("Metadata", "lifecycles");
+
+            results.push(lifecycles.validate_with_context(context));
+        }
+
         results
             .into_iter()
             .fold(ValidationResult::default(), |acc, result| acc.merge(result))
@@ -139,7 +150,11 @@ mod test {
     use crate::{
         external_models::{normalized_string::NormalizedString, spdx::SpdxExpression},
         models::{
-            component::Classification, license::LicenseChoice, property::Property, tool::Tool,
+            component::Classification,
+            license::LicenseChoice,
+            lifecycle::{Lifecycle, Phase},
+            property::Property,
+            tool::Tool,
         },
         validation::FailureReason,
     };
@@ -151,7 +166,7 @@ mod test {
     fn valid_metadata_should_pass_validation() {
         let validation_result = Metadata {
             timestamp: Some(DateTime("1969-06-28T01:20:00.00-04:00".to_string())),
-            tools: Some(Tools(vec![Tool {
+            tools: Some(Tools::List(vec![Tool {
                 vendor: Some(NormalizedString::new("vendor")),
                 name: None,
                 version: None,
@@ -180,23 +195,28 @@ mod test {
                 cpe: None,
                 purl: None,
                 swid: None,
+                omnibor_id: None,
+                swhid: None,
                 modified: None,
                 pedigree: None,
                 external_references: None,
                 properties: None,
                 components: None,
                 evidence: None,
+                data: None,
                 signature: None,
             }),
             manufacture: Some(OrganizationalEntity {
                 name: Some(NormalizedString::new("name")),
                 url: None,
                 contact: None,
+                address: None,
             }),
             supplier: Some(OrganizationalEntity {
                 name: Some(NormalizedString::new("name")),
                 url: None,
                 contact: None,
+                address: None,
             }),
             licenses: Some(Licenses(vec![LicenseChoice::Expression(SpdxExpression(
                 "MIT".to_string(),
@@ -205,6 +225,7 @@ mod test {
                 name: "name".to_string(),
                 value: NormalizedString::new("value"),
             }])),
+            lifecycles: Some(Lifecycles(vec![Lifecycle::Phase(Phase::Build)])),
         }
         .validate();
 
@@ -215,7 +236,7 @@ mod test {
     fn invalid_metadata_should_fail_validation() {
         let validation_result = Metadata {
             timestamp: Some(DateTime("invalid date".to_string())),
-            tools: Some(Tools(vec![Tool {
+            tools: Some(Tools::List(vec![Tool {
                 vendor: Some(NormalizedString("invalid\tvendor".to_string())),
                 name: None,
                 version: None,
@@ -244,23 +265,28 @@ mod test {
                 cpe: None,
                 purl: None,
                 swid: None,
+                omnibor_id: None,
+                swhid: None,
                 modified: None,
                 pedigree: None,
                 external_references: None,
                 properties: None,
                 components: None,
                 evidence: None,
+                data: None,
                 signature: None,
             }),
             manufacture: Some(OrganizationalEntity {
                 name: Some(NormalizedString("invalid\tname".to_string())),
                 url: None,
                 contact: None,
+                address: None,
             }),
             supplier: Some(OrganizationalEntity {
                 name: Some(NormalizedString("invalid\tname".to_string())),
                 url: None,
                 contact: None,
+                address: None,
             }),
             licenses: Some(Licenses(vec![LicenseChoice::Expression(SpdxExpression(
                 "invalid license".to_string(),
@@ -269,6 +295,9 @@ mod test {
                 name: "name".to_string(),
                 value: NormalizedString("invalid\tvalue".to_string()),
             }])),
+            lifecycles: Some(Lifecycles(vec![Lifecycle::Phase(Phase::UnknownPhase(
+                "unknown".to_string(),
+            ))])),
         }
         .validate();
 
@@ -278,7 +307,7 @@ mod test {
                 reasons: vec![
                     FailureReason {
                         message: "DateTime does not conform to ISO 8601".to_string(),
-                        context: ValidationContext(vec![ValidationPathComponent::Struct {
+                        context: ValidationContext::from(vec![ValidationPathComponent::Struct {
                             struct_name: "Metadata".to_string(),
                             field_name: "timestamp".to_string()
                         }])
@@ -287,7 +316,7 @@ mod test {
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Struct {
                                 struct_name: "Metadata".to_string(),
                                 field_name: "tools".to_string()
@@ -303,7 +332,7 @@ mod test {
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Struct {
                                 struct_name: "Metadata".to_string(),
                                 field_name: "authors".to_string()
@@ -317,7 +346,7 @@ mod test {
                     },
                     FailureReason {
                         message: "Unknown classification".to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Struct {
                                 struct_name: "Metadata".to_string(),
                                 field_name: "component".to_string()
@@ -332,7 +361,7 @@ mod test {
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Struct {
                                 struct_name: "Metadata".to_string(),
                                 field_name: "manufacture".to_string()
@@ -347,7 +376,7 @@ mod test {
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Struct {
                                 struct_name: "Metadata".to_string(),
                                 field_name: "supplier".to_string()
@@ -360,7 +389,7 @@ mod test {
                     },
                     FailureReason {
                         message: "SPDX expression is not valid".to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Struct {
                                 struct_name: "Metadata".to_string(),
                                 field_name: "licenses".to_string()
@@ -375,7 +404,7 @@ mod test {
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Struct {
                                 struct_name: "Metadata".to_string(),
                                 field_name: "properties".to_string()
@@ -387,6 +416,20 @@ mod test {
                             }
                         ])
                     },
+                    FailureReason {
+                        message: "Unknown lifecycle phase".to_string(),
+                        context: ValidationContext::from(vec![
+                            ValidationPathComponent::Struct {
+                                struct_name: "Metadata".to_string(),
+                                field_name: "lifecycles".to_string()
+                            },
+                            ValidationPathComponent::Array { index: 0 },
+                            ValidationPathComponent::Struct {
+                                struct_name: "Lifecycle".to_string(),
+                                field_name: "phase".to_string()
+                            }
+                        ])
+                    },
                 ]
             }
         );
@@ -16,16 +16,29 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
+//! The data model of a BOM, independent of the spec version.
+//!
+//! All models implement `Hash`, `PartialOrd` and `Ord`, so they can be deduplicated in a
+//! `HashSet` or `BTreeSet` and sorted stably. The order is derived: structs compare their fields
+//! in the order they are declared, enums their variants in the order they are declared, and
+//! strings compare by their bytes. A [`Component`](component::Component), for example, is ordered
+//! by its type first, then its MIME type, bom-ref, supplier and so on. This order is stable across
+//! runs but not meant for presentation; sort by a key such as the name and version for that.
+
 pub mod advisory;
+pub mod annotation;
 pub mod attached_text;
 pub mod bom;
 pub mod code;
 pub mod component;
+pub mod component_data;
 pub mod composition;
 pub mod dependency;
 pub mod external_reference;
+pub mod formulation;
 pub mod hash;
 pub mod license;
+pub mod lifecycle;
 pub mod metadata;
 pub mod organization;
 pub mod property;
@@ -16,18 +16,24 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
+use once_cell::sync::Lazy;
+use regex::Regex;
+
 use crate::{
-    external_models::{normalized_string::NormalizedString, uri::Uri},
+    errors::BomError,
+    external_models::{email::Email, normalized_string::NormalizedString, uri::Uri},
+    models::bom::SpecVersion,
     validation::{Validate, ValidationContext, ValidationPathComponent, ValidationResult},
 };
 
 /// Represents the contact information for an organization
 ///
 /// Defined via the [CycloneDX XML schema](https://cyclonedx.org/docs/1.3/xml/#type_organizationalContact)
-#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
 pub struct OrganizationalContact {
     pub name: Option<NormalizedString>,
-    pub email: Option<NormalizedString>,
+    pub email: Option<Email>,
     pub phone: Option<NormalizedString>,
 }
 
@@ -41,12 +47,31 @@ impl OrganizationalContact {
     pub fn new(name: &str, email: Option<&str>) -> Self {
         Self {
             name: Some(NormalizedString::new(name)),
-            email: email.map(NormalizedString::new),
+            email: email.map(|email| Email::new_unchecked(email.to_string())),
             phone: None,
         }
     }
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for OrganizationalContact {
+    /// Generates a contact with a well-formed email address and phone number
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        use crate::utilities::arbitrary_token;
+
+        let phone = match u.arbitrary()? {
+            true => Some(format!("+{}", arbitrary_token(u, b"0123456789", 15)?)),
+            false => None,
+        };
+
+        Ok(Self {
+            name: u.arbitrary()?,
+            email: u.arbitrary()?,
+            phone: phone.as_deref().map(NormalizedString::new),
+        })
+    }
+}
+
 impl Validate for OrganizationalContact {
     fn validate_with_context(&self, context: ValidationContext) -> ValidationResult {
         let mut name_result = ValidationResult::default();
@@ -67,21 +92,93 @@ impl Validate for OrganizationalContact {
         if let Some(phone) = &self.phone {
             let phone_context = context.with_struct("OrganizationalContact", "phone");
 
-            phone_result = phone.validate_with_context(phone_context);
+            phone_result = phone.validate_with_context(phone_context.clone());
+            if phone_result == ValidationResult::Passed {
+                phone_result = validate_phone(phone, phone_context);
+            }
         }
 
         name_result.merge(email_result).merge(phone_result)
     }
 }
 
+/// Checks that a phone number consists of digits and the usual separators, with an optional
+/// leading `+` and an optional extension, e.g. `+1 (555) 123-4567 ext. 89`
+fn validate_phone(phone: &NormalizedString, context: ValidationContext) -> ValidationResult {
+    static PHONE_REGEX: Lazy<Regex> = Lazy::new(|| {
+        Regex::new(r"^\+?[0-9 ()./-]*[0-9][0-9 ()./-]*(?:\s*(?:x|ext\.?)\s*[0-9]+)?$")
+            .expect("Failed to compile regex.")
+    });
+
+    if PHONE_REGEX.is_match(&phone.0) {
+        ValidationResult::Passed
+    } else {
+        ValidationResult::failure("Phone number is not valid", context)
+    }
+}
+
 /// Represents an organization with name, url, and contact information
 ///
 /// Defined via the [CycloneDX XML schema](https://cyclonedx.org/docs/1.3/xml/#type_organizationalEntity)
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub struct OrganizationalEntity {
     pub name: Option<NormalizedString>,
     pub url: Option<Vec<Uri>>,
     pub contact: Option<Vec<OrganizationalContact>>,
+    /// Added in version 1.6, writing a BOM with it fails in any version this crate can write
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    pub address: Option<PostalAddress>,
+}
+
+impl OrganizationalEntity {
+    /// Construct an `OrganizationalEntity` with a name
+    /// ```
+    /// use cyclonedx_bom::models::organization::OrganizationalEntity;
+    ///
+    /// let organizational_entity = OrganizationalEntity::new("Example Inc.");
+    /// ```
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: Some(NormalizedString::new(name)),
+            ..Self::default()
+        }
+    }
+
+    pub(crate) fn validate_version_with_context(
+        &self,
+        version: SpecVersion,
+        context: ValidationContext,
+    ) -> ValidationResult {
+        match &self.address {
+            Some(_) => ValidationResult::failure(
+                &format!(
+                    "address was introduced in spec version 1.6 and is not allowed in {}",
+                    version.to_string()
+                ),
+                context.with_struct("OrganizationalEntity", "address"),
+            ),
+            None => ValidationResult::Passed,
+        }
+    }
+
+    /// Fails if the organization has a field that documents of the given spec version cannot
+    /// hold, instead of dropping it when writing the document
+    pub(crate) fn ensure_writable_as(&self, version: SpecVersion) -> Result<(), BomError> {
+        // Added in 1.6, which is newer than any version this crate can write
+        if self.address.is_none() {
+            return Ok(());
+        }
+        let organization = match &self.name {
+            Some(name) => format!("organization {name}"),
+            None => "an organization".to_string(),
+        };
+        Err(BomError::BomSerializationError(
+            version,
+            format!("address of {organization} requires spec version 1.6"),
+        ))
+    }
 }
 
 impl Validate for OrganizationalEntity {
@@ -121,12 +218,56 @@ impl Validate for OrganizationalEntity {
             }
         }
 
+        if let Some(address) = &self.address {
+            let address_context = context.with_struct("OrganizationalEntity", "address");
+
+            results.push(address.validate_with_context(address_context));
+        }
+
         results
             .into_iter()
             .fold(ValidationResult::default(), |acc, result| acc.merge(result))
     }
 }
 
+/// Represents the physical address of an organization
+///
+/// Defined via the [CycloneDX XML schema](https://cyclonedx.org/docs/1.6/xml/#type_postalAddressType).
+/// Added in version 1.6
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct PostalAddress {
+    pub bom_ref: Option<String>,
+    pub country: Option<NormalizedString>,
+    pub region: Option<NormalizedString>,
+    pub locality: Option<NormalizedString>,
+    pub post_office_box_number: Option<NormalizedString>,
+    pub postal_code: Option<NormalizedString>,
+    pub street_address: Option<NormalizedString>,
+}
+
+impl Validate for PostalAddress {
+    fn validate_with_context(&self, context: ValidationContext) -> ValidationResult {
+        [
+            ("country", &self.country),
+            ("region", &self.region),
+            ("locality", &self.locality),
+            ("post_office_box_number", &self.post_office_box_number),
+            ("postal_code", &self.postal_code),
+            ("street_address", &self.street_address),
+        ]
+        .into_iter()
+        .filter_map(|(field_name, value)| {
+            let context = context.with_struct("PostalAddress", field_name);
+            value
+                .as_ref()
+                .map(|value| value.validate_with_context(context))
+        })
+        .fold(ValidationResult::default(), |acc, result| acc.merge(result))
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::validation::{FailureReason, ValidationPathComponent};
@@ -159,7 +300,7 @@ mod test {
                 reasons: vec![FailureReason {
                     message: "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                         .to_string(),
-                    context: ValidationContext(vec![ValidationPathComponent::Struct {
+                    context: ValidationContext::from(vec![ValidationPathComponent::Struct {
                         struct_name: "OrganizationalContact".to_string(),
                         field_name: "name".to_string()
                     }])
@@ -172,9 +313,7 @@ mod test {
     fn it_should_validate_a_contact_with_multiple_validation_issues_as_failed() {
         let contact = OrganizationalContact {
             name: Some(NormalizedString::new_unchecked("invalid\tname".to_string())),
-            email: Some(NormalizedString::new_unchecked(
-                "invalid\temail".to_string(),
-            )),
+            email: Some(Email::new_unchecked("invalid\temail".to_string())),
             phone: Some(NormalizedString::new_unchecked(
                 "invalid\tphone".to_string(),
             )),
@@ -188,16 +327,14 @@ mod test {
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
-                        context: ValidationContext(vec![ValidationPathComponent::Struct {
+                        context: ValidationContext::from(vec![ValidationPathComponent::Struct {
                             struct_name: "OrganizationalContact".to_string(),
                             field_name: "name".to_string()
                         }])
                     },
                     FailureReason {
-                        message:
-                            "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
-                                .to_string(),
-                        context: ValidationContext(vec![ValidationPathComponent::Struct {
+                        message: "Email does not conform to RFC 5321: missing @".to_string(),
+                        context: ValidationContext::from(vec![ValidationPathComponent::Struct {
                             struct_name: "OrganizationalContact".to_string(),
                             field_name: "email".to_string()
                         }])
@@ -206,7 +343,7 @@ mod test {
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
-                        context: ValidationContext(vec![ValidationPathComponent::Struct {
+                        context: ValidationContext::from(vec![ValidationPathComponent::Struct {
                             struct_name: "OrganizationalContact".to_string(),
                             field_name: "phone".to_string()
                         }])
@@ -216,12 +353,115 @@ mod test {
         )
     }
 
+    #[test]
+    fn it_should_validate_well_formed_emails_and_phone_numbers_as_passed() {
+        for (email, phone) in [
+            ("support@example.com", "+1 (555) 123-4567"),
+            ("first.last+tag@sub.example.org", "555.123.4567 ext. 89"),
+            ("user@localhost", "030/1234567 x12"),
+        ] {
+            let contact = OrganizationalContact {
+                name: None,
+                email: Some(Email::new_unchecked(email.to_string())),
+                phone: Some(NormalizedString::new(phone)),
+            };
+            assert_eq!(
+                contact.validate(),
+                ValidationResult::Passed,
+                "{email} {phone}"
+            );
+        }
+    }
+
+    #[test]
+    fn it_should_validate_malformed_emails_and_phone_numbers_as_failed() {
+        for (email, phone, reason) in [
+            ("support", "call us", "missing @"),
+            ("support@example..com", "+", "invalid domain"),
+            (
+                "Support <support@example.com>",
+                "555-1234 ext.",
+                "invalid local part",
+            ),
+        ] {
+            let contact = OrganizationalContact {
+                name: None,
+                email: Some(Email::new_unchecked(email.to_string())),
+                phone: Some(NormalizedString::new(phone)),
+            };
+            assert_eq!(
+                contact.validate(),
+                ValidationResult::Failed {
+                    reasons: vec![
+                        FailureReason::new(
+                            &format!("Email does not conform to RFC 5321: {}", reason),
+                            ValidationContext::new().with_struct("OrganizationalContact", "email")
+                        ),
+                        FailureReason::new(
+                            "Phone number is not valid",
+                            ValidationContext::new().with_struct("OrganizationalContact", "phone")
+                        ),
+                    ]
+                },
+                "{email} {phone}"
+            );
+        }
+    }
+
+    #[test]
+    fn it_should_validate_an_invalid_address_as_failed() {
+        let entity = OrganizationalEntity {
+            name: None,
+            url: None,
+            contact: None,
+            address: Some(PostalAddress {
+                country: Some(NormalizedString::new("DE")),
+                street_address: Some(NormalizedString::new_unchecked(
+                    "invalid\tstreet".to_string(),
+                )),
+                ..PostalAddress::default()
+            }),
+        };
+        assert_eq!(
+            entity.validate(),
+            ValidationResult::failure(
+                "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n",
+                ValidationContext::new()
+                    .with_struct("OrganizationalEntity", "address")
+                    .with_struct("PostalAddress", "street_address")
+            )
+        );
+    }
+
+    #[test]
+    fn it_should_not_allow_an_address_in_the_supported_spec_versions() {
+        let mut entity = OrganizationalEntity::new("Acme");
+        entity.address = Some(PostalAddress {
+            country: Some(NormalizedString::new("DE")),
+            ..PostalAddress::default()
+        });
+
+        assert_eq!(
+            entity.validate_version_with_context(SpecVersion::V1_5, ValidationContext::new()),
+            ValidationResult::failure(
+                "address was introduced in spec version 1.6 and is not allowed in 1.5",
+                ValidationContext::new().with_struct("OrganizationalEntity", "address")
+            )
+        );
+        assert!(matches!(
+            entity.ensure_writable_as(SpecVersion::V1_5),
+            Err(BomError::BomSerializationError(SpecVersion::V1_5, message))
+                if message == "address of organization Acme requires spec version 1.6"
+        ));
+    }
+
     #[test]
     fn it_should_validate_an_invalid_entity_as_failed() {
         let entity = OrganizationalEntity {
             name: Some(NormalizedString::new_unchecked("invalid\tname".to_string())),
             url: None,
             contact: None,
+            address: None,
         };
         let actual = entity.validate();
         assert_eq!(
@@ -230,7 +470,7 @@ mod test {
                 reasons: vec![FailureReason {
                     message: "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                         .to_string(),
-                    context: ValidationContext(vec![ValidationPathComponent::Struct {
+                    context: ValidationContext::from(vec![ValidationPathComponent::Struct {
                         struct_name: "OrganizationalEntity".to_string(),
                         field_name: "name".to_string()
                     }])
@@ -249,6 +489,7 @@ mod test {
                 email: None,
                 phone: None,
             }]),
+            address: None,
         };
         let actual = entity.validate();
         assert_eq!(
@@ -259,14 +500,14 @@ mod test {
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
-                        context: ValidationContext(vec![ValidationPathComponent::Struct {
+                        context: ValidationContext::from(vec![ValidationPathComponent::Struct {
                             struct_name: "OrganizationalEntity".to_string(),
                             field_name: "name".to_string()
                         }])
                     },
                     FailureReason {
                         message: "Uri does not conform to RFC 3986".to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Struct {
                                 struct_name: "OrganizationalEntity".to_string(),
                                 field_name: "url".to_string()
@@ -278,7 +519,7 @@ mod test {
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Struct {
                                 struct_name: "OrganizationalEntity".to_string(),
                                 field_name: "contact".to_string()
@@ -26,7 +26,8 @@ use crate::{
 ///
 /// Defined via the [XML schema](https://cyclonedx.org/docs/1.3/xml/#type_propertyType). Please see the
 /// [CycloneDX use case](https://cyclonedx.org/use-cases/#properties--name-value-store) for more information and examples.
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Properties(pub Vec<Property>);
 
 impl Validate for Properties {
@@ -48,7 +49,9 @@ impl Validate for Properties {
 /// Represents an individual property with a name and value
 ///
 /// Defined via the [XML schema](https://cyclonedx.org/docs/1.3/xml/#type_propertyType)
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub struct Property {
     pub name: String,
     pub value: NormalizedString,
@@ -114,7 +117,7 @@ mod test {
                 reasons: vec![FailureReason {
                     message: "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                         .to_string(),
-                    context: ValidationContext(vec![
+                    context: ValidationContext::from(vec![
                         ValidationPathComponent::Array { index: 0 },
                         ValidationPathComponent::Struct {
                             struct_name: "Property".to_string(),
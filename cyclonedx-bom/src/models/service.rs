@@ -16,13 +16,16 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
+use crate::errors::BomError;
 use crate::external_models::{normalized_string::NormalizedString, uri::Uri};
+use crate::models::bom::SpecVersion;
 use crate::models::external_reference::ExternalReferences;
 use crate::models::license::Licenses;
 use crate::models::organization::OrganizationalEntity;
 use crate::models::property::Properties;
 use crate::validation::{
-    FailureReason, Validate, ValidationContext, ValidationPathComponent, ValidationResult,
+    validate_list, FailureReason, Validate, ValidationContext, ValidationPathComponent,
+    ValidationResult,
 };
 
 use super::signature::Signature;
@@ -30,7 +33,9 @@ use super::signature::Signature;
 /// Represents a service as described in the [CycloneDX use cases](https://cyclonedx.org/use-cases/#service-definition)
 ///
 /// Defined via the [XML schema](https://cyclonedx.org/docs/1.3/xml/#type_service)
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub struct Service {
     pub bom_ref: Option<String>,
     pub provider: Option<OrganizationalEntity>,
@@ -76,6 +81,18 @@ impl Service {
             signature: None,
         }
     }
+
+    /// Fails if the service or one of its nested services has a field that documents of the
+    /// given spec version cannot hold, instead of dropping it when writing the document
+    pub(crate) fn ensure_writable_as(&self, version: SpecVersion) -> Result<(), BomError> {
+        if let Some(provider) = &self.provider {
+            provider.ensure_writable_as(version)?;
+        }
+        self.services
+            .iter()
+            .flat_map(|services| &services.0)
+            .try_for_each(|service| service.ensure_writable_as(version))
+    }
 }
 
 impl Validate for Service {
@@ -166,33 +183,53 @@ impl Validate for Service {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Services(pub Vec<Service>);
 
-impl Validate for Services {
-    fn validate_with_context(&self, context: ValidationContext) -> ValidationResult {
-        let mut results: Vec<ValidationResult> = vec![];
+impl Services {
+    /// Calls `f` with every service, including nested ones, parents before their children
+    pub fn map_services(&mut self, mut f: impl FnMut(&mut Service)) {
+        self.map_services_with(&mut f);
+    }
 
-        for (index, service) in self.0.iter().enumerate() {
-            let context = context.extend_context(vec![ValidationPathComponent::Array { index }]);
-            results.push(service.validate_with_context(context));
+    fn map_services_with(&mut self, f: &mut impl FnMut(&mut Service)) {
+        for service in &mut self.0 {
+            f(service);
+            if let Some(services) = &mut service.services {
+                services.map_services_with(f);
+            }
         }
+    }
+}
 
-        results
-            .into_iter()
-            .fold(ValidationResult::default(), |acc, result| acc.merge(result))
+impl Validate for Services {
+    fn validate_with_context(&self, context: ValidationContext) -> ValidationResult {
+        validate_list(&self.0, &context)
     }
 }
 
 /// Represents the data classification and data flow
 ///
 /// Defined via the [XML schema](https://cyclonedx.org/docs/1.3/xml/#type_dataClassificationType)
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub struct DataClassification {
     pub flow: DataFlowType,
     pub classification: NormalizedString,
 }
 
+impl DataClassification {
+    /// Constructs a `DataClassification` of a data flow
+    pub fn new(flow: DataFlowType, classification: &str) -> Self {
+        Self {
+            flow,
+            classification: NormalizedString::new(classification),
+        }
+    }
+}
+
 impl Validate for DataClassification {
     fn validate_with_context(&self, context: ValidationContext) -> ValidationResult {
         let mut results: Vec<ValidationResult> = vec![];
@@ -217,13 +254,15 @@ impl Validate for DataClassification {
 /// Represents the flow direction of the data
 ///
 /// Defined via the [XML schema](https://cyclonedx.org/docs/1.3/xml/#type_dataFlowType)
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum DataFlowType {
     Inbound,
     Outbound,
     BiDirectional,
     Unknown,
     #[doc(hidden)]
+    #[cfg_attr(feature = "arbitrary", arbitrary(skip))]
     UnknownDataFlow(String),
 }
 
@@ -289,6 +328,7 @@ mod test {
                 name: Some(NormalizedString::new("name")),
                 url: None,
                 contact: None,
+                address: None,
             }),
             group: Some(NormalizedString::new("group")),
             name: NormalizedString::new("name"),
@@ -330,6 +370,7 @@ mod test {
                 name: Some(NormalizedString("invalid\tname".to_string())),
                 url: None,
                 contact: None,
+                address: None,
             }),
             group: Some(NormalizedString("invalid\tgroup".to_string())),
             name: NormalizedString("invalid\tname".to_string()),
@@ -386,7 +427,7 @@ mod test {
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Service".to_string(),
@@ -402,7 +443,7 @@ mod test {
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Service".to_string(),
@@ -414,7 +455,7 @@ mod test {
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Service".to_string(),
@@ -426,7 +467,7 @@ mod test {
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Service".to_string(),
@@ -438,7 +479,7 @@ mod test {
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Service".to_string(),
@@ -448,7 +489,7 @@ mod test {
                     },
                     FailureReason {
                         message: "Uri does not conform to RFC 3986".to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Service".to_string(),
@@ -459,7 +500,7 @@ mod test {
                     },
                     FailureReason {
                         message: "Unknown data flow type".to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Service".to_string(),
@@ -476,7 +517,7 @@ mod test {
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Service".to_string(),
@@ -491,7 +532,7 @@ mod test {
                     },
                     FailureReason {
                         message: "SPDX expression is not valid".to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Service".to_string(),
@@ -505,7 +546,7 @@ mod test {
                     },
                     FailureReason {
                         message: "Unknown external reference type".to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Service".to_string(),
@@ -522,7 +563,7 @@ mod test {
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Service".to_string(),
@@ -539,7 +580,7 @@ mod test {
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Service".to_string(),
@@ -17,13 +17,17 @@
  */
 
 use crate::external_models::normalized_string::NormalizedString;
+use crate::models::component::{Classification, Component, Components};
 use crate::models::hash::Hashes;
+use crate::models::service::{Service, Services};
 use crate::validation::{Validate, ValidationContext, ValidationPathComponent, ValidationResult};
 
 /// Represents the tool used to create the BOM
 ///
 /// Defined via the [CycloneDX XML schema](https://cyclonedx.org/docs/1.3/xml/#type_toolType)
-#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub struct Tool {
     pub vendor: Option<NormalizedString>,
     pub name: Option<NormalizedString>,
@@ -82,17 +86,131 @@ impl Validate for Tool {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
-pub struct Tools(pub Vec<Tool>);
+/// Converts a tool component of the 1.5 representation to the legacy representation,
+/// taking the vendor from the group of the component
+impl From<Component> for Tool {
+    fn from(component: Component) -> Self {
+        Self {
+            vendor: component.group,
+            name: Some(component.name).filter(|name| !name.0.is_empty()),
+            version: component.version,
+            hashes: component.hashes,
+        }
+    }
+}
+
+/// Converts a tool service of the 1.5 representation to the legacy representation,
+/// taking the vendor from the group of the service
+impl From<Service> for Tool {
+    fn from(service: Service) -> Self {
+        Self {
+            vendor: service.group,
+            name: Some(service.name).filter(|name| !name.0.is_empty()),
+            version: service.version,
+            hashes: None,
+        }
+    }
+}
+
+/// Converts a legacy tool to an application component of the 1.5 representation
+impl From<Tool> for Component {
+    fn from(tool: Tool) -> Self {
+        let mut component = Component::new(Classification::Application, "", "", None);
+        component.group = tool.vendor;
+        component.name = tool.name.unwrap_or_else(|| NormalizedString::new(""));
+        component.version = tool.version;
+        component.hashes = tool.hashes;
+        component
+    }
+}
+
+/// The tools used to create the BOM or to identify a vulnerability
+///
+/// Defined via the [CycloneDX XML schema](https://cyclonedx.org/docs/1.5/xml/#type_metadata_tools)
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum Tools {
+    /// The flat list of tools, deprecated in version 1.5
+    List(Vec<Tool>),
+    /// Tools described as components and services. Added in version 1.5
+    Object {
+        components: Option<Components>,
+        services: Option<Services>,
+    },
+}
+
+impl Tools {
+    /// Returns the tools as the flat list of versions before 1.5
+    ///
+    /// Components and services keep their group, name, version and hashes, so converting a
+    /// list to components and back is lossless.
+    /// ```
+    /// use cyclonedx_bom::models::tool::{Tool, Tools};
+    ///
+    /// let tools = vec![Tool::new("CycloneDX", "cargo-cyclonedx", "1.0.0")];
+    /// assert_eq!(Tools::List(tools.clone()).into_components().into_list(), tools);
+    /// ```
+    pub fn into_list(self) -> Vec<Tool> {
+        match self {
+            Tools::List(tools) => tools,
+            Tools::Object {
+                components,
+                services,
+            } => components
+                .into_iter()
+                .flat_map(|components| components.0)
+                .map(Tool::from)
+                .chain(
+                    services
+                        .into_iter()
+                        .flat_map(|services| services.0)
+                        .map(Tool::from),
+                )
+                .collect(),
+        }
+    }
+
+    /// Returns the tools in the representation of version 1.5, describing each tool of a
+    /// flat list as an application component
+    pub fn into_components(self) -> Self {
+        match self {
+            Tools::List(tools) => Tools::Object {
+                components: Some(Components(tools.into_iter().map(Component::from).collect())),
+                services: None,
+            },
+            object => object,
+        }
+    }
+}
 
 impl Validate for Tools {
     fn validate_with_context(&self, context: ValidationContext) -> ValidationResult {
         let mut results: Vec<ValidationResult> = vec![];
 
-        for (index, tool) in self.0.iter().enumerate() {
-            let tool_context =
-                context.extend_context(vec![ValidationPathComponent::Array { index }]);
-            results.push(tool.validate_with_context(tool_context));
+        match self {
+            Tools::List(tools) => {
+                for (index, tool) in tools.iter().enumerate() {
+                    let tool_context =
+                        context.extend_context(vec![ValidationPathComponent::Array { index }]);
+                    results.push(tool.validate_with_context(tool_context));
+                }
+            }
+            Tools::Object {
+                components,
+                services,
+            } => {
+                if let Some(components) = components {
+                    let context = context.with_struct("Tools", "components");
+
+                    results.push(components.validate_with_context(context));
+                }
+
+                if let Some(services) = services {
+                    let context = context.with_struct("Tools", "services");
+
+                    results.push(services.validate_with_context(context));
+                }
+            }
         }
 
         results
@@ -110,7 +228,7 @@ mod test {
 
     #[test]
     fn it_should_pass_validation() {
-        let validation_result = Tools(vec![Tool {
+        let validation_result = Tools::List(vec![Tool {
             vendor: Some(NormalizedString("no_whitespace".to_string())),
             name: None,
             version: None,
@@ -123,7 +241,7 @@ mod test {
 
     #[test]
     fn it_should_fail_validation() {
-        let validation_result = Tools(vec![Tool {
+        let validation_result = Tools::List(vec![Tool {
             vendor: Some(NormalizedString("spaces and\ttabs".to_string())),
             name: None,
             version: None,
@@ -137,7 +255,7 @@ mod test {
                 reasons: vec![FailureReason {
                     message: "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                         .to_string(),
-                    context: ValidationContext(vec![
+                    context: ValidationContext::from(vec![
                         ValidationPathComponent::Array { index: 0 },
                         ValidationPathComponent::Struct {
                             struct_name: "Tool".to_string(),
@@ -151,7 +269,7 @@ mod test {
 
     #[test]
     fn it_should_merge_validations_correctly() {
-        let validation_result = Tools(vec![
+        let validation_result = Tools::List(vec![
             Tool {
                 vendor: Some(NormalizedString("no_whitespace".to_string())),
                 name: None,
@@ -181,7 +299,7 @@ mod test {
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 1 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Tool".to_string(),
@@ -193,7 +311,7 @@ mod test {
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 2 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Tool".to_string(),
@@ -205,4 +323,96 @@ mod test {
             }
         );
     }
+
+    #[test]
+    fn it_should_convert_between_the_list_and_components() {
+        let tools = vec![
+            Tool {
+                vendor: Some(NormalizedString::new("CycloneDX")),
+                name: Some(NormalizedString::new("cargo-cyclonedx")),
+                version: Some(NormalizedString::new("1.0.0")),
+                hashes: Some(Hashes(vec![crate::models::hash::Hash {
+                    alg: crate::models::hash::HashAlgorithm::SHA256,
+                    content: crate::models::hash::HashValue("a".repeat(64)),
+                }])),
+            },
+            Tool {
+                vendor: None,
+                name: None,
+                version: None,
+                hashes: None,
+            },
+        ];
+
+        let object = Tools::List(tools.clone()).into_components();
+        let Tools::Object {
+            components: Some(components),
+            services: None,
+        } = &object
+        else {
+            panic!("expected components, got {object:?}");
+        };
+        assert_eq!(components.0[0].component_type, Classification::Application);
+        assert_eq!(
+            components.0[0].group,
+            Some(NormalizedString::new("CycloneDX"))
+        );
+
+        assert_eq!(object.into_list(), tools);
+    }
+
+    #[test]
+    fn it_should_list_tool_services_after_tool_components() {
+        let mut service = Service::new("scanner", None);
+        service.group = Some(NormalizedString::new("vendor"));
+        let tools = Tools::Object {
+            components: Some(Components(vec![Component::new(
+                Classification::Application,
+                "generator",
+                "1.0.0",
+                None,
+            )])),
+            services: Some(Services(vec![service])),
+        };
+
+        assert_eq!(
+            tools.into_list(),
+            vec![
+                Tool {
+                    vendor: None,
+                    name: Some(NormalizedString::new("generator")),
+                    version: Some(NormalizedString::new("1.0.0")),
+                    hashes: None,
+                },
+                Tool {
+                    vendor: Some(NormalizedString::new("vendor")),
+                    name: Some(NormalizedString::new("scanner")),
+                    version: None,
+                    hashes: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_validate_tool_components_and_services() {
+        let mut service = Service::new("service", None);
+        service.name = NormalizedString("spaces and\ttabs".to_string());
+        let validation_result = Tools::Object {
+            components: None,
+            services: Some(Services(vec![service])),
+        }
+        .validate();
+
+        assert_eq!(
+            validation_result,
+            ValidationResult::failure(
+                "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n",
+                ValidationContext::new()
+                    .with_struct("Tools", "services")
+                    .with_index(0)
+                    .with_struct("Service", "name")
+            )
+        );
+    }
 }
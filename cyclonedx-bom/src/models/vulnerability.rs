@@ -18,6 +18,7 @@
 
 use crate::external_models::{date_time::DateTime, normalized_string::NormalizedString};
 use crate::models::advisory::Advisories;
+use crate::models::attached_text::AttachedText;
 use crate::models::property::Properties;
 use crate::models::tool::Tools;
 use crate::models::vulnerability_analysis::VulnerabilityAnalysis;
@@ -26,12 +27,14 @@ use crate::models::vulnerability_rating::VulnerabilityRatings;
 use crate::models::vulnerability_reference::VulnerabilityReferences;
 use crate::models::vulnerability_source::VulnerabilitySource;
 use crate::models::vulnerability_target::VulnerabilityTargets;
-use crate::validation::{Validate, ValidationContext, ValidationPathComponent, ValidationResult};
+use crate::validation::{validate_list, Validate, ValidationContext, ValidationResult};
 
 /// Represents a vulnerability as described in the [CycloneDX use cases](https://cyclonedx.org/use-cases/#vulnerability-exploitability)
 ///
 /// Defined via the [XML schema](https://cyclonedx.org/docs/1.4/xml/#type_vulnerabilitiesType)
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub struct Vulnerability {
     pub bom_ref: Option<String>,
     pub id: Option<NormalizedString>,
@@ -42,10 +45,16 @@ pub struct Vulnerability {
     pub description: Option<String>,
     pub detail: Option<String>,
     pub recommendation: Option<String>,
+    /// Added in version 1.5
+    pub workaround: Option<String>,
+    /// Added in version 1.5
+    pub proof_of_concept: Option<ProofOfConcept>,
     pub advisories: Option<Advisories>,
     pub created: Option<DateTime>,
     pub published: Option<DateTime>,
     pub updated: Option<DateTime>,
+    /// Added in version 1.5
+    pub rejected: Option<DateTime>,
     pub vulnerability_credits: Option<VulnerabilityCredits>,
     pub tools: Option<Tools>,
     pub vulnerability_analysis: Option<VulnerabilityAnalysis>,
@@ -71,10 +80,13 @@ impl Vulnerability {
             description: None,
             detail: None,
             recommendation: None,
+            workaround: None,
+            proof_of_concept: None,
             advisories: None,
             created: None,
             published: None,
             updated: None,
+            rejected: None,
             vulnerability_credits: None,
             tools: None,
             vulnerability_analysis: None,
@@ -112,6 +124,12 @@ impl Validate for Vulnerability {
             results.push(vulnerability_ratings.validate_with_context(context));
         }
 
+        if let Some(proof_of_concept) = &self.proof_of_concept {
+            let context = context.with_struct("Vulnerability", "proof_of_concept");
+
+            results.push(proof_of_concept.validate_with_context(context));
+        }
+
         if let Some(advisories) = &self.advisories {
             let context = context.with_struct("Vulnerability", "advisories");
 
@@ -136,6 +154,12 @@ impl Validate for Vulnerability {
             results.push(updated.validate_with_context(context));
         }
 
+        if let Some(rejected) = &self.rejected {
+            let context = context.with_struct("Vulnerability", "rejected");
+
+            results.push(rejected.validate_with_context(context));
+        }
+
         if let Some(vulnerability_credits) = &self.vulnerability_credits {
             let context = context.with_struct("Vulnerability", "vulnerability_credits");
 
@@ -172,16 +196,30 @@ impl Validate for Vulnerability {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
-pub struct Vulnerabilities(pub Vec<Vulnerability>);
+/// Evidence that the vulnerability can be exploited, and how to reproduce it
+///
+/// Added in version 1.5
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct ProofOfConcept {
+    pub reproduction_steps: Option<String>,
+    /// Description of the environment in which the reproduction steps were performed
+    pub environment: Option<String>,
+    /// Screenshots, payloads or other material that demonstrates the exploit
+    pub supporting_material: Option<Vec<AttachedText>>,
+}
 
-impl Validate for Vulnerabilities {
+impl Validate for ProofOfConcept {
     fn validate_with_context(&self, context: ValidationContext) -> ValidationResult {
         let mut results: Vec<ValidationResult> = vec![];
 
-        for (index, vulnerability) in self.0.iter().enumerate() {
-            let context = context.extend_context(vec![ValidationPathComponent::Array { index }]);
-            results.push(vulnerability.validate_with_context(context));
+        for (index, material) in self.supporting_material.iter().flatten().enumerate() {
+            let context = context
+                .with_struct("ProofOfConcept", "supporting_material")
+                .with_index(index);
+
+            results.push(material.validate_with_context(context));
         }
 
         results
@@ -190,11 +228,21 @@ impl Validate for Vulnerabilities {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct Vulnerabilities(pub Vec<Vulnerability>);
+
+impl Validate for Vulnerabilities {
+    fn validate_with_context(&self, context: ValidationContext) -> ValidationResult {
+        validate_list(&self.0, &context)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
     use crate::{
-        external_models::uri::Uri,
+        external_models::{mime_type::MimeType, uri::Uri},
         models::{
             advisory::Advisory,
             organization::OrganizationalEntity,
@@ -206,7 +254,7 @@ mod test {
             vulnerability_reference::VulnerabilityReference,
             vulnerability_target::{Status, Version, VersionRange, Versions, VulnerabilityTarget},
         },
-        validation::FailureReason,
+        validation::{FailureReason, ValidationPathComponent},
     };
 
     #[test]
@@ -233,13 +281,17 @@ mod test {
                 score: Some(10.0.into()),
                 severity: Some(Severity::Critical),
                 score_method: Some(ScoreMethod::CVSSv3),
-                vector: Some(NormalizedString::new("vector")),
+                vector: Some(NormalizedString::new(
+                    "CVSS:3.0/AV:N/AC:L/PR:N/UI:N/S:C/C:H/I:H/A:H",
+                )),
                 justification: Some("justification".to_string()),
             }])),
             cwes: Some(vec![1, 2, 3]),
             description: Some("description".to_string()),
             detail: Some("detail".to_string()),
             recommendation: Some("recommendation".to_string()),
+            workaround: None,
+            proof_of_concept: None,
             advisories: Some(Advisories(vec![Advisory {
                 title: Some(NormalizedString::new("title")),
                 url: Uri("https://example.com".to_string()),
@@ -247,11 +299,13 @@ mod test {
             created: Some(DateTime("1969-06-28T01:20:00.00-04:00".to_string())),
             published: Some(DateTime("1969-06-28T01:20:00.00-04:00".to_string())),
             updated: Some(DateTime("1969-06-28T01:20:00.00-04:00".to_string())),
+            rejected: None,
             vulnerability_credits: Some(VulnerabilityCredits {
                 organizations: Some(vec![OrganizationalEntity {
                     name: Some(NormalizedString::new("name")),
                     url: None,
                     contact: None,
+                    address: None,
                 }]),
                 individuals: None,
             }),
@@ -261,6 +315,8 @@ mod test {
                 justification: Some(ImpactAnalysisJustification::CodeNotReachable),
                 responses: Some(vec![ImpactAnalysisResponse::Update]),
                 detail: Some("detail".to_string()),
+                first_issued: None,
+                last_updated: None,
             }),
             vulnerability_targets: Some(VulnerabilityTargets(vec![VulnerabilityTarget {
                 bom_ref: "bom ref".to_string(),
@@ -302,7 +358,7 @@ mod test {
                 }),
                 score: Score::from_f32(10.0),
                 severity: Some(Severity::UndefinedSeverity("undefined".to_string())),
-                score_method: Some(ScoreMethod::Other("other method".to_string())),
+                score_method: Some(ScoreMethod::UnknownScoreMethod("other method".to_string())),
                 vector: Some(NormalizedString("invalid\tvector".to_string())),
                 justification: Some("justification".to_string()),
             }])),
@@ -310,6 +366,8 @@ mod test {
             description: Some("description".to_string()),
             detail: Some("detail".to_string()),
             recommendation: Some("recommendation".to_string()),
+            workaround: None,
+            proof_of_concept: None,
             advisories: Some(Advisories(vec![Advisory {
                 title: Some(NormalizedString("invalid\ttitle".to_string())),
                 url: Uri("invalid url".to_string()),
@@ -317,6 +375,7 @@ mod test {
             created: Some(DateTime("Thursday".to_string())),
             published: Some(DateTime("1970-01-01".to_string())),
             updated: Some(DateTime("invalid date".to_string())),
+            rejected: None,
             vulnerability_credits: None,
             tools: None,
             vulnerability_analysis: Some(VulnerabilityAnalysis {
@@ -332,6 +391,8 @@ mod test {
                     "undefined".to_string(),
                 )]),
                 detail: Some("detail".to_string()),
+                first_issued: None,
+                last_updated: None,
             }),
             vulnerability_targets: None,
             properties: Some(Properties(vec![Property {
@@ -349,7 +410,7 @@ mod test {
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Vulnerability".to_string(),
@@ -361,7 +422,7 @@ mod test {
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Vulnerability".to_string(),
@@ -375,7 +436,7 @@ mod test {
                     },
                     FailureReason {
                         message: "Uri does not conform to RFC 3986".to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Vulnerability".to_string(),
@@ -391,7 +452,7 @@ mod test {
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Vulnerability".to_string(),
@@ -406,7 +467,7 @@ mod test {
                     },
                     FailureReason {
                         message: "Undefined severity".to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Vulnerability".to_string(),
@@ -419,11 +480,26 @@ mod test {
                             }
                         ])
                     },
+                    FailureReason {
+                        message: "Unknown score method".to_string(),
+                        context: ValidationContext::from(vec![
+                            ValidationPathComponent::Array { index: 0 },
+                            ValidationPathComponent::Struct {
+                                struct_name: "Vulnerability".to_string(),
+                                field_name: "vulnerability_ratings".to_string()
+                            },
+                            ValidationPathComponent::Array { index: 0 },
+                            ValidationPathComponent::Struct {
+                                struct_name: "VulnerabilityRating".to_string(),
+                                field_name: "score_method".to_string()
+                            }
+                        ])
+                    },
                     FailureReason {
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Vulnerability".to_string(),
@@ -440,7 +516,7 @@ mod test {
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Vulnerability".to_string(),
@@ -455,7 +531,7 @@ mod test {
                     },
                     FailureReason {
                         message: "Uri does not conform to RFC 3986".to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Vulnerability".to_string(),
@@ -470,7 +546,7 @@ mod test {
                     },
                     FailureReason {
                         message: "DateTime does not conform to ISO 8601".to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Vulnerability".to_string(),
@@ -480,7 +556,7 @@ mod test {
                     },
                     FailureReason {
                         message: "DateTime does not conform to ISO 8601".to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Vulnerability".to_string(),
@@ -490,7 +566,7 @@ mod test {
                     },
                     FailureReason {
                         message: "DateTime does not conform to ISO 8601".to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Vulnerability".to_string(),
@@ -500,7 +576,7 @@ mod test {
                     },
                     FailureReason {
                         message: "Undefined impact analysis state".to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Vulnerability".to_string(),
@@ -514,7 +590,7 @@ mod test {
                     },
                     FailureReason {
                         message: "Undefined impact analysis justification".to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Vulnerability".to_string(),
@@ -528,7 +604,7 @@ mod test {
                     },
                     FailureReason {
                         message: "Undefined response".to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Vulnerability".to_string(),
@@ -545,7 +621,7 @@ mod test {
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Vulnerability".to_string(),
@@ -562,4 +638,39 @@ mod test {
             }
         );
     }
+
+    #[test]
+    fn it_should_validate_the_proof_of_concept_and_rejection() {
+        let mut vulnerability = Vulnerability::new(None);
+        vulnerability.workaround = Some("Disable the HTTP/1 server".to_string());
+        vulnerability.proof_of_concept = Some(ProofOfConcept {
+            reproduction_steps: Some("Send a request with a malformed header".to_string()),
+            environment: None,
+            supporting_material: Some(vec![
+                AttachedText::new(None, "payload"),
+                AttachedText::plain(Some(MimeType("invalid".to_string())), "payload"),
+            ]),
+        });
+        vulnerability.rejected = Some(DateTime("yesterday".to_string()));
+
+        assert_eq!(
+            vulnerability.validate(),
+            ValidationResult::Failed {
+                reasons: vec![
+                    FailureReason::new(
+                        "MimeType does not conform to RFC 6838: missing subtype",
+                        ValidationContext::new()
+                            .with_struct("Vulnerability", "proof_of_concept")
+                            .with_struct("ProofOfConcept", "supporting_material")
+                            .with_index(1)
+                            .with_struct("AttachedText", "content_type")
+                    ),
+                    FailureReason::new(
+                        "DateTime does not conform to ISO 8601",
+                        ValidationContext::new().with_struct("Vulnerability", "rejected")
+                    ),
+                ]
+            }
+        );
+    }
 }
@@ -16,6 +16,7 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
+use crate::external_models::date_time::DateTime;
 use crate::validation::{
     FailureReason, Validate, ValidationContext, ValidationPathComponent, ValidationResult,
 };
@@ -23,12 +24,18 @@ use crate::validation::{
 /// Represents a vulnerability's analysis as described in the [CycloneDX use cases](https://cyclonedx.org/use-cases/#vulnerability-exploitability)
 ///
 /// Defined via the [XML schema](https://cyclonedx.org/docs/1.4/xml/#type_vulnerabilityType)
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub struct VulnerabilityAnalysis {
     pub state: Option<ImpactAnalysisState>,
     pub justification: Option<ImpactAnalysisJustification>,
     pub responses: Option<Vec<ImpactAnalysisResponse>>,
     pub detail: Option<String>,
+    /// Added in version 1.5
+    pub first_issued: Option<DateTime>,
+    /// Added in version 1.5
+    pub last_updated: Option<DateTime>,
 }
 
 impl VulnerabilityAnalysis {
@@ -52,6 +59,8 @@ impl VulnerabilityAnalysis {
             justification,
             responses,
             detail: None,
+            first_issued: None,
+            last_updated: None,
         }
     }
 }
@@ -85,6 +94,18 @@ impl Validate for VulnerabilityAnalysis {
             }
         }
 
+        if let Some(first_issued) = &self.first_issued {
+            let context = context.with_struct("VulnerabilityAnalysis", "first_issued");
+
+            results.push(first_issued.validate_with_context(context));
+        }
+
+        if let Some(last_updated) = &self.last_updated {
+            let context = context.with_struct("VulnerabilityAnalysis", "last_updated");
+
+            results.push(last_updated.validate_with_context(context));
+        }
+
         results
             .into_iter()
             .fold(ValidationResult::default(), |acc, result| acc.merge(result))
@@ -94,7 +115,8 @@ impl Validate for VulnerabilityAnalysis {
 /// Specifies a vulnerability's state according to impact analysis.
 ///
 /// Defined via the [XML schema](https://cyclonedx.org/docs/1.4/xml/#type_impactAnalysisStateType)
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum ImpactAnalysisState {
     Resolved,
     ResolvedWithPedigree,
@@ -103,6 +125,7 @@ pub enum ImpactAnalysisState {
     FalsePositive,
     NotAffected,
     #[doc(hidden)]
+    #[cfg_attr(feature = "arbitrary", arbitrary(skip))]
     UndefinedImpactAnalysisState(String),
 }
 
@@ -152,7 +175,8 @@ impl ToString for ImpactAnalysisState {
 /// Justifies the vulnerability's state according to impact analysis.
 ///
 /// Defined via the [XML schema](https://cyclonedx.org/docs/1.4/xml/#type_impactAnalysisJustificationType)
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum ImpactAnalysisJustification {
     CodeNotPresent,
     CodeNotReachable,
@@ -164,6 +188,7 @@ pub enum ImpactAnalysisJustification {
     ProtectedAtPerimeter,
     ProtectedByMitigatingControl,
     #[doc(hidden)]
+    #[cfg_attr(feature = "arbitrary", arbitrary(skip))]
     UndefinedImpactAnalysisJustification(String),
 }
 
@@ -225,7 +250,8 @@ impl ToString for ImpactAnalysisJustification {
 /// Provides a response to the vulnerability according to impact analysis.
 ///
 /// Defined via the [XML schema](https://cyclonedx.org/docs/1.4/xml/#type_impactAnalysisResponsesType)
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum ImpactAnalysisResponse {
     CanNotFix,
     WillNotFix,
@@ -233,6 +259,7 @@ pub enum ImpactAnalysisResponse {
     Rollback,
     WorkaroundAvailable,
     #[doc(hidden)]
+    #[cfg_attr(feature = "arbitrary", arbitrary(skip))]
     UndefinedResponse(String),
 }
 
@@ -289,6 +316,8 @@ mod test {
             justification: Some(ImpactAnalysisJustification::CodeNotReachable),
             responses: Some(vec![ImpactAnalysisResponse::Update]),
             detail: Some("detail".to_string()),
+            first_issued: Some(DateTime("2024-01-01T00:00:00Z".to_string())),
+            last_updated: Some(DateTime("2024-01-02T00:00:00Z".to_string())),
         }
         .validate();
 
@@ -310,6 +339,8 @@ mod test {
                 "undefined".to_string(),
             )]),
             detail: Some("detail".to_string()),
+            first_issued: Some(DateTime("invalid date".to_string())),
+            last_updated: None,
         }
         .validate();
 
@@ -319,21 +350,21 @@ mod test {
                 reasons: vec![
                     FailureReason {
                         message: "Undefined impact analysis state".to_string(),
-                        context: ValidationContext(vec![ValidationPathComponent::Struct {
+                        context: ValidationContext::from(vec![ValidationPathComponent::Struct {
                             struct_name: "VulnerabilityAnalysis".to_string(),
                             field_name: "state".to_string()
                         },])
                     },
                     FailureReason {
                         message: "Undefined impact analysis justification".to_string(),
-                        context: ValidationContext(vec![ValidationPathComponent::Struct {
+                        context: ValidationContext::from(vec![ValidationPathComponent::Struct {
                             struct_name: "VulnerabilityAnalysis".to_string(),
                             field_name: "justification".to_string()
                         },])
                     },
                     FailureReason {
                         message: "Undefined response".to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Struct {
                                 struct_name: "VulnerabilityAnalysis".to_string(),
                                 field_name: "responses".to_string()
@@ -341,6 +372,13 @@ mod test {
                             ValidationPathComponent::Array { index: 0 },
                         ])
                     },
+                    FailureReason {
+                        message: "DateTime does not conform to ISO 8601".to_string(),
+                        context: ValidationContext::from(vec![ValidationPathComponent::Struct {
+                            struct_name: "VulnerabilityAnalysis".to_string(),
+                            field_name: "first_issued".to_string()
+                        },])
+                    },
                 ]
             }
         );
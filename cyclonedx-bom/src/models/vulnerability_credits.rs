@@ -20,12 +20,42 @@ use crate::models::organization::{OrganizationalContact, OrganizationalEntity};
 use crate::validation::{Validate, ValidationContext, ValidationPathComponent, ValidationResult};
 
 /// Provides credits to organizations or individuals who contributed to a vulnerability.
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub struct VulnerabilityCredits {
     pub organizations: Option<Vec<OrganizationalEntity>>,
     pub individuals: Option<Vec<OrganizationalContact>>,
 }
 
+impl VulnerabilityCredits {
+    /// Construct `VulnerabilityCredits`, leaving out empty lists of organizations or individuals
+    /// ```
+    /// use cyclonedx_bom::models::organization::OrganizationalContact;
+    /// use cyclonedx_bom::models::vulnerability_credits::VulnerabilityCredits;
+    ///
+    /// let reporter = OrganizationalContact::new("Jane Doe", Some("jane@example.com"));
+    /// let credits = VulnerabilityCredits::new(vec![], vec![reporter]);
+    /// assert!(credits.organizations.is_none());
+    /// assert!(!credits.is_empty());
+    /// ```
+    pub fn new(
+        organizations: Vec<OrganizationalEntity>,
+        individuals: Vec<OrganizationalContact>,
+    ) -> Self {
+        Self {
+            organizations: (!organizations.is_empty()).then_some(organizations),
+            individuals: (!individuals.is_empty()).then_some(individuals),
+        }
+    }
+
+    /// Whether nobody is credited
+    pub fn is_empty(&self) -> bool {
+        self.organizations.iter().flatten().next().is_none()
+            && self.individuals.iter().flatten().next().is_none()
+    }
+}
+
 impl Validate for VulnerabilityCredits {
     fn validate_with_context(&self, context: ValidationContext) -> ValidationResult {
         let mut results: Vec<ValidationResult> = vec![];
@@ -76,6 +106,7 @@ mod test {
                 name: Some(NormalizedString::new("name")),
                 url: None,
                 contact: None,
+                address: None,
             }]),
             individuals: Some(vec![OrganizationalContact {
                 name: Some(NormalizedString::new("name")),
@@ -95,6 +126,7 @@ mod test {
                 name: Some(NormalizedString("invalid\tname".to_string())),
                 url: None,
                 contact: None,
+                address: None,
             }]),
             individuals: Some(vec![OrganizationalContact {
                 name: Some(NormalizedString("invalid\tname".to_string())),
@@ -112,7 +144,7 @@ mod test {
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Struct {
                                 struct_name: "VulnerabilityCredits".to_string(),
                                 field_name: "organizations".to_string()
@@ -128,7 +160,7 @@ mod test {
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Struct {
                                 struct_name: "VulnerabilityCredits".to_string(),
                                 field_name: "individuals".to_string()
@@ -18,7 +18,9 @@
 
 use ordered_float::OrderedFloat;
 
+use crate::external_models::cvss::{CvssError, CvssVector, CvssVersion};
 use crate::external_models::normalized_string::NormalizedString;
+use crate::models::bom::SpecVersion;
 use crate::models::vulnerability_source::VulnerabilitySource;
 use crate::validation::{
     FailureReason, Validate, ValidationContext, ValidationPathComponent, ValidationResult,
@@ -27,7 +29,9 @@ use crate::validation::{
 /// Represents a vulnerability's rating as described in the [CycloneDX use cases](https://cyclonedx.org/use-cases/#vulnerability-exploitability)
 ///
 /// Defined via the [XML schema](https://cyclonedx.org/docs/1.4/xml/#type_ratingType)
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub struct VulnerabilityRating {
     pub vulnerability_source: Option<VulnerabilitySource>,
     pub score: Option<Score>,
@@ -58,6 +62,88 @@ impl VulnerabilityRating {
             justification: None,
         }
     }
+
+    /// Parses the vector of a rating scored with CVSS v2 or v3.x,
+    /// or returns `None` for other score methods
+    ///
+    /// CVSS v3.x vectors without a `CVSS:3.x/` prefix take their version from the score method.
+    pub fn cvss_vector(&self) -> Option<Result<CvssVector, CvssError>> {
+        let vector = self.vector.as_ref()?.trim();
+        let prefix = match self.score_method {
+            Some(ScoreMethod::CVSSv2) => "",
+            Some(ScoreMethod::CVSSv3) => "CVSS:3.0/",
+            Some(ScoreMethod::CVSSv31) => "CVSS:3.1/",
+            _ => return None,
+        };
+        match vector.starts_with("CVSS:") {
+            true => Some(CvssVector::parse(vector)),
+            false => Some(CvssVector::parse(&format!("{}{}", prefix, vector))),
+        }
+    }
+
+    /// The base score computed from the CVSS vector of the rating, to detect or correct
+    /// a mismatched score
+    /// ```
+    /// use cyclonedx_bom::external_models::normalized_string::NormalizedString;
+    /// use cyclonedx_bom::models::vulnerability_rating::{Score, ScoreMethod, VulnerabilityRating};
+    ///
+    /// let mut rating = VulnerabilityRating::new(Score::from_f32(5.0), None, Some(ScoreMethod::CVSSv31));
+    /// rating.vector = Some(NormalizedString::new("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H"));
+    /// rating.score = rating.cvss_base_score();
+    /// assert_eq!(rating.score, Score::from_f32(9.8));
+    /// ```
+    pub fn cvss_base_score(&self) -> Option<Score> {
+        match self.cvss_vector()? {
+            Ok(vector) => Some(Score::from(vector.base_score())),
+            Err(_) => None,
+        }
+    }
+
+    /// Checks the CVSS vector against the score method and the score of the rating
+    ///
+    /// The specification does not constrain the syntax of vectors, so only vectors that
+    /// can be parsed are checked.
+    fn validate_cvss_vector(&self, context: &ValidationContext) -> ValidationResult {
+        let vector = match self.cvss_vector() {
+            Some(Ok(vector)) => vector,
+            _ => return ValidationResult::Passed,
+        };
+
+        let expected_version = match self.score_method {
+            Some(ScoreMethod::CVSSv2) => CvssVersion::V2,
+            Some(ScoreMethod::CVSSv3) => CvssVersion::V3_0,
+            _ => CvssVersion::V3_1,
+        };
+        if vector.version() != expected_version {
+            let score_method = self
+                .score_method
+                .as_ref()
+                .map(ToString::to_string)
+                .unwrap_or_default();
+            return ValidationResult::failure(
+                &format!(
+                    "CVSS vector version {} does not match the score method {}",
+                    vector.version(),
+                    score_method
+                ),
+                context.with_struct("VulnerabilityRating", "vector"),
+            );
+        }
+
+        match &self.score {
+            Some(score) if (score.to_f32() - vector.base_score()).abs() > 0.05 => {
+                ValidationResult::failure(
+                    &format!(
+                        "Score {} does not match the base score {} of the CVSS vector",
+                        score.to_f32(),
+                        vector.base_score()
+                    ),
+                    context.with_struct("VulnerabilityRating", "score"),
+                )
+            }
+            _ => ValidationResult::Passed,
+        }
+    }
 }
 
 // todo: how to decide what to validate, check this
@@ -77,19 +163,28 @@ impl Validate for VulnerabilityRating {
             results.push(severity.validate_with_context(context));
         }
 
+        if let Some(score_method) = &self.score_method {
+            let context = context.with_struct("VulnerabilityRating", "score_method");
+
+            results.push(score_method.validate_with_context(context));
+        }
+
         if let Some(vector) = &self.vector {
             let context = context.with_struct("VulnerabilityRating", "vector");
 
             results.push(vector.validate_with_context(context));
         }
 
+        results.push(self.validate_cvss_vector(&context));
+
         results
             .into_iter()
             .fold(ValidationResult::default(), |acc, result| acc.merge(result))
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct VulnerabilityRatings(pub Vec<VulnerabilityRating>);
 
 impl Validate for VulnerabilityRatings {
@@ -113,8 +208,10 @@ impl Validate for VulnerabilityRatings {
 /// an i32 allows us to easily derive `Eq`. We have chosen a factor of 1000 to
 /// convert a f32 into i32 because OWASP's scoring method uses up to three decimal places.
 ///
+/// Scores are ordered by their value, with NaN greater than all other values.
+///
 /// Defined via the [XML schema](https://cyclonedx.org/docs/1.4/xml/#type_ratingType)
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Score(OrderedFloat<f32>);
 
 impl Score {
@@ -131,6 +228,14 @@ impl Score {
     }
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Score {
+    /// Generates a score between 0.0 and 10.0 with one decimal place, as used by CVSS
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self::from(f32::from(u.int_in_range(0..=100u8)?) / 10.0))
+    }
+}
+
 impl From<f32> for Score {
     fn from(value: f32) -> Self {
         Score(value.into())
@@ -146,7 +251,8 @@ impl From<Score> for f32 {
 /// Specifies a vulnerability's severity adopted by the analysis method.
 ///
 /// Defined via the [XML schema](https://cyclonedx.org/docs/1.4/xml/#type_severityType)
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum Severity {
     Critical,
     High,
@@ -156,6 +262,7 @@ pub enum Severity {
     None,
     Unknown,
     #[doc(hidden)]
+    #[cfg_attr(feature = "arbitrary", arbitrary(skip))]
     UndefinedSeverity(String),
 }
 
@@ -207,13 +314,21 @@ impl ToString for Severity {
 /// Specifies the risk scoring method or standard used.
 ///
 /// Defined via the [XML schema](https://cyclonedx.org/docs/1.4/xml/#type_scoreSourceType)
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum ScoreMethod {
     CVSSv2,
     CVSSv3,
     CVSSv31,
+    /// Added in version 1.5
+    CVSSv4,
     OWASP,
-    Other(String),
+    /// Added in version 1.5
+    SSVC,
+    Other,
+    #[doc(hidden)]
+    #[cfg_attr(feature = "arbitrary", arbitrary(skip))]
+    UnknownScoreMethod(String),
 }
 
 impl ScoreMethod {
@@ -222,10 +337,38 @@ impl ScoreMethod {
             "CVSSv2" => Self::CVSSv2,
             "CVSSv3" => Self::CVSSv3,
             "CVSSv31" => Self::CVSSv31,
+            "CVSSv4" => Self::CVSSv4,
             "OWASP" => Self::OWASP,
-            score_method => Self::Other(score_method.to_string()),
+            "SSVC" => Self::SSVC,
+            "other" => Self::Other,
+            unknown => Self::UnknownScoreMethod(unknown.to_string()),
+        }
+    }
+
+    /// The minor version of the CycloneDX 1.x specification that introduced the method
+    fn minor_version(&self) -> Option<u8> {
+        match self {
+            Self::CVSSv2 | Self::CVSSv3 | Self::CVSSv31 | Self::OWASP | Self::Other => Some(4),
+            Self::CVSSv4 | Self::SSVC => Some(5),
+            Self::UnknownScoreMethod(_) => None,
         }
     }
+
+    /// The version of the specification that introduced the method, e.g. `"1.5"`,
+    /// or `None` for an unknown method
+    pub fn introduced_in(&self) -> Option<String> {
+        self.minor_version().map(|minor| format!("1.{}", minor))
+    }
+
+    /// Whether documents of the given spec version may use the method
+    pub fn is_allowed_in(&self, version: SpecVersion) -> bool {
+        let supported = match version {
+            SpecVersion::V1_3 => 3,
+            SpecVersion::V1_4 => 4,
+            SpecVersion::V1_5 => 5,
+        };
+        self.minor_version().is_some_and(|minor| minor <= supported)
+    }
 }
 
 impl ToString for ScoreMethod {
@@ -234,13 +377,27 @@ impl ToString for ScoreMethod {
             ScoreMethod::CVSSv2 => "CVSSv2",
             ScoreMethod::CVSSv3 => "CVSSv3",
             ScoreMethod::CVSSv31 => "CVSSv31",
+            ScoreMethod::CVSSv4 => "CVSSv4",
             ScoreMethod::OWASP => "OWASP",
-            ScoreMethod::Other(score_method) => score_method,
+            ScoreMethod::SSVC => "SSVC",
+            ScoreMethod::Other => "other",
+            ScoreMethod::UnknownScoreMethod(un) => un,
         }
         .to_string()
     }
 }
 
+impl Validate for ScoreMethod {
+    fn validate_with_context(&self, context: ValidationContext) -> ValidationResult {
+        match self {
+            ScoreMethod::UnknownScoreMethod(_) => {
+                ValidationResult::failure("Unknown score method", context)
+            }
+            _ => ValidationResult::Passed,
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -269,6 +426,83 @@ mod test {
         assert_eq!(validation_result, ValidationResult::Passed);
     }
 
+    #[test]
+    fn score_methods_should_round_trip() {
+        for name in ["CVSSv31", "CVSSv4", "SSVC", "other", "EPSS"] {
+            assert_eq!(ScoreMethod::new_unchecked(name).to_string(), name);
+        }
+        assert_eq!(ScoreMethod::new_unchecked("other"), ScoreMethod::Other);
+        assert_eq!(
+            ScoreMethod::new_unchecked("EPSS").validate(),
+            ValidationResult::failure("Unknown score method", ValidationContext::new())
+        );
+
+        assert!(ScoreMethod::OWASP.is_allowed_in(SpecVersion::V1_4));
+        assert!(!ScoreMethod::SSVC.is_allowed_in(SpecVersion::V1_4));
+        assert_eq!(ScoreMethod::CVSSv4.introduced_in().as_deref(), Some("1.5"));
+    }
+
+    #[test]
+    fn cvss_vectors_should_match_the_score_method_and_score() {
+        let rating = |score: f32, score_method: ScoreMethod, vector: &str| VulnerabilityRating {
+            score: Score::from_f32(score),
+            vector: Some(NormalizedString::new(vector)),
+            ..VulnerabilityRating::new(None, None, Some(score_method))
+        };
+        let vector_context = ValidationContext::new().with_struct("VulnerabilityRating", "vector");
+
+        let valid = rating(7.5, ScoreMethod::CVSSv2, "AV:N/AC:L/Au:N/C:P/I:P/A:P");
+        assert_eq!(valid.validate(), ValidationResult::Passed);
+        assert_eq!(valid.cvss_base_score(), Score::from_f32(7.5));
+
+        let unprefixed = rating(
+            9.8,
+            ScoreMethod::CVSSv3,
+            "AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H",
+        );
+        assert_eq!(unprefixed.validate(), ValidationResult::Passed);
+        assert_eq!(
+            unprefixed.cvss_vector().unwrap().unwrap().version(),
+            CvssVersion::V3_0
+        );
+
+        let malformed = rating(9.8, ScoreMethod::CVSSv31, "CVSS:3.1/AV:N/AC:L");
+        assert_eq!(malformed.validate(), ValidationResult::Passed);
+        assert_eq!(
+            malformed.cvss_vector(),
+            Some(Err(CvssError::MissingMetric("PR".to_string())))
+        );
+        assert_eq!(malformed.cvss_base_score(), None);
+        assert_eq!(
+            rating(
+                9.8,
+                ScoreMethod::CVSSv3,
+                "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H"
+            )
+            .validate(),
+            ValidationResult::failure(
+                "CVSS vector version 3.1 does not match the score method CVSSv3",
+                vector_context
+            )
+        );
+        assert_eq!(
+            rating(
+                5.0,
+                ScoreMethod::CVSSv31,
+                "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H"
+            )
+            .validate(),
+            ValidationResult::failure(
+                "Score 5 does not match the base score 9.8 of the CVSS vector",
+                ValidationContext::new().with_struct("VulnerabilityRating", "score")
+            )
+        );
+        assert_eq!(
+            rating(5.0, ScoreMethod::OWASP, "not a CVSS vector").validate(),
+            ValidationResult::Passed
+        );
+    }
+
     #[test]
     fn invalid_vulnerability_ratings_should_fail_validation() {
         let validation_result = VulnerabilityRatings(vec![VulnerabilityRating {
@@ -292,7 +526,7 @@ mod test {
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "VulnerabilityRating".to_string(),
@@ -306,7 +540,7 @@ mod test {
                     },
                     FailureReason {
                         message: "Uri does not conform to RFC 3986".to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "VulnerabilityRating".to_string(),
@@ -320,7 +554,7 @@ mod test {
                     },
                     FailureReason {
                         message: "Undefined severity".to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "VulnerabilityRating".to_string(),
@@ -332,7 +566,7 @@ mod test {
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
-                        context: ValidationContext(vec![
+                        context: ValidationContext::from(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "VulnerabilityRating".to_string(),
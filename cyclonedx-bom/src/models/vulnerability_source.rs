@@ -22,7 +22,9 @@ use crate::validation::{Validate, ValidationContext, ValidationResult};
 /// Defines a source related to the vulnerability, e.g. who published or calculated the severity or risk rating the vulnerability.
 ///
 /// Defined via the [XML schema](https://cyclonedx.org/docs/1.4/xml/#type_vulnerabilitySourceType)
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub struct VulnerabilitySource {
     pub name: Option<NormalizedString>,
     pub url: Option<Uri>,
@@ -109,14 +111,14 @@ mod test {
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
-                        context: ValidationContext(vec![ValidationPathComponent::Struct {
+                        context: ValidationContext::from(vec![ValidationPathComponent::Struct {
                             struct_name: "VulnerabilitySource".to_string(),
                             field_name: "name".to_string()
                         },])
                     },
                     FailureReason {
                         message: "Uri does not conform to RFC 3986".to_string(),
-                        context: ValidationContext(vec![ValidationPathComponent::Struct {
+                        context: ValidationContext::from(vec![ValidationPathComponent::Struct {
                             struct_name: "VulnerabilitySource".to_string(),
                             field_name: "url".to_string()
                         },])
@@ -20,12 +20,15 @@ use once_cell::sync::Lazy;
 use regex::Regex;
 
 use crate::external_models::normalized_string::NormalizedString;
+use crate::external_models::vers::Vers;
 use crate::validation::{FailureReason, Validate, ValidationContext, ValidationResult};
 
 /// Defines how a component or service is affected by a vulnerability as described in the [CycloneDX use cases](https://cyclonedx.org/use-cases/#vulnerability-exploitability)
 ///
 /// Defined via the [XML schema](https://cyclonedx.org/docs/1.4/xml/#type_vulnerabilityType)
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub struct VulnerabilityTarget {
     pub bom_ref: String,
     pub versions: Option<Versions>,
@@ -62,7 +65,8 @@ impl Validate for VulnerabilityTarget {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct VulnerabilityTargets(pub Vec<VulnerabilityTarget>);
 
 impl Validate for VulnerabilityTargets {
@@ -80,7 +84,8 @@ impl Validate for VulnerabilityTargets {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Versions(pub Vec<Version>);
 
 impl Validate for Versions {
@@ -98,7 +103,9 @@ impl Validate for Versions {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub struct Version {
     pub version_range: VersionRange,
     pub status: Status,
@@ -144,7 +151,7 @@ impl Validate for Version {
 ///
 /// Defined via the [PURL specification](https://github.com/package-url/purl-spec/blob/master/PURL-SPECIFICATION.rst)
 /// Spec for version ranges still work in progress [PURL version-range-spec](https://github.com/package-url/purl-spec/blob/version-range-spec/VERSION-RANGE-SPEC.rst)
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum VersionRange {
     Version(NormalizedString),
     Range(NormalizedString),
@@ -152,6 +159,17 @@ pub enum VersionRange {
     UndefinedVersionRange(String),
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for VersionRange {
+    /// Generates a version or a well-formed `vers` range
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(match u.arbitrary()? {
+            true => Self::Version(u.arbitrary()?),
+            false => Self::Range(NormalizedString::new(&u.arbitrary::<Vers>()?.to_string())),
+        })
+    }
+}
+
 impl VersionRange {
     pub fn new(value: &str) -> Self {
         match matches_purl_version_range_regex(value) {
@@ -159,6 +177,21 @@ impl VersionRange {
             false => VersionRange::Version(NormalizedString::new(value)),
         }
     }
+
+    /// The range as a [`Vers`] to match versions against, or `None` for a single version
+    /// ```
+    /// use cyclonedx_bom::models::vulnerability_target::VersionRange;
+    ///
+    /// let range = VersionRange::new("vers:cargo/>=1.0.0|<1.2.0");
+    /// assert!(range.as_vers().is_some_and(|vers| vers.contains("1.1.0")));
+    /// assert!(VersionRange::new("1.0.0").as_vers().is_none());
+    /// ```
+    pub fn as_vers(&self) -> Option<Vers> {
+        match self {
+            VersionRange::Range(range) => Some(Vers(range.to_string())),
+            _ => None,
+        }
+    }
 }
 
 impl Validate for VersionRange {
@@ -170,7 +203,8 @@ impl Validate for VersionRange {
                     context,
                 }],
             },
-            _ => ValidationResult::Passed,
+            VersionRange::Range(range) => Vers(range.to_string()).validate_with_context(context),
+            VersionRange::Version(_) => ValidationResult::Passed,
         }
     }
 }
@@ -195,12 +229,14 @@ fn matches_purl_version_range_regex(value: &str) -> bool {
 /// Specifies if a vulnerability affects a component or service.
 ///
 /// Defined via the [XML schema](https://cyclonedx.org/docs/1.4/xml/#type_impactAnalysisAffectedStatusType)
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum Status {
     Affected,
     Unaffected,
     Unknown,
     #[doc(hidden)]
+    #[cfg_attr(feature = "arbitrary", arbitrary(skip))]
     UndefinedStatus(String),
 }
 
@@ -254,6 +290,21 @@ mod test {
         );
     }
 
+    #[test]
+    fn malformed_vers_ranges_should_fail_validation() {
+        assert_eq!(
+            VersionRange::new("vers:cargo/>=1.0.0|<1.2.0").validate(),
+            ValidationResult::Passed
+        );
+        assert_eq!(
+            VersionRange::new("vers:cargo/>=1.0.0|>=1.2.0").validate(),
+            ValidationResult::failure(
+                "Vers does not conform to the VERS specification: constraints do not alternate between lower and upper bounds",
+                ValidationContext::new()
+            )
+        );
+    }
+
     #[test]
     fn valid_vulnerability_targets_should_pass_validation() {
         let validation_result = VulnerabilityTargets(vec![VulnerabilityTarget {
@@ -16,6 +16,26 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
+//! The types and traits needed to build, read and validate a typical BOM.
+//!
+//! ```
+//! use cyclonedx_bom::prelude::*;
+//!
+//! let mut component = Component::new(Classification::Library, "serde", "1.0.193", None);
+//! component.licenses = Some(Licenses(vec![LicenseChoice::Expression(
+//!     SpdxExpression::parse_lax("MIT OR Apache-2.0".to_string()).unwrap(),
+//! )]));
+//! component.properties = Some(Properties(vec![Property::new("cdx:example", "value")]));
+//!
+//! let mut metadata = Metadata::default();
+//! metadata.tools = Some(Tools::List(vec![Tool::new("Acme", "generator", "1.0.0")]));
+//!
+//! let mut bom = Bom::default();
+//! bom.metadata = Some(metadata);
+//! bom.components = Some(Components(vec![component]));
+//! assert_eq!(bom.validate(), ValidationResult::Passed);
+//! ```
+
 pub use crate::external_models::{
     date_time::DateTime,
     normalized_string::NormalizedString,
@@ -23,8 +43,17 @@ pub use crate::external_models::{
     uri::{Purl, Uri},
 };
 pub use crate::models::{
-    bom::{Bom, UrnUuid},
-    component::{Component, Components},
+    bom::{Bom, SpecVersion, UrnUuid},
+    component::{Classification, Component, Components, Scope},
+    dependency::{Dependencies, Dependency},
+    external_reference::{ExternalReference, ExternalReferenceType, ExternalReferences},
+    hash::{Hash, HashAlgorithm, HashValue, Hashes},
+    license::{License, LicenseChoice, LicenseIdentifier, Licenses},
     metadata::Metadata,
+    organization::{OrganizationalContact, OrganizationalEntity},
+    property::{Properties, Property},
+    service::{Service, Services},
+    tool::{Tool, Tools},
+    vulnerability::{Vulnerabilities, Vulnerability},
 };
 pub use crate::validation::{Validate, ValidationResult};
@@ -0,0 +1,843 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Selects the components, services or vulnerabilities of a BOM that match a predicate.
+//!
+//! A [`Predicate`] can be built in code from the [`Field`]s of the selected items,
+//! or parsed from a [`Selector`] such as
+//! `components[?licenses contains 'GPL-3.0' && scope != 'excluded']`.
+//!
+//! ```
+//! use cyclonedx_bom::models::bom::Bom;
+//! use cyclonedx_bom::query::Field;
+//!
+//! # let bom = Bom::parse_from_json(r#"{
+//! #   "bomFormat": "CycloneDX", "specVersion": "1.5", "version": 1,
+//! #   "components": [
+//! #     { "type": "library", "name": "readline", "licenses": [{ "expression": "GPL-3.0-only" }] },
+//! #     { "type": "library", "name": "serde", "licenses": [{ "expression": "MIT OR Apache-2.0" }] }
+//! #   ]
+//! # }"#.as_bytes()).unwrap();
+//! let selection = bom.select("components[?licenses contains 'GPL-3.0-only']").unwrap();
+//! assert_eq!(selection.len(), 1);
+//!
+//! let gpl = bom.select_components(&Field::License.starts_with("GPL"));
+//! assert_eq!(gpl[0].name.to_string(), "readline");
+//! ```
+//!
+//! A selector names the collection, optionally followed by a filter in `[? ... ]`.
+//! A filter compares fields with `==`, `!=`, `contains` and `starts_with`, combines
+//! comparisons with `&&`, `||`, `!` and parentheses, and tests a field on its own for presence.
+//! Literals are quoted with `'` or `"`, a backslash escapes the next character.
+//!
+//! Fields that can hold several values, e.g. `licenses`, match if any of their values matches.
+//! Fields that do not apply to an item, e.g. `severity` for a component, have no values.
+
+use std::fmt;
+use std::iter::Peekable;
+use std::ops::Not;
+use std::str::{CharIndices, FromStr};
+
+use crate::errors::QueryError;
+use crate::models::bom::Bom;
+use crate::models::component::{Component, Components};
+use crate::models::license::{LicenseChoice, LicenseIdentifier, Licenses};
+use crate::models::property::Properties;
+use crate::models::service::{Service, Services};
+use crate::models::vulnerability::Vulnerability;
+
+/// A field of a component, service or vulnerability
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Field {
+    /// `bom-ref`
+    BomRef,
+    /// `name`
+    Name,
+    /// `version`
+    Version,
+    /// `group`
+    Group,
+    /// `type`, the classification of a component
+    Type,
+    /// `scope` of a component
+    Scope,
+    /// `purl` of a component
+    Purl,
+    /// `licenses`, each SPDX id, license name or license expression
+    License,
+    /// `supplier`, the name of the supplier of a component or the provider of a service
+    Supplier,
+    /// `properties.<name>`, the values of the properties with the given name
+    Property(String),
+    /// `id` of a vulnerability
+    Id,
+    /// `severity`, each severity a vulnerability is rated with
+    Severity,
+    /// `affects`, the bom-refs targeted by a vulnerability
+    Affects,
+}
+
+impl Field {
+    pub fn exists(self) -> Predicate {
+        Predicate::Exists(self)
+    }
+
+    pub fn eq(self, value: impl Into<String>) -> Predicate {
+        Predicate::Equals(self, value.into())
+    }
+
+    pub fn ne(self, value: impl Into<String>) -> Predicate {
+        !Predicate::Equals(self, value.into())
+    }
+
+    pub fn contains(self, value: impl Into<String>) -> Predicate {
+        Predicate::Contains(self, value.into())
+    }
+
+    pub fn starts_with(self, value: impl Into<String>) -> Predicate {
+        Predicate::StartsWith(self, value.into())
+    }
+}
+
+impl FromStr for Field {
+    type Err = QueryError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let field = match s {
+            "bom-ref" => Self::BomRef,
+            "name" => Self::Name,
+            "version" => Self::Version,
+            "group" => Self::Group,
+            "type" => Self::Type,
+            "scope" => Self::Scope,
+            "purl" => Self::Purl,
+            "licenses" => Self::License,
+            "supplier" => Self::Supplier,
+            "id" => Self::Id,
+            "severity" => Self::Severity,
+            "affects" => Self::Affects,
+            _ => match s.strip_prefix("properties.") {
+                Some(name) if !name.is_empty() => Self::Property(name.to_string()),
+                _ => return Err(QueryError::UnknownField(s.to_string())),
+            },
+        };
+        Ok(field)
+    }
+}
+
+impl fmt::Display for Field {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::BomRef => "bom-ref",
+            Self::Name => "name",
+            Self::Version => "version",
+            Self::Group => "group",
+            Self::Type => "type",
+            Self::Scope => "scope",
+            Self::Purl => "purl",
+            Self::License => "licenses",
+            Self::Supplier => "supplier",
+            Self::Id => "id",
+            Self::Severity => "severity",
+            Self::Affects => "affects",
+            Self::Property(name) => return write!(f, "properties.{name}"),
+        };
+        f.write_str(name)
+    }
+}
+
+/// A condition on the fields of an item, see [`Field`] for the shorthands to build one
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Predicate {
+    /// The field has at least one value
+    Exists(Field),
+    Equals(Field, String),
+    Contains(Field, String),
+    StartsWith(Field, String),
+    Not(Box<Predicate>),
+    /// All predicates match, `true` if there are none
+    All(Vec<Predicate>),
+    /// At least one predicate matches, `false` if there are none
+    Any(Vec<Predicate>),
+}
+
+impl Predicate {
+    pub fn and(self, other: Predicate) -> Self {
+        match self {
+            Self::All(mut predicates) => {
+                predicates.push(other);
+                Self::All(predicates)
+            }
+            predicate => Self::All(vec![predicate, other]),
+        }
+    }
+
+    pub fn or(self, other: Predicate) -> Self {
+        match self {
+            Self::Any(mut predicates) => {
+                predicates.push(other);
+                Self::Any(predicates)
+            }
+            predicate => Self::Any(vec![predicate, other]),
+        }
+    }
+
+    pub fn matches(&self, item: &impl Queryable) -> bool {
+        match self {
+            Self::Exists(field) => !item.values(field).is_empty(),
+            Self::Equals(field, value) => item.values(field).iter().any(|v| v == value),
+            Self::Contains(field, value) => item
+                .values(field)
+                .iter()
+                .any(|v| v.contains(value.as_str())),
+            Self::StartsWith(field, value) => item
+                .values(field)
+                .iter()
+                .any(|v| v.starts_with(value.as_str())),
+            Self::Not(predicate) => !predicate.matches(item),
+            Self::All(predicates) => predicates.iter().all(|p| p.matches(item)),
+            Self::Any(predicates) => predicates.iter().any(|p| p.matches(item)),
+        }
+    }
+}
+
+impl Not for Predicate {
+    type Output = Predicate;
+
+    fn not(self) -> Self::Output {
+        match self {
+            Self::Not(predicate) => *predicate,
+            predicate => Self::Not(Box::new(predicate)),
+        }
+    }
+}
+
+/// An item of a BOM that [`Predicate`]s can be evaluated against
+pub trait Queryable {
+    /// Returns the values of the field, empty if the item does not have it
+    fn values(&self, field: &Field) -> Vec<String>;
+}
+
+impl Queryable for Component {
+    fn values(&self, field: &Field) -> Vec<String> {
+        match field {
+            Field::BomRef => self.bom_ref.iter().cloned().collect(),
+            Field::Name => vec![self.name.to_string()],
+            Field::Version => self.version.iter().map(ToString::to_string).collect(),
+            Field::Group => self.group.iter().map(ToString::to_string).collect(),
+            Field::Type => vec![self.component_type.to_string()],
+            Field::Scope => self.scope.iter().map(ToString::to_string).collect(),
+            Field::Purl => self.purl.iter().map(ToString::to_string).collect(),
+            Field::License => license_values(self.licenses.as_ref()),
+            Field::Supplier => self
+                .supplier
+                .iter()
+                .flat_map(|s| s.name.as_ref())
+                .map(ToString::to_string)
+                .collect(),
+            Field::Property(name) => property_values(self.properties.as_ref(), name),
+            Field::Id | Field::Severity | Field::Affects => Vec::new(),
+        }
+    }
+}
+
+impl Queryable for Service {
+    fn values(&self, field: &Field) -> Vec<String> {
+        match field {
+            Field::BomRef => self.bom_ref.iter().cloned().collect(),
+            Field::Name => vec![self.name.to_string()],
+            Field::Version => self.version.iter().map(ToString::to_string).collect(),
+            Field::Group => self.group.iter().map(ToString::to_string).collect(),
+            Field::License => license_values(self.licenses.as_ref()),
+            Field::Supplier => self
+                .provider
+                .iter()
+                .flat_map(|p| p.name.as_ref())
+                .map(ToString::to_string)
+                .collect(),
+            Field::Property(name) => property_values(self.properties.as_ref(), name),
+            Field::Type
+            | Field::Scope
+            | Field::Purl
+            | Field::Id
+            | Field::Severity
+            | Field::Affects => Vec::new(),
+        }
+    }
+}
+
+impl Queryable for Vulnerability {
+    fn values(&self, field: &Field) -> Vec<String> {
+        match field {
+            Field::BomRef => self.bom_ref.iter().cloned().collect(),
+            Field::Id => self.id.iter().map(ToString::to_string).collect(),
+            Field::Severity => self
+                .vulnerability_ratings
+                .iter()
+                .flat_map(|r| r.0.iter())
+                .filter_map(|r| r.severity.as_ref())
+                .map(ToString::to_string)
+                .collect(),
+            Field::Affects => self
+                .vulnerability_targets
+                .iter()
+                .flat_map(|t| t.0.iter())
+                .map(|t| t.bom_ref.clone())
+                .collect(),
+            Field::Property(name) => property_values(self.properties.as_ref(), name),
+            Field::Name
+            | Field::Version
+            | Field::Group
+            | Field::Type
+            | Field::Scope
+            | Field::Purl
+            | Field::License
+            | Field::Supplier => Vec::new(),
+        }
+    }
+}
+
+fn license_values(licenses: Option<&Licenses>) -> Vec<String> {
+    licenses
+        .iter()
+        .flat_map(|l| l.0.iter())
+        .map(|license| match license {
+            LicenseChoice::Expression(expression) => expression.to_string(),
+            LicenseChoice::License(license) => match &license.license_identifier {
+                LicenseIdentifier::SpdxId(id) => id.to_string(),
+                LicenseIdentifier::Name(name) => name.to_string(),
+            },
+        })
+        .collect()
+}
+
+fn property_values(properties: Option<&Properties>, name: &str) -> Vec<String> {
+    properties
+        .iter()
+        .flat_map(|p| p.0.iter())
+        .filter(|p| p.name == name)
+        .map(|p| p.value.to_string())
+        .collect()
+}
+
+/// The collections of a BOM a [`Selector`] can select from
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Collection {
+    /// All components, including nested ones, but not the component in `metadata.component`
+    Components,
+    /// All services, including nested ones
+    Services,
+    Vulnerabilities,
+}
+
+/// A parsed selector, e.g. `vulnerabilities[?severity == 'critical']`
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Selector {
+    pub collection: Collection,
+    /// The filter, `None` to select the whole collection
+    pub predicate: Option<Predicate>,
+}
+
+impl Selector {
+    pub fn select<'a>(&self, bom: &'a Bom) -> Selection<'a> {
+        let all = Predicate::All(Vec::new());
+        let predicate = self.predicate.as_ref().unwrap_or(&all);
+        match self.collection {
+            Collection::Components => Selection::Components(bom.select_components(predicate)),
+            Collection::Services => Selection::Services(bom.select_services(predicate)),
+            Collection::Vulnerabilities => {
+                Selection::Vulnerabilities(bom.select_vulnerabilities(predicate))
+            }
+        }
+    }
+}
+
+impl FromStr for Selector {
+    type Err = QueryError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Parser::new(s).selector()
+    }
+}
+
+/// The items a [`Selector`] selected from a BOM
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Selection<'a> {
+    Components(Vec<&'a Component>),
+    Services(Vec<&'a Service>),
+    Vulnerabilities(Vec<&'a Vulnerability>),
+}
+
+impl Selection<'_> {
+    pub fn len(&self) -> usize {
+        match self {
+            Self::Components(components) => components.len(),
+            Self::Services(services) => services.len(),
+            Self::Vulnerabilities(vulnerabilities) => vulnerabilities.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The bom-refs of the selected items that have one
+    pub fn bom_refs(&self) -> Vec<&str> {
+        match self {
+            Self::Components(components) => components
+                .iter()
+                .filter_map(|c| c.bom_ref.as_deref())
+                .collect(),
+            Self::Services(services) => services
+                .iter()
+                .filter_map(|s| s.bom_ref.as_deref())
+                .collect(),
+            Self::Vulnerabilities(vulnerabilities) => vulnerabilities
+                .iter()
+                .filter_map(|v| v.bom_ref.as_deref())
+                .collect(),
+        }
+    }
+}
+
+pub(crate) fn select_components<'a>(
+    components: &'a Components,
+    predicate: &Predicate,
+    out: &mut Vec<&'a Component>,
+) {
+    for component in &components.0 {
+        if predicate.matches(component) {
+            out.push(component);
+        }
+        if let Some(nested) = &component.components {
+            select_components(nested, predicate, out);
+        }
+    }
+}
+
+pub(crate) fn select_services<'a>(
+    services: &'a Services,
+    predicate: &Predicate,
+    out: &mut Vec<&'a Service>,
+) {
+    for service in &services.0 {
+        if predicate.matches(service) {
+            out.push(service);
+        }
+        if let Some(nested) = &service.services {
+            select_services(nested, predicate, out);
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Token {
+    Word(String),
+    Literal(String),
+    Symbol(&'static str),
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Word(word) => f.write_str(word),
+            Self::Literal(literal) => write!(f, "'{literal}'"),
+            Self::Symbol(symbol) => f.write_str(symbol),
+        }
+    }
+}
+
+const SYMBOLS: [&str; 8] = ["[?", "]", "(", ")", "&&", "||", "==", "!="];
+
+struct Parser<'a> {
+    input: &'a str,
+    chars: Peekable<CharIndices<'a>>,
+    peeked: Option<(usize, Token)>,
+}
+
+impl<'a> Parser<'a> {
+    fn new(input: &'a str) -> Self {
+        Self {
+            input,
+            chars: input.char_indices().peekable(),
+            peeked: None,
+        }
+    }
+
+    fn selector(mut self) -> Result<Selector, QueryError> {
+        let collection = match self.next()? {
+            Some((_, Token::Word(word))) => match word.as_str() {
+                "components" => Collection::Components,
+                "services" => Collection::Services,
+                "vulnerabilities" => Collection::Vulnerabilities,
+                _ => return Err(QueryError::UnknownCollection(word)),
+            },
+            token => return Err(self.unexpected(token, "a collection")),
+        };
+
+        let predicate = match self.next()? {
+            None => None,
+            Some((_, Token::Symbol("[?"))) => {
+                let predicate = self.or()?;
+                self.expect("]")?;
+                match self.next()? {
+                    None => Some(predicate),
+                    token => return Err(self.unexpected(token, "the end of the selector")),
+                }
+            }
+            token => return Err(self.unexpected(token, "'[?'")),
+        };
+
+        Ok(Selector {
+            collection,
+            predicate,
+        })
+    }
+
+    fn or(&mut self) -> Result<Predicate, QueryError> {
+        let mut predicate = self.and()?;
+        while self.accept("||")? {
+            predicate = predicate.or(self.and()?);
+        }
+        Ok(predicate)
+    }
+
+    fn and(&mut self) -> Result<Predicate, QueryError> {
+        let mut predicate = self.unary()?;
+        while self.accept("&&")? {
+            predicate = predicate.and(self.unary()?);
+        }
+        Ok(predicate)
+    }
+
+    fn unary(&mut self) -> Result<Predicate, QueryError> {
+        match self.next()? {
+            Some((_, Token::Symbol("!"))) => Ok(!self.unary()?),
+            Some((_, Token::Symbol("("))) => {
+                let predicate = self.or()?;
+                self.expect(")")?;
+                Ok(predicate)
+            }
+            Some((_, Token::Word(word))) => {
+                let field: Field = word.parse()?;
+                let operator = match self.peek()? {
+                    Some(Token::Symbol(symbol @ ("==" | "!="))) => *symbol,
+                    Some(Token::Word(word)) if word == "contains" => "contains",
+                    Some(Token::Word(word)) if word == "starts_with" => "starts_with",
+                    _ => return Ok(field.exists()),
+                };
+                self.next()?;
+                let value = match self.next()? {
+                    Some((_, Token::Literal(value))) => value,
+                    token => return Err(self.unexpected(token, "a quoted value")),
+                };
+                Ok(match operator {
+                    "==" => field.eq(value),
+                    "!=" => field.ne(value),
+                    "contains" => field.contains(value),
+                    _ => field.starts_with(value),
+                })
+            }
+            token => Err(self.unexpected(token, "a field, '!' or '('")),
+        }
+    }
+
+    fn accept(&mut self, symbol: &str) -> Result<bool, QueryError> {
+        if matches!(self.peek()?, Some(Token::Symbol(s)) if *s == symbol) {
+            self.next()?;
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+
+    fn expect(&mut self, symbol: &str) -> Result<(), QueryError> {
+        if self.accept(symbol)? {
+            Ok(())
+        } else {
+            let token = self.next()?;
+            Err(self.unexpected(token, &format!("'{symbol}'")))
+        }
+    }
+
+    fn unexpected(&self, token: Option<(usize, Token)>, expected: &str) -> QueryError {
+        match token {
+            Some((position, token)) => QueryError::UnexpectedToken {
+                position,
+                found: token.to_string(),
+                expected: expected.to_string(),
+            },
+            None => QueryError::UnexpectedEnd {
+                expected: expected.to_string(),
+            },
+        }
+    }
+
+    fn peek(&mut self) -> Result<Option<&Token>, QueryError> {
+        if self.peeked.is_none() {
+            self.peeked = self.read()?;
+        }
+        Ok(self.peeked.as_ref().map(|(_, token)| token))
+    }
+
+    fn next(&mut self) -> Result<Option<(usize, Token)>, QueryError> {
+        match self.peeked.take() {
+            Some(token) => Ok(Some(token)),
+            None => self.read(),
+        }
+    }
+
+    fn read(&mut self) -> Result<Option<(usize, Token)>, QueryError> {
+        while self.chars.next_if(|(_, c)| c.is_whitespace()).is_some() {}
+        let Some(&(start, c)) = self.chars.peek() else {
+            return Ok(None);
+        };
+
+        if c == '\'' || c == '"' {
+            self.chars.next();
+            let mut literal = String::new();
+            loop {
+                match self.chars.next() {
+                    Some((_, '\\')) => match self.chars.next() {
+                        Some((_, escaped)) => literal.push(escaped),
+                        None => break,
+                    },
+                    Some((_, quote)) if quote == c => {
+                        return Ok(Some((start, Token::Literal(literal))))
+                    }
+                    Some((_, other)) => literal.push(other),
+                    None => break,
+                }
+            }
+            return Err(QueryError::UnterminatedLiteral { position: start });
+        }
+
+        let rest = &self.input[start..];
+        if let Some(symbol) = SYMBOLS.into_iter().find(|s| rest.starts_with(s)) {
+            for _ in 0..symbol.len() {
+                self.chars.next();
+            }
+            return Ok(Some((start, Token::Symbol(symbol))));
+        }
+        if c == '!' {
+            self.chars.next();
+            return Ok(Some((start, Token::Symbol("!"))));
+        }
+
+        let mut word = String::new();
+        while let Some((_, c)) = self
+            .chars
+            .next_if(|(_, c)| c.is_alphanumeric() || matches!(c, '-' | '_' | '.' | ':'))
+        {
+            word.push(c);
+        }
+        if word.is_empty() {
+            return Err(QueryError::UnexpectedToken {
+                position: start,
+                found: c.to_string(),
+                expected: "a field, an operator or a quoted value".to_string(),
+            });
+        }
+        Ok(Some((start, Token::Word(word))))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::external_models::normalized_string::NormalizedString;
+    use crate::external_models::spdx::SpdxExpression;
+    use crate::models::component::{Classification, Scope};
+    use crate::models::license::License;
+    use crate::models::property::Property;
+    use crate::models::vulnerability::Vulnerabilities;
+    use crate::models::vulnerability_rating::{
+        Severity, VulnerabilityRating, VulnerabilityRatings,
+    };
+    use crate::models::vulnerability_target::{VulnerabilityTarget, VulnerabilityTargets};
+    use pretty_assertions::assert_eq;
+
+    fn component(name: &str, license: LicenseChoice, scope: Scope) -> Component {
+        let mut component = Component::new(
+            Classification::Library,
+            name,
+            "1.0.0",
+            Some(name.to_string()),
+        );
+        component.licenses = Some(Licenses(vec![license]));
+        component.scope = Some(scope);
+        component
+    }
+
+    fn bom() -> Bom {
+        let mut readline = component(
+            "readline",
+            LicenseChoice::Expression(SpdxExpression("GPL-3.0-only".to_string())),
+            Scope::Required,
+        );
+        readline.components = Some(Components(vec![component(
+            "termcap",
+            LicenseChoice::License(License::named_license("Proprietary")),
+            Scope::Excluded,
+        )]));
+        let mut serde = component(
+            "serde",
+            LicenseChoice::Expression(SpdxExpression("MIT OR Apache-2.0".to_string())),
+            Scope::Required,
+        );
+        serde.properties = Some(Properties(vec![Property::new("cdx:rustc:kind", "lib")]));
+
+        let mut vulnerability = Vulnerability::new(Some("vuln".to_string()));
+        vulnerability.id = Some(NormalizedString::new("RUSTSEC-2023-0001"));
+        vulnerability.vulnerability_ratings =
+            Some(VulnerabilityRatings(vec![VulnerabilityRating::new(
+                None,
+                Some(Severity::Critical),
+                None,
+            )]));
+        vulnerability.vulnerability_targets =
+            Some(VulnerabilityTargets(vec![VulnerabilityTarget::new(
+                "readline".to_string(),
+            )]));
+
+        Bom {
+            components: Some(Components(vec![readline, serde])),
+            services: Some(Services(vec![Service {
+                services: Some(Services(vec![Service::new("auth", None)])),
+                ..Service::new("api", Some("api".to_string()))
+            }])),
+            vulnerabilities: Some(Vulnerabilities(vec![vulnerability])),
+            ..Bom::default()
+        }
+    }
+
+    fn select(bom: &Bom, selector: &str) -> Vec<String> {
+        bom.select(selector)
+            .unwrap()
+            .bom_refs()
+            .into_iter()
+            .map(String::from)
+            .collect()
+    }
+
+    #[test]
+    fn it_should_select_components_with_a_builder() {
+        let bom = bom();
+
+        let selected = bom.select_components(
+            &Field::License
+                .contains("GPL")
+                .or(Field::License.eq("Proprietary"))
+                .and(!Field::Scope.eq("excluded")),
+        );
+        assert_eq!(selected.len(), 1);
+        assert_eq!(selected[0].name.to_string(), "readline");
+
+        let selected =
+            bom.select_components(&Field::Property("cdx:rustc:kind".to_string()).exists());
+        assert_eq!(selected[0].name.to_string(), "serde");
+    }
+
+    #[test]
+    fn it_should_select_with_a_selector() {
+        let bom = bom();
+
+        assert_eq!(
+            select(&bom, "components"),
+            vec!["readline", "termcap", "serde"]
+        );
+        assert_eq!(
+            select(&bom, "components[?licenses contains 'GPL-3.0-only']"),
+            vec!["readline"]
+        );
+        assert_eq!(
+            select(
+                &bom,
+                r#"components[? scope != "excluded" && !(name == 'serde' || licenses starts_with 'GPL') ]"#
+            ),
+            Vec::<String>::new()
+        );
+        assert_eq!(
+            select(
+                &bom,
+                "components[?properties.cdx:rustc:kind == 'lib' || scope == 'excluded']"
+            ),
+            vec!["termcap", "serde"]
+        );
+        assert_eq!(select(&bom, "services[?bom-ref]"), vec!["api"]);
+        assert_eq!(bom.select("services[?!bom-ref]").unwrap().len(), 1);
+        assert_eq!(
+            select(
+                &bom,
+                "vulnerabilities[?severity == 'critical' && affects == 'readline']"
+            ),
+            vec!["vuln"]
+        );
+        assert!(bom
+            .select("vulnerabilities[?name == 'readline']")
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn it_should_group_nested_predicates() {
+        let selector: Selector = "components[?(name == 'a' || name == 'b') && version]"
+            .parse()
+            .unwrap();
+
+        assert_eq!(
+            selector.predicate,
+            Some(Predicate::All(vec![
+                Predicate::Any(vec![Field::Name.eq("a"), Field::Name.eq("b")]),
+                Field::Version.exists(),
+            ]))
+        );
+    }
+
+    #[test]
+    fn it_should_reject_invalid_selectors() {
+        let parse = |selector: &str| selector.parse::<Selector>().unwrap_err();
+
+        assert_eq!(
+            parse("packages"),
+            QueryError::UnknownCollection("packages".to_string())
+        );
+        assert_eq!(
+            parse("components[?license == 'MIT']"),
+            QueryError::UnknownField("license".to_string())
+        );
+        assert_eq!(
+            parse("components[?name == serde]"),
+            QueryError::UnexpectedToken {
+                position: 20,
+                found: "serde".to_string(),
+                expected: "a quoted value".to_string(),
+            }
+        );
+        assert_eq!(
+            parse("components[?name == 'serde'"),
+            QueryError::UnexpectedEnd {
+                expected: "']'".to_string(),
+            }
+        );
+        assert_eq!(
+            parse("components[?name == 'serde]"),
+            QueryError::UnterminatedLiteral { position: 20 }
+        );
+    }
+}
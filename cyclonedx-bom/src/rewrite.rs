@@ -0,0 +1,374 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Changes the version of a component in a BOM, e.g. after backporting a patch or vendoring a fork.
+//!
+//! The version, the purl and the hashes of a component all describe the same artifact,
+//! so [`rewrite_component`] updates them together. The bom-ref often encodes the version as well;
+//! it is only changed on request, because other documents, e.g. VEX files, may refer to it.
+//!
+//! ```
+//! use cyclonedx_bom::models::bom::Bom;
+//! use cyclonedx_bom::rewrite::{rewrite_component, ComponentRewrite};
+//!
+//! # let mut bom = Bom::parse_from_json(r#"{
+//! #   "bomFormat": "CycloneDX", "specVersion": "1.5", "version": 1,
+//! #   "components": [{
+//! #     "type": "library", "bom-ref": "pkg:cargo/openssl@0.10.55", "name": "openssl",
+//! #     "version": "0.10.55", "purl": "pkg:cargo/openssl@0.10.55"
+//! #   }]
+//! # }"#.as_bytes()).unwrap();
+//! let rewrite = ComponentRewrite::new("0.10.55-patched").with_renamed_refs();
+//! let bom_ref = rewrite_component(&mut bom, "pkg:cargo/openssl@0.10.55", &rewrite).unwrap();
+//! assert_eq!(bom_ref, "pkg:cargo/openssl@0.10.55-patched");
+//! ```
+
+use std::collections::HashSet;
+use std::str::FromStr;
+
+use packageurl::PackageUrl;
+
+use crate::errors::RewriteError;
+use crate::external_models::normalized_string::NormalizedString;
+use crate::external_models::uri::Purl;
+use crate::models::annotation::AnnotationSubject;
+use crate::models::bom::Bom;
+use crate::models::composition::BomReference;
+use crate::models::hash::Hashes;
+
+/// The new version of a component, applied by [`rewrite_component`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ComponentRewrite {
+    /// The new version, also written into the purl of the component
+    pub version: String,
+    /// The hashes of the new artifact. The hashes of the old artifact are removed if this is `None`.
+    pub hashes: Option<Hashes>,
+    /// Whether the version in the bom-ref of the component is replaced as well,
+    /// together with all references to it within the BOM
+    pub rename_refs: bool,
+}
+
+impl ComponentRewrite {
+    pub fn new(version: impl Into<String>) -> Self {
+        Self {
+            version: version.into(),
+            hashes: None,
+            rename_refs: false,
+        }
+    }
+
+    /// Sets the hashes of the new artifact
+    pub fn with_hashes(mut self, hashes: Hashes) -> Self {
+        self.hashes = Some(hashes);
+        self
+    }
+
+    /// Replaces the version in the bom-ref of the component and all references to it
+    pub fn with_renamed_refs(mut self) -> Self {
+        self.rename_refs = true;
+        self
+    }
+}
+
+/// Sets the version, purl and hashes of the component with the given bom-ref,
+/// and returns the bom-ref of the component afterwards.
+///
+/// The bom-ref is only renamed if [`ComponentRewrite::rename_refs`] is set and it encodes the old version,
+/// either because it is the purl of the component or because it contains `@<version>`.
+/// The dependencies, compositions, vulnerability targets and annotations that refer to the component
+/// are updated to the new bom-ref.
+///
+/// The BOM is left unchanged if an error is returned.
+pub fn rewrite_component(
+    bom: &mut Bom,
+    bom_ref: &str,
+    rewrite: &ComponentRewrite,
+) -> Result<String, RewriteError> {
+    let mut found = None;
+    let mut used_refs = HashSet::new();
+    bom.map_components(|component| {
+        if component.bom_ref.as_deref() == Some(bom_ref) && found.is_none() {
+            found = Some((component.version.clone(), component.purl.clone()));
+        }
+        used_refs.extend(component.bom_ref.clone());
+    });
+    bom.map_services(|service| used_refs.extend(service.bom_ref.clone()));
+
+    let (old_version, old_purl) = found.ok_or_else(|| RewriteError::ComponentNotFound {
+        bom_ref: bom_ref.to_string(),
+    })?;
+
+    let new_purl = old_purl
+        .as_ref()
+        .map(|purl| purl_with_version(purl, &rewrite.version))
+        .transpose()?;
+
+    let mut new_ref = bom_ref.to_string();
+    if rewrite.rename_refs {
+        if let (Some(old_purl), Some(new_purl)) = (&old_purl, &new_purl) {
+            if old_purl.0 == bom_ref {
+                new_ref = new_purl.0.clone();
+            }
+        }
+        if let Some(old_version) = old_version.as_ref().filter(|_| new_ref == bom_ref) {
+            if let Some(renamed) = ref_with_version(bom_ref, old_version, &rewrite.version) {
+                new_ref = renamed;
+            }
+        }
+        if new_ref != bom_ref && used_refs.contains(&new_ref) {
+            return Err(RewriteError::DuplicateBomRef { bom_ref: new_ref });
+        }
+    }
+
+    let mut updated = false;
+    bom.map_components(|component| {
+        if component.bom_ref.as_deref() == Some(bom_ref) && !updated {
+            updated = true;
+            component.bom_ref = Some(new_ref.clone());
+            component.version = Some(NormalizedString::new(&rewrite.version));
+            component.purl = new_purl.clone();
+            component.hashes = rewrite.hashes.clone();
+        }
+    });
+
+    if new_ref != bom_ref {
+        rename_refs(bom, bom_ref, &new_ref);
+    }
+
+    Ok(new_ref)
+}
+
+fn purl_with_version(purl: &Purl, version: &str) -> Result<Purl, RewriteError> {
+    let mut parsed = PackageUrl::from_str(&purl.0).map_err(|e| RewriteError::InvalidPurl {
+        purl: purl.0.clone(),
+        error: e.to_string(),
+    })?;
+    parsed.with_version(version.to_string());
+    Ok(Purl(parsed.to_string()))
+}
+
+/// Replaces the last `@<old>` in the bom-ref that is followed by its end or a purl or URL delimiter
+fn ref_with_version(bom_ref: &str, old: &NormalizedString, new: &str) -> Option<String> {
+    let pattern = format!("@{}", old);
+    bom_ref
+        .rmatch_indices(&pattern)
+        .map(|(index, _)| index)
+        .find(|index| {
+            bom_ref[index + pattern.len()..]
+                .chars()
+                .next()
+                .map_or(true, |next| matches!(next, '?' | '#' | '/'))
+        })
+        .map(|index| {
+            format!(
+                "{}@{}{}",
+                &bom_ref[..index],
+                new,
+                &bom_ref[index + pattern.len()..]
+            )
+        })
+}
+
+fn rename_refs(bom: &mut Bom, old: &str, new: &str) {
+    let rename = |bom_ref: &mut String| {
+        if bom_ref == old {
+            *bom_ref = new.to_string();
+        }
+    };
+
+    for dependency in bom.dependencies.iter_mut().flat_map(|d| d.0.iter_mut()) {
+        rename(&mut dependency.dependency_ref);
+        dependency.dependencies.iter_mut().for_each(rename);
+    }
+
+    for composition in bom.compositions.iter_mut().flat_map(|c| c.0.iter_mut()) {
+        for BomReference(bom_ref) in composition
+            .assemblies
+            .iter_mut()
+            .chain(composition.dependencies.iter_mut())
+            .flatten()
+        {
+            rename(bom_ref);
+        }
+    }
+
+    for target in bom
+        .vulnerabilities
+        .iter_mut()
+        .flat_map(|v| v.0.iter_mut())
+        .flat_map(|v| v.vulnerability_targets.iter_mut())
+        .flat_map(|t| t.0.iter_mut())
+    {
+        rename(&mut target.bom_ref);
+    }
+
+    for subject in bom
+        .annotations
+        .iter_mut()
+        .flat_map(|a| a.0.iter_mut())
+        .flat_map(|a| a.subjects.iter_mut())
+    {
+        if let AnnotationSubject::BomReference(BomReference(bom_ref)) = subject {
+            rename(bom_ref);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    use crate::models::component::{Classification, Component, Components};
+    use crate::models::dependency::{Dependencies, Dependency};
+    use crate::models::hash::{Hash, HashAlgorithm, HashValue};
+
+    fn component(name: &str, version: &str) -> Component {
+        let purl = Purl::new("cargo", name, version).unwrap();
+        let mut component = Component::new(
+            Classification::Library,
+            name,
+            version,
+            Some(purl.to_string()),
+        );
+        component.purl = Some(purl);
+        component.hashes = Some(Hashes(vec![Hash {
+            alg: HashAlgorithm::SHA256,
+            content: HashValue("0".repeat(64)),
+        }]));
+        component
+    }
+
+    fn bom() -> Bom {
+        Bom {
+            components: Some(Components(vec![
+                component("app", "1.0.0"),
+                component("openssl", "0.10.55"),
+            ])),
+            dependencies: Some(Dependencies(vec![Dependency {
+                dependency_ref: "pkg:cargo/app@1.0.0".to_string(),
+                dependencies: vec!["pkg:cargo/openssl@0.10.55".to_string()],
+            }])),
+            ..Bom::default()
+        }
+    }
+
+    #[test]
+    fn it_should_rewrite_version_purl_and_hashes() {
+        let mut bom = bom();
+        let hashes = Hashes(vec![Hash {
+            alg: HashAlgorithm::SHA256,
+            content: HashValue("1".repeat(64)),
+        }]);
+        let rewrite = ComponentRewrite::new("0.10.56").with_hashes(hashes.clone());
+
+        let bom_ref = rewrite_component(&mut bom, "pkg:cargo/openssl@0.10.55", &rewrite).unwrap();
+
+        assert_eq!(bom_ref, "pkg:cargo/openssl@0.10.55");
+        let openssl = &bom.components.as_ref().unwrap().0[1];
+        assert_eq!(openssl.version, Some(NormalizedString::new("0.10.56")));
+        assert_eq!(
+            openssl.purl,
+            Some(Purl("pkg:cargo/openssl@0.10.56".to_string()))
+        );
+        assert_eq!(openssl.hashes, Some(hashes));
+        assert_eq!(bom.dependencies, self::bom().dependencies);
+    }
+
+    #[test]
+    fn it_should_rename_refs_that_encode_the_version() {
+        let mut bom = bom();
+        let rewrite = ComponentRewrite::new("0.10.56").with_renamed_refs();
+
+        let bom_ref = rewrite_component(&mut bom, "pkg:cargo/openssl@0.10.55", &rewrite).unwrap();
+
+        assert_eq!(bom_ref, "pkg:cargo/openssl@0.10.56");
+        let openssl = &bom.components.as_ref().unwrap().0[1];
+        assert_eq!(
+            openssl.bom_ref.as_deref(),
+            Some("pkg:cargo/openssl@0.10.56")
+        );
+        assert_eq!(openssl.hashes, None);
+        assert_eq!(
+            bom.dependencies.unwrap().0[0].dependencies,
+            vec!["pkg:cargo/openssl@0.10.56".to_string()]
+        );
+    }
+
+    #[test]
+    fn it_should_replace_the_version_in_other_refs() {
+        assert_eq!(
+            ref_with_version(
+                "path+file:///src/openssl#openssl@0.10.55",
+                &NormalizedString::new("0.10.55"),
+                "0.10.56"
+            ),
+            Some("path+file:///src/openssl#openssl@0.10.56".to_string())
+        );
+        assert_eq!(
+            ref_with_version(
+                "openssl@0.10.555",
+                &NormalizedString::new("0.10.55"),
+                "0.10.56"
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn it_should_leave_the_bom_unchanged_on_errors() {
+        let mut bom = bom();
+
+        let missing = rewrite_component(&mut bom, "missing", &ComponentRewrite::new("2.0.0"));
+        assert_eq!(
+            missing,
+            Err(RewriteError::ComponentNotFound {
+                bom_ref: "missing".to_string()
+            })
+        );
+
+        bom.components
+            .as_mut()
+            .unwrap()
+            .0
+            .push(component("openssl", "0.10.56"));
+        let before = bom.clone();
+        let duplicate = rewrite_component(
+            &mut bom,
+            "pkg:cargo/openssl@0.10.55",
+            &ComponentRewrite::new("0.10.56").with_renamed_refs(),
+        );
+        assert_eq!(
+            duplicate,
+            Err(RewriteError::DuplicateBomRef {
+                bom_ref: "pkg:cargo/openssl@0.10.56".to_string()
+            })
+        );
+        assert_eq!(bom, before);
+
+        bom.components.as_mut().unwrap().0[1].purl = Some(Purl("invalid purl".to_string()));
+        let before = bom.clone();
+        let invalid = rewrite_component(
+            &mut bom,
+            "pkg:cargo/openssl@0.10.55",
+            &ComponentRewrite::new("2.0.0"),
+        );
+        assert!(matches!(invalid, Err(RewriteError::InvalidPurl { .. })));
+        assert_eq!(bom, before);
+    }
+}
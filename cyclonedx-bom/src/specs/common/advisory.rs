@@ -16,22 +16,26 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
+#[cfg(feature = "xml")]
 use crate::{
     errors::XmlReadError,
-    external_models::{normalized_string::NormalizedString, uri::Uri},
-    models,
-    utilities::convert_vec,
     xml::{
         read_lax_validation_list_tag, read_lax_validation_tag, read_simple_tag, to_xml_read_error,
         to_xml_write_error, unexpected_element_error, write_simple_tag, FromXml, ToXml,
     },
 };
+use crate::{
+    external_models::{normalized_string::NormalizedString, uri::Uri},
+    models,
+    utilities::convert_vec,
+};
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "xml")]
 use xml::{reader, writer::XmlEvent};
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(transparent)]
-pub(crate) struct Advisories(Vec<Advisory>);
+pub struct Advisories(pub Vec<Advisory>);
 
 impl From<models::advisory::Advisories> for Advisories {
     fn from(other: models::advisory::Advisories) -> Self {
@@ -47,6 +51,7 @@ impl From<Advisories> for models::advisory::Advisories {
 
 const ADVISORIES_TAG: &str = "advisories";
 
+#[cfg(feature = "xml")]
 impl ToXml for Advisories {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -68,6 +73,7 @@ impl ToXml for Advisories {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for Advisories {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -83,10 +89,10 @@ impl FromXml for Advisories {
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
-pub(crate) struct Advisory {
+pub struct Advisory {
     #[serde(skip_serializing_if = "Option::is_none")]
-    title: Option<String>,
-    url: String,
+    pub title: Option<String>,
+    pub url: String,
 }
 
 impl From<models::advisory::Advisory> for Advisory {
@@ -111,6 +117,7 @@ const ADVISORY_TAG: &str = "advisory";
 const TITLE_TAG: &str = "title";
 const URL_TAG: &str = "url";
 
+#[cfg(feature = "xml")]
 impl ToXml for Advisory {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -136,6 +143,7 @@ impl ToXml for Advisory {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for Advisory {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -185,6 +193,7 @@ impl FromXml for Advisory {
 #[cfg(test)]
 pub(crate) mod test {
     use super::*;
+    #[cfg(feature = "xml")]
     use crate::xml::test::{read_element_from_string, write_element_to_string};
 
     pub(crate) fn example_advisories() -> Advisories {
@@ -209,12 +218,14 @@ pub(crate) mod test {
         }
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_write_xml_full() {
         let xml_output = write_element_to_string(example_advisories());
         insta::assert_snapshot!(xml_output);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_xml_full() {
         let input = r#"
@@ -16,23 +16,27 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
+use crate::external_models::mime_type::MimeType;
+use crate::models;
+#[cfg(feature = "xml")]
+use crate::xml::to_xml_write_error;
+#[cfg(feature = "xml")]
 use crate::{
     errors::XmlWriteError,
-    external_models::normalized_string::NormalizedString,
     xml::{closing_tag_or_error, inner_text_or_error, to_xml_read_error, FromXml, ToInnerXml},
 };
-use crate::{models, xml::to_xml_write_error};
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "xml")]
 use xml::writer::{EventWriter, XmlEvent};
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
-pub(crate) struct AttachedText {
+pub struct AttachedText {
     #[serde(skip_serializing_if = "Option::is_none")]
-    content_type: Option<String>,
+    pub content_type: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    encoding: Option<String>,
-    content: String,
+    pub encoding: Option<String>,
+    pub content: String,
 }
 
 impl From<models::attached_text::AttachedText> for AttachedText {
@@ -48,7 +52,7 @@ impl From<models::attached_text::AttachedText> for AttachedText {
 impl From<AttachedText> for models::attached_text::AttachedText {
     fn from(other: AttachedText) -> Self {
         Self {
-            content_type: other.content_type.map(NormalizedString::new_unchecked),
+            content_type: other.content_type.map(MimeType),
             encoding: other
                 .encoding
                 .map(models::attached_text::Encoding::new_unchecked),
@@ -60,6 +64,7 @@ impl From<AttachedText> for models::attached_text::AttachedText {
 const CONTENT_TYPE_ATTR: &str = "content-type";
 const ENCODING_ATTR: &str = "encoding";
 
+#[cfg(feature = "xml")]
 impl ToInnerXml for AttachedText {
     fn write_xml_named_element<W: std::io::Write>(
         &self,
@@ -90,6 +95,7 @@ impl ToInnerXml for AttachedText {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for AttachedText {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -131,6 +137,7 @@ impl FromXml for AttachedText {
 #[cfg(test)]
 pub(crate) mod test {
     use super::*;
+    #[cfg(feature = "xml")]
     use crate::xml::test::{read_element_from_string, write_named_element_to_string};
 
     pub(crate) fn example_attached_text() -> AttachedText {
@@ -143,7 +150,7 @@ pub(crate) mod test {
 
     pub(crate) fn corresponding_attached_text() -> models::attached_text::AttachedText {
         models::attached_text::AttachedText {
-            content_type: Some(NormalizedString::new_unchecked("content type".to_string())),
+            content_type: Some(MimeType("content type".to_string())),
             encoding: Some(models::attached_text::Encoding::UnknownEncoding(
                 "encoding".to_string(),
             )),
@@ -151,12 +158,14 @@ pub(crate) mod test {
         }
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_write_xml_full() {
         let xml_output = write_named_element_to_string(example_attached_text(), "text");
         insta::assert_snapshot!(xml_output);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_write_xml_no_attributes() {
         let xml_output = write_named_element_to_string(
@@ -170,6 +179,7 @@ pub(crate) mod test {
         insta::assert_snapshot!(xml_output);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_xml_full() {
         let input = r#"
@@ -180,6 +190,7 @@ pub(crate) mod test {
         assert_eq!(actual, expected);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_xml_no_attributes() {
         let input = r#"
@@ -16,24 +16,28 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
+#[cfg(feature = "xml")]
 use crate::{
     errors::XmlReadError,
-    models,
-    utilities::{convert_optional, convert_optional_vec, convert_vec},
     xml::{
         attribute_or_error, closing_tag_or_error, read_lax_validation_list_tag, read_simple_tag,
         to_xml_read_error, to_xml_write_error, unexpected_element_error, write_simple_tag, FromXml,
         ToInnerXml, ToXml,
     },
 };
+use crate::{
+    models,
+    utilities::{convert_optional, convert_optional_vec, convert_vec},
+};
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "xml")]
 use xml::{reader, writer::XmlEvent};
 
 use super::signature::Signature;
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(transparent)]
-pub(crate) struct Compositions(Vec<Composition>);
+pub struct Compositions(pub Vec<Composition>);
 
 impl From<models::composition::Compositions> for Compositions {
     fn from(other: models::composition::Compositions) -> Self {
@@ -49,6 +53,7 @@ impl From<Compositions> for models::composition::Compositions {
 
 const COMPOSITIONS_TAG: &str = "compositions";
 
+#[cfg(feature = "xml")]
 impl ToXml for Compositions {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -69,6 +74,7 @@ impl ToXml for Compositions {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for Compositions {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -84,14 +90,14 @@ impl FromXml for Compositions {
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
-pub(crate) struct Composition {
-    aggregate: String,
+pub struct Composition {
+    pub aggregate: String,
     #[serde(skip_serializing_if = "Option::is_none")]
-    assemblies: Option<Vec<BomReference>>,
+    pub assemblies: Option<Vec<BomReference>>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    dependencies: Option<Vec<BomReference>>,
+    pub dependencies: Option<Vec<BomReference>>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    signature: Option<Signature>,
+    pub signature: Option<Signature>,
 }
 
 impl From<models::composition::Composition> for Composition {
@@ -124,6 +130,7 @@ const DEPENDENCIES_TAG: &str = "dependencies";
 const DEPENDENCY_TAG: &str = "dependency";
 const SIGNATURE_TAG: &str = "signature";
 
+#[cfg(feature = "xml")]
 impl ToXml for Composition {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -175,6 +182,7 @@ impl ToXml for Composition {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for Composition {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -247,7 +255,7 @@ impl FromXml for Composition {
 }
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
-struct BomReference(String);
+pub struct BomReference(pub String);
 
 impl From<models::composition::BomReference> for BomReference {
     fn from(other: models::composition::BomReference) -> Self {
@@ -263,6 +271,7 @@ impl From<BomReference> for models::composition::BomReference {
 
 const REF_ATTR: &str = "ref";
 
+#[cfg(feature = "xml")]
 impl ToInnerXml for BomReference {
     fn write_xml_named_element<W: std::io::Write>(
         &self,
@@ -281,6 +290,7 @@ impl ToInnerXml for BomReference {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for BomReference {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -302,10 +312,9 @@ impl FromXml for BomReference {
 
 #[cfg(test)]
 pub(crate) mod test {
-    use crate::{
-        specs::v1_4::signature::test::{corresponding_signature, example_signature},
-        xml::test::{read_element_from_string, write_element_to_string},
-    };
+    use crate::specs::common::signature::test::{corresponding_signature, example_signature};
+    #[cfg(feature = "xml")]
+    use crate::xml::test::{read_element_from_string, write_element_to_string};
 
     use super::*;
 
@@ -341,12 +350,14 @@ pub(crate) mod test {
         }
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_write_xml_full() {
         let xml_output = write_element_to_string(example_compositions());
         insta::assert_snapshot!(xml_output);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_xml_full() {
         let input = r#"
@@ -16,19 +16,21 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
+use crate::models;
+#[cfg(feature = "xml")]
 use crate::{
     errors::{XmlReadError, XmlWriteError},
-    models,
     xml::{
         attribute_or_error, closing_tag_or_error, read_list_tag, to_xml_read_error,
         to_xml_write_error, unexpected_element_error, FromXml, ToXml,
     },
 };
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "xml")]
 use xml::{reader, writer::XmlEvent};
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
-pub(crate) struct Dependencies(Vec<Dependency>);
+pub struct Dependencies(pub Vec<Dependency>);
 
 impl From<models::dependency::Dependencies> for Dependencies {
     fn from(other: models::dependency::Dependencies) -> Self {
@@ -44,6 +46,7 @@ impl From<Dependencies> for models::dependency::Dependencies {
 
 const DEPENDENCIES_TAG: &str = "dependencies";
 
+#[cfg(feature = "xml")]
 impl ToXml for Dependencies {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -65,6 +68,7 @@ impl ToXml for Dependencies {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for Dependencies {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -80,11 +84,11 @@ impl FromXml for Dependencies {
 
 #[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Hash)]
 #[serde(rename_all = "camelCase")]
-pub(crate) struct Dependency {
+pub struct Dependency {
     #[serde(rename = "ref")]
-    dependency_ref: String,
+    pub dependency_ref: String,
     #[serde(default)]
-    depends_on: Vec<String>,
+    pub depends_on: Vec<String>,
 }
 
 impl From<Dependency> for models::dependency::Dependency {
@@ -108,6 +112,7 @@ impl From<models::dependency::Dependency> for Dependency {
 const DEPENDENCY_TAG: &str = "dependency";
 const REF_ATTR: &str = "ref";
 
+#[cfg(feature = "xml")]
 impl ToXml for Dependency {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -135,6 +140,7 @@ impl ToXml for Dependency {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for Dependency {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -180,6 +186,7 @@ impl FromXml for Dependency {
 #[cfg(test)]
 pub(crate) mod test {
     use super::*;
+    #[cfg(feature = "xml")]
     use crate::xml::test::{read_element_from_string, write_element_to_string};
 
     pub(crate) fn example_dependencies() -> Dependencies {
@@ -211,18 +218,21 @@ pub(crate) mod test {
         assert_eq!(actual, expected);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_write_xml_full() {
         let xml_output = write_element_to_string(example_dependencies());
         insta::assert_snapshot!(xml_output);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_write_xml_empty_dependencies() {
         let xml_output = write_element_to_string(Dependencies(Vec::new()));
         insta::assert_snapshot!(xml_output);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_write_xml_dependencies_with_no_children() {
         let xml_output = write_element_to_string(Dependencies(vec![Dependency {
@@ -232,6 +242,7 @@ pub(crate) mod test {
         insta::assert_snapshot!(xml_output);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_xml_full() {
         let input = r#"
@@ -246,6 +257,7 @@ pub(crate) mod test {
         assert_eq!(actual, expected);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_xml_empty_dependencies() {
         let input = r#"
@@ -256,6 +268,7 @@ pub(crate) mod test {
         assert_eq!(actual, expected);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_xml_dependencies_with_no_children() {
         let input = r#"
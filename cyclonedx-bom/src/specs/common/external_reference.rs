@@ -16,9 +16,13 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
+use crate::external_models::uri::Uri;
+use crate::specs::common::hash::Hashes;
+#[cfg(feature = "xml")]
+use crate::xml::to_xml_write_error;
+#[cfg(feature = "xml")]
 use crate::{
     errors::XmlReadError,
-    external_models::uri::Uri,
     xml::{
         attribute_or_error, read_list_tag, read_simple_tag, to_xml_read_error,
         unexpected_element_error, write_simple_tag, FromXml, ToXml,
@@ -28,13 +32,13 @@ use crate::{
     models,
     utilities::{convert_optional, convert_vec},
 };
-use crate::{specs::v1_4::hash::Hashes, xml::to_xml_write_error};
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "xml")]
 use xml::{reader, writer::XmlEvent};
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(transparent)]
-pub(crate) struct ExternalReferences(Vec<VulnerabilityReference>);
+pub struct ExternalReferences(pub Vec<VulnerabilityReference>);
 
 impl From<models::external_reference::ExternalReferences> for ExternalReferences {
     fn from(other: models::external_reference::ExternalReferences) -> Self {
@@ -50,6 +54,7 @@ impl From<ExternalReferences> for models::external_reference::ExternalReferences
 
 const EXTERNAL_REFERENCES_TAG: &str = "externalReferences";
 
+#[cfg(feature = "xml")]
 impl ToXml for ExternalReferences {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -70,6 +75,7 @@ impl ToXml for ExternalReferences {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for ExternalReferences {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -85,14 +91,14 @@ impl FromXml for ExternalReferences {
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
-pub(crate) struct VulnerabilityReference {
+pub struct VulnerabilityReference {
     #[serde(rename = "type")]
-    external_reference_type: String,
-    url: String,
+    pub external_reference_type: String,
+    pub url: String,
     #[serde(skip_serializing_if = "Option::is_none")]
-    comment: Option<String>,
+    pub comment: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    hashes: Option<Hashes>,
+    pub hashes: Option<Hashes>,
 }
 
 impl From<models::external_reference::ExternalReference> for VulnerabilityReference {
@@ -125,6 +131,7 @@ const TYPE_ATTR: &str = "type";
 const URL_TAG: &str = "url";
 const COMMENT_TAG: &str = "comment";
 
+#[cfg(feature = "xml")]
 impl ToXml for VulnerabilityReference {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -157,6 +164,7 @@ impl ToXml for VulnerabilityReference {
 
 const HASHES_TAG: &str = "hashes";
 
+#[cfg(feature = "xml")]
 impl FromXml for VulnerabilityReference {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -212,10 +220,9 @@ impl FromXml for VulnerabilityReference {
 #[cfg(test)]
 pub(crate) mod test {
     use super::*;
-    use crate::{
-        specs::v1_4::hash::test::{corresponding_hashes, example_hashes},
-        xml::test::{read_element_from_string, write_element_to_string},
-    };
+    use crate::specs::common::hash::test::{corresponding_hashes, example_hashes};
+    #[cfg(feature = "xml")]
+    use crate::xml::test::{read_element_from_string, write_element_to_string};
 
     pub(crate) fn example_external_references() -> ExternalReferences {
         ExternalReferences(vec![example_external_reference()])
@@ -248,12 +255,14 @@ pub(crate) mod test {
         }
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_write_xml_full() {
         let xml_output = write_element_to_string(example_external_references());
         insta::assert_snapshot!(xml_output);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_xml_full() {
         let input = r#"
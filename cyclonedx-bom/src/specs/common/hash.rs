@@ -16,18 +16,19 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
+#[cfg(feature = "xml")]
 use crate::{
     errors::XmlReadError,
-    models,
-    utilities::convert_vec,
     xml::{attribute_or_error, read_list_tag, read_simple_tag, to_xml_write_error, FromXml, ToXml},
 };
+use crate::{models, utilities::convert_vec};
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "xml")]
 use xml::writer;
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(transparent)]
-pub(crate) struct Hashes(Vec<Hash>);
+pub struct Hashes(pub Vec<Hash>);
 
 impl From<models::hash::Hashes> for Hashes {
     fn from(other: models::hash::Hashes) -> Self {
@@ -43,6 +44,7 @@ impl From<Hashes> for models::hash::Hashes {
 
 const HASHES_TAG: &str = "hashes";
 
+#[cfg(feature = "xml")]
 impl ToXml for Hashes {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -63,6 +65,7 @@ impl ToXml for Hashes {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for Hashes {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -78,9 +81,9 @@ impl FromXml for Hashes {
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
-pub(crate) struct Hash {
-    alg: String,
-    content: HashValue,
+pub struct Hash {
+    pub alg: String,
+    pub content: HashValue,
 }
 
 impl From<models::hash::Hash> for Hash {
@@ -104,6 +107,7 @@ impl From<Hash> for models::hash::Hash {
 const HASH_TAG: &str = "hash";
 const ALG_ATTR: &str = "alg";
 
+#[cfg(feature = "xml")]
 impl ToXml for Hash {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -124,6 +128,7 @@ impl ToXml for Hash {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for Hash {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -144,7 +149,7 @@ impl FromXml for Hash {
 }
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
-pub(crate) struct HashValue(String);
+pub struct HashValue(pub String);
 
 impl From<models::hash::HashValue> for HashValue {
     fn from(other: models::hash::HashValue) -> Self {
@@ -160,6 +165,7 @@ impl From<HashValue> for models::hash::HashValue {
 
 #[cfg(test)]
 pub(crate) mod test {
+    #[cfg(feature = "xml")]
     use crate::xml::test::{read_element_from_string, write_element_to_string};
 
     use super::*;
@@ -186,12 +192,14 @@ pub(crate) mod test {
         }
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_write_xml_full() {
         let xml_output = write_element_to_string(example_hashes());
         insta::assert_snapshot!(xml_output);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_xml_full() {
         let input = r#"
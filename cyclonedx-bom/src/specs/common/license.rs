@@ -16,9 +16,18 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
+#[cfg(feature = "xml")]
 use crate::xml::write_simple_tag;
+#[cfg(feature = "xml")]
 use crate::{
     errors::XmlReadError,
+    xml::{
+        closing_tag_or_error, inner_text_or_error, read_lax_validation_tag, read_simple_tag,
+        to_xml_read_error, to_xml_write_error, unexpected_element_error, FromXml, ToInnerXml,
+        ToXml,
+    },
+};
+use crate::{
     external_models::{
         normalized_string::NormalizedString,
         spdx::{SpdxExpression, SpdxIdentifier},
@@ -26,19 +35,15 @@ use crate::{
     },
     models,
     utilities::convert_vec,
-    xml::{
-        closing_tag_or_error, inner_text_or_error, read_lax_validation_tag, read_simple_tag,
-        to_xml_read_error, to_xml_write_error, unexpected_element_error, FromXml, ToInnerXml,
-        ToXml,
-    },
 };
-use crate::{specs::v1_4::attached_text::AttachedText, utilities::convert_optional};
+use crate::{specs::common::attached_text::AttachedText, utilities::convert_optional};
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "xml")]
 use xml::{name::OwnedName, reader, writer};
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(transparent)]
-pub(crate) struct Licenses(Vec<LicenseChoice>);
+pub struct Licenses(pub Vec<LicenseChoice>);
 
 impl From<models::license::Licenses> for Licenses {
     fn from(other: models::license::Licenses) -> Self {
@@ -54,6 +59,7 @@ impl From<Licenses> for models::license::Licenses {
 
 const LICENSES_TAG: &str = "licenses";
 
+#[cfg(feature = "xml")]
 impl ToXml for Licenses {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -74,6 +80,7 @@ impl ToXml for Licenses {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for Licenses {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -113,23 +120,11 @@ impl FromXml for Licenses {
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
-pub(crate) enum LicenseChoice {
+pub enum LicenseChoice {
     License(License),
     Expression(String),
 }
 
-#[derive(Debug, Deserialize, Serialize, PartialEq)]
-pub(crate) enum Lic {
-    #[serde(rename = "license")]
-    Lic(License),
-}
-
-#[derive(Debug, Deserialize, Serialize, PartialEq)]
-pub(crate) enum Expr {
-    #[serde(rename = "expression")]
-    Expr(String),
-}
-
 impl From<models::license::LicenseChoice> for LicenseChoice {
     fn from(other: models::license::LicenseChoice) -> Self {
         match other {
@@ -150,6 +145,7 @@ impl From<LicenseChoice> for models::license::LicenseChoice {
 
 const EXPRESSION_TAG: &str = "expression";
 
+#[cfg(feature = "xml")]
 impl ToXml for LicenseChoice {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -168,6 +164,7 @@ impl ToXml for LicenseChoice {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for LicenseChoice {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -195,93 +192,15 @@ impl FromXml for LicenseChoice {
     }
 }
 
-impl FromXml for Lic {
-    fn read_xml_element<R: std::io::Read>(
-        event_reader: &mut xml::EventReader<R>,
-        element_name: &OwnedName,
-        _attributes: &[xml::attribute::OwnedAttribute],
-    ) -> Result<Self, XmlReadError>
-    where
-        Self: Sized,
-    {
-        let mut lic: Option<Lic> = None;
-
-        let mut got_end_tag = false;
-        while !got_end_tag {
-            let next_element = event_reader
-                .next()
-                .map_err(to_xml_read_error(&element_name.local_name))?;
-            match next_element {
-                reader::XmlEvent::StartElement { name, .. } if name.local_name == LICENSE_TAG => {
-                    lic = Some(Lic::Lic(License::read_xml_element(
-                        event_reader,
-                        element_name,
-                        _attributes,
-                    )?))
-                }
-                reader::XmlEvent::EndElement { name } if &name == element_name => {
-                    got_end_tag = true;
-                }
-                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
-            }
-        }
-
-        let lic = lic.ok_or_else(|| XmlReadError::RequiredDataMissing {
-            required_field: LICENSE_TAG.to_string(),
-            element: element_name.local_name.to_string(),
-        })?;
-
-        Ok(lic)
-    }
-}
-
-impl FromXml for Expr {
-    fn read_xml_element<R: std::io::Read>(
-        event_reader: &mut xml::EventReader<R>,
-        element_name: &xml::name::OwnedName,
-        _attributes: &[xml::attribute::OwnedAttribute],
-    ) -> Result<Self, XmlReadError>
-    where
-        Self: Sized,
-    {
-        let mut expr: Option<Expr> = None;
-
-        let mut got_end_tag = false;
-        while !got_end_tag {
-            let next_element = event_reader
-                .next()
-                .map_err(to_xml_read_error(&element_name.local_name))?;
-            match next_element {
-                reader::XmlEvent::StartElement { name, .. }
-                    if name.local_name == EXPRESSION_TAG =>
-                {
-                    expr = Some(Expr::Expr(read_simple_tag(event_reader, &name)?))
-                }
-                reader::XmlEvent::EndElement { name } if &name == element_name => {
-                    got_end_tag = true;
-                }
-                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
-            }
-        }
-
-        let expr = expr.ok_or_else(|| XmlReadError::RequiredDataMissing {
-            required_field: EXPRESSION_TAG.to_string(),
-            element: element_name.local_name.to_string(),
-        })?;
-
-        Ok(expr)
-    }
-}
-
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
-pub(crate) struct License {
+pub struct License {
     #[serde(flatten)]
-    license_identifier: LicenseIdentifier,
+    pub license_identifier: LicenseIdentifier,
     #[serde(skip_serializing_if = "Option::is_none")]
-    text: Option<AttachedText>,
+    pub text: Option<AttachedText>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    url: Option<String>,
+    pub url: Option<String>,
 }
 
 impl From<models::license::License> for License {
@@ -308,6 +227,7 @@ const LICENSE_TAG: &str = "license";
 const TEXT_TAG: &str = "text";
 const URL_TAG: &str = "url";
 
+#[cfg(feature = "xml")]
 impl ToXml for License {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -335,6 +255,7 @@ impl ToXml for License {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for License {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -411,7 +332,7 @@ impl FromXml for License {
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
-enum LicenseIdentifier {
+pub enum LicenseIdentifier {
     #[serde(rename = "id")]
     SpdxId(String),
     Name(String),
@@ -438,6 +359,7 @@ impl From<LicenseIdentifier> for models::license::LicenseIdentifier {
 const ID_TAG: &str = "id";
 const NAME_TAG: &str = "name";
 
+#[cfg(feature = "xml")]
 impl ToXml for LicenseIdentifier {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -456,6 +378,7 @@ impl ToXml for LicenseIdentifier {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for LicenseIdentifier {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -503,10 +426,11 @@ impl FromXml for LicenseIdentifier {
 #[cfg(test)]
 pub(crate) mod test {
     use super::*;
+    #[cfg(feature = "xml")]
+    use crate::xml::test::{read_element_from_string, write_element_to_string};
     use crate::{
         external_models::spdx::SpdxExpression,
-        specs::v1_4::attached_text::test::{corresponding_attached_text, example_attached_text},
-        xml::test::{read_element_from_string, write_element_to_string},
+        specs::common::attached_text::test::{corresponding_attached_text, example_attached_text},
     };
 
     pub(crate) fn example_licenses() -> Licenses {
@@ -565,6 +489,7 @@ pub(crate) mod test {
         models::license::LicenseChoice::Expression(SpdxExpression("expression".to_string()))
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_licenses_without_license_choices_correctly() {
         let input = r#"
@@ -577,6 +502,7 @@ pub(crate) mod test {
         assert_eq!(actual, expected);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_write_licenses_without_license_choices_correctly() {
         let xml_output = write_element_to_string(Licenses(vec![]));
@@ -601,6 +527,7 @@ pub(crate) mod test {
         insta::assert_json_snapshot!(actual);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_write_xml_full_license_choice_licenses() {
         let xml_output = write_element_to_string(Licenses(vec![
@@ -610,6 +537,7 @@ pub(crate) mod test {
         insta::assert_snapshot!(xml_output);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_write_xml_full_license_choice_expressions() {
         let xml_output = write_element_to_string(Licenses(vec![
@@ -619,6 +547,7 @@ pub(crate) mod test {
         insta::assert_snapshot!(xml_output);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_xml_full_license_choice_licenses() {
         let input = r#"
@@ -640,6 +569,7 @@ pub(crate) mod test {
         assert_eq!(actual, expected);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_xml_full_license_choice_expressions() {
         let input = r#"
@@ -16,21 +16,25 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
+#[cfg(feature = "xml")]
 use crate::{
     errors::XmlReadError,
-    external_models::{normalized_string::NormalizedString, uri::Uri},
-    models,
-    utilities::{convert_optional, convert_optional_vec, convert_vec},
     xml::{
         attribute_or_error, optional_attribute, read_boolean_tag, read_lax_validation_list_tag,
         read_lax_validation_tag, read_list_tag, read_simple_tag, to_xml_read_error,
         to_xml_write_error, unexpected_element_error, write_simple_tag, FromXml, ToInnerXml, ToXml,
     },
 };
+use crate::{
+    external_models::{normalized_string::NormalizedString, uri::Uri},
+    models,
+    utilities::{convert_optional, convert_optional_vec, convert_vec},
+};
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "xml")]
 use xml::{reader, writer::XmlEvent};
 
-use crate::specs::v1_4::{
+use crate::specs::common::{
     external_reference::ExternalReferences, license::Licenses, organization::OrganizationalEntity,
     property::Properties,
 };
@@ -39,7 +43,7 @@ use super::signature::Signature;
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(transparent)]
-pub(crate) struct Services(Vec<Service>);
+pub struct Services(pub Vec<Service>);
 
 impl From<models::service::Services> for Services {
     fn from(other: models::service::Services) -> Self {
@@ -55,6 +59,7 @@ impl From<Services> for models::service::Services {
 
 const SERVICES_TAG: &str = "services";
 
+#[cfg(feature = "xml")]
 impl ToXml for Services {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -76,6 +81,7 @@ impl ToXml for Services {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for Services {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -91,36 +97,36 @@ impl FromXml for Services {
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
-pub(crate) struct Service {
+pub struct Service {
     #[serde(rename = "bom-ref", skip_serializing_if = "Option::is_none")]
-    bom_ref: Option<String>,
+    pub bom_ref: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    provider: Option<OrganizationalEntity>,
+    pub provider: Option<OrganizationalEntity>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    group: Option<String>,
-    name: String,
+    pub group: Option<String>,
+    pub name: String,
     #[serde(skip_serializing_if = "Option::is_none")]
-    version: Option<String>,
+    pub version: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    description: Option<String>,
+    pub description: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    endpoints: Option<Vec<String>>,
+    pub endpoints: Option<Vec<String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    authenticated: Option<bool>,
+    pub authenticated: Option<bool>,
     #[serde(rename = "x-trust-boundary", skip_serializing_if = "Option::is_none")]
-    x_trust_boundary: Option<bool>,
+    pub x_trust_boundary: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    data: Option<Vec<DataClassification>>,
+    pub data: Option<Vec<DataClassification>>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    licenses: Option<Licenses>,
+    pub licenses: Option<Licenses>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    external_references: Option<ExternalReferences>,
+    pub external_references: Option<ExternalReferences>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    properties: Option<Properties>,
+    pub properties: Option<Properties>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    services: Option<Services>,
+    pub services: Option<Services>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    signature: Option<Signature>,
+    pub signature: Option<Signature>,
 }
 
 impl From<models::service::Service> for Service {
@@ -185,6 +191,7 @@ const X_TRUST_BOUNDARY_TAG: &str = "x-trust-boundary";
 const DATA_TAG: &str = "data";
 const SIGNATURE_TAG: &str = "signature";
 
+#[cfg(feature = "xml")]
 impl ToXml for Service {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -286,6 +293,7 @@ const LICENSES_TAG: &str = "licenses";
 const EXTERNAL_REFERENCES_TAG: &str = "externalReferences";
 const PROPERTIES_TAG: &str = "properties";
 
+#[cfg(feature = "xml")]
 impl FromXml for Service {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -454,9 +462,9 @@ impl FromXml for Service {
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
-struct DataClassification {
-    flow: String,
-    classification: String,
+pub struct DataClassification {
+    pub flow: String,
+    pub classification: String,
 }
 
 impl From<models::service::DataClassification> for DataClassification {
@@ -480,6 +488,7 @@ impl From<DataClassification> for models::service::DataClassification {
 const CLASSIFICATION_TAG: &str = "classification";
 const FLOW_ATTR: &str = "flow";
 
+#[cfg(feature = "xml")]
 impl ToXml for DataClassification {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -501,6 +510,7 @@ impl ToXml for DataClassification {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for DataClassification {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -522,18 +532,17 @@ impl FromXml for DataClassification {
 #[cfg(test)]
 pub(crate) mod test {
     use super::*;
-    use crate::{
-        specs::v1_4::{
-            external_reference::test::{
-                corresponding_external_references, example_external_references,
-            },
-            license::test::{corresponding_licenses, example_licenses},
-            organization::test::{corresponding_entity, example_entity},
-            property::test::{corresponding_properties, example_properties},
-            signature::test::{corresponding_signature, example_signature},
+    use crate::specs::common::{
+        external_reference::test::{
+            corresponding_external_references, example_external_references,
         },
-        xml::test::{read_element_from_string, write_element_to_string},
+        license::test::{corresponding_licenses, example_licenses},
+        organization::test::{corresponding_entity, example_entity},
+        property::test::{corresponding_properties, example_properties},
+        signature::test::{corresponding_signature, example_signature},
     };
+    #[cfg(feature = "xml")]
+    use crate::xml::test::{read_element_from_string, write_element_to_string};
 
     pub(crate) fn example_services() -> Services {
         Services(vec![example_service()])
@@ -597,12 +606,14 @@ pub(crate) mod test {
         }
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_write_xml_full() {
         let xml_output = write_element_to_string(example_services());
         insta::assert_snapshot!(xml_output);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_xml_full() {
         let input = r#"
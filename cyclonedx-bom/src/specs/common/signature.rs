@@ -19,11 +19,13 @@
 use std::str::FromStr;
 
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "xml")]
 use xml::reader;
 
+use crate::models;
+#[cfg(feature = "xml")]
 use crate::{
     errors::XmlReadError,
-    models,
     xml::{
         read_list_tag, read_simple_tag, to_xml_read_error, unexpected_element_error,
         write_close_tag, write_simple_tag, write_start_tag, FromXml, ToXml,
@@ -48,6 +50,7 @@ impl Signer {
     }
 }
 
+#[cfg(feature = "xml")]
 impl ToXml for Signer {
     fn write_xml_element<W: std::io::prelude::Write>(
         &self,
@@ -60,6 +63,7 @@ impl ToXml for Signer {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for Signer {
     fn read_xml_element<R: std::io::prelude::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -243,8 +247,8 @@ impl FromStr for Algorithm {
     }
 }
 
-impl ToString for Algorithm {
-    fn to_string(&self) -> String {
+impl std::fmt::Display for Algorithm {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let s = match self {
             Algorithm::RS256 => "RS256",
             Algorithm::RS384 => "RS384",
@@ -261,7 +265,7 @@ impl ToString for Algorithm {
             Algorithm::HS384 => "HS384",
             Algorithm::HS512 => "HS512",
         };
-        s.to_string()
+        f.write_str(s)
     }
 }
 
@@ -283,6 +287,7 @@ impl From<Algorithm> for models::signature::Algorithm {
     }
 }
 
+#[cfg(feature = "xml")]
 impl ToXml for Signature {
     fn write_xml_element<W: std::io::prelude::Write>(
         &self,
@@ -326,6 +331,7 @@ const SIGNATURE_TAG: &str = "signature";
 const ALGORITHM_TAG: &str = "algorithm";
 const VALUE_TAG: &str = "value";
 
+#[cfg(feature = "xml")]
 impl FromXml for Signature {
     fn read_xml_element<R: std::io::prelude::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -401,12 +407,12 @@ impl FromXml for Signature {
 
 #[cfg(test)]
 pub(crate) mod test {
+    #[cfg(feature = "xml")]
     use xml::{name::OwnedName, EmitterConfig, EventReader, EventWriter, ParserConfig};
 
-    use crate::{
-        models,
-        xml::{test::read_element_from_string, FromXml, ToXml},
-    };
+    use crate::models;
+    #[cfg(feature = "xml")]
+    use crate::xml::{test::read_element_from_string, FromXml, ToXml};
 
     use super::{Algorithm, Signature};
 
@@ -418,12 +424,14 @@ pub(crate) mod test {
         models::signature::Signature::single(models::signature::Algorithm::HS512, "1234567890")
     }
 
+    #[cfg(feature = "xml")]
     #[track_caller]
     fn assert_valid_signature(input: &str, expected: Signature) {
         let actual: Signature = read_element_from_string(input);
         assert_eq!(actual, expected);
     }
 
+    #[cfg(feature = "xml")]
     #[track_caller]
     fn assert_invalid_signature(input: &str) {
         let reader = input.to_string();
@@ -435,6 +443,7 @@ pub(crate) mod test {
         assert!(actual.is_err());
     }
 
+    #[cfg(feature = "xml")]
     #[track_caller]
     fn assert_write_xml(signature: Signature, expected_output: &str) {
         let mut writer = Vec::new();
@@ -453,6 +462,7 @@ pub(crate) mod test {
         assert_eq!(actual_output, expected_output);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_valid_signature() {
         let input = r#"
@@ -465,6 +475,7 @@ pub(crate) mod test {
         assert_valid_signature(input, expected);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_shoud_fail_with_missing_value() {
         let input = r#"
@@ -475,6 +486,7 @@ pub(crate) mod test {
         assert_invalid_signature(input);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_fail_with_missing_algorithm() {
         let input = r#"
@@ -485,6 +497,7 @@ pub(crate) mod test {
         assert_invalid_signature(input);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_fail_with_invalid_algorithm() {
         let input = r#"
@@ -496,6 +509,7 @@ pub(crate) mod test {
         assert_invalid_signature(input);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_write_xml_successfully() {
         let expected = r#"
@@ -507,6 +521,7 @@ pub(crate) mod test {
         assert_write_xml(signature, expected);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_write_signature_signers_successfully() {
         let expected = r#"
@@ -530,6 +545,7 @@ pub(crate) mod test {
         assert_write_xml(signature, expected);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_write_signature_chain_successfully() {
         let expected = r#"
@@ -553,6 +569,7 @@ pub(crate) mod test {
         assert_write_xml(signature, expected);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_single_signature() {
         let input = r#"
@@ -566,12 +583,14 @@ pub(crate) mod test {
         assert_eq!(actual, expected);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_fail_to_read_with_empty_signature() {
         let input = r#"<signature></signature>"#;
         assert_invalid_signature(input);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_multiple_signers_signature() {
         let input = r#"
@@ -596,6 +615,7 @@ pub(crate) mod test {
         assert_eq!(actual, expected);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_chain_signature() {
         let input = r#"
@@ -16,27 +16,28 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
+#[cfg(feature = "xml")]
 use crate::{
     errors::XmlReadError,
-    models,
-    utilities::convert_optional_vec,
     xml::{
         read_lax_validation_tag, read_list_tag, to_xml_read_error, to_xml_write_error,
         unexpected_element_error, FromXml, ToInnerXml, ToXml,
     },
 };
+use crate::{models, utilities::convert_optional_vec};
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "xml")]
 use xml::{reader, writer::XmlEvent};
 
-use crate::specs::v1_4::organization::{OrganizationalContact, OrganizationalEntity};
+use crate::specs::common::organization::{OrganizationalContact, OrganizationalEntity};
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
-pub(crate) struct VulnerabilityCredits {
+pub struct VulnerabilityCredits {
     #[serde(skip_serializing_if = "Option::is_none")]
-    organizations: Option<Vec<OrganizationalEntity>>,
+    pub organizations: Option<Vec<OrganizationalEntity>>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    individuals: Option<Vec<OrganizationalContact>>,
+    pub individuals: Option<Vec<OrganizationalContact>>,
 }
 
 impl From<models::vulnerability_credits::VulnerabilityCredits> for VulnerabilityCredits {
@@ -63,6 +64,7 @@ const ORGANIZATION_TAG: &str = "organization";
 const INDIVIDUALS_TAG: &str = "individuals";
 const INDIVIDUAL_TAG: &str = "individual";
 
+#[cfg(feature = "xml")]
 impl ToXml for VulnerabilityCredits {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -112,6 +114,7 @@ impl ToXml for VulnerabilityCredits {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for VulnerabilityCredits {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -163,12 +166,11 @@ impl FromXml for VulnerabilityCredits {
 #[cfg(test)]
 pub(crate) mod test {
     use super::*;
-    use crate::{
-        specs::v1_4::organization::test::{
-            corresponding_contact, corresponding_entity, example_contact, example_entity,
-        },
-        xml::test::{read_element_from_string, write_element_to_string},
+    use crate::specs::common::organization::test::{
+        corresponding_contact, corresponding_entity, example_contact, example_entity,
     };
+    #[cfg(feature = "xml")]
+    use crate::xml::test::{read_element_from_string, write_element_to_string};
 
     pub(crate) fn example_vulnerability_credits() -> VulnerabilityCredits {
         VulnerabilityCredits {
@@ -185,12 +187,14 @@ pub(crate) mod test {
         }
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_write_xml_full() {
         let xml_output = write_element_to_string(example_vulnerability_credits());
         insta::assert_snapshot!(xml_output);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_xml_full() {
         let input = r#"
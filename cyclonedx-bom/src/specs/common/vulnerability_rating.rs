@@ -16,25 +16,29 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
+#[cfg(feature = "xml")]
 use crate::{
     errors::XmlReadError,
-    external_models::normalized_string::NormalizedString,
-    models,
-    utilities::{convert_optional, convert_vec},
     xml::{
         read_lax_validation_list_tag, read_lax_validation_tag, read_simple_tag, to_xml_read_error,
         to_xml_write_error, unexpected_element_error, write_simple_tag, FromXml, FromXmlType,
         ToXml,
     },
 };
+use crate::{
+    external_models::normalized_string::NormalizedString,
+    models,
+    utilities::{convert_optional, convert_vec},
+};
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "xml")]
 use xml::{reader, writer::XmlEvent};
 
-use crate::specs::v1_4::vulnerability_source::VulnerabilitySource;
+use crate::specs::common::vulnerability_source::VulnerabilitySource;
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(transparent)]
-pub(crate) struct VulnerabilityRatings(Vec<VulnerabilityRating>);
+pub struct VulnerabilityRatings(pub Vec<VulnerabilityRating>);
 
 impl From<models::vulnerability_rating::VulnerabilityRatings> for VulnerabilityRatings {
     fn from(other: models::vulnerability_rating::VulnerabilityRatings) -> Self {
@@ -50,6 +54,7 @@ impl From<VulnerabilityRatings> for models::vulnerability_rating::VulnerabilityR
 
 const VULNERABILITY_RATINGS_TAG: &str = "ratings";
 
+#[cfg(feature = "xml")]
 impl ToXml for VulnerabilityRatings {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -71,6 +76,7 @@ impl ToXml for VulnerabilityRatings {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for VulnerabilityRatings {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -88,19 +94,19 @@ impl FromXml for VulnerabilityRatings {
 // todo: check naming, in XML spec its ScoreSourceType, in JSON spec it's ScoreMethod
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
-pub(crate) struct VulnerabilityRating {
+pub struct VulnerabilityRating {
     #[serde(rename = "source", skip_serializing_if = "Option::is_none")]
-    vulnerability_source: Option<VulnerabilitySource>,
+    pub vulnerability_source: Option<VulnerabilitySource>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    score: Option<f32>,
+    pub score: Option<f32>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    severity: Option<Severity>,
+    pub severity: Option<Severity>,
     #[serde(rename = "method", skip_serializing_if = "Option::is_none")]
-    score_method: Option<ScoreMethod>,
+    pub score_method: Option<ScoreMethod>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    vector: Option<String>,
+    pub vector: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    justification: Option<String>,
+    pub justification: Option<String>,
 }
 
 impl From<models::vulnerability_rating::VulnerabilityRating> for VulnerabilityRating {
@@ -137,6 +143,7 @@ const SCORE_METHOD_TAG: &str = "method";
 const VECTOR_TAG: &str = "vector";
 const JUSTIFICATION_TAG: &str = "justification";
 
+#[cfg(feature = "xml")]
 impl ToXml for VulnerabilityRating {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -180,6 +187,7 @@ impl ToXml for VulnerabilityRating {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for VulnerabilityRating {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -263,7 +271,7 @@ impl FromXml for VulnerabilityRating {
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
-struct Severity(String);
+pub struct Severity(pub String);
 
 impl From<models::vulnerability_rating::Severity> for Severity {
     fn from(other: models::vulnerability_rating::Severity) -> Self {
@@ -279,7 +287,7 @@ impl From<Severity> for models::vulnerability_rating::Severity {
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
-struct ScoreMethod(String);
+pub struct ScoreMethod(pub String);
 
 impl From<models::vulnerability_rating::ScoreMethod> for ScoreMethod {
     fn from(other: models::vulnerability_rating::ScoreMethod) -> Self {
@@ -296,12 +304,11 @@ impl From<ScoreMethod> for models::vulnerability_rating::ScoreMethod {
 #[cfg(test)]
 pub(crate) mod test {
     use super::*;
-    use crate::{
-        specs::v1_4::vulnerability_source::test::{
-            corresponding_vulnerability_source, example_vulnerability_source,
-        },
-        xml::test::{read_element_from_string, write_element_to_string},
+    use crate::specs::common::vulnerability_source::test::{
+        corresponding_vulnerability_source, example_vulnerability_source,
     };
+    #[cfg(feature = "xml")]
+    use crate::xml::test::{read_element_from_string, write_element_to_string};
 
     pub(crate) fn example_vulnerability_ratings() -> VulnerabilityRatings {
         VulnerabilityRatings(vec![example_vulnerability_rating()])
@@ -337,12 +344,14 @@ pub(crate) mod test {
         }
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_write_xml_full() {
         let xml_output = write_element_to_string(example_vulnerability_ratings());
         insta::assert_snapshot!(xml_output);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_xml_full() {
         let input = r#"
@@ -16,24 +16,24 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
+#[cfg(feature = "xml")]
 use crate::{
     errors::XmlReadError,
-    external_models::normalized_string::NormalizedString,
-    models,
-    utilities::convert_vec,
     xml::{
         read_lax_validation_list_tag, read_lax_validation_tag, read_simple_tag, to_xml_read_error,
         to_xml_write_error, unexpected_element_error, write_simple_tag, FromXml, ToXml,
     },
 };
+use crate::{external_models::normalized_string::NormalizedString, models, utilities::convert_vec};
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "xml")]
 use xml::{reader, writer::XmlEvent};
 
-use crate::specs::v1_4::vulnerability_source::VulnerabilitySource;
+use crate::specs::common::vulnerability_source::VulnerabilitySource;
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(transparent)]
-pub(crate) struct VulnerabilityReferences(Vec<VulnerabilityReference>);
+pub struct VulnerabilityReferences(pub Vec<VulnerabilityReference>);
 
 impl From<models::vulnerability_reference::VulnerabilityReferences> for VulnerabilityReferences {
     fn from(other: models::vulnerability_reference::VulnerabilityReferences) -> Self {
@@ -49,6 +49,7 @@ impl From<VulnerabilityReferences> for models::vulnerability_reference::Vulnerab
 
 const VULNERABILITY_REFERENCES_TAG: &str = "references";
 
+#[cfg(feature = "xml")]
 impl ToXml for VulnerabilityReferences {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -70,6 +71,7 @@ impl ToXml for VulnerabilityReferences {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for VulnerabilityReferences {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -87,10 +89,10 @@ impl FromXml for VulnerabilityReferences {
 // todo: check spec, in XML spec the fields are optional, in JSON spec the fields are required
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
-pub(crate) struct VulnerabilityReference {
-    id: String,
+pub struct VulnerabilityReference {
+    pub id: String,
     #[serde(rename = "source")]
-    vulnerability_source: VulnerabilitySource,
+    pub vulnerability_source: VulnerabilitySource,
 }
 
 impl From<models::vulnerability_reference::VulnerabilityReference> for VulnerabilityReference {
@@ -117,6 +119,7 @@ const VULNERABILITY_REFERENCE_TAG: &str = "reference";
 const ID_TAG: &str = "id";
 const VULNERABILITY_SOURCE_TAG: &str = "source";
 
+#[cfg(feature = "xml")]
 impl ToXml for VulnerabilityReference {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -141,6 +144,7 @@ impl ToXml for VulnerabilityReference {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for VulnerabilityReference {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -205,12 +209,11 @@ impl FromXml for VulnerabilityReference {
 #[cfg(test)]
 pub(crate) mod test {
     use super::*;
-    use crate::{
-        specs::v1_4::vulnerability_source::test::{
-            corresponding_vulnerability_source, example_vulnerability_source,
-        },
-        xml::test::{read_element_from_string, write_element_to_string},
+    use crate::specs::common::vulnerability_source::test::{
+        corresponding_vulnerability_source, example_vulnerability_source,
     };
+    #[cfg(feature = "xml")]
+    use crate::xml::test::{read_element_from_string, write_element_to_string};
 
     pub(crate) fn example_vulnerability_references() -> VulnerabilityReferences {
         VulnerabilityReferences(vec![example_vulnerability_reference()])
@@ -238,12 +241,14 @@ pub(crate) mod test {
         }
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_write_xml_full() {
         let xml_output = write_element_to_string(example_vulnerability_references());
         insta::assert_snapshot!(xml_output);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_xml_full() {
         let input = r#"
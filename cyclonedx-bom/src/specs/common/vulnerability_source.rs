@@ -16,25 +16,29 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
+#[cfg(feature = "xml")]
 use crate::{
     errors::XmlReadError,
-    external_models::{normalized_string::NormalizedString, uri::Uri},
-    models,
     xml::{
         read_lax_validation_tag, read_simple_tag, to_xml_read_error, to_xml_write_error,
         unexpected_element_error, write_simple_tag, FromXml, ToXml,
     },
 };
+use crate::{
+    external_models::{normalized_string::NormalizedString, uri::Uri},
+    models,
+};
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "xml")]
 use xml::{reader, writer::XmlEvent};
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
-pub(crate) struct VulnerabilitySource {
+pub struct VulnerabilitySource {
     #[serde(skip_serializing_if = "Option::is_none")]
-    name: Option<String>,
+    pub name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    url: Option<String>,
+    pub url: Option<String>,
 }
 
 impl From<models::vulnerability_source::VulnerabilitySource> for VulnerabilitySource {
@@ -59,6 +63,7 @@ const VULNERABILITY_SOURCE_TAG: &str = "source";
 const NAME_TAG: &str = "name";
 const URL_TAG: &str = "url";
 
+#[cfg(feature = "xml")]
 impl ToXml for VulnerabilitySource {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -86,6 +91,7 @@ impl ToXml for VulnerabilitySource {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for VulnerabilitySource {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -133,6 +139,7 @@ impl FromXml for VulnerabilitySource {
 #[cfg(test)]
 pub(crate) mod test {
     use super::*;
+    #[cfg(feature = "xml")]
     use crate::xml::test::{read_element_from_string, write_element_to_string};
 
     pub(crate) fn example_vulnerability_source() -> VulnerabilitySource {
@@ -150,12 +157,14 @@ pub(crate) mod test {
         }
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_write_xml_full() {
         let xml_output = write_element_to_string(example_vulnerability_source());
         insta::assert_snapshot!(xml_output);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_xml_full() {
         let input = r#"
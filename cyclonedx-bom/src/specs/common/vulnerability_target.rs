@@ -16,22 +16,26 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
+#[cfg(feature = "xml")]
 use crate::{
     errors::XmlReadError,
-    external_models::normalized_string::NormalizedString,
-    models,
-    utilities::{convert_optional, convert_vec},
     xml::{
         read_lax_validation_list_tag, read_lax_validation_tag, read_simple_tag, to_xml_read_error,
         to_xml_write_error, unexpected_element_error, write_simple_tag, FromXml, ToXml,
     },
 };
+use crate::{
+    external_models::normalized_string::NormalizedString,
+    models,
+    utilities::{convert_optional, convert_vec},
+};
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "xml")]
 use xml::{reader, writer::XmlEvent};
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(transparent)]
-pub(crate) struct VulnerabilityTargets(Vec<VulnerabilityTarget>);
+pub struct VulnerabilityTargets(pub Vec<VulnerabilityTarget>);
 
 impl From<models::vulnerability_target::VulnerabilityTargets> for VulnerabilityTargets {
     fn from(other: models::vulnerability_target::VulnerabilityTargets) -> Self {
@@ -47,6 +51,7 @@ impl From<VulnerabilityTargets> for models::vulnerability_target::VulnerabilityT
 
 const VULNERABILITY_TARGETS_TAG: &str = "affects";
 
+#[cfg(feature = "xml")]
 impl ToXml for VulnerabilityTargets {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -67,6 +72,7 @@ impl ToXml for VulnerabilityTargets {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for VulnerabilityTargets {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -83,11 +89,11 @@ impl FromXml for VulnerabilityTargets {
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
-pub(crate) struct VulnerabilityTarget {
+pub struct VulnerabilityTarget {
     #[serde(rename = "ref")]
-    bom_ref: String,
+    pub bom_ref: String,
     #[serde(skip_serializing_if = "Option::is_none")]
-    versions: Option<Versions>,
+    pub versions: Option<Versions>,
 }
 
 impl From<models::vulnerability_target::VulnerabilityTarget> for VulnerabilityTarget {
@@ -112,6 +118,7 @@ const VULNERABILITY_TARGET_TAG: &str = "target";
 const REF_TAG: &str = "ref";
 const VERSIONS_TAG: &str = "versions";
 
+#[cfg(feature = "xml")]
 impl ToXml for VulnerabilityTarget {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -145,6 +152,7 @@ impl ToXml for VulnerabilityTarget {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for VulnerabilityTarget {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -199,7 +207,7 @@ impl FromXml for VulnerabilityTarget {
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(transparent)]
-struct Versions(Vec<Version>);
+pub struct Versions(pub Vec<Version>);
 
 impl From<models::vulnerability_target::Versions> for Versions {
     fn from(other: models::vulnerability_target::Versions) -> Self {
@@ -213,6 +221,7 @@ impl From<Versions> for models::vulnerability_target::Versions {
     }
 }
 
+#[cfg(feature = "xml")]
 impl ToXml for Versions {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -236,6 +245,7 @@ impl ToXml for Versions {
 
 const VERSION_TAG: &str = "version";
 
+#[cfg(feature = "xml")]
 impl FromXml for Versions {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -251,10 +261,10 @@ impl FromXml for Versions {
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
-struct Version {
+pub struct Version {
     #[serde(flatten)]
-    version_range: VersionRange,
-    status: Status,
+    pub version_range: VersionRange,
+    pub status: Status,
 }
 
 impl From<models::vulnerability_target::Version> for Version {
@@ -275,6 +285,7 @@ impl From<Version> for models::vulnerability_target::Version {
     }
 }
 
+#[cfg(feature = "xml")]
 impl ToXml for Version {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -297,6 +308,7 @@ impl ToXml for Version {
 
 const RANGE_TAG: &str = "range";
 
+#[cfg(feature = "xml")]
 impl FromXml for Version {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -359,7 +371,7 @@ impl FromXml for Version {
 #[allow(clippy::enum_variant_names)]
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
-enum VersionRange {
+pub enum VersionRange {
     Version(String),
     Range(String),
     UndefinedVersionRange(String),
@@ -387,6 +399,7 @@ impl From<VersionRange> for models::vulnerability_target::VersionRange {
     }
 }
 
+#[cfg(feature = "xml")]
 impl ToXml for VersionRange {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -406,6 +419,7 @@ impl ToXml for VersionRange {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for VersionRange {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -427,7 +441,7 @@ impl FromXml for VersionRange {
 }
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
-struct Status(String);
+pub struct Status(pub String);
 
 impl From<models::vulnerability_target::Status> for Status {
     fn from(other: models::vulnerability_target::Status) -> Self {
@@ -453,6 +467,7 @@ impl From<Status> for models::vulnerability_target::Status {
 
 const STATUS_TAG: &str = "status";
 
+#[cfg(feature = "xml")]
 impl ToXml for Status {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -462,6 +477,7 @@ impl ToXml for Status {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for Status {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -478,10 +494,9 @@ impl FromXml for Status {
 #[cfg(test)]
 pub(crate) mod test {
     use super::*;
-    use crate::{
-        external_models::normalized_string::NormalizedString,
-        xml::test::{read_element_from_string, write_element_to_string},
-    };
+    use crate::external_models::normalized_string::NormalizedString;
+    #[cfg(feature = "xml")]
+    use crate::xml::test::{read_element_from_string, write_element_to_string};
 
     pub(crate) fn example_vulnerability_targets() -> VulnerabilityTargets {
         VulnerabilityTargets(vec![example_vulnerability_target()])
@@ -606,12 +621,14 @@ pub(crate) mod test {
         Status("unknown".to_string())
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn write_xml_versions_defined() {
         let xml_output = write_element_to_string(example_versions_defined());
         insta::assert_snapshot!(xml_output);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn read_xml_versions_defined() {
         let input = r#"
@@ -647,6 +664,7 @@ pub(crate) mod test {
         assert_eq!(actual, expected);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_write_xml_full() {
         let xml_output = write_element_to_string(example_vulnerability_targets());
@@ -654,6 +672,7 @@ pub(crate) mod test {
     }
 
     // todo: check encoding for '>', see '<' => '&lt;'
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_xml_full() {
         let input = r#"
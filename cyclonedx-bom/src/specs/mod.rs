@@ -16,5 +16,20 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
-pub(crate) mod v1_3;
-pub(crate) mod v1_4;
+//! The documents in the exact shape of each version of the specification.
+//!
+//! Unlike the [`models`](crate::models), these types keep every value as it is written in the
+//! document, e.g. enum values as strings, and only contain the fields of their version.
+//! They can be converted from and into the models with `From` and `TryFrom`.
+//! Fields that are not part of these types, e.g. the release notes of a component, are dropped
+//! when reading a document.
+//! Only available with the `specs` feature.
+
+#[cfg(any(feature = "v1_4", feature = "v1_5"))]
+mod common;
+#[cfg(feature = "v1_3")]
+pub mod v1_3;
+#[cfg(feature = "v1_4")]
+pub mod v1_4;
+#[cfg(feature = "v1_5")]
+pub mod v1_5;
@@ -18,50 +18,57 @@
 
 use crate::errors::BomError;
 use crate::models::bom::SpecVersion;
-use crate::{
-    models::{self},
-    utilities::{convert_optional, try_convert_optional},
-    xml::{
-        expected_namespace_or_error, optional_attribute, read_lax_validation_tag,
-        to_xml_read_error, to_xml_write_error, unexpected_element_error, FromXml, FromXmlDocument,
-        FromXmlType,
-    },
+use crate::specs::v1_3::{
+    component::{Component, Components},
+    composition::Compositions,
+    dependency::Dependencies,
+    external_reference::ExternalReferences,
+    metadata::Metadata,
+    property::Properties,
+    service::Services,
+};
+#[cfg(feature = "xml")]
+use crate::xml::ToXml;
+#[cfg(feature = "xml")]
+use crate::xml::{
+    expected_namespace_or_error, optional_attribute, read_lax_validation_tag, to_xml_read_error,
+    to_xml_write_error, unexpected_element_error, FromXml, FromXmlDocument, FromXmlType,
 };
 use crate::{
-    specs::v1_3::{
-        component::Components, composition::Compositions, dependency::Dependencies,
-        external_reference::ExternalReferences, metadata::Metadata, property::Properties,
-        service::Services,
-    },
-    xml::ToXml,
+    models,
+    utilities::{convert_optional, try_convert_optional, ConvertedVec},
 };
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
+#[cfg(feature = "xml")]
 use xml::{reader, writer::XmlEvent};
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
-pub(crate) struct Bom {
-    bom_format: BomFormat,
-    spec_version: SpecVersion,
-    version: u32,
-    serial_number: Option<UrnUuid>,
+pub struct Bom<C = Components> {
+    pub bom_format: BomFormat,
+    pub spec_version: SpecVersion,
+    pub serial_number: Option<UrnUuid>,
+    pub version: u32,
     #[serde(skip_serializing_if = "Option::is_none")]
-    metadata: Option<Metadata>,
+    pub metadata: Option<Metadata>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    components: Option<Components>,
+    pub components: Option<C>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    services: Option<Services>,
+    pub services: Option<Services>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    external_references: Option<ExternalReferences>,
+    pub external_references: Option<ExternalReferences>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    dependencies: Option<Dependencies>,
+    pub dependencies: Option<Dependencies>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    compositions: Option<Compositions>,
+    pub compositions: Option<Compositions>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    properties: Option<Properties>,
+    pub properties: Option<Properties>,
 }
 
+/// A [`Bom`] whose components are converted into the model one at a time while deserializing
+pub(crate) type ModelComponentsBom = Bom<ConvertedVec<Component, models::component::Component>>;
+
 impl TryFrom<models::bom::Bom> for Bom {
     type Error = BomError;
 
@@ -82,8 +89,11 @@ impl TryFrom<models::bom::Bom> for Bom {
     }
 }
 
-impl From<Bom> for models::bom::Bom {
-    fn from(other: Bom) -> Self {
+impl<C> From<Bom<C>> for models::bom::Bom
+where
+    models::component::Components: From<C>,
+{
+    fn from(other: Bom<C>) -> Self {
         Self {
             version: other.version,
             serial_number: convert_optional(other.serial_number),
@@ -96,6 +106,8 @@ impl From<Bom> for models::bom::Bom {
             properties: convert_optional(other.properties),
             vulnerabilities: None,
             signature: None,
+            formulation: None,
+            annotations: None,
         }
     }
 }
@@ -104,8 +116,49 @@ const BOM_TAG: &str = "bom";
 const SERIAL_NUMBER_ATTR: &str = "serialNumber";
 const VERSION_ATTR: &str = "version";
 
-impl ToXml for Bom {
-    fn write_xml_element<W: std::io::Write>(
+#[cfg(feature = "xml")]
+impl Bom {
+    /// Parse an XML document conforming to [version 1.3 of the specification](https://cyclonedx.org/docs/1.3/xml/)
+    pub fn parse_from_xml<R: std::io::Read>(
+        reader: R,
+    ) -> Result<Self, crate::errors::XmlReadError> {
+        let config = xml::ParserConfig::default().trim_whitespace(true);
+        let mut event_reader = xml::EventReader::new_with_config(reader, config);
+        Self::read_xml_document(&mut event_reader)
+    }
+
+    /// Output as an XML document conforming to [version 1.3 of the specification](https://cyclonedx.org/docs/1.3/xml/)
+    pub fn output_as_xml<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+    ) -> Result<(), crate::errors::XmlWriteError> {
+        let config = xml::EmitterConfig::default().perform_indent(true);
+        let mut event_writer = xml::EventWriter::new_with_config(writer, config);
+        self.write_xml_element(&mut event_writer)
+    }
+
+    /// Output as an XML document like [`Bom::output_as_xml`], with the extension elements at the end of the `bom` element
+    pub fn output_as_xml_with_extensions<W: std::io::Write, E: ToXml>(
+        &self,
+        writer: &mut W,
+        extensions: &[E],
+    ) -> Result<(), crate::errors::XmlWriteError> {
+        let config = xml::EmitterConfig::default().perform_indent(true);
+        let mut event_writer = xml::EventWriter::new_with_config(writer, config);
+
+        self.write_xml_start(&mut event_writer)?;
+        self.write_xml_sections(&mut event_writer)?;
+        for extension in extensions {
+            extension.write_xml_element(&mut event_writer)?;
+        }
+
+        event_writer
+            .write(XmlEvent::end_element())
+            .map_err(to_xml_write_error(BOM_TAG))
+    }
+
+    /// Writes the opening `bom` element with its attributes
+    pub(crate) fn write_xml_start<W: std::io::Write>(
         &self,
         writer: &mut xml::EventWriter<W>,
     ) -> Result<(), crate::errors::XmlWriteError> {
@@ -123,6 +176,14 @@ impl ToXml for Bom {
             .write(bom_start_element)
             .map_err(to_xml_write_error(BOM_TAG))?;
 
+        Ok(())
+    }
+
+    /// Writes all sections of the BOM, without the enclosing `bom` element
+    pub(crate) fn write_xml_sections<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), crate::errors::XmlWriteError> {
         if let Some(metadata) = &self.metadata {
             metadata.write_xml_element(writer)?;
         }
@@ -151,6 +212,19 @@ impl ToXml for Bom {
             properties.write_xml_element(writer)?;
         }
 
+        Ok(())
+    }
+}
+
+#[cfg(feature = "xml")]
+impl ToXml for Bom {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), crate::errors::XmlWriteError> {
+        self.write_xml_start(writer)?;
+        self.write_xml_sections(writer)?;
+
         writer
             .write(XmlEvent::end_element())
             .map_err(to_xml_write_error(BOM_TAG))?;
@@ -167,6 +241,7 @@ const DEPENDENCIES_TAG: &str = "dependencies";
 const COMPOSITIONS_TAG: &str = "compositions";
 const PROPERTIES_TAG: &str = "properties";
 
+#[cfg(feature = "xml")]
 impl FromXmlDocument for Bom {
     fn read_xml_document<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -315,12 +390,12 @@ impl FromXmlDocument for Bom {
 }
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
-enum BomFormat {
+pub enum BomFormat {
     CycloneDX,
 }
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
-struct UrnUuid(String);
+pub struct UrnUuid(pub String);
 
 impl From<models::bom::UrnUuid> for UrnUuid {
     fn from(other: models::bom::UrnUuid) -> Self {
@@ -336,20 +411,19 @@ impl From<UrnUuid> for models::bom::UrnUuid {
 
 #[cfg(test)]
 pub(crate) mod test {
-    use crate::{
-        specs::v1_3::{
-            component::test::{corresponding_components, example_components},
-            composition::test::{corresponding_compositions, example_compositions},
-            dependency::test::{corresponding_dependencies, example_dependencies},
-            external_reference::test::{
-                corresponding_external_references, example_external_references,
-            },
-            metadata::test::{corresponding_metadata, example_metadata},
-            property::test::{corresponding_properties, example_properties},
-            service::test::{corresponding_services, example_services},
+    use crate::specs::v1_3::{
+        component::test::{corresponding_components, example_components},
+        composition::test::{corresponding_compositions, example_compositions},
+        dependency::test::{corresponding_dependencies, example_dependencies},
+        external_reference::test::{
+            corresponding_external_references, example_external_references,
         },
-        xml::test::{read_document_from_string, write_element_to_string},
+        metadata::test::{corresponding_metadata, example_metadata},
+        property::test::{corresponding_properties, example_properties},
+        service::test::{corresponding_services, example_services},
     };
+    #[cfg(feature = "xml")]
+    use crate::xml::test::{read_document_from_string, write_element_to_string};
     use std::convert::TryInto;
 
     use super::*;
@@ -399,6 +473,8 @@ pub(crate) mod test {
             properties: Some(corresponding_properties()),
             vulnerabilities: None,
             signature: None,
+            formulation: None,
+            annotations: None,
         }
     }
 
@@ -407,6 +483,7 @@ pub(crate) mod test {
         insta::assert_json_snapshot!(minimal_bom_example());
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_serialize_to_xml() {
         let xml_output = write_element_to_string(minimal_bom_example());
@@ -420,6 +497,7 @@ pub(crate) mod test {
         insta::assert_json_snapshot!(actual);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_serialize_a_complex_example_to_xml() {
         let xml_output = write_element_to_string(full_bom_example());
@@ -442,6 +520,7 @@ pub(crate) mod test {
         assert_eq!(full_bom_example(), spec);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_deserialize_from_xml() {
         let input = r#"
@@ -454,6 +533,7 @@ pub(crate) mod test {
         assert_eq!(actual, expected);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_deserialize_a_complex_example_from_xml() {
         let input = r#"
@@ -16,24 +16,28 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
+#[cfg(feature = "xml")]
 use crate::{
     errors::XmlReadError,
-    external_models::{date_time::DateTime, normalized_string::NormalizedString, uri::Uri},
-    models,
-    specs::v1_3::attached_text::AttachedText,
-    utilities::{convert_optional, convert_optional_vec, convert_vec},
     xml::{
         attribute_or_error, read_lax_validation_list_tag, read_lax_validation_tag, read_list_tag,
         read_simple_tag, to_xml_read_error, to_xml_write_error, unexpected_element_error,
         write_simple_tag, FromXml, ToInnerXml, ToXml,
     },
 };
+use crate::{
+    external_models::{date_time::DateTime, normalized_string::NormalizedString, uri::Uri},
+    models,
+    specs::v1_3::attached_text::AttachedText,
+    utilities::{convert_optional, convert_optional_vec, convert_vec},
+};
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "xml")]
 use xml::{reader, writer};
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(transparent)]
-pub(crate) struct Commits(Vec<Commit>);
+pub struct Commits(pub Vec<Commit>);
 
 impl From<models::code::Commits> for Commits {
     fn from(other: models::code::Commits) -> Self {
@@ -49,6 +53,7 @@ impl From<Commits> for models::code::Commits {
 
 const COMMITS_TAG: &str = "commits";
 
+#[cfg(feature = "xml")]
 impl ToXml for Commits {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -69,6 +74,7 @@ impl ToXml for Commits {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for Commits {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -84,17 +90,17 @@ impl FromXml for Commits {
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
-pub(crate) struct Commit {
+pub struct Commit {
     #[serde(skip_serializing_if = "Option::is_none")]
-    uid: Option<String>,
+    pub uid: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    url: Option<String>,
+    pub url: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    author: Option<IdentifiableAction>,
+    pub author: Option<IdentifiableAction>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    committer: Option<IdentifiableAction>,
+    pub committer: Option<IdentifiableAction>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    message: Option<String>,
+    pub message: Option<String>,
 }
 
 impl From<models::code::Commit> for Commit {
@@ -128,6 +134,7 @@ const AUTHOR_TAG: &str = "author";
 const COMMITTER_TAG: &str = "committer";
 const MESSAGE_TAG: &str = "message";
 
+#[cfg(feature = "xml")]
 impl ToXml for Commit {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -165,6 +172,7 @@ impl ToXml for Commit {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for Commit {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -233,13 +241,13 @@ impl FromXml for Commit {
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
-struct IdentifiableAction {
+pub struct IdentifiableAction {
     #[serde(skip_serializing_if = "Option::is_none")]
-    timestamp: Option<String>,
+    pub timestamp: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    name: Option<String>,
+    pub name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    email: Option<String>,
+    pub email: Option<String>,
 }
 
 impl From<models::code::IdentifiableAction> for IdentifiableAction {
@@ -266,6 +274,7 @@ const TIMESTAMP_TAG: &str = "timestamp";
 const NAME_TAG: &str = "name";
 const EMAIL_TAG: &str = "email";
 
+#[cfg(feature = "xml")]
 impl ToInnerXml for IdentifiableAction {
     fn write_xml_named_element<W: std::io::Write>(
         &self,
@@ -296,6 +305,7 @@ impl ToInnerXml for IdentifiableAction {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for IdentifiableAction {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -345,7 +355,7 @@ impl FromXml for IdentifiableAction {
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(transparent)]
-pub(crate) struct Patches(Vec<Patch>);
+pub struct Patches(pub Vec<Patch>);
 
 impl From<models::code::Patches> for Patches {
     fn from(other: models::code::Patches) -> Self {
@@ -361,6 +371,7 @@ impl From<Patches> for models::code::Patches {
 
 const PATCHES_TAG: &str = "patches";
 
+#[cfg(feature = "xml")]
 impl ToXml for Patches {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -381,6 +392,7 @@ impl ToXml for Patches {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for Patches {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -396,12 +408,12 @@ impl FromXml for Patches {
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
-pub(crate) struct Patch {
+pub struct Patch {
     #[serde(rename = "type")]
-    patch_type: String,
-    diff: Option<Diff>,
+    pub patch_type: String,
+    pub diff: Option<Diff>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    resolves: Option<Vec<Issue>>,
+    pub resolves: Option<Vec<Issue>>,
 }
 
 impl From<models::code::Patch> for Patch {
@@ -428,6 +440,7 @@ const PATCH_TAG: &str = "patch";
 const TYPE_ATTR: &str = "type";
 const RESOLVES_TAG: &str = "resolves";
 
+#[cfg(feature = "xml")]
 impl ToXml for Patch {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -465,6 +478,7 @@ impl ToXml for Patch {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for Patch {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -511,11 +525,11 @@ impl FromXml for Patch {
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
-struct Diff {
+pub struct Diff {
     #[serde(skip_serializing_if = "Option::is_none")]
-    text: Option<AttachedText>,
+    pub text: Option<AttachedText>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    url: Option<String>,
+    pub url: Option<String>,
 }
 
 impl From<models::code::Diff> for Diff {
@@ -539,6 +553,7 @@ impl From<Diff> for models::code::Diff {
 const DIFF_TAG: &str = "diff";
 const TEXT_TAG: &str = "text";
 
+#[cfg(feature = "xml")]
 impl ToXml for Diff {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -568,6 +583,7 @@ impl ToXml for Diff {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for Diff {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -613,19 +629,19 @@ impl FromXml for Diff {
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
-struct Issue {
+pub struct Issue {
     #[serde(rename = "type")]
-    issue_type: String,
+    pub issue_type: String,
     #[serde(skip_serializing_if = "Option::is_none")]
-    id: Option<String>,
+    pub id: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    name: Option<String>,
+    pub name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    description: Option<String>,
+    pub description: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    source: Option<Source>,
+    pub source: Option<Source>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    references: Option<Vec<String>>,
+    pub references: Option<Vec<String>>,
 }
 
 impl From<models::code::Issue> for Issue {
@@ -663,6 +679,7 @@ const ID_TAG: &str = "id";
 const DESCRIPTION_TAG: &str = "description";
 const REFERENCES_TAG: &str = "references";
 
+#[cfg(feature = "xml")]
 impl ToXml for Issue {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -712,6 +729,7 @@ impl ToXml for Issue {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for Issue {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -777,11 +795,11 @@ impl FromXml for Issue {
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
-struct Source {
+pub struct Source {
     #[serde(skip_serializing_if = "Option::is_none")]
-    name: Option<String>,
+    pub name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    url: Option<String>,
+    pub url: Option<String>,
 }
 
 impl From<models::code::Source> for Source {
@@ -804,6 +822,7 @@ impl From<Source> for models::code::Source {
 
 const SOURCE_TAG: &str = "source";
 
+#[cfg(feature = "xml")]
 impl ToXml for Source {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -833,6 +852,7 @@ impl ToXml for Source {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for Source {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -871,10 +891,11 @@ impl FromXml for Source {
 
 #[cfg(test)]
 pub(crate) mod test {
-    use crate::{
-        specs::v1_3::attached_text::test::{corresponding_attached_text, example_attached_text},
-        xml::test::{read_element_from_string, write_element_to_string},
+    use crate::specs::v1_3::attached_text::test::{
+        corresponding_attached_text, example_attached_text,
     };
+    #[cfg(feature = "xml")]
+    use crate::xml::test::{read_element_from_string, write_element_to_string};
 
     use super::*;
 
@@ -1000,18 +1021,21 @@ pub(crate) mod test {
         }
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_write_commits_xml_full() {
         let xml_output = write_element_to_string(example_commits());
         insta::assert_snapshot!(xml_output);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_write_patches_xml_full() {
         let xml_output = write_element_to_string(example_patches());
         insta::assert_snapshot!(xml_output);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_commits_xml_full() {
         let input = r#"
@@ -1039,6 +1063,7 @@ pub(crate) mod test {
         assert_eq!(actual, expected);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_patches_xml_full() {
         let input = r#"
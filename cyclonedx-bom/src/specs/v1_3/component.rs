@@ -21,17 +21,9 @@ use std::convert::TryFrom;
 use crate::errors::BomError;
 use crate::errors::BomError::BomSerializationError;
 use crate::utilities::try_convert_optional;
+#[cfg(feature = "xml")]
 use crate::{
     errors::XmlReadError,
-    external_models::{
-        normalized_string::NormalizedString,
-        uri::{Purl, Uri},
-    },
-    specs::v1_3::{
-        attached_text::AttachedText, code::Commits, code::Patches,
-        external_reference::ExternalReferences, hash::Hashes, license::Licenses,
-        organization::OrganizationalEntity, property::Properties,
-    },
     xml::{
         attribute_or_error, optional_attribute, read_boolean_tag, read_lax_validation_list_tag,
         read_lax_validation_tag, read_list_tag, read_simple_tag, to_xml_read_error,
@@ -39,16 +31,32 @@ use crate::{
         ToInnerXml, ToXml,
     },
 };
+use crate::{
+    external_models::{
+        normalized_string::NormalizedString,
+        uri::{Purl, Uri},
+    },
+    specs::v1_3::{
+        attached_text::AttachedText,
+        code::{Commits, Patches},
+        external_reference::ExternalReferences,
+        hash::Hashes,
+        license::Licenses,
+        organization::OrganizationalEntity,
+        property::Properties,
+    },
+};
 use crate::{
     models,
     utilities::{convert_optional, convert_vec, try_convert_vec},
 };
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "xml")]
 use xml::{reader, writer::XmlEvent};
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(transparent)]
-pub(crate) struct Components(Vec<Component>);
+pub struct Components(pub Vec<Component>);
 
 impl TryFrom<models::component::Components> for Components {
     type Error = BomError;
@@ -67,6 +75,7 @@ impl From<Components> for models::component::Components {
     }
 }
 
+#[cfg(feature = "xml")]
 impl ToInnerXml for Components {
     fn write_xml_named_element<W: std::io::Write>(
         &self,
@@ -90,6 +99,7 @@ impl ToInnerXml for Components {
 
 const COMPONENTS_TAG: &str = "components";
 
+#[cfg(feature = "xml")]
 impl ToXml for Components {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -99,6 +109,7 @@ impl ToXml for Components {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for Components {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -114,51 +125,51 @@ impl FromXml for Components {
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
-pub(crate) struct Component {
+pub struct Component {
     #[serde(rename = "type")]
-    component_type: String,
+    pub component_type: String,
     #[serde(rename = "mime-type", skip_serializing_if = "Option::is_none")]
-    mime_type: Option<MimeType>,
+    pub mime_type: Option<MimeType>,
     #[serde(rename = "bom-ref", skip_serializing_if = "Option::is_none")]
-    bom_ref: Option<String>,
+    pub bom_ref: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    supplier: Option<OrganizationalEntity>,
+    pub supplier: Option<OrganizationalEntity>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    author: Option<String>,
+    pub author: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    publisher: Option<String>,
+    pub publisher: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    group: Option<String>,
-    name: String,
-    version: String,
+    pub group: Option<String>,
+    pub name: String,
+    pub version: String,
     #[serde(skip_serializing_if = "Option::is_none")]
-    description: Option<String>,
+    pub description: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    scope: Option<String>,
+    pub scope: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    hashes: Option<Hashes>,
+    pub hashes: Option<Hashes>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    licenses: Option<Licenses>,
+    pub licenses: Option<Licenses>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    copyright: Option<String>,
+    pub copyright: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    cpe: Option<Cpe>,
+    pub cpe: Option<Cpe>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    purl: Option<String>,
+    pub purl: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    swid: Option<Swid>,
+    pub swid: Option<Swid>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    modified: Option<bool>,
+    pub modified: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pedigree: Option<Pedigree>,
+    pub pedigree: Option<Pedigree>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    external_references: Option<ExternalReferences>,
+    pub external_references: Option<ExternalReferences>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    properties: Option<Properties>,
+    pub properties: Option<Properties>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    components: Option<Components>,
+    pub components: Option<Components>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    evidence: Option<ComponentEvidence>,
+    pub evidence: Option<ComponentEvidence>,
 }
 
 impl TryFrom<models::component::Component> for Component {
@@ -203,7 +214,9 @@ impl From<Component> for models::component::Component {
     fn from(other: Component) -> Self {
         Self {
             component_type: models::component::Classification::new_unchecked(other.component_type),
-            mime_type: other.mime_type.map(|m| models::component::MimeType(m.0)),
+            mime_type: other
+                .mime_type
+                .map(|m| crate::external_models::mime_type::MimeType(m.0)),
             bom_ref: other.bom_ref,
             supplier: convert_optional(other.supplier),
             author: other.author.map(NormalizedString::new_unchecked),
@@ -219,12 +232,15 @@ impl From<Component> for models::component::Component {
             cpe: convert_optional(other.cpe),
             purl: other.purl.map(Purl),
             swid: convert_optional(other.swid),
+            omnibor_id: None,
+            swhid: None,
             modified: other.modified,
             pedigree: convert_optional(other.pedigree),
             external_references: convert_optional(other.external_references),
             properties: convert_optional(other.properties),
             components: convert_optional(other.components),
             evidence: convert_optional(other.evidence),
+            data: None,
             signature: None, // Not supported in 1.3
         }
     }
@@ -246,6 +262,7 @@ const COPYRIGHT_TAG: &str = "copyright";
 const PURL_TAG: &str = "purl";
 const MODIFIED_TAG: &str = "modified";
 
+#[cfg(feature = "xml")]
 impl ToXml for Component {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -359,6 +376,7 @@ const LICENSES_TAG: &str = "licenses";
 const EXTERNAL_REFERENCES_TAG: &str = "externalReferences";
 const PROPERTIES_TAG: &str = "properties";
 
+#[cfg(feature = "xml")]
 impl FromXml for Component {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -559,19 +577,19 @@ impl FromXml for Component {
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
-struct Swid {
-    tag_id: String,
-    name: String,
+pub struct Swid {
+    pub tag_id: String,
+    pub name: String,
     #[serde(skip_serializing_if = "Option::is_none")]
-    version: Option<String>,
+    pub version: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    tag_version: Option<u32>,
+    pub tag_version: Option<u32>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    patch: Option<bool>,
+    pub patch: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    text: Option<AttachedText>,
+    pub text: Option<AttachedText>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    url: Option<String>,
+    pub url: Option<String>,
 }
 
 impl From<models::component::Swid> for Swid {
@@ -611,6 +629,7 @@ const PATCH_ATTR: &str = "patch";
 const TEXT_TAG: &str = "text";
 const URL_TAG: &str = "url";
 
+#[cfg(feature = "xml")]
 impl ToXml for Swid {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -655,6 +674,7 @@ impl ToXml for Swid {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for Swid {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -723,7 +743,7 @@ impl FromXml for Swid {
 }
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
-struct Cpe(String);
+pub struct Cpe(pub String);
 
 impl From<models::component::Cpe> for Cpe {
     fn from(other: models::component::Cpe) -> Self {
@@ -739,6 +759,7 @@ impl From<Cpe> for models::component::Cpe {
 
 const CPE_TAG: &str = "cpe";
 
+#[cfg(feature = "xml")]
 impl ToXml for Cpe {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -748,6 +769,7 @@ impl ToXml for Cpe {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for Cpe {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -763,11 +785,11 @@ impl FromXml for Cpe {
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
-struct ComponentEvidence {
+pub struct ComponentEvidence {
     #[serde(skip_serializing_if = "Option::is_none")]
-    licenses: Option<Licenses>,
+    pub licenses: Option<Licenses>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    copyright: Option<CopyrightTexts>,
+    pub copyright: Option<CopyrightTexts>,
 }
 
 impl From<models::component::ComponentEvidence> for ComponentEvidence {
@@ -790,6 +812,7 @@ impl From<ComponentEvidence> for models::component::ComponentEvidence {
 
 const EVIDENCE_TAG: &str = "evidence";
 
+#[cfg(feature = "xml")]
 impl ToXml for ComponentEvidence {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -819,6 +842,7 @@ impl ToXml for ComponentEvidence {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for ComponentEvidence {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -871,19 +895,19 @@ impl FromXml for ComponentEvidence {
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
-struct Pedigree {
+pub struct Pedigree {
     #[serde(skip_serializing_if = "Option::is_none")]
-    ancestors: Option<Components>,
+    pub ancestors: Option<Components>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    descendants: Option<Components>,
+    pub descendants: Option<Components>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    variants: Option<Components>,
+    pub variants: Option<Components>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    commits: Option<Commits>,
+    pub commits: Option<Commits>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    patches: Option<Patches>,
+    pub patches: Option<Patches>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    notes: Option<String>,
+    pub notes: Option<String>,
 }
 
 impl TryFrom<models::component::Pedigree> for Pedigree {
@@ -920,6 +944,7 @@ const DESCENDANTS_TAG: &str = "descendants";
 const VARIANTS_TAG: &str = "variants";
 const NOTES_TAG: &str = "notes";
 
+#[cfg(feature = "xml")]
 impl ToXml for Pedigree {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -973,6 +998,7 @@ impl ToXml for Pedigree {
 const COMMITS_TAG: &str = "commits";
 const PATCHES_TAG: &str = "patches";
 
+#[cfg(feature = "xml")]
 impl FromXml for Pedigree {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -1058,8 +1084,8 @@ impl FromXml for Pedigree {
 }
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
-struct Copyright {
-    text: String,
+pub struct Copyright {
+    pub text: String,
 }
 
 impl From<models::component::Copyright> for Copyright {
@@ -1074,6 +1100,7 @@ impl From<Copyright> for models::component::Copyright {
     }
 }
 
+#[cfg(feature = "xml")]
 impl ToXml for Copyright {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -1095,6 +1122,7 @@ impl ToXml for Copyright {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for Copyright {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -1110,7 +1138,7 @@ impl FromXml for Copyright {
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(transparent)]
-struct CopyrightTexts(Vec<Copyright>);
+pub struct CopyrightTexts(pub Vec<Copyright>);
 
 impl From<models::component::CopyrightTexts> for CopyrightTexts {
     fn from(other: models::component::CopyrightTexts) -> Self {
@@ -1124,6 +1152,7 @@ impl From<CopyrightTexts> for models::component::CopyrightTexts {
     }
 }
 
+#[cfg(feature = "xml")]
 impl ToXml for CopyrightTexts {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -1144,6 +1173,7 @@ impl ToXml for CopyrightTexts {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for CopyrightTexts {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -1158,15 +1188,15 @@ impl FromXml for CopyrightTexts {
 }
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
-struct MimeType(String);
+pub struct MimeType(pub String);
 
-impl From<models::component::MimeType> for MimeType {
-    fn from(other: models::component::MimeType) -> Self {
+impl From<crate::external_models::mime_type::MimeType> for MimeType {
+    fn from(other: crate::external_models::mime_type::MimeType) -> Self {
         Self(other.0)
     }
 }
 
-impl From<MimeType> for models::component::MimeType {
+impl From<MimeType> for crate::external_models::mime_type::MimeType {
     fn from(other: MimeType) -> Self {
         Self(other.0)
     }
@@ -1174,6 +1204,8 @@ impl From<MimeType> for models::component::MimeType {
 
 #[cfg(test)]
 pub(crate) mod test {
+    #[cfg(feature = "xml")]
+    use crate::xml::test::{read_element_from_string, write_element_to_string};
     use crate::{
         models::bom::SpecVersion,
         specs::v1_3::{
@@ -1189,7 +1221,6 @@ pub(crate) mod test {
             organization::test::{corresponding_entity, example_entity},
             property::test::{corresponding_properties, example_properties},
         },
-        xml::test::{read_element_from_string, write_element_to_string},
     };
 
     use super::*;
@@ -1235,7 +1266,9 @@ pub(crate) mod test {
             component_type: models::component::Classification::UnknownClassification(
                 "component type".to_string(),
             ),
-            mime_type: Some(models::component::MimeType("mime type".to_string())),
+            mime_type: Some(crate::external_models::mime_type::MimeType(
+                "mime type".to_string(),
+            )),
             bom_ref: Some("bom ref".to_string()),
             supplier: Some(corresponding_entity()),
             author: Some(NormalizedString::new_unchecked("author".to_string())),
@@ -1251,12 +1284,15 @@ pub(crate) mod test {
             cpe: Some(corresponding_cpe()),
             purl: Some(Purl("purl".to_string())),
             swid: Some(corresponding_swid()),
+            omnibor_id: None,
+            swhid: None,
             modified: Some(true),
             pedigree: Some(corresponding_pedigree()),
             external_references: Some(corresponding_external_references()),
             properties: Some(corresponding_properties()),
             components: Some(corresponding_empty_components()),
             evidence: Some(corresponding_evidence()),
+            data: None,
             signature: None,
         }
     }
@@ -1355,12 +1391,14 @@ pub(crate) mod test {
         models::component::Copyright("copyright".to_string())
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_write_xml_full() {
         let xml_output = write_element_to_string(example_components());
         insta::assert_snapshot!(xml_output);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_xml_full() {
         let input = r#"
@@ -17,28 +17,33 @@
  */
 
 use crate::utilities::convert_vec;
+#[cfg(feature = "xml")]
 use crate::xml::write_simple_tag;
+#[cfg(feature = "xml")]
 use crate::{
     errors::XmlReadError,
+    xml::{
+        closing_tag_or_error, inner_text_or_error, read_lax_validation_tag, read_simple_tag,
+        to_xml_read_error, to_xml_write_error, unexpected_element_error, FromXml, ToInnerXml,
+        ToXml,
+    },
+};
+use crate::{
     external_models::{
         normalized_string::NormalizedString,
         spdx::{SpdxExpression, SpdxIdentifier},
         uri::Uri,
     },
     models,
-    xml::{
-        closing_tag_or_error, inner_text_or_error, read_lax_validation_tag, read_simple_tag,
-        to_xml_read_error, to_xml_write_error, unexpected_element_error, FromXml, ToInnerXml,
-        ToXml,
-    },
 };
 use crate::{specs::v1_3::attached_text::AttachedText, utilities::convert_optional};
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "xml")]
 use xml::{name::OwnedName, reader, writer};
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(transparent)]
-pub(crate) struct Licenses(Vec<LicenseChoice>);
+pub struct Licenses(pub Vec<LicenseChoice>);
 
 impl From<models::license::Licenses> for Licenses {
     fn from(other: models::license::Licenses) -> Self {
@@ -54,6 +59,7 @@ impl From<Licenses> for models::license::Licenses {
 
 const LICENSES_TAG: &str = "licenses";
 
+#[cfg(feature = "xml")]
 impl ToXml for Licenses {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -74,6 +80,7 @@ impl ToXml for Licenses {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for Licenses {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -113,7 +120,7 @@ impl FromXml for Licenses {
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
-pub(crate) enum LicenseChoice {
+pub enum LicenseChoice {
     License(License),
     Expression(String),
 }
@@ -138,6 +145,7 @@ impl From<LicenseChoice> for models::license::LicenseChoice {
 
 const EXPRESSION_TAG: &str = "expression";
 
+#[cfg(feature = "xml")]
 impl ToXml for LicenseChoice {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -156,6 +164,7 @@ impl ToXml for LicenseChoice {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for LicenseChoice {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -185,13 +194,13 @@ impl FromXml for LicenseChoice {
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
-pub(crate) struct License {
+pub struct License {
     #[serde(flatten)]
-    license_identifier: LicenseIdentifier,
+    pub license_identifier: LicenseIdentifier,
     #[serde(skip_serializing_if = "Option::is_none")]
-    text: Option<AttachedText>,
+    pub text: Option<AttachedText>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    url: Option<String>,
+    pub url: Option<String>,
 }
 
 impl From<models::license::License> for License {
@@ -218,6 +227,7 @@ const LICENSE_TAG: &str = "license";
 const TEXT_TAG: &str = "text";
 const URL_TAG: &str = "url";
 
+#[cfg(feature = "xml")]
 impl ToXml for License {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -245,6 +255,7 @@ impl ToXml for License {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for License {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -321,7 +332,7 @@ impl FromXml for License {
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
-enum LicenseIdentifier {
+pub enum LicenseIdentifier {
     #[serde(rename = "id")]
     SpdxId(String),
     Name(String),
@@ -348,6 +359,7 @@ impl From<LicenseIdentifier> for models::license::LicenseIdentifier {
 const ID_TAG: &str = "id";
 const NAME_TAG: &str = "name";
 
+#[cfg(feature = "xml")]
 impl ToXml for LicenseIdentifier {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -366,6 +378,7 @@ impl ToXml for LicenseIdentifier {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for LicenseIdentifier {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -413,10 +426,11 @@ impl FromXml for LicenseIdentifier {
 #[cfg(test)]
 pub(crate) mod test {
     use super::*;
+    #[cfg(feature = "xml")]
+    use crate::xml::test::{read_element_from_string, write_element_to_string};
     use crate::{
         external_models::spdx::SpdxExpression,
         specs::v1_3::attached_text::test::{corresponding_attached_text, example_attached_text},
-        xml::test::{read_element_from_string, write_element_to_string},
     };
 
     pub(crate) fn example_licenses() -> Licenses {
@@ -473,6 +487,7 @@ pub(crate) mod test {
         models::license::LicenseChoice::Expression(SpdxExpression("expression".to_string()))
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_licenses_without_license_choices_correctly() {
         let input = r#"
@@ -485,6 +500,7 @@ pub(crate) mod test {
         assert_eq!(actual, expected);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_write_licenses_without_license_choices_correctly() {
         let xml_output = write_element_to_string(Licenses(vec![]));
@@ -509,6 +525,7 @@ pub(crate) mod test {
         insta::assert_json_snapshot!(actual);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_write_xml_full_license_choice_licenses() {
         let xml_output = write_element_to_string(Licenses(vec![
@@ -518,6 +535,7 @@ pub(crate) mod test {
         insta::assert_snapshot!(xml_output);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_write_xml_full_license_choice_expressions() {
         let xml_output = write_element_to_string(Licenses(vec![
@@ -527,6 +545,7 @@ pub(crate) mod test {
         insta::assert_snapshot!(xml_output);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_xml_full_license_choice_licenses() {
         let input = r#"
@@ -548,6 +567,7 @@ pub(crate) mod test {
         assert_eq!(actual, expected);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_xml_full_license_choice_expressions() {
         let input = r#"
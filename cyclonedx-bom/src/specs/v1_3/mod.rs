@@ -16,17 +16,17 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
-pub(crate) mod attached_text;
-pub(crate) mod bom;
-pub(crate) mod code;
-pub(crate) mod component;
-pub(crate) mod composition;
-pub(crate) mod dependency;
-pub(crate) mod external_reference;
-pub(crate) mod hash;
-pub(crate) mod license;
-pub(crate) mod metadata;
-pub(crate) mod organization;
-pub(crate) mod property;
-pub(crate) mod service;
-pub(crate) mod tool;
+pub mod attached_text;
+pub mod bom;
+pub mod code;
+pub mod component;
+pub mod composition;
+pub mod dependency;
+pub mod external_reference;
+pub mod hash;
+pub mod license;
+pub mod metadata;
+pub mod organization;
+pub mod property;
+pub mod service;
+pub mod tool;
@@ -16,28 +16,32 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
+#[cfg(feature = "xml")]
 use crate::{
     errors::XmlWriteError,
-    external_models::{normalized_string::NormalizedString, uri::Uri},
-    models,
-    utilities::convert_optional_vec,
     xml::{
         read_lax_validation_tag, read_simple_tag, to_xml_read_error, to_xml_write_error,
         unexpected_element_error, write_simple_tag, FromXml, ToInnerXml,
     },
 };
+use crate::{
+    external_models::{email::Email, normalized_string::NormalizedString, uri::Uri},
+    models,
+    utilities::convert_optional_vec,
+};
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "xml")]
 use xml::{reader, writer::XmlEvent};
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
-pub(crate) struct OrganizationalContact {
+pub struct OrganizationalContact {
     #[serde(skip_serializing_if = "Option::is_none")]
-    name: Option<String>,
+    pub name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    email: Option<String>,
+    pub email: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    phone: Option<String>,
+    pub phone: Option<String>,
 }
 
 impl From<models::organization::OrganizationalContact> for OrganizationalContact {
@@ -54,7 +58,7 @@ impl From<OrganizationalContact> for models::organization::OrganizationalContact
     fn from(other: OrganizationalContact) -> Self {
         Self {
             name: other.name.map(NormalizedString::new_unchecked),
-            email: other.email.map(NormalizedString::new_unchecked),
+            email: other.email.map(Email::new_unchecked),
             phone: other.phone.map(NormalizedString::new_unchecked),
         }
     }
@@ -64,6 +68,7 @@ const NAME_TAG: &str = "name";
 const EMAIL_TAG: &str = "email";
 const PHONE_TAG: &str = "phone";
 
+#[cfg(feature = "xml")]
 impl ToInnerXml for OrganizationalContact {
     fn write_xml_named_element<W: std::io::Write>(
         &self,
@@ -98,6 +103,7 @@ impl ToInnerXml for OrganizationalContact {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for OrganizationalContact {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -147,13 +153,13 @@ impl FromXml for OrganizationalContact {
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
-pub(crate) struct OrganizationalEntity {
+pub struct OrganizationalEntity {
     #[serde(skip_serializing_if = "Option::is_none")]
-    name: Option<String>,
+    pub name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    url: Option<Vec<String>>,
+    pub url: Option<Vec<String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    contact: Option<Vec<OrganizationalContact>>,
+    pub contact: Option<Vec<OrganizationalContact>>,
 }
 
 impl From<models::organization::OrganizationalEntity> for OrganizationalEntity {
@@ -174,6 +180,7 @@ impl From<OrganizationalEntity> for models::organization::OrganizationalEntity {
             name: other.name.map(NormalizedString::new_unchecked),
             url: other.url.map(|urls| urls.into_iter().map(Uri).collect()),
             contact: convert_optional_vec(other.contact),
+            address: None,
         }
     }
 }
@@ -181,6 +188,7 @@ impl From<OrganizationalEntity> for models::organization::OrganizationalEntity {
 const URL_TAG: &str = "url";
 const CONTACT_TAG: &str = "contact";
 
+#[cfg(feature = "xml")]
 impl ToInnerXml for OrganizationalEntity {
     fn write_xml_named_element<W: std::io::Write>(
         &self,
@@ -221,6 +229,7 @@ impl ToInnerXml for OrganizationalEntity {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for OrganizationalEntity {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -279,6 +288,7 @@ impl FromXml for OrganizationalEntity {
 
 #[cfg(test)]
 pub(crate) mod test {
+    #[cfg(feature = "xml")]
     use crate::xml::test::{read_element_from_string, write_named_element_to_string};
 
     use super::*;
@@ -294,7 +304,7 @@ pub(crate) mod test {
     pub(crate) fn corresponding_contact() -> models::organization::OrganizationalContact {
         models::organization::OrganizationalContact {
             name: Some(NormalizedString::new_unchecked("name".to_string())),
-            email: Some(NormalizedString::new_unchecked("email".to_string())),
+            email: Some(Email::new_unchecked("email".to_string())),
             phone: Some(NormalizedString::new_unchecked("phone".to_string())),
         }
     }
@@ -312,15 +322,18 @@ pub(crate) mod test {
             name: Some(NormalizedString::new_unchecked("name".to_string())),
             url: Some(vec![Uri("url".to_string())]),
             contact: Some(vec![corresponding_contact()]),
+            address: None,
         }
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_write_xml_full() {
         let xml_output = write_named_element_to_string(example_entity(), "supplier");
         insta::assert_snapshot!(xml_output);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_not_write_xml_empty_contacts() {
         let xml_output = write_named_element_to_string(
@@ -338,6 +351,7 @@ pub(crate) mod test {
         insta::assert_snapshot!(xml_output);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_write_xml_multiple_urls_contacts() {
         let xml_output = write_named_element_to_string(
@@ -351,6 +365,7 @@ pub(crate) mod test {
         insta::assert_snapshot!(xml_output);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_xml_full() {
         let input = r#"
@@ -369,6 +384,7 @@ pub(crate) mod test {
         assert_eq!(actual, expected);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_xml_multiple_urls_contacts() {
         let input = r#"
@@ -16,21 +16,22 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
+#[cfg(feature = "xml")]
 use crate::{
     errors::XmlWriteError,
-    external_models::normalized_string::NormalizedString,
-    models,
     xml::{
         attribute_or_error, read_lax_validation_list_tag, read_simple_tag, to_xml_write_error,
         FromXml, ToXml,
     },
 };
+use crate::{external_models::normalized_string::NormalizedString, models};
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "xml")]
 use xml::writer::XmlEvent;
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
-pub(crate) struct Properties(Vec<Property>);
+pub struct Properties(pub Vec<Property>);
 
 impl From<models::property::Properties> for Properties {
     fn from(other: models::property::Properties) -> Self {
@@ -46,6 +47,7 @@ impl From<Properties> for models::property::Properties {
 
 const PROPERTIES_TAG: &str = "properties";
 
+#[cfg(feature = "xml")]
 impl ToXml for Properties {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -66,6 +68,7 @@ impl ToXml for Properties {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for Properties {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -81,9 +84,9 @@ impl FromXml for Properties {
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
-pub(crate) struct Property {
-    name: String,
-    value: String,
+pub struct Property {
+    pub name: String,
+    pub value: String,
 }
 
 impl From<models::property::Property> for Property {
@@ -107,6 +110,7 @@ impl From<Property> for models::property::Property {
 const PROPERTY_TAG: &str = "property";
 const NAME_ATTR: &str = "name";
 
+#[cfg(feature = "xml")]
 impl ToXml for Property {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -128,6 +132,7 @@ impl ToXml for Property {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for Property {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -146,6 +151,7 @@ impl FromXml for Property {
 #[cfg(test)]
 pub(crate) mod test {
     use super::*;
+    #[cfg(feature = "xml")]
     use crate::xml::test::{read_element_from_string, write_element_to_string};
 
     pub(crate) fn example_properties() -> Properties {
@@ -162,18 +168,21 @@ pub(crate) mod test {
         }])
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_write_xml_full() {
         let xml_output = write_element_to_string(example_properties());
         insta::assert_snapshot!(xml_output);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_write_xml_properties_with_no_children() {
         let xml_output = write_element_to_string(Properties(Vec::new()));
         insta::assert_snapshot!(xml_output);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_xml_full() {
         let input = r#"
@@ -186,6 +195,7 @@ pub(crate) mod test {
         assert_eq!(actual, expected);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_xml_properties_with_no_children() {
         let input = r#"
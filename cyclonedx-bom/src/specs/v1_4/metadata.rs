@@ -16,41 +16,46 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
+#[cfg(feature = "xml")]
+use crate::xml::{
+    read_lax_validation_tag, read_list_tag, read_simple_tag, to_xml_read_error, to_xml_write_error,
+    unexpected_element_error, write_simple_tag, FromXml, ToInnerXml, ToXml,
+};
 use crate::{
     external_models::date_time::DateTime,
     models,
     specs::v1_4::{
-        component::Component, license::Licenses, organization::OrganizationalContact,
-        organization::OrganizationalEntity, property::Properties, tool::Tools,
+        component::Component,
+        license::Licenses,
+        organization::{OrganizationalContact, OrganizationalEntity},
+        property::Properties,
+        tool::Tools,
     },
     utilities::{convert_optional, convert_optional_vec},
-    xml::{
-        read_lax_validation_tag, read_list_tag, read_simple_tag, to_xml_read_error,
-        to_xml_write_error, unexpected_element_error, write_simple_tag, FromXml, ToInnerXml, ToXml,
-    },
 };
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "xml")]
 use xml::{reader, writer::XmlEvent};
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
-pub(crate) struct Metadata {
+pub struct Metadata {
     #[serde(skip_serializing_if = "Option::is_none")]
-    timestamp: Option<String>,
+    pub timestamp: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    tools: Option<Tools>,
+    pub tools: Option<Tools>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    authors: Option<Vec<OrganizationalContact>>,
+    pub authors: Option<Vec<OrganizationalContact>>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    component: Option<Component>,
+    pub component: Option<Component>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    manufacture: Option<OrganizationalEntity>,
+    pub manufacture: Option<OrganizationalEntity>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    supplier: Option<OrganizationalEntity>,
+    pub supplier: Option<OrganizationalEntity>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    licenses: Option<Licenses>,
+    pub licenses: Option<Licenses>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    properties: Option<Properties>,
+    pub properties: Option<Properties>,
 }
 
 impl From<models::metadata::Metadata> for Metadata {
@@ -79,6 +84,7 @@ impl From<Metadata> for models::metadata::Metadata {
             supplier: convert_optional(other.supplier),
             licenses: convert_optional(other.licenses),
             properties: convert_optional(other.properties),
+            lifecycles: None,
         }
     }
 }
@@ -90,6 +96,7 @@ const AUTHOR_TAG: &str = "author";
 const MANUFACTURE_TAG: &str = "manufacture";
 const SUPPLIER_TAG: &str = "supplier";
 
+#[cfg(feature = "xml")]
 impl ToXml for Metadata {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -167,6 +174,7 @@ const COMPONENT_TAG: &str = "component";
 const LICENSES_TAG: &str = "licenses";
 const PROPERTIES_TAG: &str = "properties";
 
+#[cfg(feature = "xml")]
 impl FromXml for Metadata {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -273,18 +281,17 @@ impl FromXml for Metadata {
 
 #[cfg(test)]
 pub(crate) mod test {
-    use crate::{
-        specs::v1_4::{
-            component::test::{corresponding_component, example_component},
-            license::test::{corresponding_licenses, example_licenses},
-            organization::test::{
-                corresponding_contact, corresponding_entity, example_contact, example_entity,
-            },
-            property::test::{corresponding_properties, example_properties},
-            tool::test::{corresponding_tools, example_tools},
+    use crate::specs::v1_4::{
+        component::test::{corresponding_component, example_component},
+        license::test::{corresponding_licenses, example_licenses},
+        organization::test::{
+            corresponding_contact, corresponding_entity, example_contact, example_entity,
         },
-        xml::test::{read_element_from_string, write_element_to_string},
+        property::test::{corresponding_properties, example_properties},
+        tool::test::{corresponding_tools, example_tools},
     };
+    #[cfg(feature = "xml")]
+    use crate::xml::test::{read_element_from_string, write_element_to_string};
 
     use super::*;
 
@@ -311,15 +318,18 @@ pub(crate) mod test {
             supplier: Some(corresponding_entity()),
             licenses: Some(corresponding_licenses()),
             properties: Some(corresponding_properties()),
+            lifecycles: None,
         }
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_write_xml_full() {
         let xml_output = write_element_to_string(example_metadata());
         insta::assert_snapshot!(xml_output);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_xml_full() {
         let input = r#"
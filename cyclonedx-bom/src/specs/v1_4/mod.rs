@@ -15,27 +15,15 @@
  *
  * SPDX-License-Identifier: Apache-2.0
  */
+pub use crate::specs::common::{
+    advisory, attached_text, code, composition, dependency, external_reference, hash, license,
+    organization, property, service, signature, vulnerability_credits, vulnerability_rating,
+    vulnerability_reference, vulnerability_source, vulnerability_target,
+};
 
-pub(crate) mod advisory;
-pub(crate) mod attached_text;
-pub(crate) mod bom;
-pub(crate) mod code;
-pub(crate) mod component;
-pub(crate) mod composition;
-pub(crate) mod dependency;
-pub(crate) mod external_reference;
-pub(crate) mod hash;
-pub(crate) mod license;
-pub(crate) mod metadata;
-pub(crate) mod organization;
-pub(crate) mod property;
-pub(crate) mod service;
-pub(crate) mod signature;
-pub(crate) mod tool;
-pub(crate) mod vulnerability;
-pub(crate) mod vulnerability_analysis;
-pub(crate) mod vulnerability_credits;
-pub(crate) mod vulnerability_rating;
-pub(crate) mod vulnerability_reference;
-pub(crate) mod vulnerability_source;
-pub(crate) mod vulnerability_target;
+pub mod bom;
+pub mod component;
+pub mod metadata;
+pub mod tool;
+pub mod vulnerability;
+pub mod vulnerability_analysis;
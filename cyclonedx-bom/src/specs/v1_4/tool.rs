@@ -16,38 +16,42 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
+#[cfg(feature = "xml")]
 use crate::{
     errors::XmlReadError,
-    external_models::normalized_string::NormalizedString,
-    specs::v1_4::hash::Hashes,
-    utilities::convert_vec,
     xml::{
         read_lax_validation_tag, read_list_tag, read_simple_tag, to_xml_read_error,
         to_xml_write_error, unexpected_element_error, write_simple_tag, FromXml, ToXml,
     },
 };
+use crate::{
+    external_models::normalized_string::NormalizedString, specs::v1_4::hash::Hashes,
+    utilities::convert_vec,
+};
 use crate::{models, utilities::convert_optional};
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "xml")]
 use xml::{reader, writer};
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(transparent)]
-pub(crate) struct Tools(Vec<Tool>);
+pub struct Tools(pub Vec<Tool>);
 
 impl From<models::tool::Tools> for Tools {
     fn from(other: models::tool::Tools) -> Self {
-        Tools(convert_vec(other.0))
+        Tools(convert_vec(other.into_list()))
     }
 }
 
 impl From<Tools> for models::tool::Tools {
     fn from(other: Tools) -> Self {
-        models::tool::Tools(convert_vec(other.0))
+        models::tool::Tools::List(convert_vec(other.0))
     }
 }
 
 const TOOLS_TAG: &str = "tools";
 
+#[cfg(feature = "xml")]
 impl ToXml for Tools {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -68,6 +72,7 @@ impl ToXml for Tools {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for Tools {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -83,15 +88,15 @@ impl FromXml for Tools {
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
-pub(crate) struct Tool {
+pub struct Tool {
     #[serde(skip_serializing_if = "Option::is_none")]
-    vendor: Option<String>,
+    pub vendor: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    name: Option<String>,
+    pub name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    version: Option<String>,
+    pub version: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    hashes: Option<Hashes>,
+    pub hashes: Option<Hashes>,
 }
 
 impl From<models::tool::Tool> for Tool {
@@ -121,6 +126,7 @@ const VENDOR_TAG: &str = "vendor";
 const NAME_TAG: &str = "name";
 const VERSION_TAG: &str = "version";
 
+#[cfg(feature = "xml")]
 impl ToXml for Tool {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -165,6 +171,7 @@ impl ToXml for Tool {
 
 const HASHES_TAG: &str = "hashes";
 
+#[cfg(feature = "xml")]
 impl FromXml for Tool {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -219,10 +226,9 @@ impl FromXml for Tool {
 
 #[cfg(test)]
 pub(crate) mod test {
-    use crate::{
-        specs::v1_4::hash::test::{corresponding_hashes, example_hashes},
-        xml::test::{read_element_from_string, write_element_to_string},
-    };
+    use crate::specs::v1_4::hash::test::{corresponding_hashes, example_hashes};
+    #[cfg(feature = "xml")]
+    use crate::xml::test::{read_element_from_string, write_element_to_string};
 
     use super::*;
 
@@ -231,7 +237,7 @@ pub(crate) mod test {
     }
 
     pub(crate) fn corresponding_tools() -> models::tool::Tools {
-        models::tool::Tools(vec![corresponding_tool()])
+        models::tool::Tools::List(vec![corresponding_tool()])
     }
 
     pub(crate) fn example_tool() -> Tool {
@@ -252,12 +258,14 @@ pub(crate) mod test {
         }
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_write_xml_full() {
         let xml_output = write_element_to_string(example_tools());
         insta::assert_snapshot!(xml_output);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_xml_full() {
         let input = r#"
@@ -16,18 +16,22 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
+#[cfg(feature = "xml")]
 use crate::{
     errors::XmlReadError,
-    external_models::{date_time::DateTime, normalized_string::NormalizedString},
-    models,
-    utilities::{convert_optional, convert_optional_vec, convert_vec},
     xml::{
         optional_attribute, read_lax_validation_list_tag, read_lax_validation_tag, read_list_tag,
         read_optional_tag, read_simple_tag, to_xml_read_error, to_xml_write_error,
         unexpected_element_error, write_simple_tag, FromXml, ToXml,
     },
 };
+use crate::{
+    external_models::{date_time::DateTime, normalized_string::NormalizedString},
+    models,
+    utilities::{convert_optional, convert_optional_vec, convert_vec},
+};
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "xml")]
 use xml::{reader, writer::XmlEvent};
 
 use crate::specs::v1_4::{
@@ -39,7 +43,7 @@ use crate::specs::v1_4::{
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(transparent)]
-pub(crate) struct Vulnerabilities(Vec<Vulnerability>);
+pub struct Vulnerabilities(pub Vec<Vulnerability>);
 
 impl From<models::vulnerability::Vulnerabilities> for Vulnerabilities {
     fn from(other: models::vulnerability::Vulnerabilities) -> Self {
@@ -55,6 +59,7 @@ impl From<Vulnerabilities> for models::vulnerability::Vulnerabilities {
 
 const VULNERABILITIES_TAG: &str = "vulnerabilities";
 
+#[cfg(feature = "xml")]
 impl ToXml for Vulnerabilities {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -75,6 +80,7 @@ impl ToXml for Vulnerabilities {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for Vulnerabilities {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -91,43 +97,43 @@ impl FromXml for Vulnerabilities {
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
-pub(crate) struct Vulnerability {
+pub struct Vulnerability {
     #[serde(rename = "bom-ref", skip_serializing_if = "Option::is_none")]
-    bom_ref: Option<String>,
+    pub bom_ref: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    id: Option<String>,
+    pub id: Option<String>,
     #[serde(rename = "source", skip_serializing_if = "Option::is_none")]
-    vulnerability_source: Option<VulnerabilitySource>,
+    pub vulnerability_source: Option<VulnerabilitySource>,
     #[serde(rename = "references", skip_serializing_if = "Option::is_none")]
-    vulnerability_references: Option<VulnerabilityReferences>,
+    pub vulnerability_references: Option<VulnerabilityReferences>,
     #[serde(rename = "ratings", skip_serializing_if = "Option::is_none")]
-    vulnerability_ratings: Option<VulnerabilityRatings>,
+    pub vulnerability_ratings: Option<VulnerabilityRatings>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    cwes: Option<Vec<u32>>,
+    pub cwes: Option<Vec<u32>>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    description: Option<String>,
+    pub description: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    detail: Option<String>,
+    pub detail: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    recommendation: Option<String>,
+    pub recommendation: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    advisories: Option<Advisories>,
+    pub advisories: Option<Advisories>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    created: Option<String>,
+    pub created: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    published: Option<String>,
+    pub published: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    updated: Option<String>,
+    pub updated: Option<String>,
     #[serde(rename = "credits", skip_serializing_if = "Option::is_none")]
-    vulnerability_credits: Option<VulnerabilityCredits>,
+    pub vulnerability_credits: Option<VulnerabilityCredits>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    tools: Option<Tools>,
+    pub tools: Option<Tools>,
     #[serde(rename = "analysis", skip_serializing_if = "Option::is_none")]
-    vulnerability_analysis: Option<VulnerabilityAnalysis>,
+    pub vulnerability_analysis: Option<VulnerabilityAnalysis>,
     #[serde(rename = "affects", skip_serializing_if = "Option::is_none")]
-    vulnerability_targets: Option<VulnerabilityTargets>,
+    pub vulnerability_targets: Option<VulnerabilityTargets>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    properties: Option<Properties>,
+    pub properties: Option<Properties>,
 }
 
 impl From<models::vulnerability::Vulnerability> for Vulnerability {
@@ -167,10 +173,13 @@ impl From<Vulnerability> for models::vulnerability::Vulnerability {
             description: other.description,
             detail: other.detail,
             recommendation: other.recommendation,
+            workaround: None,
+            proof_of_concept: None,
             advisories: convert_optional(other.advisories),
             created: other.created.map(DateTime),
             published: other.published.map(DateTime),
             updated: other.updated.map(DateTime),
+            rejected: None,
             vulnerability_credits: convert_optional(other.vulnerability_credits),
             tools: convert_optional(other.tools),
             vulnerability_analysis: convert_optional(other.vulnerability_analysis),
@@ -201,6 +210,7 @@ const VULNERABILITY_ANALYSIS_TAG: &str = "analysis";
 const VULNERABILITY_TARGETS_TAG: &str = "affects";
 const PROPERTIES_TAG: &str = "properties";
 
+#[cfg(feature = "xml")]
 impl ToXml for Vulnerability {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -302,6 +312,7 @@ impl ToXml for Vulnerability {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for Vulnerability {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -496,32 +507,31 @@ impl FromXml for Vulnerability {
 #[cfg(test)]
 pub(crate) mod test {
     use super::*;
-    use crate::{
-        specs::v1_4::{
-            advisory::test::{corresponding_advisories, example_advisories},
-            property::test::{corresponding_properties, example_properties},
-            tool::test::{corresponding_tools, example_tools},
-            vulnerability_analysis::test::{
-                corresponding_vulnerability_analysis, example_vulnerability_analysis,
-            },
-            vulnerability_credits::test::{
-                corresponding_vulnerability_credits, example_vulnerability_credits,
-            },
-            vulnerability_rating::test::{
-                corresponding_vulnerability_ratings, example_vulnerability_ratings,
-            },
-            vulnerability_reference::test::{
-                corresponding_vulnerability_references, example_vulnerability_references,
-            },
-            vulnerability_source::test::{
-                corresponding_vulnerability_source, example_vulnerability_source,
-            },
-            vulnerability_target::test::{
-                corresponding_vulnerability_targets, example_vulnerability_targets,
-            },
+    use crate::specs::v1_4::{
+        advisory::test::{corresponding_advisories, example_advisories},
+        property::test::{corresponding_properties, example_properties},
+        tool::test::{corresponding_tools, example_tools},
+        vulnerability_analysis::test::{
+            corresponding_vulnerability_analysis, example_vulnerability_analysis,
+        },
+        vulnerability_credits::test::{
+            corresponding_vulnerability_credits, example_vulnerability_credits,
+        },
+        vulnerability_rating::test::{
+            corresponding_vulnerability_ratings, example_vulnerability_ratings,
+        },
+        vulnerability_reference::test::{
+            corresponding_vulnerability_references, example_vulnerability_references,
+        },
+        vulnerability_source::test::{
+            corresponding_vulnerability_source, example_vulnerability_source,
+        },
+        vulnerability_target::test::{
+            corresponding_vulnerability_targets, example_vulnerability_targets,
         },
-        xml::test::{read_element_from_string, write_element_to_string},
     };
+    #[cfg(feature = "xml")]
+    use crate::xml::test::{read_element_from_string, write_element_to_string};
 
     pub(crate) fn example_vulnerabilities() -> Vulnerabilities {
         Vulnerabilities(vec![example_vulnerability()])
@@ -565,10 +575,13 @@ pub(crate) mod test {
             description: Some("description".to_string()),
             detail: Some("detail".to_string()),
             recommendation: Some("recommendation".to_string()),
+            workaround: None,
+            proof_of_concept: None,
             advisories: Some(corresponding_advisories()),
             created: Some(DateTime("created".to_string())),
             published: Some(DateTime("published".to_string())),
             updated: Some(DateTime("updated".to_string())),
+            rejected: None,
             vulnerability_credits: Some(corresponding_vulnerability_credits()),
             tools: Some(corresponding_tools()),
             vulnerability_analysis: Some(corresponding_vulnerability_analysis()),
@@ -577,12 +590,14 @@ pub(crate) mod test {
         }
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_write_xml_full() {
         let xml_output = write_element_to_string(example_vulnerabilities());
         insta::assert_snapshot!(xml_output);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_xml_full() {
         let input = r#"
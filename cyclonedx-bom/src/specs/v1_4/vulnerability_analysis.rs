@@ -17,29 +17,30 @@
  */
 
 use crate::utilities::convert_optional_vec;
+#[cfg(feature = "xml")]
 use crate::{
     errors::XmlReadError,
-    models,
-    utilities::convert_optional,
     xml::{
         read_lax_validation_tag, read_list_tag, read_simple_tag, to_xml_read_error,
         to_xml_write_error, unexpected_element_error, write_simple_tag, FromXml, ToXml,
     },
 };
+use crate::{models, utilities::convert_optional};
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "xml")]
 use xml::{reader, writer::XmlEvent};
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
-pub(crate) struct VulnerabilityAnalysis {
+pub struct VulnerabilityAnalysis {
     #[serde(skip_serializing_if = "Option::is_none")]
-    state: Option<ImpactAnalysisState>,
+    pub state: Option<ImpactAnalysisState>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    justification: Option<ImpactAnalysisJustification>,
+    pub justification: Option<ImpactAnalysisJustification>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    responses: Option<Vec<ImpactAnalysisResponse>>,
+    pub responses: Option<Vec<ImpactAnalysisResponse>>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    detail: Option<String>,
+    pub detail: Option<String>,
 }
 
 impl From<models::vulnerability_analysis::VulnerabilityAnalysis> for VulnerabilityAnalysis {
@@ -60,6 +61,8 @@ impl From<VulnerabilityAnalysis> for models::vulnerability_analysis::Vulnerabili
             justification: convert_optional(other.justification),
             responses: convert_optional_vec(other.responses),
             detail: other.detail,
+            first_issued: None,
+            last_updated: None,
         }
     }
 }
@@ -71,6 +74,7 @@ const RESPONSES_TAG: &str = "responses";
 const RESPONSE_TAG: &str = "response";
 const DETAIL_TAG: &str = "detail";
 
+#[cfg(feature = "xml")]
 impl ToXml for VulnerabilityAnalysis {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -114,6 +118,7 @@ impl ToXml for VulnerabilityAnalysis {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for VulnerabilityAnalysis {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -181,7 +186,7 @@ impl FromXml for VulnerabilityAnalysis {
 }
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
-pub(crate) struct ImpactAnalysisState(String);
+pub struct ImpactAnalysisState(pub String);
 
 impl From<models::vulnerability_analysis::ImpactAnalysisState> for ImpactAnalysisState {
     fn from(other: models::vulnerability_analysis::ImpactAnalysisState) -> Self {
@@ -196,7 +201,7 @@ impl From<ImpactAnalysisState> for models::vulnerability_analysis::ImpactAnalysi
 }
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
-pub(crate) struct ImpactAnalysisJustification(String);
+pub struct ImpactAnalysisJustification(pub String);
 
 impl From<models::vulnerability_analysis::ImpactAnalysisJustification>
     for ImpactAnalysisJustification
@@ -232,6 +237,7 @@ impl From<ImpactAnalysisResponse> for models::vulnerability_analysis::ImpactAnal
 #[cfg(test)]
 pub(crate) mod test {
     use super::*;
+    #[cfg(feature = "xml")]
     use crate::xml::test::{read_element_from_string, write_element_to_string};
 
     pub(crate) fn example_vulnerability_analysis() -> VulnerabilityAnalysis {
@@ -256,15 +262,19 @@ pub(crate) mod test {
                 models::vulnerability_analysis::ImpactAnalysisResponse::Update,
             ]),
             detail: Some("detail".to_string()),
+            first_issued: None,
+            last_updated: None,
         }
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_write_xml_full() {
         let xml_output = write_element_to_string(example_vulnerability_analysis());
         insta::assert_snapshot!(xml_output);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_xml_full() {
         let input = r#"
@@ -0,0 +1,599 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#[cfg(feature = "xml")]
+use crate::{
+    errors::{XmlReadError, XmlWriteError},
+    xml::{
+        attribute_or_error, closing_tag_or_error, optional_attribute, read_lax_validation_list_tag,
+        read_lax_validation_tag, read_list_tag, read_simple_tag, to_xml_read_error,
+        to_xml_write_error, unexpected_element_error, write_simple_tag, FromXml, ToInnerXml, ToXml,
+    },
+};
+use crate::{
+    external_models::date_time::DateTime,
+    models,
+    specs::v1_5::{
+        component::Component,
+        organization::{OrganizationalContact, OrganizationalEntity},
+        service::Service,
+        signature::Signature,
+    },
+    utilities::{convert_optional, convert_vec},
+};
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "xml")]
+use xml::{reader, writer::XmlEvent};
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(transparent)]
+pub struct Annotations(pub Vec<Annotation>);
+
+impl From<models::annotation::Annotations> for Annotations {
+    fn from(other: models::annotation::Annotations) -> Self {
+        Annotations(convert_vec(other.0))
+    }
+}
+
+impl From<Annotations> for models::annotation::Annotations {
+    fn from(other: Annotations) -> Self {
+        models::annotation::Annotations(convert_vec(other.0))
+    }
+}
+
+const ANNOTATIONS_TAG: &str = "annotations";
+
+#[cfg(feature = "xml")]
+impl ToXml for Annotations {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), XmlWriteError> {
+        writer
+            .write(XmlEvent::start_element(ANNOTATIONS_TAG))
+            .map_err(to_xml_write_error(ANNOTATIONS_TAG))?;
+
+        for annotation in &self.0 {
+            annotation.write_xml_element(writer)?;
+        }
+
+        writer
+            .write(XmlEvent::end_element())
+            .map_err(to_xml_write_error(ANNOTATIONS_TAG))?;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "xml")]
+impl FromXml for Annotations {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut xml::EventReader<R>,
+        element_name: &xml::name::OwnedName,
+        _attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        read_lax_validation_list_tag(event_reader, element_name, ANNOTATION_TAG).map(Annotations)
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Annotation {
+    #[serde(rename = "bom-ref", skip_serializing_if = "Option::is_none")]
+    pub bom_ref: Option<String>,
+    pub subjects: Vec<AnnotationSubject>,
+    pub annotator: Annotator,
+    pub timestamp: String,
+    pub text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signature: Option<Signature>,
+}
+
+impl From<models::annotation::Annotation> for Annotation {
+    fn from(other: models::annotation::Annotation) -> Self {
+        Self {
+            bom_ref: other.bom_ref,
+            subjects: convert_vec(other.subjects),
+            annotator: other.annotator.into(),
+            timestamp: other.timestamp.to_string(),
+            text: other.text,
+            signature: convert_optional(other.signature),
+        }
+    }
+}
+
+impl From<Annotation> for models::annotation::Annotation {
+    fn from(other: Annotation) -> Self {
+        Self {
+            bom_ref: other.bom_ref,
+            subjects: convert_vec(other.subjects),
+            annotator: other.annotator.into(),
+            timestamp: DateTime(other.timestamp),
+            text: other.text,
+            signature: convert_optional(other.signature),
+        }
+    }
+}
+
+const ANNOTATION_TAG: &str = "annotation";
+const BOM_REF_ATTR: &str = "bom-ref";
+const SUBJECTS_TAG: &str = "subjects";
+const SUBJECT_TAG: &str = "subject";
+const ANNOTATOR_TAG: &str = "annotator";
+const TIMESTAMP_TAG: &str = "timestamp";
+const TEXT_TAG: &str = "text";
+const SIGNATURE_TAG: &str = "signature";
+
+#[cfg(feature = "xml")]
+impl ToXml for Annotation {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), XmlWriteError> {
+        let mut annotation_start_tag = XmlEvent::start_element(ANNOTATION_TAG);
+
+        if let Some(bom_ref) = &self.bom_ref {
+            annotation_start_tag = annotation_start_tag.attr(BOM_REF_ATTR, bom_ref);
+        }
+
+        writer
+            .write(annotation_start_tag)
+            .map_err(to_xml_write_error(ANNOTATION_TAG))?;
+
+        writer
+            .write(XmlEvent::start_element(SUBJECTS_TAG))
+            .map_err(to_xml_write_error(SUBJECTS_TAG))?;
+
+        for subject in &self.subjects {
+            subject.write_xml_named_element(writer, SUBJECT_TAG)?;
+        }
+
+        writer
+            .write(XmlEvent::end_element())
+            .map_err(to_xml_write_error(SUBJECTS_TAG))?;
+
+        self.annotator
+            .write_xml_named_element(writer, ANNOTATOR_TAG)?;
+
+        write_simple_tag(writer, TIMESTAMP_TAG, &self.timestamp)?;
+
+        write_simple_tag(writer, TEXT_TAG, &self.text)?;
+
+        if let Some(signature) = &self.signature {
+            signature.write_xml_element(writer)?;
+        }
+
+        writer
+            .write(XmlEvent::end_element())
+            .map_err(to_xml_write_error(ANNOTATION_TAG))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "xml")]
+impl FromXml for Annotation {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut xml::EventReader<R>,
+        element_name: &xml::name::OwnedName,
+        attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let bom_ref = optional_attribute(attributes, BOM_REF_ATTR);
+        let mut subjects: Option<Vec<AnnotationSubject>> = None;
+        let mut annotator: Option<Annotator> = None;
+        let mut timestamp: Option<String> = None;
+        let mut text: Option<String> = None;
+        let mut signature: Option<Signature> = None;
+
+        let mut got_end_tag = false;
+        while !got_end_tag {
+            let next_element = event_reader
+                .next()
+                .map_err(to_xml_read_error(ANNOTATION_TAG))?;
+            match next_element {
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == SUBJECTS_TAG => {
+                    subjects = Some(read_list_tag(event_reader, &name, SUBJECT_TAG)?);
+                }
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == ANNOTATOR_TAG => {
+                    annotator = Some(Annotator::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?);
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == TIMESTAMP_TAG => {
+                    timestamp = Some(read_simple_tag(event_reader, &name)?);
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == TEXT_TAG => {
+                    text = Some(read_simple_tag(event_reader, &name)?);
+                }
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == SIGNATURE_TAG => {
+                    signature = Some(Signature::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?);
+                }
+                // lax validation of any elements from a different schema
+                reader::XmlEvent::StartElement { name, .. } => {
+                    read_lax_validation_tag(event_reader, &name)?
+                }
+                reader::XmlEvent::EndElement { name } if &name == element_name => {
+                    got_end_tag = true;
+                }
+                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+            }
+        }
+
+        let subjects = subjects.ok_or_else(|| XmlReadError::RequiredDataMissing {
+            required_field: SUBJECTS_TAG.to_string(),
+            element: ANNOTATION_TAG.to_string(),
+        })?;
+        let annotator = annotator.ok_or_else(|| XmlReadError::RequiredDataMissing {
+            required_field: ANNOTATOR_TAG.to_string(),
+            element: ANNOTATION_TAG.to_string(),
+        })?;
+        let timestamp = timestamp.ok_or_else(|| XmlReadError::RequiredDataMissing {
+            required_field: TIMESTAMP_TAG.to_string(),
+            element: ANNOTATION_TAG.to_string(),
+        })?;
+        let text = text.ok_or_else(|| XmlReadError::RequiredDataMissing {
+            required_field: TEXT_TAG.to_string(),
+            element: ANNOTATION_TAG.to_string(),
+        })?;
+
+        Ok(Self {
+            bom_ref,
+            subjects,
+            annotator,
+            timestamp,
+            text,
+            signature,
+        })
+    }
+}
+
+/// A subject is serialized as a plain bom-ref or BOM-Link string
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+pub struct AnnotationSubject(pub String);
+
+impl From<models::annotation::AnnotationSubject> for AnnotationSubject {
+    fn from(other: models::annotation::AnnotationSubject) -> Self {
+        Self(other.to_string())
+    }
+}
+
+impl From<AnnotationSubject> for models::annotation::AnnotationSubject {
+    fn from(other: AnnotationSubject) -> Self {
+        Self::new_unchecked(other.0)
+    }
+}
+
+const REF_ATTR: &str = "ref";
+
+#[cfg(feature = "xml")]
+impl ToInnerXml for AnnotationSubject {
+    fn write_xml_named_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+        tag: &str,
+    ) -> Result<(), XmlWriteError> {
+        writer
+            .write(XmlEvent::start_element(tag).attr(REF_ATTR, &self.0))
+            .map_err(to_xml_write_error(tag))?;
+
+        writer
+            .write(XmlEvent::end_element())
+            .map_err(to_xml_write_error(tag))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "xml")]
+impl FromXml for AnnotationSubject {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut xml::EventReader<R>,
+        element_name: &xml::name::OwnedName,
+        attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let reference = attribute_or_error(element_name, attributes, REF_ATTR)?;
+        event_reader
+            .next()
+            .map_err(to_xml_read_error(&element_name.local_name))
+            .and_then(closing_tag_or_error(element_name))?;
+
+        Ok(Self(reference))
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum Annotator {
+    Organization(OrganizationalEntity),
+    Individual(OrganizationalContact),
+    Component(Box<Component>),
+    Service(Box<Service>),
+}
+
+impl From<models::annotation::Annotator> for Annotator {
+    fn from(other: models::annotation::Annotator) -> Self {
+        match other {
+            models::annotation::Annotator::Organization(organization) => {
+                Self::Organization(organization.into())
+            }
+            models::annotation::Annotator::Individual(individual) => {
+                Self::Individual(individual.into())
+            }
+            models::annotation::Annotator::Component(component) => {
+                Self::Component(Box::new((*component).into()))
+            }
+            models::annotation::Annotator::Service(service) => {
+                Self::Service(Box::new((*service).into()))
+            }
+        }
+    }
+}
+
+impl From<Annotator> for models::annotation::Annotator {
+    fn from(other: Annotator) -> Self {
+        match other {
+            Annotator::Organization(organization) => Self::Organization(organization.into()),
+            Annotator::Individual(individual) => Self::Individual(individual.into()),
+            Annotator::Component(component) => Self::Component(Box::new((*component).into())),
+            Annotator::Service(service) => Self::Service(Box::new((*service).into())),
+        }
+    }
+}
+
+const ORGANIZATION_TAG: &str = "organization";
+const INDIVIDUAL_TAG: &str = "individual";
+const COMPONENT_TAG: &str = "component";
+const SERVICE_TAG: &str = "service";
+
+#[cfg(feature = "xml")]
+impl ToInnerXml for Annotator {
+    fn write_xml_named_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+        tag: &str,
+    ) -> Result<(), XmlWriteError> {
+        writer
+            .write(XmlEvent::start_element(tag))
+            .map_err(to_xml_write_error(tag))?;
+
+        match self {
+            Annotator::Organization(organization) => {
+                organization.write_xml_named_element(writer, ORGANIZATION_TAG)?
+            }
+            Annotator::Individual(individual) => {
+                individual.write_xml_named_element(writer, INDIVIDUAL_TAG)?
+            }
+            Annotator::Component(component) => component.write_xml_element(writer)?,
+            Annotator::Service(service) => service.write_xml_element(writer)?,
+        }
+
+        writer
+            .write(XmlEvent::end_element())
+            .map_err(to_xml_write_error(tag))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "xml")]
+impl FromXml for Annotator {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut xml::EventReader<R>,
+        element_name: &xml::name::OwnedName,
+        _attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let mut annotator: Option<Self> = None;
+
+        let mut got_end_tag = false;
+        while !got_end_tag {
+            let next_element = event_reader
+                .next()
+                .map_err(to_xml_read_error(ANNOTATOR_TAG))?;
+            match next_element {
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == ORGANIZATION_TAG => {
+                    annotator = Some(Self::Organization(OrganizationalEntity::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?))
+                }
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == INDIVIDUAL_TAG => {
+                    annotator = Some(Self::Individual(OrganizationalContact::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?))
+                }
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == COMPONENT_TAG => {
+                    annotator = Some(Self::Component(Box::new(Component::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?)))
+                }
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == SERVICE_TAG => {
+                    annotator = Some(Self::Service(Box::new(Service::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?)))
+                }
+                // lax validation of any elements from a different schema
+                reader::XmlEvent::StartElement { name, .. } => {
+                    read_lax_validation_tag(event_reader, &name)?
+                }
+                reader::XmlEvent::EndElement { name } if &name == element_name => {
+                    got_end_tag = true;
+                }
+                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+            }
+        }
+
+        annotator.ok_or_else(|| XmlReadError::RequiredDataMissing {
+            required_field: format!(
+                "{}, {}, {} or {}",
+                ORGANIZATION_TAG, INDIVIDUAL_TAG, COMPONENT_TAG, SERVICE_TAG
+            ),
+            element: element_name.local_name.to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+    use super::*;
+    #[cfg(feature = "xml")]
+    use crate::xml::test::{read_element_from_string, write_element_to_string};
+    use crate::{
+        models::{annotation::BomLink, composition::BomReference},
+        specs::v1_5::{
+            organization::test::{corresponding_entity, example_entity},
+            service::test::{corresponding_service, example_service},
+            signature::test::{corresponding_signature, example_signature},
+        },
+    };
+
+    pub(crate) fn example_annotations() -> Annotations {
+        Annotations(vec![
+            Annotation {
+                bom_ref: Some("annotation-1".to_string()),
+                subjects: vec![
+                    AnnotationSubject("component-a".to_string()),
+                    AnnotationSubject(
+                        "urn:cdx:3e671687-395b-41f5-a30f-a58921a69b79/1#component-b".to_string(),
+                    ),
+                ],
+                annotator: Annotator::Organization(example_entity()),
+                timestamp: "timestamp".to_string(),
+                text: "text".to_string(),
+                signature: Some(example_signature()),
+            },
+            Annotation {
+                bom_ref: None,
+                subjects: vec![AnnotationSubject("service-a".to_string())],
+                annotator: Annotator::Service(Box::new(example_service())),
+                timestamp: "timestamp".to_string(),
+                text: "text".to_string(),
+                signature: None,
+            },
+        ])
+    }
+
+    pub(crate) fn corresponding_annotations() -> models::annotation::Annotations {
+        models::annotation::Annotations(vec![
+            models::annotation::Annotation {
+                bom_ref: Some("annotation-1".to_string()),
+                subjects: vec![
+                    models::annotation::AnnotationSubject::BomReference(BomReference(
+                        "component-a".to_string(),
+                    )),
+                    models::annotation::AnnotationSubject::BomLink(BomLink(
+                        "urn:cdx:3e671687-395b-41f5-a30f-a58921a69b79/1#component-b".to_string(),
+                    )),
+                ],
+                annotator: models::annotation::Annotator::Organization(corresponding_entity()),
+                timestamp: DateTime("timestamp".to_string()),
+                text: "text".to_string(),
+                signature: Some(corresponding_signature()),
+            },
+            models::annotation::Annotation {
+                bom_ref: None,
+                subjects: vec![models::annotation::AnnotationSubject::BomReference(
+                    BomReference("service-a".to_string()),
+                )],
+                annotator: models::annotation::Annotator::Service(
+                    Box::new(corresponding_service()),
+                ),
+                timestamp: DateTime("timestamp".to_string()),
+                text: "text".to_string(),
+                signature: None,
+            },
+        ])
+    }
+
+    #[test]
+    fn it_should_convert_to_and_from_the_model() {
+        let model: models::annotation::Annotations = example_annotations().into();
+        assert_eq!(model, corresponding_annotations());
+        assert_eq!(Annotations::from(model), example_annotations());
+    }
+
+    #[cfg(feature = "xml")]
+    #[test]
+    fn it_should_write_xml_full() {
+        let xml_output = write_element_to_string(example_annotations());
+        insta::assert_snapshot!(xml_output);
+    }
+
+    #[cfg(feature = "xml")]
+    #[test]
+    fn it_should_read_xml_full() {
+        let xml_output = write_element_to_string(example_annotations());
+        let actual: Annotations = read_element_from_string(xml_output);
+        let expected = example_annotations();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn it_should_read_json_subjects_as_strings() {
+        let input = r#"
+[
+  {
+    "subjects": ["component-a"],
+    "annotator": { "individual": { "name": "name" } },
+    "timestamp": "timestamp",
+    "text": "text"
+  }
+]
+"#;
+        let actual: Annotations = serde_json::from_str(input).expect("Failed to parse JSON");
+        assert_eq!(
+            actual.0[0].subjects,
+            vec![AnnotationSubject("component-a".to_string())]
+        );
+    }
+}
@@ -0,0 +1,1025 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#[cfg(feature = "xml")]
+use crate::{
+    errors::{XmlReadError, XmlWriteError},
+    xml::{
+        optional_attribute, read_lax_validation_tag, read_list_tag, read_simple_tag,
+        to_xml_read_error, to_xml_write_error, unexpected_element_error, write_simple_tag, FromXml,
+        ToInnerXml, ToXml,
+    },
+};
+use crate::{
+    external_models::{normalized_string::NormalizedString, uri::Uri},
+    models,
+    specs::v1_5::{
+        attached_text::AttachedText,
+        organization::{OrganizationalContact, OrganizationalEntity},
+        property::Properties,
+    },
+    utilities::{convert_optional, convert_optional_vec},
+};
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "xml")]
+use xml::{reader, writer::XmlEvent};
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ComponentData {
+    #[serde(rename = "bom-ref", skip_serializing_if = "Option::is_none")]
+    pub bom_ref: Option<String>,
+    #[serde(rename = "type")]
+    pub data_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contents: Option<DataContents>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub classification: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sensitive_data: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub graphics: Option<GraphicsCollection>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub governance: Option<DataGovernance>,
+}
+
+impl From<models::component_data::ComponentData> for ComponentData {
+    fn from(other: models::component_data::ComponentData) -> Self {
+        Self {
+            bom_ref: other.bom_ref,
+            data_type: other.data_type.to_string(),
+            name: other.name.map(|n| n.to_string()),
+            contents: convert_optional(other.contents),
+            classification: other.classification.map(|c| c.to_string()),
+            sensitive_data: other
+                .sensitive_data
+                .map(|s| s.into_iter().map(|s| s.to_string()).collect()),
+            graphics: convert_optional(other.graphics),
+            description: other.description.map(|d| d.to_string()),
+            governance: convert_optional(other.governance),
+        }
+    }
+}
+
+impl From<ComponentData> for models::component_data::ComponentData {
+    fn from(other: ComponentData) -> Self {
+        Self {
+            bom_ref: other.bom_ref,
+            data_type: models::component_data::ComponentDataType::new_unchecked(other.data_type),
+            name: other.name.map(NormalizedString::new_unchecked),
+            contents: convert_optional(other.contents),
+            classification: other.classification.map(NormalizedString::new_unchecked),
+            sensitive_data: other
+                .sensitive_data
+                .map(|s| s.into_iter().map(NormalizedString::new_unchecked).collect()),
+            graphics: convert_optional(other.graphics),
+            description: other.description.map(NormalizedString::new_unchecked),
+            governance: convert_optional(other.governance),
+        }
+    }
+}
+
+pub(crate) const DATA_TAG: &str = "data";
+const BOM_REF_ATTR: &str = "bom-ref";
+const TYPE_TAG: &str = "type";
+const NAME_TAG: &str = "name";
+const CONTENTS_TAG: &str = "contents";
+const CLASSIFICATION_TAG: &str = "classification";
+const SENSITIVE_DATA_TAG: &str = "sensitiveData";
+const GRAPHICS_TAG: &str = "graphics";
+const DESCRIPTION_TAG: &str = "description";
+const GOVERNANCE_TAG: &str = "governance";
+
+#[cfg(feature = "xml")]
+impl ToXml for ComponentData {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), XmlWriteError> {
+        let mut data_start_tag = XmlEvent::start_element(DATA_TAG);
+
+        if let Some(bom_ref) = &self.bom_ref {
+            data_start_tag = data_start_tag.attr(BOM_REF_ATTR, bom_ref);
+        }
+
+        writer
+            .write(data_start_tag)
+            .map_err(to_xml_write_error(DATA_TAG))?;
+
+        write_simple_tag(writer, TYPE_TAG, &self.data_type)?;
+
+        if let Some(name) = &self.name {
+            write_simple_tag(writer, NAME_TAG, name)?;
+        }
+
+        if let Some(contents) = &self.contents {
+            contents.write_xml_element(writer)?;
+        }
+
+        if let Some(classification) = &self.classification {
+            write_simple_tag(writer, CLASSIFICATION_TAG, classification)?;
+        }
+
+        for sensitive_data in self.sensitive_data.iter().flatten() {
+            write_simple_tag(writer, SENSITIVE_DATA_TAG, sensitive_data)?;
+        }
+
+        if let Some(graphics) = &self.graphics {
+            graphics.write_xml_element(writer)?;
+        }
+
+        if let Some(description) = &self.description {
+            write_simple_tag(writer, DESCRIPTION_TAG, description)?;
+        }
+
+        if let Some(governance) = &self.governance {
+            governance.write_xml_element(writer)?;
+        }
+
+        writer
+            .write(XmlEvent::end_element())
+            .map_err(to_xml_write_error(DATA_TAG))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "xml")]
+impl FromXml for ComponentData {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut xml::EventReader<R>,
+        element_name: &xml::name::OwnedName,
+        attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let bom_ref = optional_attribute(attributes, BOM_REF_ATTR);
+        let mut data_type: Option<String> = None;
+        let mut data_name: Option<String> = None;
+        let mut contents: Option<DataContents> = None;
+        let mut classification: Option<String> = None;
+        let mut sensitive_data: Vec<String> = Vec::new();
+        let mut graphics: Option<GraphicsCollection> = None;
+        let mut description: Option<String> = None;
+        let mut governance: Option<DataGovernance> = None;
+
+        let mut got_end_tag = false;
+        while !got_end_tag {
+            let next_element = event_reader.next().map_err(to_xml_read_error(DATA_TAG))?;
+            match next_element {
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == TYPE_TAG => {
+                    data_type = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == NAME_TAG => {
+                    data_name = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == CONTENTS_TAG => {
+                    contents = Some(DataContents::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?)
+                }
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == CLASSIFICATION_TAG =>
+                {
+                    classification = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == SENSITIVE_DATA_TAG =>
+                {
+                    sensitive_data.push(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == GRAPHICS_TAG => {
+                    graphics = Some(GraphicsCollection::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?)
+                }
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == DESCRIPTION_TAG =>
+                {
+                    description = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == GOVERNANCE_TAG => {
+                    governance = Some(DataGovernance::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?)
+                }
+                // lax validation of any elements from a different schema
+                reader::XmlEvent::StartElement { name, .. } => {
+                    read_lax_validation_tag(event_reader, &name)?
+                }
+                reader::XmlEvent::EndElement { name } if &name == element_name => {
+                    got_end_tag = true;
+                }
+                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+            }
+        }
+
+        let data_type = data_type.ok_or_else(|| XmlReadError::RequiredDataMissing {
+            required_field: TYPE_TAG.to_string(),
+            element: element_name.local_name.to_string(),
+        })?;
+
+        Ok(Self {
+            bom_ref,
+            data_type,
+            name: data_name,
+            contents,
+            classification,
+            sensitive_data: (!sensitive_data.is_empty()).then_some(sensitive_data),
+            graphics,
+            description,
+            governance,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DataContents {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attachment: Option<AttachedText>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub properties: Option<Properties>,
+}
+
+impl From<models::component_data::DataContents> for DataContents {
+    fn from(other: models::component_data::DataContents) -> Self {
+        Self {
+            attachment: convert_optional(other.attachment),
+            url: other.url.map(|u| u.to_string()),
+            properties: convert_optional(other.properties),
+        }
+    }
+}
+
+impl From<DataContents> for models::component_data::DataContents {
+    fn from(other: DataContents) -> Self {
+        Self {
+            attachment: convert_optional(other.attachment),
+            url: other.url.map(Uri),
+            properties: convert_optional(other.properties),
+        }
+    }
+}
+
+const ATTACHMENT_TAG: &str = "attachment";
+const URL_TAG: &str = "url";
+const PROPERTIES_TAG: &str = "properties";
+
+#[cfg(feature = "xml")]
+impl ToXml for DataContents {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), XmlWriteError> {
+        writer
+            .write(XmlEvent::start_element(CONTENTS_TAG))
+            .map_err(to_xml_write_error(CONTENTS_TAG))?;
+
+        if let Some(attachment) = &self.attachment {
+            attachment.write_xml_named_element(writer, ATTACHMENT_TAG)?;
+        }
+
+        if let Some(url) = &self.url {
+            write_simple_tag(writer, URL_TAG, url)?;
+        }
+
+        if let Some(properties) = &self.properties {
+            properties.write_xml_element(writer)?;
+        }
+
+        writer
+            .write(XmlEvent::end_element())
+            .map_err(to_xml_write_error(CONTENTS_TAG))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "xml")]
+impl FromXml for DataContents {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut xml::EventReader<R>,
+        element_name: &xml::name::OwnedName,
+        _attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let mut attachment: Option<AttachedText> = None;
+        let mut url: Option<String> = None;
+        let mut properties: Option<Properties> = None;
+
+        let mut got_end_tag = false;
+        while !got_end_tag {
+            let next_element = event_reader
+                .next()
+                .map_err(to_xml_read_error(CONTENTS_TAG))?;
+            match next_element {
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == ATTACHMENT_TAG => {
+                    attachment = Some(AttachedText::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?)
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == URL_TAG => {
+                    url = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == PROPERTIES_TAG => {
+                    properties = Some(Properties::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?)
+                }
+                // lax validation of any elements from a different schema
+                reader::XmlEvent::StartElement { name, .. } => {
+                    read_lax_validation_tag(event_reader, &name)?
+                }
+                reader::XmlEvent::EndElement { name } if &name == element_name => {
+                    got_end_tag = true;
+                }
+                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+            }
+        }
+
+        Ok(Self {
+            attachment,
+            url,
+            properties,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct GraphicsCollection {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub collection: Option<Vec<Graphic>>,
+}
+
+impl From<models::component_data::GraphicsCollection> for GraphicsCollection {
+    fn from(other: models::component_data::GraphicsCollection) -> Self {
+        Self {
+            description: other.description.map(|d| d.to_string()),
+            collection: convert_optional_vec(other.collection),
+        }
+    }
+}
+
+impl From<GraphicsCollection> for models::component_data::GraphicsCollection {
+    fn from(other: GraphicsCollection) -> Self {
+        Self {
+            description: other.description.map(NormalizedString::new_unchecked),
+            collection: convert_optional_vec(other.collection),
+        }
+    }
+}
+
+const COLLECTION_TAG: &str = "collection";
+const GRAPHIC_TAG: &str = "graphic";
+const IMAGE_TAG: &str = "image";
+
+#[cfg(feature = "xml")]
+impl ToXml for GraphicsCollection {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), XmlWriteError> {
+        writer
+            .write(XmlEvent::start_element(GRAPHICS_TAG))
+            .map_err(to_xml_write_error(GRAPHICS_TAG))?;
+
+        if let Some(description) = &self.description {
+            write_simple_tag(writer, DESCRIPTION_TAG, description)?;
+        }
+
+        if let Some(collection) = &self.collection {
+            writer
+                .write(XmlEvent::start_element(COLLECTION_TAG))
+                .map_err(to_xml_write_error(COLLECTION_TAG))?;
+
+            for graphic in collection {
+                graphic.write_xml_element(writer)?;
+            }
+
+            writer
+                .write(XmlEvent::end_element())
+                .map_err(to_xml_write_error(COLLECTION_TAG))?;
+        }
+
+        writer
+            .write(XmlEvent::end_element())
+            .map_err(to_xml_write_error(GRAPHICS_TAG))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "xml")]
+impl FromXml for GraphicsCollection {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut xml::EventReader<R>,
+        element_name: &xml::name::OwnedName,
+        _attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let mut description: Option<String> = None;
+        let mut collection: Option<Vec<Graphic>> = None;
+
+        let mut got_end_tag = false;
+        while !got_end_tag {
+            let next_element = event_reader
+                .next()
+                .map_err(to_xml_read_error(GRAPHICS_TAG))?;
+            match next_element {
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == DESCRIPTION_TAG =>
+                {
+                    description = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == COLLECTION_TAG =>
+                {
+                    collection = Some(read_list_tag(event_reader, &name, GRAPHIC_TAG)?)
+                }
+                // lax validation of any elements from a different schema
+                reader::XmlEvent::StartElement { name, .. } => {
+                    read_lax_validation_tag(event_reader, &name)?
+                }
+                reader::XmlEvent::EndElement { name } if &name == element_name => {
+                    got_end_tag = true;
+                }
+                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+            }
+        }
+
+        Ok(Self {
+            description,
+            collection,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Graphic {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image: Option<AttachedText>,
+}
+
+impl From<models::component_data::Graphic> for Graphic {
+    fn from(other: models::component_data::Graphic) -> Self {
+        Self {
+            name: other.name.map(|n| n.to_string()),
+            image: convert_optional(other.image),
+        }
+    }
+}
+
+impl From<Graphic> for models::component_data::Graphic {
+    fn from(other: Graphic) -> Self {
+        Self {
+            name: other.name.map(NormalizedString::new_unchecked),
+            image: convert_optional(other.image),
+        }
+    }
+}
+
+#[cfg(feature = "xml")]
+impl ToXml for Graphic {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), XmlWriteError> {
+        writer
+            .write(XmlEvent::start_element(GRAPHIC_TAG))
+            .map_err(to_xml_write_error(GRAPHIC_TAG))?;
+
+        if let Some(name) = &self.name {
+            write_simple_tag(writer, NAME_TAG, name)?;
+        }
+
+        if let Some(image) = &self.image {
+            image.write_xml_named_element(writer, IMAGE_TAG)?;
+        }
+
+        writer
+            .write(XmlEvent::end_element())
+            .map_err(to_xml_write_error(GRAPHIC_TAG))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "xml")]
+impl FromXml for Graphic {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut xml::EventReader<R>,
+        element_name: &xml::name::OwnedName,
+        _attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let mut graphic_name: Option<String> = None;
+        let mut image: Option<AttachedText> = None;
+
+        let mut got_end_tag = false;
+        while !got_end_tag {
+            let next_element = event_reader
+                .next()
+                .map_err(to_xml_read_error(GRAPHIC_TAG))?;
+            match next_element {
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == NAME_TAG => {
+                    graphic_name = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == IMAGE_TAG => {
+                    image = Some(AttachedText::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?)
+                }
+                // lax validation of any elements from a different schema
+                reader::XmlEvent::StartElement { name, .. } => {
+                    read_lax_validation_tag(event_reader, &name)?
+                }
+                reader::XmlEvent::EndElement { name } if &name == element_name => {
+                    got_end_tag = true;
+                }
+                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+            }
+        }
+
+        Ok(Self {
+            name: graphic_name,
+            image,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DataGovernance {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custodians: Option<Vec<DataGovernanceResponsibleParty>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stewards: Option<Vec<DataGovernanceResponsibleParty>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owners: Option<Vec<DataGovernanceResponsibleParty>>,
+}
+
+impl From<models::component_data::DataGovernance> for DataGovernance {
+    fn from(other: models::component_data::DataGovernance) -> Self {
+        Self {
+            custodians: convert_optional_vec(other.custodians),
+            stewards: convert_optional_vec(other.stewards),
+            owners: convert_optional_vec(other.owners),
+        }
+    }
+}
+
+impl From<DataGovernance> for models::component_data::DataGovernance {
+    fn from(other: DataGovernance) -> Self {
+        Self {
+            custodians: convert_optional_vec(other.custodians),
+            stewards: convert_optional_vec(other.stewards),
+            owners: convert_optional_vec(other.owners),
+        }
+    }
+}
+
+const CUSTODIANS_TAG: &str = "custodians";
+const CUSTODIAN_TAG: &str = "custodian";
+const STEWARDS_TAG: &str = "stewards";
+const STEWARD_TAG: &str = "steward";
+const OWNERS_TAG: &str = "owners";
+const OWNER_TAG: &str = "owner";
+
+#[cfg(feature = "xml")]
+impl ToXml for DataGovernance {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), XmlWriteError> {
+        writer
+            .write(XmlEvent::start_element(GOVERNANCE_TAG))
+            .map_err(to_xml_write_error(GOVERNANCE_TAG))?;
+
+        let parties = [
+            (CUSTODIANS_TAG, CUSTODIAN_TAG, &self.custodians),
+            (STEWARDS_TAG, STEWARD_TAG, &self.stewards),
+            (OWNERS_TAG, OWNER_TAG, &self.owners),
+        ];
+
+        for (list_tag, party_tag, parties) in parties {
+            if let Some(parties) = parties {
+                writer
+                    .write(XmlEvent::start_element(list_tag))
+                    .map_err(to_xml_write_error(list_tag))?;
+
+                for party in parties {
+                    party.write_xml_named_element(writer, party_tag)?;
+                }
+
+                writer
+                    .write(XmlEvent::end_element())
+                    .map_err(to_xml_write_error(list_tag))?;
+            }
+        }
+
+        writer
+            .write(XmlEvent::end_element())
+            .map_err(to_xml_write_error(GOVERNANCE_TAG))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "xml")]
+impl FromXml for DataGovernance {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut xml::EventReader<R>,
+        element_name: &xml::name::OwnedName,
+        _attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let mut custodians: Option<Vec<DataGovernanceResponsibleParty>> = None;
+        let mut stewards: Option<Vec<DataGovernanceResponsibleParty>> = None;
+        let mut owners: Option<Vec<DataGovernanceResponsibleParty>> = None;
+
+        let mut got_end_tag = false;
+        while !got_end_tag {
+            let next_element = event_reader
+                .next()
+                .map_err(to_xml_read_error(GOVERNANCE_TAG))?;
+            match next_element {
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == CUSTODIANS_TAG =>
+                {
+                    custodians = Some(read_list_tag(event_reader, &name, CUSTODIAN_TAG)?)
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == STEWARDS_TAG => {
+                    stewards = Some(read_list_tag(event_reader, &name, STEWARD_TAG)?)
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == OWNERS_TAG => {
+                    owners = Some(read_list_tag(event_reader, &name, OWNER_TAG)?)
+                }
+                // lax validation of any elements from a different schema
+                reader::XmlEvent::StartElement { name, .. } => {
+                    read_lax_validation_tag(event_reader, &name)?
+                }
+                reader::XmlEvent::EndElement { name } if &name == element_name => {
+                    got_end_tag = true;
+                }
+                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+            }
+        }
+
+        Ok(Self {
+            custodians,
+            stewards,
+            owners,
+        })
+    }
+}
+
+/// A responsible party is either an organization or an individual contact
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum DataGovernanceResponsibleParty {
+    Organization(OrganizationalEntity),
+    Contact(OrganizationalContact),
+}
+
+impl From<models::component_data::DataGovernanceResponsibleParty>
+    for DataGovernanceResponsibleParty
+{
+    fn from(other: models::component_data::DataGovernanceResponsibleParty) -> Self {
+        match other {
+            models::component_data::DataGovernanceResponsibleParty::Organization(organization) => {
+                Self::Organization(organization.into())
+            }
+            models::component_data::DataGovernanceResponsibleParty::Contact(contact) => {
+                Self::Contact(contact.into())
+            }
+        }
+    }
+}
+
+impl From<DataGovernanceResponsibleParty>
+    for models::component_data::DataGovernanceResponsibleParty
+{
+    fn from(other: DataGovernanceResponsibleParty) -> Self {
+        match other {
+            DataGovernanceResponsibleParty::Organization(organization) => {
+                Self::Organization(organization.into())
+            }
+            DataGovernanceResponsibleParty::Contact(contact) => Self::Contact(contact.into()),
+        }
+    }
+}
+
+const ORGANIZATION_TAG: &str = "organization";
+const CONTACT_TAG: &str = "contact";
+
+#[cfg(feature = "xml")]
+impl ToInnerXml for DataGovernanceResponsibleParty {
+    fn write_xml_named_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+        tag: &str,
+    ) -> Result<(), XmlWriteError> {
+        writer
+            .write(XmlEvent::start_element(tag))
+            .map_err(to_xml_write_error(tag))?;
+
+        match self {
+            DataGovernanceResponsibleParty::Organization(organization) => {
+                organization.write_xml_named_element(writer, ORGANIZATION_TAG)?
+            }
+            DataGovernanceResponsibleParty::Contact(contact) => {
+                contact.write_xml_named_element(writer, CONTACT_TAG)?
+            }
+        }
+
+        writer
+            .write(XmlEvent::end_element())
+            .map_err(to_xml_write_error(tag))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "xml")]
+impl FromXml for DataGovernanceResponsibleParty {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut xml::EventReader<R>,
+        element_name: &xml::name::OwnedName,
+        _attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let mut party: Option<Self> = None;
+
+        let mut got_end_tag = false;
+        while !got_end_tag {
+            let next_element = event_reader
+                .next()
+                .map_err(to_xml_read_error(&element_name.local_name))?;
+            match next_element {
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == ORGANIZATION_TAG => {
+                    party = Some(Self::Organization(OrganizationalEntity::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?))
+                }
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == CONTACT_TAG => {
+                    party = Some(Self::Contact(OrganizationalContact::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?))
+                }
+                // lax validation of any elements from a different schema
+                reader::XmlEvent::StartElement { name, .. } => {
+                    read_lax_validation_tag(event_reader, &name)?
+                }
+                reader::XmlEvent::EndElement { name } if &name == element_name => {
+                    got_end_tag = true;
+                }
+                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+            }
+        }
+
+        party.ok_or_else(|| XmlReadError::RequiredDataMissing {
+            required_field: format!("{} or {}", ORGANIZATION_TAG, CONTACT_TAG),
+            element: element_name.local_name.to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+    use super::*;
+    use crate::specs::v1_5::{
+        attached_text::test::{corresponding_attached_text, example_attached_text},
+        organization::test::{
+            corresponding_contact, corresponding_entity, example_contact, example_entity,
+        },
+        property::test::{corresponding_properties, example_properties},
+    };
+    #[cfg(feature = "xml")]
+    use crate::xml::test::{read_element_from_string, write_element_to_string};
+
+    pub(crate) fn example_component_data() -> ComponentData {
+        ComponentData {
+            bom_ref: Some("dataset-1".to_string()),
+            data_type: "dataset".to_string(),
+            name: Some("name".to_string()),
+            contents: Some(DataContents {
+                attachment: Some(example_attached_text()),
+                url: Some("https://example.com/dataset".to_string()),
+                properties: Some(example_properties()),
+            }),
+            classification: Some("public".to_string()),
+            sensitive_data: Some(vec!["PII".to_string()]),
+            graphics: Some(GraphicsCollection {
+                description: Some("description".to_string()),
+                collection: Some(vec![Graphic {
+                    name: Some("name".to_string()),
+                    image: Some(example_attached_text()),
+                }]),
+            }),
+            description: Some("description".to_string()),
+            governance: Some(DataGovernance {
+                custodians: Some(vec![DataGovernanceResponsibleParty::Organization(
+                    example_entity(),
+                )]),
+                stewards: None,
+                owners: Some(vec![DataGovernanceResponsibleParty::Contact(
+                    example_contact(),
+                )]),
+            }),
+        }
+    }
+
+    pub(crate) fn corresponding_component_data() -> models::component_data::ComponentData {
+        models::component_data::ComponentData {
+            bom_ref: Some("dataset-1".to_string()),
+            data_type: models::component_data::ComponentDataType::Dataset,
+            name: Some(NormalizedString::new_unchecked("name".to_string())),
+            contents: Some(models::component_data::DataContents {
+                attachment: Some(corresponding_attached_text()),
+                url: Some(Uri("https://example.com/dataset".to_string())),
+                properties: Some(corresponding_properties()),
+            }),
+            classification: Some(NormalizedString::new_unchecked("public".to_string())),
+            sensitive_data: Some(vec![NormalizedString::new_unchecked("PII".to_string())]),
+            graphics: Some(models::component_data::GraphicsCollection {
+                description: Some(NormalizedString::new_unchecked("description".to_string())),
+                collection: Some(vec![models::component_data::Graphic {
+                    name: Some(NormalizedString::new_unchecked("name".to_string())),
+                    image: Some(corresponding_attached_text()),
+                }]),
+            }),
+            description: Some(NormalizedString::new_unchecked("description".to_string())),
+            governance: Some(models::component_data::DataGovernance {
+                custodians: Some(vec![
+                    models::component_data::DataGovernanceResponsibleParty::Organization(
+                        corresponding_entity(),
+                    ),
+                ]),
+                stewards: None,
+                owners: Some(vec![
+                    models::component_data::DataGovernanceResponsibleParty::Contact(
+                        corresponding_contact(),
+                    ),
+                ]),
+            }),
+        }
+    }
+
+    #[test]
+    fn it_should_convert_between_spec_and_model() {
+        let model: models::component_data::ComponentData = example_component_data().into();
+        assert_eq!(model, corresponding_component_data());
+        assert_eq!(ComponentData::from(model), example_component_data());
+    }
+
+    #[test]
+    fn it_should_handle_json() {
+        let json = serde_json::to_value(example_component_data()).unwrap();
+        assert_eq!(json["type"], "dataset");
+        assert_eq!(json["bom-ref"], "dataset-1");
+        assert_eq!(json["sensitiveData"][0], "PII");
+        assert_eq!(
+            json["governance"]["custodians"][0]["organization"]["name"],
+            "name"
+        );
+        assert_eq!(json["governance"]["owners"][0]["contact"]["email"], "email");
+
+        let parsed: ComponentData = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed, example_component_data());
+    }
+
+    #[cfg(feature = "xml")]
+    #[test]
+    fn it_should_write_xml_full() {
+        let xml_output = write_element_to_string(example_component_data());
+        insta::assert_snapshot!(xml_output);
+    }
+
+    #[cfg(feature = "xml")]
+    #[test]
+    fn it_should_read_xml_full() {
+        let input = r#"
+<data bom-ref="dataset-1">
+  <type>dataset</type>
+  <name>name</name>
+  <contents>
+    <attachment content-type="content type" encoding="encoding">content</attachment>
+    <url>https://example.com/dataset</url>
+    <properties>
+      <property name="name">value</property>
+    </properties>
+  </contents>
+  <classification>public</classification>
+  <sensitiveData>PII</sensitiveData>
+  <graphics>
+    <description>description</description>
+    <collection>
+      <graphic>
+        <name>name</name>
+        <image content-type="content type" encoding="encoding">content</image>
+      </graphic>
+    </collection>
+  </graphics>
+  <description>description</description>
+  <governance>
+    <custodians>
+      <custodian>
+        <organization>
+          <name>name</name>
+          <url>url</url>
+          <contact>
+            <name>name</name>
+            <email>email</email>
+            <phone>phone</phone>
+          </contact>
+        </organization>
+      </custodian>
+    </custodians>
+    <owners>
+      <owner>
+        <contact>
+          <name>name</name>
+          <email>email</email>
+          <phone>phone</phone>
+        </contact>
+      </owner>
+    </owners>
+  </governance>
+</data>
+"#;
+        let actual: ComponentData = read_element_from_string(input);
+        let expected = example_component_data();
+        assert_eq!(actual, expected);
+    }
+}
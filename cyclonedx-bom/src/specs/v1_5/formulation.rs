@@ -0,0 +1,1342 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#[cfg(feature = "xml")]
+use crate::{
+    errors::XmlReadError,
+    xml::{
+        attribute_or_error, optional_attribute, read_lax_validation_list_tag,
+        read_lax_validation_tag, read_list_tag, read_simple_tag, to_xml_read_error,
+        to_xml_write_error, unexpected_element_error, write_simple_tag, FromXml, ToXml,
+    },
+};
+use crate::{
+    external_models::{date_time::DateTime, normalized_string::NormalizedString},
+    models,
+    specs::v1_5::{component::Components, property::Properties, service::Services},
+    utilities::{convert_optional, convert_optional_vec, convert_vec},
+};
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "xml")]
+use xml::{reader, writer::XmlEvent};
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(transparent)]
+pub struct Formulation(pub Vec<Formula>);
+
+impl From<models::formulation::Formulation> for Formulation {
+    fn from(other: models::formulation::Formulation) -> Self {
+        Formulation(convert_vec(other.0))
+    }
+}
+
+impl From<Formulation> for models::formulation::Formulation {
+    fn from(other: Formulation) -> Self {
+        models::formulation::Formulation(convert_vec(other.0))
+    }
+}
+
+const FORMULATION_TAG: &str = "formulation";
+
+#[cfg(feature = "xml")]
+impl ToXml for Formulation {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), crate::errors::XmlWriteError> {
+        writer
+            .write(XmlEvent::start_element(FORMULATION_TAG))
+            .map_err(to_xml_write_error(FORMULATION_TAG))?;
+
+        for formula in &self.0 {
+            formula.write_xml_element(writer)?;
+        }
+
+        writer
+            .write(XmlEvent::end_element())
+            .map_err(to_xml_write_error(FORMULATION_TAG))?;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "xml")]
+impl FromXml for Formulation {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut xml::EventReader<R>,
+        element_name: &xml::name::OwnedName,
+        _attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        read_lax_validation_list_tag(event_reader, element_name, FORMULA_TAG).map(Formulation)
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Formula {
+    #[serde(rename = "bom-ref", skip_serializing_if = "Option::is_none")]
+    pub bom_ref: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub components: Option<Components>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub services: Option<Services>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub workflows: Option<Vec<Workflow>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub properties: Option<Properties>,
+}
+
+impl From<models::formulation::Formula> for Formula {
+    fn from(other: models::formulation::Formula) -> Self {
+        Self {
+            bom_ref: other.bom_ref,
+            components: convert_optional(other.components),
+            services: convert_optional(other.services),
+            workflows: convert_optional_vec(other.workflows),
+            properties: convert_optional(other.properties),
+        }
+    }
+}
+
+impl From<Formula> for models::formulation::Formula {
+    fn from(other: Formula) -> Self {
+        Self {
+            bom_ref: other.bom_ref,
+            components: convert_optional(other.components),
+            services: convert_optional(other.services),
+            workflows: convert_optional_vec(other.workflows),
+            properties: convert_optional(other.properties),
+        }
+    }
+}
+
+const FORMULA_TAG: &str = "formula";
+const BOM_REF_ATTR: &str = "bom-ref";
+const COMPONENTS_TAG: &str = "components";
+const SERVICES_TAG: &str = "services";
+const WORKFLOWS_TAG: &str = "workflows";
+const PROPERTIES_TAG: &str = "properties";
+
+#[cfg(feature = "xml")]
+impl ToXml for Formula {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), crate::errors::XmlWriteError> {
+        let mut formula_start_tag = XmlEvent::start_element(FORMULA_TAG);
+        if let Some(bom_ref) = &self.bom_ref {
+            formula_start_tag = formula_start_tag.attr(BOM_REF_ATTR, bom_ref);
+        }
+        writer
+            .write(formula_start_tag)
+            .map_err(to_xml_write_error(FORMULA_TAG))?;
+
+        if let Some(components) = &self.components {
+            components.write_xml_element(writer)?;
+        }
+
+        if let Some(services) = &self.services {
+            services.write_xml_element(writer)?;
+        }
+
+        if let Some(workflows) = &self.workflows {
+            writer
+                .write(XmlEvent::start_element(WORKFLOWS_TAG))
+                .map_err(to_xml_write_error(WORKFLOWS_TAG))?;
+
+            for workflow in workflows {
+                workflow.write_xml_element(writer)?;
+            }
+
+            writer
+                .write(XmlEvent::end_element())
+                .map_err(to_xml_write_error(WORKFLOWS_TAG))?;
+        }
+
+        if let Some(properties) = &self.properties {
+            properties.write_xml_element(writer)?;
+        }
+
+        writer
+            .write(XmlEvent::end_element())
+            .map_err(to_xml_write_error(FORMULA_TAG))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "xml")]
+impl FromXml for Formula {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut xml::EventReader<R>,
+        element_name: &xml::name::OwnedName,
+        attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let bom_ref = optional_attribute(attributes, BOM_REF_ATTR);
+        let mut components: Option<Components> = None;
+        let mut services: Option<Services> = None;
+        let mut workflows: Option<Vec<Workflow>> = None;
+        let mut properties: Option<Properties> = None;
+
+        let mut got_end_tag = false;
+        while !got_end_tag {
+            let next_element = event_reader
+                .next()
+                .map_err(to_xml_read_error(FORMULA_TAG))?;
+            match next_element {
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == COMPONENTS_TAG => {
+                    components = Some(Components::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?)
+                }
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == SERVICES_TAG => {
+                    services = Some(Services::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?)
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == WORKFLOWS_TAG => {
+                    workflows = Some(read_lax_validation_list_tag(
+                        event_reader,
+                        &name,
+                        WORKFLOW_TAG,
+                    )?)
+                }
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == PROPERTIES_TAG => {
+                    properties = Some(Properties::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?)
+                }
+                // lax validation of any elements from a different schema
+                reader::XmlEvent::StartElement { name, .. } => {
+                    read_lax_validation_tag(event_reader, &name)?
+                }
+                reader::XmlEvent::EndElement { name } if &name == element_name => {
+                    got_end_tag = true;
+                }
+                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+            }
+        }
+
+        Ok(Self {
+            bom_ref,
+            components,
+            services,
+            workflows,
+            properties,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Workflow {
+    #[serde(rename = "bom-ref")]
+    pub bom_ref: String,
+    pub uid: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub task_types: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub steps: Option<Vec<Step>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inputs: Option<Vec<Input>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_start: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_end: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub properties: Option<Properties>,
+}
+
+impl From<models::formulation::Workflow> for Workflow {
+    fn from(other: models::formulation::Workflow) -> Self {
+        Self {
+            bom_ref: other.bom_ref,
+            uid: other.uid,
+            name: other.name.map(|n| n.to_string()),
+            description: other.description.map(|d| d.to_string()),
+            task_types: other.task_types.iter().map(|t| t.to_string()).collect(),
+            steps: convert_optional_vec(other.steps),
+            inputs: convert_optional_vec(other.inputs),
+            time_start: other.time_start.map(|t| t.to_string()),
+            time_end: other.time_end.map(|t| t.to_string()),
+            properties: convert_optional(other.properties),
+        }
+    }
+}
+
+impl From<Workflow> for models::formulation::Workflow {
+    fn from(other: Workflow) -> Self {
+        Self {
+            bom_ref: other.bom_ref,
+            uid: other.uid,
+            name: other.name.map(NormalizedString::new_unchecked),
+            description: other.description.map(NormalizedString::new_unchecked),
+            task_types: other
+                .task_types
+                .into_iter()
+                .map(models::formulation::TaskType::new_unchecked)
+                .collect(),
+            steps: convert_optional_vec(other.steps),
+            inputs: convert_optional_vec(other.inputs),
+            time_start: other.time_start.map(DateTime),
+            time_end: other.time_end.map(DateTime),
+            properties: convert_optional(other.properties),
+        }
+    }
+}
+
+const WORKFLOW_TAG: &str = "workflow";
+const UID_TAG: &str = "uid";
+const NAME_TAG: &str = "name";
+const DESCRIPTION_TAG: &str = "description";
+const TASK_TYPES_TAG: &str = "taskTypes";
+const TASK_TYPE_TAG: &str = "taskType";
+const STEPS_TAG: &str = "steps";
+const INPUTS_TAG: &str = "inputs";
+const TIME_START_TAG: &str = "timeStart";
+const TIME_END_TAG: &str = "timeEnd";
+
+#[cfg(feature = "xml")]
+impl ToXml for Workflow {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), crate::errors::XmlWriteError> {
+        writer
+            .write(XmlEvent::start_element(WORKFLOW_TAG).attr(BOM_REF_ATTR, &self.bom_ref))
+            .map_err(to_xml_write_error(WORKFLOW_TAG))?;
+
+        write_simple_tag(writer, UID_TAG, &self.uid)?;
+
+        if let Some(name) = &self.name {
+            write_simple_tag(writer, NAME_TAG, name)?;
+        }
+
+        if let Some(description) = &self.description {
+            write_simple_tag(writer, DESCRIPTION_TAG, description)?;
+        }
+
+        writer
+            .write(XmlEvent::start_element(TASK_TYPES_TAG))
+            .map_err(to_xml_write_error(TASK_TYPES_TAG))?;
+        for task_type in &self.task_types {
+            write_simple_tag(writer, TASK_TYPE_TAG, task_type)?;
+        }
+        writer
+            .write(XmlEvent::end_element())
+            .map_err(to_xml_write_error(TASK_TYPES_TAG))?;
+
+        if let Some(steps) = &self.steps {
+            writer
+                .write(XmlEvent::start_element(STEPS_TAG))
+                .map_err(to_xml_write_error(STEPS_TAG))?;
+            for step in steps {
+                step.write_xml_element(writer)?;
+            }
+            writer
+                .write(XmlEvent::end_element())
+                .map_err(to_xml_write_error(STEPS_TAG))?;
+        }
+
+        if let Some(inputs) = &self.inputs {
+            writer
+                .write(XmlEvent::start_element(INPUTS_TAG))
+                .map_err(to_xml_write_error(INPUTS_TAG))?;
+            for input in inputs {
+                input.write_xml_element(writer)?;
+            }
+            writer
+                .write(XmlEvent::end_element())
+                .map_err(to_xml_write_error(INPUTS_TAG))?;
+        }
+
+        if let Some(time_start) = &self.time_start {
+            write_simple_tag(writer, TIME_START_TAG, time_start)?;
+        }
+
+        if let Some(time_end) = &self.time_end {
+            write_simple_tag(writer, TIME_END_TAG, time_end)?;
+        }
+
+        if let Some(properties) = &self.properties {
+            properties.write_xml_element(writer)?;
+        }
+
+        writer
+            .write(XmlEvent::end_element())
+            .map_err(to_xml_write_error(WORKFLOW_TAG))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "xml")]
+impl FromXml for Workflow {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut xml::EventReader<R>,
+        element_name: &xml::name::OwnedName,
+        attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let bom_ref = attribute_or_error(element_name, attributes, BOM_REF_ATTR)?;
+        let mut uid: Option<String> = None;
+        let mut workflow_name: Option<String> = None;
+        let mut description: Option<String> = None;
+        let mut task_types: Option<Vec<String>> = None;
+        let mut steps: Option<Vec<Step>> = None;
+        let mut inputs: Option<Vec<Input>> = None;
+        let mut time_start: Option<String> = None;
+        let mut time_end: Option<String> = None;
+        let mut properties: Option<Properties> = None;
+
+        let mut got_end_tag = false;
+        while !got_end_tag {
+            let next_element = event_reader
+                .next()
+                .map_err(to_xml_read_error(WORKFLOW_TAG))?;
+            match next_element {
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == UID_TAG => {
+                    uid = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == NAME_TAG => {
+                    workflow_name = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == DESCRIPTION_TAG =>
+                {
+                    description = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == TASK_TYPES_TAG =>
+                {
+                    task_types = Some(read_list_tag(event_reader, &name, TASK_TYPE_TAG)?)
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == STEPS_TAG => {
+                    steps = Some(read_lax_validation_list_tag(event_reader, &name, STEP_TAG)?)
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == INPUTS_TAG => {
+                    inputs = Some(read_lax_validation_list_tag(
+                        event_reader,
+                        &name,
+                        INPUT_TAG,
+                    )?)
+                }
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == TIME_START_TAG =>
+                {
+                    time_start = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == TIME_END_TAG => {
+                    time_end = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == PROPERTIES_TAG => {
+                    properties = Some(Properties::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?)
+                }
+                // Fields of workflows that aren't represented yet are skipped too
+                reader::XmlEvent::StartElement { name, .. } => {
+                    read_lax_validation_tag(event_reader, &name)?
+                }
+                reader::XmlEvent::EndElement { name } if &name == element_name => {
+                    got_end_tag = true;
+                }
+                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+            }
+        }
+
+        let uid = uid.ok_or_else(|| XmlReadError::RequiredDataMissing {
+            required_field: UID_TAG.to_string(),
+            element: element_name.local_name.to_string(),
+        })?;
+        let task_types = task_types.ok_or_else(|| XmlReadError::RequiredDataMissing {
+            required_field: TASK_TYPES_TAG.to_string(),
+            element: element_name.local_name.to_string(),
+        })?;
+
+        Ok(Self {
+            bom_ref,
+            uid,
+            name: workflow_name,
+            description,
+            task_types,
+            steps,
+            inputs,
+            time_start,
+            time_end,
+            properties,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Step {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub commands: Option<Vec<Command>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub properties: Option<Properties>,
+}
+
+impl From<models::formulation::Step> for Step {
+    fn from(other: models::formulation::Step) -> Self {
+        Self {
+            name: other.name.map(|n| n.to_string()),
+            description: other.description.map(|d| d.to_string()),
+            commands: convert_optional_vec(other.commands),
+            properties: convert_optional(other.properties),
+        }
+    }
+}
+
+impl From<Step> for models::formulation::Step {
+    fn from(other: Step) -> Self {
+        Self {
+            name: other.name.map(NormalizedString::new_unchecked),
+            description: other.description.map(NormalizedString::new_unchecked),
+            commands: convert_optional_vec(other.commands),
+            properties: convert_optional(other.properties),
+        }
+    }
+}
+
+const STEP_TAG: &str = "step";
+const COMMANDS_TAG: &str = "commands";
+
+#[cfg(feature = "xml")]
+impl ToXml for Step {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), crate::errors::XmlWriteError> {
+        writer
+            .write(XmlEvent::start_element(STEP_TAG))
+            .map_err(to_xml_write_error(STEP_TAG))?;
+
+        if let Some(name) = &self.name {
+            write_simple_tag(writer, NAME_TAG, name)?;
+        }
+
+        if let Some(description) = &self.description {
+            write_simple_tag(writer, DESCRIPTION_TAG, description)?;
+        }
+
+        if let Some(commands) = &self.commands {
+            writer
+                .write(XmlEvent::start_element(COMMANDS_TAG))
+                .map_err(to_xml_write_error(COMMANDS_TAG))?;
+            for command in commands {
+                command.write_xml_element(writer)?;
+            }
+            writer
+                .write(XmlEvent::end_element())
+                .map_err(to_xml_write_error(COMMANDS_TAG))?;
+        }
+
+        if let Some(properties) = &self.properties {
+            properties.write_xml_element(writer)?;
+        }
+
+        writer
+            .write(XmlEvent::end_element())
+            .map_err(to_xml_write_error(STEP_TAG))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "xml")]
+impl FromXml for Step {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut xml::EventReader<R>,
+        element_name: &xml::name::OwnedName,
+        _attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let mut step_name: Option<String> = None;
+        let mut description: Option<String> = None;
+        let mut commands: Option<Vec<Command>> = None;
+        let mut properties: Option<Properties> = None;
+
+        let mut got_end_tag = false;
+        while !got_end_tag {
+            let next_element = event_reader.next().map_err(to_xml_read_error(STEP_TAG))?;
+            match next_element {
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == NAME_TAG => {
+                    step_name = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == DESCRIPTION_TAG =>
+                {
+                    description = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == COMMANDS_TAG => {
+                    commands = Some(read_lax_validation_list_tag(
+                        event_reader,
+                        &name,
+                        COMMAND_TAG,
+                    )?)
+                }
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == PROPERTIES_TAG => {
+                    properties = Some(Properties::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?)
+                }
+                // lax validation of any elements from a different schema
+                reader::XmlEvent::StartElement { name, .. } => {
+                    read_lax_validation_tag(event_reader, &name)?
+                }
+                reader::XmlEvent::EndElement { name } if &name == element_name => {
+                    got_end_tag = true;
+                }
+                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+            }
+        }
+
+        Ok(Self {
+            name: step_name,
+            description,
+            commands,
+            properties,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Command {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub executed: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub properties: Option<Properties>,
+}
+
+impl From<models::formulation::Command> for Command {
+    fn from(other: models::formulation::Command) -> Self {
+        Self {
+            executed: other.executed,
+            properties: convert_optional(other.properties),
+        }
+    }
+}
+
+impl From<Command> for models::formulation::Command {
+    fn from(other: Command) -> Self {
+        Self {
+            executed: other.executed,
+            properties: convert_optional(other.properties),
+        }
+    }
+}
+
+const COMMAND_TAG: &str = "command";
+const EXECUTED_TAG: &str = "executed";
+
+#[cfg(feature = "xml")]
+impl ToXml for Command {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), crate::errors::XmlWriteError> {
+        writer
+            .write(XmlEvent::start_element(COMMAND_TAG))
+            .map_err(to_xml_write_error(COMMAND_TAG))?;
+
+        if let Some(executed) = &self.executed {
+            write_simple_tag(writer, EXECUTED_TAG, executed)?;
+        }
+
+        if let Some(properties) = &self.properties {
+            properties.write_xml_element(writer)?;
+        }
+
+        writer
+            .write(XmlEvent::end_element())
+            .map_err(to_xml_write_error(COMMAND_TAG))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "xml")]
+impl FromXml for Command {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut xml::EventReader<R>,
+        element_name: &xml::name::OwnedName,
+        _attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let mut executed: Option<String> = None;
+        let mut properties: Option<Properties> = None;
+
+        let mut got_end_tag = false;
+        while !got_end_tag {
+            let next_element = event_reader
+                .next()
+                .map_err(to_xml_read_error(COMMAND_TAG))?;
+            match next_element {
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == EXECUTED_TAG => {
+                    executed = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == PROPERTIES_TAG => {
+                    properties = Some(Properties::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?)
+                }
+                // lax validation of any elements from a different schema
+                reader::XmlEvent::StartElement { name, .. } => {
+                    read_lax_validation_tag(event_reader, &name)?
+                }
+                reader::XmlEvent::EndElement { name } if &name == element_name => {
+                    got_end_tag = true;
+                }
+                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+            }
+        }
+
+        Ok(Self {
+            executed,
+            properties,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Input {
+    #[serde(flatten)]
+    pub required: RequiredInputField,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub properties: Option<Properties>,
+}
+
+impl From<models::formulation::Input> for Input {
+    fn from(other: models::formulation::Input) -> Self {
+        Self {
+            required: other.required.into(),
+            properties: convert_optional(other.properties),
+        }
+    }
+}
+
+impl From<Input> for models::formulation::Input {
+    fn from(other: Input) -> Self {
+        Self {
+            required: other.required.into(),
+            properties: convert_optional(other.properties),
+        }
+    }
+}
+
+const INPUT_TAG: &str = "input";
+const PARAMETERS_TAG: &str = "parameters";
+const PARAMETER_TAG: &str = "parameter";
+const ENVIRONMENT_VARS_TAG: &str = "environmentVars";
+
+#[cfg(feature = "xml")]
+impl ToXml for Input {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), crate::errors::XmlWriteError> {
+        writer
+            .write(XmlEvent::start_element(INPUT_TAG))
+            .map_err(to_xml_write_error(INPUT_TAG))?;
+
+        match &self.required {
+            RequiredInputField::Parameters(parameters) => {
+                writer
+                    .write(XmlEvent::start_element(PARAMETERS_TAG))
+                    .map_err(to_xml_write_error(PARAMETERS_TAG))?;
+                for parameter in parameters {
+                    parameter.write_xml_element(writer)?;
+                }
+                writer
+                    .write(XmlEvent::end_element())
+                    .map_err(to_xml_write_error(PARAMETERS_TAG))?;
+            }
+            RequiredInputField::EnvironmentVars(environment_vars) => {
+                writer
+                    .write(XmlEvent::start_element(ENVIRONMENT_VARS_TAG))
+                    .map_err(to_xml_write_error(ENVIRONMENT_VARS_TAG))?;
+                for environment_var in environment_vars {
+                    environment_var.write_xml_element(writer)?;
+                }
+                writer
+                    .write(XmlEvent::end_element())
+                    .map_err(to_xml_write_error(ENVIRONMENT_VARS_TAG))?;
+            }
+        }
+
+        if let Some(properties) = &self.properties {
+            properties.write_xml_element(writer)?;
+        }
+
+        writer
+            .write(XmlEvent::end_element())
+            .map_err(to_xml_write_error(INPUT_TAG))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "xml")]
+impl FromXml for Input {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut xml::EventReader<R>,
+        element_name: &xml::name::OwnedName,
+        _attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let mut required: Option<RequiredInputField> = None;
+        let mut properties: Option<Properties> = None;
+
+        let mut got_end_tag = false;
+        while !got_end_tag {
+            let next_element = event_reader.next().map_err(to_xml_read_error(INPUT_TAG))?;
+            match next_element {
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == PARAMETERS_TAG =>
+                {
+                    required = Some(RequiredInputField::Parameters(
+                        read_lax_validation_list_tag(event_reader, &name, PARAMETER_TAG)?,
+                    ))
+                }
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == ENVIRONMENT_VARS_TAG =>
+                {
+                    required = Some(RequiredInputField::EnvironmentVars(read_environment_vars(
+                        event_reader,
+                        &name,
+                    )?))
+                }
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == PROPERTIES_TAG => {
+                    properties = Some(Properties::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?)
+                }
+                // lax validation of any elements from a different schema
+                reader::XmlEvent::StartElement { name, .. } => {
+                    read_lax_validation_tag(event_reader, &name)?
+                }
+                reader::XmlEvent::EndElement { name } if &name == element_name => {
+                    got_end_tag = true;
+                }
+                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+            }
+        }
+
+        let required = required.ok_or_else(|| XmlReadError::RequiredDataMissing {
+            required_field: format!("{} or {}", PARAMETERS_TAG, ENVIRONMENT_VARS_TAG),
+            element: element_name.local_name.to_string(),
+        })?;
+
+        Ok(Self {
+            required,
+            properties,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum RequiredInputField {
+    Parameters(Vec<Parameter>),
+    EnvironmentVars(Vec<EnvironmentVar>),
+}
+
+impl From<models::formulation::RequiredInputField> for RequiredInputField {
+    fn from(other: models::formulation::RequiredInputField) -> Self {
+        match other {
+            models::formulation::RequiredInputField::Parameters(parameters) => {
+                Self::Parameters(convert_vec(parameters))
+            }
+            models::formulation::RequiredInputField::EnvironmentVars(environment_vars) => {
+                Self::EnvironmentVars(convert_vec(environment_vars))
+            }
+        }
+    }
+}
+
+impl From<RequiredInputField> for models::formulation::RequiredInputField {
+    fn from(other: RequiredInputField) -> Self {
+        match other {
+            RequiredInputField::Parameters(parameters) => Self::Parameters(convert_vec(parameters)),
+            RequiredInputField::EnvironmentVars(environment_vars) => {
+                Self::EnvironmentVars(convert_vec(environment_vars))
+            }
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Parameter {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data_type: Option<String>,
+}
+
+impl From<models::formulation::Parameter> for Parameter {
+    fn from(other: models::formulation::Parameter) -> Self {
+        Self {
+            name: other.name,
+            value: other.value,
+            data_type: other.data_type,
+        }
+    }
+}
+
+impl From<Parameter> for models::formulation::Parameter {
+    fn from(other: Parameter) -> Self {
+        Self {
+            name: other.name,
+            value: other.value,
+            data_type: other.data_type,
+        }
+    }
+}
+
+const VALUE_TAG: &str = "value";
+const DATA_TYPE_TAG: &str = "dataType";
+
+#[cfg(feature = "xml")]
+impl ToXml for Parameter {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), crate::errors::XmlWriteError> {
+        writer
+            .write(XmlEvent::start_element(PARAMETER_TAG))
+            .map_err(to_xml_write_error(PARAMETER_TAG))?;
+
+        if let Some(name) = &self.name {
+            write_simple_tag(writer, NAME_TAG, name)?;
+        }
+
+        if let Some(value) = &self.value {
+            write_simple_tag(writer, VALUE_TAG, value)?;
+        }
+
+        if let Some(data_type) = &self.data_type {
+            write_simple_tag(writer, DATA_TYPE_TAG, data_type)?;
+        }
+
+        writer
+            .write(XmlEvent::end_element())
+            .map_err(to_xml_write_error(PARAMETER_TAG))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "xml")]
+impl FromXml for Parameter {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut xml::EventReader<R>,
+        element_name: &xml::name::OwnedName,
+        _attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let mut parameter_name: Option<String> = None;
+        let mut value: Option<String> = None;
+        let mut data_type: Option<String> = None;
+
+        let mut got_end_tag = false;
+        while !got_end_tag {
+            let next_element = event_reader
+                .next()
+                .map_err(to_xml_read_error(PARAMETER_TAG))?;
+            match next_element {
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == NAME_TAG => {
+                    parameter_name = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == VALUE_TAG => {
+                    value = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == DATA_TYPE_TAG => {
+                    data_type = Some(read_simple_tag(event_reader, &name)?)
+                }
+                // lax validation of any elements from a different schema
+                reader::XmlEvent::StartElement { name, .. } => {
+                    read_lax_validation_tag(event_reader, &name)?
+                }
+                reader::XmlEvent::EndElement { name } if &name == element_name => {
+                    got_end_tag = true;
+                }
+                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+            }
+        }
+
+        Ok(Self {
+            name: parameter_name,
+            value,
+            data_type,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(untagged)]
+pub enum EnvironmentVar {
+    Property { name: String, value: String },
+    Value(String),
+}
+
+impl From<models::formulation::EnvironmentVar> for EnvironmentVar {
+    fn from(other: models::formulation::EnvironmentVar) -> Self {
+        match other {
+            models::formulation::EnvironmentVar::Property { name, value } => {
+                Self::Property { name, value }
+            }
+            models::formulation::EnvironmentVar::Value(value) => Self::Value(value),
+        }
+    }
+}
+
+impl From<EnvironmentVar> for models::formulation::EnvironmentVar {
+    fn from(other: EnvironmentVar) -> Self {
+        match other {
+            EnvironmentVar::Property { name, value } => Self::Property { name, value },
+            EnvironmentVar::Value(value) => Self::Value(value),
+        }
+    }
+}
+
+const ENVIRONMENT_VAR_TAG: &str = "environmentVar";
+const NAME_ATTR: &str = "name";
+
+#[cfg(feature = "xml")]
+impl ToXml for EnvironmentVar {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), crate::errors::XmlWriteError> {
+        match self {
+            EnvironmentVar::Property { name, value } => {
+                writer
+                    .write(XmlEvent::start_element(ENVIRONMENT_VAR_TAG).attr(NAME_ATTR, name))
+                    .map_err(to_xml_write_error(ENVIRONMENT_VAR_TAG))?;
+                writer
+                    .write(XmlEvent::characters(value))
+                    .map_err(to_xml_write_error(ENVIRONMENT_VAR_TAG))?;
+                writer
+                    .write(XmlEvent::end_element())
+                    .map_err(to_xml_write_error(ENVIRONMENT_VAR_TAG))?;
+            }
+            EnvironmentVar::Value(value) => write_simple_tag(writer, VALUE_TAG, value)?,
+        }
+
+        Ok(())
+    }
+}
+
+/// Environment variables are either `environmentVar` elements with a name or plain `value` elements
+#[cfg(feature = "xml")]
+fn read_environment_vars<R: std::io::Read>(
+    event_reader: &mut xml::EventReader<R>,
+    element_name: &xml::name::OwnedName,
+) -> Result<Vec<EnvironmentVar>, XmlReadError> {
+    let mut environment_vars = Vec::new();
+
+    let mut got_end_tag = false;
+    while !got_end_tag {
+        let next_element = event_reader
+            .next()
+            .map_err(to_xml_read_error(ENVIRONMENT_VARS_TAG))?;
+        match next_element {
+            reader::XmlEvent::StartElement {
+                name, attributes, ..
+            } if name.local_name == ENVIRONMENT_VAR_TAG => {
+                let var_name = attribute_or_error(&name, &attributes, NAME_ATTR)?;
+                let value = read_simple_tag(event_reader, &name)?;
+                environment_vars.push(EnvironmentVar::Property {
+                    name: var_name,
+                    value,
+                });
+            }
+            reader::XmlEvent::StartElement { name, .. } if name.local_name == VALUE_TAG => {
+                environment_vars.push(EnvironmentVar::Value(read_simple_tag(event_reader, &name)?));
+            }
+            // lax validation of any elements from a different schema
+            reader::XmlEvent::StartElement { name, .. } => {
+                read_lax_validation_tag(event_reader, &name)?
+            }
+            reader::XmlEvent::EndElement { name } if &name == element_name => {
+                got_end_tag = true;
+            }
+            unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+        }
+    }
+
+    Ok(environment_vars)
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+    use crate::specs::v1_5::property::test::{corresponding_properties, example_properties};
+    #[cfg(feature = "xml")]
+    use crate::xml::test::{read_element_from_string, write_element_to_string};
+
+    use super::*;
+
+    pub(crate) fn example_formulation() -> Formulation {
+        Formulation(vec![Formula {
+            bom_ref: Some("formula-1".to_string()),
+            components: None,
+            services: None,
+            workflows: Some(vec![Workflow {
+                bom_ref: "workflow-1".to_string(),
+                uid: "uid".to_string(),
+                name: Some("name".to_string()),
+                description: Some("description".to_string()),
+                task_types: vec!["build".to_string()],
+                steps: Some(vec![Step {
+                    name: Some("name".to_string()),
+                    description: Some("description".to_string()),
+                    commands: Some(vec![Command {
+                        executed: Some("executed".to_string()),
+                        properties: Some(example_properties()),
+                    }]),
+                    properties: Some(example_properties()),
+                }]),
+                inputs: Some(vec![
+                    Input {
+                        required: RequiredInputField::Parameters(vec![Parameter {
+                            name: Some("name".to_string()),
+                            value: Some("value".to_string()),
+                            data_type: Some("dataType".to_string()),
+                        }]),
+                        properties: None,
+                    },
+                    Input {
+                        required: RequiredInputField::EnvironmentVars(vec![
+                            EnvironmentVar::Property {
+                                name: "name".to_string(),
+                                value: "value".to_string(),
+                            },
+                            EnvironmentVar::Value("value".to_string()),
+                        ]),
+                        properties: Some(example_properties()),
+                    },
+                ]),
+                time_start: Some("time start".to_string()),
+                time_end: Some("time end".to_string()),
+                properties: Some(example_properties()),
+            }]),
+            properties: Some(example_properties()),
+        }])
+    }
+
+    pub(crate) fn corresponding_formulation() -> models::formulation::Formulation {
+        models::formulation::Formulation(vec![models::formulation::Formula {
+            bom_ref: Some("formula-1".to_string()),
+            components: None,
+            services: None,
+            workflows: Some(vec![models::formulation::Workflow {
+                bom_ref: "workflow-1".to_string(),
+                uid: "uid".to_string(),
+                name: Some(NormalizedString::new_unchecked("name".to_string())),
+                description: Some(NormalizedString::new_unchecked("description".to_string())),
+                task_types: vec![models::formulation::TaskType::Build],
+                steps: Some(vec![models::formulation::Step {
+                    name: Some(NormalizedString::new_unchecked("name".to_string())),
+                    description: Some(NormalizedString::new_unchecked("description".to_string())),
+                    commands: Some(vec![models::formulation::Command {
+                        executed: Some("executed".to_string()),
+                        properties: Some(corresponding_properties()),
+                    }]),
+                    properties: Some(corresponding_properties()),
+                }]),
+                inputs: Some(vec![
+                    models::formulation::Input {
+                        required: models::formulation::RequiredInputField::Parameters(vec![
+                            models::formulation::Parameter {
+                                name: Some("name".to_string()),
+                                value: Some("value".to_string()),
+                                data_type: Some("dataType".to_string()),
+                            },
+                        ]),
+                        properties: None,
+                    },
+                    models::formulation::Input {
+                        required: models::formulation::RequiredInputField::EnvironmentVars(vec![
+                            models::formulation::EnvironmentVar::Property {
+                                name: "name".to_string(),
+                                value: "value".to_string(),
+                            },
+                            models::formulation::EnvironmentVar::Value("value".to_string()),
+                        ]),
+                        properties: Some(corresponding_properties()),
+                    },
+                ]),
+                time_start: Some(DateTime("time start".to_string())),
+                time_end: Some(DateTime("time end".to_string())),
+                properties: Some(corresponding_properties()),
+            }]),
+            properties: Some(corresponding_properties()),
+        }])
+    }
+
+    #[test]
+    fn it_should_convert_to_and_from_the_model() {
+        let model: models::formulation::Formulation = example_formulation().into();
+        assert_eq!(model, corresponding_formulation());
+        assert_eq!(Formulation::from(model), example_formulation());
+    }
+
+    #[test]
+    fn it_should_round_trip_json() {
+        let json = serde_json::to_value(example_formulation()).unwrap();
+        assert_eq!(
+            json[0]["workflows"][0]["inputs"][1]["environmentVars"],
+            serde_json::json!([{ "name": "name", "value": "value" }, "value"])
+        );
+        let actual: Formulation = serde_json::from_value(json).unwrap();
+        assert_eq!(actual, example_formulation());
+    }
+
+    #[cfg(feature = "xml")]
+    #[test]
+    fn it_should_write_xml_full() {
+        let xml_output = write_element_to_string(example_formulation());
+        insta::assert_snapshot!(xml_output);
+    }
+
+    #[cfg(feature = "xml")]
+    #[test]
+    fn it_should_read_xml_full() {
+        let input = r#"
+<formulation>
+  <formula bom-ref="formula-1">
+    <workflows>
+      <workflow bom-ref="workflow-1">
+        <uid>uid</uid>
+        <name>name</name>
+        <description>description</description>
+        <taskTypes>
+          <taskType>build</taskType>
+        </taskTypes>
+        <steps>
+          <step>
+            <name>name</name>
+            <description>description</description>
+            <commands>
+              <command>
+                <executed>executed</executed>
+                <properties>
+                  <property name="name">value</property>
+                </properties>
+              </command>
+            </commands>
+            <properties>
+              <property name="name">value</property>
+            </properties>
+          </step>
+        </steps>
+        <inputs>
+          <input>
+            <parameters>
+              <parameter>
+                <name>name</name>
+                <value>value</value>
+                <dataType>dataType</dataType>
+              </parameter>
+            </parameters>
+          </input>
+          <input>
+            <environmentVars>
+              <environmentVar name="name">value</environmentVar>
+              <value>value</value>
+            </environmentVars>
+            <properties>
+              <property name="name">value</property>
+            </properties>
+          </input>
+        </inputs>
+        <timeStart>time start</timeStart>
+        <timeEnd>time end</timeEnd>
+        <properties>
+          <property name="name">value</property>
+        </properties>
+      </workflow>
+    </workflows>
+    <properties>
+      <property name="name">value</property>
+    </properties>
+  </formula>
+</formulation>
+"#;
+        let actual: Formulation = read_element_from_string(input);
+        let expected = example_formulation();
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,33 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+pub use crate::specs::common::{
+    advisory, attached_text, code, composition, dependency, external_reference, hash, license,
+    organization, property, service, signature, vulnerability_credits, vulnerability_rating,
+    vulnerability_reference, vulnerability_source, vulnerability_target,
+};
+
+pub mod annotation;
+pub mod bom;
+pub mod component;
+pub mod component_data;
+pub mod formulation;
+pub mod lifecycle;
+pub mod metadata;
+pub mod tool;
+pub mod vulnerability;
+pub mod vulnerability_analysis;
@@ -0,0 +1,979 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Reads and writes large BOMs one item at a time.
+//!
+//! Parsing a BOM with [`Bom::parse_from_xml_v1_5`](crate::models::bom::Bom::parse_from_xml_v1_5)
+//! keeps the whole document in memory. [`XmlBomReader`] instead yields the metadata, components,
+//! services, dependencies and vulnerabilities of a BOM as they are read, so that only a single
+//! item needs to fit into memory at any time.
+//!
+//! Only the top-level items of each list are yielded; nested components stay part of their parent.
+//! Sections that describe the BOM as a whole, like compositions, external references or
+//! properties, are skipped.
+//!
+//! ```
+//! use cyclonedx_bom::streaming::{BomItem, XmlBomReader};
+//!
+//! let input = r#"<?xml version="1.0" encoding="utf-8"?>
+//! <bom xmlns="http://cyclonedx.org/schema/bom/1.5" version="1">
+//!   <components>
+//!     <component type="library" bom-ref="a"><name>a</name><version>1.0.0</version></component>
+//!     <component type="library" bom-ref="b"><name>b</name><version>2.0.0</version></component>
+//!   </components>
+//! </bom>"#;
+//!
+//! let mut names = Vec::new();
+//! for item in XmlBomReader::new(input.as_bytes()).expect("Failed to read the BOM header") {
+//!     if let BomItem::Component(component) = item.expect("Failed to read an item") {
+//!         names.push(component.name.to_string());
+//!     }
+//! }
+//! assert_eq!(names, vec!["a", "b"]);
+//! ```
+//!
+//! In the same way, [`BomWriter`] writes the components and dependencies of a BOM as they are
+//! produced, in JSON or XML.
+//!
+//! ```
+//! use cyclonedx_bom::models::bom::{Bom, SpecVersion};
+//! use cyclonedx_bom::models::component::{Classification, Component};
+//! use cyclonedx_bom::streaming::BomWriter;
+//!
+//! let mut writer = BomWriter::json(Vec::new(), SpecVersion::V1_5, Bom::default())
+//!     .expect("Failed to write the BOM header");
+//! for name in ["a", "b"] {
+//!     let component = Component::new(Classification::Library, name, "1.0.0", None);
+//!     writer.write_component(component).expect("Failed to write a component");
+//! }
+//! let output = writer.finish().expect("Failed to finish the BOM");
+//!
+//! let bom = Bom::parse_from_json_v1_5(output.as_slice()).expect("Failed to parse the BOM");
+//! assert_eq!(bom.components.map(|components| components.0.len()), Some(2));
+//! ```
+
+#[cfg(feature = "xml")]
+use std::io::Read;
+use std::io::Write;
+
+use serde::Serialize;
+#[cfg(feature = "xml")]
+use xml::{
+    attribute::OwnedAttribute, name::OwnedName, reader, writer::XmlEvent, EmitterConfig,
+    EventReader, EventWriter, ParserConfig,
+};
+
+#[cfg(feature = "xml")]
+use crate::compression::Decompressed;
+#[cfg(feature = "json")]
+use crate::errors::JsonWriteError;
+use crate::errors::{BomError, BomWriteError};
+#[cfg(feature = "xml")]
+use crate::errors::{XmlReadError, XmlWriteError};
+use crate::models::bom::{Bom, SpecVersion, UrnUuid};
+use crate::models::component::Component;
+use crate::models::dependency::Dependency;
+#[cfg(feature = "xml")]
+use crate::models::metadata::Metadata;
+#[cfg(feature = "xml")]
+use crate::models::service::Service;
+#[cfg(feature = "xml")]
+use crate::models::vulnerability::Vulnerability;
+use crate::specs::{v1_3, v1_4, v1_5};
+#[cfg(feature = "xml")]
+use crate::xml::{
+    optional_attribute, read_lax_validation_tag, to_xml_read_error, to_xml_write_error,
+    unexpected_element_error, FromXml, FromXmlType, ToXml,
+};
+
+/// An item of a BOM yielded by [`XmlBomReader`]
+#[cfg(feature = "xml")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BomItem {
+    Metadata(Box<Metadata>),
+    Component(Box<Component>),
+    Service(Box<Service>),
+    Dependency(Dependency),
+    Vulnerability(Box<Vulnerability>),
+}
+
+/// Iterates over the items of an XML BOM without reading the whole document into memory
+///
+/// The spec version is detected from the namespace of the document. Compressed input is
+/// decompressed like with the other `parse_from_xml_*` functions.
+/// The iterator stops after the first error.
+#[cfg(feature = "xml")]
+pub struct XmlBomReader<R: Read> {
+    event_reader: EventReader<Decompressed<R>>,
+    spec_version: SpecVersion,
+    version: u32,
+    serial_number: Option<UrnUuid>,
+    /// The list element the reader is currently in, e.g. `components`
+    section: Option<String>,
+    finished: bool,
+}
+
+#[cfg(feature = "xml")]
+const BOM_TAG: &str = "bom";
+#[cfg(feature = "xml")]
+const SERIAL_NUMBER_ATTR: &str = "serialNumber";
+#[cfg(feature = "xml")]
+const VERSION_ATTR: &str = "version";
+#[cfg(feature = "xml")]
+const METADATA_TAG: &str = "metadata";
+const COMPONENTS_TAG: &str = "components";
+#[cfg(feature = "xml")]
+const COMPONENT_TAG: &str = "component";
+#[cfg(feature = "xml")]
+const SERVICES_TAG: &str = "services";
+#[cfg(feature = "xml")]
+const SERVICE_TAG: &str = "service";
+const DEPENDENCIES_TAG: &str = "dependencies";
+#[cfg(feature = "xml")]
+const DEPENDENCY_TAG: &str = "dependency";
+#[cfg(feature = "xml")]
+const VULNERABILITIES_TAG: &str = "vulnerabilities";
+#[cfg(feature = "xml")]
+const VULNERABILITY_TAG: &str = "vulnerability";
+#[cfg(feature = "xml")]
+const NAMESPACE_PREFIX: &str = "http://cyclonedx.org/schema/bom/";
+
+#[cfg(feature = "xml")]
+impl<R: Read> XmlBomReader<R> {
+    /// Reads the start of the document up to the opening `bom` element
+    pub fn new(reader: R) -> Result<Self, XmlReadError> {
+        let config = ParserConfig::default().trim_whitespace(true);
+        let mut event_reader = EventReader::new_with_config(Decompressed::new(reader), config);
+
+        event_reader
+            .next()
+            .map_err(to_xml_read_error(BOM_TAG))
+            .and_then(|event| match event {
+                reader::XmlEvent::StartDocument { .. } => Ok(()),
+                unexpected => Err(unexpected_element_error(BOM_TAG, unexpected)),
+            })?;
+
+        let (spec_version, version, serial_number) = event_reader
+            .next()
+            .map_err(to_xml_read_error(BOM_TAG))
+            .and_then(|event| match event {
+                reader::XmlEvent::StartElement {
+                    name,
+                    attributes,
+                    namespace,
+                } if name.local_name == BOM_TAG => {
+                    let actual_namespace = namespace.get(xml::namespace::NS_NO_PREFIX);
+                    let spec_version = match actual_namespace
+                        .and_then(|namespace| namespace.strip_prefix(NAMESPACE_PREFIX))
+                    {
+                        Some("1.3") => SpecVersion::V1_3,
+                        Some("1.4") => SpecVersion::V1_4,
+                        Some("1.5") => SpecVersion::V1_5,
+                        _ => {
+                            return Err(XmlReadError::InvalidNamespaceError {
+                                expected_namespace: format!("{}{{1.3,1.4,1.5}}", NAMESPACE_PREFIX),
+                                actual_namespace: actual_namespace.map(String::from),
+                            })
+                        }
+                    };
+                    let version =
+                        if let Some(version) = optional_attribute(&attributes, VERSION_ATTR) {
+                            u32::from_xml_value(VERSION_ATTR, version)?
+                        } else {
+                            1
+                        };
+                    let serial_number =
+                        optional_attribute(&attributes, SERIAL_NUMBER_ATTR).map(UrnUuid);
+                    Ok((spec_version, version, serial_number))
+                }
+                unexpected => Err(unexpected_element_error(BOM_TAG, unexpected)),
+            })?;
+
+        Ok(Self {
+            event_reader,
+            spec_version,
+            version,
+            serial_number,
+            section: None,
+            finished: false,
+        })
+    }
+
+    /// The spec version of the document, taken from its namespace
+    pub fn spec_version(&self) -> SpecVersion {
+        self.spec_version
+    }
+
+    /// The version of the BOM
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    pub fn serial_number(&self) -> Option<&UrnUuid> {
+        self.serial_number.as_ref()
+    }
+
+    fn read_next(&mut self) -> Result<Option<BomItem>, XmlReadError> {
+        loop {
+            let next_element = self
+                .event_reader
+                .next()
+                .map_err(to_xml_read_error(BOM_TAG))?;
+
+            match (self.section.take(), next_element) {
+                (
+                    Some(section),
+                    reader::XmlEvent::StartElement {
+                        name, attributes, ..
+                    },
+                ) => {
+                    let item = self.read_item(&section, &name, &attributes)?;
+                    self.section = Some(section);
+                    if item.is_some() {
+                        return Ok(item);
+                    }
+                }
+                (Some(_), reader::XmlEvent::EndElement { .. }) => {}
+                (
+                    None,
+                    reader::XmlEvent::StartElement {
+                        name, attributes, ..
+                    },
+                ) => match name.local_name.as_str() {
+                    METADATA_TAG => {
+                        let metadata = self.read_metadata(&name, &attributes)?;
+                        return Ok(Some(BomItem::Metadata(Box::new(metadata))));
+                    }
+                    COMPONENTS_TAG | SERVICES_TAG | DEPENDENCIES_TAG | VULNERABILITIES_TAG => {
+                        self.section = Some(name.local_name);
+                    }
+                    // everything else is skipped, including elements from a different schema
+                    _ => read_lax_validation_tag(&mut self.event_reader, &name)?,
+                },
+                (None, reader::XmlEvent::EndElement { name }) if name.local_name == BOM_TAG => {
+                    self.event_reader
+                        .next()
+                        .map_err(to_xml_read_error(BOM_TAG))
+                        .and_then(|event| match event {
+                            reader::XmlEvent::EndDocument => Ok(()),
+                            unexpected => Err(unexpected_element_error(BOM_TAG, unexpected)),
+                        })?;
+                    return Ok(None);
+                }
+                (_, unexpected) => return Err(unexpected_element_error(BOM_TAG, unexpected)),
+            }
+        }
+    }
+
+    fn read_metadata(
+        &mut self,
+        name: &OwnedName,
+        attributes: &[OwnedAttribute],
+    ) -> Result<Metadata, XmlReadError> {
+        let event_reader = &mut self.event_reader;
+        Ok(match self.spec_version {
+            SpecVersion::V1_3 => {
+                v1_3::metadata::Metadata::read_xml_element(event_reader, name, attributes)?.into()
+            }
+            SpecVersion::V1_4 => {
+                v1_4::metadata::Metadata::read_xml_element(event_reader, name, attributes)?.into()
+            }
+            SpecVersion::V1_5 => {
+                v1_5::metadata::Metadata::read_xml_element(event_reader, name, attributes)?.into()
+            }
+        })
+    }
+
+    /// Reads an element of a list, returns `None` if the element was skipped
+    fn read_item(
+        &mut self,
+        section: &str,
+        name: &OwnedName,
+        attributes: &[OwnedAttribute],
+    ) -> Result<Option<BomItem>, XmlReadError> {
+        use SpecVersion::*;
+
+        let event_reader = &mut self.event_reader;
+        let item = match (self.spec_version, section, name.local_name.as_str()) {
+            (V1_3, COMPONENTS_TAG, COMPONENT_TAG) => BomItem::Component(Box::new(
+                v1_3::component::Component::read_xml_element(event_reader, name, attributes)?
+                    .into(),
+            )),
+            (V1_4, COMPONENTS_TAG, COMPONENT_TAG) => BomItem::Component(Box::new(
+                v1_4::component::Component::read_xml_element(event_reader, name, attributes)?
+                    .into(),
+            )),
+            (V1_5, COMPONENTS_TAG, COMPONENT_TAG) => BomItem::Component(Box::new(
+                v1_5::component::Component::read_xml_element(event_reader, name, attributes)?
+                    .into(),
+            )),
+            (V1_3, SERVICES_TAG, SERVICE_TAG) => BomItem::Service(Box::new(
+                v1_3::service::Service::read_xml_element(event_reader, name, attributes)?.into(),
+            )),
+            (V1_4, SERVICES_TAG, SERVICE_TAG) => BomItem::Service(Box::new(
+                v1_4::service::Service::read_xml_element(event_reader, name, attributes)?.into(),
+            )),
+            (V1_5, SERVICES_TAG, SERVICE_TAG) => BomItem::Service(Box::new(
+                v1_5::service::Service::read_xml_element(event_reader, name, attributes)?.into(),
+            )),
+            (V1_3, DEPENDENCIES_TAG, DEPENDENCY_TAG) => BomItem::Dependency(
+                v1_3::dependency::Dependency::read_xml_element(event_reader, name, attributes)?
+                    .into(),
+            ),
+            (V1_4, DEPENDENCIES_TAG, DEPENDENCY_TAG) => BomItem::Dependency(
+                v1_4::dependency::Dependency::read_xml_element(event_reader, name, attributes)?
+                    .into(),
+            ),
+            (V1_5, DEPENDENCIES_TAG, DEPENDENCY_TAG) => BomItem::Dependency(
+                v1_5::dependency::Dependency::read_xml_element(event_reader, name, attributes)?
+                    .into(),
+            ),
+            (V1_4, VULNERABILITIES_TAG, VULNERABILITY_TAG) => BomItem::Vulnerability(Box::new(
+                v1_4::vulnerability::Vulnerability::read_xml_element(
+                    event_reader,
+                    name,
+                    attributes,
+                )?
+                .into(),
+            )),
+            (V1_5, VULNERABILITIES_TAG, VULNERABILITY_TAG) => BomItem::Vulnerability(Box::new(
+                v1_5::vulnerability::Vulnerability::read_xml_element(
+                    event_reader,
+                    name,
+                    attributes,
+                )?
+                .into(),
+            )),
+            // lax validation of any elements from a different schema
+            _ => {
+                read_lax_validation_tag(event_reader, name)?;
+                return Ok(None);
+            }
+        };
+        Ok(Some(item))
+    }
+}
+
+#[cfg(feature = "xml")]
+impl<R: Read> Iterator for XmlBomReader<R> {
+    type Item = Result<BomItem, XmlReadError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        let result = self.read_next();
+        if !matches!(result, Ok(Some(_))) {
+            self.finished = true;
+        }
+        result.transpose()
+    }
+}
+
+/// Writes a BOM piece by piece, so that its components and dependencies never need to be in memory at once
+///
+/// The BOM given as header is written first, including its own components and dependencies.
+/// More components and then dependencies can be added afterwards; once the first dependency
+/// has been written, no more components can be added.
+/// The document is only complete after calling [`BomWriter::finish`].
+pub struct BomWriter<W: Write> {
+    output: Output<W>,
+    spec_version: SpecVersion,
+    /// The sections of the header which come after the components in XML
+    middle: Option<SpecBom>,
+    /// The sections of the header which come after the dependencies in XML
+    tail: Option<SpecBom>,
+    section: Section,
+    items_in_section: usize,
+}
+
+enum Output<W: Write> {
+    #[cfg(feature = "json")]
+    Json(W),
+    #[cfg(feature = "xml")]
+    Xml(EventWriter<W>),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum Section {
+    Header,
+    Components,
+    Dependencies,
+}
+
+impl<W: Write> BomWriter<W> {
+    /// Starts a pretty-printed JSON document, in which the components and dependencies come last
+    #[cfg(feature = "json")]
+    pub fn json(writer: W, spec_version: SpecVersion, header: Bom) -> Result<Self, BomWriteError> {
+        let (header, components, dependencies) = split_streamed(header);
+
+        let mut writer = writer;
+        let head = serde_json::to_string_pretty(&SpecBom::new(header, spec_version)?)
+            .map_err(JsonWriteError::from)?;
+        write_json(&mut writer, head.strip_suffix("\n}").unwrap_or(&head))?;
+
+        let output = Output::Json(writer);
+        Self::start(output, spec_version, None, None, components, dependencies)
+    }
+
+    /// Starts an XML document, formatted like the output of `output_as_xml_*`
+    #[cfg(feature = "xml")]
+    pub fn xml(writer: W, spec_version: SpecVersion, header: Bom) -> Result<Self, BomWriteError> {
+        let (header, components, dependencies) = split_streamed(header);
+        let head = Bom {
+            metadata: header.metadata,
+            ..empty_bom(header.version, header.serial_number)
+        };
+        let middle = Bom {
+            services: header.services,
+            external_references: header.external_references,
+            ..empty_bom(header.version, None)
+        };
+        let tail = Bom {
+            compositions: header.compositions,
+            properties: header.properties,
+            vulnerabilities: header.vulnerabilities,
+            annotations: header.annotations,
+            formulation: header.formulation,
+            signature: header.signature,
+            ..empty_bom(header.version, None)
+        };
+
+        let config = EmitterConfig::default().perform_indent(true);
+        let mut writer = EventWriter::new_with_config(writer, config);
+        let head = SpecBom::new(head, spec_version)?;
+        head.write_xml_start(&mut writer)?;
+        head.write_xml_sections(&mut writer)?;
+
+        let middle = Some(SpecBom::new(middle, spec_version)?);
+        let tail = Some(SpecBom::new(tail, spec_version)?);
+        let output = Output::Xml(writer);
+        Self::start(output, spec_version, middle, tail, components, dependencies)
+    }
+
+    fn start(
+        output: Output<W>,
+        spec_version: SpecVersion,
+        middle: Option<SpecBom>,
+        tail: Option<SpecBom>,
+        components: Vec<Component>,
+        dependencies: Vec<Dependency>,
+    ) -> Result<Self, BomWriteError> {
+        let mut writer = Self {
+            output,
+            spec_version,
+            middle,
+            tail,
+            section: Section::Header,
+            items_in_section: 0,
+        };
+
+        for component in components {
+            writer.write_component(component)?;
+        }
+        for dependency in dependencies {
+            writer.write_dependency(dependency)?;
+        }
+
+        Ok(writer)
+    }
+
+    /// Appends a component to the top-level components of the BOM
+    pub fn write_component(&mut self, component: Component) -> Result<(), BomWriteError> {
+        if self.section > Section::Components {
+            return Err(BomWriteError::ComponentAfterDependencies);
+        }
+        component.ensure_writable_as(self.spec_version)?;
+        self.enter(Section::Components)?;
+
+        let component = match self.spec_version {
+            SpecVersion::V1_3 => SpecItem::V1_3(v1_3::component::Component::try_from(component)?),
+            SpecVersion::V1_4 => SpecItem::V1_4(v1_4::component::Component::from(component)),
+            SpecVersion::V1_5 => SpecItem::V1_5(v1_5::component::Component::from(component)),
+        };
+        self.write_item(component)
+    }
+
+    /// Appends a dependency, closing the list of components
+    pub fn write_dependency(&mut self, dependency: Dependency) -> Result<(), BomWriteError> {
+        self.enter(Section::Dependencies)?;
+
+        let dependency = match self.spec_version {
+            SpecVersion::V1_3 => SpecItem::V1_3(v1_3::dependency::Dependency::from(dependency)),
+            SpecVersion::V1_4 => SpecItem::V1_4(v1_4::dependency::Dependency::from(dependency)),
+            SpecVersion::V1_5 => SpecItem::V1_5(v1_5::dependency::Dependency::from(dependency)),
+        };
+        self.write_item(dependency)
+    }
+
+    /// Completes the document and returns the underlying writer
+    pub fn finish(mut self) -> Result<W, BomWriteError> {
+        self.close_section()?;
+
+        match self.output {
+            #[cfg(feature = "json")]
+            Output::Json(mut writer) => {
+                write_json(&mut writer, "\n}")?;
+                Ok(writer)
+            }
+            #[cfg(feature = "xml")]
+            Output::Xml(mut writer) => {
+                for sections in [self.middle, self.tail].iter().flatten() {
+                    sections.write_xml_sections(&mut writer)?;
+                }
+                writer
+                    .write(XmlEvent::end_element())
+                    .map_err(to_xml_write_error(BOM_TAG))?;
+                Ok(writer.into_inner())
+            }
+        }
+    }
+
+    /// Moves on to the given list, closing the current one
+    fn enter(&mut self, section: Section) -> Result<(), BomWriteError> {
+        if self.section == section {
+            return Ok(());
+        }
+        self.close_section()?;
+
+        let tag = match section {
+            Section::Header => return Ok(()),
+            Section::Components => COMPONENTS_TAG,
+            Section::Dependencies => DEPENDENCIES_TAG,
+        };
+        match &mut self.output {
+            #[cfg(feature = "json")]
+            Output::Json(writer) => write_json(writer, &format!(",\n  \"{}\": [", tag))?,
+            #[cfg(feature = "xml")]
+            Output::Xml(writer) => {
+                if section == Section::Dependencies {
+                    if let Some(middle) = self.middle.take() {
+                        middle.write_xml_sections(writer)?;
+                    }
+                }
+                writer
+                    .write(XmlEvent::start_element(tag))
+                    .map_err(to_xml_write_error(tag))?;
+            }
+        }
+
+        self.section = section;
+        self.items_in_section = 0;
+        Ok(())
+    }
+
+    fn close_section(&mut self) -> Result<(), BomWriteError> {
+        if self.section == Section::Header {
+            return Ok(());
+        }
+        match &mut self.output {
+            #[cfg(feature = "json")]
+            Output::Json(writer) => write_json(writer, "\n  ]")?,
+            #[cfg(feature = "xml")]
+            Output::Xml(writer) => {
+                let tag = match self.section {
+                    Section::Components => COMPONENTS_TAG,
+                    _ => DEPENDENCIES_TAG,
+                };
+                writer
+                    .write(XmlEvent::end_element())
+                    .map_err(to_xml_write_error(tag))?
+            }
+        }
+        Ok(())
+    }
+
+    fn write_item<T1, T4, T5>(&mut self, item: SpecItem<T1, T4, T5>) -> Result<(), BomWriteError>
+    where
+        T1: StreamedItem,
+        T4: StreamedItem,
+        T5: StreamedItem,
+    {
+        match &mut self.output {
+            #[cfg(feature = "json")]
+            Output::Json(writer) => {
+                let separator = if self.items_in_section == 0 { "" } else { "," };
+                // indent the item to the level of the list's elements
+                let item = serde_json::to_string_pretty(&item)
+                    .map_err(JsonWriteError::from)?
+                    .replace('\n', "\n    ");
+                write_json(writer, &format!("{}\n    {}", separator, item))?;
+            }
+            #[cfg(feature = "xml")]
+            Output::Xml(writer) => match &item {
+                SpecItem::V1_3(item) => item.write_xml_element(writer)?,
+                SpecItem::V1_4(item) => item.write_xml_element(writer)?,
+                SpecItem::V1_5(item) => item.write_xml_element(writer)?,
+            },
+        }
+        self.items_in_section += 1;
+        Ok(())
+    }
+}
+
+/// An item that can be written in every enabled format
+#[cfg(feature = "xml")]
+trait StreamedItem: Serialize + ToXml {}
+
+#[cfg(feature = "xml")]
+impl<T: Serialize + ToXml> StreamedItem for T {}
+
+/// An item that can be written in every enabled format
+#[cfg(not(feature = "xml"))]
+trait StreamedItem: Serialize {}
+
+#[cfg(not(feature = "xml"))]
+impl<T: Serialize> StreamedItem for T {}
+
+/// A BOM or an item in one of the supported spec versions
+#[derive(Serialize)]
+#[serde(untagged)]
+enum SpecItem<T1, T4, T5> {
+    V1_3(T1),
+    V1_4(T4),
+    V1_5(T5),
+}
+
+type SpecBom = SpecItem<v1_3::bom::Bom, v1_4::bom::Bom, v1_5::bom::Bom>;
+
+impl SpecBom {
+    fn new(bom: Bom, spec_version: SpecVersion) -> Result<Self, BomError> {
+        bom.ensure_writable_as(spec_version)?;
+        Ok(match spec_version {
+            SpecVersion::V1_3 => SpecItem::V1_3(v1_3::bom::Bom::try_from(bom)?),
+            SpecVersion::V1_4 => SpecItem::V1_4(bom.into()),
+            SpecVersion::V1_5 => SpecItem::V1_5(bom.into()),
+        })
+    }
+
+    #[cfg(feature = "xml")]
+    fn write_xml_start<W: Write>(&self, writer: &mut EventWriter<W>) -> Result<(), XmlWriteError> {
+        match self {
+            SpecItem::V1_3(bom) => bom.write_xml_start(writer),
+            SpecItem::V1_4(bom) => bom.write_xml_start(writer),
+            SpecItem::V1_5(bom) => bom.write_xml_start(writer),
+        }
+    }
+
+    #[cfg(feature = "xml")]
+    fn write_xml_sections<W: Write>(
+        &self,
+        writer: &mut EventWriter<W>,
+    ) -> Result<(), XmlWriteError> {
+        match self {
+            SpecItem::V1_3(bom) => bom.write_xml_sections(writer),
+            SpecItem::V1_4(bom) => bom.write_xml_sections(writer),
+            SpecItem::V1_5(bom) => bom.write_xml_sections(writer),
+        }
+    }
+}
+
+/// Takes the components and dependencies out of the header, to be written like any other
+fn split_streamed(mut header: Bom) -> (Bom, Vec<Component>, Vec<Dependency>) {
+    let components = header.components.take().map(|c| c.0).unwrap_or_default();
+    let dependencies = header.dependencies.take().map(|d| d.0).unwrap_or_default();
+    (header, components, dependencies)
+}
+
+fn empty_bom(version: u32, serial_number: Option<UrnUuid>) -> Bom {
+    Bom {
+        version,
+        serial_number,
+        metadata: None,
+        components: None,
+        services: None,
+        external_references: None,
+        dependencies: None,
+        compositions: None,
+        properties: None,
+        vulnerabilities: None,
+        signature: None,
+        formulation: None,
+        annotations: None,
+    }
+}
+
+#[cfg(feature = "json")]
+fn write_json<W: Write>(writer: &mut W, json: &str) -> Result<(), JsonWriteError> {
+    writer
+        .write_all(json.as_bytes())
+        .map_err(|error| serde_json::Error::io(error).into())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::models::component::{Classification, Components};
+    use crate::models::dependency::Dependencies;
+    use crate::models::metadata::Metadata;
+    use crate::models::property::{Properties, Property};
+    use crate::models::service::{Service, Services};
+    use pretty_assertions::assert_eq;
+
+    #[cfg(feature = "xml")]
+    #[test]
+    fn it_should_yield_the_same_items_as_the_full_parser() {
+        let input = r#"<?xml version="1.0" encoding="utf-8"?>
+<bom xmlns="http://cyclonedx.org/schema/bom/1.5" serialNumber="urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79" version="2">
+  <metadata>
+    <timestamp>2023-09-01T10:00:00Z</timestamp>
+  </metadata>
+  <components>
+    <component type="application" bom-ref="app">
+      <name>app</name>
+      <version>1.0.0</version>
+      <components>
+        <component type="library" bom-ref="nested">
+          <name>nested</name>
+          <version>1.0.0</version>
+        </component>
+      </components>
+    </component>
+    <component type="library" bom-ref="lib">
+      <name>lib</name>
+      <version>2.0.0</version>
+    </component>
+  </components>
+  <services>
+    <service bom-ref="api">
+      <name>api</name>
+    </service>
+  </services>
+  <externalReferences>
+    <reference type="website">
+      <url>https://example.com</url>
+    </reference>
+  </externalReferences>
+  <dependencies>
+    <dependency ref="app">
+      <dependency ref="lib" />
+    </dependency>
+  </dependencies>
+  <vulnerabilities>
+    <vulnerability bom-ref="vuln">
+      <id>CVE-2023-0001</id>
+    </vulnerability>
+  </vulnerabilities>
+</bom>
+"#;
+        let bom = Bom::parse_from_xml_v1_5(input.as_bytes()).expect("Failed to parse BOM");
+
+        let reader = XmlBomReader::new(input.as_bytes()).expect("Failed to read BOM header");
+        assert_eq!(reader.spec_version(), SpecVersion::V1_5);
+        assert_eq!(reader.version(), 2);
+        assert_eq!(reader.serial_number(), bom.serial_number.as_ref());
+
+        let items = reader
+            .collect::<Result<Vec<_>, _>>()
+            .expect("Failed to stream BOM");
+
+        let mut expected = vec![BomItem::Metadata(Box::new(bom.metadata.unwrap()))];
+        expected.extend(
+            bom.components
+                .unwrap()
+                .0
+                .into_iter()
+                .map(|component| BomItem::Component(Box::new(component))),
+        );
+        expected.extend(
+            bom.services
+                .unwrap()
+                .0
+                .into_iter()
+                .map(|service| BomItem::Service(Box::new(service))),
+        );
+        expected.extend(
+            bom.dependencies
+                .unwrap()
+                .0
+                .into_iter()
+                .map(BomItem::Dependency),
+        );
+        expected.extend(
+            bom.vulnerabilities
+                .unwrap()
+                .0
+                .into_iter()
+                .map(|vulnerability| BomItem::Vulnerability(Box::new(vulnerability))),
+        );
+        assert_eq!(items, expected);
+    }
+
+    #[cfg(feature = "xml")]
+    #[test]
+    fn it_should_detect_the_spec_version() {
+        let input = r#"<?xml version="1.0" encoding="utf-8"?>
+<bom xmlns="http://cyclonedx.org/schema/bom/1.3" version="1">
+  <components>
+    <component type="library">
+      <name>lib</name>
+      <version>1.0.0</version>
+    </component>
+  </components>
+</bom>
+"#;
+        let reader = XmlBomReader::new(input.as_bytes()).expect("Failed to read BOM header");
+        assert_eq!(reader.spec_version(), SpecVersion::V1_3);
+        assert_eq!(reader.count(), 1);
+
+        let input = r#"<?xml version="1.0" encoding="utf-8"?>
+<bom xmlns="http://cyclonedx.org/schema/bom/2.0" version="1" />
+"#;
+        assert!(matches!(
+            XmlBomReader::new(input.as_bytes()),
+            Err(XmlReadError::InvalidNamespaceError { .. })
+        ));
+    }
+
+    #[cfg(feature = "xml")]
+    #[test]
+    fn it_should_stop_after_an_error() {
+        let input = r#"<?xml version="1.0" encoding="utf-8"?>
+<bom xmlns="http://cyclonedx.org/schema/bom/1.5" version="1">
+  <components>
+    <component type="library">
+      <version>1.0.0</version>
+    </component>
+    <component type="library">
+      <name>lib</name>
+      <version>1.0.0</version>
+    </component>
+  </components>
+</bom>
+"#;
+        let mut reader = XmlBomReader::new(input.as_bytes()).expect("Failed to read BOM header");
+        assert!(matches!(
+            reader.next(),
+            Some(Err(XmlReadError::RequiredDataMissing { .. }))
+        ));
+        assert!(reader.next().is_none());
+    }
+
+    fn example_bom() -> Bom {
+        Bom {
+            serial_number: Some(UrnUuid(
+                "urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79".to_string(),
+            )),
+            metadata: Some(Metadata::default()),
+            components: Some(Components(vec![
+                Component::new(
+                    Classification::Application,
+                    "app",
+                    "1.0.0",
+                    Some("app".into()),
+                ),
+                Component::new(Classification::Library, "lib", "2.0.0", Some("lib".into())),
+            ])),
+            services: Some(Services(vec![Service::new("api", Some("api".to_string()))])),
+            dependencies: Some(Dependencies(vec![
+                Dependency {
+                    dependency_ref: "app".to_string(),
+                    dependencies: vec!["lib".to_string()],
+                },
+                Dependency {
+                    dependency_ref: "lib".to_string(),
+                    dependencies: vec![],
+                },
+            ])),
+            properties: Some(Properties(vec![Property::new("name", "value")])),
+            ..Bom::default()
+        }
+    }
+
+    /// Streams the second component and dependency of the example after the header
+    fn stream_example<W: Write>(
+        start: impl FnOnce(Bom) -> Result<BomWriter<W>, BomWriteError>,
+    ) -> W {
+        let mut header = example_bom();
+        let component = header.components.as_mut().unwrap().0.pop().unwrap();
+        let dependency = header.dependencies.as_mut().unwrap().0.pop().unwrap();
+        header.dependencies = None;
+
+        let mut writer = start(header).expect("Failed to write header");
+        writer
+            .write_component(component)
+            .expect("Failed to write component");
+        writer
+            .write_dependency(Dependency {
+                dependency_ref: "app".to_string(),
+                dependencies: vec!["lib".to_string()],
+            })
+            .expect("Failed to write dependency");
+        writer
+            .write_dependency(dependency)
+            .expect("Failed to write dependency");
+        writer.finish().expect("Failed to finish")
+    }
+
+    #[cfg(feature = "xml")]
+    #[test]
+    fn it_should_write_the_same_xml_as_the_full_writer() {
+        let mut expected = Vec::new();
+        example_bom()
+            .output_as_xml_v1_5(&mut expected)
+            .expect("Failed to write BOM");
+
+        let actual = stream_example(|header| BomWriter::xml(Vec::new(), SpecVersion::V1_5, header));
+
+        assert_eq!(
+            String::from_utf8(actual).unwrap(),
+            String::from_utf8(expected).unwrap()
+        );
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn it_should_write_the_same_json_as_the_full_writer() {
+        for spec_version in [SpecVersion::V1_3, SpecVersion::V1_4, SpecVersion::V1_5] {
+            let mut expected = Vec::new();
+            match spec_version {
+                SpecVersion::V1_3 => example_bom().output_as_json_v1_3(&mut expected),
+                SpecVersion::V1_4 => example_bom().output_as_json_v1_4(&mut expected),
+                SpecVersion::V1_5 => example_bom().output_as_json_v1_5(&mut expected),
+            }
+            .expect("Failed to write BOM");
+
+            let actual = stream_example(|header| BomWriter::json(Vec::new(), spec_version, header));
+
+            let actual: serde_json::Value =
+                serde_json::from_slice(&actual).expect("Failed to parse streamed JSON");
+            let expected: serde_json::Value = serde_json::from_slice(&expected).unwrap();
+            assert_eq!(actual, expected);
+        }
+    }
+
+    #[cfg(feature = "xml")]
+    #[test]
+    fn it_should_reject_components_after_dependencies() {
+        let mut writer = BomWriter::xml(Vec::new(), SpecVersion::V1_5, example_bom())
+            .expect("Failed to write header");
+
+        let result = writer.write_component(Component::new(
+            Classification::Library,
+            "late",
+            "1.0.0",
+            None,
+        ));
+
+        assert!(matches!(
+            result,
+            Err(BomWriteError::ComponentAfterDependencies)
+        ));
+    }
+}
@@ -1,5 +1,8 @@
 use crate::errors::BomError;
+use crate::models::component::{Component, Components};
+use serde::de::{Deserialize, Deserializer, SeqAccess, Visitor};
 use std::convert::TryFrom;
+use std::marker::PhantomData;
 
 /// Convert an optional list of a type
 ///
@@ -52,3 +55,67 @@ a.into_iter()
     .map(|x| x.ok_or(NoneError))
     .collect::<Result<Vec<_>,_>>()?
 */
+
+/// A list deserialized from elements of the specification version `S`,
+/// each of which is converted into the model version `M` as soon as it has been read
+///
+/// This avoids holding the whole list in both versions at once.
+pub(crate) struct ConvertedVec<S, M>(pub(crate) Vec<M>, PhantomData<S>);
+
+impl<'de, S: Deserialize<'de>, M: From<S>> Deserialize<'de> for ConvertedVec<S, M> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct ConvertedVecVisitor<S, M>(PhantomData<(S, M)>);
+
+        impl<'de, S: Deserialize<'de>, M: From<S>> Visitor<'de> for ConvertedVecVisitor<S, M> {
+            type Value = ConvertedVec<S, M>;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("a list")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut items = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+                while let Some(item) = seq.next_element::<S>()? {
+                    items.push(item.into());
+                }
+                Ok(ConvertedVec(items, PhantomData))
+            }
+        }
+
+        deserializer.deserialize_seq(ConvertedVecVisitor(PhantomData))
+    }
+}
+
+impl<S> From<ConvertedVec<S, Component>> for Components {
+    fn from(other: ConvertedVec<S, Component>) -> Self {
+        Components(other.0)
+    }
+}
+
+/// Generates a non-empty string of at most `max_len` characters from the alphabet,
+/// for the `Arbitrary` implementations of types that only allow some characters
+#[cfg(feature = "arbitrary")]
+pub(crate) fn arbitrary_token(
+    u: &mut arbitrary::Unstructured,
+    alphabet: &[u8],
+    max_len: usize,
+) -> arbitrary::Result<String> {
+    let len = u.int_in_range(1..=max_len)?;
+    (0..len)
+        .map(|_| u.choose(alphabet).map(|&c| char::from(c)))
+        .collect()
+}
+
+/// Generates a lowercase hex digest of `len` characters
+#[cfg(feature = "arbitrary")]
+pub(crate) fn arbitrary_hex(
+    u: &mut arbitrary::Unstructured,
+    len: usize,
+) -> arbitrary::Result<String> {
+    (0..len)
+        .map(|_| u.choose(b"0123456789abcdef").map(|&c| char::from(c)))
+        .collect()
+}
+
+#[cfg(feature = "arbitrary")]
+pub(crate) const LOWERCASE_ALPHANUMERIC: &[u8] = b"abcdefghijklmnopqrstuvwxyz0123456789";
@@ -16,6 +16,11 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
+use std::borrow::Cow;
+use std::sync::Arc;
+
+use crate::localization::{self, LocalizedMessage, MessageCatalog};
+
 pub trait Validate {
     fn validate(&self) -> ValidationResult {
         self.validate_with_context(ValidationContext::default())
@@ -24,8 +29,68 @@ pub trait Validate {
     fn validate_with_context(&self, context: ValidationContext) -> ValidationResult;
 }
 
-#[derive(Clone, Debug, Default, PartialEq, Eq)]
-pub struct ValidationContext(pub(crate) Vec<ValidationPathComponent>);
+/// The path from the validated root to the current value.
+///
+/// Every extension only allocates a single node that points to its parent, so the context can be
+/// passed down by value cheaply. The full path is only built when it is displayed or compared,
+/// which usually only happens for failures.
+#[derive(Clone, Default)]
+pub struct ValidationContext(Option<Arc<ContextNode>>);
+
+struct ContextNode {
+    parent: Option<Arc<ContextNode>>,
+    segment: PathSegment,
+}
+
+/// A [`ValidationPathComponent`] that borrows the static struct and field names.
+enum PathSegment {
+    Struct {
+        struct_name: Cow<'static, str>,
+        field_name: Cow<'static, str>,
+    },
+    Array {
+        index: usize,
+    },
+    EnumVariant {
+        variant_name: Cow<'static, str>,
+    },
+}
+
+impl PathSegment {
+    fn to_component(&self) -> ValidationPathComponent {
+        match self {
+            PathSegment::Struct {
+                struct_name,
+                field_name,
+            } => ValidationPathComponent::Struct {
+                struct_name: struct_name.to_string(),
+                field_name: field_name.to_string(),
+            },
+            PathSegment::Array { index } => ValidationPathComponent::Array { index: *index },
+            PathSegment::EnumVariant { variant_name } => ValidationPathComponent::EnumVariant {
+                variant_name: variant_name.to_string(),
+            },
+        }
+    }
+}
+
+impl From<ValidationPathComponent> for PathSegment {
+    fn from(component: ValidationPathComponent) -> Self {
+        match component {
+            ValidationPathComponent::Struct {
+                struct_name,
+                field_name,
+            } => PathSegment::Struct {
+                struct_name: struct_name.into(),
+                field_name: field_name.into(),
+            },
+            ValidationPathComponent::Array { index } => PathSegment::Array { index },
+            ValidationPathComponent::EnumVariant { variant_name } => PathSegment::EnumVariant {
+                variant_name: variant_name.into(),
+            },
+        }
+    }
+}
 
 #[allow(dead_code)]
 impl ValidationContext {
@@ -33,29 +98,98 @@ impl ValidationContext {
         ValidationContext::default()
     }
 
+    fn push(&self, segment: PathSegment) -> Self {
+        Self(Some(Arc::new(ContextNode {
+            parent: self.0.clone(),
+            segment,
+        })))
+    }
+
     pub(crate) fn extend_context(&self, components: Vec<ValidationPathComponent>) -> Self {
-        let mut extended_context = self.0.clone();
-        extended_context.extend(components);
-        Self(extended_context)
+        components
+            .into_iter()
+            .fold(self.clone(), |context, component| {
+                context.push(component.into())
+            })
     }
 
     /// Extends the [`ValidationContext`] with an index, e.g. to specify the index in array.
     pub(crate) fn with_index(&self, index: usize) -> Self {
-        let component = vec![ValidationPathComponent::Array { index }];
-        self.extend_context(component)
+        self.push(PathSegment::Array { index })
     }
 
     /// Extends the [`ValidationContext`] with a struct field.
     pub(crate) fn with_struct(
         &self,
-        struct_name: impl ToString,
-        field_name: impl ToString,
+        struct_name: impl Into<Cow<'static, str>>,
+        field_name: impl Into<Cow<'static, str>>,
     ) -> Self {
-        let component = vec![ValidationPathComponent::Struct {
-            struct_name: struct_name.to_string(),
-            field_name: field_name.to_string(),
-        }];
-        self.extend_context(component)
+        self.push(PathSegment::Struct {
+            struct_name: struct_name.into(),
+            field_name: field_name.into(),
+        })
+    }
+
+    /// The segments of the path from the root, innermost last.
+    fn segments(&self) -> Vec<&PathSegment> {
+        let mut segments = vec![];
+        let mut node = self.0.as_deref();
+        while let Some(current) = node {
+            segments.push(&current.segment);
+            node = current.parent.as_deref();
+        }
+        segments.reverse();
+        segments
+    }
+
+    /// Returns the components of the path from the root to the validated value.
+    pub fn components(&self) -> Vec<ValidationPathComponent> {
+        self.segments()
+            .into_iter()
+            .map(PathSegment::to_component)
+            .collect()
+    }
+}
+
+impl From<Vec<ValidationPathComponent>> for ValidationContext {
+    fn from(components: Vec<ValidationPathComponent>) -> Self {
+        ValidationContext::new().extend_context(components)
+    }
+}
+
+impl PartialEq for ValidationContext {
+    fn eq(&self, other: &Self) -> bool {
+        self.components() == other.components()
+    }
+}
+
+impl Eq for ValidationContext {}
+
+impl std::fmt::Debug for ValidationContext {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("ValidationContext")
+            .field(&self.components())
+            .finish()
+    }
+}
+
+/// Renders the path to the validated value, e.g. `Bom.components[2] > Component.version`
+impl std::fmt::Display for ValidationContext {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, segment) in self.segments().into_iter().enumerate() {
+            if i > 0 && !matches!(segment, PathSegment::Array { .. }) {
+                f.write_str(" > ")?;
+            }
+            match segment {
+                PathSegment::Struct {
+                    struct_name,
+                    field_name,
+                } => write!(f, "{}.{}", struct_name, field_name)?,
+                PathSegment::Array { index } => write!(f, "[{}]", index)?,
+                PathSegment::EnumVariant { variant_name } => f.write_str(variant_name)?,
+            }
+        }
+        Ok(())
     }
 }
 
@@ -115,6 +249,29 @@ impl Default for ValidationResult {
     }
 }
 
+/// Validates every element of a list, on several threads with the `parallel` feature.
+///
+/// The results are merged in the order of the list, so they do not depend on scheduling.
+pub(crate) fn validate_list<T: Validate + Sync>(
+    items: &[T],
+    context: &ValidationContext,
+) -> ValidationResult {
+    let validate_item =
+        |(index, item): (usize, &T)| item.validate_with_context(context.with_index(index));
+
+    #[cfg(feature = "parallel")]
+    let results: Vec<ValidationResult> = {
+        use rayon::prelude::*;
+        items.par_iter().enumerate().map(validate_item).collect()
+    };
+    #[cfg(not(feature = "parallel"))]
+    let results: Vec<ValidationResult> = items.iter().enumerate().map(validate_item).collect();
+
+    results
+        .into_iter()
+        .fold(ValidationResult::default(), |acc, result| acc.merge(result))
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct FailureReason {
     pub message: String,
@@ -128,4 +285,89 @@ impl FailureReason {
             context,
         }
     }
+
+    /// Returns the stable key and the arguments of the message,
+    /// or `None` if it is not part of the built-in message catalog.
+    pub fn localized_message(&self) -> Option<LocalizedMessage> {
+        localization::identify(&self.message)
+    }
+
+    /// Renders the message using the given catalog, falling back to the original message.
+    pub fn localize(&self, catalog: &dyn MessageCatalog) -> String {
+        match self.localized_message() {
+            Some(message) => message.render(catalog),
+            None => self.message.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn it_should_display_the_context() {
+        let context = ValidationContext::new()
+            .with_struct("Bom", "components")
+            .with_index(2)
+            .with_struct("Component", "licenses")
+            .with_index(0)
+            .extend_context(vec![ValidationPathComponent::EnumVariant {
+                variant_name: "Expression".to_string(),
+            }]);
+
+        assert_eq!(
+            context.to_string(),
+            "Bom.components[2] > Component.licenses[0] > Expression"
+        );
+        assert_eq!(ValidationContext::new().to_string(), "");
+    }
+
+    #[test]
+    fn it_should_compare_contexts_by_their_path() {
+        let parent = ValidationContext::new().with_struct("Bom", "components");
+        let first = parent.with_index(0);
+        let second = parent.with_index(1);
+
+        assert_eq!(
+            first,
+            ValidationContext::from(vec![
+                ValidationPathComponent::Struct {
+                    struct_name: "Bom".to_string(),
+                    field_name: "components".to_string(),
+                },
+                ValidationPathComponent::Array { index: 0 },
+            ])
+        );
+        assert_ne!(first, second);
+        assert_eq!(parent.components().len(), 1);
+        assert_eq!(
+            format!("{:?}", parent.with_index(1)),
+            format!("{:?}", second)
+        );
+    }
+
+    #[test]
+    fn it_should_merge_list_results_in_order() {
+        use crate::external_models::normalized_string::NormalizedString;
+
+        let items: Vec<NormalizedString> = (0..100)
+            .map(|index| NormalizedString(format!("invalid\t{}", index)))
+            .collect();
+
+        let actual = validate_list(&items, &ValidationContext::new());
+
+        let expected = ValidationResult::Failed {
+            reasons: (0..100)
+                .map(|index| {
+                    FailureReason::new(
+                        "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n",
+                        ValidationContext::new().with_index(index),
+                    )
+                })
+                .collect(),
+        };
+        assert_eq!(actual, expected);
+    }
 }
@@ -0,0 +1,1444 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Verifies the signatures of ingested BOMs.
+//!
+//! JSON documents are signed with the [JSON Signature Format (JSF)](https://cyberphone.github.io/doc/security/jsf.html):
+//! any object of the document, e.g. the BOM itself or a component, can carry a `signature` that covers
+//! the object in its [JSON Canonicalization Scheme (JCS)](https://www.rfc-editor.org/rfc/rfc8785) form.
+//! XML documents are signed with [XML-DSig](https://www.w3.org/TR/xmldsig-core1/) `Signature` elements,
+//! whose references cover the whole document or the element with the given `Id`.
+//!
+//! [`sign_xml`] adds such a signature over the whole document to an XML document; JSON documents
+//! are signed by setting the [`Signature`](crate::models::signature::Signature) of the BOM.
+//!
+//! A document may carry signatures of several parties. [`verify_document`] returns the parts of the
+//! document covered by a signature that verifies with the given key, so check that the parts you rely on,
+//! usually the whole document, are among them.
+//!
+//! ```
+//! use cyclonedx_bom::verification::{verify_document, PublicKey};
+//!
+//! let key = PublicKey::hmac(b"secret".to_vec());
+//! let document = br#"{
+//!   "bomFormat": "CycloneDX", "specVersion": "1.5", "version": 1,
+//!   "signature": {
+//!     "algorithm": "HS256",
+//!     "value": "LXRnjLxl5Z_UIom86Rr2vd6m7uu4_mN1a-0W2-7D76o"
+//!   }
+//! }"#;
+//!
+//! let parts = verify_document(document, &key).unwrap();
+//! assert!(parts[0].is_document());
+//! ```
+
+use std::collections::BTreeMap;
+
+use base64::engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD};
+use base64::Engine;
+use hmac::{Hmac, Mac};
+use rsa::RsaPublicKey;
+use serde_json::{Map, Value};
+use sha2::{Digest, Sha256, Sha384, Sha512};
+use xml::attribute::OwnedAttribute;
+use xml::name::OwnedName;
+use xml::namespace::Namespace;
+use xml::reader::XmlEvent;
+use xml::ParserConfig;
+
+use crate::errors::SignatureError;
+use crate::models::signature::Algorithm;
+
+/// The key to verify signatures with
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PublicKey(Key);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Key {
+    Ed25519(ed25519_dalek::VerifyingKey),
+    Es256(p256::ecdsa::VerifyingKey),
+    Rsa(RsaPublicKey),
+    Hmac(Vec<u8>),
+}
+
+impl PublicKey {
+    /// Reads an Ed25519, P-256 or RSA public key from a PEM encoded `PUBLIC KEY`,
+    /// e.g. as created by `openssl pkey -pubout`, or an RSA key from an `RSA PUBLIC KEY`
+    pub fn from_pem(pem: &str) -> Result<Self, SignatureError> {
+        use p256::pkcs8::DecodePublicKey;
+        use rsa::pkcs1::DecodeRsaPublicKey;
+
+        if let Ok(key) = ed25519_dalek::VerifyingKey::from_public_key_pem(pem) {
+            return Ok(Self(Key::Ed25519(key)));
+        }
+        if let Ok(key) = p256::ecdsa::VerifyingKey::from_public_key_pem(pem) {
+            return Ok(Self(Key::Es256(key)));
+        }
+        RsaPublicKey::from_public_key_pem(pem)
+            .or_else(|_| RsaPublicKey::from_pkcs1_pem(pem))
+            .map(|key| Self(Key::Rsa(key)))
+            .map_err(|_| {
+                SignatureError::InvalidKey(
+                    "expected a PEM encoded Ed25519, P-256 or RSA public key".to_string(),
+                )
+            })
+    }
+
+    /// The shared secret of HMAC signatures
+    pub fn hmac(secret: Vec<u8>) -> Self {
+        Self(Key::Hmac(secret))
+    }
+}
+
+/// A part of a document covered by a verified signature
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SignedPart {
+    /// The JSON pointer to the signed object, e.g. `/components/0`, or for XML the `#id` fragment
+    /// of the signed element. It is empty if the signature covers the whole document.
+    pub location: String,
+    /// The bom-ref of the signed object, if it has one
+    pub bom_ref: Option<String>,
+}
+
+impl SignedPart {
+    /// Whether the signature covers the whole document
+    pub fn is_document(&self) -> bool {
+        self.location.is_empty()
+    }
+}
+
+/// Verifies the signatures of a JSON or XML document, see [`verify_json`] and [`verify_xml`]
+pub fn verify_document(input: &[u8], key: &PublicKey) -> Result<Vec<SignedPart>, SignatureError> {
+    match input.iter().find(|byte| !byte.is_ascii_whitespace()) {
+        Some(b'<') => verify_xml(input, key),
+        _ => verify_json(input, key),
+    }
+}
+
+/// Verifies the JSF signatures of a JSON document and returns the objects they cover.
+///
+/// A signature with several `signers` covers the object if one of them verifies,
+/// a `chain` if one of its links verifies together with the links before it.
+///
+/// # Errors
+///
+/// Returns [`SignatureError::Unsigned`] if the document has no signature,
+/// [`SignatureError::InvalidSignature`] if none of the signatures verifies and at least one uses
+/// an algorithm of the key, and [`SignatureError::NoMatchingSignature`] if all signatures use other algorithms.
+pub fn verify_json(input: &[u8], key: &PublicKey) -> Result<Vec<SignedPart>, SignatureError> {
+    let document: Value = serde_json::from_slice(input)
+        .map_err(|error| SignatureError::InvalidDocument(error.to_string()))?;
+
+    let mut signed = Vec::new();
+    collect_signed_objects(&document, String::new(), &mut signed);
+
+    let mut outcomes = Vec::new();
+    for (location, object) in signed {
+        let outcome = verify_jsf(object, key, &location)?;
+        let part = SignedPart {
+            location,
+            bom_ref: object
+                .get("bom-ref")
+                .and_then(Value::as_str)
+                .map(str::to_string),
+        };
+        outcomes.push((part, outcome));
+    }
+    covered_parts(outcomes)
+}
+
+/// Collects the objects with a `signature`, parents before their children
+fn collect_signed_objects<'a>(
+    value: &'a Value,
+    location: String,
+    signed: &mut Vec<(String, &'a Map<String, Value>)>,
+) {
+    match value {
+        Value::Object(object) => {
+            if object.get("signature").is_some_and(Value::is_object) {
+                signed.push((location.clone(), object));
+            }
+            for (name, value) in object {
+                if name != "signature" {
+                    let name = name.replace('~', "~0").replace('/', "~1");
+                    collect_signed_objects(value, format!("{location}/{name}"), signed);
+                }
+            }
+        }
+        Value::Array(items) => {
+            for (index, item) in items.iter().enumerate() {
+                collect_signed_objects(item, format!("{location}/{index}"), signed);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Whether a signature verified, did not verify, or was made with a different kind of key
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Outcome {
+    Verified,
+    Mismatch,
+    NotApplicable,
+}
+
+impl Outcome {
+    /// Combines the outcomes of alternative signatures of the same content
+    fn or(self, other: Self) -> Self {
+        match (self, other) {
+            (Outcome::Verified, _) | (_, Outcome::Verified) => Outcome::Verified,
+            (Outcome::Mismatch, _) | (_, Outcome::Mismatch) => Outcome::Mismatch,
+            _ => Outcome::NotApplicable,
+        }
+    }
+}
+
+fn covered_parts(outcomes: Vec<(SignedPart, Outcome)>) -> Result<Vec<SignedPart>, SignatureError> {
+    if outcomes.is_empty() {
+        return Err(SignatureError::Unsigned);
+    }
+
+    let mismatch = outcomes
+        .iter()
+        .find(|(_, outcome)| *outcome == Outcome::Mismatch)
+        .map(|(part, _)| part.location.clone());
+    let covered: Vec<SignedPart> = outcomes
+        .into_iter()
+        .filter(|(_, outcome)| *outcome == Outcome::Verified)
+        .map(|(part, _)| part)
+        .collect();
+
+    match mismatch {
+        _ if !covered.is_empty() => Ok(covered),
+        Some(location) => Err(SignatureError::InvalidSignature { location }),
+        None => Err(SignatureError::NoMatchingSignature),
+    }
+}
+
+fn verify_jsf(
+    object: &Map<String, Value>,
+    key: &PublicKey,
+    location: &str,
+) -> Result<Outcome, SignatureError> {
+    let malformed = |reason: &str| SignatureError::MalformedSignature {
+        location: location.to_string(),
+        reason: reason.to_string(),
+    };
+    let signature = object
+        .get("signature")
+        .and_then(Value::as_object)
+        .ok_or_else(|| malformed("the signature is not an object"))?;
+
+    // Every signer is verified against the object as it was when the signer was added,
+    // i.e. with the signer itself but without its value.
+    let verify_signer = |signers: Option<(&str, Vec<Value>)>,
+                         signer: &Value|
+     -> Result<Outcome, SignatureError> {
+        let signer = signer
+            .as_object()
+            .ok_or_else(|| malformed("the signer is not an object"))?;
+        let algorithm = signer
+            .get("algorithm")
+            .and_then(Value::as_str)
+            .ok_or_else(|| malformed("the algorithm is missing"))?;
+        let value = signer
+            .get("value")
+            .and_then(Value::as_str)
+            .ok_or_else(|| malformed("the value is missing"))?;
+        let value = URL_SAFE_NO_PAD
+            .decode(value)
+            .map_err(|_| malformed("the value is not base64url encoded"))?;
+        let method =
+            Method::from_jsf(algorithm).ok_or_else(|| SignatureError::UnsupportedAlgorithm {
+                algorithm: algorithm.to_string(),
+            })?;
+
+        let mut unsigned_signer = signer.clone();
+        unsigned_signer.remove("value");
+        let mut unsigned = object.clone();
+        let unsigned_signature = match signers {
+            None => Value::Object(unsigned_signer),
+            Some((property, mut signers)) => {
+                signers.push(Value::Object(unsigned_signer));
+                let mut unsigned_signature = signature.clone();
+                unsigned_signature.insert(property.to_string(), Value::Array(signers));
+                Value::Object(unsigned_signature)
+            }
+        };
+        unsigned.insert("signature".to_string(), unsigned_signature);
+
+        let data = canonicalize_json(&Value::Object(unsigned));
+        Ok(key.verify(method, data.as_bytes(), &value))
+    };
+
+    if let Some(signers) = signature.get("signers") {
+        let signers = signers
+            .as_array()
+            .ok_or_else(|| malformed("the signers are not an array"))?;
+        signers
+            .iter()
+            .try_fold(Outcome::NotApplicable, |outcome, signer| {
+                Ok(outcome.or(verify_signer(Some(("signers", Vec::new())), signer)?))
+            })
+    } else if let Some(chain) = signature.get("chain") {
+        let chain = chain
+            .as_array()
+            .ok_or_else(|| malformed("the chain is not an array"))?;
+        (0..chain.len()).try_fold(Outcome::NotApplicable, |outcome, index| {
+            let previous = chain[..index].to_vec();
+            Ok(outcome.or(verify_signer(Some(("chain", previous)), &chain[index])?))
+        })
+    } else {
+        verify_signer(None, &Value::Object(signature.clone()))
+    }
+}
+
+/// Serializes the JSON value according to the JSON Canonicalization Scheme, the form JSF signatures cover
+pub fn canonicalize_json(value: &Value) -> String {
+    let mut output = String::new();
+    write_canonical(value, &mut output);
+    output
+}
+
+fn write_canonical(value: &Value, output: &mut String) {
+    match value {
+        Value::Null | Value::Bool(_) | Value::String(_) => output.push_str(&value.to_string()),
+        Value::Number(number) => match number.as_f64() {
+            Some(float) if !(number.is_i64() || number.is_u64()) => {
+                output.push_str(&canonical_float(float))
+            }
+            _ => output.push_str(&number.to_string()),
+        },
+        Value::Array(items) => {
+            output.push('[');
+            for (index, item) in items.iter().enumerate() {
+                if index > 0 {
+                    output.push(',');
+                }
+                write_canonical(item, output);
+            }
+            output.push(']');
+        }
+        Value::Object(members) => {
+            // Members are sorted by the UTF-16 code units of their names
+            let mut members: Vec<_> = members.iter().collect();
+            members.sort_by_cached_key(|(name, _)| name.encode_utf16().collect::<Vec<u16>>());
+
+            output.push('{');
+            for (index, (name, value)) in members.into_iter().enumerate() {
+                if index > 0 {
+                    output.push(',');
+                }
+                output.push_str(&Value::String(name.clone()).to_string());
+                output.push(':');
+                write_canonical(value, output);
+            }
+            output.push('}');
+        }
+    }
+}
+
+/// Formats a number the way ECMAScript does, e.g. `5` instead of `5.0` and `1e+21` instead of `1e21`
+fn canonical_float(float: f64) -> String {
+    if float.fract() == 0.0 && float.abs() < 1e21 {
+        return format!("{}", float as i64);
+    }
+    let formatted = Value::from(float).to_string();
+    match formatted.split_once('e') {
+        Some((mantissa, exponent)) if !exponent.starts_with('-') => {
+            format!("{mantissa}e+{exponent}")
+        }
+        _ => formatted,
+    }
+}
+
+const XMLDSIG_NAMESPACE: &str = "http://www.w3.org/2000/09/xmldsig#";
+const ENVELOPED_SIGNATURE: &str = "http://www.w3.org/2000/09/xmldsig#enveloped-signature";
+const C14N: &str = "http://www.w3.org/TR/2001/REC-xml-c14n-20010315";
+const C14N_WITH_COMMENTS: &str = "http://www.w3.org/TR/2001/REC-xml-c14n-20010315#WithComments";
+const EXC_C14N: &str = "http://www.w3.org/2001/10/xml-exc-c14n#";
+const EXC_C14N_WITH_COMMENTS: &str = "http://www.w3.org/2001/10/xml-exc-c14n#WithComments";
+
+/// Verifies the enveloped XML-DSig signatures of an XML document and returns the parts their
+/// references cover.
+///
+/// References to the whole document (`URI=""`) and to elements by their `Id` are supported,
+/// canonicalized with inclusive or exclusive XML canonicalization 1.0.
+///
+/// # Errors
+///
+/// The same as [`verify_json`]. A signature whose value verifies but whose references
+/// do not match the content of the document is invalid as well.
+pub fn verify_xml(input: &[u8], key: &PublicKey) -> Result<Vec<SignedPart>, SignatureError> {
+    let root = Element::parse(input)?;
+
+    let mut signatures = Vec::new();
+    root.find_all(
+        &|element| element.is(XMLDSIG_NAMESPACE, "Signature"),
+        &mut signatures,
+    );
+
+    let mut outcomes = Vec::new();
+    for (index, signature) in signatures.into_iter().enumerate() {
+        let location = format!("Signature[{index}]");
+        for (part, outcome) in verify_xml_signature(&root, signature, key, &location)? {
+            outcomes.push((part, outcome));
+        }
+    }
+    covered_parts(outcomes)
+}
+
+fn verify_xml_signature(
+    root: &Element,
+    signature: &Element,
+    key: &PublicKey,
+    location: &str,
+) -> Result<Vec<(SignedPart, Outcome)>, SignatureError> {
+    let malformed = |reason: &str| SignatureError::MalformedSignature {
+        location: location.to_string(),
+        reason: reason.to_string(),
+    };
+    let unsupported = |algorithm: &str| SignatureError::UnsupportedAlgorithm {
+        algorithm: algorithm.to_string(),
+    };
+
+    let signed_info = signature
+        .child(XMLDSIG_NAMESPACE, "SignedInfo")
+        .ok_or_else(|| malformed("SignedInfo is missing"))?;
+    let canonicalization = signed_info
+        .child(XMLDSIG_NAMESPACE, "CanonicalizationMethod")
+        .ok_or_else(|| malformed("CanonicalizationMethod is missing"))?;
+    let canonicalization = Canonicalization::from_method(canonicalization)
+        .ok_or_else(|| unsupported(canonicalization.attribute("Algorithm").unwrap_or_default()))?;
+    let method = signed_info
+        .child(XMLDSIG_NAMESPACE, "SignatureMethod")
+        .and_then(|method| method.attribute("Algorithm"))
+        .ok_or_else(|| malformed("SignatureMethod is missing"))?;
+    let method = Method::from_xmldsig(method).ok_or_else(|| unsupported(method))?;
+    let value = signature
+        .child(XMLDSIG_NAMESPACE, "SignatureValue")
+        .ok_or_else(|| malformed("SignatureValue is missing"))?;
+    let value = decode_xml_base64(&value.text())
+        .ok_or_else(|| malformed("SignatureValue is not base64 encoded"))?;
+
+    let mut signed_info_c14n = String::new();
+    canonicalization.write(signed_info, None, &mut signed_info_c14n);
+    let outcome = key.verify(method, signed_info_c14n.as_bytes(), &value);
+
+    let mut parts = Vec::new();
+    for reference in signed_info.children_named(XMLDSIG_NAMESPACE, "Reference") {
+        let uri = reference.attribute("URI").unwrap_or_default();
+        let referenced = match uri.strip_prefix('#') {
+            None if uri.is_empty() => root,
+            Some(id) => {
+                root.find(&|element| {
+                    ["Id", "ID", "id"]
+                        .iter()
+                        .any(|name| element.attribute(name) == Some(id))
+                })
+                .ok_or_else(|| malformed(&format!("the referenced element {uri} does not exist")))?
+            }
+            None => return Err(malformed(&format!("the reference {uri} is not supported"))),
+        };
+
+        let mut excluded = None;
+        let mut reference_canonicalization = Canonicalization::default();
+        for transform in reference
+            .child(XMLDSIG_NAMESPACE, "Transforms")
+            .into_iter()
+            .flat_map(|transforms| transforms.children_named(XMLDSIG_NAMESPACE, "Transform"))
+        {
+            let algorithm = transform.attribute("Algorithm").unwrap_or_default();
+            if algorithm == ENVELOPED_SIGNATURE {
+                excluded = Some(signature);
+            } else {
+                reference_canonicalization = Canonicalization::from_method(transform)
+                    .ok_or_else(|| unsupported(algorithm))?;
+            }
+        }
+
+        let digest_method = reference
+            .child(XMLDSIG_NAMESPACE, "DigestMethod")
+            .and_then(|method| method.attribute("Algorithm"))
+            .ok_or_else(|| malformed("DigestMethod is missing"))?;
+        let digest_method =
+            DigestMethod::from_xmldsig(digest_method).ok_or_else(|| unsupported(digest_method))?;
+        let digest_value = reference
+            .child(XMLDSIG_NAMESPACE, "DigestValue")
+            .and_then(|digest| decode_xml_base64(&digest.text()))
+            .ok_or_else(|| malformed("DigestValue is missing or not base64 encoded"))?;
+
+        let mut content = String::new();
+        reference_canonicalization.write(referenced, excluded, &mut content);
+        let digest_matches = digest_method.digest(content.as_bytes()) == digest_value;
+
+        let part = SignedPart {
+            location: uri.to_string(),
+            bom_ref: (!uri.is_empty())
+                .then(|| referenced.attribute("bom-ref").map(str::to_string))
+                .flatten(),
+        };
+        let outcome = match outcome {
+            Outcome::Verified if !digest_matches => Outcome::Mismatch,
+            outcome => outcome,
+        };
+        parts.push((part, outcome));
+    }
+
+    if parts.is_empty() {
+        return Err(malformed("the signature has no reference"));
+    }
+    Ok(parts)
+}
+
+/// Adds an enveloped XML-DSig signature over the whole document at the end of its root element,
+/// in the form [`verify_xml`] checks.
+///
+/// The document is digested with SHA-256 after exclusive XML canonicalization. `sign` computes the
+/// raw signature with `algorithm` over the canonicalized `SignedInfo`: for ECDSA the concatenated
+/// `r` and `s`, for RSA with PSS a salt as long as the digest.
+///
+/// # Errors
+///
+/// Fails if the input is not a well-formed XML document, or if XML-DSig has no identifier for the
+/// algorithm, i.e. for ES384, ES512 and Ed448.
+pub fn sign_xml(
+    input: &[u8],
+    algorithm: Algorithm,
+    sign: impl FnOnce(&[u8]) -> Vec<u8>,
+) -> Result<Vec<u8>, SignatureError> {
+    let method = xmldsig_signature_method(algorithm).ok_or_else(|| {
+        SignatureError::UnsupportedAlgorithm {
+            algorithm: algorithm.to_string(),
+        }
+    })?;
+    let document = std::str::from_utf8(input)
+        .map_err(|error| SignatureError::InvalidDocument(error.to_string()))?;
+    let root = Element::parse(input)?;
+
+    let exclusive = Canonicalization {
+        exclusive: true,
+        ..Canonicalization::default()
+    };
+    let mut content = String::new();
+    exclusive.write(&root, None, &mut content);
+    let digest = STANDARD.encode(Sha256::digest(content.as_bytes()));
+
+    let signed_info = format!(
+        r#"<ds:SignedInfo xmlns:ds="{XMLDSIG_NAMESPACE}"><ds:CanonicalizationMethod Algorithm="{EXC_C14N}"/><ds:SignatureMethod Algorithm="{method}"/><ds:Reference URI=""><ds:Transforms><ds:Transform Algorithm="{ENVELOPED_SIGNATURE}"/><ds:Transform Algorithm="{EXC_C14N}"/></ds:Transforms><ds:DigestMethod Algorithm="http://www.w3.org/2001/04/xmlenc#sha256"/><ds:DigestValue>{digest}</ds:DigestValue></ds:Reference></ds:SignedInfo>"#
+    );
+    // Exclusive canonicalization of `SignedInfo` does not depend on the namespaces of the document
+    let mut signed_info_c14n = String::new();
+    exclusive.write(
+        &Element::parse(signed_info.as_bytes())?,
+        None,
+        &mut signed_info_c14n,
+    );
+    let value = STANDARD.encode(sign(signed_info_c14n.as_bytes()));
+    let signature = format!(
+        r#"<ds:Signature xmlns:ds="{XMLDSIG_NAMESPACE}">{signed_info}<ds:SignatureValue>{value}</ds:SignatureValue></ds:Signature>"#
+    );
+
+    // Nothing but the signature is added, so that the enveloped signature transform restores
+    // exactly the digested content
+    let (content, trailing) = document.split_at(document.trim_end().len());
+    let signed = match content.strip_suffix("/>") {
+        Some(start) => format!(
+            "{start}>{signature}</{}>{trailing}",
+            root.name.borrow().to_repr()
+        ),
+        None => {
+            let end = content.rfind("</").unwrap_or(content.len());
+            format!(
+                "{}{signature}{}{trailing}",
+                &content[..end],
+                &content[end..]
+            )
+        }
+    };
+    Ok(signed.into_bytes())
+}
+
+/// The XML-DSig identifier of the signature algorithm, the reverse of [`Method::from_xmldsig`]
+fn xmldsig_signature_method(algorithm: Algorithm) -> Option<&'static str> {
+    let method = match algorithm {
+        Algorithm::RS256 => "http://www.w3.org/2001/04/xmldsig-more#rsa-sha256",
+        Algorithm::RS384 => "http://www.w3.org/2001/04/xmldsig-more#rsa-sha384",
+        Algorithm::RS512 => "http://www.w3.org/2001/04/xmldsig-more#rsa-sha512",
+        Algorithm::PS256 => "http://www.w3.org/2007/05/xmldsig-more#sha256-rsa-MGF1",
+        Algorithm::PS384 => "http://www.w3.org/2007/05/xmldsig-more#sha384-rsa-MGF1",
+        Algorithm::PS512 => "http://www.w3.org/2007/05/xmldsig-more#sha512-rsa-MGF1",
+        Algorithm::ES256 => "http://www.w3.org/2001/04/xmldsig-more#ecdsa-sha256",
+        Algorithm::Ed25519 => "http://www.w3.org/2021/04/xmldsig-more#eddsa-ed25519",
+        Algorithm::HS256 => "http://www.w3.org/2001/04/xmldsig-more#hmac-sha256",
+        Algorithm::HS384 => "http://www.w3.org/2001/04/xmldsig-more#hmac-sha384",
+        Algorithm::HS512 => "http://www.w3.org/2001/04/xmldsig-more#hmac-sha512",
+        Algorithm::ES384 | Algorithm::ES512 | Algorithm::Ed448 => return None,
+    };
+    Some(method)
+}
+
+fn decode_xml_base64(text: &str) -> Option<Vec<u8>> {
+    let text: String = text.chars().filter(|c| !c.is_ascii_whitespace()).collect();
+    STANDARD.decode(text).ok()
+}
+
+/// A signature algorithm, with the digest it is computed over
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Method {
+    Ed25519,
+    Es256,
+    RsaPkcs1(DigestMethod),
+    RsaPss(DigestMethod),
+    Hmac(DigestMethod),
+}
+
+impl Method {
+    fn from_jsf(algorithm: &str) -> Option<Self> {
+        let method = match algorithm {
+            "Ed25519" => Method::Ed25519,
+            "ES256" => Method::Es256,
+            "RS256" => Method::RsaPkcs1(DigestMethod::Sha256),
+            "RS384" => Method::RsaPkcs1(DigestMethod::Sha384),
+            "RS512" => Method::RsaPkcs1(DigestMethod::Sha512),
+            "PS256" => Method::RsaPss(DigestMethod::Sha256),
+            "PS384" => Method::RsaPss(DigestMethod::Sha384),
+            "PS512" => Method::RsaPss(DigestMethod::Sha512),
+            "HS256" => Method::Hmac(DigestMethod::Sha256),
+            "HS384" => Method::Hmac(DigestMethod::Sha384),
+            "HS512" => Method::Hmac(DigestMethod::Sha512),
+            _ => return None,
+        };
+        Some(method)
+    }
+
+    fn from_xmldsig(algorithm: &str) -> Option<Self> {
+        let method = match algorithm {
+            "http://www.w3.org/2021/04/xmldsig-more#eddsa-ed25519" => Method::Ed25519,
+            "http://www.w3.org/2001/04/xmldsig-more#ecdsa-sha256" => Method::Es256,
+            "http://www.w3.org/2001/04/xmldsig-more#rsa-sha256" => {
+                Method::RsaPkcs1(DigestMethod::Sha256)
+            }
+            "http://www.w3.org/2001/04/xmldsig-more#rsa-sha384" => {
+                Method::RsaPkcs1(DigestMethod::Sha384)
+            }
+            "http://www.w3.org/2001/04/xmldsig-more#rsa-sha512" => {
+                Method::RsaPkcs1(DigestMethod::Sha512)
+            }
+            "http://www.w3.org/2007/05/xmldsig-more#sha256-rsa-MGF1" => {
+                Method::RsaPss(DigestMethod::Sha256)
+            }
+            "http://www.w3.org/2007/05/xmldsig-more#sha384-rsa-MGF1" => {
+                Method::RsaPss(DigestMethod::Sha384)
+            }
+            "http://www.w3.org/2007/05/xmldsig-more#sha512-rsa-MGF1" => {
+                Method::RsaPss(DigestMethod::Sha512)
+            }
+            "http://www.w3.org/2001/04/xmldsig-more#hmac-sha256" => {
+                Method::Hmac(DigestMethod::Sha256)
+            }
+            "http://www.w3.org/2001/04/xmldsig-more#hmac-sha384" => {
+                Method::Hmac(DigestMethod::Sha384)
+            }
+            "http://www.w3.org/2001/04/xmldsig-more#hmac-sha512" => {
+                Method::Hmac(DigestMethod::Sha512)
+            }
+            _ => return None,
+        };
+        Some(method)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum DigestMethod {
+    Sha256,
+    Sha384,
+    Sha512,
+}
+
+impl DigestMethod {
+    fn from_xmldsig(algorithm: &str) -> Option<Self> {
+        match algorithm {
+            "http://www.w3.org/2001/04/xmlenc#sha256" => Some(DigestMethod::Sha256),
+            "http://www.w3.org/2001/04/xmldsig-more#sha384" => Some(DigestMethod::Sha384),
+            "http://www.w3.org/2001/04/xmlenc#sha512" => Some(DigestMethod::Sha512),
+            _ => None,
+        }
+    }
+
+    fn digest(self, data: &[u8]) -> Vec<u8> {
+        match self {
+            DigestMethod::Sha256 => Sha256::digest(data).to_vec(),
+            DigestMethod::Sha384 => Sha384::digest(data).to_vec(),
+            DigestMethod::Sha512 => Sha512::digest(data).to_vec(),
+        }
+    }
+}
+
+impl PublicKey {
+    fn verify(&self, method: Method, data: &[u8], signature: &[u8]) -> Outcome {
+        let verified = match (&self.0, method) {
+            (Key::Ed25519(key), Method::Ed25519) => {
+                use ed25519_dalek::Verifier;
+                ed25519_dalek::Signature::from_slice(signature)
+                    .is_ok_and(|signature| key.verify(data, &signature).is_ok())
+            }
+            (Key::Es256(key), Method::Es256) => {
+                use p256::ecdsa::signature::Verifier;
+                p256::ecdsa::Signature::from_slice(signature)
+                    .is_ok_and(|signature| key.verify(data, &signature).is_ok())
+            }
+            (Key::Rsa(key), Method::RsaPkcs1(digest)) => match digest {
+                DigestMethod::Sha256 => verify_rsa_pkcs1::<Sha256>(key, data, signature),
+                DigestMethod::Sha384 => verify_rsa_pkcs1::<Sha384>(key, data, signature),
+                DigestMethod::Sha512 => verify_rsa_pkcs1::<Sha512>(key, data, signature),
+            },
+            (Key::Rsa(key), Method::RsaPss(digest)) => match digest {
+                DigestMethod::Sha256 => verify_rsa_pss::<Sha256>(key, data, signature),
+                DigestMethod::Sha384 => verify_rsa_pss::<Sha384>(key, data, signature),
+                DigestMethod::Sha512 => verify_rsa_pss::<Sha512>(key, data, signature),
+            },
+            (Key::Hmac(secret), Method::Hmac(digest)) => match digest {
+                DigestMethod::Sha256 => verify_hmac::<Hmac<Sha256>>(secret, data, signature),
+                DigestMethod::Sha384 => verify_hmac::<Hmac<Sha384>>(secret, data, signature),
+                DigestMethod::Sha512 => verify_hmac::<Hmac<Sha512>>(secret, data, signature),
+            },
+            _ => return Outcome::NotApplicable,
+        };
+
+        if verified {
+            Outcome::Verified
+        } else {
+            Outcome::Mismatch
+        }
+    }
+}
+
+fn verify_rsa_pkcs1<D>(key: &RsaPublicKey, data: &[u8], signature: &[u8]) -> bool
+where
+    D: Digest + rsa::pkcs8::AssociatedOid,
+{
+    use rsa::signature::Verifier;
+    let key = rsa::pkcs1v15::VerifyingKey::<D>::new(key.clone());
+    rsa::pkcs1v15::Signature::try_from(signature)
+        .is_ok_and(|signature| key.verify(data, &signature).is_ok())
+}
+
+fn verify_rsa_pss<D>(key: &RsaPublicKey, data: &[u8], signature: &[u8]) -> bool
+where
+    D: Digest + sha2::digest::FixedOutputReset,
+{
+    use rsa::signature::Verifier;
+    let key = rsa::pss::VerifyingKey::<D>::new(key.clone());
+    rsa::pss::Signature::try_from(signature)
+        .is_ok_and(|signature| key.verify(data, &signature).is_ok())
+}
+
+fn verify_hmac<M: Mac + hmac::digest::KeyInit>(
+    secret: &[u8],
+    data: &[u8],
+    signature: &[u8],
+) -> bool {
+    let mut mac = <M as Mac>::new_from_slice(secret).expect("HMAC accepts keys of any length");
+    mac.update(data);
+    mac.verify_slice(signature).is_ok()
+}
+
+/// An XML element with the namespaces in scope, as needed for canonicalization
+#[derive(Debug)]
+struct Element {
+    name: OwnedName,
+    attributes: Vec<OwnedAttribute>,
+    namespaces: Namespace,
+    children: Vec<Node>,
+}
+
+#[derive(Debug)]
+enum Node {
+    Element(Element),
+    Text(String),
+    Comment(String),
+    ProcessingInstruction(String, Option<String>),
+}
+
+impl Element {
+    fn parse(input: &[u8]) -> Result<Self, SignatureError> {
+        let invalid =
+            |error: &dyn std::fmt::Display| SignatureError::InvalidDocument(error.to_string());
+        let reader = ParserConfig::new()
+            .trim_whitespace(false)
+            .whitespace_to_characters(true)
+            .cdata_to_characters(true)
+            .coalesce_characters(true)
+            .ignore_comments(false)
+            .create_reader(input);
+
+        let mut stack: Vec<Element> = Vec::new();
+        for event in reader {
+            let node = match event.map_err(|e| invalid(&e))? {
+                XmlEvent::StartElement {
+                    name,
+                    attributes,
+                    namespace,
+                } => {
+                    stack.push(Element {
+                        name,
+                        attributes,
+                        namespaces: namespace,
+                        children: Vec::new(),
+                    });
+                    continue;
+                }
+                XmlEvent::EndElement { .. } => {
+                    let element = stack.pop().ok_or_else(|| invalid(&"unbalanced element"))?;
+                    if stack.is_empty() {
+                        return Ok(element);
+                    }
+                    Node::Element(element)
+                }
+                XmlEvent::Characters(text) | XmlEvent::Whitespace(text) => Node::Text(text),
+                XmlEvent::Comment(text) => Node::Comment(text),
+                XmlEvent::ProcessingInstruction { name, data } => {
+                    Node::ProcessingInstruction(name, data)
+                }
+                _ => continue,
+            };
+            // Nodes outside of the root element are not part of any supported reference
+            if let Some(parent) = stack.last_mut() {
+                parent.children.push(node);
+            }
+        }
+        Err(invalid(&"the document has no root element"))
+    }
+
+    fn is(&self, namespace: &str, local_name: &str) -> bool {
+        self.name.namespace.as_deref() == Some(namespace) && self.name.local_name == local_name
+    }
+
+    fn attribute(&self, local_name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|attribute| {
+                attribute.name.namespace.is_none() && attribute.name.local_name == local_name
+            })
+            .map(|attribute| attribute.value.as_str())
+    }
+
+    fn elements(&self) -> impl Iterator<Item = &Element> {
+        self.children.iter().filter_map(|child| match child {
+            Node::Element(element) => Some(element),
+            _ => None,
+        })
+    }
+
+    fn children_named<'a>(
+        &'a self,
+        namespace: &'a str,
+        local_name: &'a str,
+    ) -> impl Iterator<Item = &'a Element> {
+        self.elements()
+            .filter(move |element| element.is(namespace, local_name))
+    }
+
+    fn child(&self, namespace: &str, local_name: &str) -> Option<&Element> {
+        self.elements()
+            .find(|element| element.is(namespace, local_name))
+    }
+
+    fn text(&self) -> String {
+        self.children
+            .iter()
+            .filter_map(|child| match child {
+                Node::Text(text) => Some(text.as_str()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn find(&self, predicate: &dyn Fn(&Element) -> bool) -> Option<&Element> {
+        if predicate(self) {
+            return Some(self);
+        }
+        self.elements().find_map(|element| element.find(predicate))
+    }
+
+    fn find_all<'a>(&'a self, predicate: &dyn Fn(&Element) -> bool, found: &mut Vec<&'a Element>) {
+        if predicate(self) {
+            found.push(self);
+        }
+        for element in self.elements() {
+            element.find_all(predicate, found);
+        }
+    }
+
+    /// The prefixes of the namespaces used by the name of the element and its attributes
+    fn utilized_prefixes(&self) -> Vec<String> {
+        let mut prefixes = vec![self.name.prefix.clone().unwrap_or_default()];
+        prefixes.extend(
+            self.attributes
+                .iter()
+                .filter_map(|attribute| attribute.name.prefix.clone()),
+        );
+        prefixes
+    }
+}
+
+/// XML canonicalization 1.0, inclusive or exclusive, with or without comments
+#[derive(Clone, Debug, Default)]
+struct Canonicalization {
+    exclusive: bool,
+    with_comments: bool,
+    /// The prefixes that exclusive canonicalization treats as in inclusive canonicalization
+    inclusive_prefixes: Vec<String>,
+}
+
+impl Canonicalization {
+    fn from_method(method: &Element) -> Option<Self> {
+        let (exclusive, with_comments) = match method.attribute("Algorithm")? {
+            C14N => (false, false),
+            C14N_WITH_COMMENTS => (false, true),
+            EXC_C14N => (true, false),
+            EXC_C14N_WITH_COMMENTS => (true, true),
+            _ => return None,
+        };
+        let inclusive_prefixes = method
+            .child(EXC_C14N, "InclusiveNamespaces")
+            .and_then(|namespaces| namespaces.attribute("PrefixList"))
+            .map(|list| {
+                list.split_whitespace()
+                    .map(|prefix| match prefix {
+                        "#default" => String::new(),
+                        prefix => prefix.to_string(),
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        Some(Self {
+            exclusive,
+            with_comments,
+            inclusive_prefixes,
+        })
+    }
+
+    /// Writes the canonical form of the element, leaving out the excluded element
+    fn write(&self, element: &Element, excluded: Option<&Element>, output: &mut String) {
+        self.write_element(element, excluded, &BTreeMap::new(), output);
+    }
+
+    fn write_element(
+        &self,
+        element: &Element,
+        excluded: Option<&Element>,
+        rendered: &BTreeMap<String, String>,
+        output: &mut String,
+    ) {
+        if excluded.is_some_and(|excluded| std::ptr::eq(excluded, element)) {
+            return;
+        }
+
+        let in_scope = |prefix: &str| -> String {
+            element
+                .namespaces
+                .get(prefix)
+                .unwrap_or_default()
+                .to_string()
+        };
+        let candidates: Vec<String> = if self.exclusive {
+            let mut prefixes = element.utilized_prefixes();
+            prefixes.extend(
+                self.inclusive_prefixes
+                    .iter()
+                    .filter(|prefix| element.namespaces.get(prefix.as_str()).is_some())
+                    .cloned(),
+            );
+            prefixes
+        } else {
+            element
+                .namespaces
+                .0
+                .keys()
+                .cloned()
+                .chain(std::iter::once(String::new()))
+                .collect()
+        };
+
+        let mut declarations = BTreeMap::new();
+        for prefix in candidates {
+            if prefix == "xml" || prefix == "xmlns" {
+                continue;
+            }
+            let uri = in_scope(&prefix);
+            let previous = rendered
+                .get(&prefix)
+                .map(String::as_str)
+                .unwrap_or_default();
+            if uri != previous {
+                declarations.insert(prefix, uri);
+            }
+        }
+
+        output.push('<');
+        push_name(&element.name, output);
+        for (prefix, uri) in &declarations {
+            if prefix.is_empty() {
+                output.push_str(" xmlns=\"");
+            } else {
+                output.push_str(" xmlns:");
+                output.push_str(prefix);
+                output.push_str("=\"");
+            }
+            escape_attribute(uri, output);
+            output.push('"');
+        }
+        let mut attributes: Vec<&OwnedAttribute> = element.attributes.iter().collect();
+        attributes.sort_by(|a, b| {
+            let key = |attribute: &OwnedAttribute| {
+                (
+                    attribute.name.namespace.clone().unwrap_or_default(),
+                    attribute.name.local_name.clone(),
+                )
+            };
+            key(a).cmp(&key(b))
+        });
+        for attribute in attributes {
+            output.push(' ');
+            push_name(&attribute.name, output);
+            output.push_str("=\"");
+            escape_attribute(&attribute.value, output);
+            output.push('"');
+        }
+        output.push('>');
+
+        let mut rendered = rendered.clone();
+        rendered.extend(declarations);
+        for child in &element.children {
+            match child {
+                Node::Element(child) => self.write_element(child, excluded, &rendered, output),
+                Node::Text(text) => escape_text(text, output),
+                Node::Comment(text) if self.with_comments => {
+                    output.push_str("<!--");
+                    output.push_str(text);
+                    output.push_str("-->");
+                }
+                Node::Comment(_) => {}
+                Node::ProcessingInstruction(name, data) => {
+                    output.push_str("<?");
+                    output.push_str(name);
+                    if let Some(data) = data {
+                        output.push(' ');
+                        output.push_str(data);
+                    }
+                    output.push_str("?>");
+                }
+            }
+        }
+
+        output.push_str("</");
+        push_name(&element.name, output);
+        output.push('>');
+    }
+}
+
+fn push_name(name: &OwnedName, output: &mut String) {
+    if let Some(prefix) = &name.prefix {
+        output.push_str(prefix);
+        output.push(':');
+    }
+    output.push_str(&name.local_name);
+}
+
+fn escape_text(text: &str, output: &mut String) {
+    for c in text.chars() {
+        match c {
+            '&' => output.push_str("&amp;"),
+            '<' => output.push_str("&lt;"),
+            '>' => output.push_str("&gt;"),
+            '\r' => output.push_str("&#xD;"),
+            c => output.push(c),
+        }
+    }
+}
+
+fn escape_attribute(value: &str, output: &mut String) {
+    for c in value.chars() {
+        match c {
+            '&' => output.push_str("&amp;"),
+            '<' => output.push_str("&lt;"),
+            '"' => output.push_str("&quot;"),
+            '\t' => output.push_str("&#x9;"),
+            '\n' => output.push_str("&#xA;"),
+            '\r' => output.push_str("&#xD;"),
+            c => output.push(c),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    fn ed25519_key() -> ed25519_dalek::SigningKey {
+        ed25519_dalek::SigningKey::from_bytes(&[7; 32])
+    }
+
+    fn p256_key() -> p256::ecdsa::SigningKey {
+        p256::ecdsa::SigningKey::from_slice(&[7; 32]).unwrap()
+    }
+
+    /// Signs the object at the pointer with a single signature
+    fn sign_jsf(document: &mut Value, pointer: &str, key: &ed25519_dalek::SigningKey) {
+        use ed25519_dalek::Signer;
+
+        let object = document.pointer_mut(pointer).unwrap();
+        object["signature"] = json!({ "algorithm": "Ed25519" });
+        let signature = key.sign(canonicalize_json(object).as_bytes());
+        object["signature"]["value"] = URL_SAFE_NO_PAD.encode(signature.to_bytes()).into();
+    }
+
+    fn hmac_value(secret: &[u8], data: &Value) -> Value {
+        let mut mac = Hmac::<Sha256>::new_from_slice(secret).unwrap();
+        mac.update(canonicalize_json(data).as_bytes());
+        URL_SAFE_NO_PAD.encode(mac.finalize().into_bytes()).into()
+    }
+
+    fn bom_json() -> Value {
+        json!({
+            "bomFormat": "CycloneDX",
+            "specVersion": "1.5",
+            "version": 1,
+            "components": [
+                { "type": "library", "bom-ref": "serde", "name": "serde", "version": "1.0.193" }
+            ]
+        })
+    }
+
+    fn public_key() -> PublicKey {
+        use ed25519_dalek::pkcs8::EncodePublicKey;
+        let pem = ed25519_key()
+            .verifying_key()
+            .to_public_key_pem(Default::default())
+            .unwrap();
+        PublicKey::from_pem(&pem).unwrap()
+    }
+
+    #[test]
+    fn it_should_canonicalize_json() {
+        let value: Value = serde_json::from_str(
+            r#"{"numbers": [333333333.33333329, 1E30, 4.50, 2e-3, 1e-27, -0],
+                "string": "€$\u000F\u000aA'B\"\\\\\"\/",
+                "literals": [null, true, false],
+                "€": 1, "\r": 2, "a": {"b": 1, "B": 2}}"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            canonicalize_json(&value),
+            r#"{"\r":2,"a":{"B":2,"b":1},"literals":[null,true,false],"numbers":[333333333.3333333,1e+30,4.5,0.002,1e-27,0],"string":"€$\u000f\nA'B\"\\\\\"/","€":1}"#
+        );
+    }
+
+    #[test]
+    fn it_should_verify_the_signed_objects_of_a_json_document() {
+        let mut document = bom_json();
+        sign_jsf(&mut document, "/components/0", &ed25519_key());
+        sign_jsf(&mut document, "", &ed25519_key());
+        let input = serde_json::to_vec_pretty(&document).unwrap();
+
+        assert_eq!(
+            verify_document(&input, &public_key()),
+            Ok(vec![
+                SignedPart {
+                    location: String::new(),
+                    bom_ref: None,
+                },
+                SignedPart {
+                    location: "/components/0".to_string(),
+                    bom_ref: Some("serde".to_string()),
+                },
+            ])
+        );
+
+        document["version"] = 2.into();
+        let input = serde_json::to_vec(&document).unwrap();
+        assert_eq!(
+            verify_document(&input, &public_key()),
+            Ok(vec![SignedPart {
+                location: "/components/0".to_string(),
+                bom_ref: Some("serde".to_string()),
+            }])
+        );
+
+        document["components"][0]["version"] = "1.0.194".into();
+        let input = serde_json::to_vec(&document).unwrap();
+        assert_eq!(
+            verify_document(&input, &public_key()),
+            Err(SignatureError::InvalidSignature {
+                location: String::new()
+            })
+        );
+    }
+
+    #[test]
+    fn it_should_verify_multiple_signers_and_chains() {
+        let mut document = bom_json();
+        let signer = |secret: &[u8], signers: Vec<Value>, property: &str| {
+            let mut unsigned = bom_json();
+            let mut signers = signers;
+            signers.push(json!({ "algorithm": "HS256" }));
+            unsigned["signature"] = json!({ property: signers });
+            json!({ "algorithm": "HS256", "value": hmac_value(secret, &unsigned) })
+        };
+
+        let first = signer(b"first", vec![], "signers");
+        let second = signer(b"second", vec![], "signers");
+        document["signature"] = json!({ "signers": [first, second] });
+        let input = serde_json::to_vec(&document).unwrap();
+        for secret in [&b"first"[..], b"second"] {
+            let parts = verify_json(&input, &PublicKey::hmac(secret.to_vec())).unwrap();
+            assert!(parts[0].is_document());
+        }
+        assert_eq!(
+            verify_json(&input, &PublicKey::hmac(b"third".to_vec())),
+            Err(SignatureError::InvalidSignature {
+                location: String::new()
+            })
+        );
+
+        let first = signer(b"first", vec![], "chain");
+        let second = signer(b"second", vec![first.clone()], "chain");
+        document["signature"] = json!({ "chain": [first, second] });
+        let input = serde_json::to_vec(&document).unwrap();
+        let parts = verify_json(&input, &PublicKey::hmac(b"second".to_vec())).unwrap();
+        assert!(parts[0].is_document());
+    }
+
+    #[test]
+    fn it_should_report_unsigned_and_unmatched_documents() {
+        let mut document = bom_json();
+        let input = serde_json::to_vec(&document).unwrap();
+        assert_eq!(
+            verify_json(&input, &public_key()),
+            Err(SignatureError::Unsigned)
+        );
+
+        document["signature"] = json!({ "algorithm": "HS256", "value": "AAAA" });
+        let input = serde_json::to_vec(&document).unwrap();
+        assert_eq!(
+            verify_json(&input, &public_key()),
+            Err(SignatureError::NoMatchingSignature)
+        );
+
+        document["signature"] = json!({ "algorithm": "XS256", "value": "AAAA" });
+        let input = serde_json::to_vec(&document).unwrap();
+        assert_eq!(
+            verify_json(&input, &public_key()),
+            Err(SignatureError::UnsupportedAlgorithm {
+                algorithm: "XS256".to_string()
+            })
+        );
+
+        assert!(matches!(
+            PublicKey::from_pem("not a key"),
+            Err(SignatureError::InvalidKey(_))
+        ));
+    }
+
+    fn canonicalize_xml(input: &str, path: &[&str], exclusive: bool) -> String {
+        let root = Element::parse(input.as_bytes()).unwrap();
+        let element = path.iter().fold(&root, |element, name| {
+            element
+                .elements()
+                .find(|child| child.name.local_name == *name)
+                .unwrap()
+        });
+        let mut output = String::new();
+        Canonicalization {
+            exclusive,
+            ..Canonicalization::default()
+        }
+        .write(element, None, &mut output);
+        output
+    }
+
+    #[test]
+    fn it_should_canonicalize_xml() {
+        // Example 3.3 of the Canonical XML 1.0 recommendation, without the DTD
+        let input = r#"<?xml version="1.0"?>
+<doc>
+   <e1   />
+   <e2   ></e2>
+   <e3   name = "elem3"   id="elem3"   />
+   <e4   name="elem4"   id="elem4"   ></e4>
+   <e5 a:attr="out" b:attr="sorted" attr2="all" attr="I'm"
+      xmlns:b="http://www.ietf.org"
+      xmlns:a="http://www.w3.org"
+      xmlns="http://example.org"/>
+   <e6 xmlns="" xmlns:a="http://www.w3.org">
+      <e7 xmlns="http://www.ietf.org">
+         <e8 xmlns="" xmlns:a="http://www.w3.org">
+            <e9 xmlns="" xmlns:a="http://www.ietf.org"/>
+         </e8>
+      </e7>
+   </e6>
+</doc>"#;
+        assert_eq!(
+            canonicalize_xml(input, &[], false),
+            r#"<doc>
+   <e1></e1>
+   <e2></e2>
+   <e3 id="elem3" name="elem3"></e3>
+   <e4 id="elem4" name="elem4"></e4>
+   <e5 xmlns="http://example.org" xmlns:a="http://www.w3.org" xmlns:b="http://www.ietf.org" attr="I'm" attr2="all" b:attr="sorted" a:attr="out"></e5>
+   <e6 xmlns:a="http://www.w3.org">
+      <e7 xmlns="http://www.ietf.org">
+         <e8 xmlns="">
+            <e9 xmlns:a="http://www.ietf.org"></e9>
+         </e8>
+      </e7>
+   </e6>
+</doc>"#
+        );
+
+        // Section 2.2 of the Exclusive XML Canonicalization 1.0 recommendation
+        let input = r#"<n0:local xmlns:n0="foo:bar" xmlns:n3="ftp://example.org">
+  <n1:elem2 xmlns:n1="http://example.net" xml:lang="en">
+     <n3:stuff xmlns:n3="ftp://example.org"/>
+  </n1:elem2>
+</n0:local>"#;
+        assert_eq!(
+            canonicalize_xml(input, &["elem2"], false),
+            r#"<n1:elem2 xmlns:n0="foo:bar" xmlns:n1="http://example.net" xmlns:n3="ftp://example.org" xml:lang="en">
+     <n3:stuff></n3:stuff>
+  </n1:elem2>"#
+        );
+        assert_eq!(
+            canonicalize_xml(input, &["elem2"], true),
+            r#"<n1:elem2 xmlns:n1="http://example.net" xml:lang="en">
+     <n3:stuff xmlns:n3="ftp://example.org"></n3:stuff>
+  </n1:elem2>"#
+        );
+    }
+
+    /// Signs the whole document with an enveloped ECDSA signature at the end of the root element
+    fn sign_xml_es256(document: &str) -> String {
+        use p256::ecdsa::signature::Signer;
+
+        let signed = sign_xml(document.as_bytes(), Algorithm::ES256, |data| {
+            let signature: p256::ecdsa::Signature = p256_key().sign(data);
+            signature.to_bytes().to_vec()
+        })
+        .unwrap();
+        String::from_utf8(signed).unwrap()
+    }
+
+    #[test]
+    fn it_should_verify_enveloped_xml_signatures() {
+        use p256::pkcs8::EncodePublicKey;
+
+        let document = r#"<?xml version="1.0" encoding="utf-8"?>
+<bom xmlns="http://cyclonedx.org/schema/bom/1.5" version="1">
+  <components>
+    <component type="library" bom-ref="serde">
+      <name>serde</name>
+      <version>1.0.193</version>
+    </component>
+  </components>
+</bom>"#;
+        let signed = sign_xml_es256(document);
+        let pem = p256_key()
+            .verifying_key()
+            .to_public_key_pem(Default::default())
+            .unwrap();
+        let key = PublicKey::from_pem(&pem).unwrap();
+
+        let parts = verify_document(signed.as_bytes(), &key).unwrap();
+        assert_eq!(
+            parts,
+            vec![SignedPart {
+                location: String::new(),
+                bom_ref: None,
+            }]
+        );
+
+        let tampered = signed.replace("1.0.193", "1.0.194");
+        assert_eq!(
+            verify_document(tampered.as_bytes(), &key),
+            Err(SignatureError::InvalidSignature {
+                location: String::new()
+            })
+        );
+
+        assert_eq!(
+            verify_document(signed.as_bytes(), &public_key()),
+            Err(SignatureError::NoMatchingSignature)
+        );
+        assert_eq!(
+            verify_document(document.as_bytes(), &key),
+            Err(SignatureError::Unsigned)
+        );
+    }
+
+    #[test]
+    fn it_should_sign_xml_documents_as_they_are_verified() {
+        let secret = b"secret".to_vec();
+        let hmac = |data: &[u8]| {
+            let mut mac = Hmac::<Sha256>::new_from_slice(b"secret").unwrap();
+            mac.update(data);
+            mac.finalize().into_bytes().to_vec()
+        };
+
+        for document in [
+            "<bom xmlns=\"http://cyclonedx.org/schema/bom/1.4\" version=\"1\"/>\n",
+            "<bom xmlns=\"http://cyclonedx.org/schema/bom/1.4\" version=\"1\">\n  <components/>\n</bom>",
+        ] {
+            let signed = sign_xml(document.as_bytes(), Algorithm::HS256, hmac).unwrap();
+            assert_eq!(
+                verify_document(&signed, &PublicKey::hmac(secret.clone())),
+                Ok(vec![SignedPart {
+                    location: String::new(),
+                    bom_ref: None,
+                }])
+            );
+        }
+
+        assert!(matches!(
+            sign_xml(b"<bom", Algorithm::HS256, hmac),
+            Err(SignatureError::InvalidDocument(_))
+        ));
+        assert_eq!(
+            sign_xml(b"<bom/>", Algorithm::ES384, hmac),
+            Err(SignatureError::UnsupportedAlgorithm {
+                algorithm: "ES384".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn it_should_sign_with_the_methods_it_verifies() {
+        use Algorithm::*;
+
+        for algorithm in [
+            RS256, RS384, RS512, PS256, PS384, PS512, ES256, Ed25519, HS256, HS384, HS512,
+        ] {
+            let method = xmldsig_signature_method(algorithm).unwrap();
+            assert!(Method::from_xmldsig(method).is_some(), "{method}");
+        }
+    }
+}
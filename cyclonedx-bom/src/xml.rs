@@ -1,3 +1,45 @@
+//! Traits and helpers to read and write XML elements.
+//!
+//! CycloneDX allows elements of other namespaces as extensions, e.g. at the end of the `bom`
+//! element. Implement [`ToXml`] for an extension to write it with
+//! [`Bom::output_as_xml_with_extensions`](crate::models::bom::Bom::output_as_xml_with_extensions).
+//! The extensions of a parsed document are kept by
+//! [`Bom::parse_from_xml_preserving_extensions`](crate::models::bom::Bom::parse_from_xml_preserving_extensions)
+//! instead.
+//!
+//! ```
+//! use cyclonedx_bom::errors::XmlWriteError;
+//! use cyclonedx_bom::models::bom::{Bom, SpecVersion};
+//! use cyclonedx_bom::xml::{to_xml_write_error, ToXml};
+//! use xml::writer::XmlEvent;
+//!
+//! struct BuildInfo(String);
+//!
+//! impl ToXml for BuildInfo {
+//!     fn write_xml_element<W: std::io::Write>(
+//!         &self,
+//!         writer: &mut xml::EventWriter<W>,
+//!     ) -> Result<(), XmlWriteError> {
+//!         writer
+//!             .write(XmlEvent::start_element("build:info").ns("build", "urn:example:build"))
+//!             .map_err(to_xml_write_error("build:info"))?;
+//!         writer
+//!             .write(XmlEvent::characters(&self.0))
+//!             .map_err(to_xml_write_error("build:info"))?;
+//!         writer
+//!             .write(XmlEvent::end_element())
+//!             .map_err(to_xml_write_error("build:info"))
+//!     }
+//! }
+//!
+//! let mut output = Vec::new();
+//! Bom::default()
+//!     .output_as_xml_with_extensions(&mut output, SpecVersion::V1_5, &[BuildInfo("ci-42".to_string())])
+//!     .unwrap();
+//! let output = String::from_utf8(output).unwrap();
+//! assert!(output.contains(r#"<build:info xmlns:build="urn:example:build">ci-42</build:info>"#));
+//! ```
+
 use crate::errors::{XmlReadError, XmlWriteError};
 use std::io::{Read, Write};
 use xml::{
@@ -16,29 +58,33 @@ pub(crate) trait ToXmlDocument {
     ) -> Result<(), XmlWriteError>;
 }
 
-pub(crate) trait ToXml {
+/// A value that is written as an XML element with a fixed name
+pub trait ToXml {
     fn write_xml_element<W: Write>(&self, writer: &mut EventWriter<W>)
         -> Result<(), XmlWriteError>;
 
+    /// Whether the value writes an element at all, e.g. not for an empty list
     fn will_write(&self) -> bool {
         true
     }
 }
 
-pub(crate) trait ToInnerXml {
+/// A value that is written as an XML element whose name is given by the parent element
+pub trait ToInnerXml {
     fn write_xml_named_element<W: Write>(
         &self,
         writer: &mut EventWriter<W>,
         tag: &str,
     ) -> Result<(), XmlWriteError>;
 
+    /// Whether the value writes an element at all, e.g. not for an empty list
     fn will_write(&self) -> bool {
         true
     }
 }
 
 /// Write a tag that is of the form `<tag>content</tag>`
-pub(crate) fn write_simple_tag<W: Write>(
+pub fn write_simple_tag<W: Write>(
     writer: &mut EventWriter<W>,
     tag: &str,
     content: &str,
@@ -58,7 +104,7 @@ pub(crate) fn write_simple_tag<W: Write>(
 }
 
 /// Writes a simple start tag of the form `<tag>` without attributes.
-pub(crate) fn write_start_tag<W: Write>(
+pub fn write_start_tag<W: Write>(
     writer: &mut EventWriter<W>,
     tag: &str,
 ) -> Result<(), XmlWriteError> {
@@ -68,7 +114,7 @@ pub(crate) fn write_start_tag<W: Write>(
 }
 
 /// Writes the closing tag of the form `</tag>`
-pub(crate) fn write_close_tag<W: Write>(
+pub fn write_close_tag<W: Write>(
     writer: &mut EventWriter<W>,
     tag: &str,
 ) -> Result<(), XmlWriteError> {
@@ -77,7 +123,8 @@ pub(crate) fn write_close_tag<W: Write>(
         .map_err(to_xml_write_error(tag))
 }
 
-pub(crate) fn to_xml_write_error(
+/// Maps an error of the XML writer to an [`XmlWriteError`] for the given element
+pub fn to_xml_write_error(
     element: impl AsRef<str>,
 ) -> impl FnOnce(xml::writer::Error) -> XmlWriteError {
     let element = element.as_ref().to_owned();
@@ -90,7 +137,8 @@ pub(crate) trait FromXmlDocument {
         Self: Sized;
 }
 
-pub(crate) trait FromXml {
+/// A value that is read from an XML element, after its start tag has been read
+pub trait FromXml {
     fn read_xml_element<R: Read>(
         event_reader: &mut EventReader<R>,
         element_name: &OwnedName,
@@ -100,7 +148,8 @@ pub(crate) trait FromXml {
         Self: Sized;
 }
 
-pub(crate) fn to_xml_read_error(
+/// Maps an error of the XML reader to an [`XmlReadError`] for the given element
+pub fn to_xml_read_error(
     element_name: impl AsRef<str>,
 ) -> impl FnOnce(xml::reader::Error) -> XmlReadError {
     let element_name = element_name.as_ref().to_owned();
@@ -150,7 +199,8 @@ pub(crate) fn inner_text_or_none(
     }
 }
 
-pub(crate) fn closing_tag_or_error(
+/// Expects the event to be the closing tag of the given element
+pub fn closing_tag_or_error(
     element: &OwnedName,
 ) -> impl FnOnce(xml::reader::XmlEvent) -> Result<(), XmlReadError> {
     let element = element.clone();
@@ -160,7 +210,8 @@ pub(crate) fn closing_tag_or_error(
     }
 }
 
-pub(crate) fn attribute_or_error(
+/// Returns the value of the required attribute, or an error if it is missing
+pub fn attribute_or_error(
     element_name: &OwnedName,
     attributes: &[OwnedAttribute],
     expected_attribute: &str,
@@ -176,7 +227,8 @@ pub(crate) fn attribute_or_error(
         })
 }
 
-pub(crate) fn optional_attribute(
+/// Returns the value of the attribute, if it is present
+pub fn optional_attribute(
     attributes: &[OwnedAttribute],
     expected_attribute: &str,
 ) -> Option<String> {
@@ -187,7 +239,8 @@ pub(crate) fn optional_attribute(
         .next()
 }
 
-pub(crate) trait FromXmlType
+/// A value that is read from the text of an XML element or attribute
+pub trait FromXmlType
 where
     Self: Sized,
 {
@@ -259,7 +312,8 @@ impl FromXmlType for f32 {
     }
 }
 
-pub(crate) fn read_simple_tag<R: Read>(
+/// Reads the text of an element of the form `<tag>content</tag>`, after its start tag has been read
+pub fn read_simple_tag<R: Read>(
     event_reader: &mut EventReader<R>,
     element: &OwnedName,
 ) -> Result<String, XmlReadError> {
@@ -492,7 +546,8 @@ pub(crate) fn read_lax_validation_list_tag<R: Read, X: FromXml>(
     Ok(items)
 }
 
-pub(crate) fn unexpected_element_error(
+/// Reports an unexpected event while reading the given element
+pub fn unexpected_element_error(
     element: impl ToString,
     unexpected: reader::XmlEvent,
 ) -> XmlReadError {
@@ -502,6 +557,343 @@ pub(crate) fn unexpected_element_error(
     }
 }
 
+/// The namespaces of the spec versions start with this prefix, elements and attributes of any
+/// other namespace are extensions
+const CYCLONEDX_NAMESPACE_PREFIX: &str = "http://cyclonedx.org/schema/bom/";
+
+/// The extension elements and attributes of a document, returned by
+/// [`Bom::parse_from_xml_preserving_extensions`](crate::models::bom::Bom::parse_from_xml_preserving_extensions)
+/// to be written back by
+/// [`Bom::output_as_xml_preserving_extensions`](crate::models::bom::Bom::output_as_xml_preserving_extensions)
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct XmlExtensions(Vec<XmlExtension>);
+
+impl XmlExtensions {
+    /// Collects the elements and attributes of other namespaces than CycloneDX's in the document
+    ///
+    /// The content of an extension element is kept as a whole, including its own CycloneDX
+    /// elements.
+    pub(crate) fn read(input: &[u8]) -> Result<Self, XmlReadError> {
+        let root = XmlElement::read_document(input)?;
+        let mut extensions = Vec::new();
+        collect_extensions(&root, &mut Vec::new(), &mut extensions);
+        Ok(Self(extensions))
+    }
+
+    /// Adds the extensions to the elements of `input` they were found in, and writes the result
+    ///
+    /// An extension element is written after the CycloneDX element it followed, or at the end if
+    /// that element is gone. The extensions of elements that are gone are dropped.
+    pub(crate) fn restore<W: Write>(
+        &self,
+        input: &[u8],
+        writer: &mut W,
+    ) -> Result<(), XmlWriteError> {
+        let mut root = XmlElement::read_document(input).map_err(|error| {
+            XmlWriteError::XmlElementWriteError {
+                error: std::io::Error::new(std::io::ErrorKind::InvalidData, error.to_string())
+                    .into(),
+                element: "bom".to_string(),
+            }
+        })?;
+
+        for extension in &self.0 {
+            let Some(element) = root.resolve_mut(&extension.path) else {
+                continue;
+            };
+            match &extension.content {
+                ExtensionContent::Attribute(attribute) => element.add_attribute(attribute),
+                ExtensionContent::Element {
+                    after,
+                    element: extension,
+                } => element.insert_after(after.as_ref(), extension),
+            }
+        }
+
+        let config = xml::EmitterConfig::default().perform_indent(true);
+        root.write_xml_element(&mut EventWriter::new_with_config(writer, config))
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct XmlExtension {
+    /// The path from the root to the CycloneDX element that contains the extension
+    path: Vec<XmlPathSegment>,
+    content: ExtensionContent,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum ExtensionContent {
+    Attribute(OwnedAttribute),
+    Element {
+        /// The CycloneDX element it followed, if any
+        after: Option<XmlPathSegment>,
+        element: XmlElement,
+    },
+}
+
+/// A step from an element to one of its CycloneDX child elements
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum XmlPathSegment {
+    /// The child element with the `bom-ref` attribute, so that it is found again after the
+    /// elements were reordered
+    BomRef(String),
+    /// The child element that is the `index`th one with the local name
+    Element { local_name: String, index: usize },
+}
+
+fn collect_extensions(
+    element: &XmlElement,
+    path: &mut Vec<XmlPathSegment>,
+    extensions: &mut Vec<XmlExtension>,
+) {
+    for attribute in &element.attributes {
+        if attribute.name.namespace.is_some() && is_extension(&attribute.name) {
+            extensions.push(XmlExtension {
+                path: path.clone(),
+                content: ExtensionContent::Attribute(attribute.clone()),
+            });
+        }
+    }
+
+    let mut after = None;
+    for (child, segment) in element.child_elements() {
+        match segment {
+            Some(segment) => {
+                path.push(segment.clone());
+                collect_extensions(child, path, extensions);
+                path.pop();
+                after = Some(segment);
+            }
+            None => extensions.push(XmlExtension {
+                path: path.clone(),
+                content: ExtensionContent::Element {
+                    after: after.clone(),
+                    element: child.clone(),
+                },
+            }),
+        }
+    }
+}
+
+fn is_extension(name: &OwnedName) -> bool {
+    !name
+        .namespace
+        .as_deref()
+        .is_some_and(|namespace| namespace.starts_with(CYCLONEDX_NAMESPACE_PREFIX))
+}
+
+/// An element of a document, read as it is
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct XmlElement {
+    name: OwnedName,
+    attributes: Vec<OwnedAttribute>,
+    namespace: Namespace,
+    children: Vec<XmlNode>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum XmlNode {
+    Element(XmlElement),
+    Text(String),
+}
+
+impl XmlElement {
+    fn read_document(input: &[u8]) -> Result<Self, XmlReadError> {
+        let config = xml::ParserConfig::default().trim_whitespace(true);
+        let mut event_reader = EventReader::new_with_config(input, config);
+        loop {
+            match event_reader.next().map_err(to_xml_read_error("bom"))? {
+                reader::XmlEvent::StartElement {
+                    name,
+                    attributes,
+                    namespace,
+                } => return Self::read(&mut event_reader, name, attributes, namespace),
+                unexpected @ reader::XmlEvent::EndDocument => {
+                    return Err(unexpected_element_error("bom", unexpected))
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn read<R: Read>(
+        event_reader: &mut EventReader<R>,
+        name: OwnedName,
+        attributes: Vec<OwnedAttribute>,
+        namespace: Namespace,
+    ) -> Result<Self, XmlReadError> {
+        let mut children = Vec::new();
+        loop {
+            match event_reader
+                .next()
+                .map_err(to_xml_read_error(&name.local_name))?
+            {
+                reader::XmlEvent::StartElement {
+                    name,
+                    attributes,
+                    namespace,
+                } => children.push(XmlNode::Element(Self::read(
+                    event_reader,
+                    name,
+                    attributes,
+                    namespace,
+                )?)),
+                reader::XmlEvent::Characters(text) | reader::XmlEvent::CData(text) => {
+                    children.push(XmlNode::Text(text))
+                }
+                reader::XmlEvent::EndElement { .. } => break,
+                unexpected @ reader::XmlEvent::EndDocument => {
+                    return Err(unexpected_element_error(&name, unexpected))
+                }
+                _ => {}
+            }
+        }
+
+        Ok(Self {
+            name,
+            attributes,
+            namespace,
+            children,
+        })
+    }
+
+    /// The child elements with the path segments of the CycloneDX ones
+    fn child_elements(&self) -> Vec<(&XmlElement, Option<XmlPathSegment>)> {
+        let mut counts = std::collections::HashMap::new();
+        self.children
+            .iter()
+            .filter_map(|child| match child {
+                XmlNode::Element(element) => Some(element),
+                XmlNode::Text(_) => None,
+            })
+            .map(|element| {
+                if is_extension(&element.name) {
+                    return (element, None);
+                }
+
+                let local_name = &element.name.local_name;
+                let index = counts.entry(local_name.clone()).or_insert(0);
+                let segment = match element.bom_ref() {
+                    Some(bom_ref) => XmlPathSegment::BomRef(bom_ref.to_string()),
+                    None => XmlPathSegment::Element {
+                        local_name: local_name.clone(),
+                        index: *index,
+                    },
+                };
+                *index += 1;
+                (element, Some(segment))
+            })
+            .collect()
+    }
+
+    fn bom_ref(&self) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|attribute| {
+                attribute.name.namespace.is_none() && attribute.name.local_name == "bom-ref"
+            })
+            .map(|attribute| attribute.value.as_str())
+    }
+
+    /// The position in the children of the CycloneDX element with the path segment
+    fn position(&self, segment: &XmlPathSegment) -> Option<usize> {
+        let position = self
+            .child_elements()
+            .iter()
+            .position(|(_, child)| child.as_ref() == Some(segment))?;
+        self.children
+            .iter()
+            .enumerate()
+            .filter(|(_, child)| matches!(child, XmlNode::Element(_)))
+            .nth(position)
+            .map(|(position, _)| position)
+    }
+
+    fn resolve_mut(&mut self, path: &[XmlPathSegment]) -> Option<&mut XmlElement> {
+        let Some((segment, path)) = path.split_first() else {
+            return Some(self);
+        };
+        let position = self.position(segment)?;
+        match &mut self.children[position] {
+            XmlNode::Element(element) => element.resolve_mut(path),
+            XmlNode::Text(_) => None,
+        }
+    }
+
+    fn add_attribute(&mut self, attribute: &OwnedAttribute) {
+        if self
+            .attributes
+            .iter()
+            .any(|existing| existing.name == attribute.name)
+        {
+            return;
+        }
+        if let (Some(prefix), Some(namespace)) = (&attribute.name.prefix, &attribute.name.namespace)
+        {
+            self.namespace.put(prefix.clone(), namespace.clone());
+        }
+        self.attributes.push(attribute.clone());
+    }
+
+    /// Inserts the extension after the CycloneDX element and the extensions already inserted
+    /// after it
+    fn insert_after(&mut self, after: Option<&XmlPathSegment>, extension: &XmlElement) {
+        let mut position = match after {
+            Some(after) => match self.position(after) {
+                Some(position) => position + 1,
+                None => self.children.len(),
+            },
+            None => 0,
+        };
+        while let Some(XmlNode::Element(element)) = self.children.get(position) {
+            if !is_extension(&element.name) {
+                break;
+            }
+            position += 1;
+        }
+        self.children
+            .insert(position, XmlNode::Element(extension.clone()));
+    }
+}
+
+impl ToXml for XmlElement {
+    fn write_xml_element<W: Write>(
+        &self,
+        writer: &mut EventWriter<W>,
+    ) -> Result<(), XmlWriteError> {
+        let attributes: Vec<_> = self.attributes.iter().map(OwnedAttribute::borrow).collect();
+        writer
+            .write(XmlEvent::StartElement {
+                name: self.name.borrow(),
+                attributes: attributes.into(),
+                namespace: std::borrow::Cow::Borrowed(&self.namespace),
+            })
+            .map_err(to_xml_write_error(&self.name.local_name))?;
+
+        for child in &self.children {
+            match child {
+                XmlNode::Element(element) => element.write_xml_element(writer)?,
+                XmlNode::Text(text) => writer
+                    .write(XmlEvent::characters(text))
+                    .map_err(to_xml_write_error(&self.name.local_name))?,
+            }
+        }
+
+        writer
+            .write(XmlEvent::end_element())
+            .map_err(to_xml_write_error(&self.name.local_name))
+    }
+}
+
 #[cfg(test)]
 pub(crate) mod test {
     use xml::{EmitterConfig, ParserConfig};
@@ -1,3 +1,4 @@
+#[cfg(all(feature = "json", feature = "v1_4"))]
 mod examples {
     use cyclonedx_bom::models::bom::Bom;
     use cyclonedx_bom::validation::{Validate, ValidationResult};
@@ -1,7 +1,9 @@
+#[cfg(feature = "v1_4")]
 mod v1_4 {
     use cyclonedx_bom::models::bom::Bom;
     use cyclonedx_bom::validation::{Validate, ValidationResult};
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_parse_all_of_the_valid_xml_specifications() {
         insta::with_settings!({
@@ -29,6 +31,7 @@ mod v1_4 {
         });
     }
 
+    #[cfg(feature = "json")]
     #[test]
     fn it_should_parse_all_of_the_valid_json_specifications() {
         insta::with_settings!({
@@ -56,6 +59,7 @@ mod v1_4 {
         });
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_fail_to_parse_all_of_the_invalid_xml_specifications() {
         insta::with_settings!({
@@ -76,6 +80,7 @@ mod v1_4 {
         });
     }
 
+    #[cfg(feature = "json")]
     #[test]
     fn it_should_fail_to_parse_all_of_the_invalid_json_specifications() {
         insta::with_settings!({
@@ -0,0 +1,50 @@
+#![cfg(feature = "specs")]
+
+#[cfg(feature = "v1_4")]
+mod v1_4 {
+    use cyclonedx_bom::specs::v1_4::bom::Bom;
+
+    #[test]
+    fn it_should_keep_the_values_as_written() {
+        let input = serde_json::json!({
+            "bomFormat": "CycloneDX",
+            "specVersion": "1.4",
+            "serialNumber": "urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79",
+            "version": 1,
+            "components": [
+                {
+                    "type": "not-a-classification",
+                    "name": "acme-library",
+                    "version": "1.0.0",
+                    "hashes": [{ "alg": "SHA-256", "content": "not a hash" }]
+                }
+            ]
+        });
+
+        let bom: Bom = serde_json::from_value(input.clone()).unwrap();
+        let output = serde_json::to_value(&bom).unwrap();
+
+        assert_eq!(output, input);
+    }
+
+    #[cfg(feature = "xml")]
+    #[test]
+    fn it_should_round_trip_xml_documents() {
+        let input = r#"<?xml version="1.0" encoding="utf-8"?>
+<bom xmlns="http://cyclonedx.org/schema/bom/1.4" serialNumber="urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79" version="1">
+  <components>
+    <component type="library">
+      <name>acme-library</name>
+      <version>1.0.0</version>
+    </component>
+  </components>
+</bom>"#;
+        let mut bom = Bom::parse_from_xml(input.as_bytes()).unwrap();
+        bom.version = 2;
+
+        let mut output = Vec::new();
+        bom.output_as_xml(&mut output).unwrap();
+
+        assert_eq!(Bom::parse_from_xml(output.as_slice()).unwrap(), bom);
+    }
+}